@@ -287,6 +287,11 @@ impl ExecutionClient for BinanceClient {
     }
 
     async fn amend_order(&self, request: OrderUpdateRequest) -> BrokerResult<Order> {
+        if request.new_trigger_price.is_some() {
+            return Err(BrokerError::InvalidRequest(
+                "Binance cannot amend stop trigger prices".into(),
+            ));
+        }
         self.throttle_weight(ORDER_WEIGHT).await?;
         let new_price = request.new_price.ok_or_else(|| {
             BrokerError::InvalidRequest("amend requires new price for Binance".into())
@@ -575,6 +580,7 @@ fn instrument_from_symbol(
         .unwrap_or(quote);
     let mut tick_size = Decimal::ONE;
     let mut lot_size = Decimal::ONE;
+    let mut min_notional = None;
     if let Some(filters) = &symbol.filters {
         for filter in filters {
             match filter.filter_type.as_deref() {
@@ -588,6 +594,9 @@ fn instrument_from_symbol(
                         lot_size = step;
                     }
                 }
+                Some("MIN_NOTIONAL") => {
+                    min_notional = parse_decimal_opt(filter.notional.as_deref());
+                }
                 _ => {}
             }
         }
@@ -601,6 +610,7 @@ fn instrument_from_symbol(
         settlement_currency: settlement,
         tick_size,
         lot_size,
+        min_notional,
     })
 }
 
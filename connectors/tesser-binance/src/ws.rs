@@ -27,7 +27,8 @@ use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use tesser_broker::{BrokerError, BrokerInfo, BrokerResult, MarketStream};
 use tesser_core::{
-    Candle, ExchangeId, Interval, LocalOrderBook, OrderBook, OrderBookLevel, Side, Symbol, Tick,
+    Candle, CandleStatus, ExchangeId, Interval, LocalOrderBook, OrderBook, OrderBookLevel, Side,
+    Symbol, Tick,
 };
 use tokio::sync::mpsc::error::TryRecvError;
 use tokio::sync::{mpsc, Mutex};
@@ -274,6 +275,12 @@ fn convert_kline(
     let low = parse_decimal_opt(kline.l.as_deref())?;
     let close = parse_decimal_opt(kline.c.as_deref())?;
     let volume = parse_decimal_opt(kline.v.as_deref()).unwrap_or(Decimal::ZERO);
+    // Binance pushes every update of the open kline; `x` flags the final one.
+    let status = if kline.x == Some(false) {
+        CandleStatus::Forming
+    } else {
+        CandleStatus::Closed
+    };
     Some(Candle {
         symbol: Symbol::from_code(exchange, symbol),
         interval,
@@ -283,6 +290,7 @@ fn convert_kline(
         close,
         volume,
         timestamp: timestamp_from_ms(kline.t),
+        status,
     })
 }
 
@@ -27,10 +27,12 @@ use tracing::{trace, warn};
 
 pub mod ws;
 
-pub use ws::{BybitMarketStream, BybitSubscription, PublicChannel};
+pub use ws::{BookGapPolicy, BybitMarketStream, BybitSubscription, PublicChannel};
 
 type HmacSha256 = Hmac<Sha256>;
 const EXECUTION_MAX_WINDOW_MS: i64 = 7 * 24 * 60 * 60 * 1000;
+/// Largest batch accepted by `/v5/order/cancel-batch` across all categories (spot caps at 10).
+const BATCH_CANCEL_LIMIT: usize = 10;
 
 /// API credentials required for private REST endpoints.
 #[derive(Clone)]
@@ -498,6 +500,43 @@ impl ExecutionClient for BybitClient {
         Ok(())
     }
 
+    async fn cancel_orders(
+        &self,
+        orders: Vec<(tesser_core::OrderId, Symbol)>,
+    ) -> BrokerResult<Vec<Result<(), BrokerError>>> {
+        let mut results = Vec::with_capacity(orders.len());
+        for chunk in orders.chunks(BATCH_CANCEL_LIMIT) {
+            let request: Vec<Value> = chunk
+                .iter()
+                .map(|(order_id, symbol)| {
+                    serde_json::json!({
+                        "symbol": Self::symbol_code(*symbol),
+                        "orderId": order_id,
+                    })
+                })
+                .collect();
+            let payload = serde_json::json!({
+                "category": self.config.category,
+                "request": request,
+            });
+            match self
+                .signed_request::<Value>(Method::POST, "/v5/order/cancel-batch", payload, None)
+                .await
+            {
+                Ok(resp) => results.extend(batch_outcomes(chunk.len(), resp.ret_ext_info)),
+                Err(err) => {
+                    let message = err.to_string();
+                    results.extend(
+                        chunk
+                            .iter()
+                            .map(|_| Err(BrokerError::Other(message.clone()))),
+                    );
+                }
+            }
+        }
+        Ok(results)
+    }
+
     async fn amend_order(&self, request: OrderUpdateRequest) -> BrokerResult<Order> {
         let mut payload = serde_json::json!({
             "category": self.config.category,
@@ -510,9 +549,15 @@ impl ExecutionClient for BybitClient {
         if let Some(quantity) = request.new_quantity {
             payload["qty"] = serde_json::json!(Self::qty_string(quantity));
         }
-        if payload.get("price").is_none() && payload.get("qty").is_none() {
+        if let Some(trigger) = request.new_trigger_price {
+            payload["triggerPrice"] = serde_json::json!(trigger);
+        }
+        if payload.get("price").is_none()
+            && payload.get("qty").is_none()
+            && payload.get("triggerPrice").is_none()
+        {
             return Err(BrokerError::InvalidRequest(
-                "amend requires price or quantity".into(),
+                "amend requires price, quantity or trigger price".into(),
             ));
         }
         let resp: ApiResponse<CreateOrderResult> = self
@@ -689,6 +734,11 @@ impl ExecutionClient for BybitClient {
                 .as_deref()
                 .and_then(|value| value.parse().ok())
                 .unwrap_or(Decimal::ZERO);
+            let min_notional = item
+                .lot_size_filter
+                .min_notional_value
+                .as_deref()
+                .and_then(|value| value.parse().ok());
             let settlement = item
                 .settle_coin
                 .clone()
@@ -701,6 +751,7 @@ impl ExecutionClient for BybitClient {
                 settlement_currency: self.parse_asset(&settlement),
                 tick_size,
                 lot_size,
+                min_notional,
             });
         }
         Ok(instruments)
@@ -747,6 +798,36 @@ struct ApiResponse<T> {
     #[serde(rename = "retMsg")]
     ret_msg: String,
     result: T,
+    #[serde(rename = "retExtInfo", default)]
+    ret_ext_info: Option<RetExtInfo>,
+}
+
+/// Per-item status codes returned alongside batch endpoints.
+#[derive(Default, Deserialize)]
+struct RetExtInfo {
+    #[serde(default)]
+    list: Vec<BatchItemStatus>,
+}
+
+#[derive(Deserialize)]
+struct BatchItemStatus {
+    code: i64,
+    #[serde(default)]
+    msg: String,
+}
+
+/// Map the per-item statuses of a batch call onto `len` outcomes; missing entries count as success.
+fn batch_outcomes(len: usize, ext: Option<RetExtInfo>) -> Vec<BrokerResult<()>> {
+    let statuses = ext.unwrap_or_default().list;
+    (0..len)
+        .map(|idx| match statuses.get(idx) {
+            Some(status) if status.code != 0 => Err(BrokerError::Exchange(format!(
+                "{} (code {})",
+                status.msg, status.code
+            ))),
+            _ => Ok(()),
+        })
+        .collect()
 }
 
 #[derive(Deserialize)]
@@ -793,6 +874,8 @@ struct InstrumentPriceFilter {
 struct InstrumentLotFilter {
     #[serde(rename = "qtyStep")]
     qty_step: Option<String>,
+    #[serde(rename = "minNotionalValue", default)]
+    min_notional_value: Option<String>,
 }
 
 #[derive(Deserialize)]
@@ -916,6 +999,9 @@ struct BybitConnectorConfig {
     private_rps: Option<u32>,
     #[serde(default)]
     public_rps: Option<u32>,
+    /// Order book sequence gaps up to this size wait for the missing deltas before resyncing.
+    #[serde(default)]
+    book_gap_tolerance: u64,
 }
 
 fn default_rest_url() -> String {
@@ -1056,6 +1142,9 @@ impl ConnectorFactory for BybitFactory {
             channel,
             stream_config.connection_status,
             exchange,
+            BookGapPolicy {
+                max_patchable_gap: cfg.book_gap_tolerance,
+            },
         )
         .await?;
         Ok(Box::new(BybitConnectorStream::new(
@@ -1146,4 +1235,33 @@ mod tests {
             "signature should be 256-bit hex encoded"
         );
     }
+
+    #[test]
+    fn batch_cancel_reports_per_item_failures() {
+        let body = r#"{
+            "retCode": 0,
+            "retMsg": "OK",
+            "result": {"list": [{"orderId": "a"}, {"orderId": "b"}, {"orderId": "c"}]},
+            "retExtInfo": {"list": [
+                {"code": 0, "msg": "OK"},
+                {"code": 110001, "msg": "order not exists or too late to cancel"},
+                {"code": 0, "msg": "OK"}
+            ]}
+        }"#;
+        let resp: ApiResponse<Value> = serde_json::from_str(body).unwrap();
+        let outcomes = batch_outcomes(3, resp.ret_ext_info);
+        assert!(outcomes[0].is_ok());
+        match &outcomes[1] {
+            Err(BrokerError::Exchange(msg)) => assert!(msg.contains("110001")),
+            other => panic!("unexpected outcome {other:?}"),
+        }
+        assert!(outcomes[2].is_ok());
+
+        let bare: ApiResponse<Value> =
+            serde_json::from_str(r#"{"retCode":0,"retMsg":"OK","result":{},"retExtInfo":null}"#)
+                .unwrap();
+        assert!(batch_outcomes(2, bare.ret_ext_info)
+            .iter()
+            .all(Result::is_ok));
+    }
 }
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::str::FromStr;
 use std::sync::{
     atomic::{AtomicBool, Ordering},
@@ -28,8 +28,8 @@ type HmacSha256 = Hmac<Sha256>;
 
 use tesser_broker::{BrokerError, BrokerErrorKind, BrokerInfo, BrokerResult, MarketStream};
 use tesser_core::{
-    AssetId, Candle, ExchangeId, Fill, Interval, LocalOrderBook, Order, OrderBook, OrderBookLevel,
-    OrderRequest, OrderType, Side, Symbol, Tick,
+    AssetId, Candle, CandleStatus, ExchangeId, Fill, Interval, LocalOrderBook, Order, OrderBook,
+    OrderBookLevel, OrderRequest, OrderType, Side, Symbol, Tick,
 };
 
 #[derive(Clone, Copy, Debug)]
@@ -97,6 +97,14 @@ enum WsCommand {
     Shutdown,
 }
 
+/// Controls how the order book manager reacts to sequence gaps in depth deltas.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BookGapPolicy {
+    /// Largest sequence gap that is held back waiting for the missing deltas to arrive
+    /// out of order. Anything wider, or a gap that is never filled, triggers a full resubscribe.
+    pub max_patchable_gap: u64,
+}
+
 pub struct BybitMarketStream {
     info: BrokerInfo,
     command_tx: mpsc::UnboundedSender<WsCommand>,
@@ -112,6 +120,7 @@ impl BybitMarketStream {
         channel: PublicChannel,
         connection_status: Option<Arc<AtomicBool>>,
         exchange: ExchangeId,
+        gap_policy: BookGapPolicy,
     ) -> BrokerResult<Self> {
         let endpoint = format!(
             "{}/v5/public/{}",
@@ -141,6 +150,7 @@ impl BybitMarketStream {
                 order_book_tx,
                 status_for_loop,
                 exchange_id,
+                gap_policy,
             )
             .await
             {
@@ -296,6 +306,7 @@ async fn run_ws_loop(
     order_book_tx: mpsc::Sender<OrderBook>,
     connection_status: Option<Arc<AtomicBool>>,
     exchange: ExchangeId,
+    gap_policy: BookGapPolicy,
 ) -> BrokerResult<()> {
     let mut heartbeat = interval(Duration::from_secs(20));
     heartbeat.set_missed_tick_behavior(MissedTickBehavior::Delay);
@@ -304,7 +315,8 @@ async fn run_ws_loop(
         flag.store(true, Ordering::SeqCst);
     }
 
-    let mut book_manager = BookManager::new(exchange, order_book_tx.clone(), command_tx);
+    let mut book_manager =
+        BookManager::new(exchange, order_book_tx.clone(), command_tx).with_gap_policy(gap_policy);
 
     loop {
         tokio::select! {
@@ -598,6 +610,7 @@ fn build_candle(exchange: ExchangeId, topic: &str, entry: &KlineEntry) -> Option
         close: entry.close.parse().ok()?,
         volume: entry.volume.parse().ok()?,
         timestamp: millis_to_datetime(entry.timestamp)?,
+        status: CandleStatus::Closed,
     })
 }
 
@@ -675,6 +688,7 @@ struct BookManager {
     order_book_tx: mpsc::Sender<OrderBook>,
     command_tx: mpsc::UnboundedSender<WsCommand>,
     exchange: ExchangeId,
+    gap_policy: BookGapPolicy,
 }
 
 impl BookManager {
@@ -688,9 +702,16 @@ impl BookManager {
             order_book_tx,
             command_tx,
             exchange,
+            gap_policy: BookGapPolicy::default(),
         }
     }
 
+    #[must_use]
+    fn with_gap_policy(mut self, gap_policy: BookGapPolicy) -> Self {
+        self.gap_policy = gap_policy;
+        self
+    }
+
     async fn handle(&mut self, payload: OrderbookMessage) {
         let Some((depth, _)) = parse_topic(&payload.topic) else {
             return;
@@ -699,11 +720,13 @@ impl BookManager {
             return;
         };
         let symbol = data.s.clone();
+        let gap_policy = self.gap_policy;
         let stream = self
             .streams
             .entry(payload.topic.clone())
             .or_insert_with(|| {
                 SymbolBook::new(self.exchange, payload.topic.clone(), symbol, depth)
+                    .with_gap_policy(gap_policy)
             });
 
         match stream.ingest(payload.msg_type.as_str(), data, payload.ts) {
@@ -767,6 +790,9 @@ struct SymbolBook {
     synced: bool,
     pending: Vec<PendingDelta>,
     last_checksum: Option<u32>,
+    gap_policy: BookGapPolicy,
+    /// Deltas that arrived ahead of a small gap, keyed by their previous sequence.
+    reorder: BTreeMap<i64, PendingDelta>,
 }
 
 impl SymbolBook {
@@ -780,9 +806,17 @@ impl SymbolBook {
             synced: false,
             pending: Vec::new(),
             last_checksum: None,
+            gap_policy: BookGapPolicy::default(),
+            reorder: BTreeMap::new(),
         }
     }
 
+    #[must_use]
+    fn with_gap_policy(mut self, gap_policy: BookGapPolicy) -> Self {
+        self.gap_policy = gap_policy;
+        self
+    }
+
     fn ingest(&mut self, msg_type: &str, data: OrderbookData, ts: i64) -> BookUpdate {
         match msg_type {
             "snapshot" => self.apply_snapshot(data, ts),
@@ -834,21 +868,39 @@ impl SymbolBook {
     }
 
     fn apply_pending(&mut self, delta: PendingDelta) -> ApplyOutcome {
-        if let Some(last) = self.last_seq {
-            if let Some(prev) = delta.prev_seq {
-                if prev != last {
-                    self.reset();
-                    return ApplyOutcome::Gap;
-                }
-            } else if delta.seq - 1 != last {
-                self.reset();
-                return ApplyOutcome::Gap;
-            }
-        } else {
+        let Some(last) = self.last_seq else {
             self.pending.push(delta);
             return ApplyOutcome::Pending;
+        };
+        let prev = delta.prev_seq.unwrap_or(delta.seq - 1);
+        if prev != last {
+            let gap = prev - last;
+            let max_gap = self.gap_policy.max_patchable_gap;
+            if gap > 0 && gap as u64 <= max_gap && (self.reorder.len() as u64) < max_gap {
+                self.reorder.insert(prev, delta);
+                return ApplyOutcome::Pending;
+            }
+            self.reset();
+            return ApplyOutcome::Gap;
         }
 
+        let mut updates = Vec::new();
+        let mut next = Some(delta);
+        while let Some(delta) = next {
+            self.apply_levels(&delta);
+            self.last_seq = Some(delta.seq);
+            if let Some(book) = self.snapshot(delta.ts) {
+                updates.push(book);
+            }
+            next = self.reorder.remove(&delta.seq);
+        }
+        if let Some(last) = self.last_seq {
+            self.reorder.retain(|prev, _| *prev > last);
+        }
+        ApplyOutcome::Updates(updates)
+    }
+
+    fn apply_levels(&mut self, delta: &PendingDelta) {
         for level in &delta.bids {
             self.book
                 .apply_delta(Side::Buy, level.price, level.quantity);
@@ -857,13 +909,6 @@ impl SymbolBook {
             self.book
                 .apply_delta(Side::Sell, level.price, level.quantity);
         }
-        self.last_seq = Some(delta.seq);
-
-        if let Some(book) = self.snapshot(delta.ts) {
-            ApplyOutcome::Updates(vec![book])
-        } else {
-            ApplyOutcome::Updates(Vec::new())
-        }
     }
 
     fn snapshot(&self, ts: i64) -> Option<OrderBook> {
@@ -897,6 +942,7 @@ impl SymbolBook {
         self.synced = false;
         self.last_seq = None;
         self.pending.clear();
+        self.reorder.clear();
     }
 }
 
@@ -946,6 +992,10 @@ pub struct BybitWsExecution {
     pub cum_exec_qty: String,
     #[serde(rename = "avgPrice")]
     pub avg_price: String,
+    #[serde(rename = "execType", default)]
+    pub exec_type: Option<String>,
+    #[serde(rename = "feeRate", default)]
+    pub fee_rate: Option<String>,
 }
 
 impl BybitWsOrder {
@@ -985,6 +1035,31 @@ impl BybitWsOrder {
 }
 
 impl BybitWsExecution {
+    /// Whether this execution settles a funding payment instead of a trade.
+    pub fn is_funding(&self) -> bool {
+        self.exec_type.as_deref() == Some("Funding")
+    }
+
+    /// Venue time of the execution.
+    pub fn timestamp(&self) -> DateTime<Utc> {
+        parse_millis(&self.exec_time)
+    }
+
+    /// Cash received from a funding settlement; negative when funding was paid.
+    ///
+    /// Bybit reports funding as a fee charged to the account, so the sign is flipped.
+    pub fn funding_amount(&self) -> Result<Decimal, BrokerError> {
+        self.exec_fee
+            .parse::<Decimal>()
+            .map(|fee| -fee)
+            .map_err(|e| {
+                BrokerError::Serialization(format!(
+                    "failed to parse funding fee {}: {e}",
+                    self.exec_fee
+                ))
+            })
+    }
+
     pub fn to_tesser_fill(&self, exchange: ExchangeId) -> Result<Fill, BrokerError> {
         let fill_price = self.exec_price.parse::<Decimal>().map_err(|e| {
             BrokerError::Serialization(format!(
@@ -996,7 +1071,7 @@ impl BybitWsExecution {
             BrokerError::Serialization(format!("failed to parse exec qty {}: {e}", self.exec_qty))
         })?;
         let fee = self.exec_fee.parse::<Decimal>().ok();
-        let timestamp = parse_millis(&self.exec_time);
+        let timestamp = self.timestamp();
         let side = match self.side.as_str() {
             "Buy" => Side::Buy,
             "Sell" => Side::Sell,
@@ -1140,4 +1215,105 @@ mod tests {
             _ => panic!("unexpected command {:?}", resub),
         }
     }
+
+    fn book_message(
+        topic: &str,
+        msg_type: &str,
+        seq: i64,
+        bids: &[(&str, &str)],
+    ) -> OrderbookMessage {
+        OrderbookMessage {
+            topic: topic.into(),
+            msg_type: msg_type.into(),
+            ts: seq,
+            data: vec![sample_data(
+                "BTCUSDT",
+                bids,
+                &[("101", "1")],
+                seq,
+                Some(seq - 1),
+            )],
+        }
+    }
+
+    #[tokio::test]
+    async fn book_manager_patches_small_gap_from_late_deltas() {
+        let (book_tx, mut book_rx) = mpsc::channel(8);
+        let (cmd_tx, mut cmd_rx) = mpsc::unbounded_channel();
+        let exchange = ExchangeId::from("bybit_linear");
+        let mut manager =
+            BookManager::new(exchange, book_tx, cmd_tx).with_gap_policy(BookGapPolicy {
+                max_patchable_gap: 2,
+            });
+        let topic = "orderbook.1.BTCUSDT";
+
+        manager
+            .handle(book_message(topic, "snapshot", 5, &[("100", "1")]))
+            .await;
+        book_rx.recv().await.expect("snapshot missing");
+
+        // Sequence 7 arrives before 6: held back instead of resubscribing.
+        manager
+            .handle(book_message(topic, "delta", 7, &[("100", "3")]))
+            .await;
+        assert!(book_rx.try_recv().is_err());
+        assert!(cmd_rx.try_recv().is_err());
+
+        manager
+            .handle(book_message(topic, "delta", 6, &[("100", "2")]))
+            .await;
+        let patched = book_rx.recv().await.expect("patched delta missing");
+        assert_eq!(patched.bids[0].size, Decimal::from(2));
+        let replayed = book_rx.recv().await.expect("buffered delta missing");
+        assert_eq!(replayed.bids[0].size, Decimal::from(3));
+        assert!(cmd_rx.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn book_manager_resyncs_on_gap_beyond_tolerance() {
+        let (book_tx, mut book_rx) = mpsc::channel(8);
+        let (cmd_tx, mut cmd_rx) = mpsc::unbounded_channel();
+        let exchange = ExchangeId::from("bybit_linear");
+        let mut manager =
+            BookManager::new(exchange, book_tx, cmd_tx).with_gap_policy(BookGapPolicy {
+                max_patchable_gap: 2,
+            });
+        let topic = "orderbook.1.BTCUSDT";
+
+        manager
+            .handle(book_message(topic, "snapshot", 5, &[("100", "1")]))
+            .await;
+        book_rx.recv().await.expect("snapshot missing");
+
+        manager
+            .handle(book_message(topic, "delta", 10, &[("100", "3")]))
+            .await;
+        match cmd_rx.recv().await.expect("resubscribe missing") {
+            WsCommand::Subscribe(resub) => assert_eq!(resub, topic),
+            other => panic!("unexpected command {:?}", other),
+        }
+        assert!(book_rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn funding_executions_report_received_cash() {
+        let exec: BybitWsExecution = serde_json::from_value(serde_json::json!({
+            "execId": "f-1",
+            "orderId": "",
+            "symbol": "BTCUSDT",
+            "execPrice": "30000",
+            "execQty": "0.5",
+            "side": "Sell",
+            "execFee": "-1.5",
+            "feeCurrency": "USDT",
+            "execTime": "1700000000000",
+            "cumExecQty": "0",
+            "avgPrice": "0",
+            "execType": "Funding",
+            "feeRate": "0.0001"
+        }))
+        .unwrap();
+        assert!(exec.is_funding());
+        assert_eq!(exec.funding_amount().unwrap(), Decimal::new(15, 1));
+    }
 }
@@ -1,7 +1,7 @@
 use std::collections::{HashMap, HashSet};
 
 use chrono::{DateTime, Utc};
-use tesser_core::{Candle, Order, Price, Side};
+use tesser_core::{Candle, Order, Price, Quantity, Side};
 
 /// Internal classification for conditional orders (used for OCO resolution).
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
@@ -53,6 +53,42 @@ impl ConditionalOrderManager {
         self.orders.push(PendingConditional { order, kind, group });
     }
 
+    /// Look up a queued order by id.
+    pub fn get(&self, order_id: &str) -> Option<&Order> {
+        self.orders
+            .iter()
+            .map(|pending| &pending.order)
+            .find(|order| order.id == order_id)
+    }
+
+    /// Update a queued order in place, returning the amended order if it is still pending.
+    pub fn amend(
+        &mut self,
+        order_id: &str,
+        trigger_price: Option<Price>,
+        quantity: Option<Quantity>,
+    ) -> Option<Order> {
+        let pending = self
+            .orders
+            .iter_mut()
+            .find(|pending| pending.order.id == order_id)?;
+        if let Some(trigger) = trigger_price {
+            pending.order.request.trigger_price = Some(trigger);
+        }
+        if let Some(quantity) = quantity {
+            pending.order.request.quantity = quantity;
+        }
+        pending.order.updated_at = Utc::now();
+        Some(pending.order.clone())
+    }
+
+    /// Drop a queued order, returning whether it was still pending.
+    pub fn cancel(&mut self, order_id: &str) -> bool {
+        let before = self.orders.len();
+        self.orders.retain(|pending| pending.order.id != order_id);
+        self.orders.len() != before
+    }
+
     /// Trigger any orders touched by the provided candle range.
     pub fn trigger_with_candle(&mut self, candle: &Candle) -> Vec<TriggeredOrder> {
         self.evaluate(|pending| {
@@ -72,12 +108,20 @@ impl ConditionalOrderManager {
         timestamp: DateTime<Utc>,
     ) -> Vec<TriggeredOrder> {
         self.evaluate(|pending| {
-            let trigger = pending.order.request.trigger_price?;
-            let touched = match pending.order.request.side {
-                Side::Buy => last_price >= trigger,
-                Side::Sell => last_price <= trigger,
-            };
-            touched.then_some((last_price, timestamp))
+            price_touches(pending, last_price).then_some((last_price, timestamp))
+        })
+    }
+
+    /// Trigger a single order if the provided trade price already crosses its threshold.
+    pub fn trigger_order_with_price(
+        &mut self,
+        order_id: &str,
+        last_price: Price,
+        timestamp: DateTime<Utc>,
+    ) -> Vec<TriggeredOrder> {
+        self.evaluate(|pending| {
+            (pending.order.id == order_id && price_touches(pending, last_price))
+                .then_some((last_price, timestamp))
         })
     }
 
@@ -135,6 +179,16 @@ impl ConditionalOrderManager {
     }
 }
 
+fn price_touches(pending: &PendingConditional, last_price: Price) -> bool {
+    let Some(trigger) = pending.order.request.trigger_price else {
+        return false;
+    };
+    match pending.order.request.side {
+        Side::Buy => last_price >= trigger,
+        Side::Sell => last_price <= trigger,
+    }
+}
+
 fn parse_group(order: &Order) -> (Option<String>, TriggerKind) {
     if let Some(cid) = order.request.client_order_id.as_ref() {
         if let Some(base) = cid.strip_suffix("-sl") {
@@ -151,7 +205,7 @@ fn parse_group(order: &Order) -> (Option<String>, TriggerKind) {
 mod tests {
     use super::*;
     use rust_decimal::Decimal;
-    use tesser_core::{OrderRequest, OrderStatus, Quantity, TimeInForce};
+    use tesser_core::{CandleStatus, OrderRequest, OrderStatus, Quantity, TimeInForce};
     use uuid::Uuid;
 
     fn pending(side: Side, trigger: Price, cid: &str) -> Order {
@@ -192,9 +246,43 @@ mod tests {
             close: Decimal::from(95),
             volume: Decimal::from(1),
             timestamp: Utc::now(),
+            status: CandleStatus::Closed,
         };
         let triggered = book.trigger_with_candle(&candle);
         assert_eq!(triggered.len(), 1);
         assert_eq!(triggered[0].kind, TriggerKind::StopLoss);
     }
+
+    #[test]
+    fn amended_trigger_fires_at_the_new_level() {
+        let mut book = ConditionalOrderManager::new();
+        let order = pending(Side::Sell, Decimal::from(90), "trail");
+        book.push(order.clone());
+        let amended = book
+            .amend(&order.id, Some(Decimal::from(95)), None)
+            .expect("order still pending");
+        assert_eq!(amended.request.trigger_price, Some(Decimal::from(95)));
+        assert!(book
+            .trigger_with_price(Decimal::from(96), Utc::now())
+            .is_empty());
+        assert_eq!(
+            book.trigger_with_price(Decimal::from(94), Utc::now()).len(),
+            1
+        );
+        assert!(book
+            .amend(&order.id, Some(Decimal::from(99)), None)
+            .is_none());
+    }
+
+    #[test]
+    fn canceled_orders_never_trigger() {
+        let mut book = ConditionalOrderManager::new();
+        let order = pending(Side::Sell, Decimal::from(90), "trail");
+        book.push(order.clone());
+        assert!(book.cancel(&order.id));
+        assert!(!book.cancel(&order.id));
+        assert!(book
+            .trigger_with_price(Decimal::from(80), Utc::now())
+            .is_empty());
+    }
 }
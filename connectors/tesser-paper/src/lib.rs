@@ -5,7 +5,7 @@ mod fees;
 
 use std::{
     any::Any,
-    collections::{HashMap, VecDeque},
+    collections::{BTreeMap, HashMap, VecDeque},
     path::PathBuf,
     sync::{
         atomic::{AtomicBool, Ordering},
@@ -15,7 +15,7 @@ use std::{
 
 use async_trait::async_trait;
 use chrono::{DateTime, Duration as ChronoDuration, NaiveDateTime, TimeZone, Utc};
-use conditional::ConditionalOrderManager;
+use conditional::{ConditionalOrderManager, TriggeredOrder};
 use csv::StringRecord;
 use rand::{rngs::StdRng, Rng, SeedableRng};
 use rand_distr::StandardNormal;
@@ -30,9 +30,9 @@ use tesser_broker::{
     ConnectorStream, ConnectorStreamConfig, ExecutionClient, MarketStream,
 };
 use tesser_core::{
-    AccountBalance, AssetId, Candle, DepthUpdate, Fill, Instrument, Interval, LocalOrderBook,
-    Order, OrderBook, OrderId, OrderRequest, OrderStatus, OrderType, OrderUpdateRequest, Position,
-    Price, Quantity, Side, Symbol, Tick, TimeInForce,
+    AccountBalance, AssetId, Candle, CandleStatus, DepthUpdate, Fill, Instrument, Interval,
+    LocalOrderBook, Order, OrderBook, OrderId, OrderRequest, OrderStatus, OrderType,
+    OrderUpdateRequest, Position, Price, Quantity, Side, Symbol, Tick, TimeInForce,
 };
 use tokio::task::JoinHandle;
 use tokio::{
@@ -92,6 +92,12 @@ pub struct PaperConnectorConfig {
     pub balance_currency: String,
     #[serde(default = "default_initial_balance")]
     pub initial_balance: Decimal,
+    /// Additional starting balances keyed by asset (`BTC` or `exchange:BTC`).
+    ///
+    /// Entries override `initial_balance` for the same asset; other assets are added alongside
+    /// it. The cash asset used for fill accounting is unchanged.
+    #[serde(default)]
+    pub balances: BTreeMap<String, Decimal>,
     #[serde(default)]
     pub slippage_bps: Decimal,
     #[serde(default)]
@@ -109,6 +115,7 @@ impl Default for PaperConnectorConfig {
             symbols: Vec::new(),
             balance_currency: default_balance_currency(),
             initial_balance: default_initial_balance(),
+            balances: BTreeMap::new(),
             slippage_bps: Decimal::ZERO,
             fee_bps: Decimal::ZERO,
             fee_schedule: None,
@@ -130,7 +137,12 @@ impl PaperConnectorConfig {
     }
 
     fn balance_asset(&self) -> AssetId {
-        if let Ok(parsed) = self.balance_currency.parse::<AssetId>() {
+        self.resolve_asset(&self.balance_currency)
+    }
+
+    /// Resolve `code`, qualifying bare asset codes with the primary symbol's exchange.
+    fn resolve_asset(&self, code: &str) -> AssetId {
+        if let Ok(parsed) = code.parse::<AssetId>() {
             parsed
         } else {
             let primary = self
@@ -138,7 +150,7 @@ impl PaperConnectorConfig {
                 .first()
                 .copied()
                 .unwrap_or_else(default_symbol);
-            AssetId::from_code(primary.exchange, &self.balance_currency)
+            AssetId::from_code(primary.exchange, code)
         }
     }
 
@@ -193,6 +205,10 @@ impl PaperRuntimeState {
         self.client
             .initialize_balance(asset, self.config.initial_balance)
             .await;
+        for (code, amount) in &self.config.balances {
+            let asset = self.config.resolve_asset(code);
+            self.client.set_balance(asset, *amount).await;
+        }
         for symbol in &self.symbols {
             self.client
                 .update_price(symbol, self.config.market.initial_price());
@@ -304,6 +320,29 @@ fn default_replay_speed() -> f64 {
     1.0
 }
 
+/// Amend a queued stop order, failing when it is no longer pending.
+async fn amend_conditional(
+    book: &AsyncMutex<ConditionalOrderManager>,
+    request: &OrderUpdateRequest,
+) -> BrokerResult<Order> {
+    let mut book = book.lock().await;
+    let not_found = || BrokerError::InvalidRequest(format!("order {} not found", request.order_id));
+    let queued = book.get(&request.order_id).ok_or_else(not_found)?;
+    if queued.request.symbol != request.symbol || queued.request.side != request.side {
+        return Err(BrokerError::InvalidRequest(
+            "symbol or side mismatch for amend".into(),
+        ));
+    }
+    let order = book
+        .amend(
+            &request.order_id,
+            request.new_trigger_price,
+            request.new_quantity,
+        )
+        .ok_or_else(not_found)?;
+    Ok(order)
+}
+
 /// In-memory execution client that fills orders immediately at the provided limit (or last) price.
 #[derive(Clone)]
 pub struct PaperExecutionClient {
@@ -312,6 +351,9 @@ pub struct PaperExecutionClient {
     balances: Arc<AsyncMutex<Vec<AccountBalance>>>,
     positions: Arc<AsyncMutex<HashMap<Symbol, Position>>>,
     conditional_orders: Arc<AsyncMutex<ConditionalOrderManager>>,
+    /// Fills produced by immediate executions that have not been drained yet, when capture is
+    /// enabled
+    fills: Arc<Mutex<Option<Vec<Fill>>>>,
     /// Latest market prices for each symbol
     last_prices: Arc<Mutex<HashMap<Symbol, Price>>>,
     /// Simulation parameters
@@ -377,6 +419,7 @@ impl PaperExecutionClient {
             balances: Arc::new(AsyncMutex::new(vec![initial_balance])),
             positions: Arc::new(AsyncMutex::new(HashMap::new())),
             conditional_orders: Arc::new(AsyncMutex::new(ConditionalOrderManager::new())),
+            fills: Arc::new(Mutex::new(None)),
             last_prices: Arc::new(Mutex::new(HashMap::new())),
             slippage_bps,
             fee_model,
@@ -393,6 +436,11 @@ impl PaperExecutionClient {
     /// Reset the available balance to a configured amount.
     pub async fn initialize_balance(&self, asset: AssetId, amount: Decimal) {
         *self.cash_asset.lock().unwrap() = asset;
+        self.set_balance(asset, amount).await;
+    }
+
+    /// Set the total and available balance of `asset` without changing the cash asset.
+    pub async fn set_balance(&self, asset: AssetId, amount: Decimal) {
         let mut balances = self.balances.lock().await;
         if let Some(entry) = balances.iter_mut().find(|b| b.asset == asset) {
             entry.total = amount;
@@ -649,6 +697,61 @@ impl PaperExecutionClient {
         position.updated_at = fill.timestamp;
     }
 
+    /// Keep fills from immediate executions so callers can [`drain_fills`](Self::drain_fills) them.
+    #[must_use]
+    pub fn with_fill_capture(self) -> Self {
+        self.enable_fill_capture();
+        self
+    }
+
+    /// Start capturing fills on this client and every clone sharing its state.
+    ///
+    /// Only enable capture where something drains the fills; otherwise they accumulate.
+    pub fn enable_fill_capture(&self) {
+        self.fills.lock().unwrap().get_or_insert_with(Vec::new);
+    }
+
+    /// Drain fills captured since the previous call (empty unless fill capture is enabled).
+    pub async fn drain_fills(&self) -> Vec<Fill> {
+        self.fills
+            .lock()
+            .unwrap()
+            .as_mut()
+            .map(std::mem::take)
+            .unwrap_or_default()
+    }
+
+    /// Fill an amended conditional order right away when the last price already crosses it.
+    async fn trigger_amended_conditional(&self, order: Order) -> Order {
+        let last_price = self
+            .last_prices
+            .lock()
+            .unwrap()
+            .get(&order.request.symbol)
+            .copied();
+        let Some(last_price) = last_price else {
+            return order;
+        };
+        let triggered = self
+            .conditional_orders
+            .lock()
+            .await
+            .trigger_order_with_price(&order.id, last_price, Utc::now());
+        let Some(event) = triggered.into_iter().next() else {
+            return order;
+        };
+        let mut filled = event.order;
+        filled.status = OrderStatus::Filled;
+        filled.filled_quantity = filled.request.quantity;
+        filled.avg_fill_price = Some(event.fill_price);
+        filled.updated_at = event.timestamp;
+        if let Some(store) = self.fills.lock().unwrap().as_mut() {
+            store.push(self.create_fill_from_order(&filled, event.fill_price, event.timestamp));
+        }
+        self.orders.lock().await.push(filled.clone());
+        filled
+    }
+
     /// Inspect conditional orders and emit fills for any whose trigger price was reached.
     pub async fn check_triggers(&self, candle: &Candle) -> BrokerResult<Vec<Fill>> {
         let triggered = {
@@ -693,7 +796,18 @@ impl ExecutionClient for MatchingEngine {
                 let limit_price = request.price.ok_or_else(|| {
                     BrokerError::InvalidRequest("limit order requires price".into())
                 })?;
-                if self.limit_crosses(request.side, limit_price) {
+                if self.depth_fills && self.limit_crosses(request.side, limit_price) {
+                    // Take only the depth the limit reaches; the remainder rests.
+                    let slices =
+                        self.take_through_limit(request.side, limit_price, request.quantity);
+                    self.handle_immediate_fills(&mut order, &slices).await?;
+                    if order.status != OrderStatus::Filled {
+                        let activation_time = self.activation_deadline(self.simulated_now());
+                        self.record_resting_order(order.clone(), limit_price, activation_time)
+                            .await;
+                    }
+                    Ok(order)
+                } else if self.limit_crosses(request.side, limit_price) {
                     let (slices, _) = self.match_market(&request).await?;
                     self.handle_immediate_fills(&mut order, &slices).await?;
                     Ok(order)
@@ -740,6 +854,10 @@ impl ExecutionClient for MatchingEngine {
             resting.cancel_after = Some(self.activation_deadline(now));
             return Ok(());
         }
+        drop(open);
+        if self.conditional_orders.lock().await.cancel(&order_id) {
+            return Ok(());
+        }
         Err(BrokerError::InvalidRequest(format!(
             "order {} not found",
             order_id
@@ -748,6 +866,20 @@ impl ExecutionClient for MatchingEngine {
 
     async fn amend_order(&self, request: OrderUpdateRequest) -> BrokerResult<Order> {
         let mut open = self.open_orders.lock().await;
+        if !open.contains_key(&request.order_id) {
+            drop(open);
+            let order = amend_conditional(&self.conditional_orders, &request).await?;
+            // A stop moved through the market triggers right away instead of on the next trade.
+            if let Some(price) = self.mid_price() {
+                let triggered = self
+                    .conditional_orders
+                    .lock()
+                    .await
+                    .trigger_order_with_price(&order.id, price, self.simulated_now());
+                self.fill_triggered(triggered).await;
+            }
+            return Ok(order);
+        }
         let resting = open.get_mut(&request.order_id).ok_or_else(|| {
             BrokerError::InvalidRequest(format!("order {} not found", request.order_id))
         })?;
@@ -761,6 +893,14 @@ impl ExecutionClient for MatchingEngine {
                 "side mismatch for amend".into(),
             ));
         }
+        if request
+            .new_quantity
+            .is_some_and(|quantity| quantity < resting.order.filled_quantity)
+        {
+            return Err(BrokerError::InvalidRequest(
+                "new quantity below filled amount".into(),
+            ));
+        }
         if resting.active && resting.remaining > Decimal::ZERO {
             let mut book = self.resting_depth.lock().unwrap();
             book.remove_order(resting.order.request.side, resting.price, resting.remaining);
@@ -770,11 +910,6 @@ impl ExecutionClient for MatchingEngine {
             resting.order.request.price = Some(price);
         }
         if let Some(quantity) = request.new_quantity {
-            if quantity < resting.order.filled_quantity {
-                return Err(BrokerError::InvalidRequest(
-                    "new quantity below filled amount".into(),
-                ));
-            }
             resting.order.request.quantity = quantity;
             resting.remaining = (quantity - resting.order.filled_quantity).max(Decimal::ZERO);
             resting.original_quantity = quantity;
@@ -790,6 +925,12 @@ impl ExecutionClient for MatchingEngine {
             resting.order.status = OrderStatus::Accepted;
         }
         resting.order.updated_at = Utc::now();
+        if resting.active
+            && resting.remaining > Decimal::ZERO
+            && self.limit_crosses(resting.order.request.side, resting.price)
+        {
+            self.fill_marketable_amend(resting).await;
+        }
         if resting.active && resting.remaining > Decimal::ZERO {
             resting.initial_queue_position =
                 self.queue_ahead_for(resting.order.request.side, resting.price, Decimal::ZERO);
@@ -797,7 +938,13 @@ impl ExecutionClient for MatchingEngine {
             let mut book = self.resting_depth.lock().unwrap();
             book.add_order(resting.order.request.side, resting.price, resting.remaining);
         }
-        Ok(resting.order.clone())
+        let order = resting.order.clone();
+        if order.status == OrderStatus::Filled {
+            open.remove(&order.id);
+            drop(open);
+            self.spawn_attached_orders(&order).await;
+        }
+        Ok(order)
     }
 
     async fn list_open_orders(&self, _symbol: Symbol) -> BrokerResult<Vec<Order>> {
@@ -823,6 +970,15 @@ impl ExecutionClient for MatchingEngine {
     }
 }
 
+/// Status of a working order that rests on the book.
+fn resting_status(order: &Order) -> OrderStatus {
+    if order.filled_quantity > Decimal::ZERO {
+        OrderStatus::PartiallyFilled
+    } else {
+        OrderStatus::Accepted
+    }
+}
+
 /// Order-level metadata stored for maker orders managed by the matching engine.
 #[derive(Clone)]
 struct RestingOrder {
@@ -852,6 +1008,9 @@ pub struct MatchingEngineConfig {
     pub queue_model: QueueModel,
     pub fee_model: Arc<dyn FeeModel>,
     pub cash_asset: Option<AssetId>,
+    /// Fill resting limits against replayed depth that trades through their price, instead of
+    /// only against trade prints; see [`MatchingEngine::fill_resting_against_depth`].
+    pub depth_fills: bool,
 }
 
 impl Default for MatchingEngineConfig {
@@ -861,6 +1020,7 @@ impl Default for MatchingEngineConfig {
             queue_model: QueueModel::default(),
             fee_model: FeeScheduleConfig::default().build_model(),
             cash_asset: None,
+            depth_fills: false,
         }
     }
 }
@@ -870,6 +1030,8 @@ impl Default for MatchingEngineConfig {
 /// Backtester integration guide:
 /// - hydrate depth via [`load_market_snapshot`] and [`upsert_market_level`].
 /// - feed tick prints into [`process_trade`] so maker orders can fill.
+/// - with [`MatchingEngineConfig::depth_fills`], call [`fill_resting_against_depth`] after each
+///   depth change so limits also fill against liquidity shown through their price.
 /// - drain generated fills through [`drain_fills`] and forward them to strategies.
 ///
 ///  CLI/backtester wiring for this engine will land in a follow-up change.
@@ -889,6 +1051,7 @@ pub struct MatchingEngine {
     queue_reset: Arc<AtomicBool>,
     fee_model: Arc<dyn FeeModel>,
     cash_asset: Arc<Mutex<AssetId>>,
+    depth_fills: bool,
 }
 
 impl MatchingEngine {
@@ -940,6 +1103,7 @@ impl MatchingEngine {
             queue_reset: Arc::new(AtomicBool::new(false)),
             fee_model: config.fee_model.clone(),
             cash_asset: Arc::new(Mutex::new(cash_asset)),
+            depth_fills: config.depth_fills,
         }
     }
 
@@ -1049,7 +1213,7 @@ impl MatchingEngine {
                     continue;
                 }
                 resting.active = true;
-                resting.order.status = OrderStatus::Accepted;
+                resting.order.status = resting_status(&resting.order);
                 resting.order.updated_at = now;
                 resting.initial_queue_position =
                     self.queue_ahead_for(resting.order.request.side, resting.price, Decimal::ZERO);
@@ -1355,11 +1519,65 @@ impl MatchingEngine {
         generated
     }
 
+    /// Fill resting limit orders that the current market depth trades through.
+    ///
+    /// Does nothing unless [`MatchingEngineConfig::depth_fills`] is set. Each order fills at its
+    /// limit for the size shown at or through that price, possibly partially; the consumed
+    /// size is removed from the book until the next snapshot or depth update restores it.
+    pub async fn fill_resting_against_depth(&self) -> Vec<Fill> {
+        if !self.depth_fills {
+            return Vec::new();
+        }
+        let timestamp = self.simulated_now();
+        let mut generated = Vec::new();
+        let mut finished = Vec::new();
+        let mut open = self.open_orders.lock().await;
+        for (order_id, resting) in open.iter_mut() {
+            if !resting.active || resting.remaining <= Decimal::ZERO {
+                continue;
+            }
+            let price = resting.price;
+            let slices =
+                self.take_through_limit(resting.order.request.side, price, resting.remaining);
+            let quantity: Quantity = slices.iter().map(|(_, qty)| *qty).sum();
+            self.apply_resting_fill(
+                order_id,
+                resting,
+                price,
+                quantity,
+                timestamp,
+                &mut generated,
+            )
+            .await;
+            if resting.remaining <= Decimal::ZERO {
+                finished.push(order_id.clone());
+            }
+        }
+        let mut completed = Vec::new();
+        for order_id in finished {
+            if let Some(resting) = open.remove(&order_id) {
+                completed.push(resting.order);
+            }
+        }
+        drop(open);
+        for order in &completed {
+            self.spawn_attached_orders(order).await;
+        }
+
+        let mut store = self.fills.lock().await;
+        store.extend(generated.clone());
+        generated
+    }
+
     async fn trigger_conditionals(&self, price: Price, timestamp: DateTime<Utc>) {
         let triggered = {
             let mut book = self.conditional_orders.lock().await;
             book.trigger_with_price(price, timestamp)
         };
+        self.fill_triggered(triggered).await;
+    }
+
+    async fn fill_triggered(&self, triggered: Vec<TriggeredOrder>) {
         if triggered.is_empty() {
             return;
         }
@@ -1447,7 +1665,7 @@ impl MatchingEngine {
             book.add_order(entry.order.request.side, entry.price, entry.remaining);
         }
         if entry.active {
-            entry.order.status = OrderStatus::Accepted;
+            entry.order.status = resting_status(&entry.order);
             entry.order.updated_at = activation_time;
         }
         let mut open = self.open_orders.lock().await;
@@ -1584,6 +1802,77 @@ impl MatchingEngine {
         drained
     }
 
+    /// Take liquidity for an amended order whose new limit crosses the market.
+    async fn fill_marketable_amend(&self, resting: &mut RestingOrder) {
+        let side = resting.order.request.side;
+        let slices = self.take_through_limit(side, resting.price, resting.remaining);
+        if slices.is_empty() {
+            return;
+        }
+        let timestamp = self.simulated_now();
+        let mut realized = Vec::new();
+        for (price, qty) in slices {
+            let fee_amount = self.compute_fee(
+                resting.order.request.symbol,
+                side,
+                LiquidityRole::Taker,
+                price,
+                qty,
+            );
+            let fill = self.build_fill(
+                &resting.order.id,
+                resting.order.request.symbol,
+                side,
+                price,
+                qty,
+                fee_amount,
+                timestamp,
+            );
+            let prev_notional = resting.order.avg_fill_price.unwrap_or(Decimal::ZERO)
+                * resting.order.filled_quantity;
+            resting.order.filled_quantity += qty;
+            resting.order.avg_fill_price =
+                Some((prev_notional + price * qty) / resting.order.filled_quantity);
+            resting.remaining = (resting.remaining - qty).max(Decimal::ZERO);
+            self.apply_fill_accounting(&fill).await;
+            realized.push(fill);
+        }
+        resting.order.status = if resting.remaining <= Decimal::ZERO {
+            OrderStatus::Filled
+        } else {
+            OrderStatus::PartiallyFilled
+        };
+        resting.order.updated_at = timestamp;
+        let mut store = self.fills.lock().await;
+        store.extend(realized);
+    }
+
+    /// Consume market depth at or through `limit`, up to `quantity`, returning the levels taken.
+    fn take_through_limit(
+        &self,
+        side: Side,
+        limit: Price,
+        quantity: Quantity,
+    ) -> Vec<(Price, Quantity)> {
+        let mut depth = self.market_depth.lock().unwrap();
+        let mut slices = Vec::new();
+        let mut remaining = quantity;
+        while remaining > Decimal::ZERO {
+            let level = match side {
+                Side::Buy => depth.best_ask().filter(|(ask, _)| *ask <= limit),
+                Side::Sell => depth.best_bid().filter(|(bid, _)| *bid >= limit),
+            };
+            let Some((price, available)) = level else {
+                break;
+            };
+            let traded = remaining.min(available);
+            depth.remove_order(side.inverse(), price, traded);
+            slices.push((price, traded));
+            remaining -= traded;
+        }
+        slices
+    }
+
     fn limit_crosses(&self, side: Side, price: Price) -> bool {
         let depth = self.market_depth.lock().unwrap();
         match side {
@@ -1604,6 +1893,11 @@ impl ExecutionClient for PaperExecutionClient {
             tesser_core::OrderType::Market | tesser_core::OrderType::Limit => {
                 let order = self.fill_order(&request);
                 self.orders.lock().await.push(order.clone());
+                if let Some(price) = order.avg_fill_price {
+                    if let Some(store) = self.fills.lock().unwrap().as_mut() {
+                        store.push(self.create_fill_from_order(&order, price, order.updated_at));
+                    }
+                }
 
                 info!(
                     symbol = %order.request.symbol,
@@ -1636,36 +1930,27 @@ impl ExecutionClient for PaperExecutionClient {
         }
     }
 
-    async fn cancel_order(&self, _order_id: OrderId, _symbol: Symbol) -> BrokerResult<()> {
+    async fn cancel_order(&self, order_id: OrderId, _symbol: Symbol) -> BrokerResult<()> {
+        self.conditional_orders.lock().await.cancel(&order_id);
         Ok(())
     }
 
     async fn amend_order(&self, request: OrderUpdateRequest) -> BrokerResult<Order> {
-        let mut orders = self.orders.lock().await;
-        if let Some(order) = orders.iter_mut().find(|o| o.id == request.order_id) {
-            if order.request.symbol != request.symbol {
-                return Err(BrokerError::InvalidRequest(
-                    "symbol mismatch for amend".into(),
-                ));
-            }
-            if order.request.side != request.side {
-                return Err(BrokerError::InvalidRequest(
-                    "side mismatch for amend".into(),
-                ));
-            }
-            if let Some(price) = request.new_price {
-                order.request.price = Some(price);
-            }
-            if let Some(quantity) = request.new_quantity {
-                order.request.quantity = quantity;
-            }
-            order.updated_at = Utc::now();
-            return Ok(order.clone());
+        if self
+            .conditional_orders
+            .lock()
+            .await
+            .get(&request.order_id)
+            .is_none()
+        {
+            // Market and limit orders fill on placement, so nothing else is left to amend.
+            return Err(BrokerError::InvalidRequest(format!(
+                "order {} is no longer open",
+                request.order_id
+            )));
         }
-        Err(BrokerError::InvalidRequest(format!(
-            "order {} not found",
-            request.order_id
-        )))
+        let order = amend_conditional(&self.conditional_orders, &request).await?;
+        Ok(self.trigger_amended_conditional(order).await)
     }
 
     async fn list_open_orders(&self, _symbol: Symbol) -> BrokerResult<Vec<Order>> {
@@ -1746,6 +2031,7 @@ impl CandleBuilder {
                 close: self.close,
                 volume: self.volume,
                 timestamp: start,
+                status: CandleStatus::Closed,
             };
             self.reset(price, timestamp, qty);
             Some(candle)
@@ -2340,6 +2626,74 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test(flavor = "current_thread")]
+    async fn paper_factory_seeds_configured_balances() -> BrokerResult<()> {
+        let usdt = AssetId::from("USDT");
+        let btc = AssetId::from("BTC");
+        let client = PaperFactory::default()
+            .create_execution_client(&json!({
+                "balance_currency": "USDT",
+                "balances": { "USDT": "2500", "BTC": "1" },
+            }))
+            .await?;
+        let balances = client.account_balances().await?;
+        let usdt_balance = balances.iter().find(|b| b.asset == usdt).unwrap();
+        assert_eq!(usdt_balance.total, Decimal::from(2_500));
+        assert_eq!(usdt_balance.available, Decimal::from(2_500));
+        let btc_balance = balances.iter().find(|b| b.asset == btc).unwrap();
+        assert_eq!(btc_balance.total, Decimal::ONE);
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn paper_factory_defaults_to_configured_initial_balance() -> BrokerResult<()> {
+        let client = PaperFactory::default()
+            .create_execution_client(&json!({ "initial_balance": "750" }))
+            .await?;
+        let balances = client.account_balances().await?;
+        assert_eq!(balances.len(), 1);
+        assert_eq!(balances[0].total, Decimal::from(750));
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn paper_client_captures_immediate_fills_when_enabled() {
+        let symbol = Symbol::from("BTCUSDT");
+        let request = OrderRequest {
+            symbol,
+            side: Side::Buy,
+            order_type: OrderType::Market,
+            quantity: Decimal::from(2),
+            price: None,
+            trigger_price: None,
+            time_in_force: None,
+            client_order_id: None,
+            take_profit: None,
+            stop_loss: None,
+            display_quantity: None,
+        };
+
+        let silent = PaperExecutionClient::default();
+        silent.update_price(&symbol, Decimal::from(100));
+        silent.place_order(request.clone()).await.unwrap();
+        assert!(silent.drain_fills().await.is_empty());
+
+        // Capture enabled through a clone applies to the shared client state.
+        silent.clone().enable_fill_capture();
+        silent.place_order(request.clone()).await.unwrap();
+        assert_eq!(silent.drain_fills().await.len(), 1);
+
+        let client = PaperExecutionClient::default().with_fill_capture();
+        client.update_price(&symbol, Decimal::from(100));
+        let order = client.place_order(request).await.unwrap();
+        let fills = client.drain_fills().await;
+        assert_eq!(fills.len(), 1);
+        assert_eq!(fills[0].order_id, order.id);
+        assert_eq!(fills[0].fill_price, Decimal::from(100));
+        assert_eq!(fills[0].fill_quantity, Decimal::from(2));
+        assert!(client.drain_fills().await.is_empty());
+    }
+
     #[tokio::test(flavor = "current_thread")]
     async fn matching_engine_amend_updates_resting_state() {
         let engine = MatchingEngine::new(
@@ -2369,6 +2723,7 @@ mod tests {
             side: order.request.side,
             new_price: Some(Decimal::from(25_500)),
             new_quantity: Some(Decimal::from(3)),
+            new_trigger_price: None,
         };
 
         let amended = engine.amend_order(update).await.unwrap();
@@ -2381,6 +2736,190 @@ mod tests {
         assert_eq!(qty, Decimal::from(3));
     }
 
+    fn amend_test_engine() -> MatchingEngine {
+        let engine = MatchingEngine::new(
+            "paper",
+            vec![Symbol::from("BTCUSDT")],
+            Decimal::from(100_000),
+        );
+        engine.load_market_snapshot(&OrderBook {
+            symbol: "BTCUSDT".into(),
+            bids: vec![OrderBookLevel {
+                price: Decimal::from(9_900),
+                size: Decimal::from(5),
+            }],
+            asks: vec![OrderBookLevel {
+                price: Decimal::from(10_000),
+                size: Decimal::from(5),
+            }],
+            timestamp: Utc::now(),
+            exchange_checksum: None,
+            local_checksum: None,
+        });
+        engine
+    }
+
+    fn resting_bid(price: i64) -> OrderRequest {
+        OrderRequest {
+            symbol: "BTCUSDT".into(),
+            side: Side::Buy,
+            order_type: OrderType::Limit,
+            quantity: Decimal::ONE,
+            price: Some(Decimal::from(price)),
+            trigger_price: None,
+            time_in_force: Some(TimeInForce::GoodTilCanceled),
+            client_order_id: None,
+            take_profit: None,
+            stop_loss: None,
+            display_quantity: None,
+        }
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn amending_into_the_spread_fills_at_new_price() {
+        let engine = amend_test_engine();
+        let order = engine.place_order(resting_bid(9_800)).await.unwrap();
+        assert_eq!(order.status, OrderStatus::Accepted);
+
+        let amended = engine
+            .amend_order(OrderUpdateRequest {
+                order_id: order.id.clone(),
+                symbol: order.request.symbol,
+                side: Side::Buy,
+                new_price: Some(Decimal::from(10_000)),
+                new_quantity: None,
+                new_trigger_price: None,
+            })
+            .await
+            .unwrap();
+        assert_eq!(amended.status, OrderStatus::Filled);
+        assert_eq!(amended.avg_fill_price, Some(Decimal::from(10_000)));
+
+        let fills = engine.drain_fills().await;
+        assert_eq!(fills.len(), 1);
+        assert_eq!(fills[0].order_id, order.id);
+        assert_eq!(fills[0].fill_price, Decimal::from(10_000));
+        assert_eq!(fills[0].fill_quantity, Decimal::ONE);
+        assert!(engine
+            .list_open_orders("BTCUSDT".into())
+            .await
+            .unwrap()
+            .is_empty());
+        assert!(engine.resting_depth.lock().unwrap().best_bid().is_none());
+    }
+
+    fn sell_stop(trigger: i64) -> OrderRequest {
+        OrderRequest {
+            symbol: "BTCUSDT".into(),
+            side: Side::Sell,
+            order_type: OrderType::StopMarket,
+            quantity: Decimal::ONE,
+            price: None,
+            trigger_price: Some(Decimal::from(trigger)),
+            time_in_force: Some(TimeInForce::GoodTilCanceled),
+            client_order_id: None,
+            take_profit: None,
+            stop_loss: None,
+            display_quantity: None,
+        }
+    }
+
+    fn trigger_update(order: &Order, trigger: i64) -> OrderUpdateRequest {
+        OrderUpdateRequest {
+            order_id: order.id.clone(),
+            symbol: order.request.symbol,
+            side: order.request.side,
+            new_price: None,
+            new_quantity: None,
+            new_trigger_price: Some(Decimal::from(trigger)),
+        }
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn amending_stop_through_the_market_triggers_it() {
+        let engine = amend_test_engine();
+        let order = engine.place_order(sell_stop(9_000)).await.unwrap();
+        engine
+            .amend_order(trigger_update(&order, 9_800))
+            .await
+            .unwrap();
+        assert!(engine.drain_fills().await.is_empty());
+
+        engine
+            .amend_order(trigger_update(&order, 9_960))
+            .await
+            .unwrap();
+        let fills = engine.drain_fills().await;
+        assert_eq!(fills.len(), 1);
+        assert_eq!(fills[0].order_id, order.id);
+        assert_eq!(fills[0].fill_price, Decimal::from(9_950));
+        assert!(engine
+            .conditional_orders
+            .lock()
+            .await
+            .get(&order.id)
+            .is_none());
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn paper_client_amended_stop_rechecks_last_price() {
+        let client = PaperExecutionClient::default();
+        client.enable_fill_capture();
+        let symbol = Symbol::from("BTCUSDT");
+        client.update_price(&symbol, Decimal::from(100));
+        let order = client.place_order(sell_stop(90)).await.unwrap();
+
+        let resting = client
+            .amend_order(trigger_update(&order, 95))
+            .await
+            .unwrap();
+        assert_eq!(resting.status, OrderStatus::PendingNew);
+        assert!(client.drain_fills().await.is_empty());
+
+        let filled = client
+            .amend_order(trigger_update(&order, 101))
+            .await
+            .unwrap();
+        assert_eq!(filled.status, OrderStatus::Filled);
+        assert_eq!(filled.avg_fill_price, Some(Decimal::from(100)));
+        let fills = client.drain_fills().await;
+        assert_eq!(fills.len(), 1);
+        assert_eq!(fills[0].order_id, order.id);
+        assert_eq!(fills[0].fill_price, Decimal::from(100));
+
+        let err = client
+            .amend_order(trigger_update(&order, 102))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("no longer open"));
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn amended_resting_order_fills_when_trade_crosses_new_price() {
+        let engine = amend_test_engine();
+        let order = engine.place_order(resting_bid(9_800)).await.unwrap();
+        engine
+            .amend_order(OrderUpdateRequest {
+                order_id: order.id.clone(),
+                symbol: order.request.symbol,
+                side: Side::Buy,
+                new_price: Some(Decimal::from(9_950)),
+                new_quantity: None,
+                new_trigger_price: None,
+            })
+            .await
+            .unwrap();
+        assert!(engine.drain_fills().await.is_empty());
+
+        let fills = engine
+            .process_trade(Side::Sell, Decimal::from(9_950), Decimal::ONE, Utc::now())
+            .await;
+        assert_eq!(fills.len(), 1);
+        assert_eq!(fills[0].order_id, order.id);
+        assert_eq!(fills[0].fill_price, Decimal::from(9_950));
+        assert_eq!(fills[0].fill_quantity, Decimal::ONE);
+    }
+
     #[tokio::test(flavor = "current_thread")]
     async fn queue_model_waits_for_depth_before_fill() {
         let engine = MatchingEngine::with_config(
@@ -2392,6 +2931,7 @@ mod tests {
                 queue_model: QueueModel::Conservative,
                 fee_model: FeeScheduleConfig::default().build_model(),
                 cash_asset: None,
+                depth_fills: false,
             },
         );
         let book_time = Utc::now();
@@ -2470,6 +3010,7 @@ mod tests {
                 queue_model: QueueModel::Optimistic,
                 fee_model: FeeScheduleConfig::default().build_model(),
                 cash_asset: None,
+                depth_fills: false,
             },
         );
         let book_time = Utc::now();
@@ -2552,6 +3093,7 @@ mod tests {
                 queue_model: QueueModel::Optimistic,
                 fee_model: FeeScheduleConfig::default().build_model(),
                 cash_asset: None,
+                depth_fills: false,
             },
         );
         let book_time = Utc::now();
@@ -2596,6 +3138,138 @@ mod tests {
         assert_eq!(fills[0].fill_quantity, Decimal::new(2, 0));
     }
 
+    fn depth_fill_engine(bids: &[(i64, Decimal)], asks: &[(i64, Decimal)]) -> MatchingEngine {
+        let engine = MatchingEngine::with_config(
+            "paper",
+            vec![Symbol::from("BTCUSDT")],
+            Decimal::from(10_000),
+            MatchingEngineConfig {
+                depth_fills: true,
+                ..MatchingEngineConfig::default()
+            },
+        );
+        let update = depth(bids, asks);
+        engine.load_market_snapshot(&OrderBook {
+            symbol: update.symbol,
+            bids: update.bids,
+            asks: update.asks,
+            timestamp: update.timestamp,
+            exchange_checksum: None,
+            local_checksum: None,
+        });
+        engine
+    }
+
+    fn depth(bids: &[(i64, Decimal)], asks: &[(i64, Decimal)]) -> DepthUpdate {
+        let levels = |levels: &[(i64, Decimal)]| {
+            levels
+                .iter()
+                .map(|(price, size)| OrderBookLevel {
+                    price: Decimal::from(*price),
+                    size: *size,
+                })
+                .collect()
+        };
+        DepthUpdate {
+            symbol: "BTCUSDT".into(),
+            bids: levels(bids),
+            asks: levels(asks),
+            timestamp: Utc::now(),
+        }
+    }
+
+    fn depth_limit(side: Side, price: i64, quantity: Decimal) -> OrderRequest {
+        OrderRequest {
+            side,
+            quantity,
+            ..resting_bid(price)
+        }
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn depth_fills_wait_for_the_book_to_cross_the_limit() {
+        let engine = depth_fill_engine(&[(100, Decimal::from(5))], &[(101, Decimal::from(5))]);
+        let symbol = Symbol::from("BTCUSDT");
+        let order = engine
+            .place_order(depth_limit(Side::Sell, 105, Decimal::ONE))
+            .await
+            .unwrap();
+        assert_eq!(order.status, OrderStatus::Accepted);
+        assert!(engine.fill_resting_against_depth().await.is_empty());
+
+        engine.apply_depth_update(&depth(&[(104, Decimal::from(3))], &[]));
+        assert!(engine.fill_resting_against_depth().await.is_empty());
+
+        engine.apply_depth_update(&depth(&[(106, Decimal::new(4, 1))], &[]));
+        let fills = engine.fill_resting_against_depth().await;
+        assert_eq!(fills.len(), 1);
+        assert_eq!(fills[0].order_id, order.id);
+        assert_eq!(fills[0].fill_price, Decimal::from(105));
+        assert_eq!(fills[0].fill_quantity, Decimal::new(4, 1));
+        let open = engine.list_open_orders(symbol).await.unwrap();
+        assert_eq!(open.len(), 1);
+        assert_eq!(open[0].status, OrderStatus::PartiallyFilled);
+
+        engine.apply_depth_update(&depth(&[(107, Decimal::from(2))], &[]));
+        let fills = engine.fill_resting_against_depth().await;
+        assert_eq!(fills.len(), 1);
+        assert_eq!(fills[0].fill_quantity, Decimal::new(6, 1));
+        assert!(engine.list_open_orders(symbol).await.unwrap().is_empty());
+        assert_eq!(engine.drain_fills().await.len(), 2);
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn marketable_depth_limit_takes_only_liquidity_through_its_price() {
+        let engine = depth_fill_engine(
+            &[(99, Decimal::ONE)],
+            &[(100, Decimal::new(5, 1)), (101, Decimal::ONE)],
+        );
+        let symbol = Symbol::from("BTCUSDT");
+        let order = engine
+            .place_order(depth_limit(Side::Buy, 100, Decimal::from(2)))
+            .await
+            .unwrap();
+        assert_eq!(order.status, OrderStatus::PartiallyFilled);
+        assert_eq!(order.filled_quantity, Decimal::new(5, 1));
+        assert_eq!(order.avg_fill_price, Some(Decimal::from(100)));
+        let open = engine.list_open_orders(symbol).await.unwrap();
+        assert_eq!(open.len(), 1);
+        assert_eq!(open[0].status, OrderStatus::PartiallyFilled);
+
+        engine.cancel_order(order.id.clone(), symbol).await.unwrap();
+        assert!(engine.list_open_orders(symbol).await.unwrap().is_empty());
+        engine.apply_depth_update(&depth(&[], &[(99, Decimal::from(5))]));
+        assert!(engine.fill_resting_against_depth().await.is_empty());
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn amend_below_filled_quantity_leaves_the_order_untouched() {
+        let engine = depth_fill_engine(&[(99, Decimal::ONE)], &[(100, Decimal::new(5, 1))]);
+        let order = engine
+            .place_order(depth_limit(Side::Buy, 100, Decimal::from(2)))
+            .await
+            .unwrap();
+        assert_eq!(order.filled_quantity, Decimal::new(5, 1));
+
+        let err = engine
+            .amend_order(OrderUpdateRequest {
+                order_id: order.id.clone(),
+                symbol: order.request.symbol,
+                side: Side::Buy,
+                new_price: Some(Decimal::from(98)),
+                new_quantity: Some(Decimal::new(2, 1)),
+                new_trigger_price: None,
+            })
+            .await;
+        assert!(matches!(err, Err(BrokerError::InvalidRequest(_))));
+        let open = engine.list_open_orders(order.request.symbol).await.unwrap();
+        assert_eq!(open[0].request.price, Some(Decimal::from(100)));
+        assert_eq!(
+            engine.resting_depth.lock().unwrap().best_bid(),
+            Some((Decimal::from(100), Decimal::new(15, 1)))
+        );
+    }
+
     #[tokio::test(flavor = "current_thread")]
     async fn fee_schedule_distinguishes_maker_and_taker() {
         let fee_cfg =
@@ -2609,6 +3283,7 @@ mod tests {
                 queue_model: QueueModel::Optimistic,
                 fee_model: fee_cfg.build_model(),
                 cash_asset: None,
+                depth_fills: false,
             },
         );
         let book_time = Utc::now();
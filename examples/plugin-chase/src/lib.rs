@@ -1,5 +1,8 @@
-use rust_decimal::prelude::FromStr;
+use std::collections::HashMap;
+
 use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use tesser_wasm::{
     export_plugin, ExecutionPlugin, PluginChildOrderAction, PluginInitContext, PluginOrderRequest,
     PluginOrderType, PluginResult, PluginSide, PluginTick,
@@ -12,6 +15,20 @@ struct ChasePlugin {
     remaining: Decimal,
     clip_size: Decimal,
     last_price: Decimal,
+    /// Our side's touch (best bid for buys, best ask for sells) when the host sends depth.
+    near_touch: Option<Decimal>,
+    next_seq: u64,
+    /// Quantity of every clip we sent, keyed by client order id.
+    clips: HashMap<String, Decimal>,
+}
+
+/// Working state persisted across host restarts; everything else is rebuilt by `init`.
+#[derive(Deserialize, Serialize)]
+struct ChaseState {
+    remaining: Decimal,
+    last_price: Decimal,
+    next_seq: u64,
+    clips: HashMap<String, Decimal>,
 }
 
 impl ExecutionPlugin for ChasePlugin {
@@ -20,10 +37,7 @@ impl ExecutionPlugin for ChasePlugin {
         self.side = ctx.signal.side;
         self.remaining = ctx.signal.target_quantity.max(Decimal::ZERO);
         self.clip_size = ctx
-            .params
-            .get("clip_size")
-            .and_then(|value| value.as_str())
-            .and_then(|raw| Decimal::from_str_exact(raw).ok())
+            .param_decimal("clip_size")
             .unwrap_or_else(|| Decimal::new(1, 0));
         self.last_price = ctx.risk.last_price.max(Decimal::ONE);
         Ok(PluginResult::default())
@@ -31,6 +45,10 @@ impl ExecutionPlugin for ChasePlugin {
 
     fn on_tick(&mut self, tick: PluginTick) -> Result<PluginResult, tesser_wasm::PluginError> {
         self.last_price = tick.price;
+        self.near_touch = match self.side {
+            PluginSide::Buy => tick.best_bid,
+            PluginSide::Sell => tick.best_ask,
+        };
         Ok(PluginResult::default())
     }
 
@@ -40,22 +58,156 @@ impl ExecutionPlugin for ChasePlugin {
         }
         let slice = self.clip_size.min(self.remaining);
         self.remaining -= slice;
-        let price = self.last_price;
+        let price = self.near_touch.unwrap_or(self.last_price);
+        self.next_seq += 1;
+        let client_order_id = format!("chase-{}", self.next_seq);
+        self.clips.insert(client_order_id.clone(), slice);
         let order = PluginOrderRequest {
             symbol: self.symbol.clone(),
             side: self.side,
             order_type: PluginOrderType::Limit,
-            quantity: slice,
+            quantity: Some(slice),
+            notional: None,
             price: Some(price),
             trigger_price: None,
             time_in_force: None,
-            client_order_id: None,
+            client_order_id: Some(client_order_id),
             take_profit: None,
             stop_loss: None,
             display_quantity: None,
         };
         Ok(PluginResult::default().with_order(PluginChildOrderAction::Place(order)))
     }
+
+    fn on_order_rejected(
+        &mut self,
+        order_id: String,
+        _reason: String,
+    ) -> Result<(), tesser_wasm::PluginError> {
+        // Put the rejected clip back so the next timer retries it.
+        if let Some(quantity) = self.clips.remove(&order_id) {
+            self.remaining += quantity;
+        }
+        Ok(())
+    }
+
+    fn snapshot(&mut self) -> Result<Value, tesser_wasm::PluginError> {
+        let state = ChaseState {
+            remaining: self.remaining,
+            last_price: self.last_price,
+            next_seq: self.next_seq,
+            clips: self.clips.clone(),
+        };
+        serde_json::to_value(state).map_err(tesser_wasm::PluginError::from)
+    }
+
+    fn restore(&mut self, state: Value) -> Result<(), tesser_wasm::PluginError> {
+        if state.is_null() {
+            return Ok(());
+        }
+        let state: ChaseState =
+            serde_json::from_value(state).map_err(tesser_wasm::PluginError::from)?;
+        self.remaining = state.remaining;
+        self.last_price = state.last_price;
+        self.next_seq = state.next_seq;
+        self.clips = state.clips;
+        Ok(())
+    }
 }
 
 export_plugin!(ChasePlugin);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejected_clip_is_restored_to_remaining() {
+        let mut plugin = ChasePlugin {
+            symbol: "BTCUSDT".into(),
+            remaining: Decimal::new(3, 0),
+            clip_size: Decimal::ONE,
+            last_price: Decimal::new(100, 0),
+            ..ChasePlugin::default()
+        };
+        let result = plugin.on_timer().unwrap();
+        let PluginChildOrderAction::Place(order) = &result.orders[0].action else {
+            panic!("expected a placed clip");
+        };
+        let client_id = order.client_order_id.clone().unwrap();
+        assert_eq!(plugin.remaining, Decimal::new(2, 0));
+
+        plugin
+            .on_order_rejected(client_id.clone(), "max order notional".into())
+            .unwrap();
+        assert_eq!(plugin.remaining, Decimal::new(3, 0));
+
+        // A duplicate rejection must not inflate the remaining quantity.
+        plugin
+            .on_order_rejected(client_id, "max order notional".into())
+            .unwrap();
+        assert_eq!(plugin.remaining, Decimal::new(3, 0));
+    }
+
+    #[test]
+    fn quotes_at_near_touch_when_depth_is_present() {
+        let mut plugin = ChasePlugin {
+            symbol: "BTCUSDT".into(),
+            side: PluginSide::Sell,
+            remaining: Decimal::new(2, 0),
+            clip_size: Decimal::ONE,
+            ..ChasePlugin::default()
+        };
+        let tick = PluginTick::new(
+            "BTCUSDT",
+            Decimal::new(100, 0),
+            Decimal::ONE,
+            PluginSide::Buy,
+            1,
+        );
+        plugin.on_tick(tick.clone()).unwrap();
+        assert_eq!(placed_price(&mut plugin), Some(Decimal::new(100, 0)));
+
+        let with_depth = tick.with_depth(
+            Some((Decimal::new(99, 0), Decimal::ONE)),
+            Some((Decimal::new(101, 0), Decimal::ONE)),
+        );
+        plugin.on_tick(with_depth).unwrap();
+        assert_eq!(placed_price(&mut plugin), Some(Decimal::new(101, 0)));
+    }
+
+    #[test]
+    fn snapshot_restores_remaining_and_last_price() {
+        let mut plugin = ChasePlugin {
+            symbol: "BTCUSDT".into(),
+            remaining: Decimal::new(3, 0),
+            clip_size: Decimal::ONE,
+            last_price: Decimal::new(100, 0),
+            ..ChasePlugin::default()
+        };
+        plugin.on_timer().unwrap();
+        let state = plugin.snapshot().unwrap();
+
+        let mut restarted = ChasePlugin {
+            remaining: Decimal::new(3, 0),
+            clip_size: Decimal::ONE,
+            ..ChasePlugin::default()
+        };
+        restarted.restore(state).unwrap();
+        assert_eq!(restarted.remaining, Decimal::new(2, 0));
+        assert_eq!(restarted.last_price, Decimal::new(100, 0));
+        assert_eq!(restarted.next_seq, 1);
+        assert_eq!(restarted.clips.get("chase-1"), Some(&Decimal::ONE));
+
+        restarted.restore(Value::Null).unwrap();
+        assert_eq!(restarted.remaining, Decimal::new(2, 0));
+    }
+
+    fn placed_price(plugin: &mut ChasePlugin) -> Option<Decimal> {
+        let result = plugin.on_timer().unwrap();
+        let PluginChildOrderAction::Place(order) = &result.orders[0].action else {
+            panic!("expected a placed clip");
+        };
+        order.price
+    }
+}
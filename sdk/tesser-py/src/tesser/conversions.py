@@ -31,6 +31,8 @@ _SIGNAL_KIND_MAP = {
     proto.Signal.Kind.KIND_ENTER_SHORT: SignalKind.ENTER_SHORT,
     proto.Signal.Kind.KIND_EXIT_SHORT: SignalKind.EXIT_SHORT,
     proto.Signal.Kind.KIND_FLATTEN: SignalKind.FLATTEN,
+    proto.Signal.Kind.KIND_REDUCE_LONG: SignalKind.REDUCE_LONG,
+    proto.Signal.Kind.KIND_REDUCE_SHORT: SignalKind.REDUCE_SHORT,
 }
 
 
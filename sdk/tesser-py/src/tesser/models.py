@@ -20,6 +20,8 @@ class SignalKind(str, Enum):
     ENTER_SHORT = "ENTER_SHORT"
     EXIT_SHORT = "EXIT_SHORT"
     FLATTEN = "FLATTEN"
+    REDUCE_LONG = "REDUCE_LONG"
+    REDUCE_SHORT = "REDUCE_SHORT"
 
 
 @dataclass(slots=True)
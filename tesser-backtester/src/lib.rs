@@ -1,9 +1,11 @@
 //! Basic backtesting harness that ties strategies to the paper connector.
 
 pub mod reporting;
+pub mod trades;
 
 use std::{
     collections::{HashMap, VecDeque},
+    path::PathBuf,
     pin::Pin,
     sync::Arc,
 };
@@ -15,17 +17,20 @@ use reporting::{PerformanceReport, Reporter};
 use rust_decimal::Decimal;
 use tesser_broker::MarketStream;
 use tesser_core::{
-    AssetId, Candle, DepthUpdate, Fill, InstrumentKind, Order, OrderBook, Price, Quantity, Side,
-    Symbol, Tick,
+    AssetId, Candle, DepthUpdate, Fill, InstrumentKind, Order, OrderBook, OrderType, Price,
+    Quantity, Side, Signal, SignalKind, Symbol, Tick,
 };
 use tesser_data::merger::{UnifiedEvent, UnifiedEventKind};
-use tesser_execution::{ExecutionEngine, RiskContext};
+use tesser_execution::{
+    resolve_flatten, ExecutionEngine, OrderMappingPolicy, OrderPlacement, RiskContext, TopOfBook,
+};
 use tesser_ledger::{entries_from_fill, FillLedgerContext};
 use tesser_markets::MarketRegistry;
 use tesser_paper::{MatchingEngine, PaperExecutionClient};
 use tesser_portfolio::{Portfolio, PortfolioConfig};
-use tesser_strategy::{Strategy, StrategyContext};
-use tracing::{info, warn};
+use tesser_strategy::{ContextSnapshot, Strategy, StrategyContext};
+use tracing::{debug, info, warn};
+use trades::{write_trade_log, TradeLog};
 
 /// Configuration used by the backtest harness.
 pub struct BacktestConfig {
@@ -36,6 +41,12 @@ pub struct BacktestConfig {
     pub reporting_currency: AssetId,
     pub execution: ExecutionModel,
     pub mode: BacktestMode,
+    /// Write the final strategy context snapshot as JSON to this path for debugging.
+    pub context_dump: Option<PathBuf>,
+    /// Export every simulated trade to this path (parquet for `.parquet`, CSV otherwise).
+    pub trade_log: Option<PathBuf>,
+    /// Order type used per signal kind, from the strategy's `[order_mapping]` table.
+    pub order_mapping: OrderMappingPolicy,
 }
 
 impl BacktestConfig {
@@ -49,6 +60,9 @@ impl BacktestConfig {
             reporting_currency: AssetId::from("USDT"),
             execution: ExecutionModel::default(),
             mode: BacktestMode::Candle,
+            context_dump: None,
+            trade_log: None,
+            order_mapping: OrderMappingPolicy::default(),
         }
     }
 }
@@ -147,6 +161,7 @@ pub struct Backtester {
     lob_stream: Option<MarketEventStream>,
     candle_index: usize,
     market_registry: Arc<MarketRegistry>,
+    trade_log: TradeLog,
 }
 
 struct PendingFill {
@@ -171,7 +186,9 @@ impl Backtester {
             max_drawdown: None, // Disable liquidate-only for backtests for now
         };
         let mut strategy_ctx = StrategyContext::new(config.history);
+        strategy_ctx.ensure_history(strategy.required_history());
         strategy_ctx.attach_market_registry(market_registry.clone());
+        let trade_log = TradeLog::new(strategy.name());
         Self {
             strategy_ctx,
             portfolio: Portfolio::new(portfolio_config, market_registry.clone()),
@@ -184,15 +201,35 @@ impl Backtester {
             lob_stream,
             candle_index: 0,
             market_registry,
+            trade_log,
         }
     }
 
     /// Execute the backtest using the provided candles.
     pub async fn run(mut self) -> anyhow::Result<PerformanceReport> {
-        match self.config.mode {
+        let mut report = match self.config.mode {
             BacktestMode::Candle => self.run_candle().await,
             BacktestMode::Tick => self.run_tick().await,
+        }?;
+        self.dump_context()?;
+        report.trades = self.trade_log.into_trades();
+        if let Some(path) = &self.config.trade_log {
+            write_trade_log(&report.trades, path)
+                .with_context(|| format!("failed to write trade log to {}", path.display()))?;
+            info!(path = %path.display(), trades = report.trades.len(), "wrote trade log");
         }
+        Ok(report)
+    }
+
+    fn dump_context(&self) -> anyhow::Result<()> {
+        let Some(path) = &self.config.context_dump else {
+            return Ok(());
+        };
+        let json = ContextSnapshot::from(&self.strategy_ctx).to_json_pretty()?;
+        std::fs::write(path, json)
+            .with_context(|| format!("failed to write context dump to {}", path.display()))?;
+        info!(path = %path.display(), "wrote strategy context snapshot");
+        Ok(())
     }
 
     async fn run_candle(&mut self) -> anyhow::Result<PerformanceReport> {
@@ -338,7 +375,16 @@ impl Backtester {
                 quote_available,
                 settlement_available,
             };
-            if let Some(order) = self.execution.handle_signal(signal, ctx).await? {
+            let Some(signal) = resolve_signal(signal, ctx.signed_position_qty) else {
+                continue;
+            };
+            let placement = self.order_placement(&signal, candle.close);
+            if let Some(order) = self
+                .execution
+                .handle_signal_with(signal, ctx, placement)
+                .await?
+            {
+                self.trade_log.link_order(&order);
                 let latency = self.config.execution.latency_candles.max(1);
                 let due_after = idx.saturating_add(latency);
                 self.pending.push_back(PendingFill { order, due_after });
@@ -369,7 +415,7 @@ impl Backtester {
     ) -> anyhow::Result<()> {
         let mut remaining = VecDeque::new();
         while let Some(pending) = self.pending.pop_front() {
-            if pending.due_after <= candle_index {
+            if pending.due_after <= candle_index && limit_reached(&pending.order, candle) {
                 let fill = self.build_fill(&pending.order, candle);
                 self.record_fill(&fill, all_fills)
                     .await
@@ -409,6 +455,12 @@ impl Backtester {
             };
             price *= multiplier;
         }
+        if let (OrderType::Limit, Some(limit)) = (order.request.order_type, order.request.price) {
+            price = match order.request.side {
+                Side::Buy => price.min(limit),
+                Side::Sell => price.max(limit),
+            };
+        }
         let fee_rate = self.config.execution.fee_bps.max(Decimal::ZERO) / Decimal::from(10_000);
         let notional = price * order.request.quantity.abs();
         let fee = if fee_rate > Decimal::ZERO {
@@ -460,6 +512,7 @@ impl Backtester {
             match &event.kind {
                 MarketEventKind::OrderBook(book) => {
                     matching.load_market_snapshot(book);
+                    matching.fill_resting_against_depth().await;
                     self.strategy_ctx.push_order_book(book.clone());
                     self.strategy
                         .on_order_book(&self.strategy_ctx, book)
@@ -468,6 +521,7 @@ impl Backtester {
                 }
                 MarketEventKind::Depth(update) => {
                     matching.apply_depth_update(update);
+                    matching.fill_resting_against_depth().await;
                 }
                 MarketEventKind::Trade(tick) => {
                     matching
@@ -539,11 +593,40 @@ impl Backtester {
                 quote_available,
                 settlement_available,
             };
-            let _ = self.execution.handle_signal(signal, ctx).await?;
+            let Some(signal) = resolve_signal(signal, ctx.signed_position_qty) else {
+                continue;
+            };
+            let placement = self.order_placement(&signal, reference_price);
+            if let Some(order) = self
+                .execution
+                .handle_signal_with(signal, ctx, placement)
+                .await?
+            {
+                self.trade_log.link_order(&order);
+            }
         }
         Ok(())
     }
 
+    /// Resolve the configured order mapping against the latest book and `last_price`.
+    fn order_placement(&self, signal: &Signal, last_price: Price) -> OrderPlacement {
+        let mut book = TopOfBook {
+            last: (last_price > Decimal::ZERO).then_some(last_price),
+            ..TopOfBook::default()
+        };
+        if let Some(order_book) = self.strategy_ctx.order_book(signal.symbol) {
+            book.bid = order_book.best_bid().map(|level| level.price);
+            book.ask = order_book.best_ask().map(|level| level.price);
+        }
+        let tick_size = self
+            .market_registry
+            .get(signal.symbol)
+            .map(|instrument| instrument.tick_size);
+        self.config
+            .order_mapping
+            .resolve(signal.kind, signal.kind.side(), &book, tick_size)
+    }
+
     fn last_tick_price(&self, symbol: &Symbol) -> Option<Price> {
         self.strategy_ctx
             .ticks()
@@ -566,6 +649,8 @@ impl Backtester {
     }
 
     async fn record_fill(&mut self, fill: &Fill, all_fills: &mut Vec<Fill>) -> anyhow::Result<()> {
+        // Resting limit entries only get their stop-loss/take-profit once they fill.
+        self.execution.on_fill(fill).await;
         let impact = self
             .portfolio
             .apply_fill_positions(fill)
@@ -574,14 +659,15 @@ impl Backtester {
             .market_registry
             .get(fill.symbol)
             .ok_or_else(|| anyhow!("unknown instrument {}", fill.symbol))?;
-        let entries = entries_from_fill(FillLedgerContext::new(
-            fill,
-            &instrument,
-            impact.realized_pnl,
-        ));
+        // Simulated fills have no venue execution id; their position in the run is unique.
+        let context = FillLedgerContext::new(fill, &instrument, impact.realized_pnl)
+            .with_fill_id(format!("{}-{}", fill.order_id, all_fills.len()))
+            .with_strategy(self.strategy.name());
+        let entries = entries_from_fill(context);
         self.portfolio
             .apply_ledger_entries(&entries)
             .context("failed to update portfolio ledger")?;
+        self.trade_log.record_fill(fill);
         all_fills.push(fill.clone());
         self.strategy_ctx
             .update_positions(self.portfolio.positions());
@@ -592,3 +678,104 @@ impl Backtester {
         Ok(())
     }
 }
+
+/// Resolve `Flatten` into an exit of the open position, as the live orchestrator does, so the
+/// order mapping sees the exit's side; `None` when there is nothing to flatten.
+fn resolve_signal(signal: Signal, signed_position_qty: Quantity) -> Option<Signal> {
+    if signal.kind != SignalKind::Flatten {
+        return Some(signal);
+    }
+    let exit = resolve_flatten(&signal, signed_position_qty);
+    if exit.is_none() {
+        debug!(signal_id = %signal.id, symbol = %signal.symbol, "flatten signal ignored; no open position");
+    }
+    exit
+}
+
+/// Whether `candle` trades through a limit order's price; other order types always fill.
+fn limit_reached(order: &Order, candle: &Candle) -> bool {
+    match (order.request.order_type, order.request.price) {
+        (OrderType::Limit, Some(limit)) => match order.request.side {
+            Side::Buy => candle.low <= limit,
+            Side::Sell => candle.high >= limit,
+        },
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use tesser_core::{CandleStatus, Interval, OrderRequest, OrderStatus};
+
+    use super::*;
+
+    fn order(order_type: OrderType, side: Side, price: Option<Price>) -> Order {
+        Order {
+            id: "1".into(),
+            request: OrderRequest {
+                symbol: Symbol::from("BTCUSDT"),
+                side,
+                order_type,
+                quantity: Decimal::ONE,
+                price,
+                trigger_price: None,
+                time_in_force: None,
+                client_order_id: None,
+                take_profit: None,
+                stop_loss: None,
+                display_quantity: None,
+            },
+            status: OrderStatus::Accepted,
+            filled_quantity: Decimal::ZERO,
+            avg_fill_price: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn candle(low: i64, high: i64) -> Candle {
+        Candle {
+            symbol: Symbol::from("BTCUSDT"),
+            interval: Interval::OneMinute,
+            open: Decimal::from(high),
+            high: Decimal::from(high),
+            low: Decimal::from(low),
+            close: Decimal::from(low),
+            volume: Decimal::ONE,
+            timestamp: Utc::now(),
+            status: CandleStatus::Closed,
+        }
+    }
+
+    #[test]
+    fn limit_orders_wait_for_the_bar_to_reach_their_price() {
+        let bar = candle(95, 105);
+        let buy_below = order(OrderType::Limit, Side::Buy, Some(Decimal::from(90)));
+        let buy_inside = order(OrderType::Limit, Side::Buy, Some(Decimal::from(96)));
+        let sell_above = order(OrderType::Limit, Side::Sell, Some(Decimal::from(110)));
+        assert!(!limit_reached(&buy_below, &bar));
+        assert!(limit_reached(&buy_inside, &bar));
+        assert!(!limit_reached(&sell_above, &bar));
+        assert!(limit_reached(
+            &order(OrderType::Market, Side::Buy, None),
+            &bar
+        ));
+    }
+
+    #[test]
+    fn flatten_resolves_against_the_open_position() {
+        let flatten = Signal::new("BTCUSDT", SignalKind::Flatten, 1.0);
+        let exit = resolve_signal(flatten.clone(), Decimal::from(-2)).unwrap();
+        assert_eq!(exit.kind, SignalKind::ExitShort);
+        assert_eq!(exit.kind.side(), Side::Buy);
+        assert_eq!(exit.quantity, Some(Decimal::from(2)));
+        assert!(resolve_signal(flatten, Decimal::ZERO).is_none());
+
+        let entry = Signal::new("BTCUSDT", SignalKind::EnterLong, 1.0);
+        assert_eq!(
+            resolve_signal(entry, Decimal::from(-2)).unwrap().kind,
+            SignalKind::EnterLong
+        );
+    }
+}
@@ -6,6 +6,8 @@ use itertools::Itertools;
 use rust_decimal::{prelude::ToPrimitive, Decimal};
 use tesser_core::{Fill, Side};
 
+use crate::trades::TradeRecord;
+
 const TRADING_DAYS_PER_YEAR: f64 = 252.0;
 const RISK_FREE_RATE: f64 = 0.0;
 
@@ -29,6 +31,8 @@ pub struct PerformanceReport {
     pub avg_loss_pct: f64,
     pub profit_loss_ratio: f64,
     pub ending_equity: f64,
+    /// Every closed trade, in the order it was closed.
+    pub trades: Vec<TradeRecord>,
 }
 
 impl fmt::Display for PerformanceReport {
@@ -148,6 +152,7 @@ impl Reporter {
             avg_loss_pct: avg_loss_pct_f64,
             profit_loss_ratio,
             ending_equity: decimal_to_f64(ending_equity, "ending equity")?,
+            trades: Vec::new(),
         })
     }
 
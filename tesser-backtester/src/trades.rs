@@ -0,0 +1,451 @@
+//! Per-trade log assembled from simulated fills.
+
+use std::collections::{HashMap, VecDeque};
+use std::fs::File;
+use std::path::Path;
+use std::sync::Arc;
+
+use anyhow::{anyhow, Context, Result};
+use arrow::array::{
+    ArrayRef, Decimal128Builder, Int64Builder, StringBuilder, TimestampNanosecondBuilder,
+};
+use arrow::datatypes::{DataType, Field, Schema, SchemaRef, TimeUnit};
+use arrow::record_batch::RecordBatch;
+use chrono::{DateTime, Duration, Utc};
+use parquet::arrow::ArrowWriter;
+use rust_decimal::Decimal;
+use tesser_core::{Fill, Order, OrderId, Price, Quantity, Side, Symbol};
+use uuid::Uuid;
+
+const DECIMAL_PRECISION: u8 = 38;
+const DECIMAL_SCALE: u32 = 18;
+
+/// A round trip from opening a position to closing (part of) it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TradeRecord {
+    /// Name of the strategy that produced the signals.
+    pub strategy: String,
+    pub symbol: Symbol,
+    /// Side of the opening fill: `Buy` for longs, `Sell` for shorts.
+    pub side: Side,
+    pub quantity: Quantity,
+    pub entry_time: DateTime<Utc>,
+    pub exit_time: DateTime<Utc>,
+    pub entry_price: Price,
+    pub exit_price: Price,
+    /// Realized PnL before fees.
+    pub pnl: Decimal,
+    /// Entry and exit fees attributed to this trade's quantity.
+    pub fees: Decimal,
+    pub entry_signal: Option<Uuid>,
+    pub exit_signal: Option<Uuid>,
+}
+
+impl TradeRecord {
+    /// Time between the opening and closing fills.
+    pub fn holding_period(&self) -> Duration {
+        self.exit_time - self.entry_time
+    }
+
+    /// Realized PnL after fees.
+    pub fn net_pnl(&self) -> Decimal {
+        self.pnl - self.fees
+    }
+}
+
+struct OpenLot {
+    side: Side,
+    quantity: Quantity,
+    price: Price,
+    fee_per_unit: Decimal,
+    opened_at: DateTime<Utc>,
+    signal: Option<Uuid>,
+}
+
+/// Pairs fills first-in-first-out per symbol to build [`TradeRecord`]s.
+pub struct TradeLog {
+    strategy: String,
+    order_signals: HashMap<OrderId, Uuid>,
+    open: HashMap<Symbol, VecDeque<OpenLot>>,
+    trades: Vec<TradeRecord>,
+}
+
+impl TradeLog {
+    pub fn new(strategy: impl Into<String>) -> Self {
+        Self {
+            strategy: strategy.into(),
+            order_signals: HashMap::new(),
+            open: HashMap::new(),
+            trades: Vec::new(),
+        }
+    }
+
+    /// Remember which signal produced `order`, based on the client order id the
+    /// execution engine derives from the signal id.
+    pub fn link_order(&mut self, order: &Order) {
+        let signal = order
+            .request
+            .client_order_id
+            .as_deref()
+            .and_then(signal_from_client_id);
+        if let Some(signal) = signal {
+            self.order_signals.insert(order.id.clone(), signal);
+        }
+    }
+
+    /// Apply a fill, closing open lots on the opposite side before opening a new one.
+    pub fn record_fill(&mut self, fill: &Fill) {
+        let mut remaining = fill.fill_quantity.abs();
+        if remaining.is_zero() {
+            return;
+        }
+        let fee_per_unit = fill.fee.unwrap_or_default() / remaining;
+        let signal = self.order_signals.get(&fill.order_id).copied();
+        let lots = self.open.entry(fill.symbol).or_default();
+        while remaining > Decimal::ZERO {
+            let Some(lot) = lots.front_mut() else {
+                break;
+            };
+            if lot.side == fill.side {
+                break;
+            }
+            let quantity = remaining.min(lot.quantity);
+            let pnl = match lot.side {
+                Side::Buy => (fill.fill_price - lot.price) * quantity,
+                Side::Sell => (lot.price - fill.fill_price) * quantity,
+            };
+            self.trades.push(TradeRecord {
+                strategy: self.strategy.clone(),
+                symbol: fill.symbol,
+                side: lot.side,
+                quantity,
+                entry_time: lot.opened_at,
+                exit_time: fill.timestamp,
+                entry_price: lot.price,
+                exit_price: fill.fill_price,
+                pnl,
+                fees: (lot.fee_per_unit + fee_per_unit) * quantity,
+                entry_signal: lot.signal,
+                exit_signal: signal,
+            });
+            lot.quantity -= quantity;
+            remaining -= quantity;
+            if lot.quantity.is_zero() {
+                lots.pop_front();
+            }
+        }
+        if remaining > Decimal::ZERO {
+            lots.push_back(OpenLot {
+                side: fill.side,
+                quantity: remaining,
+                price: fill.fill_price,
+                fee_per_unit,
+                opened_at: fill.timestamp,
+                signal,
+            });
+        }
+    }
+
+    /// Completed trades in the order they were closed.
+    pub fn trades(&self) -> &[TradeRecord] {
+        &self.trades
+    }
+
+    pub fn into_trades(self) -> Vec<TradeRecord> {
+        self.trades
+    }
+}
+
+/// Write trades as CSV, or as parquet when `path` ends in `.parquet`.
+pub fn write_trade_log(trades: &[TradeRecord], path: &Path) -> Result<()> {
+    if path.extension().and_then(|ext| ext.to_str()) == Some("parquet") {
+        write_trades_parquet(trades, path)
+    } else {
+        write_trades_csv(trades, path)
+    }
+}
+
+pub fn write_trades_csv(trades: &[TradeRecord], path: &Path) -> Result<()> {
+    let mut writer = csv::Writer::from_path(path)
+        .with_context(|| format!("failed to create {}", path.display()))?;
+    writer.write_record([
+        "strategy",
+        "symbol",
+        "side",
+        "quantity",
+        "entry_time",
+        "exit_time",
+        "entry_price",
+        "exit_price",
+        "pnl",
+        "fees",
+        "holding_secs",
+        "entry_signal",
+        "exit_signal",
+    ])?;
+    for trade in trades {
+        writer.write_record([
+            trade.strategy.clone(),
+            trade.symbol.to_string(),
+            side_label(trade.side).to_string(),
+            trade.quantity.to_string(),
+            trade.entry_time.to_rfc3339(),
+            trade.exit_time.to_rfc3339(),
+            trade.entry_price.to_string(),
+            trade.exit_price.to_string(),
+            trade.pnl.to_string(),
+            trade.fees.to_string(),
+            trade.holding_period().num_seconds().to_string(),
+            trade
+                .entry_signal
+                .map(|id| id.to_string())
+                .unwrap_or_default(),
+            trade
+                .exit_signal
+                .map(|id| id.to_string())
+                .unwrap_or_default(),
+        ])?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+pub fn write_trades_parquet(trades: &[TradeRecord], path: &Path) -> Result<()> {
+    let schema = trade_schema();
+    let batch = trades_to_batch(trades, &schema)?;
+    let file =
+        File::create(path).with_context(|| format!("failed to create {}", path.display()))?;
+    let mut writer = ArrowWriter::try_new(file, schema, None)?;
+    writer.write(&batch)?;
+    writer.close()?;
+    Ok(())
+}
+
+fn trade_schema() -> SchemaRef {
+    let decimal = DataType::Decimal128(DECIMAL_PRECISION, DECIMAL_SCALE as i8);
+    let timestamp = DataType::Timestamp(TimeUnit::Nanosecond, None);
+    Arc::new(Schema::new(vec![
+        Field::new("strategy", DataType::Utf8, false),
+        Field::new("symbol", DataType::Utf8, false),
+        Field::new("side", DataType::Utf8, false),
+        Field::new("quantity", decimal.clone(), false),
+        Field::new("entry_time", timestamp.clone(), false),
+        Field::new("exit_time", timestamp, false),
+        Field::new("entry_price", decimal.clone(), false),
+        Field::new("exit_price", decimal.clone(), false),
+        Field::new("pnl", decimal.clone(), false),
+        Field::new("fees", decimal, false),
+        Field::new("holding_secs", DataType::Int64, false),
+        Field::new("entry_signal", DataType::Utf8, true),
+        Field::new("exit_signal", DataType::Utf8, true),
+    ]))
+}
+
+fn trades_to_batch(trades: &[TradeRecord], schema: &SchemaRef) -> Result<RecordBatch> {
+    let decimal_builder = || {
+        Decimal128Builder::new()
+            .with_data_type(DataType::Decimal128(DECIMAL_PRECISION, DECIMAL_SCALE as i8))
+    };
+    let mut strategies = StringBuilder::new();
+    let mut symbols = StringBuilder::new();
+    let mut sides = StringBuilder::new();
+    let mut quantities = decimal_builder();
+    let mut entry_times = TimestampNanosecondBuilder::new();
+    let mut exit_times = TimestampNanosecondBuilder::new();
+    let mut entry_prices = decimal_builder();
+    let mut exit_prices = decimal_builder();
+    let mut pnls = decimal_builder();
+    let mut fees = decimal_builder();
+    let mut holding = Int64Builder::new();
+    let mut entry_signals = StringBuilder::new();
+    let mut exit_signals = StringBuilder::new();
+
+    for trade in trades {
+        strategies.append_value(&trade.strategy);
+        symbols.append_value(trade.symbol.to_string());
+        sides.append_value(side_label(trade.side));
+        quantities.append_value(decimal_to_i128(trade.quantity)?);
+        entry_times.append_value(timestamp_nanos(trade.entry_time)?);
+        exit_times.append_value(timestamp_nanos(trade.exit_time)?);
+        entry_prices.append_value(decimal_to_i128(trade.entry_price)?);
+        exit_prices.append_value(decimal_to_i128(trade.exit_price)?);
+        pnls.append_value(decimal_to_i128(trade.pnl)?);
+        fees.append_value(decimal_to_i128(trade.fees)?);
+        holding.append_value(trade.holding_period().num_seconds());
+        entry_signals.append_option(trade.entry_signal.map(|id| id.to_string()));
+        exit_signals.append_option(trade.exit_signal.map(|id| id.to_string()));
+    }
+
+    let columns: Vec<ArrayRef> = vec![
+        Arc::new(strategies.finish()),
+        Arc::new(symbols.finish()),
+        Arc::new(sides.finish()),
+        Arc::new(quantities.finish()),
+        Arc::new(entry_times.finish()),
+        Arc::new(exit_times.finish()),
+        Arc::new(entry_prices.finish()),
+        Arc::new(exit_prices.finish()),
+        Arc::new(pnls.finish()),
+        Arc::new(fees.finish()),
+        Arc::new(holding.finish()),
+        Arc::new(entry_signals.finish()),
+        Arc::new(exit_signals.finish()),
+    ];
+    RecordBatch::try_new(schema.clone(), columns).map_err(Into::into)
+}
+
+fn signal_from_client_id(client_id: &str) -> Option<Uuid> {
+    let head = client_id.split('|').next()?;
+    Uuid::parse_str(head.get(..36)?).ok()
+}
+
+fn side_label(side: Side) -> &'static str {
+    match side {
+        Side::Buy => "long",
+        Side::Sell => "short",
+    }
+}
+
+fn timestamp_nanos(value: DateTime<Utc>) -> Result<i64> {
+    value
+        .timestamp_nanos_opt()
+        .ok_or_else(|| anyhow!("timestamp {value} exceeds nanosecond precision"))
+}
+
+fn decimal_to_i128(value: Decimal) -> Result<i128> {
+    let normalized = value.round_dp(DECIMAL_SCALE);
+    let factor = 10i128
+        .checked_pow(DECIMAL_SCALE - normalized.scale())
+        .ok_or_else(|| anyhow!("decimal scaling overflow"))?;
+    normalized
+        .mantissa()
+        .checked_mul(factor)
+        .ok_or_else(|| anyhow!("decimal {value} overflows the parquet column"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use tesser_core::{OrderRequest, OrderStatus, OrderType};
+
+    fn at(minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 1, 1, 0, minute, 0).unwrap()
+    }
+
+    fn order(id: &str, side: Side, signal: Uuid) -> Order {
+        Order {
+            id: id.into(),
+            request: OrderRequest {
+                symbol: Symbol::from("BTCUSDT"),
+                side,
+                order_type: OrderType::Market,
+                quantity: Decimal::ONE,
+                price: None,
+                trigger_price: None,
+                time_in_force: None,
+                client_order_id: Some(signal.to_string()),
+                take_profit: None,
+                stop_loss: None,
+                display_quantity: None,
+            },
+            status: OrderStatus::PendingNew,
+            filled_quantity: Decimal::ZERO,
+            avg_fill_price: None,
+            created_at: at(0),
+            updated_at: at(0),
+        }
+    }
+
+    fn fill(id: &str, side: Side, price: i64, minute: u32) -> Fill {
+        Fill {
+            order_id: id.into(),
+            symbol: Symbol::from("BTCUSDT"),
+            side,
+            fill_price: Decimal::from(price),
+            fill_quantity: Decimal::ONE,
+            fee: Some(Decimal::new(1, 1)),
+            fee_asset: None,
+            timestamp: at(minute),
+        }
+    }
+
+    #[test]
+    fn two_round_trips_produce_two_records() {
+        let mut log = TradeLog::new("sma-cross");
+        let signals: Vec<Uuid> = (0..4).map(|_| Uuid::new_v4()).collect();
+        let script = [
+            ("o1", Side::Buy, 100, 0),
+            ("o2", Side::Sell, 110, 5),
+            ("o3", Side::Sell, 120, 10),
+            ("o4", Side::Buy, 125, 40),
+        ];
+        for ((id, side, price, minute), signal) in script.into_iter().zip(&signals) {
+            log.link_order(&order(id, side, *signal));
+            log.record_fill(&fill(id, side, price, minute));
+        }
+
+        let trades = log.trades();
+        assert_eq!(trades.len(), 2);
+
+        let long = &trades[0];
+        assert_eq!(long.side, Side::Buy);
+        assert_eq!(long.pnl, Decimal::from(10));
+        assert_eq!(long.fees, Decimal::new(2, 1));
+        assert_eq!(long.net_pnl(), Decimal::new(98, 1));
+        assert_eq!(long.holding_period(), Duration::minutes(5));
+        assert_eq!(long.entry_signal, Some(signals[0]));
+        assert_eq!(long.exit_signal, Some(signals[1]));
+        assert_eq!(long.strategy, "sma-cross");
+
+        let short = &trades[1];
+        assert_eq!(short.side, Side::Sell);
+        assert_eq!(short.pnl, Decimal::from(-5));
+        assert_eq!(short.holding_period(), Duration::minutes(30));
+        assert_eq!(short.entry_signal, Some(signals[2]));
+    }
+
+    #[test]
+    fn partial_exit_splits_the_open_lot() {
+        let mut log = TradeLog::new("test");
+        let mut entry = fill("a", Side::Buy, 100, 0);
+        entry.fill_quantity = Decimal::from(2);
+        entry.fee = None;
+        log.record_fill(&entry);
+        let mut exit = fill("b", Side::Sell, 104, 1);
+        exit.fee = None;
+        log.record_fill(&exit);
+        assert_eq!(log.trades().len(), 1);
+        assert_eq!(log.trades()[0].quantity, Decimal::ONE);
+        assert_eq!(log.trades()[0].pnl, Decimal::from(4));
+        assert_eq!(log.trades()[0].entry_signal, None);
+    }
+
+    #[test]
+    fn client_ids_with_group_suffix_resolve_to_signal() {
+        let signal = Uuid::new_v4();
+        let group = Uuid::new_v4();
+        assert_eq!(
+            signal_from_client_id(&format!("{signal}|grp:{group}")),
+            Some(signal)
+        );
+        assert_eq!(signal_from_client_id(&format!("{signal}-sl")), Some(signal));
+        assert_eq!(signal_from_client_id("manual"), None);
+    }
+
+    #[test]
+    fn csv_export_writes_one_row_per_trade() {
+        let mut log = TradeLog::new("test");
+        log.record_fill(&fill("a", Side::Buy, 100, 0));
+        log.record_fill(&fill("b", Side::Sell, 101, 2));
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("trades.csv");
+        write_trade_log(log.trades(), &path).unwrap();
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let lines: Vec<_> = contents.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[1].starts_with("test,"));
+        assert!(lines[1].contains(",long,"));
+        assert!(lines[1].contains(",120,"));
+    }
+}
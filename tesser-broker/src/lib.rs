@@ -113,6 +113,21 @@ pub trait ExecutionClient: Send + Sync {
     /// Cancel an existing order by identifier.
     async fn cancel_order(&self, order_id: OrderId, symbol: Symbol) -> BrokerResult<()>;
 
+    /// Cancel several orders, returning one outcome per entry in request order.
+    ///
+    /// The default issues sequential [`cancel_order`](Self::cancel_order) calls; connectors
+    /// with a native batch endpoint should override it.
+    async fn cancel_orders(
+        &self,
+        orders: Vec<(OrderId, Symbol)>,
+    ) -> BrokerResult<Vec<Result<(), BrokerError>>> {
+        let mut results = Vec::with_capacity(orders.len());
+        for (order_id, symbol) in orders {
+            results.push(self.cancel_order(order_id, symbol).await);
+        }
+        Ok(results)
+    }
+
     /// Amend an existing order in-place when the exchange supports it.
     async fn amend_order(&self, request: OrderUpdateRequest) -> BrokerResult<Order>;
 
@@ -191,5 +206,7 @@ pub use connector::{
     get_connector_factory, register_connector_factory, registered_connectors, ConnectorFactory,
     ConnectorStream, ConnectorStreamConfig,
 };
+pub mod merged;
+pub use merged::{MergedMarketStream, MergedSubscription, SourcedEvent};
 pub mod router;
 pub use router::RouterExecutionClient;
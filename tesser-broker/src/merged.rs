@@ -0,0 +1,319 @@
+//! Composite market stream that multiplexes several venue connectors.
+
+use async_trait::async_trait;
+use serde::Serialize;
+use tesser_core::{Candle, ExchangeId, OrderBook, Tick};
+
+use crate::{BrokerError, BrokerResult, MarketStream};
+
+/// Market data event tagged with the venue that produced it.
+#[derive(Clone, Debug)]
+pub struct SourcedEvent<T> {
+    pub exchange: ExchangeId,
+    pub event: T,
+}
+
+/// Subscription routed to the underlying stream registered for `exchange`.
+#[derive(Clone, Debug, Serialize)]
+pub struct MergedSubscription<S> {
+    pub exchange: ExchangeId,
+    pub subscription: S,
+}
+
+struct Source<S> {
+    exchange: ExchangeId,
+    stream: S,
+    closed: bool,
+}
+
+/// Round-robins reads across several [`MarketStream`]s so one busy venue cannot starve the rest.
+///
+/// Each call polls the sources once, starting after the source that produced the previous
+/// event. A source that returns an error is closed and skipped afterwards, while the remaining
+/// sources keep flowing.
+pub struct MergedMarketStream<S> {
+    name: String,
+    sources: Vec<Source<S>>,
+    tick_cursor: usize,
+    candle_cursor: usize,
+    book_cursor: usize,
+}
+
+macro_rules! poll_sources {
+    ($self:ident, $cursor:ident, $method:ident) => {{
+        let count = $self.sources.len();
+        let mut result = Ok(None);
+        for offset in 0..count {
+            let idx = ($self.$cursor + offset) % count;
+            let source = &mut $self.sources[idx];
+            if source.closed {
+                continue;
+            }
+            match source.stream.$method().await {
+                Ok(Some(event)) => {
+                    $self.$cursor = (idx + 1) % count;
+                    result = Ok(Some(SourcedEvent {
+                        exchange: source.exchange,
+                        event,
+                    }));
+                    break;
+                }
+                Ok(None) => {}
+                Err(err) => {
+                    source.closed = true;
+                    $self.$cursor = (idx + 1) % count;
+                    result = Err(err);
+                    break;
+                }
+            }
+        }
+        result
+    }};
+}
+
+impl<S: MarketStream> MergedMarketStream<S> {
+    /// Build a merged stream from `(exchange, stream)` pairs.
+    pub fn new(sources: impl IntoIterator<Item = (ExchangeId, S)>) -> Self {
+        let sources: Vec<_> = sources
+            .into_iter()
+            .map(|(exchange, stream)| Source {
+                exchange,
+                stream,
+                closed: false,
+            })
+            .collect();
+        let name = format!(
+            "merged({})",
+            sources
+                .iter()
+                .map(|source| source.stream.name())
+                .collect::<Vec<_>>()
+                .join(",")
+        );
+        Self {
+            name,
+            sources,
+            tick_cursor: 0,
+            candle_cursor: 0,
+            book_cursor: 0,
+        }
+    }
+
+    /// Number of sources that have not been closed by an error.
+    pub fn active_sources(&self) -> usize {
+        self.sources.iter().filter(|source| !source.closed).count()
+    }
+
+    /// Fetch the next tick along with the venue that produced it.
+    pub async fn next_sourced_tick(&mut self) -> BrokerResult<Option<SourcedEvent<Tick>>> {
+        poll_sources!(self, tick_cursor, next_tick)
+    }
+
+    /// Fetch the next candle along with the venue that produced it.
+    pub async fn next_sourced_candle(&mut self) -> BrokerResult<Option<SourcedEvent<Candle>>> {
+        poll_sources!(self, candle_cursor, next_candle)
+    }
+
+    /// Fetch the next order book along with the venue that produced it.
+    pub async fn next_sourced_order_book(
+        &mut self,
+    ) -> BrokerResult<Option<SourcedEvent<OrderBook>>> {
+        poll_sources!(self, book_cursor, next_order_book)
+    }
+}
+
+#[async_trait]
+impl<S: MarketStream> MarketStream for MergedMarketStream<S> {
+    type Subscription = MergedSubscription<S::Subscription>;
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    async fn subscribe(&mut self, subscription: Self::Subscription) -> BrokerResult<()> {
+        let source = self
+            .sources
+            .iter_mut()
+            .find(|source| source.exchange == subscription.exchange)
+            .ok_or_else(|| {
+                BrokerError::InvalidRequest(format!(
+                    "no market stream registered for exchange {}",
+                    subscription.exchange
+                ))
+            })?;
+        source.stream.subscribe(subscription.subscription).await
+    }
+
+    async fn next_tick(&mut self) -> BrokerResult<Option<Tick>> {
+        Ok(self.next_sourced_tick().await?.map(|sourced| sourced.event))
+    }
+
+    async fn next_candle(&mut self) -> BrokerResult<Option<Candle>> {
+        Ok(self
+            .next_sourced_candle()
+            .await?
+            .map(|sourced| sourced.event))
+    }
+
+    async fn next_order_book(&mut self) -> BrokerResult<Option<OrderBook>> {
+        Ok(self
+            .next_sourced_order_book()
+            .await?
+            .map(|sourced| sourced.event))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+
+    use super::*;
+    use chrono::Utc;
+    use rust_decimal::Decimal;
+    use tesser_core::{Side, Symbol};
+
+    struct FakeStream {
+        name: String,
+        ticks: VecDeque<Tick>,
+        fail_after_drain: bool,
+        subscriptions: Vec<String>,
+    }
+
+    impl FakeStream {
+        fn new(name: &str, symbol: &str, count: usize) -> Self {
+            let symbol = Symbol::from(symbol);
+            let ticks = (0..count)
+                .map(|idx| Tick {
+                    symbol,
+                    price: Decimal::from(100 + idx as i64),
+                    size: Decimal::ONE,
+                    side: Side::Buy,
+                    exchange_timestamp: Utc::now(),
+                    received_at: Utc::now(),
+                })
+                .collect();
+            Self {
+                name: name.to_string(),
+                ticks,
+                fail_after_drain: false,
+                subscriptions: Vec::new(),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl MarketStream for FakeStream {
+        type Subscription = String;
+
+        fn name(&self) -> &str {
+            &self.name
+        }
+
+        async fn subscribe(&mut self, subscription: Self::Subscription) -> BrokerResult<()> {
+            self.subscriptions.push(subscription);
+            Ok(())
+        }
+
+        async fn next_tick(&mut self) -> BrokerResult<Option<Tick>> {
+            match self.ticks.pop_front() {
+                Some(tick) => Ok(Some(tick)),
+                None if self.fail_after_drain => {
+                    Err(BrokerError::Transport("connection closed".into()))
+                }
+                None => Ok(None),
+            }
+        }
+
+        async fn next_candle(&mut self) -> BrokerResult<Option<Candle>> {
+            Ok(None)
+        }
+
+        async fn next_order_book(&mut self) -> BrokerResult<Option<OrderBook>> {
+            Ok(None)
+        }
+    }
+
+    fn exchanges() -> (ExchangeId, ExchangeId) {
+        (ExchangeId::from("bybit"), ExchangeId::from("binance"))
+    }
+
+    #[tokio::test]
+    async fn interleaves_sources_fairly() {
+        let (bybit, binance) = exchanges();
+        let mut merged = MergedMarketStream::new([
+            (bybit, FakeStream::new("bybit", "bybit:BTCUSDT", 3)),
+            (binance, FakeStream::new("binance", "binance:BTCUSDT", 3)),
+        ]);
+        let mut order = Vec::new();
+        while let Some(sourced) = merged.next_sourced_tick().await.unwrap() {
+            order.push(sourced.exchange);
+        }
+        assert_eq!(order, vec![bybit, binance, bybit, binance, bybit, binance]);
+        assert_eq!(merged.name(), "merged(bybit,binance)");
+    }
+
+    #[tokio::test]
+    async fn drained_source_does_not_end_merged_stream() {
+        let (bybit, binance) = exchanges();
+        let mut merged = MergedMarketStream::new([
+            (bybit, FakeStream::new("bybit", "bybit:BTCUSDT", 1)),
+            (binance, FakeStream::new("binance", "binance:BTCUSDT", 4)),
+        ]);
+        let mut order = Vec::new();
+        while let Some(sourced) = merged.next_sourced_tick().await.unwrap() {
+            order.push(sourced.exchange);
+        }
+        assert_eq!(order, vec![bybit, binance, binance, binance, binance]);
+    }
+
+    #[tokio::test]
+    async fn failed_source_is_closed_while_others_continue() {
+        let (bybit, binance) = exchanges();
+        let mut failing = FakeStream::new("bybit", "bybit:BTCUSDT", 1);
+        failing.fail_after_drain = true;
+        let mut merged = MergedMarketStream::new([
+            (bybit, failing),
+            (binance, FakeStream::new("binance", "binance:BTCUSDT", 3)),
+        ]);
+
+        assert_eq!(
+            merged.next_tick().await.unwrap().unwrap().symbol.exchange,
+            bybit
+        );
+        assert_eq!(
+            merged.next_tick().await.unwrap().unwrap().symbol.exchange,
+            binance
+        );
+        assert!(merged.next_tick().await.is_err());
+        assert_eq!(merged.active_sources(), 1);
+        let mut remaining = 0;
+        while merged.next_tick().await.unwrap().is_some() {
+            remaining += 1;
+        }
+        assert_eq!(remaining, 2);
+    }
+
+    #[tokio::test]
+    async fn routes_subscriptions_by_exchange() {
+        let (bybit, binance) = exchanges();
+        let mut merged = MergedMarketStream::new([
+            (bybit, FakeStream::new("bybit", "bybit:BTCUSDT", 0)),
+            (binance, FakeStream::new("binance", "binance:BTCUSDT", 0)),
+        ]);
+        merged
+            .subscribe(MergedSubscription {
+                exchange: binance,
+                subscription: "trades".to_string(),
+            })
+            .await
+            .unwrap();
+        assert!(merged.sources[0].stream.subscriptions.is_empty());
+        assert_eq!(merged.sources[1].stream.subscriptions, vec!["trades"]);
+
+        let unknown = MergedSubscription {
+            exchange: ExchangeId::from("okx"),
+            subscription: "trades".to_string(),
+        };
+        assert!(merged.subscribe(unknown).await.is_err());
+    }
+}
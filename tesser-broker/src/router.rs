@@ -179,6 +179,58 @@ impl ExecutionClient for RouterExecutionClient {
             .await
     }
 
+    async fn cancel_orders(
+        &self,
+        orders: Vec<(String, Symbol)>,
+    ) -> BrokerResult<Vec<Result<(), BrokerError>>> {
+        let mut results: Vec<Option<Result<(), BrokerError>>> =
+            (0..orders.len()).map(|_| None).collect();
+        let mut batches: HashMap<ExchangeId, Vec<(usize, String, Symbol)>> = HashMap::new();
+        for (idx, (order_id, _symbol)) in orders.into_iter().enumerate() {
+            match self.translate_internal(&order_id) {
+                Ok(routed) => batches.entry(routed.exchange).or_default().push((
+                    idx,
+                    routed.external_order_id,
+                    routed.symbol,
+                )),
+                Err(err) => results[idx] = Some(Err(err)),
+            }
+        }
+        for (exchange, batch) in batches {
+            let (indices, requests): (Vec<usize>, Vec<(String, Symbol)>) = batch
+                .into_iter()
+                .map(|(idx, order_id, symbol)| (idx, (order_id, symbol)))
+                .unzip();
+            let outcomes = match self.client_for(exchange) {
+                Ok(client) => client.cancel_orders(requests).await,
+                Err(err) => Err(err),
+            };
+            match outcomes {
+                Ok(outcomes) => {
+                    for (idx, outcome) in indices.into_iter().zip(outcomes) {
+                        results[idx] = Some(outcome);
+                    }
+                }
+                Err(err) => {
+                    let message = err.to_string();
+                    for idx in indices {
+                        results[idx] = Some(Err(BrokerError::Other(message.clone())));
+                    }
+                }
+            }
+        }
+        Ok(results
+            .into_iter()
+            .map(|outcome| {
+                outcome.unwrap_or_else(|| {
+                    Err(BrokerError::Other(
+                        "connector returned no cancel outcome".into(),
+                    ))
+                })
+            })
+            .collect())
+    }
+
     async fn amend_order(&self, request: OrderUpdateRequest) -> BrokerResult<Order> {
         let routed = self.translate_internal(&request.order_id)?;
         let client = self.client_for(routed.exchange)?;
@@ -339,6 +391,7 @@ mod tests {
         canceled: Mutex<Vec<(String, Symbol)>>,
         listed: Mutex<Vec<Symbol>>,
         open_orders: Mutex<Vec<Order>>,
+        reject_cancels: bool,
     }
 
     impl TestClient {
@@ -350,6 +403,15 @@ mod tests {
                 canceled: Mutex::new(Vec::new()),
                 listed: Mutex::new(Vec::new()),
                 open_orders: Mutex::new(Vec::new()),
+                reject_cancels: false,
+            }
+        }
+
+        /// Client whose venue answers every cancel with an exchange error.
+        fn rejecting_cancels(exchange: ExchangeId) -> Self {
+            Self {
+                reject_cancels: true,
+                ..Self::new(exchange)
             }
         }
 
@@ -385,6 +447,9 @@ mod tests {
         }
 
         async fn cancel_order(&self, order_id: String, symbol: Symbol) -> BrokerResult<()> {
+            if self.reject_cancels {
+                return Err(BrokerError::Exchange(format!("order {order_id} not found")));
+            }
             self.canceled.lock().unwrap().push((order_id, symbol));
             Ok(())
         }
@@ -582,4 +647,44 @@ mod tests {
         assert_eq!(normalized.order_id, order.id);
         assert_eq!(normalized.symbol, order.request.symbol);
     }
+
+    #[tokio::test]
+    async fn batch_cancel_reports_each_outcome_in_request_order() {
+        let bybit = ExchangeId::from("bybit_linear");
+        let binance = ExchangeId::from("binance_perp");
+        let okx = ExchangeId::from("okx_swap");
+        let client_a = Arc::new(TestClient::new(bybit));
+        let client_b = Arc::new(TestClient::new(binance));
+        let failing = Arc::new(TestClient::rejecting_cancels(okx));
+        let mut routes: HashMap<ExchangeId, Arc<dyn ExecutionClient>> = HashMap::new();
+        routes.insert(bybit, client_a.clone());
+        routes.insert(binance, client_b.clone());
+        routes.insert(okx, failing.clone());
+        let router = RouterExecutionClient::new(routes);
+
+        let symbol_a = Symbol::from_code(bybit, "BTCUSDT");
+        let symbol_b = Symbol::from_code(binance, "BTCUSDT");
+        let symbol_c = Symbol::from_code(okx, "BTCUSDT");
+        let first = router.place_order(sample_request(symbol_a)).await.unwrap();
+        let rejected = router.place_order(sample_request(symbol_c)).await.unwrap();
+        let other = router.place_order(sample_request(symbol_b)).await.unwrap();
+
+        let results = router
+            .cancel_orders(vec![
+                (first.id, symbol_a),
+                ("missing".into(), symbol_a),
+                (rejected.id, symbol_c),
+                (other.id, symbol_b),
+            ])
+            .await
+            .expect("batch cancel");
+        assert_eq!(results.len(), 4);
+        assert!(results[0].is_ok());
+        assert!(matches!(results[1], Err(BrokerError::InvalidRequest(_))));
+        assert!(matches!(results[2], Err(BrokerError::Exchange(_))));
+        assert!(results[3].is_ok());
+        assert_eq!(client_a.canceled.lock().unwrap().len(), 1);
+        assert_eq!(client_b.canceled.lock().unwrap().len(), 1);
+        assert!(failing.canceled.lock().unwrap().is_empty());
+    }
 }
@@ -10,6 +10,8 @@ use tokio::sync::Mutex;
 use tokio::time::interval;
 use tracing::{error, warn};
 
+use crate::live::OmsHandle;
+
 #[derive(Clone)]
 pub struct AlertDispatcher {
     client: Client,
@@ -49,6 +51,49 @@ struct AlertState {
     checksum_alerts: HashMap<String, Instant>,
 }
 
+/// Change in the market-data gap state observed by one watchdog pass.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum DataGapTransition {
+    /// No data arrived within the configured window.
+    Stalled,
+    /// Data arrived again after a reported stall.
+    Resumed,
+    Unchanged,
+}
+
+impl AlertState {
+    fn new(now: Instant) -> Self {
+        Self {
+            last_data: now,
+            consecutive_failures: 0,
+            peak_equity: Decimal::ZERO,
+            drawdown_triggered: false,
+            data_gap_triggered: false,
+            last_public_connection: now,
+            last_private_connection: now,
+            public_alerted: false,
+            private_alerted: false,
+            checksum_alerts: HashMap::new(),
+        }
+    }
+
+    /// Compare the last heartbeat against `now`, reporting only state changes.
+    fn data_gap_transition(&mut self, now: Instant, window: Duration) -> DataGapTransition {
+        let stalled = now.saturating_duration_since(self.last_data) >= window;
+        match (self.data_gap_triggered, stalled) {
+            (false, true) => {
+                self.data_gap_triggered = true;
+                DataGapTransition::Stalled
+            }
+            (true, false) => {
+                self.data_gap_triggered = false;
+                DataGapTransition::Resumed
+            }
+            _ => DataGapTransition::Unchanged,
+        }
+    }
+}
+
 pub struct AlertManager {
     config: AlertingConfig,
     dispatcher: AlertDispatcher,
@@ -64,22 +109,10 @@ impl AlertManager {
         public_connection: Option<Arc<std::sync::atomic::AtomicBool>>,
         private_connection: Option<Arc<std::sync::atomic::AtomicBool>>,
     ) -> Self {
-        let state = AlertState {
-            last_data: Instant::now(),
-            consecutive_failures: 0,
-            peak_equity: Decimal::ZERO,
-            drawdown_triggered: false,
-            data_gap_triggered: false,
-            last_public_connection: Instant::now(),
-            last_private_connection: Instant::now(),
-            public_alerted: false,
-            private_alerted: false,
-            checksum_alerts: HashMap::new(),
-        };
         Self {
             config,
             dispatcher,
-            state: Arc::new(Mutex::new(state)),
+            state: Arc::new(Mutex::new(AlertState::new(Instant::now()))),
             public_connection,
             private_connection,
         }
@@ -88,7 +121,6 @@ impl AlertManager {
     pub async fn heartbeat(&self) {
         let mut state = self.state.lock().await;
         state.last_data = Instant::now();
-        state.data_gap_triggered = false;
     }
 
     pub async fn order_failure(&self, reason: &str) {
@@ -178,7 +210,12 @@ impl AlertManager {
         }
     }
 
-    pub fn spawn_watchdog(&self) -> Option<tokio::task::JoinHandle<()>> {
+    /// Watch connection flags and the market-data heartbeat.
+    ///
+    /// When no data arrives for `max_data_gap_secs` an alert is raised and, if
+    /// `data_gap_liquidate_only` is set, `oms` is switched to liquidate-only mode. A follow-up
+    /// alert reports when data resumes.
+    pub fn spawn_watchdog(&self, oms: OmsHandle) -> Option<tokio::task::JoinHandle<()>> {
         let threshold = self.config.max_data_gap_secs;
         if threshold == 0 {
             return None;
@@ -187,9 +224,11 @@ impl AlertManager {
         let state = self.state.clone();
         let public_connection = self.public_connection.clone();
         let private_connection = self.private_connection.clone();
+        let liquidate_via = self.config.data_gap_liquidate_only.then_some(oms);
         let period = Duration::from_secs(threshold);
         Some(tokio::spawn(async move {
-            let mut ticker = interval(Duration::from_secs(30));
+            let poll = (period / 4).clamp(Duration::from_secs(1), Duration::from_secs(30));
+            let mut ticker = interval(poll);
             loop {
                 ticker.tick().await;
                 let mut guard = state.lock().await;
@@ -232,12 +271,24 @@ impl AlertManager {
                         guard = state.lock().await;
                     }
                 }
-                if guard.last_data.elapsed() >= period && !guard.data_gap_triggered {
-                    guard.data_gap_triggered = true;
-                    drop(guard);
-                    dispatcher
-                        .notify("Market data stalled", "No heartbeat in configured window")
-                        .await;
+                let transition = guard.data_gap_transition(now, period);
+                drop(guard);
+                match transition {
+                    DataGapTransition::Stalled => {
+                        let mut message = format!("No market data received for {threshold}s");
+                        if let Some(oms) = &liquidate_via {
+                            if oms.enter_liquidate_only().await {
+                                message.push_str("; entered liquidate-only mode");
+                            }
+                        }
+                        dispatcher.notify("Market data stalled", &message).await;
+                    }
+                    DataGapTransition::Resumed => {
+                        dispatcher
+                            .notify("Market data resumed", "Market data is flowing again")
+                            .await;
+                    }
+                    DataGapTransition::Unchanged => {}
                 }
             }
         }))
@@ -254,3 +305,51 @@ pub fn sanitize_webhook(input: Option<String>) -> Option<String> {
         }
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WINDOW: Duration = Duration::from_secs(60);
+
+    #[test]
+    fn stalled_data_raises_once() {
+        let start = Instant::now();
+        let mut state = AlertState::new(start);
+        assert_eq!(
+            state.data_gap_transition(start + Duration::from_secs(30), WINDOW),
+            DataGapTransition::Unchanged
+        );
+        assert_eq!(
+            state.data_gap_transition(start + Duration::from_secs(90), WINDOW),
+            DataGapTransition::Stalled
+        );
+        assert_eq!(
+            state.data_gap_transition(start + Duration::from_secs(120), WINDOW),
+            DataGapTransition::Unchanged
+        );
+    }
+
+    #[test]
+    fn fresh_data_clears_the_gap() {
+        let start = Instant::now();
+        let mut state = AlertState::new(start);
+        assert_eq!(
+            state.data_gap_transition(start + Duration::from_secs(100), WINDOW),
+            DataGapTransition::Stalled
+        );
+        state.last_data = start + Duration::from_secs(95);
+        assert_eq!(
+            state.data_gap_transition(start + Duration::from_secs(100), WINDOW),
+            DataGapTransition::Resumed
+        );
+        assert_eq!(
+            state.data_gap_transition(start + Duration::from_secs(110), WINDOW),
+            DataGapTransition::Unchanged
+        );
+        assert_eq!(
+            state.data_gap_transition(start + Duration::from_secs(200), WINDOW),
+            DataGapTransition::Stalled
+        );
+    }
+}
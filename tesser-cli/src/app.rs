@@ -36,32 +36,38 @@ use tesser_backtester::{
     MarketEventKind, MarketEventStream,
 };
 use tesser_broker::{ExecutionClient, RouterExecutionClient};
-use tesser_config::{load_config, AppConfig, PersistenceEngine, RiskManagementConfig};
+use tesser_config::{
+    load_config, AppConfig, LiveRuntimeConfig, MinNotionalPolicy as ConfigMinNotionalPolicy,
+    PersistenceEngine, RiskManagementConfig,
+};
 use tesser_core::{
     AssetId, Candle, DepthUpdate, ExchangeId, Interval, OrderBook, OrderBookLevel, Side, Symbol,
     Tick,
 };
 use tesser_data::analytics::ExecutionAnalysisRequest;
 use tesser_data::download::{
-    BinanceDownloader, BybitDownloader, KlineRequest, NormalizedTrade, TradeRequest, TradeSource,
+    BinanceDownloader, BybitDownloader, CancellationToken, KlineRequest, NormalizedTrade,
+    TradeRequest, TradeSource,
 };
 use tesser_data::etl::{
-    MappingConfig as EtlMappingConfig, Partitioning as EtlPartitioning, Pipeline as EtlPipeline,
+    fill_lake_gaps, partition_stats, ticks_to_candle_lake, CandleLakeWriter,
+    MappingConfig as EtlMappingConfig, PartitionStats, Partitioning as EtlPartitioning,
+    Pipeline as EtlPipeline, TickLakeWriter,
 };
 use tesser_data::io::{self, DatasetFormat as IoDatasetFormat, StreamingTicksWriter};
 use tesser_data::merger::UnifiedEventStream;
 use tesser_data::parquet::ParquetMarketStream;
 use tesser_data::transform::Resampler;
 use tesser_execution::{
-    ExecutionEngine, FixedOrderSizer, NoopRiskChecker, OrderSizer, PanicCloseConfig,
-    PanicCloseMode, PortfolioPercentSizer, RiskAdjustedSizer,
+    ExecutionEngine, FixedOrderSizer, MinNotionalPolicy, NoopRiskChecker, OrderMappingPolicy,
+    OrderSizer, PanicCloseConfig, PanicCloseMode, PortfolioPercentSizer, RiskAdjustedSizer,
 };
 use tesser_markets::MarketRegistry;
 use tesser_paper::{
     FeeModel, FeeScheduleConfig, MatchingEngine, MatchingEngineConfig, PaperExecutionClient,
     PaperMarketStream, QueueModel,
 };
-use tesser_strategy::{builtin_strategy_names, load_strategy};
+use tesser_strategy::{builtin_strategy_descriptors, load_strategy};
 use tracing::{info, warn};
 
 #[derive(Parser)]
@@ -125,6 +131,10 @@ pub enum DataCommand {
     InspectParquet(DataInspectParquetArgs),
     /// Normalize raw data into the canonical schema
     Normalize(DataNormalizeArgs),
+    /// Summarize row counts, time range and null counts of parquet partitions
+    Stats(DataStatsArgs),
+    /// Aggregate a tick parquet lake into canonical candle partitions
+    TicksToCandles(DataTicksToCandlesArgs),
 }
 
 #[derive(Subcommand)]
@@ -181,13 +191,22 @@ pub struct DataDownloadArgs {
     /// Allowed divergence between primary and reference closes (fractional)
     #[arg(long, default_value_t = 0.002)]
     validation_reference_tolerance: f64,
+    /// Stream candles page by page into the canonical parquet lake under DIR (skips validation)
+    #[arg(long, value_name = "DIR", conflicts_with = "output")]
+    lake_dir: Option<PathBuf>,
+    /// Partitioning strategy used with `--lake-dir`
+    #[arg(long, value_enum, default_value = "daily")]
+    lake_partition: DataPartitionArg,
+    /// Only download bars missing from `--lake-dir` for this symbol/interval
+    #[arg(long, requires = "lake_dir")]
+    fill_gaps: bool,
 }
 
 #[derive(Args)]
 #[command(group(
     ArgGroup::new("tick_output_mode")
         .required(true)
-        .args(["partition_by_day", "no_partition"])
+        .args(["partition_by_day", "no_partition", "lake_dir"])
 ))]
 pub struct DataDownloadTradesArgs {
     #[arg(long, default_value = "bybit")]
@@ -225,6 +244,13 @@ pub struct DataDownloadTradesArgs {
     /// Legacy output path (directory for `--partition-by-day`, file for `--no-partition`)
     #[arg(long, hide = true)]
     output: Option<PathBuf>,
+    /// Stream trades day by day into a tick lake under DIR, skipping trades already stored
+    #[arg(
+        long,
+        value_name = "DIR",
+        conflicts_with_all = ["output", "output_dir", "output_file"]
+    )]
+    lake_dir: Option<PathBuf>,
     /// Per-request REST chunk size (max 1000)
     #[arg(long, default_value_t = 1000)]
     limit: usize,
@@ -346,9 +372,24 @@ pub struct MonitorArgs {
     /// Control plane address (overrides config.live.control_addr)
     #[arg(long)]
     control_addr: Option<String>,
+    /// File holding the control plane bearer token (overrides config.live.control_token_file)
+    #[arg(long)]
+    control_token_file: Option<PathBuf>,
     /// UI refresh rate in milliseconds
     #[arg(long, default_value_t = 250)]
     tick_rate: u64,
+    /// Initial delay before reconnecting to the control plane, in milliseconds
+    #[arg(long, default_value_t = 250)]
+    reconnect_backoff_ms: u64,
+    /// Upper bound for the doubling reconnect delay, in milliseconds
+    #[arg(long, default_value_t = 5_000)]
+    reconnect_max_backoff_ms: u64,
+    /// Consecutive failed attempts before giving up
+    #[arg(long, default_value_t = 30)]
+    reconnect_max_attempts: u32,
+    /// Keep reconnecting indefinitely (ignores --reconnect-max-attempts)
+    #[arg(long)]
+    reconnect_forever: bool,
 }
 
 impl StateInspectArgs {
@@ -370,7 +411,15 @@ impl MonitorArgs {
             .clone()
             .unwrap_or_else(|| config.live.control_addr.clone());
         let refresh = self.tick_rate.max(50);
-        let monitor_config = tui::MonitorConfig::new(addr, StdDuration::from_millis(refresh));
+        let reconnect = tui::ReconnectPolicy {
+            initial_backoff: StdDuration::from_millis(self.reconnect_backoff_ms.max(1)),
+            max_backoff: StdDuration::from_millis(self.reconnect_max_backoff_ms),
+            max_attempts: (!self.reconnect_forever).then_some(self.reconnect_max_attempts.max(1)),
+        };
+        let token = resolve_control_token(self.control_token_file.as_deref(), &config.live)?;
+        let monitor_config = tui::MonitorConfig::new(addr, StdDuration::from_millis(refresh))
+            .with_reconnect(reconnect)
+            .with_control_token(token);
         tui::run_monitor(monitor_config).await
     }
 }
@@ -408,14 +457,26 @@ impl DataDownloadArgs {
         if start >= end {
             return Err(anyhow!("start time must be earlier than end time"));
         }
+        if let Some(lake_dir) = &self.lake_dir {
+            if self.fill_gaps {
+                return self
+                    .download_lake_gaps(exchange_cfg, lake_dir, interval, start, end)
+                    .await;
+            }
+            return self
+                .download_to_lake(exchange_cfg, lake_dir, interval, start, end)
+                .await;
+        }
 
         info!(
             "Downloading {} candles for {} ({})",
             self.interval, self.symbol, self.exchange
         );
+        let cancel = ctrl_c_token();
         let mut candles = match exchange_cfg.driver.as_str() {
             "bybit" | "" => {
-                let downloader = BybitDownloader::new(&exchange_cfg.rest_url);
+                let downloader =
+                    BybitDownloader::new(&exchange_cfg.rest_url)?.with_cancellation(cancel);
                 let request = KlineRequest::new(&self.category, &self.symbol, interval, start, end);
                 downloader
                     .download_klines(&request)
@@ -423,7 +484,8 @@ impl DataDownloadArgs {
                     .with_context(|| "failed to download candles from Bybit")?
             }
             "binance" => {
-                let downloader = BinanceDownloader::new(&exchange_cfg.rest_url);
+                let downloader =
+                    BinanceDownloader::new(&exchange_cfg.rest_url)?.with_cancellation(cancel);
                 let request = KlineRequest::new("", &self.symbol, interval, start, end);
                 downloader
                     .download_klines(&request)
@@ -467,6 +529,96 @@ impl DataDownloadArgs {
         );
         Ok(())
     }
+
+    async fn download_to_lake(
+        &self,
+        exchange_cfg: &tesser_config::ExchangeConfig,
+        lake_dir: &Path,
+        interval: Interval,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<()> {
+        info!(
+            "Streaming {} candles for {} ({}) into {}",
+            self.interval,
+            self.symbol,
+            self.exchange,
+            lake_dir.display()
+        );
+        let cancel = ctrl_c_token();
+        let mut writer = CandleLakeWriter::new(lake_dir, self.lake_partition.into());
+        match exchange_cfg.driver.as_str() {
+            "bybit" | "" => {
+                let downloader =
+                    BybitDownloader::new(&exchange_cfg.rest_url)?.with_cancellation(cancel);
+                let request = KlineRequest::new(&self.category, &self.symbol, interval, start, end);
+                downloader
+                    .stream_klines(&request, &mut writer)
+                    .await
+                    .with_context(|| "failed to download candles from Bybit")?;
+            }
+            "binance" => {
+                let downloader =
+                    BinanceDownloader::new(&exchange_cfg.rest_url)?.with_cancellation(cancel);
+                let request = KlineRequest::new("", &self.symbol, interval, start, end);
+                downloader
+                    .stream_klines(&request, &mut writer)
+                    .await
+                    .with_context(|| "failed to download candles from Binance")?;
+            }
+            other => bail!("unknown exchange driver '{other}' for {}", self.exchange),
+        }
+        let rows = writer.finish()?;
+        info!("Wrote {} candles under {}", rows, lake_dir.display());
+        Ok(())
+    }
+
+    async fn download_lake_gaps(
+        &self,
+        exchange_cfg: &tesser_config::ExchangeConfig,
+        lake_dir: &Path,
+        interval: Interval,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<()> {
+        let cancel = ctrl_c_token();
+        let partitioning = self.lake_partition.into();
+        let outcome = match exchange_cfg.driver.as_str() {
+            "bybit" | "" => {
+                let downloader =
+                    BybitDownloader::new(&exchange_cfg.rest_url)?.with_cancellation(cancel);
+                let request = KlineRequest::new(&self.category, &self.symbol, interval, start, end);
+                fill_lake_gaps(&downloader, lake_dir, &request, partitioning)
+                    .await
+                    .with_context(|| "failed to fill candle gaps from Bybit")?
+            }
+            "binance" => {
+                let downloader =
+                    BinanceDownloader::new(&exchange_cfg.rest_url)?.with_cancellation(cancel);
+                let request = KlineRequest::new("", &self.symbol, interval, start, end);
+                fill_lake_gaps(&downloader, lake_dir, &request, partitioning)
+                    .await
+                    .with_context(|| "failed to fill candle gaps from Binance")?
+            }
+            other => bail!("unknown exchange driver '{other}' for {}", self.exchange),
+        };
+        if outcome.gaps.is_empty() {
+            info!(
+                "No gaps found for {} {} under {}",
+                self.symbol,
+                self.interval,
+                lake_dir.display()
+            );
+            return Ok(());
+        }
+        info!(
+            "Filled {} gap(s) with {} candles under {}",
+            outcome.gaps.len(),
+            outcome.rows_written,
+            lake_dir.display()
+        );
+        Ok(())
+    }
 }
 
 impl DataDownloadTradesArgs {
@@ -530,7 +682,10 @@ impl DataDownloadTradesArgs {
             other => bail!("unknown exchange driver '{other}' for {}", self.exchange),
         }
 
-        if self.partition_by_day {
+        if let Some(lake_dir) = &self.lake_dir {
+            self.download_to_lake(exchange_cfg, lake_dir, base_request)
+                .await
+        } else if self.partition_by_day {
             self.download_partitioned(config, exchange_cfg, base_request, start, end)
                 .await
         } else if self.no_partition {
@@ -750,22 +905,55 @@ impl DataDownloadTradesArgs {
         Ok(())
     }
 
+    async fn download_to_lake(
+        &self,
+        exchange_cfg: &tesser_config::ExchangeConfig,
+        lake_dir: &Path,
+        request: TradeRequest<'_>,
+    ) -> Result<()> {
+        let cancel = ctrl_c_token();
+        let mut writer = TickLakeWriter::new(lake_dir);
+        match exchange_cfg.driver.as_str() {
+            "bybit" | "" => {
+                let downloader =
+                    BybitDownloader::new(&exchange_cfg.rest_url)?.with_cancellation(cancel);
+                downloader
+                    .stream_trades(&request, &mut writer)
+                    .await
+                    .with_context(|| "failed to download trades from Bybit")?;
+            }
+            "binance" => {
+                let downloader =
+                    BinanceDownloader::new(&exchange_cfg.rest_url)?.with_cancellation(cancel);
+                downloader
+                    .stream_trades(&request, &mut writer)
+                    .await
+                    .with_context(|| "failed to download trades from Binance")?;
+            }
+            other => bail!("unknown exchange driver '{other}' for {}", self.exchange),
+        }
+        let rows = writer.finish()?;
+        info!("Wrote {} raw trades under {}", rows, lake_dir.display());
+        Ok(())
+    }
+
     async fn fetch_trades(
         &self,
         driver: &str,
         rest_url: &str,
         request: TradeRequest<'_>,
     ) -> Result<Vec<NormalizedTrade>> {
+        let cancel = ctrl_c_token();
         match driver {
             "bybit" | "" => {
-                let downloader = BybitDownloader::new(rest_url);
+                let downloader = BybitDownloader::new(rest_url)?.with_cancellation(cancel);
                 downloader
                     .download_trades(&request)
                     .await
                     .with_context(|| "failed to download trades from Bybit")
             }
             "binance" => {
-                let downloader = BinanceDownloader::new(rest_url);
+                let downloader = BinanceDownloader::new(rest_url)?.with_cancellation(cancel);
                 downloader
                     .download_trades(&request)
                     .await
@@ -797,6 +985,110 @@ impl DataNormalizeArgs {
     }
 }
 
+#[derive(Args)]
+pub struct DataStatsArgs {
+    /// Parquet files or lake directories (searched recursively for *.parquet)
+    #[arg(value_name = "PATH", num_args = 1.., required = true)]
+    paths: Vec<PathBuf>,
+}
+
+impl DataStatsArgs {
+    fn run(&self) -> Result<()> {
+        let mut files = Vec::new();
+        for path in &self.paths {
+            collect_parquet_files(path, &mut files)?;
+        }
+        if files.is_empty() {
+            bail!("no parquet files found under the provided paths");
+        }
+        files.sort();
+        let stats = partition_stats(&files)?;
+        print_partition_stats(&stats);
+        Ok(())
+    }
+}
+
+#[derive(Args)]
+pub struct DataTicksToCandlesArgs {
+    /// Tick parquet file or lake directory (searched recursively for *.parquet)
+    #[arg(long)]
+    input: PathBuf,
+    /// Output directory for canonical candle partitions
+    #[arg(long)]
+    output: PathBuf,
+    /// Candle interval to produce; repeat to build several in one pass
+    #[arg(long = "interval", num_args = 1.., required = true)]
+    intervals: Vec<String>,
+    /// Partitioning strategy (daily or monthly)
+    #[arg(long, value_enum, default_value = "daily")]
+    partition: DataPartitionArg,
+}
+
+impl DataTicksToCandlesArgs {
+    async fn run(&self) -> Result<()> {
+        let intervals = self
+            .intervals
+            .iter()
+            .map(|value| value.parse().map_err(|err: String| anyhow!(err)))
+            .collect::<Result<Vec<Interval>>>()?;
+        let mut files = Vec::new();
+        collect_parquet_files(&self.input, &mut files)?;
+        if files.is_empty() {
+            bail!("no parquet files found under {}", self.input.display());
+        }
+        files.sort();
+        let summary =
+            ticks_to_candle_lake(files, &self.output, &intervals, self.partition.into()).await?;
+        info!(
+            ticks = summary.ticks,
+            candles = summary.candles,
+            output = %self.output.display(),
+            "tick lake converted to candles"
+        );
+        Ok(())
+    }
+}
+
+fn collect_parquet_files(path: &Path, files: &mut Vec<PathBuf>) -> Result<()> {
+    if !path.is_dir() {
+        files.push(path.to_path_buf());
+        return Ok(());
+    }
+    for entry in fs::read_dir(path).with_context(|| format!("failed to read {}", path.display()))? {
+        let entry = entry?.path();
+        if entry.is_dir() {
+            collect_parquet_files(&entry, files)?;
+        } else if entry.extension().and_then(|ext| ext.to_str()) == Some("parquet") {
+            files.push(entry);
+        }
+    }
+    Ok(())
+}
+
+fn print_partition_stats(stats: &PartitionStats) {
+    let join = |values: &std::collections::BTreeSet<String>| {
+        if values.is_empty() {
+            "-".to_string()
+        } else {
+            values.iter().cloned().collect::<Vec<_>>().join(", ")
+        }
+    };
+    println!("Partition summary ({} file(s))", stats.files);
+    println!("  Rows: {}", stats.rows);
+    match (stats.first_timestamp, stats.last_timestamp) {
+        (Some(first), Some(last)) => {
+            println!("  Range: {} -> {}", first.to_rfc3339(), last.to_rfc3339())
+        }
+        _ => println!("  Range: -"),
+    }
+    println!("  Symbols: {}", join(&stats.symbols));
+    println!("  Intervals: {}", join(&stats.intervals));
+    println!("  Null counts:");
+    for (column, nulls) in &stats.null_counts {
+        println!("    {column}: {nulls}");
+    }
+}
+
 impl From<DataPartitionArg> for EtlPartitioning {
     fn from(value: DataPartitionArg) -> Self {
         match value {
@@ -1083,8 +1375,17 @@ pub struct BacktestRunArgs {
     /// Queue modeling assumption used when simulating passive fills
     #[arg(long = "sim-queue-model", value_enum, default_value = "conserv")]
     sim_queue_model: QueueModelArg,
+    /// Fill resting limits against order book depth that trades through them, not only trade prints
+    #[arg(long = "sim-depth-fills")]
+    sim_depth_fills: bool,
     #[arg(long)]
     markets_file: Option<PathBuf>,
+    /// Write the final strategy context (recent candles/ticks/positions) as JSON for debugging
+    #[arg(long = "dump-context", value_name = "PATH")]
+    dump_context: Option<PathBuf>,
+    /// Export every simulated trade to CSV, or parquet when the path ends in `.parquet`
+    #[arg(long = "trade-log", value_name = "PATH")]
+    trade_log: Option<PathBuf>,
 }
 
 enum LobSource {
@@ -1149,6 +1450,9 @@ pub struct LiveRunArgs {
         alias = "live-exec"
     )]
     exec: ExecutionBackend,
+    /// Stream live market data but simulate fills with the paper engine (overrides `--exec`)
+    #[arg(long)]
+    dry_run: bool,
     /// Path to persisted state (file for sqlite, directory for lmdb)
     #[arg(long)]
     state_path: Option<PathBuf>,
@@ -1169,6 +1473,10 @@ pub struct LiveRunArgs {
     /// Control plane gRPC bind address (overrides config.live.control_addr)
     #[arg(long)]
     control_addr: Option<String>,
+    /// File holding the bearer token required by the control plane
+    /// (overrides config.live.control_token_file)
+    #[arg(long)]
+    control_token_file: Option<PathBuf>,
     #[arg(long)]
     initial_equity: Option<Decimal>,
     #[arg(long)]
@@ -1194,6 +1502,8 @@ pub struct LiveRunArgs {
     #[arg(long)]
     alert_max_drawdown: Option<Decimal>,
     #[arg(long)]
+    alert_data_gap_liquidate_only: bool,
+    #[arg(long)]
     risk_max_order_qty: Option<Decimal>,
     #[arg(long)]
     risk_max_order_notional: Option<Decimal>,
@@ -1216,6 +1526,12 @@ pub struct LiveRunArgs {
     /// Directory containing compiled WASM execution plugins.
     #[arg(long = "plugins-dir")]
     plugins_dir: Option<PathBuf>,
+    /// Build candles locally from trade ticks (for venues that do not stream klines)
+    #[arg(long)]
+    candles_from_ticks: bool,
+    /// Candle datasets (CSV/parquet) replayed into the strategy before live trading starts
+    #[arg(long = "warmup-data", value_name = "PATH", num_args = 0.., action = clap::ArgAction::Append)]
+    warmup_data: Vec<PathBuf>,
 }
 
 impl LiveRunArgs {
@@ -1225,6 +1541,14 @@ impl LiveRunArgs {
             .unwrap_or_else(|| config.live.log_path.clone())
     }
 
+    fn resolved_exec_backend(&self, config: &AppConfig) -> ExecutionBackend {
+        if self.dry_run || config.live.dry_run {
+            ExecutionBackend::Paper
+        } else {
+            self.exec
+        }
+    }
+
     fn resolved_metrics_addr(&self, config: &AppConfig) -> Result<SocketAddr> {
         let addr = self
             .metrics_addr
@@ -1287,6 +1611,9 @@ impl LiveRunArgs {
         if let Some(limit) = self.alert_max_drawdown {
             alerting.max_drawdown = limit.max(Decimal::ZERO);
         }
+        if self.alert_data_gap_liquidate_only {
+            alerting.data_gap_liquidate_only = true;
+        }
         alerting
     }
 
@@ -1314,6 +1641,12 @@ struct StrategyConfigFile {
     name: String,
     #[serde(default = "empty_table")]
     params: toml::Value,
+    /// Order type used per signal kind for plain signals (market by default).
+    #[serde(default)]
+    order_mapping: OrderMappingPolicy,
+    /// Seconds to suppress new entries on a symbol after one of its stop-losses fills.
+    #[serde(default)]
+    cooldown_after_stop_secs: Option<u64>,
 }
 
 fn empty_table() -> toml::Value {
@@ -1379,6 +1712,12 @@ async fn handle_data(cmd: DataCommand, config: &AppConfig) -> Result<()> {
         DataCommand::Normalize(args) => {
             args.run()?;
         }
+        DataCommand::Stats(args) => {
+            args.run()?;
+        }
+        DataCommand::TicksToCandles(args) => {
+            args.run().await?;
+        }
     }
     Ok(())
 }
@@ -1477,6 +1816,7 @@ impl BacktestRunArgs {
                         queue_model: self.sim_queue_model.into(),
                         fee_model: fee_model.clone(),
                         cash_asset: Some(reporting_currency),
+                        depth_fills: self.sim_depth_fills,
                     },
                 ));
                 let stream = match source {
@@ -1507,6 +1847,9 @@ impl BacktestRunArgs {
         cfg.execution.fee_bps = self.fee_bps.max(Decimal::ZERO);
         cfg.execution.latency_candles = self.latency_candles.max(1);
         cfg.mode = mode;
+        cfg.context_dump = self.dump_context.clone();
+        cfg.trade_log = self.trade_log.clone();
+        cfg.order_mapping = def.order_mapping;
 
         let report = Backtester::new(
             cfg,
@@ -1660,6 +2003,7 @@ impl BacktestBatchArgs {
             cfg.execution.slippage_bps = self.slippage_bps.max(Decimal::ZERO);
             cfg.execution.fee_bps = self.fee_bps.max(Decimal::ZERO);
             cfg.execution.latency_candles = self.latency_candles.max(1);
+            cfg.order_mapping = def.order_mapping;
 
             let report = Backtester::new(
                 cfg,
@@ -1761,6 +2105,27 @@ async fn build_paper_client_for_exchange(
     exec
 }
 
+/// Resolve the control-plane token: `file` first, then `live.control_token_file`, then
+/// `live.control_token` (which `TESSER_LIVE__CONTROL_TOKEN` can set). The token never comes
+/// from a command-line argument, where other users could read it from the process list.
+fn resolve_control_token(file: Option<&Path>, live: &LiveRuntimeConfig) -> Result<Option<String>> {
+    let token = match file.or(live.control_token_file.as_deref()) {
+        Some(path) => {
+            let raw = fs::read_to_string(path)
+                .with_context(|| format!("failed to read control token from {}", path.display()))?;
+            Some(raw.trim().to_string())
+        }
+        None => live.control_token.clone(),
+    };
+    if token
+        .as_deref()
+        .is_some_and(|token| token.trim().is_empty())
+    {
+        bail!("control token must not be empty");
+    }
+    Ok(token)
+}
+
 fn cash_asset_for_exchange(reporting: AssetId, exchange: ExchangeId) -> AssetId {
     if reporting.exchange.is_specified() {
         if reporting.exchange == exchange {
@@ -1798,6 +2163,46 @@ fn initial_balance_for_asset(balances: &HashMap<AssetId, Decimal>, target: Asset
 #[cfg(test)]
 mod tests {
     use super::*;
+    use tesser_core::CandleStatus;
+
+    fn live_config(token: Option<&str>, file: Option<PathBuf>) -> LiveRuntimeConfig {
+        LiveRuntimeConfig {
+            control_token: token.map(str::to_string),
+            control_token_file: file,
+            ..LiveRuntimeConfig::default()
+        }
+    }
+
+    #[test]
+    fn control_token_file_takes_precedence_and_empty_tokens_fail() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("token");
+        fs::write(&path, "from-file\n").unwrap();
+
+        let live = live_config(Some("inline"), Some(path.clone()));
+        assert_eq!(
+            resolve_control_token(None, &live).unwrap().as_deref(),
+            Some("from-file")
+        );
+        let live = live_config(Some("inline"), None);
+        assert_eq!(
+            resolve_control_token(None, &live).unwrap().as_deref(),
+            Some("inline")
+        );
+        assert_eq!(
+            resolve_control_token(Some(&path), &live)
+                .unwrap()
+                .as_deref(),
+            Some("from-file")
+        );
+        assert!(resolve_control_token(None, &live_config(None, None))
+            .unwrap()
+            .is_none());
+
+        fs::write(&path, "  \n").unwrap();
+        assert!(resolve_control_token(Some(&path), &live).is_err());
+        assert!(resolve_control_token(None, &live_config(Some(""), None)).is_err());
+    }
 
     #[test]
     fn initial_balance_prefers_exchange_specific_entry() {
@@ -1902,6 +2307,16 @@ impl LiveRunArgs {
             .clone()
             .or_else(|| config.live.plugins_dir.clone());
 
+        let exec_backend = self.resolved_exec_backend(config);
+        if exec_backend != self.exec {
+            warn!("dry-run enabled; orders will be simulated instead of sent to the exchange");
+        }
+
+        let risk = self.build_risk_config(config);
+        let max_corrective_quantity = config
+            .live
+            .max_corrective_quantity
+            .unwrap_or(risk.max_order_quantity);
         let settings = LiveSessionSettings {
             category,
             interval,
@@ -1915,15 +2330,33 @@ impl LiveRunArgs {
             reporting_currency,
             markets_file,
             alerting,
-            exec_backend: self.exec,
-            risk: self.build_risk_config(config),
+            exec_backend,
+            risk,
             reconciliation_interval,
             reconciliation_threshold,
+            reconciliation_asset_thresholds: config.live.reconciliation_asset_thresholds.clone(),
+            auto_correct_positions: config.live.auto_correct_positions,
+            max_corrective_quantity,
             orderbook_depth,
             record_path: Some(self.record_data.clone()),
             control_addr,
+            control_token: resolve_control_token(self.control_token_file.as_deref(), &config.live)?,
             panic_close,
             plugins_dir,
+            candles_from_ticks: self.candles_from_ticks,
+            tick_filter: config.live.tick_filter.clone(),
+            warmup_paths: self.warmup_data.clone(),
+            order_mapping: def.order_mapping,
+            cooldown_after_stop: def
+                .cooldown_after_stop_secs
+                .filter(|secs| *secs > 0)
+                .and_then(|secs| i64::try_from(secs).ok())
+                .and_then(chrono::Duration::try_seconds),
+            min_notional_policy: match config.live.min_notional_policy {
+                ConfigMinNotionalPolicy::Drop => MinNotionalPolicy::Drop,
+                ConfigMinNotionalPolicy::ScaleUp => MinNotionalPolicy::ScaleUp,
+            },
+            event_log_dir: config.live.event_log_dir.clone(),
         };
 
         let exchange_labels: Vec<String> = named_exchanges
@@ -1936,7 +2369,7 @@ impl LiveRunArgs {
             symbols = ?symbols,
             exchanges = ?exchange_labels,
             interval = %self.interval,
-            exec = ?self.exec,
+            exec = ?settings.exec_backend,
             persistence_engine = ?settings.persistence.engine,
             state_path = %settings.persistence.state_path.display(),
             control_addr = %settings.control_addr,
@@ -1951,8 +2384,15 @@ impl LiveRunArgs {
 
 fn list_strategies() {
     println!("Built-in strategies:");
-    for name in builtin_strategy_names() {
-        println!("- {name}");
+    for descriptor in builtin_strategy_descriptors() {
+        let mut line = format!("- {}", descriptor.name);
+        if !descriptor.tags.is_empty() {
+            line.push_str(&format!(" [{}]", descriptor.tags.join(", ")));
+        }
+        if !descriptor.description.is_empty() {
+            line.push_str(&format!(": {}", descriptor.description));
+        }
+        println!("{line}");
     }
 }
 
@@ -2059,11 +2499,24 @@ fn synth_candles(symbol: Symbol, len: usize, offset_minutes: i64) -> Vec<Candle>
             volume: Decimal::ONE,
             timestamp: Utc::now() - Duration::minutes((len - i) as i64)
                 + Duration::minutes(offset_minutes),
+            status: CandleStatus::Closed,
         });
     }
     candles
 }
 
+/// Returns a token that is cancelled once the user presses Ctrl-C.
+fn ctrl_c_token() -> CancellationToken {
+    let token = CancellationToken::new();
+    let trigger = token.clone();
+    tokio::spawn(async move {
+        if tokio::signal::ctrl_c().await.is_ok() {
+            trigger.cancel();
+        }
+    });
+    token
+}
+
 fn parse_datetime(value: &str) -> Result<DateTime<Utc>> {
     if let Ok(dt) = DateTime::parse_from_rfc3339(value) {
         return Ok(dt.with_timezone(&Utc));
@@ -2138,6 +2591,7 @@ fn load_candles_from_paths(paths: &[PathBuf]) -> Result<Vec<Candle>> {
                 close,
                 volume,
                 timestamp,
+                status: CandleStatus::Closed,
             });
         }
     }
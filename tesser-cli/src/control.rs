@@ -4,6 +4,7 @@ use std::sync::Arc;
 
 use anyhow::Result;
 use chrono::{DateTime, Utc};
+use futures::StreamExt;
 use tokio::sync::mpsc;
 use tokio::task::JoinHandle;
 use tokio_stream::wrappers::ReceiverStream;
@@ -11,22 +12,27 @@ use tonic::transport::Server;
 use tonic::{Request, Response, Status};
 use tracing::{debug, info, warn};
 
-use tesser_core::ExitStrategy;
-use tesser_events::{Event as RuntimeEvent, EventBus};
-use tesser_execution::OrderOrchestrator;
+use tesser_core::{ExitStrategy, Symbol};
+use tesser_events::{Event as RuntimeEvent, EventBus, EventFilter};
+use tesser_execution::{builtin_algorithm_names, AlgoStatus, AlgorithmSnapshot, OrderOrchestrator};
+use tesser_rpc::auth::BearerAuth;
 use tesser_rpc::conversions::to_decimal_proto;
 use tesser_rpc::proto::control_service_server::{ControlService, ControlServiceServer};
 use tesser_rpc::proto::{
-    self, CancelAllRequest, CancelAllResponse, Event, GetOpenOrdersRequest, GetOpenOrdersResponse,
-    GetPortfolioRequest, GetPortfolioResponse, GetStatusRequest, GetStatusResponse,
-    ListManagedTradesRequest, ListManagedTradesResponse, ManagedTradeInfo, MonitorRequest,
-    OrderSnapshot, PortfolioSnapshot, UpdateTradeExitStrategyRequest,
+    self, AlgorithmInfo, CancelAllRequest, CancelAllResponse, Event, GetOpenOrdersRequest,
+    GetOpenOrdersResponse, GetPortfolioRequest, GetPortfolioResponse, GetStatusRequest,
+    GetStatusResponse, ListAlgorithmsRequest, ListAlgorithmsResponse, ListCapabilitiesRequest,
+    ListCapabilitiesResponse, ListManagedTradesRequest, ListManagedTradesResponse,
+    ManagedTradeInfo, MonitorRequest, OrderSnapshot, PortfolioSnapshot, StrategyCapability,
+    SwitchStrategyRequest, SwitchStrategyResponse, UpdateTradeExitStrategyRequest,
     UpdateTradeExitStrategyResponse,
 };
-use tesser_strategy::PairTradeSnapshot;
+use tesser_strategy::{
+    builtin_strategy_descriptors, builtin_strategy_names, json_to_toml, PairTradeSnapshot,
+};
 use uuid::Uuid;
 
-use crate::live::{OmsHandle, ShutdownSignal, StrategyHandle};
+use crate::live::{OmsHandle, ShutdownSignal, StrategyHandle, SwitchGuard};
 
 pub struct ControlPlaneComponents {
     pub oms: OmsHandle,
@@ -38,7 +44,13 @@ pub struct ControlPlaneComponents {
 }
 
 /// Launch the Control Plane gRPC server alongside the live runtime.
-pub fn spawn_control_plane(addr: SocketAddr, components: ControlPlaneComponents) -> JoinHandle<()> {
+///
+/// Every call passes through `auth`, which rejects requests lacking the configured token.
+pub fn spawn_control_plane(
+    addr: SocketAddr,
+    auth: BearerAuth,
+    components: ControlPlaneComponents,
+) -> JoinHandle<()> {
     let ControlPlaneComponents {
         oms,
         orchestrator,
@@ -55,10 +67,14 @@ pub fn spawn_control_plane(addr: SocketAddr, components: ControlPlaneComponents)
         strategy,
         shutdown.clone(),
     );
-    info!(%addr, "starting control plane gRPC server");
+    info!(
+        %addr,
+        authenticated = auth.is_enabled(),
+        "starting control plane gRPC server"
+    );
     tokio::spawn(async move {
         if let Err(err) = Server::builder()
-            .add_service(ControlServiceServer::new(service))
+            .add_service(ControlServiceServer::with_interceptor(service, auth))
             .serve_with_shutdown(addr, async move { shutdown.wait().await })
             .await
         {
@@ -123,13 +139,28 @@ impl ControlGrpcService {
 
         let open_orders = self.oms.open_orders().await;
         let client = self.orchestrator.execution_engine().client();
+        let targets: Vec<_> = open_orders
+            .into_iter()
+            .map(|order| (order.id, order.request.symbol))
+            .collect();
+        let order_ids: Vec<_> = targets.iter().map(|(id, _)| id.clone()).collect();
         let mut cancelled_orders = 0u32;
-        for order in open_orders {
-            let symbol = order.request.symbol;
-            match client.cancel_order(order.id.clone(), symbol).await {
-                Ok(_) => cancelled_orders += 1,
-                Err(err) => warn!(order_id = %order.id, error = %err, "failed to cancel order"),
+        match client.cancel_orders(targets).await {
+            Ok(outcomes) => {
+                for (order_id, outcome) in order_ids.iter().zip(outcomes) {
+                    match outcome {
+                        Ok(()) => cancelled_orders += 1,
+                        Err(err) => {
+                            warn!(order_id = %order_id, error = %err, "failed to cancel order")
+                        }
+                    }
+                }
             }
+            Err(err) => warn!(
+                orders = order_ids.len(),
+                error = %err,
+                "batch cancel failed"
+            ),
         }
         Ok((cancelled_orders, cancelled_algorithms))
     }
@@ -147,6 +178,7 @@ impl ControlGrpcService {
             entry_z: Some(to_decimal_proto(snapshot.entry_z_score)),
             candles_held: snapshot.candles_held,
             exit_strategy_json,
+            beta: Some(to_decimal_proto(snapshot.beta)),
         })
     }
 }
@@ -247,14 +279,112 @@ impl ControlService for ControlGrpcService {
         }))
     }
 
+    async fn list_capabilities(
+        &self,
+        _request: Request<ListCapabilitiesRequest>,
+    ) -> Result<Response<ListCapabilitiesResponse>, Status> {
+        Ok(Response::new(capabilities_response(
+            self.orchestrator.plugin_names(),
+        )))
+    }
+
+    async fn list_algorithms(
+        &self,
+        _request: Request<ListAlgorithmsRequest>,
+    ) -> Result<Response<ListAlgorithmsResponse>, Status> {
+        let algorithms = self
+            .orchestrator
+            .algorithm_snapshots()
+            .into_iter()
+            .map(algorithm_to_proto)
+            .collect();
+        Ok(Response::new(ListAlgorithmsResponse { algorithms }))
+    }
+
+    async fn switch_strategy(
+        &self,
+        request: Request<SwitchStrategyRequest>,
+    ) -> Result<Response<SwitchStrategyResponse>, Status> {
+        let payload = request.into_inner();
+        let params = if payload.params_json.trim().is_empty() {
+            serde_json::Value::Object(Default::default())
+        } else {
+            serde_json::from_str(&payload.params_json)
+                .map_err(|err| Status::invalid_argument(format!("invalid params json: {err}")))?
+        };
+        let params =
+            json_to_toml(params).map_err(|err| Status::invalid_argument(err.to_string()))?;
+        let oms = self.oms.clone();
+        let orchestrator = self.orchestrator.clone();
+        let guard: SwitchGuard = Box::new(move |queued_signals| {
+            Box::pin(async move {
+                ensure_no_orders_in_flight(
+                    queued_signals,
+                    oms.open_orders().await.len(),
+                    orchestrator.active_algorithms_count(),
+                )
+                .map_err(anyhow::Error::from)
+            })
+        });
+        let outcome = self
+            .strategy
+            .switch_strategy(payload.name, params, payload.flatten, guard)
+            .await
+            .map_err(|err| {
+                err.downcast::<Status>()
+                    .unwrap_or_else(|err| Status::invalid_argument(format!("{err:#}")))
+            })?;
+        Ok(Response::new(SwitchStrategyResponse {
+            previous_strategy: outcome.previous,
+            active_strategy: outcome.active,
+            previous_state_json: outcome.previous_state.to_string(),
+            subscriptions: outcome
+                .subscriptions
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+            flatten_signals: outcome.flatten_signals as u32,
+        }))
+    }
+
     async fn monitor(
         &self,
-        _request: Request<MonitorRequest>,
+        request: Request<MonitorRequest>,
     ) -> Result<Response<Self::MonitorStream>, Status> {
-        let mut stream = self.event_bus.subscribe();
-        info!("monitor subscriber connected");
+        let request = request.into_inner();
+        let replay_from = request.replay_from_seq;
+        let filter = monitor_filter(request)?;
+        let mut replay_filter = filter.clone();
+        let mut stream = self.event_bus.subscribe_filtered(filter);
+        // Only replay what was logged before subscribing; later events arrive on `stream`.
+        let live_from = self.event_bus.next_seq().unwrap_or_default();
+        let replay = match replay_from {
+            Some(from_seq) => {
+                let bus = self.event_bus.clone();
+                let replay = tokio::task::spawn_blocking(move || bus.replay(from_seq))
+                    .await
+                    .map_err(|err| Status::internal(format!("event log replay failed: {err}")))?
+                    .map_err(|err| Status::internal(format!("failed to read event log: {err}")))?;
+                Some(replay)
+            }
+            None => None,
+        };
+        info!(replay_from, "monitor subscriber connected");
         let (tx, rx) = mpsc::channel(256);
         tokio::spawn(async move {
+            if let Some(mut replay) = replay {
+                while let Some(record) = replay.next().await {
+                    if record.seq >= live_from || !replay_filter.matches(&record.event) {
+                        continue;
+                    }
+                    if let Some(proto) = event_to_proto(record.event) {
+                        if tx.send(Ok(proto)).await.is_err() {
+                            warn!("monitor stream receiver dropped during replay");
+                            return;
+                        }
+                    }
+                }
+            }
             loop {
                 match stream.recv().await {
                     Ok(event) => {
@@ -283,6 +413,26 @@ impl ControlService for ControlGrpcService {
     }
 }
 
+/// Translate the monitor request into the event filter applied before forwarding.
+#[allow(clippy::result_large_err)]
+fn monitor_filter(request: MonitorRequest) -> Result<EventFilter, Status> {
+    let mut filter = EventFilter::default().with_symbols(
+        request
+            .symbols
+            .iter()
+            .map(|symbol| symbol.trim())
+            .filter(|symbol| !symbol.is_empty())
+            .map(Symbol::from),
+    );
+    let algo_id = request.algo_id.trim();
+    if !algo_id.is_empty() {
+        let algo_id = Uuid::parse_str(algo_id)
+            .map_err(|err| Status::invalid_argument(format!("invalid algo_id: {err}")))?;
+        filter = filter.with_algo(algo_id.to_string());
+    }
+    Ok(filter)
+}
+
 fn event_to_proto(event: RuntimeEvent) -> Option<proto::Event> {
     use tesser_rpc::proto::event::Payload;
 
@@ -320,9 +470,137 @@ fn event_label(event: &RuntimeEvent) -> &'static str {
     }
 }
 
+/// Refuse to swap strategies while signals, orders or execution algorithms are still working.
+#[allow(clippy::result_large_err)]
+fn ensure_no_orders_in_flight(
+    queued_signals: usize,
+    open_orders: usize,
+    active_algorithms: usize,
+) -> Result<(), Status> {
+    if queued_signals > 0 {
+        return Err(Status::failed_precondition(format!(
+            "cannot switch strategy with {queued_signals} signals awaiting execution; retry shortly"
+        )));
+    }
+    if open_orders > 0 || active_algorithms > 0 {
+        return Err(Status::failed_precondition(format!(
+            "cannot switch strategy with {open_orders} open orders and {active_algorithms} active algorithms; cancel them first"
+        )));
+    }
+    Ok(())
+}
+
+fn capabilities_response(plugins: Vec<String>) -> ListCapabilitiesResponse {
+    let strategy_details = builtin_strategy_descriptors()
+        .into_iter()
+        .map(|descriptor| StrategyCapability {
+            name: descriptor.name.to_string(),
+            description: descriptor.description.to_string(),
+            aliases: descriptor
+                .aliases
+                .iter()
+                .map(|alias| alias.to_string())
+                .collect(),
+            tags: descriptor.tags.iter().map(|tag| tag.to_string()).collect(),
+        })
+        .collect();
+    ListCapabilitiesResponse {
+        strategies: builtin_strategy_names()
+            .into_iter()
+            .map(str::to_string)
+            .collect(),
+        algorithms: builtin_algorithm_names()
+            .into_iter()
+            .map(str::to_string)
+            .collect(),
+        plugins,
+        strategy_details,
+    }
+}
+
+fn algorithm_to_proto(snapshot: AlgorithmSnapshot) -> AlgorithmInfo {
+    let status = match snapshot.status {
+        AlgoStatus::Working => "Working".to_string(),
+        AlgoStatus::Completed => "Completed".to_string(),
+        AlgoStatus::Cancelled => "Cancelled".to_string(),
+        AlgoStatus::Failed(reason) => format!("Failed: {reason}"),
+    };
+    AlgorithmInfo {
+        id: snapshot.id.to_string(),
+        kind: snapshot.kind,
+        status,
+        state_json: snapshot.state.to_string(),
+    }
+}
+
 fn timestamp_from_datetime(ts: DateTime<Utc>) -> prost_types::Timestamp {
     prost_types::Timestamp {
         seconds: ts.timestamp(),
         nanos: ts.timestamp_subsec_nanos() as i32,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn capabilities_include_builtin_strategies_and_algorithms() {
+        let response = capabilities_response(vec!["chase".to_string()]);
+        assert!(response.strategies.iter().any(|name| name == "SmaCross"));
+        assert!(response
+            .algorithms
+            .iter()
+            .any(|name| name == "TRAILING_STOP"));
+        assert_eq!(response.plugins, vec!["chase".to_string()]);
+    }
+
+    #[test]
+    fn capabilities_describe_strategy_tags() {
+        let response = capabilities_response(Vec::new());
+        let details = |name: &str| {
+            response
+                .strategy_details
+                .iter()
+                .find(|detail| detail.name == name)
+                .cloned()
+                .unwrap_or_else(|| panic!("{name} missing from capabilities"))
+        };
+        let imbalance = details("OrderBookImbalance");
+        assert!(imbalance.tags.iter().any(|tag| tag == "order-book"));
+        assert!(!imbalance.description.is_empty());
+        assert_eq!(imbalance.aliases, vec!["OBI".to_string()]);
+        assert!(details("SmaCross")
+            .tags
+            .iter()
+            .any(|tag| tag == "trend-following"));
+        assert_eq!(response.strategy_details.len(), response.strategies.len());
+    }
+
+    #[test]
+    fn algorithm_info_carries_status_and_state() {
+        let id = Uuid::new_v4();
+        let info = algorithm_to_proto(AlgorithmSnapshot {
+            id,
+            kind: "TRAILING_STOP".to_string(),
+            status: AlgoStatus::Failed("rejected".to_string()),
+            state: serde_json::json!({ "activated": true }),
+        });
+        assert_eq!(info.id, id.to_string());
+        assert_eq!(info.status, "Failed: rejected");
+        assert_eq!(info.state_json, r#"{"activated":true}"#);
+    }
+
+    #[test]
+    fn switching_requires_an_idle_execution_pipeline() {
+        assert!(ensure_no_orders_in_flight(0, 0, 0).is_ok());
+        let status = ensure_no_orders_in_flight(0, 2, 0).unwrap_err();
+        assert_eq!(status.code(), tonic::Code::FailedPrecondition);
+        assert!(status.message().contains("2 open orders"));
+        let status = ensure_no_orders_in_flight(0, 0, 1).unwrap_err();
+        assert_eq!(status.code(), tonic::Code::FailedPrecondition);
+        let status = ensure_no_orders_in_flight(3, 0, 0).unwrap_err();
+        assert_eq!(status.code(), tonic::Code::FailedPrecondition);
+        assert!(status.message().contains("3 signals"));
+    }
+}
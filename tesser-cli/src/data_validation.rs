@@ -3,7 +3,7 @@ use std::collections::HashMap;
 use anyhow::{anyhow, bail, Result};
 use chrono::{DateTime, Duration, Utc};
 use rust_decimal::{prelude::ToPrimitive, Decimal};
-use tesser_core::{Candle, Interval, Symbol};
+use tesser_core::{Candle, CandleStatus, Interval, Symbol};
 
 /// Configuration flags used when validating a dataset.
 #[derive(Clone, Copy, Debug)]
@@ -174,6 +174,7 @@ pub fn validate_dataset(
                             close: fill_price,
                             volume: Decimal::ZERO,
                             timestamp: ts,
+                            status: CandleStatus::Closed,
                         };
                         repaired.insert(idx + step, fill);
                         repaired_candles += 1;
@@ -238,6 +239,7 @@ mod tests {
             close,
             volume,
             timestamp: Utc::now() + Duration::minutes(minute),
+            status: CandleStatus::Closed,
         }
     }
 
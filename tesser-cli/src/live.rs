@@ -1,5 +1,5 @@
 use std::any::Any;
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::net::SocketAddr;
 use std::path::PathBuf;
 use std::sync::{
@@ -11,9 +11,10 @@ use std::time::{Duration, Instant};
 use anyhow::{anyhow, bail, Context, Result};
 use chrono::{DateTime, Utc};
 use clap::ValueEnum;
+use futures::future::BoxFuture;
 use futures::StreamExt;
 use rust_decimal::{prelude::ToPrimitive, Decimal};
-use tokio::sync::{mpsc, oneshot, Notify};
+use tokio::sync::{mpsc, oneshot, watch, Notify};
 use tokio::task::JoinHandle;
 use tokio::time::MissedTickBehavior;
 use tokio_tungstenite::tungstenite::Message;
@@ -41,41 +42,48 @@ use tesser_binance::{
 };
 use tesser_broker::{
     get_connector_factory, register_connector_factory, BrokerResult, ConnectorFactory,
-    ConnectorStream, ConnectorStreamConfig, ExecutionClient, RouterExecutionClient,
+    ConnectorStream, ConnectorStreamConfig, ExecutionClient, MarketStream, MergedMarketStream,
+    RouterExecutionClient,
 };
 #[cfg(feature = "bybit")]
 use tesser_bybit::ws::{BybitWsExecution, BybitWsOrder, PrivateMessage};
 #[cfg(feature = "bybit")]
 use tesser_bybit::{register_factory as register_bybit_factory, BybitClient, BybitCredentials};
-use tesser_config::{AlertingConfig, ExchangeConfig, PersistenceEngine, RiskManagementConfig};
+use tesser_config::{
+    AlertingConfig, ExchangeConfig, PersistenceEngine, RiskManagementConfig, TickFilterConfig,
+};
 use tesser_core::{
     AccountBalance, AssetId, Candle, ExchangeId, ExitStrategy, Fill, Interval, Order, OrderBook,
-    OrderStatus, Position, Price, Quantity, Side, Signal, SignalKind, Symbol, Tick,
+    OrderId, OrderStatus, Position, Price, Quantity, Side, Signal, SignalKind, Symbol, Tick,
 };
+use tesser_data::download::{BinanceDownloader, BybitDownloader, KlineRequest};
+use tesser_data::io::read_dataset;
 use tesser_data::recorder::{ParquetRecorder, RecorderConfig, RecorderHandle};
+use tesser_data::transform::{CandleBuilder, TickSanitizer};
 use tesser_events::{
-    CandleEvent, Event, EventBus, FillEvent, OrderBookEvent, OrderUpdateEvent, SignalEvent,
-    TickEvent,
+    CandleEvent, Event, EventBus, EventLog, EventLogConfig, FillEvent, OrderBookEvent,
+    OrderUpdateEvent, SignalEvent, TickEvent,
 };
 use tesser_execution::{
-    AlgoStateRepository, BasicRiskChecker, ExecutionEngine, FixedOrderSizer, OrderOrchestrator,
-    PanicCloseConfig, PanicObserver, PreTradeRiskChecker, RiskContext, RiskLimits,
-    SqliteAlgoStateRepository, StoredAlgoState, WasmPluginEngine,
+    AlgoStateRepository, BasicRiskChecker, ExecutionEngine, FixedOrderSizer, MinNotionalPolicy,
+    OrderMappingPolicy, OrderOrchestrator, PanicCloseConfig, PanicObserver, PreTradeRiskChecker,
+    RiskContext, RiskLimits, SqliteAlgoStateRepository, StoredAlgoState, WasmPluginEngine,
 };
 use tesser_journal::LmdbJournal;
 use tesser_ledger::{
-    entries_from_fill, FillLedgerContext, LedgerEntry, LedgerRepository, LedgerSequencer,
-    SqliteLedgerRepository,
+    entries_from_fill, entries_from_funding, FillLedgerContext, FundingEntry, FundingLedgerContext,
+    LedgerEntry, LedgerQuery, LedgerRepository, LedgerSequencer, SqliteLedgerRepository,
 };
 use tesser_markets::{InstrumentCatalog, MarketRegistry};
 use tesser_paper::{FeeScheduleConfig, PaperExecutionClient, PaperFactory};
 use tesser_portfolio::{
-    ExecutionCheckpoint, LiveState, Portfolio, PortfolioConfig, PortfolioState,
-    SqliteStateRepository, StateRepository,
+    DeleverageGuard, DeleverageTier, ExecutionCheckpoint, LiveState, Portfolio, PortfolioConfig,
+    PortfolioState, SqliteStateRepository, StateRepository,
 };
+use tesser_rpc::auth::BearerAuth;
 use tesser_strategy::{
-    PairTradeSnapshot, PairsTradingArbitrage, Strategy, StrategyContext, StrategyError,
-    StrategyResult,
+    load_strategy, ContextMode, PairTradeSnapshot, PairsTradingArbitrage, Strategy,
+    StrategyContext, StrategyError, StrategyResult,
 };
 
 use crate::alerts::{AlertDispatcher, AlertManager};
@@ -91,7 +99,12 @@ use crate::PublicChannel;
 #[derive(Debug)]
 pub enum BrokerEvent {
     OrderUpdate(Order),
-    Fill(Fill),
+    /// Execution report, with the venue execution id when the connector exposes one.
+    Fill {
+        fill: Fill,
+        exec_id: Option<String>,
+    },
+    Funding(FundingEntry),
 }
 
 #[derive(Clone)]
@@ -161,17 +174,41 @@ enum MarketEvent {
     OrderBook(OrderBook),
 }
 
+impl MarketEvent {
+    fn symbol(&self) -> Symbol {
+        match self {
+            Self::Tick(tick) => tick.symbol,
+            Self::Candle(candle) => candle.symbol,
+            Self::OrderBook(book) => book.symbol,
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct StrategyHandle {
     tx: mpsc::Sender<StrategyCommand>,
+    /// Name of the running strategy, replaced whenever a switch succeeds.
+    active: Arc<watch::Sender<String>>,
 }
 
 impl StrategyHandle {
+    fn new(tx: mpsc::Sender<StrategyCommand>, active: impl Into<String>) -> Self {
+        Self {
+            tx,
+            active: Arc::new(watch::Sender::new(active.into())),
+        }
+    }
+
+    /// Name of the strategy currently driving signals.
+    pub fn active_strategy(&self) -> String {
+        self.active.borrow().clone()
+    }
+
     async fn send_account_event(&self, event: StrategyAccountEvent) {
         let _ = self.tx.send(StrategyCommand::Account(event)).await;
     }
 
-    async fn snapshot(&self) -> Option<serde_json::Value> {
+    async fn snapshot(&self) -> Option<StrategyState> {
         let (tx, rx) = oneshot::channel();
         let _ = self
             .tx
@@ -182,6 +219,14 @@ impl StrategyHandle {
         rx.await.ok().flatten()
     }
 
+    /// Stop the strategy actor once it has processed every queued command, returning the
+    /// strategy's final snapshot.
+    async fn stop(&self) -> Option<StrategyState> {
+        let (tx, rx) = oneshot::channel();
+        let _ = self.tx.send(StrategyCommand::Stop { respond_to: tx }).await;
+        rx.await.ok().flatten()
+    }
+
     pub async fn list_managed_trades(&self) -> anyhow::Result<Vec<PairTradeSnapshot>> {
         let (tx, rx) = oneshot::channel();
         let _ = self
@@ -213,11 +258,69 @@ impl StrategyHandle {
         rx.await
             .unwrap_or_else(|_| Err(anyhow!("strategy channel closed")))
     }
+
+    /// Replace the running strategy with a freshly configured `name` instance.
+    ///
+    /// `guard` runs inside the strategy actor right before the swap; see [`SwitchGuard`].
+    pub async fn switch_strategy(
+        &self,
+        name: String,
+        params: tesser_strategy::Value,
+        flatten: bool,
+        guard: SwitchGuard,
+    ) -> anyhow::Result<StrategySwitchOutcome> {
+        let (tx, rx) = oneshot::channel();
+        let _ = self
+            .tx
+            .send(StrategyCommand::Request(StrategyRequest::Switch {
+                name,
+                params,
+                flatten,
+                guard,
+                respond_to: tx,
+            }))
+            .await;
+        let outcome = rx
+            .await
+            .unwrap_or_else(|_| Err(anyhow!("strategy channel closed")))?;
+        self.active.send_replace(outcome.active.clone());
+        Ok(outcome)
+    }
+}
+
+/// Confirms execution is idle before a strategy switch, given the number of signals still
+/// queued for the OMS.
+///
+/// The strategy actor runs the guard while it handles the switch, so the strategy cannot emit
+/// new signals between the check and the swap.
+pub type SwitchGuard = Box<dyn FnOnce(usize) -> BoxFuture<'static, anyhow::Result<()>> + Send>;
+
+/// Strategy snapshot tagged with the name of the strategy that produced it.
+#[derive(Clone, Debug, PartialEq)]
+struct StrategyState {
+    name: String,
+    state: serde_json::Value,
+}
+
+/// Result of swapping the live strategy.
+#[derive(Clone, Debug)]
+pub struct StrategySwitchOutcome {
+    pub previous: String,
+    pub active: String,
+    /// Final snapshot of the replaced strategy.
+    pub previous_state: Value,
+    pub subscriptions: Vec<Symbol>,
+    /// Number of flatten signals routed for the replaced strategy's positions.
+    pub flatten_signals: usize,
 }
 
 enum StrategyCommand {
     Account(StrategyAccountEvent),
     Request(StrategyRequest),
+    /// Final command of a shutdown: reply with the strategy snapshot and exit.
+    Stop {
+        respond_to: oneshot::Sender<Option<StrategyState>>,
+    },
 }
 
 enum StrategyAccountEvent {
@@ -229,7 +332,7 @@ enum StrategyAccountEvent {
 
 enum StrategyRequest {
     Snapshot {
-        respond_to: oneshot::Sender<Option<serde_json::Value>>,
+        respond_to: oneshot::Sender<Option<StrategyState>>,
     },
     ListManagedTrades {
         respond_to: oneshot::Sender<anyhow::Result<Vec<PairTradeSnapshot>>>,
@@ -239,6 +342,13 @@ enum StrategyRequest {
         exit: ExitStrategy,
         respond_to: oneshot::Sender<anyhow::Result<()>>,
     },
+    Switch {
+        name: String,
+        params: tesser_strategy::Value,
+        flatten: bool,
+        guard: SwitchGuard,
+        respond_to: oneshot::Sender<anyhow::Result<StrategySwitchOutcome>>,
+    },
 }
 
 #[derive(Clone)]
@@ -313,6 +423,22 @@ impl OmsHandle {
             .await;
         let _ = rx.await;
     }
+
+    /// Replace local positions with the venue's, e.g. `(symbol, None)` when it reports flat.
+    pub async fn resync_positions(&self, positions: Vec<(Symbol, Option<Position>)>) {
+        if positions.is_empty() {
+            return;
+        }
+        let (tx, rx) = oneshot::channel();
+        let _ = self
+            .tx
+            .send(OmsRequest::ResyncPositions {
+                positions,
+                respond_to: tx,
+            })
+            .await;
+        let _ = rx.await;
+    }
 }
 
 #[derive(Default, Clone)]
@@ -342,6 +468,10 @@ pub(crate) enum OmsRequest {
         fills: Vec<Fill>,
         respond_to: oneshot::Sender<()>,
     },
+    ResyncPositions {
+        positions: Vec<(Symbol, Option<Position>)>,
+        respond_to: oneshot::Sender<()>,
+    },
 }
 
 #[derive(Clone)]
@@ -429,6 +559,16 @@ const MARKET_EVENT_TIMEOUT: Duration = Duration::from_millis(10);
 const EXECUTION_GAP_THRESHOLD_SECS: i64 = 120;
 const EXECUTION_BACKFILL_INTERVAL_SECS: u64 = 60;
 const EXECUTION_FALLBACK_LOOKBACK_MINS: i64 = 30;
+/// Closed orders remembered so late updates cannot reopen them.
+const CLOSED_ORDER_HISTORY: usize = 1_024;
+/// Upper bound on how long shutdown waits for unacknowledged orders to settle.
+const SHUTDOWN_SETTLE_TIMEOUT: Duration = Duration::from_secs(5);
+/// How long a stopped strategy actor keeps answering commands while the OMS winds down.
+const STRATEGY_SHUTDOWN_GRACE: Duration = Duration::from_secs(10);
+/// Upper bound on the pre-switch idle check, which waits on the OMS actor.
+const SWITCH_GUARD_TIMEOUT: Duration = Duration::from_secs(5);
+/// Strategy error alerts waiting for the webhook; further errors are logged but not sent.
+const STRATEGY_ALERT_QUEUE: usize = 16;
 
 #[async_trait::async_trait]
 trait LiveMarketStream: Send {
@@ -462,23 +602,66 @@ impl LiveMarketStream for FactoryStreamAdapter {
     }
 }
 
-struct RouterMarketStream {
+/// Buffered events pumped out of one venue's stream, drained without blocking.
+struct SourceChannelStream {
+    name: String,
     tick_rx: mpsc::Receiver<Tick>,
     candle_rx: mpsc::Receiver<Candle>,
     book_rx: mpsc::Receiver<OrderBook>,
+}
+
+#[async_trait::async_trait]
+impl MarketStream for SourceChannelStream {
+    type Subscription = ();
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    async fn subscribe(&mut self, _subscription: Self::Subscription) -> BrokerResult<()> {
+        // Venues are subscribed before their pump starts.
+        Ok(())
+    }
+
+    async fn next_tick(&mut self) -> BrokerResult<Option<Tick>> {
+        Ok(self.tick_rx.try_recv().ok())
+    }
+
+    async fn next_candle(&mut self) -> BrokerResult<Option<Candle>> {
+        Ok(self.candle_rx.try_recv().ok())
+    }
+
+    async fn next_order_book(&mut self) -> BrokerResult<Option<OrderBook>> {
+        Ok(self.book_rx.try_recv().ok())
+    }
+}
+
+/// Fans several venue streams into one, interleaving them through a [`MergedMarketStream`].
+///
+/// Each venue is pumped by its own task so a blocking or failing connector cannot stall the
+/// others; the merged stream then round-robins the buffered events.
+struct RouterMarketStream {
+    merged: MergedMarketStream<SourceChannelStream>,
     tasks: Vec<JoinHandle<()>>,
 }
 
 impl RouterMarketStream {
     fn new(streams: Vec<(String, Box<dyn LiveMarketStream>)>, shutdown: ShutdownSignal) -> Self {
-        let (tick_tx, tick_rx) = mpsc::channel(512);
-        let (candle_tx, candle_rx) = mpsc::channel(512);
-        let (book_tx, book_rx) = mpsc::channel(512);
+        let mut sources = Vec::new();
         let mut tasks = Vec::new();
         for (name, mut stream) in streams {
-            let tick_tx = tick_tx.clone();
-            let candle_tx = candle_tx.clone();
-            let book_tx = book_tx.clone();
+            let (tick_tx, tick_rx) = mpsc::channel(512);
+            let (candle_tx, candle_rx) = mpsc::channel(512);
+            let (book_tx, book_rx) = mpsc::channel(512);
+            sources.push((
+                ExchangeId::from(name.as_str()),
+                SourceChannelStream {
+                    name: name.clone(),
+                    tick_rx,
+                    candle_rx,
+                    book_rx,
+                },
+            ));
             let shutdown = shutdown.clone();
             tasks.push(tokio::spawn(async move {
                 loop {
@@ -548,9 +731,7 @@ impl RouterMarketStream {
             }));
         }
         Self {
-            tick_rx,
-            candle_rx,
-            book_rx,
+            merged: MergedMarketStream::new(sources),
             tasks,
         }
     }
@@ -559,15 +740,27 @@ impl RouterMarketStream {
 #[async_trait::async_trait]
 impl LiveMarketStream for RouterMarketStream {
     async fn next_tick(&mut self) -> BrokerResult<Option<Tick>> {
-        Ok(self.tick_rx.recv().await)
+        Ok(self
+            .merged
+            .next_sourced_tick()
+            .await?
+            .map(|sourced| sourced.event))
     }
 
     async fn next_candle(&mut self) -> BrokerResult<Option<Candle>> {
-        Ok(self.candle_rx.recv().await)
+        Ok(self
+            .merged
+            .next_sourced_candle()
+            .await?
+            .map(|sourced| sourced.event))
     }
 
     async fn next_order_book(&mut self) -> BrokerResult<Option<OrderBook>> {
-        Ok(self.book_rx.recv().await)
+        Ok(self
+            .merged
+            .next_sourced_order_book()
+            .await?
+            .map(|sourced| sourced.event))
     }
 }
 
@@ -627,6 +820,7 @@ pub struct NamedExchange {
 struct ExchangeRoute {
     name: String,
     driver: String,
+    rest_url: String,
     #[cfg(feature = "binance")]
     ws_url: String,
     execution: Arc<dyn ExecutionClient>,
@@ -656,11 +850,32 @@ pub struct LiveSessionSettings {
     pub risk: RiskManagementConfig,
     pub reconciliation_interval: Duration,
     pub reconciliation_threshold: Decimal,
+    /// Per-asset overrides of `reconciliation_threshold` for balance checks, keyed by asset code.
+    pub reconciliation_asset_thresholds: HashMap<String, Decimal>,
+    /// Place corrective orders for position divergence instead of only halting.
+    pub auto_correct_positions: bool,
+    /// Upper bound on the size of a single corrective order.
+    pub max_corrective_quantity: Quantity,
     pub orderbook_depth: usize,
     pub record_path: Option<PathBuf>,
     pub control_addr: SocketAddr,
+    /// Bearer token required by the control plane; `None` leaves it unauthenticated.
+    pub control_token: Option<String>,
     pub panic_close: PanicCloseConfig,
     pub plugins_dir: Option<PathBuf>,
+    pub candles_from_ticks: bool,
+    /// Opt-in filter dropping outlier ticks before they reach strategies.
+    pub tick_filter: Option<TickFilterConfig>,
+    /// Candle datasets replayed into the strategy before live signals are routed.
+    pub warmup_paths: Vec<PathBuf>,
+    /// Strategy-level mapping from signal kinds to order types.
+    pub order_mapping: OrderMappingPolicy,
+    /// Window after a stop-loss fill during which new entries on that symbol are dropped.
+    pub cooldown_after_stop: Option<chrono::Duration>,
+    /// Handling of entry orders below the instrument minimum notional.
+    pub min_notional_policy: MinNotionalPolicy,
+    /// Directory of the runtime event log; events are only broadcast when unset.
+    pub event_log_dir: Option<PathBuf>,
 }
 
 impl LiveSessionSettings {
@@ -863,7 +1078,14 @@ pub async fn run_live_with_shutdown(
         Some(panic_hook.clone()),
         wasm_plugins.clone(),
     )
-    .await?;
+    .await?
+    .with_market_registry(market_registry.clone())
+    .with_min_notional(market_registry.clone(), settings.min_notional_policy)
+    .with_order_mapping(settings.order_mapping);
+    let orchestrator = match settings.cooldown_after_stop {
+        Some(cooldown) => orchestrator.with_cooldown_after_stop(cooldown),
+        None => orchestrator,
+    };
 
     let runtime = LiveRuntime::new(
         market_stream,
@@ -939,6 +1161,7 @@ async fn build_exchange_routes(
         routes.push(ExchangeRoute {
             name: exchange.name.clone(),
             driver,
+            rest_url: exchange.config.rest_url.clone(),
             #[cfg(feature = "binance")]
             ws_url: exchange.config.ws_url.clone(),
             execution: execution_client.clone(),
@@ -1055,7 +1278,19 @@ impl LiveRuntime {
         bootstrap: Option<LiveBootstrap>,
     ) -> Result<Self> {
         let mut strategy_ctx = StrategyContext::new(settings.history);
+        let required_history = strategy.required_history();
+        if required_history > settings.history {
+            info!(
+                strategy = strategy.name(),
+                configured = settings.history,
+                required = required_history,
+                "growing strategy context history to fit the strategy"
+            );
+            strategy_ctx.ensure_history(required_history);
+        }
         strategy_ctx.attach_market_registry(market_registry.clone());
+        let warmup =
+            load_warmup(&settings, &exchanges, &symbols, strategy.warmup_candles()).await?;
         let mut persisted = match tokio::task::spawn_blocking({
             let repo = state_repo.clone();
             move || repo.load()
@@ -1089,7 +1324,23 @@ impl LiveRuntime {
             market_registry: market_registry.clone(),
         });
         let portfolio = if let Some(snapshot) = remote_snapshot {
-            let local_snapshot = LocalSnapshot::from_live_state(&persisted);
+            let ledger_entries = match tokio::task::spawn_blocking({
+                let repo = ledger_repo.clone();
+                move || repo.query(LedgerQuery::default())
+            })
+            .await
+            {
+                Ok(Ok(entries)) => entries,
+                Ok(Err(err)) => {
+                    warn!(error = %err, "failed to read ledger; reconciling persisted balances");
+                    Vec::new()
+                }
+                Err(err) => {
+                    warn!(error = %err, "ledger read task failed; reconciling persisted balances");
+                    Vec::new()
+                }
+            };
+            let local_snapshot = startup_handler.local_snapshot(&persisted, &ledger_entries);
             let report = StateDiffer::diff(local_snapshot, snapshot);
             let outcome = startup_handler.apply(&report, persisted.portfolio.as_ref());
             persisted.open_orders = outcome.open_orders.clone();
@@ -1111,12 +1362,7 @@ impl LiveRuntime {
             }
         }
         strategy_ctx.update_positions(portfolio.positions());
-        if let Some(state) = persisted.strategy_state.take() {
-            info!("restoring strategy state from persistence");
-            strategy
-                .restore(state)
-                .context("failed to restore strategy state")?;
-        }
+        restore_strategy_state(strategy.as_mut(), &mut persisted)?;
         persisted.portfolio = Some(portfolio.snapshot());
 
         let mut market_snapshots = HashMap::new();
@@ -1137,7 +1383,6 @@ impl LiveRuntime {
         let execution_tracker = Arc::new(ExecutionTracker::new(
             persisted.execution_checkpoint.clone(),
         ));
-        let alert_task = alerts.spawn_watchdog();
         let mut connection_monitors = Vec::new();
         connection_monitors.push(spawn_connection_monitor(
             shutdown.clone(),
@@ -1241,7 +1486,19 @@ impl LiveRuntime {
         };
         let recorder_handle = recorder.as_ref().map(|rec| rec.handle());
 
-        let event_bus = Arc::new(EventBus::new(2048));
+        let event_bus = Arc::new(match settings.event_log_dir.as_ref() {
+            Some(dir) => {
+                let log = EventLog::open(EventLogConfig::new(dir))
+                    .with_context(|| format!("failed to open event log in {}", dir.display()))?;
+                info!(
+                    dir = %dir.display(),
+                    next_seq = log.next_seq(),
+                    "persisting runtime events"
+                );
+                EventBus::with_log(2048, log).context("failed to start event log writer")?
+            }
+            None => EventBus::new(2048),
+        });
         let last_data_timestamp = Arc::new(AtomicI64::new(0));
         let (persistence_handle, persistence_task) = spawn_persistence_actor(state_repo.clone());
         let orchestrator = Arc::new(orchestrator);
@@ -1268,13 +1525,15 @@ impl LiveRuntime {
         let (strategy_cmd_tx, strategy_cmd_rx) = mpsc::channel(128);
         let (oms_req_tx, oms_req_rx) = mpsc::channel(64);
 
-        let strategy_handle = StrategyHandle {
-            tx: strategy_cmd_tx.clone(),
-        };
+        let strategy_handle = StrategyHandle::new(strategy_cmd_tx.clone(), strategy.name());
         let oms_handle = OmsHandle::new(oms_req_tx.clone());
+        let alert_task = alerts.spawn_watchdog(oms_handle.clone());
 
+        let control_auth = BearerAuth::new(settings.control_token.as_deref())
+            .map_err(|status| anyhow!("invalid control token: {}", status.message()))?;
         let control_task = control::spawn_control_plane(
             settings.control_addr,
+            control_auth,
             control::ControlPlaneComponents {
                 oms: oms_handle.clone(),
                 orchestrator: orchestrator.clone(),
@@ -1292,6 +1551,9 @@ impl LiveRuntime {
                 metrics: metrics.clone(),
                 reporting_currency: settings.reporting_currency,
                 threshold: settings.reconciliation_threshold,
+                asset_thresholds: settings.reconciliation_asset_thresholds.clone(),
+                auto_correct_positions: settings.auto_correct_positions,
+                max_corrective_quantity: settings.max_corrective_quantity,
                 symbols: symbols.clone(),
             }))
         });
@@ -1320,9 +1582,10 @@ impl LiveRuntime {
             shutdown.clone(),
         );
 
-        let strategy_actor = StrategyActor::new(
+        let mut strategy_actor = StrategyActor::new(
             strategy,
             strategy_ctx,
+            &symbols,
             strategy_market_rx,
             strategy_cmd_rx,
             signal_tx,
@@ -1334,6 +1597,10 @@ impl LiveRuntime {
             driver_summary.clone(),
             shutdown.clone(),
         );
+        strategy_actor
+            .warm_up(warmup)
+            .await
+            .context("strategy warmup failed")?;
         let strategy_task = tokio::spawn(async move { strategy_actor.run().await });
 
         let oms_actor = OmsActor::new(
@@ -1357,6 +1624,7 @@ impl LiveRuntime {
             ledger_seq,
             shutdown.clone(),
             execution_tracker.clone(),
+            deleverage_guard(&settings.risk),
         );
         for symbol in &symbols {
             let ctx = shared_risk_context(
@@ -1379,6 +1647,13 @@ impl LiveRuntime {
             last_data_timestamp.clone(),
             strategy_market_tx,
             oms_market_tx,
+            settings
+                .candles_from_ticks
+                .then(|| CandleBuilder::new(settings.interval)),
+            settings
+                .tick_filter
+                .as_ref()
+                .map(|cfg| TickSanitizer::new(cfg.max_deviation_pct, cfg.window)),
             shutdown.clone(),
         );
         let market_task = tokio::spawn(async move { market_actor.run().await });
@@ -1455,19 +1730,20 @@ impl LiveRuntime {
             handle.abort();
         }
 
-        self.persistence_handle.shutdown().await;
-
         if market_result.is_none() {
             if let Err(err) = market_task.await {
                 warn!(error = %err, "market actor join failed");
             }
         }
+        // The OMS settles in-flight orders and saves the final state, including the strategy
+        // snapshot, before it exits; only then may the persistence actor stop.
         if let Err(err) = strategy_task.await {
             warn!(error = %err, "strategy actor join failed");
         }
         if let Err(err) = oms_task.await {
             warn!(error = %err, "oms actor join failed");
         }
+        self.persistence_handle.shutdown().await;
         if let Err(err) = self.persistence_task.await {
             warn!(error = %err, "persistence actor join failed");
         }
@@ -1500,6 +1776,9 @@ struct ReconciliationContextConfig {
     metrics: Arc<LiveMetrics>,
     reporting_currency: AssetId,
     threshold: Decimal,
+    asset_thresholds: HashMap<String, Decimal>,
+    auto_correct_positions: bool,
+    max_corrective_quantity: Quantity,
     symbols: Vec<Symbol>,
 }
 
@@ -1512,6 +1791,9 @@ impl ReconciliationContext {
             metrics,
             reporting_currency,
             threshold,
+            asset_thresholds,
+            auto_correct_positions,
+            max_corrective_quantity,
             symbols,
         } = config;
         let handler = RuntimeHandler::new(RuntimeHandlerConfig {
@@ -1520,6 +1802,9 @@ impl ReconciliationContext {
             oms: oms.clone(),
             reporting_currency,
             threshold,
+            asset_thresholds,
+            auto_correct_positions,
+            max_corrective_quantity,
             client: client.clone(),
         });
         Self {
@@ -1711,6 +1996,53 @@ fn log_strategy_call(event: &str, elapsed: Duration) {
     }
 }
 
+/// Restore the persisted strategy state unless it was produced by a different strategy, as
+/// happens when the strategy was switched at runtime and the config still names the old one.
+fn restore_strategy_state(strategy: &mut dyn Strategy, persisted: &mut LiveState) -> Result<()> {
+    let Some(state) = persisted.strategy_state.take() else {
+        return Ok(());
+    };
+    match persisted.strategy_name.take() {
+        Some(name) if name != strategy.name() => {
+            warn!(
+                persisted = %name,
+                configured = strategy.name(),
+                "persisted strategy state belongs to another strategy; starting fresh"
+            );
+            Ok(())
+        }
+        _ => {
+            info!("restoring strategy state from persistence");
+            strategy
+                .restore(state)
+                .context("failed to restore strategy state")
+        }
+    }
+}
+
+/// Forward queued strategy error alerts to the webhook until the actor drops its sender.
+fn spawn_strategy_alerts(alerts: Arc<AlertManager>) -> mpsc::Sender<String> {
+    let (tx, mut rx) = mpsc::channel::<String>(STRATEGY_ALERT_QUEUE);
+    tokio::spawn(async move {
+        while let Some(message) = rx.recv().await {
+            alerts.notify("Strategy error", &message).await;
+        }
+    });
+    tx
+}
+
+/// Apply the strategy error contract: [`StrategyError::is_transient`] errors are logged and
+/// the event is skipped, while every other error is returned for escalation.
+fn strategy_call_result(result: StrategyResult<()>, event: &str) -> Result<()> {
+    match result {
+        Err(err) if err.is_transient() => {
+            debug!(target: "strategy", event, error = %err, "strategy skipped event");
+            Ok(())
+        }
+        other => other.with_context(|| format!("strategy failure on {event}")),
+    }
+}
+
 fn normalize_group_quantities(signals: &mut [Signal], registry: &MarketRegistry) {
     use std::collections::HashMap;
 
@@ -1801,7 +2133,11 @@ fn assign_implicit_group_ids(signals: &mut [Signal]) {
 fn signal_kind_family(kind: SignalKind) -> Option<u8> {
     match kind {
         SignalKind::EnterLong | SignalKind::EnterShort => Some(0),
-        SignalKind::ExitLong | SignalKind::ExitShort | SignalKind::Flatten => Some(1),
+        SignalKind::ExitLong
+        | SignalKind::ExitShort
+        | SignalKind::Flatten
+        | SignalKind::ReduceLong
+        | SignalKind::ReduceShort => Some(1),
     }
 }
 
@@ -1842,12 +2178,18 @@ fn spawn_persistence_actor(
 struct StrategyActor {
     strategy: Box<dyn Strategy>,
     ctx: StrategyContext,
+    /// Symbols carried by the live market feed; a replacement strategy must stay within them.
+    streamed: HashSet<Symbol>,
+    /// Symbols routed to the strategy after a switch; `None` routes the whole feed.
+    routes: Option<HashSet<Symbol>>,
     market_rx: mpsc::Receiver<MarketEvent>,
     cmd_rx: mpsc::Receiver<StrategyCommand>,
     signal_tx: mpsc::Sender<Signal>,
     bus: Arc<EventBus>,
     metrics: Arc<LiveMetrics>,
     alerts: Arc<AlertManager>,
+    /// Strategy errors handed to a background task so a slow webhook never stalls the actor.
+    error_alerts: mpsc::Sender<String>,
     recorder: Option<RecorderHandle>,
     market_registry: Arc<MarketRegistry>,
     driver_label: Arc<String>,
@@ -1863,6 +2205,8 @@ struct MarketActor {
     last_data_timestamp: Arc<AtomicI64>,
     strategy_tx: mpsc::Sender<MarketEvent>,
     oms_tx: mpsc::Sender<MarketEvent>,
+    candle_builder: Option<CandleBuilder>,
+    tick_sanitizer: Option<TickSanitizer>,
     shutdown: ShutdownSignal,
 }
 
@@ -1877,6 +2221,8 @@ impl MarketActor {
         last_data_timestamp: Arc<AtomicI64>,
         strategy_tx: mpsc::Sender<MarketEvent>,
         oms_tx: mpsc::Sender<MarketEvent>,
+        candle_builder: Option<CandleBuilder>,
+        tick_sanitizer: Option<TickSanitizer>,
         shutdown: ShutdownSignal,
     ) -> Self {
         Self {
@@ -1888,6 +2234,8 @@ impl MarketActor {
             last_data_timestamp,
             strategy_tx,
             oms_tx,
+            candle_builder,
+            tick_sanitizer,
             shutdown,
         }
     }
@@ -1898,7 +2246,16 @@ impl MarketActor {
             let mut progressed = false;
             if let Some(tick) = self.next_tick().await? {
                 progressed = true;
+                let built = self
+                    .candle_builder
+                    .as_mut()
+                    .and_then(|builder| builder.push_tick(&tick));
                 self.dispatch_market_event(MarketEvent::Tick(tick)).await;
+                if let Some(candle) = built {
+                    self.publish_built_candle(&candle);
+                    self.dispatch_market_event(MarketEvent::Candle(candle))
+                        .await;
+                }
             }
             if let Some(candle) = self.next_candle().await? {
                 progressed = true;
@@ -1931,6 +2288,9 @@ impl MarketActor {
                 self.last_data_timestamp
                     .store(tick.exchange_timestamp.timestamp(), Ordering::SeqCst);
                 self.alerts.heartbeat().await;
+                if !self.accept_tick(&tick) {
+                    return Ok(None);
+                }
                 if let Some(handle) = &self.recorder {
                     handle.record_tick(tick.clone());
                 }
@@ -1945,6 +2305,37 @@ impl MarketActor {
         }
     }
 
+    /// Run the optional sanitizer, counting and logging any tick it rejects.
+    fn accept_tick(&mut self, tick: &Tick) -> bool {
+        let Some(sanitizer) = self.tick_sanitizer.as_mut() else {
+            return true;
+        };
+        match sanitizer.check(tick) {
+            Ok(()) => true,
+            Err(reason) => {
+                self.metrics.inc_rejected_tick(reason.as_str());
+                warn!(
+                    symbol = %tick.symbol,
+                    price = %tick.price,
+                    size = %tick.size,
+                    reason = reason.as_str(),
+                    "dropping anomalous tick"
+                );
+                false
+            }
+        }
+    }
+
+    /// Record and broadcast a candle synthesized from ticks, mirroring exchange klines.
+    fn publish_built_candle(&self, candle: &Candle) {
+        if let Some(handle) = &self.recorder {
+            handle.record_candle(candle.clone());
+        }
+        self.event_bus.publish(Event::Candle(CandleEvent {
+            candle: candle.clone(),
+        }));
+    }
+
     async fn next_candle(&mut self) -> Result<Option<Candle>> {
         let candle = tokio::select! {
             res = tokio::time::timeout(MARKET_EVENT_TIMEOUT, self.stream.next_candle()) => Some(res),
@@ -2030,6 +2421,9 @@ struct OmsActor {
     ledger_seq: Arc<LedgerSequencer>,
     shutdown: ShutdownSignal,
     execution_tracker: Arc<ExecutionTracker>,
+    deleverage: Option<DeleverageGuard>,
+    /// Final status of recently closed orders, oldest first.
+    closed_orders: VecDeque<(OrderId, OrderStatus)>,
 }
 
 impl OmsActor {
@@ -2055,8 +2449,9 @@ impl OmsActor {
         ledger_seq: Arc<LedgerSequencer>,
         shutdown: ShutdownSignal,
         execution_tracker: Arc<ExecutionTracker>,
+        deleverage: Option<DeleverageGuard>,
     ) -> Self {
-        Self {
+        let actor = Self {
             market_rx,
             signal_rx,
             broker_rx,
@@ -2077,7 +2472,14 @@ impl OmsActor {
             ledger_seq,
             shutdown,
             execution_tracker,
+            deleverage,
+            closed_orders: VecDeque::new(),
+        };
+        // Only this actor drains simulated fills, so capture them only when it will.
+        if let Some(paper) = actor.paper_client() {
+            paper.enable_fill_capture();
         }
+        actor
     }
 
     async fn run(mut self) {
@@ -2104,19 +2506,70 @@ impl OmsActor {
                     if let Err(err) = self.orchestrator.on_timer_tick().await {
                         error!(error = %err, "orchestrator timer tick failed");
                     }
+                    self.settle_paper_fills().await;
                 }
                 _ = self.shutdown.wait() => break,
                 else => break,
             }
         }
+        self.shut_down().await;
         debug!("oms actor stopped");
     }
 
+    /// Let in-flight orders settle, record their fills in the ledger, and persist the final
+    /// live state together with the strategy snapshot.
+    async fn shut_down(&mut self) {
+        self.settle_in_flight_orders(SHUTDOWN_SETTLE_TIMEOUT).await;
+        self.snapshot_portfolio();
+        let mut snapshot = self.live_state.clone();
+        if let Some(state) = self.strategy.stop().await {
+            snapshot.strategy_name = Some(state.name);
+            snapshot.strategy_state = Some(state.state);
+        }
+        snapshot.execution_checkpoint = self.execution_tracker.snapshot().await;
+        self.persistence.save(snapshot).await;
+    }
+
+    /// Process queued broker events until no order awaits acknowledgement or `timeout` elapses.
+    async fn settle_in_flight_orders(&mut self, timeout: Duration) {
+        let deadline = tokio::time::Instant::now() + timeout;
+        loop {
+            while let Ok(event) = self.broker_rx.try_recv() {
+                if let Err(err) = self.handle_broker_event(event).await {
+                    warn!(error = %err, "oms broker handler failed during shutdown");
+                }
+            }
+            let in_flight = self
+                .live_state
+                .open_orders
+                .iter()
+                .filter(|order| order.status == OrderStatus::PendingNew)
+                .count();
+            if in_flight == 0 {
+                return;
+            }
+            tokio::select! {
+                event = self.broker_rx.recv() => match event {
+                    Some(event) => {
+                        if let Err(err) = self.handle_broker_event(event).await {
+                            warn!(error = %err, "oms broker handler failed during shutdown");
+                        }
+                    }
+                    None => return,
+                },
+                _ = tokio::time::sleep_until(deadline) => {
+                    warn!(in_flight, "shutting down with unacknowledged orders");
+                    return;
+                }
+            }
+        }
+    }
+
     async fn handle_market_event(&mut self, event: MarketEvent) -> Result<()> {
         match event {
             MarketEvent::Tick(tick) => self.handle_tick(tick).await?,
             MarketEvent::Candle(candle) => self.handle_candle(candle).await?,
-            MarketEvent::OrderBook(_) => {}
+            MarketEvent::OrderBook(book) => self.orchestrator.on_order_book(&book),
         }
         Ok(())
     }
@@ -2126,6 +2579,9 @@ impl OmsActor {
             snapshot.last_trade = Some(tick.price);
             snapshot.last_trade_ts = Some(tick.exchange_timestamp);
         }
+        if let Some(paper) = self.paper_client() {
+            paper.update_price(&tick.symbol, tick.price);
+        }
         let was_liquidate_only = self.portfolio.liquidate_only();
         if let Err(err) = self.portfolio.update_market_data(tick.symbol, tick.price) {
             warn!(symbol = %tick.symbol, error = %err, "failed to refresh market data");
@@ -2136,7 +2592,7 @@ impl OmsActor {
             alert_liquidate_only(self.alerts.clone()).await;
             self.persist_state(true).await;
         }
-        Ok(())
+        self.apply_deleverage().await
     }
 
     async fn handle_candle(&mut self, candle: Candle) -> Result<()> {
@@ -2144,11 +2600,8 @@ impl OmsActor {
             snapshot.last_candle = Some(candle.clone());
             snapshot.last_trade = Some(candle.close);
         }
-        if self.exec_backend.is_paper() {
-            let client = self.orchestrator.execution_engine().client();
-            if let Some(paper) = client.as_any().downcast_ref::<PaperExecutionClient>() {
-                paper.update_price(&candle.symbol, candle.close);
-            }
+        if let Some(paper) = self.paper_client() {
+            paper.update_price(&candle.symbol, candle.close);
         }
         let was_liquidate_only = self.portfolio.liquidate_only();
         if let Err(err) = self
@@ -2160,6 +2613,9 @@ impl OmsActor {
         if !was_liquidate_only && self.portfolio.liquidate_only() {
             alert_liquidate_only(self.alerts.clone()).await;
         }
+        if let Some(equity) = self.portfolio.equity().to_f64() {
+            self.metrics.update_equity(equity);
+        }
         self.live_state.last_candle_ts = Some(candle.timestamp);
         self.live_state
             .last_prices
@@ -2174,6 +2630,26 @@ impl OmsActor {
         );
         self.orchestrator.update_risk_context(candle.symbol, ctx);
         self.persist_state(true).await;
+        self.apply_deleverage().await
+    }
+
+    /// Route reduce signals from the drawdown guard when a new tier is crossed.
+    async fn apply_deleverage(&mut self) -> Result<()> {
+        let Some(guard) = self.deleverage.as_mut() else {
+            return Ok(());
+        };
+        let signals = guard.evaluate(self.portfolio.equity(), &self.portfolio.positions());
+        if signals.is_empty() {
+            return Ok(());
+        }
+        warn!(
+            tiers = guard.active_tiers(),
+            orders = signals.len(),
+            "drawdown tier crossed; deleveraging open positions"
+        );
+        for signal in signals {
+            self.handle_signal(signal).await?;
+        }
         Ok(())
     }
 
@@ -2186,9 +2662,27 @@ impl OmsActor {
             &self.market_registry,
         );
         self.orchestrator.update_risk_context(signal.symbol, ctx);
-        match self.orchestrator.on_signal(&signal, &ctx).await {
+        let stale_before = self.orchestrator.stale_signals_dropped();
+        let cooldown_before = self.orchestrator.cooldown_signals_dropped();
+        let result = self.orchestrator.on_signal(&signal, &ctx).await;
+        let stale = self
+            .orchestrator
+            .stale_signals_dropped()
+            .saturating_sub(stale_before);
+        if stale > 0 {
+            self.metrics.inc_stale_signals(stale);
+        }
+        let cooled = self
+            .orchestrator
+            .cooldown_signals_dropped()
+            .saturating_sub(cooldown_before);
+        if cooled > 0 {
+            self.metrics.inc_cooldown_signals(cooled);
+        }
+        match result {
             Ok(_) => {
                 self.alerts.reset_order_failures().await;
+                self.settle_paper_fills().await;
             }
             Err(err) => {
                 warn!(
@@ -2209,7 +2703,32 @@ impl OmsActor {
         Ok(())
     }
 
-    async fn handle_fill(&mut self, fill: Fill) -> Result<()> {
+    /// Paper execution client backing the OMS, if orders are being simulated.
+    fn paper_client(&self) -> Option<PaperExecutionClient> {
+        if !self.exec_backend.is_paper() {
+            return None;
+        }
+        let client = self.orchestrator.execution_engine().client();
+        client
+            .as_any()
+            .downcast_ref::<PaperExecutionClient>()
+            .cloned()
+    }
+
+    /// Feed fills simulated by the paper client back through the regular fill path.
+    async fn settle_paper_fills(&mut self) {
+        let Some(paper) = self.paper_client() else {
+            return;
+        };
+        for fill in paper.drain_fills().await {
+            if let Err(err) = self.handle_fill(fill, None).await {
+                warn!(error = %err, "failed to apply paper fill");
+            }
+        }
+    }
+
+    /// Apply `fill` to the portfolio and ledger; `exec_id` is the venue execution id, if known.
+    async fn handle_fill(&mut self, fill: Fill, exec_id: Option<String>) -> Result<()> {
         let was_liquidate_only = self.portfolio.liquidate_only();
         let instrument = self
             .market_registry
@@ -2220,11 +2739,12 @@ impl OmsActor {
             .portfolio
             .apply_fill_positions(&fill)
             .with_context(|| format!("failed to update positions for {}", fill.symbol))?;
-        let mut ledger_entries = entries_from_fill(FillLedgerContext::new(
-            &fill,
-            &instrument,
-            impact.realized_pnl,
-        ));
+        let mut context = FillLedgerContext::new(&fill, &instrument, impact.realized_pnl)
+            .with_strategy(self.strategy.active_strategy());
+        if let Some(exec_id) = exec_id {
+            context = context.with_fill_id(exec_id);
+        }
+        let mut ledger_entries = entries_from_fill(context);
         for entry in &mut ledger_entries {
             entry.sequence = self.ledger_seq.next();
         }
@@ -2297,8 +2817,40 @@ impl OmsActor {
         Ok(())
     }
 
+    /// Last known status of `order_id`, including recently closed orders.
+    fn known_status(&self, order_id: &str) -> Option<OrderStatus> {
+        self.live_state
+            .open_orders
+            .iter()
+            .find(|existing| existing.id == order_id)
+            .map(|existing| existing.status)
+            .or_else(|| {
+                self.closed_orders
+                    .iter()
+                    .rev()
+                    .find(|(id, _)| id == order_id)
+                    .map(|(_, status)| *status)
+            })
+    }
+
     async fn handle_order_update(&mut self, order: Order) -> Result<()> {
+        let previous = self.known_status(&order.id);
+        if let Some(current) = previous {
+            if !current.can_transition_to(order.status) {
+                warn!(
+                    order_id = %order.id,
+                    symbol = %order.request.symbol,
+                    from = ?current,
+                    to = ?order.status,
+                    "ignoring illegal order status transition"
+                );
+                return Ok(());
+            }
+        }
         self.orchestrator.on_order_update(&order).await;
+        if !previous.is_some_and(OrderStatus::is_terminal) {
+            self.metrics.record_order_outcome(&order);
+        }
         if let Some(handle) = &self.recorder {
             handle.record_order(order.clone());
         }
@@ -2332,11 +2884,13 @@ impl OmsActor {
         if !found {
             self.live_state.open_orders.push(order.clone());
         }
-        if matches!(
-            order.status,
-            OrderStatus::Filled | OrderStatus::Canceled | OrderStatus::Rejected
-        ) {
+        if order.status.is_terminal() {
             self.live_state.open_orders.retain(|o| o.id != order.id);
+            if self.closed_orders.len() == CLOSED_ORDER_HISTORY {
+                self.closed_orders.pop_front();
+            }
+            self.closed_orders
+                .push_back((order.id.clone(), order.status));
         }
         self.bus
             .publish(Event::OrderUpdate(OrderUpdateEvent { order }));
@@ -2344,10 +2898,32 @@ impl OmsActor {
         Ok(())
     }
 
+    /// Journal a funding settlement and credit or debit it against the portfolio.
+    async fn handle_funding(&mut self, funding: FundingEntry) -> Result<()> {
+        let instrument = self
+            .market_registry
+            .get(funding.symbol)
+            .ok_or_else(|| anyhow!("unknown instrument {}", funding.symbol))?
+            .clone();
+        let mut ledger_entries =
+            entries_from_funding(&funding, FundingLedgerContext::new(&instrument));
+        for entry in &mut ledger_entries {
+            entry.sequence = self.ledger_seq.next();
+        }
+        self.portfolio
+            .apply_ledger_entries(&ledger_entries)
+            .context("failed to update portfolio balances from funding")?;
+        self.persist_ledger_entries(ledger_entries).await;
+        self.snapshot_portfolio();
+        self.persist_state(false).await;
+        Ok(())
+    }
+
     async fn handle_broker_event(&mut self, event: BrokerEvent) -> Result<()> {
         match event {
             BrokerEvent::OrderUpdate(order) => self.handle_order_update(order).await?,
-            BrokerEvent::Fill(fill) => self.handle_fill(fill).await?,
+            BrokerEvent::Fill { fill, exec_id } => self.handle_fill(fill, exec_id).await?,
+            BrokerEvent::Funding(funding) => self.handle_funding(funding).await?,
         }
         Ok(())
     }
@@ -2356,7 +2932,8 @@ impl OmsActor {
         let mut snapshot = self.live_state.clone();
         if include_strategy {
             if let Some(state) = self.strategy.snapshot().await {
-                snapshot.strategy_state = Some(state);
+                snapshot.strategy_name = Some(state.name);
+                snapshot.strategy_state = Some(state.state);
             }
         }
         snapshot.execution_checkpoint = self.execution_tracker.snapshot().await;
@@ -2415,12 +2992,23 @@ impl OmsActor {
             }
             OmsRequest::ApplyFills { fills, respond_to } => {
                 for fill in fills {
-                    if let Err(err) = self.handle_fill(fill).await {
+                    if let Err(err) = self.handle_fill(fill, None).await {
                         warn!(error = %err, "failed to apply reconciliation fill");
                     }
                 }
                 let _ = respond_to.send(());
             }
+            OmsRequest::ResyncPositions {
+                positions,
+                respond_to,
+            } => {
+                for (symbol, remote) in positions {
+                    self.portfolio.resync_position(symbol, remote);
+                }
+                self.snapshot_portfolio();
+                self.persist_state(true).await;
+                let _ = respond_to.send(());
+            }
         }
     }
 }
@@ -2430,6 +3018,7 @@ impl StrategyActor {
     fn new(
         strategy: Box<dyn Strategy>,
         ctx: StrategyContext,
+        streamed: &[Symbol],
         market_rx: mpsc::Receiver<MarketEvent>,
         cmd_rx: mpsc::Receiver<StrategyCommand>,
         signal_tx: mpsc::Sender<Signal>,
@@ -2444,11 +3033,14 @@ impl StrategyActor {
         Self {
             strategy,
             ctx,
+            streamed: streamed.iter().copied().collect(),
+            routes: None,
             market_rx,
             cmd_rx,
             signal_tx,
             bus,
             metrics,
+            error_alerts: spawn_strategy_alerts(alerts.clone()),
             alerts,
             recorder,
             market_registry,
@@ -2463,22 +3055,70 @@ impl StrategyActor {
                 biased;
                 Some(event) = self.market_rx.recv() => {
                     if let Err(err) = self.handle_market_event(event).await {
-                        warn!(error = %err, "strategy market handler failed");
+                        self.escalate_strategy_error("strategy market handler failed", &err);
                     }
                 }
                 Some(cmd) = self.cmd_rx.recv() => {
-                    if let Err(err) = self.handle_command(cmd).await {
-                        warn!(error = %err, "strategy command failed");
+                    if self.process_command(cmd).await {
+                        debug!("strategy actor stopped");
+                        return;
                     }
                 }
                 _ = self.shutdown.wait() => break,
                 else => break,
             }
         }
+        self.serve_until_stopped().await;
         debug!("strategy actor stopped");
     }
 
+    /// Handle one command, returning `true` once the actor has been asked to stop.
+    async fn process_command(&mut self, cmd: StrategyCommand) -> bool {
+        let stop = matches!(cmd, StrategyCommand::Stop { .. });
+        if let Err(err) = self.handle_command(cmd).await {
+            self.escalate_strategy_error("strategy command failed", &err);
+        }
+        stop
+    }
+
+    /// Keep answering commands after shutdown so the OMS can deliver its last fills and
+    /// collect the final snapshot, giving up after [`STRATEGY_SHUTDOWN_GRACE`].
+    async fn serve_until_stopped(&mut self) {
+        let deadline = tokio::time::Instant::now() + STRATEGY_SHUTDOWN_GRACE;
+        loop {
+            match tokio::time::timeout_at(deadline, self.cmd_rx.recv()).await {
+                Ok(Some(cmd)) => {
+                    if self.process_command(cmd).await {
+                        return;
+                    }
+                }
+                Ok(None) => return,
+                Err(_) => {
+                    warn!("strategy actor stopped without delivering a final snapshot");
+                    return;
+                }
+            }
+        }
+    }
+
+    /// Report a non-transient strategy failure through logs and the alert channel.
+    ///
+    /// The alert is queued rather than awaited; when the queue is full the error is only logged.
+    fn escalate_strategy_error(&self, context: &str, err: &anyhow::Error) {
+        error!("{context}: {err:#}");
+        if self
+            .error_alerts
+            .try_send(format!("{context}: {err:#}"))
+            .is_err()
+        {
+            warn!("strategy alert queue full; dropping alert");
+        }
+    }
+
     async fn handle_market_event(&mut self, event: MarketEvent) -> Result<()> {
+        if !self.routes_symbol(event.symbol()) {
+            return Ok(());
+        }
         match event {
             MarketEvent::Tick(tick) => self.handle_tick(tick).await?,
             MarketEvent::Candle(candle) => self.handle_candle(candle).await?,
@@ -2490,10 +3130,8 @@ impl StrategyActor {
     async fn handle_tick(&mut self, tick: Tick) -> Result<()> {
         self.ctx.push_tick(tick.clone());
         let call_start = Instant::now();
-        self.strategy
-            .on_tick(&self.ctx, &tick)
-            .await
-            .context("strategy failure on tick event")?;
+        let result = self.strategy.on_tick(&self.ctx, &tick).await;
+        strategy_call_result(result, "tick event")?;
         log_strategy_call("tick", call_start.elapsed());
         self.emit_signals().await;
         Ok(())
@@ -2504,15 +3142,38 @@ impl StrategyActor {
             .update_price(candle.symbol.code(), candle.close.to_f64().unwrap_or(0.0));
         self.ctx.push_candle(candle.clone());
         let call_start = Instant::now();
-        self.strategy
-            .on_candle(&self.ctx, &candle)
-            .await
-            .context("strategy failure on candle event")?;
+        let result = self.strategy.on_candle(&self.ctx, &candle).await;
+        strategy_call_result(result, "candle event")?;
         log_strategy_call("candle", call_start.elapsed());
         self.emit_signals().await;
         Ok(())
     }
 
+    /// Replay historical candles with the context in [`ContextMode::Replay`], discarding any signals.
+    async fn warm_up(&mut self, candles: Vec<Candle>) -> Result<()> {
+        if candles.is_empty() {
+            return Ok(());
+        }
+        let total = candles.len();
+        self.ctx.set_mode(ContextMode::Replay);
+        let mut discarded = 0;
+        for candle in candles {
+            self.ctx.push_candle(candle.clone());
+            let result = self.strategy.on_candle(&self.ctx, &candle).await;
+            strategy_call_result(result, "warmup candle")?;
+            discarded += self.strategy.drain_signals().len();
+        }
+        self.ctx.set_mode(ContextMode::Live);
+        if discarded > 0 {
+            warn!(
+                discarded,
+                "strategy emitted signals during warmup; they were not routed"
+            );
+        }
+        info!(candles = total, "strategy warmup complete");
+        Ok(())
+    }
+
     async fn handle_order_book(&mut self, mut book: OrderBook) -> Result<()> {
         let driver_name = self.driver_label.as_str();
         let local_checksum = if let Some(cs) = book.local_checksum {
@@ -2539,10 +3200,8 @@ impl StrategyActor {
         }
         self.ctx.push_order_book(book.clone());
         let call_start = Instant::now();
-        self.strategy
-            .on_order_book(&self.ctx, &book)
-            .await
-            .context("strategy failure on order book")?;
+        let result = self.strategy.on_order_book(&self.ctx, &book).await;
+        strategy_call_result(result, "order book")?;
         log_strategy_call("order_book", call_start.elapsed());
         self.emit_signals().await;
         Ok(())
@@ -2553,16 +3212,13 @@ impl StrategyActor {
             StrategyCommand::Account(StrategyAccountEvent::Fill { fill, positions }) => {
                 self.ctx.update_positions(positions);
                 let call_start = Instant::now();
-                self.strategy
-                    .on_fill(&self.ctx, &fill)
-                    .await
-                    .context("strategy failure on fill event")?;
+                let result = self.strategy.on_fill(&self.ctx, &fill).await;
+                strategy_call_result(result, "fill event")?;
                 log_strategy_call("fill", call_start.elapsed());
             }
             StrategyCommand::Request(request) => match request {
                 StrategyRequest::Snapshot { respond_to } => {
-                    let snapshot = self.strategy.snapshot().ok();
-                    let _ = respond_to.send(snapshot);
+                    let _ = respond_to.send(self.state_snapshot());
                 }
                 StrategyRequest::ListManagedTrades { respond_to } => {
                     let result = self
@@ -2582,11 +3238,112 @@ impl StrategyActor {
                         .map_err(|err| anyhow!(err.to_string()));
                     let _ = respond_to.send(result);
                 }
+                StrategyRequest::Switch {
+                    name,
+                    params,
+                    flatten,
+                    guard,
+                    respond_to,
+                } => {
+                    let result = self.switch_strategy(&name, params, flatten, guard).await;
+                    let _ = respond_to.send(result);
+                }
             },
+            StrategyCommand::Stop { respond_to } => {
+                let _ = respond_to.send(self.state_snapshot());
+            }
         }
         Ok(())
     }
 
+    fn state_snapshot(&self) -> Option<StrategyState> {
+        let state = self.strategy.snapshot().ok()?;
+        Some(StrategyState {
+            name: self.strategy.name().to_string(),
+            state,
+        })
+    }
+
+    fn routes_symbol(&self, symbol: Symbol) -> bool {
+        self.routes
+            .as_ref()
+            .is_none_or(|routes| routes.contains(&symbol))
+    }
+
+    /// Build `name` from `params` and swap it in, optionally flattening the old strategy's positions.
+    ///
+    /// `guard` errors are returned unchanged so callers can downcast them.
+    async fn switch_strategy(
+        &mut self,
+        name: &str,
+        params: tesser_strategy::Value,
+        flatten: bool,
+        guard: SwitchGuard,
+    ) -> Result<StrategySwitchOutcome> {
+        let next = load_strategy(name, params)
+            .map_err(|err| anyhow!("failed to configure strategy {name}: {err}"))?;
+        let subscriptions = next.subscriptions();
+        if subscriptions.is_empty() {
+            bail!("strategy {name} did not declare any subscriptions");
+        }
+        if let Some(missing) = subscriptions
+            .iter()
+            .find(|symbol| !self.streamed.contains(symbol))
+        {
+            bail!("{missing} is not part of the live market feed; restart to subscribe to it");
+        }
+        let queued_signals = self.signal_tx.max_capacity() - self.signal_tx.capacity();
+        tokio::time::timeout(SWITCH_GUARD_TIMEOUT, guard(queued_signals))
+            .await
+            .map_err(|_| anyhow!("timed out confirming that execution is idle"))??;
+
+        let previous_state = self.strategy.snapshot().unwrap_or_else(|err| {
+            warn!(error = %err, "failed to snapshot strategy before switching");
+            Value::Null
+        });
+        let dropped = self.strategy.drain_signals().len();
+        if dropped > 0 {
+            warn!(
+                dropped,
+                "discarding pending signals from the replaced strategy"
+            );
+        }
+        let mut flatten_signals = 0;
+        if flatten {
+            let owned: HashSet<Symbol> = self.strategy.subscriptions().into_iter().collect();
+            let signals: Vec<Signal> = self
+                .ctx
+                .positions()
+                .iter()
+                .filter(|position| owned.contains(&position.symbol) && !position.quantity.is_zero())
+                .map(|position| {
+                    let mut signal = Signal::new(position.symbol, SignalKind::Flatten, 1.0);
+                    signal.note = Some("strategy switch".to_string());
+                    signal
+                })
+                .collect();
+            flatten_signals = signals.len();
+            self.route_signals(signals).await;
+        }
+
+        let previous = std::mem::replace(&mut self.strategy, next);
+        self.routes = Some(subscriptions.iter().copied().collect());
+        self.ctx.ensure_history(self.strategy.required_history());
+        info!(
+            previous = previous.name(),
+            active = self.strategy.name(),
+            flatten_signals,
+            "strategy switched"
+        );
+        Ok(StrategySwitchOutcome {
+            previous: previous.name().to_string(),
+            active: self.strategy.name().to_string(),
+            previous_state,
+            subscriptions,
+            flatten_signals,
+        })
+    }
+
     fn with_pairs_strategy<R>(
         &mut self,
         f: impl FnOnce(&mut PairsTradingArbitrage) -> StrategyResult<R>,
@@ -2601,11 +3358,15 @@ impl StrategyActor {
     }
 
     async fn emit_signals(&mut self) {
-        let mut signals = self.strategy.drain_signals();
+        let signals = self.strategy.drain_signals();
+        self.route_signals(signals).await;
+    }
+
+    async fn route_signals(&mut self, mut signals: Vec<Signal>) {
         if signals.is_empty() {
             return;
         }
-        self.metrics.inc_signals(signals.len());
+        self.metrics.record_signals(self.strategy.name(), &signals);
         normalize_group_quantities(&mut signals, &self.market_registry);
         for signal in signals {
             let event_signal = signal.clone();
@@ -2625,6 +3386,131 @@ impl StrategyActor {
     }
 }
 
+/// Collect warmup candles from `--warmup-data` when supplied, otherwise from the REST API of
+/// the venue each subscription trades on.
+async fn load_warmup(
+    settings: &LiveSessionSettings,
+    routes: &[ExchangeRoute],
+    symbols: &[Symbol],
+    per_symbol: usize,
+) -> Result<Vec<Candle>> {
+    if per_symbol == 0 {
+        return Ok(Vec::new());
+    }
+    if settings.warmup_paths.is_empty() {
+        return fetch_warmup_candles(
+            routes,
+            symbols,
+            settings.category,
+            settings.interval,
+            per_symbol,
+        )
+        .await;
+    }
+    let paths = settings.warmup_paths.clone();
+    let symbols = symbols.to_vec();
+    tokio::task::spawn_blocking(move || load_warmup_candles(&paths, &symbols, per_symbol))
+        .await
+        .map_err(|err| anyhow!("warmup loader task failed: {err}"))?
+}
+
+/// Download the last `per_symbol` closed candles for each subscription from its venue.
+async fn fetch_warmup_candles(
+    routes: &[ExchangeRoute],
+    symbols: &[Symbol],
+    category: PublicChannel,
+    interval: Interval,
+    per_symbol: usize,
+) -> Result<Vec<Candle>> {
+    let end = interval.align_down(Utc::now());
+    let start = end - interval.as_duration() * per_symbol as i32;
+    let mut warmup = Vec::new();
+    for symbol in symbols {
+        let Some(route) = routes
+            .iter()
+            .find(|route| ExchangeId::from(route.name.as_str()) == symbol.exchange)
+        else {
+            continue;
+        };
+        let request = KlineRequest::new(category.as_path(), symbol.code(), interval, start, end);
+        let candles = match route.driver.as_str() {
+            "bybit" => BybitDownloader::new(&route.rest_url)?
+                .download_klines(&request)
+                .await
+                .with_context(|| format!("failed to fetch Bybit warmup candles for {symbol}"))?,
+            "binance" => {
+                let request = KlineRequest {
+                    category: "",
+                    ..request
+                };
+                BinanceDownloader::new(&route.rest_url)?
+                    .download_klines(&request)
+                    .await
+                    .with_context(|| {
+                        format!("failed to fetch Binance warmup candles for {symbol}")
+                    })?
+            }
+            other => {
+                warn!(
+                    symbol = %symbol.code(),
+                    driver = other,
+                    "driver cannot serve warmup candles; supply --warmup-data"
+                );
+                continue;
+            }
+        };
+        // The bar still forming at `end` is delivered by the live stream instead.
+        let closed: Vec<Candle> = candles
+            .into_iter()
+            .filter(|candle| candle.timestamp < end)
+            .map(|mut candle| {
+                candle.symbol = *symbol;
+                candle
+            })
+            .collect();
+        let skip = closed.len().saturating_sub(per_symbol);
+        warmup.extend(closed.into_iter().skip(skip));
+    }
+    warmup.sort_by_key(|candle| candle.timestamp);
+    Ok(warmup)
+}
+
+/// Load the most recent `per_symbol` candles for each subscription from the warmup datasets.
+fn load_warmup_candles(
+    paths: &[PathBuf],
+    symbols: &[Symbol],
+    per_symbol: usize,
+) -> Result<Vec<Candle>> {
+    let mut by_symbol: HashMap<Symbol, Vec<Candle>> = HashMap::new();
+    for path in paths {
+        let dataset = read_dataset(path)
+            .with_context(|| format!("failed to load warmup data from {}", path.display()))?;
+        for mut candle in dataset.candles {
+            // Datasets persist bare venue codes, so match subscriptions by code.
+            let Some(symbol) = symbols
+                .iter()
+                .find(|symbol| symbol.code() == candle.symbol.code())
+            else {
+                continue;
+            };
+            candle.symbol = *symbol;
+            by_symbol.entry(*symbol).or_default().push(candle);
+        }
+    }
+    let mut warmup = Vec::new();
+    for symbol in symbols {
+        let Some(mut candles) = by_symbol.remove(symbol) else {
+            warn!(symbol = %symbol.code(), "no warmup candles found");
+            continue;
+        };
+        candles.sort_by_key(|candle| candle.timestamp);
+        let skip = candles.len().saturating_sub(per_symbol);
+        warmup.extend(candles.into_iter().skip(skip));
+    }
+    warmup.sort_by_key(|candle| candle.timestamp);
+    Ok(warmup)
+}
+
 fn shared_risk_context(
     symbol: Symbol,
     portfolio: &Portfolio,
@@ -2700,6 +3586,19 @@ fn shared_risk_context(
     }
 }
 
+fn deleverage_guard(risk: &RiskManagementConfig) -> Option<DeleverageGuard> {
+    let tiers = risk
+        .deleverage_tiers
+        .iter()
+        .map(|tier| DeleverageTier {
+            drawdown: tier.drawdown,
+            reduce_fraction: tier.reduce_fraction,
+        })
+        .collect();
+    let guard = DeleverageGuard::new(tiers);
+    (!guard.is_empty()).then_some(guard)
+}
+
 async fn alert_liquidate_only(alerts: Arc<AlertManager>) {
     alerts
         .notify(
@@ -3016,6 +3915,16 @@ fn spawn_bybit_private_stream(
                                                             PrivateMessage<BybitWsExecution>,
                                                         >(value.clone()) {
                                                             for exec in msg.data {
+                                                                if exec.is_funding() {
+                                                                    forward_bybit_funding(
+                                                                        &exec,
+                                                                        exchange_id,
+                                                                        &execution_tracker,
+                                                                        &private_tx,
+                                                                    )
+                                                                    .await;
+                                                                    continue;
+                                                                }
                                                                 if let Ok(mut fill) =
                                                                     exec.to_tesser_fill(exchange_id)
                                                                 {
@@ -3053,7 +3962,12 @@ fn spawn_bybit_private_stream(
                                                                     }
                                                                     metrics.record_execution_event("ws", fill.timestamp);
                                                                     if let Err(err) = private_tx
-                                                                        .send(BrokerEvent::Fill(fill))
+                                                                        .send(BrokerEvent::Fill {
+                                                                            fill,
+                                                                            exec_id: Some(
+                                                                                exec.exec_id.clone(),
+                                                                            ),
+                                                                        })
                                                                         .await
                                                                     {
                                                                         error!(
@@ -3096,6 +4010,43 @@ fn spawn_bybit_private_stream(
     });
 }
 
+/// Forward a Bybit funding settlement to the OMS once per execution id.
+#[cfg(feature = "bybit")]
+async fn forward_bybit_funding(
+    exec: &BybitWsExecution,
+    exchange_id: ExchangeId,
+    execution_tracker: &ExecutionTracker,
+    private_tx: &mpsc::Sender<BrokerEvent>,
+) {
+    let amount = match exec.funding_amount() {
+        Ok(amount) => amount,
+        Err(err) => {
+            warn!(exec_id = %exec.exec_id, error = %err, "dropping malformed funding execution");
+            return;
+        }
+    };
+    let timestamp = exec.timestamp();
+    if !execution_tracker
+        .record_if_new(Some(&exec.exec_id), timestamp)
+        .await
+    {
+        return;
+    }
+    let funding = FundingEntry {
+        symbol: Symbol::from_code(exchange_id, &exec.symbol),
+        amount,
+        rate: exec
+            .fee_rate
+            .as_deref()
+            .and_then(|rate| rate.parse::<Decimal>().ok()),
+        timestamp,
+        reference_id: exec.exec_id.clone(),
+    };
+    if let Err(err) = private_tx.send(BrokerEvent::Funding(funding)).await {
+        error!("failed to send funding event: {err}");
+    }
+}
+
 #[cfg(feature = "bybit")]
 async fn replay_bybit_executions(
     bybit: &BybitClient,
@@ -3127,7 +4078,11 @@ async fn replay_bybit_executions(
             }
         }
         metrics.record_execution_event("rest", fill.timestamp);
-        if let Err(err) = private_tx.send(BrokerEvent::Fill(fill)).await {
+        let event = BrokerEvent::Fill {
+            fill,
+            exec_id: record.exec_id,
+        };
+        if let Err(err) = private_tx.send(event).await {
             error!("failed to send reconciled fill: {err}");
             continue;
         }
@@ -3197,7 +4152,10 @@ fn spawn_binance_private_stream(
                                         }
                                     }
                                 }
-                                let _ = tx_orders.blocking_send(BrokerEvent::Fill(fill));
+                                let _ = tx_orders.blocking_send(BrokerEvent::Fill {
+                                    fill,
+                                    exec_id: None,
+                                });
                             }
                         }
                         if matches!(event, UserDataStreamEventsResponse::ListenKeyExpired(_)) {
@@ -3254,7 +4212,7 @@ fn spawn_binance_private_stream(
 mod tests {
     use super::*;
     use std::collections::VecDeque;
-    use tesser_core::OrderBookLevel;
+    use tesser_core::{CandleStatus, OrderBookLevel};
 
     struct StaticStream {
         ticks: VecDeque<Tick>,
@@ -3308,6 +4266,7 @@ mod tests {
             close: Decimal::from(close),
             volume: Decimal::ONE,
             timestamp: Utc::now(),
+            status: CandleStatus::Closed,
         }
     }
 
@@ -3329,7 +4288,7 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn router_market_stream_fans_in_events() {
+    async fn router_market_stream_interleaves_venues() {
         let shutdown = ShutdownSignal::new();
         let stream_a = Box::new(StaticStream::new(
             vec![build_tick("A", 1), build_tick("A", 2)],
@@ -3345,13 +4304,16 @@ mod tests {
             vec![("A".into(), stream_a), ("B".into(), stream_b)],
             shutdown.clone(),
         );
+        // Let both pumps drain their static sources into the buffers.
+        tokio::time::sleep(Duration::from_millis(50)).await;
 
         let first = router.next_tick().await.unwrap().unwrap();
         let second = router.next_tick().await.unwrap().unwrap();
         let third = router.next_tick().await.unwrap().unwrap();
         assert_eq!(first.symbol, Symbol::from("A"));
-        assert_eq!(second.symbol, Symbol::from("A"));
-        assert_eq!(third.symbol, Symbol::from("B"));
+        assert_eq!(second.symbol, Symbol::from("B"));
+        assert_eq!(third.symbol, Symbol::from("A"));
+        assert!(router.next_tick().await.unwrap().is_none());
 
         let candle_a = router.next_candle().await.unwrap().unwrap();
         let candle_b = router.next_candle().await.unwrap().unwrap();
@@ -3365,4 +4327,320 @@ mod tests {
 
         shutdown.trigger();
     }
+
+    struct FailingStream;
+
+    #[async_trait::async_trait]
+    impl LiveMarketStream for FailingStream {
+        async fn next_tick(&mut self) -> BrokerResult<Option<Tick>> {
+            Err(tesser_broker::BrokerError::Other("socket closed".into()))
+        }
+
+        async fn next_candle(&mut self) -> BrokerResult<Option<Candle>> {
+            Ok(None)
+        }
+
+        async fn next_order_book(&mut self) -> BrokerResult<Option<OrderBook>> {
+            Ok(None)
+        }
+    }
+
+    #[tokio::test]
+    async fn router_market_stream_survives_a_failed_venue() {
+        let shutdown = ShutdownSignal::new();
+        let healthy = Box::new(StaticStream::new(
+            vec![build_tick("A", 1), build_tick("A", 2)],
+            Vec::new(),
+            Vec::new(),
+        ));
+        let mut router = RouterMarketStream::new(
+            vec![("B".into(), Box::new(FailingStream)), ("A".into(), healthy)],
+            shutdown.clone(),
+        );
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        let first = router.next_tick().await.unwrap().unwrap();
+        let second = router.next_tick().await.unwrap().unwrap();
+        assert_eq!(first.price, Decimal::from(1));
+        assert_eq!(second.price, Decimal::from(2));
+
+        shutdown.trigger();
+    }
+
+    /// Waits for `required` candles, returning `NotEnoughData` until then, or always fails.
+    struct DataHungryStrategy {
+        required: usize,
+        always_fail: bool,
+        signals: Vec<Signal>,
+    }
+
+    #[async_trait::async_trait]
+    impl Strategy for DataHungryStrategy {
+        fn name(&self) -> &str {
+            "data-hungry"
+        }
+
+        fn symbol(&self) -> Symbol {
+            Symbol::from("A")
+        }
+
+        fn configure(&mut self, _params: toml::Value) -> StrategyResult<()> {
+            Ok(())
+        }
+
+        async fn on_tick(&mut self, _ctx: &StrategyContext, _tick: &Tick) -> StrategyResult<()> {
+            Ok(())
+        }
+
+        async fn on_candle(
+            &mut self,
+            ctx: &StrategyContext,
+            candle: &Candle,
+        ) -> StrategyResult<()> {
+            if self.always_fail {
+                return Err(StrategyError::Internal("boom".into()));
+            }
+            let seen = ctx
+                .candles_for(candle.symbol)
+                .map_or(0, |candles| candles.len());
+            if seen < self.required {
+                return Err(StrategyError::NotEnoughData);
+            }
+            self.signals
+                .push(Signal::new(candle.symbol, SignalKind::EnterLong, 1.0));
+            Ok(())
+        }
+
+        async fn on_fill(&mut self, _ctx: &StrategyContext, _fill: &Fill) -> StrategyResult<()> {
+            Ok(())
+        }
+
+        fn drain_signals(&mut self) -> Vec<Signal> {
+            std::mem::take(&mut self.signals)
+        }
+
+        fn snapshot(&self) -> StrategyResult<serde_json::Value> {
+            Ok(serde_json::json!({ "required": self.required }))
+        }
+    }
+
+    fn strategy_actor(strategy: DataHungryStrategy) -> (StrategyActor, mpsc::Receiver<Signal>) {
+        let (actor, signal_rx, _handle) = strategy_actor_with(strategy, ShutdownSignal::new());
+        (actor, signal_rx)
+    }
+
+    fn strategy_actor_with(
+        strategy: DataHungryStrategy,
+        shutdown: ShutdownSignal,
+    ) -> (StrategyActor, mpsc::Receiver<Signal>, StrategyHandle) {
+        let name = strategy.name().to_string();
+        let (_market_tx, market_rx) = mpsc::channel(4);
+        let (cmd_tx, cmd_rx) = mpsc::channel(4);
+        let (signal_tx, signal_rx) = mpsc::channel(4);
+        let alerts = AlertManager::new(
+            AlertingConfig::default(),
+            AlertDispatcher::new(None),
+            None,
+            None,
+        );
+        let actor = StrategyActor::new(
+            Box::new(strategy),
+            StrategyContext::new(16),
+            &[Symbol::from("A"), Symbol::from("B")],
+            market_rx,
+            cmd_rx,
+            signal_tx,
+            Arc::new(EventBus::new(16)),
+            Arc::new(LiveMetrics::new()),
+            Arc::new(alerts),
+            None,
+            Arc::new(MarketRegistry::from_instruments(Vec::new()).unwrap()),
+            Arc::new("test".to_string()),
+            shutdown,
+        );
+        (actor, signal_rx, StrategyHandle::new(cmd_tx, name))
+    }
+
+    #[tokio::test]
+    async fn not_enough_data_is_skipped_until_the_strategy_is_ready() {
+        let (mut actor, mut signal_rx) = strategy_actor(DataHungryStrategy {
+            required: 3,
+            always_fail: false,
+            signals: Vec::new(),
+        });
+        for close in 1..=2 {
+            let event = MarketEvent::Candle(build_candle("A", close));
+            actor.handle_market_event(event).await.unwrap();
+        }
+        assert!(signal_rx.try_recv().is_err());
+
+        let event = MarketEvent::Candle(build_candle("A", 3));
+        actor.handle_market_event(event).await.unwrap();
+        let signal = signal_rx
+            .try_recv()
+            .expect("signal once enough data is buffered");
+        assert_eq!(signal.kind, SignalKind::EnterLong);
+    }
+
+    #[tokio::test]
+    async fn internal_strategy_errors_are_escalated() {
+        let (mut actor, _signal_rx) = strategy_actor(DataHungryStrategy {
+            required: 0,
+            always_fail: true,
+            signals: Vec::new(),
+        });
+        let event = MarketEvent::Candle(build_candle("A", 1));
+        let err = actor.handle_market_event(event).await.unwrap_err();
+        assert!(format!("{err:#}").contains("boom"));
+    }
+
+    #[tokio::test]
+    async fn stopped_strategy_actor_hands_over_its_final_snapshot() {
+        let shutdown = ShutdownSignal::new();
+        let (actor, _signal_rx, handle) = strategy_actor_with(
+            DataHungryStrategy {
+                required: 7,
+                always_fail: false,
+                signals: Vec::new(),
+            },
+            shutdown.clone(),
+        );
+        let task = tokio::spawn(actor.run());
+        shutdown.trigger();
+
+        // Commands sent after shutdown are still served until the final snapshot is taken.
+        let expected = Some(StrategyState {
+            name: "data-hungry".to_string(),
+            state: serde_json::json!({ "required": 7 }),
+        });
+        assert_eq!(handle.snapshot().await, expected);
+        assert_eq!(handle.stop().await, expected);
+        tokio::time::timeout(Duration::from_secs(1), task)
+            .await
+            .expect("actor exits after stop")
+            .unwrap();
+        assert_eq!(handle.snapshot().await, None);
+    }
+
+    fn sma_params(symbol: &str) -> tesser_strategy::Value {
+        toml::from_str(&format!("symbol = \"{symbol}\"")).unwrap()
+    }
+
+    fn idle_pipeline() -> SwitchGuard {
+        Box::new(|_| Box::pin(async { Ok(()) }))
+    }
+
+    #[tokio::test]
+    async fn switch_guard_sees_signals_queued_before_the_swap() {
+        let (mut actor, _signal_rx) = strategy_actor(DataHungryStrategy {
+            required: 0,
+            always_fail: false,
+            signals: Vec::new(),
+        });
+        let event = MarketEvent::Candle(build_candle("A", 1));
+        actor.handle_market_event(event).await.unwrap();
+
+        let guard: SwitchGuard = Box::new(|queued| {
+            Box::pin(async move {
+                if queued > 0 {
+                    bail!("{queued} signals still queued");
+                }
+                Ok(())
+            })
+        });
+        let err = actor
+            .switch_strategy("SmaCross", sma_params("B"), false, guard)
+            .await
+            .unwrap_err();
+        assert_eq!(err.to_string(), "1 signals still queued");
+        assert_eq!(actor.strategy.name(), "data-hungry");
+    }
+
+    #[test]
+    fn persisted_state_of_another_strategy_is_not_restored() {
+        let mut strategy = PairsTradingArbitrage::default();
+        let persisted = |name: Option<&str>| LiveState {
+            strategy_name: name.map(str::to_string),
+            strategy_state: Some(serde_json::json!({ "required": 7 })),
+            ..LiveState::default()
+        };
+
+        let mut foreign = persisted(Some("data-hungry"));
+        restore_strategy_state(&mut strategy, &mut foreign).unwrap();
+        assert!(foreign.strategy_state.is_none());
+
+        // State tagged with the configured strategy, or saved before names were recorded, is
+        // handed to `restore`, which rejects this payload.
+        let name = strategy.name().to_string();
+        assert!(restore_strategy_state(&mut strategy, &mut persisted(Some(&name))).is_err());
+        assert!(restore_strategy_state(&mut strategy, &mut persisted(None)).is_err());
+    }
+
+    #[tokio::test]
+    async fn switching_replaces_the_strategy_and_its_routes() {
+        let (mut actor, mut signal_rx) = strategy_actor(DataHungryStrategy {
+            required: 0,
+            always_fail: false,
+            signals: Vec::new(),
+        });
+        let outcome = actor
+            .switch_strategy("SmaCross", sma_params("B"), false, idle_pipeline())
+            .await
+            .unwrap();
+        assert_eq!(outcome.previous, "data-hungry");
+        assert_eq!(outcome.active, "sma-cross");
+        assert_eq!(outcome.subscriptions, vec![Symbol::from("B")]);
+        assert_eq!(actor.strategy.name(), "sma-cross");
+
+        // The old strategy would signal on every "A" candle; "A" is no longer routed.
+        let event = MarketEvent::Candle(build_candle("A", 1));
+        actor.handle_market_event(event).await.unwrap();
+        assert!(actor.ctx.candles_for(Symbol::from("A")).is_none());
+        assert!(signal_rx.try_recv().is_err());
+
+        let event = MarketEvent::Candle(build_candle("B", 1));
+        actor.handle_market_event(event).await.unwrap();
+        assert_eq!(actor.ctx.candles_for(Symbol::from("B")).unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn switching_to_an_unstreamed_symbol_keeps_the_current_strategy() {
+        let (mut actor, _signal_rx) = strategy_actor(DataHungryStrategy {
+            required: 0,
+            always_fail: false,
+            signals: Vec::new(),
+        });
+        let err = actor
+            .switch_strategy("SmaCross", sma_params("C"), false, idle_pipeline())
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("not part of the live market feed"));
+        assert_eq!(actor.strategy.name(), "data-hungry");
+        assert!(actor.routes.is_none());
+    }
+
+    #[tokio::test]
+    async fn switching_with_flatten_closes_the_old_positions() {
+        let (mut actor, mut signal_rx) = strategy_actor(DataHungryStrategy {
+            required: 0,
+            always_fail: false,
+            signals: Vec::new(),
+        });
+        actor.ctx.update_positions(vec![Position {
+            symbol: Symbol::from("A"),
+            side: Some(Side::Buy),
+            quantity: Decimal::ONE,
+            entry_price: Some(Decimal::from(10)),
+            unrealized_pnl: Decimal::ZERO,
+            updated_at: Utc::now(),
+        }]);
+        let outcome = actor
+            .switch_strategy("SmaCross", sma_params("B"), true, idle_pipeline())
+            .await
+            .unwrap();
+        assert_eq!(outcome.flatten_signals, 1);
+        let signal = signal_rx.try_recv().expect("flatten signal routed");
+        assert_eq!(signal.kind, SignalKind::Flatten);
+        assert_eq!(signal.symbol, Symbol::from("A"));
+    }
 }
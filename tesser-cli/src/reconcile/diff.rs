@@ -211,10 +211,14 @@ mod tests {
     use super::*;
     use chrono::Utc;
     use rust_decimal::prelude::FromPrimitive;
+    use std::sync::Arc;
     use tesser_core::{
         AccountBalance, AssetId, Cash, ExchangeId, OrderRequest, OrderStatus, OrderType, Position,
         Side, Symbol,
     };
+    use tesser_ledger::{LedgerEntry, LedgerType};
+    use tesser_markets::MarketRegistry;
+    use tesser_portfolio::{Portfolio, PortfolioConfig};
 
     fn sample_order(id: &str) -> Order {
         Order {
@@ -317,4 +321,53 @@ mod tests {
             Decimal::from_f64(1.0).unwrap()
         );
     }
+
+    #[test]
+    fn ledger_snapshot_reports_drift_from_exchange() {
+        let asset = AssetId::from("USDT");
+        let config = || PortfolioConfig {
+            initial_balances: HashMap::from([(asset, Decimal::from(1_000))]),
+            reporting_currency: asset,
+            max_drawdown: None,
+        };
+        let journal = vec![
+            LedgerEntry::new(
+                ExchangeId::UNSPECIFIED,
+                asset,
+                Decimal::from(-5),
+                LedgerType::Fee,
+                "fill-1",
+            ),
+            LedgerEntry::new(
+                ExchangeId::UNSPECIFIED,
+                asset,
+                Decimal::from(25),
+                LedgerType::TradeRealizedPnl,
+                "fill-1",
+            ),
+        ];
+        let ledger =
+            Portfolio::from_ledger(&journal, config(), Arc::new(MarketRegistry::default()))
+                .unwrap();
+        // The in-memory portfolio may agree with the venue while the ledger does not.
+        let remote =
+            ExchangeSnapshot::new(Vec::new(), vec![sample_balance(asset, 1_000.0)], Vec::new());
+
+        let report = StateDiffer::diff(
+            LocalSnapshot::from_ledger(&ledger, None, Vec::new()),
+            remote.clone(),
+        );
+        assert_eq!(report.balance_diff.discrepancies.len(), 1);
+        let discrepancy = &report.balance_diff.discrepancies[0];
+        assert_eq!(discrepancy.asset, asset);
+        assert_eq!(discrepancy.delta, Decimal::from(20));
+
+        let matching =
+            Portfolio::from_ledger(&[], config(), Arc::new(MarketRegistry::default())).unwrap();
+        let report = StateDiffer::diff(
+            LocalSnapshot::from_ledger(&matching, None, Vec::new()),
+            remote,
+        );
+        assert!(report.balance_diff.discrepancies.is_empty());
+    }
 }
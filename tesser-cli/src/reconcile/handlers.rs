@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use anyhow::Result;
@@ -14,9 +15,11 @@ use super::diff::{BalanceDiscrepancy, PositionDiscrepancy, ReconciliationReport}
 use super::snapshot::{ExchangeSnapshot, LocalSnapshot};
 use super::StateDiffer;
 use tesser_broker::ExecutionClient;
-use tesser_core::{AssetId, Fill, Order, OrderStatus};
+use tesser_core::{AssetId, Fill, Order, OrderRequest, OrderStatus, OrderType, Side};
+use tesser_ledger::LedgerEntry;
 use tesser_markets::MarketRegistry;
-use tesser_portfolio::{Portfolio, PortfolioConfig, PortfolioState};
+use tesser_portfolio::{LiveState, Portfolio, PortfolioConfig, PortfolioState};
+use uuid::Uuid;
 
 /// Configuration for the runtime handler.
 #[derive(Clone)]
@@ -26,6 +29,13 @@ pub struct RuntimeHandlerConfig {
     pub oms: OmsHandle,
     pub reporting_currency: AssetId,
     pub threshold: Decimal,
+    /// Per-asset overrides of `threshold` applied to balance mismatches, keyed by asset code
+    /// so one override covers the asset on every venue.
+    pub asset_thresholds: HashMap<String, Decimal>,
+    /// Place corrective orders for position divergence above `threshold`.
+    pub auto_correct_positions: bool,
+    /// Largest corrective order placed for a single symbol per reconciliation pass.
+    pub max_corrective_quantity: Decimal,
     pub client: Arc<dyn ExecutionClient>,
 }
 
@@ -36,6 +46,9 @@ pub struct RuntimeHandler {
     oms: OmsHandle,
     reporting_currency: AssetId,
     threshold: Decimal,
+    asset_thresholds: HashMap<String, Decimal>,
+    auto_correct_positions: bool,
+    max_corrective_quantity: Decimal,
     client: Arc<dyn ExecutionClient>,
 }
 
@@ -51,13 +64,22 @@ impl RuntimeHandler {
             } else {
                 config.threshold
             },
+            asset_thresholds: config
+                .asset_thresholds
+                .into_iter()
+                .filter(|(_, threshold)| *threshold > Decimal::ZERO)
+                .map(|(code, threshold)| (AssetId::from(code).code().to_string(), threshold))
+                .collect(),
+            auto_correct_positions: config.auto_correct_positions,
+            max_corrective_quantity: config.max_corrective_quantity.max(Decimal::ZERO),
             client: config.client,
         }
     }
 
     pub async fn handle(&self, report: &ReconciliationReport) -> Result<()> {
         let mut severe_findings = Vec::new();
-        self.handle_positions(&report.position_diff.discrepancies, &mut severe_findings);
+        self.handle_positions(&report.position_diff.discrepancies, &mut severe_findings)
+            .await;
         self.handle_balances(&report.balance_diff.discrepancies, &mut severe_findings);
         self.resolve_ghost_orders(&report.order_diff.ghosts).await;
         self.resolve_zombie_orders(&report.order_diff.zombies).await;
@@ -75,7 +97,7 @@ impl RuntimeHandler {
         Ok(())
     }
 
-    fn handle_positions(&self, entries: &[PositionDiscrepancy], severe: &mut Vec<String>) {
+    async fn handle_positions(&self, entries: &[PositionDiscrepancy], severe: &mut Vec<String>) {
         for entry in entries {
             let diff = entry.delta.abs();
             let symbol_label = entry.symbol.code().to_string();
@@ -101,6 +123,9 @@ impl RuntimeHandler {
                     pct = %pct,
                     "position mismatch exceeds threshold"
                 );
+                if self.auto_correct_positions {
+                    self.correct_position(entry).await;
+                }
                 severe.push(format!(
                     "{symbol_label} local={} remote={} diff={diff}",
                     entry.local_signed, entry.remote_signed
@@ -109,18 +134,79 @@ impl RuntimeHandler {
         }
     }
 
+    /// Place a market order that closes the divergence for one symbol.
+    ///
+    /// The exchange is authoritative, so the local book first adopts the remote position. The
+    /// order then trades `local - remote`, capped at `max_corrective_quantity`, back toward the
+    /// exposure the strategy was running. Its fill lands on the resynced book once, so venue and
+    /// book move together and the next pass sees them agree. Divergence beyond the cap is left
+    /// to an operator. The caller still escalates, so trading stays liquidate-only until then.
+    async fn correct_position(&self, entry: &PositionDiscrepancy) {
+        let quantity = entry.delta.abs().min(self.max_corrective_quantity);
+        if quantity <= Decimal::ZERO {
+            return;
+        }
+        let side = if entry.delta > Decimal::ZERO {
+            Side::Buy
+        } else {
+            Side::Sell
+        };
+        self.oms
+            .resync_positions(vec![(entry.symbol, entry.remote.clone())])
+            .await;
+        let request = OrderRequest {
+            symbol: entry.symbol,
+            side,
+            order_type: OrderType::Market,
+            quantity,
+            price: None,
+            trigger_price: None,
+            time_in_force: None,
+            client_order_id: Some(format!("reconcile-{}", Uuid::new_v4())),
+            take_profit: None,
+            stop_loss: None,
+            display_quantity: None,
+        };
+        match self.client.place_order(request).await {
+            Ok(order) => {
+                warn!(
+                    order_id = %order.id,
+                    symbol = %entry.symbol.code(),
+                    side = ?side,
+                    qty = %quantity,
+                    remaining = %(entry.delta.abs() - quantity),
+                    "placed corrective order for position divergence"
+                );
+                self.metrics
+                    .inc_reconciliation_action("position_corrected", 1);
+            }
+            Err(err) => {
+                error!(
+                    symbol = %entry.symbol.code(),
+                    error = %err,
+                    "failed to place corrective order for position divergence"
+                );
+            }
+        }
+    }
+
     fn handle_balances(&self, entries: &[BalanceDiscrepancy], severe: &mut Vec<String>) {
-        let reporting = self.reporting_currency;
-        let label = reporting.to_string();
-        let entry = entries.iter().find(|entry| entry.asset == reporting);
-        let (local_cash, remote_cash) = entry
-            .map(|record| {
-                (
-                    record.local_available.unwrap_or(Decimal::ZERO),
-                    record.remote_available.unwrap_or(Decimal::ZERO),
-                )
-            })
-            .unwrap_or((Decimal::ZERO, Decimal::ZERO));
+        if !entries
+            .iter()
+            .any(|entry| entry.asset == self.reporting_currency)
+        {
+            self.metrics
+                .update_balance_diff(&self.reporting_currency.to_string(), 0.0);
+        }
+        for entry in entries {
+            self.handle_balance(entry, severe);
+        }
+    }
+
+    fn handle_balance(&self, entry: &BalanceDiscrepancy, severe: &mut Vec<String>) {
+        let label = entry.asset.to_string();
+        let local_cash = entry.local_available.unwrap_or(Decimal::ZERO);
+        let remote_cash = entry.remote_available.unwrap_or(Decimal::ZERO);
         let diff = (local_cash - remote_cash).abs();
         self.metrics
             .update_balance_diff(&label, diff.to_f64().unwrap_or(0.0));
@@ -135,7 +221,7 @@ impl RuntimeHandler {
             "balance mismatch detected during reconciliation"
         );
         let pct = normalize_diff(diff, remote_cash);
-        if pct >= self.threshold {
+        if pct >= self.balance_threshold(entry.asset) {
             error!(
                 currency = %label,
                 local = %local_cash,
@@ -150,6 +236,13 @@ impl RuntimeHandler {
         }
     }
 
+    fn balance_threshold(&self, asset: AssetId) -> Decimal {
+        self.asset_thresholds
+            .get(asset.code())
+            .copied()
+            .unwrap_or(self.threshold)
+    }
+
     async fn resolve_ghost_orders(&self, ghosts: &[Order]) {
         if ghosts.is_empty() {
             return;
@@ -276,6 +369,34 @@ impl StartupHandler {
         }
     }
 
+    /// Build the local side of the startup diff with balances replayed from the `ledger`, so
+    /// drift between the ledger and the exchange is reported.
+    ///
+    /// Falls back to the persisted portfolio when the ledger is empty or cannot be replayed.
+    pub fn local_snapshot(&self, state: &LiveState, ledger: &[LedgerEntry]) -> LocalSnapshot {
+        if ledger.is_empty() {
+            return LocalSnapshot::from_live_state(state);
+        }
+        match Portfolio::from_ledger(
+            ledger,
+            self.portfolio_config.clone(),
+            self.market_registry.clone(),
+        ) {
+            Ok(book) => LocalSnapshot::from_ledger(
+                &book,
+                state.portfolio.as_ref(),
+                state.open_orders.clone(),
+            ),
+            Err(err) => {
+                warn!(
+                    error = %err,
+                    "failed to replay ledger; reconciling against persisted portfolio"
+                );
+                LocalSnapshot::from_live_state(state)
+            }
+        }
+    }
+
     pub fn reconcile(
         &self,
         local_state: LocalSnapshot,
@@ -306,6 +427,15 @@ impl StartupHandler {
                 "position divergence detected during startup"
             );
         }
+        for discrepancy in &report.balance_diff.discrepancies {
+            warn!(
+                asset = %discrepancy.asset,
+                local = ?discrepancy.local_available,
+                remote = ?discrepancy.remote_available,
+                delta = %discrepancy.delta,
+                "local balance diverges from the exchange during startup"
+            );
+        }
         if !report.order_diff.ghosts.is_empty() {
             for order in &report.order_diff.ghosts {
                 info!(
@@ -376,7 +506,7 @@ mod tests {
     use crate::{
         alerts::{AlertDispatcher, AlertManager},
         live::{OmsHandle, OmsRequest},
-        reconcile::OrderDiff,
+        reconcile::{BalanceDiff, OrderDiff, PositionDiff},
         telemetry::LiveMetrics,
     };
     use async_trait::async_trait;
@@ -391,9 +521,10 @@ mod tests {
     use tesser_broker::{BrokerError, BrokerInfo, BrokerResult};
     use tesser_config::AlertingConfig;
     use tesser_core::{
-        AccountBalance, Instrument, OrderId, OrderRequest, OrderType, OrderUpdateRequest, Position,
-        Side, Symbol,
+        AccountBalance, ExchangeId, Instrument, OrderId, OrderRequest, OrderType,
+        OrderUpdateRequest, Position, Side, Symbol,
     };
+    use tesser_ledger::LedgerType;
     use tokio::sync::{mpsc, Mutex};
     use tokio::task::JoinHandle;
     use uuid::Uuid;
@@ -439,6 +570,59 @@ mod tests {
         assert_eq!(outcome.cancel_orders[0].id, "remote");
     }
 
+    #[test]
+    fn startup_snapshot_reports_ledger_drift() {
+        let usdt = AssetId::from("USDT");
+        let config = PortfolioConfig {
+            initial_balances: HashMap::from([(usdt, Decimal::from(1_000))]),
+            reporting_currency: usdt,
+            max_drawdown: None,
+        };
+        let registry = Arc::new(MarketRegistry::default());
+        let handler = StartupHandler::new(StartupHandlerConfig {
+            portfolio_config: config.clone(),
+            market_registry: registry.clone(),
+        });
+        // The persisted portfolio agrees with the venue while the ledger booked a fee.
+        let persisted = LiveState {
+            portfolio: Some(Portfolio::new(config, registry).snapshot()),
+            open_orders: vec![sample_order("local", "BTCUSDT")],
+            ..LiveState::default()
+        };
+        let ledger = vec![LedgerEntry::new(
+            ExchangeId::UNSPECIFIED,
+            usdt,
+            Decimal::from(-30),
+            LedgerType::Fee,
+            "fill-1",
+        )];
+        let remote = ExchangeSnapshot::new(
+            Vec::new(),
+            vec![AccountBalance {
+                exchange: ExchangeId::UNSPECIFIED,
+                asset: usdt,
+                total: Decimal::from(1_000),
+                available: Decimal::from(1_000),
+                updated_at: Utc::now(),
+            }],
+            vec![sample_order("local", "BTCUSDT")],
+        );
+
+        let local = handler.local_snapshot(&persisted, &ledger);
+        assert_eq!(local.open_orders.len(), 1);
+        let report = StateDiffer::diff(local, remote.clone());
+        assert_eq!(report.balance_diff.discrepancies.len(), 1);
+        assert_eq!(report.balance_diff.discrepancies[0].asset, usdt);
+        assert_eq!(
+            report.balance_diff.discrepancies[0].delta,
+            Decimal::from(-30)
+        );
+
+        // With an empty ledger the persisted portfolio is diffed instead.
+        let report = StateDiffer::diff(handler.local_snapshot(&persisted, &[]), remote);
+        assert!(report.balance_diff.discrepancies.is_empty());
+    }
+
     #[tokio::test]
     async fn runtime_handler_replays_ghost_fills() {
         let harness = TestOmsHarness::new();
@@ -494,10 +678,223 @@ mod tests {
         harness.shutdown().await;
     }
 
+    fn balance_discrepancy(asset: &str, local: i64, remote: i64) -> BalanceDiscrepancy {
+        BalanceDiscrepancy {
+            asset: AssetId::from(asset),
+            local_available: Some(Decimal::new(local, 0)),
+            remote_available: Some(Decimal::new(remote, 0)),
+            delta: Decimal::new(local - remote, 0),
+        }
+    }
+
+    #[tokio::test]
+    async fn runtime_handler_escalates_non_reporting_balance_mismatches() {
+        let harness = TestOmsHarness::new();
+        let handler =
+            runtime_handler_for_tests(harness.handle(), Arc::new(FakeExecutionClient::default()));
+        let entries = vec![
+            balance_discrepancy("BTC", 3, 2),
+            balance_discrepancy("ETH", 10, 14),
+        ];
+        let mut severe = Vec::new();
+        handler.handle_balances(&entries, &mut severe);
+        assert_eq!(severe.len(), 2);
+        assert!(severe[0].contains("BTC balance"));
+        assert!(severe[1].contains("ETH balance"));
+
+        let report = ReconciliationReport {
+            balance_diff: BalanceDiff {
+                discrepancies: entries,
+            },
+            ..Default::default()
+        };
+        handler.handle(&report).await.unwrap();
+        assert!(harness.state.liquidate_only.load(Ordering::SeqCst));
+        harness.shutdown().await;
+    }
+
+    #[tokio::test]
+    async fn per_asset_threshold_overrides_default() {
+        let harness = TestOmsHarness::new();
+        let handler = runtime_handler_with_thresholds(
+            harness.handle(),
+            Arc::new(FakeExecutionClient::default()),
+            HashMap::from([("eth".to_string(), Decimal::new(5, 1))]),
+        );
+        // Discrepancies carry exchange-qualified assets; the override is keyed by code.
+        let entries = vec![
+            balance_discrepancy("bybit:BTC", 3, 2),
+            balance_discrepancy("bybit:ETH", 10, 14),
+        ];
+        let mut severe = Vec::new();
+        handler.handle_balances(&entries, &mut severe);
+        assert_eq!(severe.len(), 1);
+        assert!(severe[0].contains("BTC balance"));
+        harness.shutdown().await;
+    }
+
+    fn remote_position(symbol: &str, signed: i64) -> Option<Position> {
+        (signed != 0).then(|| Position {
+            symbol: Symbol::from(symbol),
+            side: Some(if signed > 0 { Side::Buy } else { Side::Sell }),
+            quantity: Decimal::new(signed.abs(), 0),
+            entry_price: Some(Decimal::new(100, 0)),
+            unrealized_pnl: Decimal::ZERO,
+            updated_at: Utc::now(),
+        })
+    }
+
+    fn position_discrepancy(symbol: &str, local: i64, remote: i64) -> PositionDiscrepancy {
+        PositionDiscrepancy {
+            symbol: Symbol::from(symbol),
+            local: None,
+            remote: remote_position(symbol, remote),
+            local_signed: Decimal::new(local, 0),
+            remote_signed: Decimal::new(remote, 0),
+            delta: Decimal::new(local - remote, 0),
+        }
+    }
+
+    #[tokio::test]
+    async fn auto_correct_places_capped_corrective_orders_and_keeps_the_halt() {
+        let harness = TestOmsHarness::new();
+        let client = Arc::new(FakeExecutionClient::default());
+        let mut config = runtime_config_for_tests(harness.handle(), client.clone());
+        config.auto_correct_positions = true;
+        config.max_corrective_quantity = Decimal::new(15, 1);
+        let handler = RuntimeHandler::new(config);
+        let report = ReconciliationReport {
+            position_diff: PositionDiff {
+                discrepancies: vec![
+                    position_discrepancy("BTCUSDT", 0, 2),
+                    position_discrepancy("ETHUSDT", 3, 2),
+                ],
+            },
+            ..Default::default()
+        };
+        handler.handle(&report).await.unwrap();
+
+        let mut placed = client.placed().await;
+        placed.sort_by_key(|request| request.symbol.code().to_string());
+        assert_eq!(placed.len(), 2);
+        // BTCUSDT diverges by 2, more than the cap.
+        assert_eq!(placed[0].symbol, Symbol::from("BTCUSDT"));
+        assert_eq!(placed[0].side, Side::Sell);
+        assert_eq!(placed[0].quantity, Decimal::new(15, 1));
+        assert_eq!(placed[0].order_type, OrderType::Market);
+        assert_eq!(placed[1].symbol, Symbol::from("ETHUSDT"));
+        assert_eq!(placed[1].side, Side::Buy);
+        assert_eq!(placed[1].quantity, Decimal::ONE);
+        assert!(placed.iter().all(|request| request
+            .client_order_id
+            .as_deref()
+            .is_some_and(|id| id.starts_with("reconcile-"))));
+        assert_eq!(harness.state.resynced.lock().await.len(), 2);
+        assert!(harness.state.liquidate_only.load(Ordering::SeqCst));
+        harness.shutdown().await;
+    }
+
+    #[tokio::test]
+    async fn corrective_fill_reconciles_clean_on_the_next_pass() {
+        let harness = TestOmsHarness::new();
+        let client = Arc::new(FakeExecutionClient::default());
+        let mut config = runtime_config_for_tests(harness.handle(), client.clone());
+        config.auto_correct_positions = true;
+        let handler = RuntimeHandler::new(config);
+        let registry = MarketRegistry::from_instruments(vec![Instrument {
+            symbol: Symbol::from("BTCUSDT"),
+            base: AssetId::from("BTC"),
+            quote: AssetId::from("USDT"),
+            kind: tesser_core::InstrumentKind::LinearPerpetual,
+            settlement_currency: AssetId::from("USDT"),
+            tick_size: Decimal::new(1, 1),
+            lot_size: Decimal::new(1, 3),
+            min_notional: None,
+        }])
+        .unwrap();
+        let mut portfolio = Portfolio::new(PortfolioConfig::default(), Arc::new(registry));
+        let first = StateDiffer::diff(
+            LocalSnapshot::new(Some(portfolio.snapshot()), Vec::new()),
+            ExchangeSnapshot::new(
+                remote_position("BTCUSDT", -1).into_iter().collect(),
+                Vec::new(),
+                Vec::new(),
+            ),
+        );
+        assert_eq!(first.position_diff.discrepancies.len(), 1);
+        handler.handle(&first).await.unwrap();
+
+        // Replay what the OMS sees: the resync, then the corrective order's fill.
+        for (symbol, position) in harness.state.resynced.lock().await.clone() {
+            portfolio.resync_position(symbol, position);
+        }
+        let placed = client.placed().await;
+        assert_eq!(placed.len(), 1);
+        assert_eq!(placed[0].side, Side::Buy);
+        assert_eq!(placed[0].quantity, Decimal::ONE);
+        portfolio
+            .apply_fill_positions(&Fill {
+                order_id: "corrective".into(),
+                symbol: placed[0].symbol,
+                side: placed[0].side,
+                fill_price: Decimal::new(100, 0),
+                fill_quantity: placed[0].quantity,
+                fee: None,
+                fee_asset: None,
+                timestamp: Utc::now(),
+            })
+            .unwrap();
+
+        // The same fill flattened the venue position.
+        let second = StateDiffer::diff(
+            LocalSnapshot::new(Some(portfolio.snapshot()), Vec::new()),
+            ExchangeSnapshot::new(Vec::new(), Vec::new(), Vec::new()),
+        );
+        assert!(second.position_diff.discrepancies.is_empty());
+        handler.handle(&second).await.unwrap();
+        assert_eq!(client.placed().await.len(), 1);
+        harness.shutdown().await;
+    }
+
+    #[tokio::test]
+    async fn position_divergence_places_no_order_without_auto_correct() {
+        let harness = TestOmsHarness::new();
+        let client = Arc::new(FakeExecutionClient::default());
+        let handler = runtime_handler_for_tests(harness.handle(), client.clone());
+        let report = ReconciliationReport {
+            position_diff: PositionDiff {
+                discrepancies: vec![position_discrepancy("BTCUSDT", 0, 2)],
+            },
+            ..Default::default()
+        };
+        handler.handle(&report).await.unwrap();
+        assert!(harness.state.resynced.lock().await.is_empty());
+        assert!(client.placed().await.is_empty());
+        assert!(harness.state.liquidate_only.load(Ordering::SeqCst));
+        harness.shutdown().await;
+    }
+
     fn runtime_handler_for_tests(
         oms: OmsHandle,
         client: Arc<FakeExecutionClient>,
     ) -> RuntimeHandler {
+        runtime_handler_with_thresholds(oms, client, HashMap::new())
+    }
+
+    fn runtime_handler_with_thresholds(
+        oms: OmsHandle,
+        client: Arc<FakeExecutionClient>,
+        asset_thresholds: HashMap<String, Decimal>,
+    ) -> RuntimeHandler {
+        let mut config = runtime_config_for_tests(oms, client);
+        config.asset_thresholds = asset_thresholds;
+        RuntimeHandler::new(config)
+    }
+
+    fn runtime_config_for_tests(
+        oms: OmsHandle,
+        client: Arc<FakeExecutionClient>,
+    ) -> RuntimeHandlerConfig {
         let alerts = Arc::new(AlertManager::new(
             AlertingConfig::default(),
             AlertDispatcher::new(None),
@@ -505,20 +902,24 @@ mod tests {
             None,
         ));
         let metrics = Arc::new(LiveMetrics::new());
-        RuntimeHandler::new(RuntimeHandlerConfig {
+        RuntimeHandlerConfig {
             alerts,
             metrics,
             oms,
             reporting_currency: AssetId::from("USDT"),
             threshold: Decimal::new(1, 3),
+            asset_thresholds: HashMap::new(),
+            auto_correct_positions: false,
+            max_corrective_quantity: Decimal::ONE,
             client,
-        })
+        }
     }
 
     #[derive(Default)]
     struct TestOmsState {
         orders: Mutex<Vec<Order>>,
         fills: Mutex<Vec<Fill>>,
+        resynced: Mutex<Vec<(Symbol, Option<Position>)>>,
         liquidate_only: AtomicBool,
     }
 
@@ -550,6 +951,13 @@ mod tests {
                             }
                             let _ = respond_to.send(());
                         }
+                        OmsRequest::ResyncPositions {
+                            positions,
+                            respond_to,
+                        } => {
+                            state_handle.resynced.lock().await.extend(positions);
+                            let _ = respond_to.send(());
+                        }
                         OmsRequest::EnterLiquidateOnly { respond_to } => {
                             state_handle.liquidate_only.store(true, Ordering::SeqCst);
                             let _ = respond_to.send(true);
@@ -579,19 +987,24 @@ mod tests {
     struct FakeExecutionClient {
         fills: Mutex<HashMap<String, Vec<Fill>>>,
         canceled: Mutex<Vec<(String, Symbol)>>,
+        placed: Mutex<Vec<OrderRequest>>,
     }
 
     impl FakeExecutionClient {
         fn with_fills(map: HashMap<String, Vec<Fill>>) -> Self {
             Self {
                 fills: Mutex::new(map),
-                canceled: Mutex::new(Vec::new()),
+                ..Default::default()
             }
         }
 
         async fn canceled(&self) -> Vec<(String, Symbol)> {
             self.canceled.lock().await.clone()
         }
+
+        async fn placed(&self) -> Vec<OrderRequest> {
+            self.placed.lock().await.clone()
+        }
     }
 
     #[async_trait]
@@ -604,8 +1017,17 @@ mod tests {
             }
         }
 
-        async fn place_order(&self, _request: OrderRequest) -> BrokerResult<Order> {
-            Err(BrokerError::Other("not implemented".into()))
+        async fn place_order(&self, request: OrderRequest) -> BrokerResult<Order> {
+            self.placed.lock().await.push(request.clone());
+            Ok(Order {
+                id: Uuid::new_v4().to_string(),
+                request,
+                status: OrderStatus::Accepted,
+                filled_quantity: Decimal::ZERO,
+                avg_fill_price: None,
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+            })
         }
 
         async fn cancel_order(&self, order_id: OrderId, symbol: Symbol) -> BrokerResult<()> {
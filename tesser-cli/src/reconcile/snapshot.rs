@@ -1,5 +1,5 @@
 use tesser_core::{AccountBalance, Order, Position};
-use tesser_portfolio::{LiveState, PortfolioState};
+use tesser_portfolio::{LiveState, Portfolio, PortfolioState};
 
 /// Lightweight clone of the OMS state used for reconciliation.
 #[derive(Clone, Debug, Default)]
@@ -24,6 +24,30 @@ impl LocalSnapshot {
             open_orders: state.open_orders.clone(),
         }
     }
+
+    /// Build a snapshot whose balances are the ledger's record (see [`Portfolio::from_ledger`])
+    /// rather than the in-memory portfolio, so the diff reports ledger/exchange drift.
+    ///
+    /// The ledger holds no positions; they are copied from `positions_from` when provided.
+    pub fn from_ledger(
+        ledger: &Portfolio,
+        positions_from: Option<&PortfolioState>,
+        open_orders: Vec<Order>,
+    ) -> Self {
+        let mut portfolio = ledger.snapshot();
+        if let Some(source) = positions_from {
+            portfolio.positions = source.positions.clone();
+            for (exchange, account) in &source.sub_accounts {
+                let target = portfolio.sub_accounts.entry(*exchange).or_default();
+                target.exchange = *exchange;
+                target.positions = account.positions.clone();
+            }
+        }
+        Self {
+            portfolio: Some(portfolio),
+            open_orders,
+        }
+    }
 }
 
 /// Remote exchange snapshot captured via REST calls.
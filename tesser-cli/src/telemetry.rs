@@ -1,8 +1,9 @@
+use std::collections::VecDeque;
 use std::convert::Infallible;
 use std::fs::{self, OpenOptions};
 use std::net::SocketAddr;
 use std::path::Path;
-use std::sync::OnceLock;
+use std::sync::{Mutex, OnceLock};
 
 use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
@@ -10,6 +11,7 @@ use hyper::body::Body;
 use hyper::service::{make_service_fn, service_fn};
 use hyper::{Request, Response, StatusCode};
 use prometheus::{Encoder, Gauge, GaugeVec, IntCounter, IntCounterVec, Registry, TextEncoder};
+use tesser_core::{Order, OrderStatus, Signal, SignalKind};
 use tracing::{error, info};
 use tracing_appender::non_blocking::WorkerGuard;
 use tracing_subscriber::{fmt, layer::SubscriberExt, util::SubscriberInitExt, EnvFilter, Layer};
@@ -55,15 +57,161 @@ pub fn init_tracing(filter: &str, log_path: Option<&Path>) -> Result<()> {
     Ok(())
 }
 
+/// Default number of equity returns used by the rolling Sharpe gauge.
+pub const DEFAULT_SHARPE_WINDOW: usize = 120;
+
+/// Bounded buffer of simple returns derived from consecutive equity samples.
+struct RollingReturns {
+    window: usize,
+    last_equity: Option<f64>,
+    returns: VecDeque<f64>,
+}
+
+impl RollingReturns {
+    fn new(window: usize) -> Self {
+        let window = window.max(2);
+        Self {
+            window,
+            last_equity: None,
+            returns: VecDeque::with_capacity(window),
+        }
+    }
+
+    fn push(&mut self, equity: f64) {
+        if !equity.is_finite() {
+            return;
+        }
+        if let Some(previous) = self.last_equity {
+            if previous.abs() > f64::EPSILON {
+                if self.returns.len() == self.window {
+                    self.returns.pop_front();
+                }
+                self.returns.push_back((equity - previous) / previous);
+            }
+        }
+        self.last_equity = Some(equity);
+    }
+
+    /// Per-sample Sharpe ratio (mean / sample std dev); zero when undefined.
+    fn sharpe(&self) -> f64 {
+        let count = self.returns.len();
+        if count < 2 {
+            return 0.0;
+        }
+        let n = count as f64;
+        let mean = self.returns.iter().sum::<f64>() / n;
+        let variance = self
+            .returns
+            .iter()
+            .map(|value| (value - mean).powi(2))
+            .sum::<f64>()
+            / (n - 1.0);
+        let std_dev = variance.sqrt();
+        if !std_dev.is_finite() || std_dev <= f64::EPSILON {
+            0.0
+        } else {
+            mean / std_dev
+        }
+    }
+}
+
+/// Terminal outcome of an order as tracked by the fill statistics.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OrderOutcome {
+    Filled,
+    PartiallyFilled,
+    CanceledUnfilled,
+    Rejected,
+}
+
+impl OrderOutcome {
+    const ALL: [OrderOutcome; 4] = [
+        Self::Filled,
+        Self::PartiallyFilled,
+        Self::CanceledUnfilled,
+        Self::Rejected,
+    ];
+
+    /// Classify a closed order; `None` while the order is still working.
+    pub fn classify(order: &Order) -> Option<Self> {
+        match order.status {
+            OrderStatus::Filled => Some(Self::Filled),
+            OrderStatus::Canceled if order.filled_quantity > tesser_core::Quantity::ZERO => {
+                Some(Self::PartiallyFilled)
+            }
+            OrderStatus::Canceled => Some(Self::CanceledUnfilled),
+            OrderStatus::Rejected => Some(Self::Rejected),
+            _ => None,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::Filled => "filled",
+            Self::PartiallyFilled => "partially_filled",
+            Self::CanceledUnfilled => "canceled_unfilled",
+            Self::Rejected => "rejected",
+        }
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// Running tally of order outcomes and fill latency.
+#[derive(Default)]
+struct FillStats {
+    outcomes: [u64; 4],
+    latency_sum: f64,
+    latency_count: u64,
+}
+
+impl FillStats {
+    fn record(&mut self, outcome: OrderOutcome, latency: Option<f64>) {
+        self.outcomes[outcome.index()] += 1;
+        if let Some(seconds) = latency.filter(|value| value.is_finite() && *value >= 0.0) {
+            self.latency_sum += seconds;
+            self.latency_count += 1;
+        }
+    }
+
+    fn closed(&self) -> u64 {
+        self.outcomes.iter().sum()
+    }
+
+    /// Share of closed orders ending with `outcome`; zero before any order closes.
+    fn ratio(&self, outcome: OrderOutcome) -> f64 {
+        let closed = self.closed();
+        if closed == 0 {
+            0.0
+        } else {
+            self.outcomes[outcome.index()] as f64 / closed as f64
+        }
+    }
+
+    fn average_latency(&self) -> f64 {
+        if self.latency_count == 0 {
+            0.0
+        } else {
+            self.latency_sum / self.latency_count as f64
+        }
+    }
+}
+
 /// Prometheus metrics collected during live trading.
 pub struct LiveMetrics {
     registry: Registry,
     ticks_total: IntCounter,
     candles_total: IntCounter,
     signals_total: IntCounter,
+    signals_by_kind: IntCounterVec,
     orders_total: IntCounter,
     order_failures: IntCounter,
     panic_closes: IntCounter,
+    stale_signals: IntCounter,
+    cooldown_signals: IntCounter,
+    rejected_ticks: IntCounterVec,
     router_failures: IntCounterVec,
     equity_gauge: Gauge,
     price_gauge: GaugeVec,
@@ -77,6 +225,12 @@ pub struct LiveMetrics {
     execution_events: IntCounterVec,
     execution_backfills: IntCounter,
     reconciliation_actions: IntCounterVec,
+    rolling_sharpe: Gauge,
+    returns: Mutex<RollingReturns>,
+    order_outcomes: IntCounterVec,
+    order_outcome_ratio: GaugeVec,
+    fill_latency: Gauge,
+    fill_stats: Mutex<FillStats>,
 }
 
 impl LiveMetrics {
@@ -87,12 +241,43 @@ impl LiveMetrics {
             IntCounter::new("candles_total", "Number of candles processed").unwrap();
         let signals_total =
             IntCounter::new("signals_total", "Signals emitted by strategies").unwrap();
+        let signals_by_kind = IntCounterVec::new(
+            prometheus::Opts::new(
+                "tesser_strategy_signals_total",
+                "Signals emitted grouped by strategy and signal kind",
+            ),
+            &["strategy", "kind"],
+        )
+        .unwrap();
         let orders_total =
             IntCounter::new("orders_total", "Orders submitted to execution").unwrap();
         let order_failures = IntCounter::new("order_failures_total", "Execution failures").unwrap();
         let panic_closes =
             IntCounter::new("tesser_panic_closes_total", "Execution group panic closes").unwrap();
+        let stale_signals = IntCounter::new(
+            "tesser_stale_signals_dropped_total",
+            "Signals dropped because their validity window elapsed",
+        )
+        .unwrap();
+        let cooldown_signals = IntCounter::new(
+            "tesser_cooldown_signals_dropped_total",
+            "Entry signals dropped during the post-stop-loss cooldown",
+        )
+        .unwrap();
+        let rejected_ticks = IntCounterVec::new(
+            prometheus::Opts::new(
+                "tesser_rejected_ticks_total",
+                "Ticks dropped by the tick sanitizer grouped by reason",
+            ),
+            &["reason"],
+        )
+        .unwrap();
         let equity_gauge = Gauge::new("portfolio_equity", "Current portfolio equity").unwrap();
+        let rolling_sharpe = Gauge::new(
+            "tesser_rolling_sharpe",
+            "Sharpe ratio of per-sample equity returns over the rolling window",
+        )
+        .unwrap();
         let price_gauge = GaugeVec::new(
             prometheus::Opts::new("symbol_price", "Latest observed price per symbol"),
             &["symbol"],
@@ -174,14 +359,44 @@ impl LiveMetrics {
             &["action"],
         )
         .unwrap();
+        let order_outcomes = IntCounterVec::new(
+            prometheus::Opts::new(
+                "tesser_order_outcomes_total",
+                "Closed orders grouped by outcome (filled, partially_filled, canceled_unfilled, rejected)",
+            ),
+            &["outcome"],
+        )
+        .unwrap();
+        let order_outcome_ratio = GaugeVec::new(
+            prometheus::Opts::new(
+                "tesser_order_outcome_ratio",
+                "Share of closed orders ending with each outcome",
+            ),
+            &["outcome"],
+        )
+        .unwrap();
+        let fill_latency = Gauge::new(
+            "tesser_order_fill_latency_seconds_avg",
+            "Average seconds between order creation and its final fill",
+        )
+        .unwrap();
 
         registry.register(Box::new(ticks_total.clone())).unwrap();
         registry.register(Box::new(candles_total.clone())).unwrap();
         registry.register(Box::new(signals_total.clone())).unwrap();
+        registry
+            .register(Box::new(signals_by_kind.clone()))
+            .unwrap();
         registry.register(Box::new(orders_total.clone())).unwrap();
         registry.register(Box::new(order_failures.clone())).unwrap();
         registry.register(Box::new(panic_closes.clone())).unwrap();
+        registry.register(Box::new(stale_signals.clone())).unwrap();
+        registry
+            .register(Box::new(cooldown_signals.clone()))
+            .unwrap();
+        registry.register(Box::new(rejected_ticks.clone())).unwrap();
         registry.register(Box::new(equity_gauge.clone())).unwrap();
+        registry.register(Box::new(rolling_sharpe.clone())).unwrap();
         registry.register(Box::new(price_gauge.clone())).unwrap();
         registry.register(Box::new(data_gap_gauge.clone())).unwrap();
         registry
@@ -214,12 +429,19 @@ impl LiveMetrics {
         registry
             .register(Box::new(reconciliation_actions.clone()))
             .unwrap();
+        registry.register(Box::new(order_outcomes.clone())).unwrap();
+        registry
+            .register(Box::new(order_outcome_ratio.clone()))
+            .unwrap();
+        registry.register(Box::new(fill_latency.clone())).unwrap();
+        tesser_rpc::metrics::global().register(&registry).unwrap();
 
         Self {
             registry,
             ticks_total,
             candles_total,
             signals_total,
+            signals_by_kind,
             orders_total,
             order_failures,
             equity_gauge,
@@ -231,14 +453,30 @@ impl LiveMetrics {
             last_data_timestamp,
             checksum_mismatches,
             panic_closes,
+            stale_signals,
+            cooldown_signals,
+            rejected_ticks,
             router_failures,
             execution_timestamp,
             execution_events,
             execution_backfills,
             reconciliation_actions,
+            rolling_sharpe,
+            returns: Mutex::new(RollingReturns::new(DEFAULT_SHARPE_WINDOW)),
+            order_outcomes,
+            order_outcome_ratio,
+            fill_latency,
+            fill_stats: Mutex::new(FillStats::default()),
         }
     }
 
+    /// Override the number of equity returns used by the rolling Sharpe gauge.
+    #[must_use]
+    pub fn with_sharpe_window(mut self, window: usize) -> Self {
+        self.returns = Mutex::new(RollingReturns::new(window));
+        self
+    }
+
     pub fn registry(&self) -> Registry {
         self.registry.clone()
     }
@@ -255,6 +493,16 @@ impl LiveMetrics {
         self.signals_total.inc_by(count as u64);
     }
 
+    /// Count a batch of drained signals, both in total and per strategy and kind.
+    pub fn record_signals(&self, strategy: &str, signals: &[Signal]) {
+        self.inc_signals(signals.len());
+        for signal in signals {
+            self.signals_by_kind
+                .with_label_values(&[strategy, signal_kind_label(signal.kind)])
+                .inc();
+        }
+    }
+
     pub fn inc_order(&self) {
         self.orders_total.inc();
     }
@@ -267,6 +515,18 @@ impl LiveMetrics {
         self.panic_closes.inc();
     }
 
+    pub fn inc_stale_signals(&self, count: u64) {
+        self.stale_signals.inc_by(count);
+    }
+
+    pub fn inc_cooldown_signals(&self, count: u64) {
+        self.cooldown_signals.inc_by(count);
+    }
+
+    pub fn inc_rejected_tick(&self, reason: &str) {
+        self.rejected_ticks.with_label_values(&[reason]).inc();
+    }
+
     pub fn inc_router_failure(&self, reason: &str) {
         self.router_failures.with_label_values(&[reason]).inc();
     }
@@ -282,6 +542,51 @@ impl LiveMetrics {
 
     pub fn update_equity(&self, equity: f64) {
         self.equity_gauge.set(equity);
+        let sharpe = {
+            let mut returns = self.returns.lock().unwrap();
+            returns.push(equity);
+            returns.sharpe()
+        };
+        self.rolling_sharpe.set(sharpe);
+    }
+
+    /// Latest value reported by the rolling Sharpe gauge.
+    pub fn rolling_sharpe(&self) -> f64 {
+        self.rolling_sharpe.get()
+    }
+
+    /// Fold an order update into the fill statistics once the order has closed.
+    ///
+    /// Fill latency is measured from creation to the update that completed the fill.
+    pub fn record_order_outcome(&self, order: &Order) {
+        let Some(outcome) = OrderOutcome::classify(order) else {
+            return;
+        };
+        let latency = (outcome == OrderOutcome::Filled)
+            .then(|| (order.updated_at - order.created_at).num_milliseconds() as f64 / 1_000.0);
+        self.order_outcomes
+            .with_label_values(&[outcome.label()])
+            .inc();
+        let mut stats = self.fill_stats.lock().unwrap();
+        stats.record(outcome, latency);
+        for outcome in OrderOutcome::ALL {
+            self.order_outcome_ratio
+                .with_label_values(&[outcome.label()])
+                .set(stats.ratio(outcome));
+        }
+        self.fill_latency.set(stats.average_latency());
+    }
+
+    /// Latest share of closed orders ending with `outcome`.
+    pub fn order_outcome_ratio(&self, outcome: OrderOutcome) -> f64 {
+        self.order_outcome_ratio
+            .with_label_values(&[outcome.label()])
+            .get()
+    }
+
+    /// Latest average fill latency in seconds.
+    pub fn average_fill_latency(&self) -> f64 {
+        self.fill_latency.get()
     }
 
     pub fn update_price(&self, symbol: &str, price: f64) {
@@ -328,6 +633,18 @@ impl LiveMetrics {
     }
 }
 
+fn signal_kind_label(kind: SignalKind) -> &'static str {
+    match kind {
+        SignalKind::EnterLong => "enter_long",
+        SignalKind::ExitLong => "exit_long",
+        SignalKind::EnterShort => "enter_short",
+        SignalKind::ExitShort => "exit_short",
+        SignalKind::Flatten => "flatten",
+        SignalKind::ReduceLong => "reduce_long",
+        SignalKind::ReduceShort => "reduce_short",
+    }
+}
+
 impl Default for LiveMetrics {
     fn default() -> Self {
         Self::new()
@@ -374,3 +691,140 @@ pub fn spawn_metrics_server(registry: Registry, addr: SocketAddr) -> tokio::task
         }
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+    use rust_decimal::Decimal;
+    use tesser_core::{OrderRequest, OrderType, Side, Symbol};
+
+    fn feed(metrics: &LiveMetrics, path: &[f64]) {
+        for equity in path {
+            metrics.update_equity(*equity);
+        }
+    }
+
+    #[test]
+    fn record_signals_counts_per_strategy_and_kind() {
+        let metrics = LiveMetrics::new();
+        let signals = vec![
+            Signal::new("BTCUSDT", SignalKind::EnterLong, 1.0),
+            Signal::new("BTCUSDT", SignalKind::ExitLong, 1.0),
+            Signal::new("ETHUSDT", SignalKind::EnterLong, 1.0),
+        ];
+        metrics.record_signals("SmaCross", &signals);
+        metrics.record_signals("RsiReversion", &signals[..1]);
+
+        let count = |strategy: &str, kind: &str| {
+            metrics
+                .signals_by_kind
+                .with_label_values(&[strategy, kind])
+                .get()
+        };
+        assert_eq!(count("SmaCross", "enter_long"), 2);
+        assert_eq!(count("SmaCross", "exit_long"), 1);
+        assert_eq!(count("SmaCross", "enter_short"), 0);
+        assert_eq!(count("RsiReversion", "enter_long"), 1);
+        assert_eq!(metrics.signals_total.get(), 4);
+    }
+
+    #[test]
+    fn rolling_sharpe_matches_sample_statistics() {
+        let metrics = LiveMetrics::new();
+        // Returns: +10%, -10%, +10% -> mean 1/30, sample std sqrt(0.04/3).
+        feed(&metrics, &[100.0, 110.0, 99.0, 108.9]);
+        let expected = (1.0 / 30.0) / (0.04f64 / 3.0).sqrt();
+        assert!((metrics.rolling_sharpe() - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn rolling_sharpe_only_uses_the_configured_window() {
+        let metrics = LiveMetrics::new().with_sharpe_window(2);
+        // Early drawdown falls out of the window, leaving +10% and +20% returns.
+        feed(&metrics, &[100.0, 50.0, 55.0, 66.0]);
+        let expected = 0.15 / (0.005f64).sqrt();
+        assert!((metrics.rolling_sharpe() - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn rolling_sharpe_reports_zero_for_flat_or_short_paths() {
+        let metrics = LiveMetrics::new();
+        metrics.update_equity(100.0);
+        assert_eq!(metrics.rolling_sharpe(), 0.0);
+        feed(&metrics, &[100.0, 100.0, 100.0]);
+        assert_eq!(metrics.rolling_sharpe(), 0.0);
+        assert!(!metrics.rolling_sharpe().is_nan());
+    }
+
+    fn closed_order(id: &str, status: OrderStatus, filled: Decimal, latency_secs: i64) -> Order {
+        let created_at = Utc::now();
+        Order {
+            id: id.to_string(),
+            request: OrderRequest {
+                symbol: Symbol::from("BTCUSDT"),
+                side: Side::Buy,
+                order_type: OrderType::Limit,
+                quantity: Decimal::new(2, 0),
+                price: Some(Decimal::new(100, 0)),
+                trigger_price: None,
+                time_in_force: None,
+                client_order_id: None,
+                take_profit: None,
+                stop_loss: None,
+                display_quantity: None,
+            },
+            status,
+            filled_quantity: filled,
+            avg_fill_price: None,
+            created_at,
+            updated_at: created_at + Duration::seconds(latency_secs),
+        }
+    }
+
+    #[test]
+    fn order_outcome_ratios_follow_closed_lifecycles() {
+        let metrics = LiveMetrics::new();
+        let two = Decimal::new(2, 0);
+        // Working orders are not counted until they close.
+        metrics.record_order_outcome(&closed_order(
+            "a",
+            OrderStatus::PartiallyFilled,
+            Decimal::ONE,
+            1,
+        ));
+        assert_eq!(metrics.order_outcome_ratio(OrderOutcome::Filled), 0.0);
+
+        metrics.record_order_outcome(&closed_order("a", OrderStatus::Filled, two, 2));
+        metrics.record_order_outcome(&closed_order("b", OrderStatus::Filled, two, 4));
+        metrics.record_order_outcome(&closed_order("c", OrderStatus::Canceled, Decimal::ONE, 9));
+        metrics.record_order_outcome(&closed_order("d", OrderStatus::Canceled, Decimal::ZERO, 9));
+
+        assert_eq!(metrics.order_outcome_ratio(OrderOutcome::Filled), 0.5);
+        assert_eq!(
+            metrics.order_outcome_ratio(OrderOutcome::PartiallyFilled),
+            0.25
+        );
+        assert_eq!(
+            metrics.order_outcome_ratio(OrderOutcome::CanceledUnfilled),
+            0.25
+        );
+        assert_eq!(metrics.order_outcome_ratio(OrderOutcome::Rejected), 0.0);
+        assert_eq!(
+            metrics.order_outcomes.with_label_values(&["filled"]).get(),
+            2
+        );
+    }
+
+    #[test]
+    fn average_fill_latency_only_counts_full_fills() {
+        let metrics = LiveMetrics::new();
+        assert_eq!(metrics.average_fill_latency(), 0.0);
+        let two = Decimal::new(2, 0);
+        metrics.record_order_outcome(&closed_order("a", OrderStatus::Filled, two, 2));
+        metrics.record_order_outcome(&closed_order("b", OrderStatus::Rejected, Decimal::ZERO, 30));
+        metrics.record_order_outcome(&closed_order("c", OrderStatus::Filled, two, 6));
+        assert!((metrics.average_fill_latency() - 4.0).abs() < 1e-9);
+        assert!((metrics.order_outcome_ratio(OrderOutcome::Rejected) - 1.0 / 3.0).abs() < 1e-9);
+    }
+}
@@ -5,15 +5,21 @@ use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
 use tesser_rpc::conversions::from_decimal_proto;
 use tesser_rpc::proto::{
-    self, CancelAllResponse, Event, GetStatusResponse, OrderSnapshot, PortfolioSnapshot,
+    self, AlgorithmInfo, CancelAllResponse, Event, GetStatusResponse, OrderSnapshot,
+    PortfolioSnapshot,
 };
 
+use crate::tui::reconnect::ReconnectPolicy;
+
 const LOG_CAPACITY: usize = 200;
 
 #[derive(Clone)]
 pub struct MonitorConfig {
     pub control_addr: String,
     pub tick_rate: Duration,
+    pub reconnect: ReconnectPolicy,
+    /// Bearer token attached to every control-plane request.
+    pub control_token: Option<String>,
 }
 
 impl MonitorConfig {
@@ -21,8 +27,22 @@ impl MonitorConfig {
         Self {
             control_addr,
             tick_rate,
+            reconnect: ReconnectPolicy::default(),
+            control_token: None,
         }
     }
+
+    #[must_use]
+    pub fn with_reconnect(mut self, reconnect: ReconnectPolicy) -> Self {
+        self.reconnect = reconnect;
+        self
+    }
+
+    #[must_use]
+    pub fn with_control_token(mut self, token: Option<String>) -> Self {
+        self.control_token = token;
+        self
+    }
 }
 
 pub struct MonitorApp {
@@ -30,15 +50,19 @@ pub struct MonitorApp {
     status: Option<GetStatusResponse>,
     portfolio: Option<PortfolioSnapshot>,
     orders: Vec<OrderSnapshot>,
+    algorithms: Vec<AlgorithmInfo>,
     logs: VecDeque<LogEntry>,
     last_error: Option<String>,
     last_snapshot_at: Option<DateTime<Utc>>,
     last_event_at: Option<DateTime<Utc>>,
     stream_connected: bool,
+    reconnect_attempt: Option<u32>,
     cancel_in_progress: bool,
     should_quit: bool,
     overlay: CommandOverlay,
     overlay_error: Option<String>,
+    order_sort: OrderSortKey,
+    order_filter: Option<String>,
 }
 
 impl MonitorApp {
@@ -48,15 +72,19 @@ impl MonitorApp {
             status: None,
             portfolio: None,
             orders: Vec::new(),
+            algorithms: Vec::new(),
             logs: VecDeque::with_capacity(LOG_CAPACITY),
             last_error: None,
             last_snapshot_at: None,
             last_event_at: None,
             stream_connected: false,
+            reconnect_attempt: None,
             cancel_in_progress: false,
             should_quit: false,
             overlay: CommandOverlay::Hidden,
             overlay_error: None,
+            order_sort: OrderSortKey::default(),
+            order_filter: None,
         }
     }
 
@@ -94,6 +122,12 @@ impl MonitorApp {
         self.clear_error();
     }
 
+    pub fn on_algorithms(&mut self, algorithms: Vec<AlgorithmInfo>) {
+        self.algorithms = algorithms;
+        self.last_snapshot_at = Some(Utc::now());
+        self.clear_error();
+    }
+
     pub fn on_stream_event(&mut self, event: Event) {
         self.last_event_at = Some(Utc::now());
         if let Some(entry) = LogEntry::from_event(event) {
@@ -109,6 +143,31 @@ impl MonitorApp {
         &self.orders
     }
 
+    pub fn algorithms(&self) -> &[AlgorithmInfo] {
+        &self.algorithms
+    }
+
+    /// Open orders after applying the active symbol filter and sort column.
+    pub fn visible_orders(&self) -> Vec<OrderSnapshot> {
+        arrange_orders(
+            self.orders.clone(),
+            self.order_sort,
+            self.order_filter.as_deref(),
+        )
+    }
+
+    pub fn order_sort(&self) -> OrderSortKey {
+        self.order_sort
+    }
+
+    pub fn cycle_order_sort(&mut self) {
+        self.order_sort = self.order_sort.next();
+    }
+
+    pub fn order_filter(&self) -> Option<&str> {
+        self.order_filter.as_deref()
+    }
+
     pub fn status(&self) -> Option<&GetStatusResponse> {
         self.status.as_ref()
     }
@@ -180,6 +239,9 @@ impl MonitorApp {
     }
 
     pub fn set_stream_connected(&mut self, connected: bool) {
+        if connected && self.reconnect_attempt.take().is_some() {
+            self.record_info("Monitor stream reconnected");
+        }
         self.stream_connected = connected;
     }
 
@@ -187,6 +249,19 @@ impl MonitorApp {
         self.stream_connected
     }
 
+    pub fn set_reconnecting(&mut self, attempt: u32, delay: Duration) {
+        self.stream_connected = false;
+        self.reconnect_attempt = Some(attempt);
+        self.record_info(format!(
+            "Monitor stream reconnecting (attempt {attempt}) in {}ms",
+            delay.as_millis()
+        ));
+    }
+
+    pub fn reconnect_attempt(&self) -> Option<u32> {
+        self.reconnect_attempt
+    }
+
     pub fn set_cancel_in_progress(&mut self, active: bool) {
         self.cancel_in_progress = active;
     }
@@ -236,6 +311,42 @@ impl MonitorApp {
         }
     }
 
+    pub fn begin_order_filter(&mut self) {
+        self.overlay = CommandOverlay::Filter {
+            buffer: self.order_filter.clone().unwrap_or_default(),
+        };
+        self.overlay_error = None;
+    }
+
+    pub fn append_filter_char(&mut self, ch: char) {
+        if let CommandOverlay::Filter { buffer } = &mut self.overlay {
+            buffer.push(ch);
+        }
+    }
+
+    pub fn backspace_filter(&mut self) {
+        if let CommandOverlay::Filter { buffer } = &mut self.overlay {
+            buffer.pop();
+        }
+    }
+
+    pub fn filter_buffer(&self) -> Option<&str> {
+        match &self.overlay {
+            CommandOverlay::Filter { buffer } => Some(buffer.as_str()),
+            _ => None,
+        }
+    }
+
+    /// Apply the filter being edited; an empty buffer clears the filter.
+    pub fn apply_order_filter(&mut self) {
+        let filter = self
+            .filter_buffer()
+            .map(|buffer| buffer.trim().to_string())
+            .unwrap_or_default();
+        self.order_filter = (!filter.is_empty()).then_some(filter);
+        self.close_overlay();
+    }
+
     pub fn confirmation_buffer(&self) -> Option<&str> {
         match &self.overlay {
             CommandOverlay::Confirm { buffer } => Some(buffer.as_str()),
@@ -408,4 +519,209 @@ pub enum CommandOverlay {
     Hidden,
     Palette,
     Confirm { buffer: String },
+    Filter { buffer: String },
+}
+
+/// Column used to order the active orders table.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum OrderSortKey {
+    /// Keep the order returned by the control plane.
+    #[default]
+    None,
+    Symbol,
+    Side,
+    /// Oldest orders first.
+    Age,
+    /// Largest quantity first.
+    Quantity,
+}
+
+impl OrderSortKey {
+    pub fn next(self) -> Self {
+        match self {
+            Self::None => Self::Symbol,
+            Self::Symbol => Self::Side,
+            Self::Side => Self::Age,
+            Self::Age => Self::Quantity,
+            Self::Quantity => Self::None,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::None => "none",
+            Self::Symbol => "symbol",
+            Self::Side => "side",
+            Self::Age => "age",
+            Self::Quantity => "qty",
+        }
+    }
+}
+
+/// Filter orders by a case-insensitive symbol substring and sort them by `key`.
+///
+/// Sorting is stable, so ties keep the order supplied by the control plane.
+pub fn arrange_orders(
+    mut orders: Vec<OrderSnapshot>,
+    key: OrderSortKey,
+    symbol_filter: Option<&str>,
+) -> Vec<OrderSnapshot> {
+    if let Some(filter) = symbol_filter {
+        let needle = filter.to_ascii_uppercase();
+        orders.retain(|order| order.symbol.to_ascii_uppercase().contains(&needle));
+    }
+    match key {
+        OrderSortKey::None => {}
+        OrderSortKey::Symbol => orders.sort_by(|a, b| a.symbol.cmp(&b.symbol)),
+        OrderSortKey::Side => orders.sort_by_key(|order| side_label(order.side)),
+        OrderSortKey::Age => orders.sort_by_key(|order| {
+            let created = order.created_at.as_ref();
+            (created.is_none(), created.map(|ts| (ts.seconds, ts.nanos)))
+        }),
+        OrderSortKey::Quantity => orders.sort_by(|a, b| {
+            decimal_from_option(b.quantity.as_ref()).cmp(&decimal_from_option(a.quantity.as_ref()))
+        }),
+    }
+    orders
+}
+
+/// Render the scalar fields of an algorithm's serialized state as `key=value` pairs.
+///
+/// Identifiers, status, and nested values (such as the parent signal) are omitted.
+pub fn summarize_algo_state(state_json: &str) -> String {
+    let Ok(serde_json::Value::Object(fields)) = serde_json::from_str(state_json) else {
+        return state_json.to_string();
+    };
+    fields
+        .iter()
+        .filter(|(key, _)| !matches!(key.as_str(), "id" | "status"))
+        .filter_map(|(key, value)| {
+            let rendered = match value {
+                serde_json::Value::String(text) => text.clone(),
+                serde_json::Value::Number(_) | serde_json::Value::Bool(_) => value.to_string(),
+                _ => return None,
+            };
+            Some(format!("{key}={rendered}"))
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tesser_rpc::conversions::to_decimal_proto;
+
+    fn order(id: &str, symbol: &str, side: proto::Side, qty: i64, created: i64) -> OrderSnapshot {
+        OrderSnapshot {
+            id: id.to_string(),
+            symbol: symbol.to_string(),
+            side: side as i32,
+            quantity: Some(to_decimal_proto(Decimal::from(qty))),
+            created_at: Some(prost_types::Timestamp {
+                seconds: created,
+                nanos: 0,
+            }),
+            ..OrderSnapshot::default()
+        }
+    }
+
+    fn sample_orders() -> Vec<OrderSnapshot> {
+        vec![
+            order("1", "ETHUSDT", proto::Side::Sell, 2, 30),
+            order("2", "BTCUSDT", proto::Side::Buy, 1, 10),
+            order("3", "SOLUSDT", proto::Side::Buy, 5, 20),
+        ]
+    }
+
+    fn ids(orders: &[OrderSnapshot]) -> Vec<&str> {
+        orders.iter().map(|order| order.id.as_str()).collect()
+    }
+
+    #[test]
+    fn sorts_orders_by_each_column() {
+        let by = |key| arrange_orders(sample_orders(), key, None);
+        assert_eq!(ids(&by(OrderSortKey::None)), ["1", "2", "3"]);
+        assert_eq!(ids(&by(OrderSortKey::Symbol)), ["2", "1", "3"]);
+        assert_eq!(ids(&by(OrderSortKey::Side)), ["2", "3", "1"]);
+        assert_eq!(ids(&by(OrderSortKey::Age)), ["2", "3", "1"]);
+        assert_eq!(ids(&by(OrderSortKey::Quantity)), ["3", "1", "2"]);
+    }
+
+    #[test]
+    fn filters_orders_by_symbol_substring() {
+        let filtered = arrange_orders(sample_orders(), OrderSortKey::Symbol, Some("usdt"));
+        assert_eq!(filtered.len(), 3);
+        let filtered = arrange_orders(sample_orders(), OrderSortKey::None, Some("btc"));
+        assert_eq!(ids(&filtered), ["2"]);
+    }
+
+    #[test]
+    fn orders_without_timestamp_sort_last_by_age() {
+        let mut orders = sample_orders();
+        orders[1].created_at = None;
+        assert_eq!(
+            ids(&arrange_orders(orders, OrderSortKey::Age, None)),
+            ["3", "1", "2"]
+        );
+    }
+
+    #[test]
+    fn sort_key_cycles_back_to_none() {
+        let mut key = OrderSortKey::None;
+        for _ in 0..5 {
+            key = key.next();
+        }
+        assert_eq!(key, OrderSortKey::None);
+    }
+
+    #[test]
+    fn applying_empty_filter_clears_it() {
+        let mut app = MonitorApp::new(MonitorConfig::new(
+            "127.0.0.1:50052".to_string(),
+            Duration::from_millis(250),
+        ));
+        app.on_orders(sample_orders());
+        app.begin_order_filter();
+        for ch in "eth".chars() {
+            app.append_filter_char(ch);
+        }
+        app.apply_order_filter();
+        assert_eq!(app.order_filter(), Some("eth"));
+        assert_eq!(ids(&app.visible_orders()), ["1"]);
+
+        app.begin_order_filter();
+        for _ in 0..3 {
+            app.backspace_filter();
+        }
+        app.apply_order_filter();
+        assert_eq!(app.order_filter(), None);
+        assert_eq!(app.visible_orders().len(), 3);
+    }
+
+    #[test]
+    fn reconnect_status_clears_once_the_stream_is_back() {
+        let mut app = MonitorApp::new(MonitorConfig::new(
+            "127.0.0.1:50052".to_string(),
+            Duration::from_millis(250),
+        ));
+        app.set_stream_connected(true);
+        app.set_reconnecting(2, Duration::from_millis(500));
+        assert!(!app.stream_connected());
+        assert_eq!(app.reconnect_attempt(), Some(2));
+
+        app.set_stream_connected(true);
+        assert!(app.stream_connected());
+        assert_eq!(app.reconnect_attempt(), None);
+    }
+
+    #[test]
+    fn algo_state_summary_keeps_scalar_fields() {
+        let state = r#"{"id":"abc","status":"Working","parent_signal":{"kind":"ExitLong"},"activated":true,"highest_market_price":"105","callback_rate":"0.02"}"#;
+        assert_eq!(
+            summarize_algo_state(state),
+            "activated=true callback_rate=0.02 highest_market_price=105"
+        );
+        assert_eq!(summarize_algo_state("not json"), "not json");
+    }
 }
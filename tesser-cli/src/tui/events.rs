@@ -2,15 +2,16 @@ use std::time::Duration;
 
 use crossterm::event::{Event as CrosstermEvent, EventStream, KeyEvent, KeyEventKind};
 use futures::StreamExt;
-use tesser_rpc::proto::control_service_client::ControlServiceClient;
 use tesser_rpc::proto::{
-    Event, GetOpenOrdersRequest, GetPortfolioRequest, GetStatusRequest, GetStatusResponse,
-    MonitorRequest, OrderSnapshot, PortfolioSnapshot,
+    AlgorithmInfo, Event, GetOpenOrdersRequest, GetPortfolioRequest, GetStatusRequest,
+    GetStatusResponse, ListAlgorithmsRequest, MonitorRequest, OrderSnapshot, PortfolioSnapshot,
 };
 use tokio::sync::mpsc;
 use tokio::task::JoinHandle;
 use tokio::time::{interval, sleep, MissedTickBehavior};
-use tonic::transport::Channel;
+
+use crate::tui::reconnect::{ReconnectPolicy, ReconnectStep, Reconnector};
+use crate::tui::ControlClient;
 
 #[derive(Debug)]
 pub enum MonitorEvent {
@@ -18,9 +19,11 @@ pub enum MonitorEvent {
     Status(GetStatusResponse),
     Portfolio(PortfolioSnapshot),
     Orders(Vec<OrderSnapshot>),
+    Algorithms(Vec<AlgorithmInfo>),
     Stream(Event),
     StreamConnected,
     StreamDisconnected,
+    Reconnecting { attempt: u32, delay: Duration },
     Error(String),
 }
 
@@ -50,7 +53,7 @@ pub fn spawn_input_listener(tx: mpsc::Sender<MonitorEvent>) -> JoinHandle<()> {
 }
 
 pub fn spawn_snapshot_poller(
-    client: ControlServiceClient<Channel>,
+    client: ControlClient,
     tx: mpsc::Sender<MonitorEvent>,
 ) -> JoinHandle<()> {
     tokio::spawn(async move {
@@ -120,6 +123,27 @@ pub fn spawn_snapshot_poller(
                     {
                         break;
                     }
+                    continue;
+                }
+            }
+            match client.list_algorithms(ListAlgorithmsRequest {}).await {
+                Ok(resp) => {
+                    if tx
+                        .send(MonitorEvent::Algorithms(resp.into_inner().algorithms))
+                        .await
+                        .is_err()
+                    {
+                        break;
+                    }
+                }
+                Err(err) => {
+                    if tx
+                        .send(MonitorEvent::Error(format!("algorithms error: {err}")))
+                        .await
+                        .is_err()
+                    {
+                        break;
+                    }
                 }
             }
         }
@@ -127,17 +151,20 @@ pub fn spawn_snapshot_poller(
 }
 
 pub fn spawn_monitor_stream(
-    client: ControlServiceClient<Channel>,
+    client: ControlClient,
+    policy: ReconnectPolicy,
     tx: mpsc::Sender<MonitorEvent>,
 ) -> JoinHandle<()> {
     tokio::spawn(async move {
         let mut client = client;
+        let mut reconnector = Reconnector::new(policy);
         loop {
             if tx.is_closed() {
                 break;
             }
-            match client.monitor(MonitorRequest {}).await {
+            match client.monitor(MonitorRequest::default()).await {
                 Ok(resp) => {
+                    reconnector.on_connected();
                     if tx.send(MonitorEvent::StreamConnected).await.is_err() {
                         break;
                     }
@@ -172,7 +199,26 @@ pub fn spawn_monitor_stream(
                     }
                 }
             }
-            sleep(Duration::from_secs(1)).await;
+            match reconnector.on_failure() {
+                ReconnectStep::Retry { attempt, delay } => {
+                    if tx
+                        .send(MonitorEvent::Reconnecting { attempt, delay })
+                        .await
+                        .is_err()
+                    {
+                        break;
+                    }
+                    sleep(delay).await;
+                }
+                ReconnectStep::GiveUp { attempts } => {
+                    let _ = tx
+                        .send(MonitorEvent::Error(format!(
+                            "monitor stream gave up after {attempts} attempts"
+                        )))
+                        .await;
+                    break;
+                }
+            }
         }
     })
 }
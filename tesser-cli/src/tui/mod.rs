@@ -1,9 +1,11 @@
 mod app;
 mod events;
+mod reconnect;
 mod ui;
 
 use app::CommandOverlay;
 pub use app::{LogCategory, LogEntry, MonitorApp, MonitorConfig};
+pub use reconnect::ReconnectPolicy;
 
 use anyhow::{anyhow, Context, Result};
 use crossterm::{
@@ -13,39 +15,57 @@ use crossterm::{
 };
 use ratatui::{backend::CrosstermBackend, Terminal};
 use std::io::{stdout, Stdout};
+use tesser_rpc::auth::BearerToken;
 use tesser_rpc::proto::control_service_client::ControlServiceClient;
 use tesser_rpc::proto::CancelAllRequest;
 use tokio::sync::mpsc;
 use tokio::time::{interval, sleep, Duration, MissedTickBehavior};
-use tonic::transport::Channel;
+use tonic::service::interceptor::InterceptedService;
+use tonic::transport::{Channel, Endpoint};
 
 use crate::tui::events::MonitorEvent;
+use crate::tui::reconnect::{ReconnectStep, Reconnector};
+
+/// Control-plane client that stamps the configured bearer token onto each request.
+pub(crate) type ControlClient = ControlServiceClient<InterceptedService<Channel, BearerToken>>;
 
 pub async fn run_monitor(config: MonitorConfig) -> Result<()> {
     let endpoint = normalize_endpoint(&config.control_addr);
-    let client = connect_with_retry(&endpoint).await?;
+    let token = BearerToken::new(config.control_token.as_deref())
+        .map_err(|status| anyhow!("invalid control token: {}", status.message()))?;
+    let client = connect_with_retry(&endpoint, token, config.reconnect).await?;
     let mut terminal = setup_terminal().context("failed to setup terminal")?;
     let result = run_loop(&mut terminal, client, config.clone()).await;
     teardown_terminal(&mut terminal)?;
     result
 }
 
-async fn connect_with_retry(target: &str) -> Result<ControlServiceClient<Channel>> {
-    const MAX_ATTEMPTS: usize = 30;
-    const BACKOFF: Duration = Duration::from_millis(250);
-    let mut last_err = None;
-    for _ in 0..MAX_ATTEMPTS {
-        match ControlServiceClient::connect(target.to_string()).await {
-            Ok(client) => return Ok(client),
-            Err(err) => {
-                last_err = Some(err);
-                sleep(BACKOFF).await;
+async fn connect_with_retry(
+    target: &str,
+    token: BearerToken,
+    policy: ReconnectPolicy,
+) -> Result<ControlClient> {
+    let endpoint = Endpoint::from_shared(target.to_string())
+        .with_context(|| format!("invalid control plane address {target}"))?;
+    let mut reconnector = Reconnector::new(policy);
+    loop {
+        match endpoint.connect().await {
+            Ok(channel) => {
+                return Ok(ControlServiceClient::with_interceptor(
+                    channel,
+                    token.clone(),
+                ))
             }
+            Err(err) => match reconnector.on_failure() {
+                ReconnectStep::Retry { delay, .. } => sleep(delay).await,
+                ReconnectStep::GiveUp { attempts } => {
+                    return Err(anyhow!(
+                        "failed to connect to control plane after {attempts} attempts: {err}"
+                    ))
+                }
+            },
         }
     }
-    Err(last_err
-        .map(|err| anyhow!("failed to connect to control plane: {err}"))
-        .unwrap_or_else(|| anyhow!("failed to connect to control plane")))
 }
 
 fn setup_terminal() -> Result<Terminal<CrosstermBackend<Stdout>>> {
@@ -69,7 +89,7 @@ fn teardown_terminal(terminal: &mut Terminal<CrosstermBackend<Stdout>>) -> Resul
 
 async fn run_loop(
     terminal: &mut Terminal<CrosstermBackend<Stdout>>,
-    base_client: ControlServiceClient<Channel>,
+    base_client: ControlClient,
     config: MonitorConfig,
 ) -> Result<()> {
     let mut app = MonitorApp::new(config.clone());
@@ -80,7 +100,7 @@ async fn run_loop(
     let (tx, mut rx) = mpsc::channel(512);
     events::spawn_input_listener(tx.clone());
     events::spawn_snapshot_poller(poll_client, tx.clone());
-    events::spawn_monitor_stream(stream_client, tx.clone());
+    events::spawn_monitor_stream(stream_client, config.reconnect, tx.clone());
 
     let mut ticker = interval(config.tick_rate);
     ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
@@ -113,16 +133,18 @@ async fn run_loop(
 async fn handle_event(
     event: MonitorEvent,
     app: &mut MonitorApp,
-    cancel_client: &mut ControlServiceClient<Channel>,
+    cancel_client: &mut ControlClient,
 ) -> Result<()> {
     match event {
         MonitorEvent::Input(key) => handle_key_event(key, app, cancel_client).await?,
         MonitorEvent::Status(status) => app.on_status(status),
         MonitorEvent::Portfolio(snapshot) => app.on_portfolio(snapshot),
         MonitorEvent::Orders(orders) => app.on_orders(orders),
+        MonitorEvent::Algorithms(algorithms) => app.on_algorithms(algorithms),
         MonitorEvent::Stream(event) => app.on_stream_event(event),
         MonitorEvent::StreamConnected => app.set_stream_connected(true),
         MonitorEvent::StreamDisconnected => app.set_stream_connected(false),
+        MonitorEvent::Reconnecting { attempt, delay } => app.set_reconnecting(attempt, delay),
         MonitorEvent::Error(msg) => app.set_error(msg),
     }
     Ok(())
@@ -131,7 +153,7 @@ async fn handle_event(
 async fn handle_key_event(
     key: KeyEvent,
     app: &mut MonitorApp,
-    cancel_client: &mut ControlServiceClient<Channel>,
+    cancel_client: &mut ControlClient,
 ) -> Result<()> {
     if handle_overlay_key(key, app, cancel_client).await? {
         return Ok(());
@@ -156,6 +178,9 @@ async fn handle_key_event(
                 app.request_quit();
             }
         }
+        crossterm::event::KeyCode::Char('s') | crossterm::event::KeyCode::Char('S') => {
+            app.cycle_order_sort();
+        }
         _ => {}
     }
     Ok(())
@@ -164,7 +189,7 @@ async fn handle_key_event(
 async fn handle_overlay_key(
     key: KeyEvent,
     app: &mut MonitorApp,
-    cancel_client: &mut ControlServiceClient<Channel>,
+    cancel_client: &mut ControlClient,
 ) -> Result<bool> {
     use crossterm::event::KeyCode;
     match app.overlay() {
@@ -175,6 +200,9 @@ async fn handle_overlay_key(
                     app.begin_cancel_confirmation();
                     app.record_info("Confirm cancel-all by typing 'cancel all'");
                 }
+                KeyCode::Char('f') | KeyCode::Char('F') => {
+                    app.begin_order_filter();
+                }
                 KeyCode::Esc | KeyCode::Char('m') | KeyCode::Char('M') => {
                     app.close_overlay();
                 }
@@ -209,13 +237,32 @@ async fn handle_overlay_key(
             }
             Ok(true)
         }
+        CommandOverlay::Filter { .. } => {
+            match key.code {
+                KeyCode::Esc => {
+                    app.close_overlay();
+                }
+                KeyCode::Backspace => {
+                    app.backspace_filter();
+                }
+                KeyCode::Enter => {
+                    app.apply_order_filter();
+                }
+                KeyCode::Char(ch) => {
+                    if !key.modifiers.contains(KeyModifiers::CONTROL)
+                        && !key.modifiers.contains(KeyModifiers::ALT)
+                    {
+                        app.append_filter_char(ch);
+                    }
+                }
+                _ => {}
+            }
+            Ok(true)
+        }
     }
 }
 
-async fn trigger_cancel_all(
-    app: &mut MonitorApp,
-    cancel_client: &mut ControlServiceClient<Channel>,
-) -> Result<()> {
+async fn trigger_cancel_all(app: &mut MonitorApp, cancel_client: &mut ControlClient) -> Result<()> {
     if app.cancel_in_progress() {
         return Ok(());
     }
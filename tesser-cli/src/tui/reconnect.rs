@@ -0,0 +1,162 @@
+use std::time::Duration;
+
+const DEFAULT_INITIAL_BACKOFF: Duration = Duration::from_millis(250);
+const DEFAULT_MAX_BACKOFF: Duration = Duration::from_secs(5);
+const DEFAULT_MAX_ATTEMPTS: u32 = 30;
+
+/// Backoff schedule used when (re)connecting to the control plane.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ReconnectPolicy {
+    /// Delay before the first retry; doubled after every further failure.
+    pub initial_backoff: Duration,
+    /// Upper bound applied to the doubled delay.
+    pub max_backoff: Duration,
+    /// Number of consecutive failures tolerated, or `None` to retry forever.
+    pub max_attempts: Option<u32>,
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self {
+            initial_backoff: DEFAULT_INITIAL_BACKOFF,
+            max_backoff: DEFAULT_MAX_BACKOFF,
+            max_attempts: Some(DEFAULT_MAX_ATTEMPTS),
+        }
+    }
+}
+
+impl ReconnectPolicy {
+    /// Delay to wait after the `attempt`-th consecutive failure (1-based).
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(31);
+        self.initial_backoff
+            .saturating_mul(1u32 << exponent)
+            .min(self.max_backoff.max(self.initial_backoff))
+    }
+
+    /// Whether another attempt is allowed after `failures` consecutive failures.
+    pub fn allows(&self, failures: u32) -> bool {
+        self.max_attempts.is_none_or(|max| failures < max)
+    }
+}
+
+/// Next action after a connection attempt fails.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReconnectStep {
+    Retry { attempt: u32, delay: Duration },
+    GiveUp { attempts: u32 },
+}
+
+/// Tracks consecutive failures against a [`ReconnectPolicy`].
+#[derive(Clone, Debug)]
+pub struct Reconnector {
+    policy: ReconnectPolicy,
+    failures: u32,
+}
+
+impl Reconnector {
+    pub fn new(policy: ReconnectPolicy) -> Self {
+        Self {
+            policy,
+            failures: 0,
+        }
+    }
+
+    /// Record a failed attempt and decide whether to retry.
+    pub fn on_failure(&mut self) -> ReconnectStep {
+        self.failures = self.failures.saturating_add(1);
+        if self.policy.allows(self.failures) {
+            ReconnectStep::Retry {
+                attempt: self.failures,
+                delay: self.policy.delay(self.failures),
+            }
+        } else {
+            ReconnectStep::GiveUp {
+                attempts: self.failures,
+            }
+        }
+    }
+
+    /// Reset the failure budget after a successful connection.
+    pub fn on_connected(&mut self) {
+        self.failures = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy(max_attempts: Option<u32>) -> ReconnectPolicy {
+        ReconnectPolicy {
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_millis(700),
+            max_attempts,
+        }
+    }
+
+    #[test]
+    fn backoff_doubles_until_capped() {
+        let policy = policy(None);
+        let delays: Vec<u64> = (1..=6)
+            .map(|attempt| policy.delay(attempt).as_millis() as u64)
+            .collect();
+        assert_eq!(delays, vec![100, 200, 400, 700, 700, 700]);
+        assert_eq!(policy.delay(u32::MAX), Duration::from_millis(700));
+    }
+
+    #[test]
+    fn fixed_backoff_when_cap_matches_initial() {
+        let policy = ReconnectPolicy {
+            initial_backoff: Duration::from_millis(250),
+            max_backoff: Duration::from_millis(250),
+            max_attempts: Some(3),
+        };
+        assert!((1..=5).all(|attempt| policy.delay(attempt) == Duration::from_millis(250)));
+    }
+
+    #[test]
+    fn gives_up_after_max_attempts() {
+        let mut reconnector = Reconnector::new(policy(Some(3)));
+        assert_eq!(
+            reconnector.on_failure(),
+            ReconnectStep::Retry {
+                attempt: 1,
+                delay: Duration::from_millis(100)
+            }
+        );
+        assert!(matches!(
+            reconnector.on_failure(),
+            ReconnectStep::Retry { attempt: 2, .. }
+        ));
+        assert_eq!(
+            reconnector.on_failure(),
+            ReconnectStep::GiveUp { attempts: 3 }
+        );
+    }
+
+    #[test]
+    fn connection_resets_the_failure_budget() {
+        let mut reconnector = Reconnector::new(policy(Some(2)));
+        reconnector.on_failure();
+        reconnector.on_connected();
+        assert_eq!(
+            reconnector.on_failure(),
+            ReconnectStep::Retry {
+                attempt: 1,
+                delay: Duration::from_millis(100)
+            }
+        );
+    }
+
+    #[test]
+    fn retry_forever_never_gives_up() {
+        let mut reconnector = Reconnector::new(policy(None));
+        for _ in 0..1_000 {
+            assert!(matches!(
+                reconnector.on_failure(),
+                ReconnectStep::Retry { .. }
+            ));
+        }
+    }
+}
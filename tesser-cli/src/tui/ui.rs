@@ -10,7 +10,7 @@ use rust_decimal::Decimal;
 use tesser_rpc::conversions::{from_decimal_proto, from_timestamp_proto};
 use tesser_rpc::proto;
 
-use super::app::{CommandOverlay, LogCategory, MonitorApp};
+use super::app::{summarize_algo_state, CommandOverlay, LogCategory, MonitorApp};
 
 pub fn draw(f: &mut Frame<'_>, app: &MonitorApp) {
     let layout = Layout::default()
@@ -41,7 +41,12 @@ pub fn draw(f: &mut Frame<'_>, app: &MonitorApp) {
     render_sub_accounts(f, summary_chunks[0], app);
     render_balances(f, summary_chunks[1], app);
     render_positions(f, venue_chunks[1], app);
-    render_orders(f, main_chunks[1], app);
+    let activity_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(65), Constraint::Percentage(35)])
+        .split(main_chunks[1]);
+    render_orders(f, activity_chunks[0], app);
+    render_algorithms(f, activity_chunks[1], app);
 
     let footer = Layout::default()
         .direction(Direction::Vertical)
@@ -108,6 +113,11 @@ fn render_header(f: &mut Frame<'_>, area: Rect, app: &MonitorApp) {
         label("Stream"),
         if app.stream_connected() {
             Span::styled("ONLINE", Style::default().fg(Color::Green))
+        } else if let Some(attempt) = app.reconnect_attempt() {
+            Span::styled(
+                format!("RECONNECTING ({attempt})"),
+                Style::default().fg(Color::Yellow),
+            )
         } else {
             Span::styled("OFFLINE", Style::default().fg(Color::Yellow))
         },
@@ -289,14 +299,20 @@ fn render_balances(f: &mut Frame<'_>, area: Rect, app: &MonitorApp) {
 }
 
 fn render_orders(f: &mut Frame<'_>, area: Rect, app: &MonitorApp) {
-    let orders = app.orders();
+    let orders = app.visible_orders();
+    let mut title = format!("Active Orders (sort: {}", app.order_sort().label());
+    if let Some(filter) = app.order_filter() {
+        title.push_str(&format!(", filter: {filter}"));
+    }
+    title.push(')');
     if orders.is_empty() {
-        let block = Paragraph::new("No open orders")
-            .block(
-                Block::default()
-                    .title("Active Orders")
-                    .borders(Borders::ALL),
-            )
+        let message = if app.orders().is_empty() {
+            "No open orders"
+        } else {
+            "No orders match the filter"
+        };
+        let block = Paragraph::new(message)
+            .block(Block::default().title(title).borders(Borders::ALL))
             .wrap(Wrap { trim: true });
         f.render_widget(block, area);
         return;
@@ -333,11 +349,43 @@ fn render_orders(f: &mut Frame<'_>, area: Rect, app: &MonitorApp) {
             ])
             .style(Style::default().fg(Color::Gray)),
         )
-        .block(
-            Block::default()
-                .title("Active Orders")
-                .borders(Borders::ALL),
+        .block(Block::default().title(title).borders(Borders::ALL))
+        .column_spacing(1);
+    f.render_widget(table, area);
+}
+
+fn render_algorithms(f: &mut Frame<'_>, area: Rect, app: &MonitorApp) {
+    let algorithms = app.algorithms();
+    let title = "Execution Algorithms";
+    if algorithms.is_empty() {
+        let block = Paragraph::new("No active algorithms")
+            .block(Block::default().title(title).borders(Borders::ALL))
+            .wrap(Wrap { trim: true });
+        f.render_widget(block, area);
+        return;
+    }
+
+    let rows = algorithms.iter().map(|algo| {
+        let short_id: String = algo.id.chars().take(8).collect();
+        Row::new(vec![
+            Cell::from(algo.kind.clone()),
+            Cell::from(short_id),
+            Cell::from(algo.status.clone()),
+            Cell::from(summarize_algo_state(&algo.state_json)),
+        ])
+    });
+
+    let widths = [
+        Constraint::Length(14),
+        Constraint::Length(9),
+        Constraint::Length(10),
+        Constraint::Min(20),
+    ];
+    let table = Table::new(rows, widths)
+        .header(
+            Row::new(vec!["Kind", "ID", "Status", "State"]).style(Style::default().fg(Color::Gray)),
         )
+        .block(Block::default().title(title).borders(Borders::ALL))
         .column_spacing(1);
     f.render_widget(table, area);
 }
@@ -382,12 +430,16 @@ fn render_help(f: &mut Frame<'_>, area: Rect) {
             key_hint("Ctrl+C"),
             Span::raw(" Immediate quit   "),
             key_hint("m"),
-            Span::raw(" Toggle command palette"),
+            Span::raw(" Toggle command palette   "),
+            key_hint("s"),
+            Span::raw(" Cycle order sort"),
         ]),
         Line::from(vec![
             Span::styled("In palette: ", Style::default().fg(Color::Gray)),
             key_hint("c"),
             Span::raw(" start Cancel-All   "),
+            key_hint("f"),
+            Span::raw(" filter orders by symbol   "),
             key_hint("Esc"),
             Span::raw(" close palette"),
         ]),
@@ -420,6 +472,7 @@ fn render_overlay(f: &mut Frame<'_>, area: Rect, app: &MonitorApp) {
                 .style(Style::default().bg(Color::Black));
             let lines = vec![
                 Line::from("Press 'c' to initiate Cancel All."),
+                Line::from("Press 'f' to filter orders by symbol."),
                 Line::from("Press Esc (or 'm') to close this panel."),
             ];
             let paragraph = Paragraph::new(lines)
@@ -456,9 +509,32 @@ fn render_overlay(f: &mut Frame<'_>, area: Rect, app: &MonitorApp) {
                 .block(block);
             f.render_widget(paragraph, chunk);
         }
+        CommandOverlay::Filter { .. } => render_filter_overlay(f, area, app),
     }
 }
 
+fn render_filter_overlay(f: &mut Frame<'_>, area: Rect, app: &MonitorApp) {
+    let chunk = centered_rect(60, 25, area);
+    let input = app.filter_buffer().unwrap_or_default();
+    let lines = vec![
+        Line::from("Type a symbol fragment and press Enter; leave empty to clear."),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("> ", Style::default().fg(Color::Gray)),
+            Span::styled(input.to_string(), Style::default().fg(Color::White)),
+        ]),
+    ];
+    let block = Block::default()
+        .title("Filter Orders")
+        .borders(Borders::ALL)
+        .style(Style::default().bg(Color::Black));
+    let paragraph = Paragraph::new(lines)
+        .alignment(Alignment::Left)
+        .wrap(Wrap { trim: true })
+        .block(block);
+    f.render_widget(paragraph, chunk);
+}
+
 fn centered_rect(percent_x: u16, percent_y: u16, area: Rect) -> Rect {
     let popup_layout = Layout::default()
         .direction(Direction::Vertical)
@@ -13,7 +13,7 @@ use tempfile::tempdir;
 
 use arrow::array::{ArrayRef, Decimal128Builder, Int64Builder, StringBuilder};
 use arrow::record_batch::RecordBatch;
-use tesser_core::{Candle, Interval, Symbol};
+use tesser_core::{Candle, CandleStatus, Interval, Symbol};
 use tesser_data::schema::{
     canonical_candle_schema, canonical_decimal_type, CANONICAL_DECIMAL_SCALE_U32,
 };
@@ -149,6 +149,7 @@ fn sample_candles() -> Vec<Candle> {
             close: Decimal::new(20_005 + idx as i64, 0),
             volume: Decimal::new(1, 0),
             timestamp: base + Duration::minutes(idx as i64),
+            status: CandleStatus::Closed,
         })
         .collect()
 }
@@ -171,6 +172,7 @@ fn dual_exchange_candles() -> Vec<Candle> {
             close: price_a,
             volume: Decimal::ONE,
             timestamp: ts,
+            status: CandleStatus::Closed,
         });
         candles.push(Candle {
             symbol: symbol_b,
@@ -181,6 +183,7 @@ fn dual_exchange_candles() -> Vec<Candle> {
             close: price_b,
             volume: Decimal::ONE,
             timestamp: ts,
+            status: CandleStatus::Closed,
         });
     }
     candles
@@ -7,7 +7,7 @@ use chrono::{Duration, TimeZone, Utc};
 use rust_decimal::Decimal;
 use tempfile::tempdir;
 
-use tesser_core::{Candle, Interval};
+use tesser_core::{Candle, CandleStatus, Interval};
 use tesser_data::io::{self, DatasetFormat};
 
 #[test]
@@ -92,6 +92,7 @@ fn sample_candles(count: usize) -> Vec<Candle> {
             close: Decimal::new(10 + idx as i64, 0),
             volume: Decimal::new(1 + idx as i64, 0),
             timestamp: base + Duration::minutes(idx as i64),
+            status: CandleStatus::Closed,
         })
         .collect()
 }
@@ -21,7 +21,7 @@ use tempfile::tempdir;
 use tokio::sync::Notify;
 use tokio::time::{sleep, timeout};
 use tokio::{sync::mpsc, task::JoinHandle};
-use tonic::transport::Channel;
+use tonic::transport::{Channel, Endpoint};
 
 use async_trait::async_trait;
 use tesser_cli::live::{
@@ -31,17 +31,22 @@ use tesser_cli::live::{
 use tesser_cli::PublicChannel;
 use tesser_config::{AlertingConfig, ExchangeConfig, PersistenceEngine, RiskManagementConfig};
 use tesser_core::{
-    AccountBalance, AssetId, Candle, ExchangeId, ExitStrategy, Interval, Position, Side, Signal,
-    SignalKind, Symbol, Tick,
+    AccountBalance, AssetId, Candle, CandleStatus, ExchangeId, ExitStrategy, Interval, Position,
+    Side, Signal, SignalKind, Symbol, Tick,
 };
-use tesser_execution::PanicCloseConfig;
+use tesser_data::io::{write_dataset, DatasetFormat};
+use tesser_execution::{MinNotionalPolicy, OrderMappingPolicy, PanicCloseConfig};
+use tesser_ledger::{LedgerQuery, LedgerRepository, LedgerType, SqliteLedgerRepository};
 use tesser_portfolio::{SqliteStateRepository, StateRepository};
+use tesser_rpc::auth::BearerToken;
 use tesser_rpc::proto::control_service_client::ControlServiceClient;
 use tesser_rpc::proto::{
     CancelAllRequest, GetOpenOrdersRequest, GetPortfolioRequest, GetStatusRequest,
     ListManagedTradesRequest, UpdateTradeExitStrategyRequest,
 };
-use tesser_strategy::{PairsTradingArbitrage, Strategy, StrategyContext, StrategyResult};
+use tesser_strategy::{
+    ContextMode, PairsTradingArbitrage, Strategy, StrategyContext, StrategyResult,
+};
 use tesser_test_utils::{
     AccountConfig, AutoFillConfig, MockExchange, MockExchangeConfig, OrderFillStep, Scenario,
     ScenarioAction, ScenarioManager, ScenarioTrigger,
@@ -99,6 +104,7 @@ fn build_price_series(
             close: price,
             volume: Decimal::ONE,
             timestamp,
+            status: CandleStatus::Closed,
         });
         ticks.push(Tick {
             symbol,
@@ -194,11 +200,17 @@ fn spawn_live_runtime(
 }
 
 async fn connect_control_client(addr: SocketAddr) -> Result<ControlServiceClient<Channel>> {
-    let endpoint = format!("http://{addr}");
+    Ok(ControlServiceClient::new(
+        connect_control_channel(addr).await?,
+    ))
+}
+
+async fn connect_control_channel(addr: SocketAddr) -> Result<Channel> {
+    let endpoint = Endpoint::from_shared(format!("http://{addr}"))?;
     const MAX_ATTEMPTS: usize = 50;
     for attempt in 0..MAX_ATTEMPTS {
-        match ControlServiceClient::connect(endpoint.clone()).await {
-            Ok(client) => return Ok(client),
+        match endpoint.connect().await {
+            Ok(channel) => return Ok(channel),
             Err(err) => {
                 if attempt + 1 == MAX_ATTEMPTS {
                     return Err(anyhow!("failed to connect to control plane: {err}"));
@@ -224,6 +236,7 @@ async fn mock_exchange_starts() -> Result<()> {
         close: Decimal::new(1005, 0),
         volume: Decimal::ONE,
         timestamp: Utc::now(),
+        status: CandleStatus::Closed,
     }];
 
     let ticks = vec![Tick {
@@ -246,9 +259,8 @@ async fn mock_exchange_starts() -> Result<()> {
     Ok(())
 }
 
-#[tokio::test(flavor = "multi_thread")]
-async fn live_run_executes_round_trip() -> Result<()> {
-    let _ = tracing_subscriber::fmt::try_init();
+/// Runs the scripted entry/exit round trip against a mock Bybit venue and shuts the runtime down.
+async fn run_scripted_round_trip(state_path: &Path) -> Result<MockExchange> {
     let account = AccountConfig::new("test-key", "test-secret")
         .with_balance(account_balance(Decimal::new(10_000, 0)));
     let base_time = Utc::now();
@@ -262,6 +274,7 @@ async fn live_run_executes_round_trip() -> Result<()> {
             close: Decimal::new(1_005 + i as i64, 0),
             volume: Decimal::ONE,
             timestamp: base_time + ChronoDuration::minutes(i as i64),
+            status: CandleStatus::Closed,
         })
         .collect::<Vec<_>>();
     let ticks = (0..6)
@@ -280,7 +293,7 @@ async fn live_run_executes_round_trip() -> Result<()> {
         .with_account(account)
         .with_candles(candles)
         .with_ticks(ticks);
-    let mut exchange = MockExchange::start(config).await?;
+    let exchange = MockExchange::start(config).await?;
     let scenarios = exchange.state().scenarios();
     scenarios
         .push(Scenario {
@@ -309,8 +322,6 @@ async fn live_run_executes_round_trip() -> Result<()> {
         })
         .await;
 
-    let temp = tempdir()?;
-    let state_path = temp.path().join("live_state.db");
     let markets_file = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../config/markets.toml");
     let risk = RiskManagementConfig {
         max_drawdown: Decimal::ZERO,
@@ -324,7 +335,7 @@ async fn live_run_executes_round_trip() -> Result<()> {
         fee_bps: Decimal::ZERO,
         history: 8,
         metrics_addr: "127.0.0.1:0".parse::<SocketAddr>().unwrap(),
-        persistence: PersistenceSettings::new(PersistenceEngine::Sqlite, state_path.clone()),
+        persistence: PersistenceSettings::new(PersistenceEngine::Sqlite, state_path.to_path_buf()),
         initial_balances: default_initial_balances(),
         reporting_currency: usdt_asset(),
         markets_file: Some(markets_file),
@@ -333,11 +344,22 @@ async fn live_run_executes_round_trip() -> Result<()> {
         risk,
         reconciliation_interval: Duration::from_secs(60),
         reconciliation_threshold: Decimal::ONE,
+        reconciliation_asset_thresholds: HashMap::new(),
         orderbook_depth: 50,
         record_path: None,
         control_addr: "127.0.0.1:0".parse().unwrap(),
+        control_token: None,
         panic_close: PanicCloseConfig::default(),
         plugins_dir: None,
+        candles_from_ticks: false,
+        tick_filter: None,
+        auto_correct_positions: false,
+        max_corrective_quantity: Decimal::ONE,
+        warmup_paths: Vec::new(),
+        order_mapping: OrderMappingPolicy::default(),
+        cooldown_after_stop: None,
+        min_notional_policy: MinNotionalPolicy::Drop,
+        event_log_dir: None,
     };
     let exchange_cfg = ExchangeConfig {
         rest_url: exchange.rest_url(),
@@ -381,6 +403,15 @@ async fn live_run_executes_round_trip() -> Result<()> {
     };
     shutdown.trigger();
     run_handle.await??;
+    Ok(exchange)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn live_run_executes_round_trip() -> Result<()> {
+    let _ = tracing_subscriber::fmt::try_init();
+    let temp = tempdir()?;
+    let state_path = temp.path().join("live_state.db");
+    let mut exchange = run_scripted_round_trip(&state_path).await?;
 
     let positions = exchange
         .state()
@@ -402,6 +433,128 @@ async fn live_run_executes_round_trip() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn live_shutdown_settles_ledger_and_persists_strategy_state() -> Result<()> {
+    let _ = tracing_subscriber::fmt::try_init();
+    let temp = tempdir()?;
+    let state_path = temp.path().join("live_state.db");
+    let mut exchange = run_scripted_round_trip(&state_path).await?;
+
+    // Shutdown leaves the realized PnL in the ledger and the final strategy snapshot on disk.
+    let ledger = SqliteLedgerRepository::new(&state_path)?;
+    let entries = ledger.query(LedgerQuery::default())?;
+    assert!(entries
+        .iter()
+        .any(|entry| entry.entry_type == LedgerType::TradeRealizedPnl));
+    let persisted = SqliteStateRepository::new(state_path)
+        .load()
+        .map_err(|err| anyhow!("failed to load state: {err}"))?;
+    assert_eq!(persisted.strategy_state, Some(json!({ "stage": 2 })));
+    assert!(persisted.open_orders.is_empty());
+
+    exchange.shutdown().await;
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn dry_run_routes_live_data_to_paper_fills() -> Result<()> {
+    let _ = tracing_subscriber::fmt::try_init();
+    let account = AccountConfig::new("test-key", "test-secret")
+        .with_balance(account_balance(Decimal::new(10_000, 0)));
+    let base_time = Utc::now();
+    let (candles, ticks) =
+        build_price_series(test_symbol(), base_time, &[1_000, 1_002, 1_004, 1_006]);
+    let config = MockExchangeConfig::new()
+        .with_exchange(bybit_exchange())
+        .with_account(account)
+        .with_candles(candles)
+        .with_ticks(ticks);
+    let mut exchange = MockExchange::start(config).await?;
+
+    let temp = tempdir()?;
+    let state_path = temp.path().join("live_state.db");
+    let markets_file = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../config/markets.toml");
+    let settings = LiveSessionSettings {
+        category: PublicChannel::Linear,
+        interval: Interval::OneMinute,
+        quantity: Decimal::ONE,
+        slippage_bps: Decimal::ZERO,
+        fee_bps: Decimal::ZERO,
+        history: 8,
+        metrics_addr: "127.0.0.1:0".parse::<SocketAddr>().unwrap(),
+        persistence: PersistenceSettings::new(PersistenceEngine::Sqlite, state_path),
+        initial_balances: default_initial_balances(),
+        reporting_currency: usdt_asset(),
+        markets_file: Some(markets_file),
+        alerting: AlertingConfig::default(),
+        exec_backend: ExecutionBackend::Paper,
+        risk: RiskManagementConfig {
+            max_drawdown: Decimal::ZERO,
+            ..RiskManagementConfig::default()
+        },
+        reconciliation_interval: Duration::from_secs(60),
+        reconciliation_threshold: Decimal::ONE,
+        reconciliation_asset_thresholds: HashMap::new(),
+        orderbook_depth: 50,
+        record_path: None,
+        control_addr: "127.0.0.1:0".parse().unwrap(),
+        control_token: None,
+        panic_close: PanicCloseConfig::default(),
+        plugins_dir: None,
+        candles_from_ticks: false,
+        tick_filter: None,
+        auto_correct_positions: false,
+        max_corrective_quantity: Decimal::ONE,
+        warmup_paths: Vec::new(),
+        order_mapping: OrderMappingPolicy::default(),
+        cooldown_after_stop: None,
+        min_notional_policy: MinNotionalPolicy::Drop,
+        event_log_dir: None,
+    };
+    let exchanges = vec![NamedExchange {
+        name: "bybit_linear".into(),
+        config: ExchangeConfig {
+            rest_url: exchange.rest_url(),
+            ws_url: exchange.ws_url(),
+            api_key: "test-key".into(),
+            api_secret: "test-secret".into(),
+            driver: "bybit".into(),
+            params: JsonValue::Null,
+        },
+    }];
+    let (strategy, monitor) = ScriptedStrategy::new(test_symbol());
+    let shutdown = ShutdownSignal::new();
+    let run_handle = spawn_live_runtime(
+        Box::new(strategy),
+        vec![test_symbol()],
+        exchanges,
+        settings,
+        shutdown.clone(),
+    );
+
+    timeout(Duration::from_secs(10), monitor.wait_for_fills(2))
+        .await
+        .context("timed out waiting for paper fills")??;
+    shutdown.trigger();
+    run_handle.await??;
+
+    let state = exchange.state();
+    let submitted = state
+        .with_account("test-key", |account| Ok(account.orders.len()))
+        .await?;
+    assert_eq!(
+        submitted, 0,
+        "dry-run must not submit orders to the exchange"
+    );
+    assert!(state
+        .executions_between("test-key", base_time - ChronoDuration::minutes(1), None)
+        .await?
+        .is_empty());
+
+    exchange.shutdown().await;
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn live_run_executes_round_trip_multi_exchange() -> Result<()> {
     let _ = tracing_subscriber::fmt::try_init();
@@ -420,6 +573,7 @@ async fn live_run_executes_round_trip_multi_exchange() -> Result<()> {
             close: Decimal::new(1_005 + i as i64, 0),
             volume: Decimal::ONE,
             timestamp: base_time + ChronoDuration::minutes(i as i64),
+            status: CandleStatus::Closed,
         })
         .collect::<Vec<_>>();
     let ticks = (0..6)
@@ -449,6 +603,7 @@ async fn live_run_executes_round_trip_multi_exchange() -> Result<()> {
             close: c.close,
             volume: c.volume,
             timestamp: c.timestamp,
+            status: CandleStatus::Closed,
         })
         .collect::<Vec<_>>();
     let ticks_b = ticks
@@ -521,11 +676,22 @@ async fn live_run_executes_round_trip_multi_exchange() -> Result<()> {
         risk,
         reconciliation_interval: Duration::from_secs(60),
         reconciliation_threshold: Decimal::ONE,
+        reconciliation_asset_thresholds: HashMap::new(),
         orderbook_depth: 50,
         record_path: None,
         control_addr: "127.0.0.1:0".parse().unwrap(),
+        control_token: None,
         panic_close: PanicCloseConfig::default(),
         plugins_dir: None,
+        candles_from_ticks: false,
+        tick_filter: None,
+        auto_correct_positions: false,
+        max_corrective_quantity: Decimal::ONE,
+        warmup_paths: Vec::new(),
+        order_mapping: OrderMappingPolicy::default(),
+        cooldown_after_stop: None,
+        min_notional_policy: MinNotionalPolicy::Drop,
+        event_log_dir: None,
     };
     let exchanges = vec![
         NamedExchange {
@@ -672,11 +838,22 @@ async fn pairs_trading_executes_cross_exchange_round_trip() -> Result<()> {
         risk,
         reconciliation_interval: Duration::from_secs(30),
         reconciliation_threshold: Decimal::new(1, 1),
+        reconciliation_asset_thresholds: HashMap::new(),
         orderbook_depth: 50,
         record_path: None,
         control_addr: "127.0.0.1:0".parse().unwrap(),
+        control_token: None,
         panic_close: PanicCloseConfig::default(),
         plugins_dir: None,
+        candles_from_ticks: false,
+        tick_filter: None,
+        auto_correct_positions: false,
+        max_corrective_quantity: Decimal::ONE,
+        warmup_paths: Vec::new(),
+        order_mapping: OrderMappingPolicy::default(),
+        cooldown_after_stop: None,
+        min_notional_policy: MinNotionalPolicy::Drop,
+        event_log_dir: None,
     };
     let exchanges = vec![
         NamedExchange {
@@ -813,11 +990,22 @@ async fn control_plane_updates_pairs_exit_strategy() -> Result<()> {
         risk,
         reconciliation_interval: Duration::from_secs(30),
         reconciliation_threshold: Decimal::new(1, 1),
+        reconciliation_asset_thresholds: HashMap::new(),
         orderbook_depth: 50,
         record_path: None,
         control_addr,
+        control_token: None,
         panic_close: PanicCloseConfig::default(),
         plugins_dir: None,
+        candles_from_ticks: false,
+        tick_filter: None,
+        auto_correct_positions: false,
+        max_corrective_quantity: Decimal::ONE,
+        warmup_paths: Vec::new(),
+        order_mapping: OrderMappingPolicy::default(),
+        cooldown_after_stop: None,
+        min_notional_policy: MinNotionalPolicy::Drop,
+        event_log_dir: None,
     };
     let exchanges = vec![
         NamedExchange {
@@ -929,6 +1117,7 @@ async fn control_plane_reports_status() -> Result<()> {
         close: Decimal::new(1_005, 0),
         volume: Decimal::ONE,
         timestamp: Utc::now(),
+        status: CandleStatus::Closed,
     }];
     let ticks = vec![Tick {
         symbol: test_symbol(),
@@ -966,11 +1155,22 @@ async fn control_plane_reports_status() -> Result<()> {
         risk: RiskManagementConfig::default(),
         reconciliation_interval: Duration::from_secs(1),
         reconciliation_threshold: Decimal::new(1, 3),
+        reconciliation_asset_thresholds: HashMap::new(),
         orderbook_depth: 50,
         record_path: None,
         control_addr,
+        control_token: None,
         panic_close: PanicCloseConfig::default(),
         plugins_dir: None,
+        candles_from_ticks: false,
+        tick_filter: None,
+        auto_correct_positions: false,
+        max_corrective_quantity: Decimal::ONE,
+        warmup_paths: Vec::new(),
+        order_mapping: OrderMappingPolicy::default(),
+        cooldown_after_stop: None,
+        min_notional_policy: MinNotionalPolicy::Drop,
+        event_log_dir: None,
     };
     let exchange_cfg = ExchangeConfig {
         rest_url: exchange.rest_url(),
@@ -1022,6 +1222,108 @@ async fn control_plane_reports_status() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn control_plane_requires_configured_token() -> Result<()> {
+    let account = AccountConfig::new("test-key", "test-secret")
+        .with_balance(account_balance(Decimal::new(10_000, 0)));
+    let (candles, ticks) = build_price_series(test_symbol(), Utc::now(), &[1_000, 1_005]);
+    let config = MockExchangeConfig::new()
+        .with_exchange(bybit_exchange())
+        .with_account(account)
+        .with_candles(candles)
+        .with_ticks(ticks);
+    let mut exchange = MockExchange::start(config).await?;
+
+    let control_addr = next_control_addr();
+    let temp = tempdir()?;
+    let state_path = temp.path().join("live_state.db");
+    let markets_file = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../config/markets.toml");
+    let settings = LiveSessionSettings {
+        category: PublicChannel::Linear,
+        interval: Interval::OneMinute,
+        quantity: Decimal::ONE,
+        slippage_bps: Decimal::ZERO,
+        fee_bps: Decimal::ZERO,
+        history: 8,
+        metrics_addr: "127.0.0.1:0".parse::<SocketAddr>().unwrap(),
+        persistence: PersistenceSettings::new(PersistenceEngine::Sqlite, state_path.clone()),
+        initial_balances: default_initial_balances(),
+        reporting_currency: usdt_asset(),
+        markets_file: Some(markets_file),
+        alerting: AlertingConfig::default(),
+        exec_backend: ExecutionBackend::Live,
+        risk: RiskManagementConfig::default(),
+        reconciliation_interval: Duration::from_secs(60),
+        reconciliation_threshold: Decimal::new(1, 3),
+        reconciliation_asset_thresholds: HashMap::new(),
+        auto_correct_positions: false,
+        max_corrective_quantity: Decimal::ONE,
+        orderbook_depth: 50,
+        record_path: None,
+        control_addr,
+        control_token: Some("s3cret".into()),
+        panic_close: PanicCloseConfig::default(),
+        plugins_dir: None,
+        candles_from_ticks: false,
+        tick_filter: None,
+        warmup_paths: Vec::new(),
+        order_mapping: OrderMappingPolicy::default(),
+        cooldown_after_stop: None,
+        min_notional_policy: MinNotionalPolicy::Drop,
+        event_log_dir: None,
+    };
+    let exchange_cfg = ExchangeConfig {
+        rest_url: exchange.rest_url(),
+        ws_url: exchange.ws_url(),
+        api_key: "test-key".into(),
+        api_secret: "test-secret".into(),
+        driver: "bybit".into(),
+        params: JsonValue::Null,
+    };
+    let exchanges = vec![NamedExchange {
+        name: "bybit_linear".into(),
+        config: exchange_cfg,
+    }];
+    let strategy: Box<dyn Strategy> = Box::new(PassiveStrategy::new(test_symbol()));
+    let shutdown = ShutdownSignal::new();
+    let run_handle = spawn_live_runtime(
+        strategy,
+        vec![test_symbol()],
+        exchanges,
+        settings,
+        shutdown.clone(),
+    );
+
+    let channel = connect_control_channel(control_addr).await?;
+    let mut anonymous = ControlServiceClient::new(channel.clone());
+    let err = anonymous
+        .cancel_all(CancelAllRequest {})
+        .await
+        .expect_err("missing token must be rejected");
+    assert_eq!(err.code(), tonic::Code::Unauthenticated);
+
+    let mut wrong =
+        ControlServiceClient::with_interceptor(channel.clone(), BearerToken::new(Some("nope"))?);
+    let err = wrong
+        .get_status(GetStatusRequest {})
+        .await
+        .expect_err("wrong token must be rejected");
+    assert_eq!(err.code(), tonic::Code::Unauthenticated);
+
+    let mut authorized =
+        ControlServiceClient::with_interceptor(channel, BearerToken::new(Some("s3cret"))?);
+    let status = authorized
+        .get_status(GetStatusRequest {})
+        .await?
+        .into_inner();
+    assert!(!status.shutdown);
+
+    shutdown.trigger();
+    run_handle.await??;
+    exchange.shutdown().await;
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn reconciliation_enters_liquidate_only_on_divergence() -> Result<()> {
     let account = AccountConfig::new("test-key", "test-secret")
@@ -1037,6 +1339,7 @@ async fn reconciliation_enters_liquidate_only_on_divergence() -> Result<()> {
             close: Decimal::new(1_005 + i as i64, 0),
             volume: Decimal::ONE,
             timestamp: base_time + ChronoDuration::minutes(i as i64),
+            status: CandleStatus::Closed,
         })
         .collect::<Vec<_>>();
     let ticks = (0..3)
@@ -1081,11 +1384,22 @@ async fn reconciliation_enters_liquidate_only_on_divergence() -> Result<()> {
         risk: RiskManagementConfig::default(),
         reconciliation_interval: Duration::from_millis(200),
         reconciliation_threshold: Decimal::new(1, 4),
+        reconciliation_asset_thresholds: HashMap::new(),
         orderbook_depth: 50,
         record_path: None,
         control_addr: "127.0.0.1:0".parse().unwrap(),
+        control_token: None,
         panic_close: PanicCloseConfig::default(),
         plugins_dir: None,
+        candles_from_ticks: false,
+        tick_filter: None,
+        auto_correct_positions: false,
+        max_corrective_quantity: Decimal::ONE,
+        warmup_paths: Vec::new(),
+        order_mapping: OrderMappingPolicy::default(),
+        cooldown_after_stop: None,
+        min_notional_policy: MinNotionalPolicy::Drop,
+        event_log_dir: None,
     };
     let exchange_cfg = ExchangeConfig {
         rest_url: exchange.rest_url(),
@@ -1166,6 +1480,7 @@ async fn alerts_on_rejected_order() -> Result<()> {
             close: Decimal::new(1_005 + i as i64, 0),
             volume: Decimal::ONE,
             timestamp: base_time + ChronoDuration::minutes(i as i64),
+            status: CandleStatus::Closed,
         })
         .collect::<Vec<_>>();
     let ticks = (0..2)
@@ -1230,11 +1545,22 @@ async fn alerts_on_rejected_order() -> Result<()> {
         risk: RiskManagementConfig::default(),
         reconciliation_interval: Duration::from_secs(1),
         reconciliation_threshold: Decimal::new(1, 3),
+        reconciliation_asset_thresholds: HashMap::new(),
         orderbook_depth: 50,
         record_path: None,
         control_addr: "127.0.0.1:0".parse().unwrap(),
+        control_token: None,
         panic_close: PanicCloseConfig::default(),
         plugins_dir: None,
+        candles_from_ticks: false,
+        tick_filter: None,
+        auto_correct_positions: false,
+        max_corrective_quantity: Decimal::ONE,
+        warmup_paths: Vec::new(),
+        order_mapping: OrderMappingPolicy::default(),
+        cooldown_after_stop: None,
+        min_notional_policy: MinNotionalPolicy::Drop,
+        event_log_dir: None,
     };
     let exchange_cfg = ExchangeConfig {
         rest_url: exchange.rest_url(),
@@ -1294,6 +1620,136 @@ async fn alerts_on_rejected_order() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn warmup_replays_history_before_routing_orders() -> Result<()> {
+    let _ = tracing_subscriber::fmt::try_init();
+    let account = AccountConfig::new("test-key", "test-secret")
+        .with_balance(account_balance(Decimal::new(10_000, 0)));
+    let base_time = Utc::now();
+    let (history, _) = build_price_series(
+        test_symbol(),
+        base_time - ChronoDuration::minutes(10),
+        &[990, 992, 994, 996, 998],
+    );
+    let (candles, ticks) = build_price_series(test_symbol(), base_time, &[1_000, 1_002]);
+    let config = MockExchangeConfig::new()
+        .with_exchange(bybit_exchange())
+        .with_account(account)
+        .with_candles(candles)
+        .with_ticks(ticks);
+    let mut exchange = MockExchange::start(config).await?;
+
+    let temp = tempdir()?;
+    let warmup_path = temp.path().join("warmup.csv");
+    write_dataset(&warmup_path, DatasetFormat::Csv, &history)?;
+    let state_path = temp.path().join("live_state.db");
+    let markets_file = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../config/markets.toml");
+    let settings = LiveSessionSettings {
+        category: PublicChannel::Linear,
+        interval: Interval::OneMinute,
+        quantity: Decimal::ONE,
+        slippage_bps: Decimal::ZERO,
+        fee_bps: Decimal::ZERO,
+        history: 8,
+        metrics_addr: "127.0.0.1:0".parse::<SocketAddr>().unwrap(),
+        persistence: PersistenceSettings::new(PersistenceEngine::Sqlite, state_path),
+        initial_balances: default_initial_balances(),
+        reporting_currency: usdt_asset(),
+        markets_file: Some(markets_file),
+        alerting: AlertingConfig::default(),
+        exec_backend: ExecutionBackend::Live,
+        risk: RiskManagementConfig {
+            max_drawdown: Decimal::ZERO,
+            ..RiskManagementConfig::default()
+        },
+        reconciliation_interval: Duration::from_secs(60),
+        reconciliation_threshold: Decimal::ONE,
+        reconciliation_asset_thresholds: HashMap::new(),
+        orderbook_depth: 50,
+        record_path: None,
+        control_addr: "127.0.0.1:0".parse().unwrap(),
+        control_token: None,
+        panic_close: PanicCloseConfig::default(),
+        plugins_dir: None,
+        candles_from_ticks: false,
+        tick_filter: None,
+        auto_correct_positions: false,
+        max_corrective_quantity: Decimal::ONE,
+        warmup_paths: vec![warmup_path],
+        order_mapping: OrderMappingPolicy::default(),
+        cooldown_after_stop: None,
+        min_notional_policy: MinNotionalPolicy::Drop,
+        event_log_dir: None,
+    };
+    let exchanges = vec![NamedExchange {
+        name: "bybit_linear".into(),
+        config: ExchangeConfig {
+            rest_url: exchange.rest_url(),
+            ws_url: exchange.ws_url(),
+            api_key: "test-key".into(),
+            api_secret: "test-secret".into(),
+            driver: "bybit".into(),
+            params: JsonValue::Null,
+        },
+    }];
+    let strategy = WarmupProbeStrategy::new(test_symbol(), 3);
+    let probe = strategy.probe.clone();
+    let shutdown = ShutdownSignal::new();
+    let run_handle = spawn_live_runtime(
+        Box::new(strategy),
+        vec![test_symbol()],
+        exchanges,
+        settings,
+        shutdown.clone(),
+    );
+
+    let state = exchange.state();
+    timeout(Duration::from_secs(10), async {
+        loop {
+            let submitted = state
+                .with_account("test-key", |account| Ok(account.orders.len()))
+                .await?;
+            if submitted > 0 {
+                return Ok::<(), anyhow::Error>(());
+            }
+            sleep(Duration::from_millis(25)).await;
+        }
+    })
+    .await
+    .context("timed out waiting for the live order")??;
+    sleep(Duration::from_millis(200)).await;
+    shutdown.trigger();
+    run_handle.await??;
+
+    let observed = probe.lock().unwrap().clone();
+    let replayed: Vec<_> = observed
+        .iter()
+        .take_while(|(mode, _)| *mode == ContextMode::Replay)
+        .map(|(_, close)| *close)
+        .collect();
+    assert_eq!(
+        replayed,
+        vec![
+            Decimal::new(994, 0),
+            Decimal::new(996, 0),
+            Decimal::new(998, 0)
+        ]
+    );
+    assert!(observed[replayed.len()..]
+        .iter()
+        .all(|(mode, _)| *mode == ContextMode::Live));
+    let submitted = state
+        .with_account("test-key", |account| Ok(account.orders.len()))
+        .await?;
+    assert_eq!(
+        submitted, 1,
+        "signals emitted during warmup must not be routed"
+    );
+
+    exchange.shutdown().await;
+    Ok(())
+}
+
 async fn start_alert_listener() -> Result<(String, mpsc::Receiver<JsonValue>, JoinHandle<()>)> {
     let (tx, rx) = mpsc::channel(8);
     let make_svc = make_service_fn(move |_| {
@@ -1468,6 +1924,10 @@ impl Strategy for ScriptedStrategy {
     fn drain_signals(&mut self) -> Vec<Signal> {
         std::mem::take(&mut self.pending)
     }
+
+    fn snapshot(&self) -> StrategyResult<JsonValue> {
+        Ok(json!({ "stage": self.stage }))
+    }
 }
 
 impl MultiScriptedStrategy {
@@ -1548,6 +2008,74 @@ impl Strategy for MultiScriptedStrategy {
     }
 }
 
+/// Emits an entry on every warmup candle and once live, recording the mode of each candle.
+struct WarmupProbeStrategy {
+    symbol: Symbol,
+    warmup: usize,
+    entered: bool,
+    pending: Vec<Signal>,
+    probe: Arc<std::sync::Mutex<Vec<(ContextMode, Decimal)>>>,
+}
+
+impl WarmupProbeStrategy {
+    fn new(symbol: Symbol, warmup: usize) -> Self {
+        Self {
+            symbol,
+            warmup,
+            entered: false,
+            pending: Vec::new(),
+            probe: Arc::new(std::sync::Mutex::new(Vec::new())),
+        }
+    }
+}
+
+#[async_trait]
+impl Strategy for WarmupProbeStrategy {
+    fn name(&self) -> &str {
+        "warmup-probe"
+    }
+
+    fn symbol(&self) -> Symbol {
+        self.symbol
+    }
+
+    fn warmup_candles(&self) -> usize {
+        self.warmup
+    }
+
+    fn configure(&mut self, _params: toml::Value) -> StrategyResult<()> {
+        Ok(())
+    }
+
+    async fn on_tick(&mut self, _ctx: &StrategyContext, _tick: &Tick) -> StrategyResult<()> {
+        Ok(())
+    }
+
+    async fn on_candle(&mut self, ctx: &StrategyContext, candle: &Candle) -> StrategyResult<()> {
+        self.probe.lock().unwrap().push((ctx.mode(), candle.close));
+        if ctx.mode() == ContextMode::Replay || !self.entered {
+            self.pending
+                .push(Signal::new(self.symbol, SignalKind::EnterLong, 1.0));
+        }
+        if ctx.mode() == ContextMode::Live {
+            self.entered = true;
+        }
+        Ok(())
+    }
+
+    async fn on_fill(
+        &mut self,
+        _ctx: &StrategyContext,
+        _fill: &tesser_core::Fill,
+    ) -> StrategyResult<()> {
+        Ok(())
+    }
+
+    fn drain_signals(&mut self) -> Vec<Signal> {
+        std::mem::take(&mut self.pending)
+    }
+}
+
 struct StrategyState {
     fills: AtomicUsize,
     notify: Notify,
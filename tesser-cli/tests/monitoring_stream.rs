@@ -24,11 +24,11 @@ use tesser_cli::live::{
 use tesser_cli::PublicChannel;
 use tesser_config::{AlertingConfig, ExchangeConfig, PersistenceEngine, RiskManagementConfig};
 use tesser_core::{
-    AccountBalance, AssetId, Candle, ExchangeId, ExecutionHint, Interval, OrderBook,
+    AccountBalance, AssetId, Candle, CandleStatus, ExchangeId, ExecutionHint, Interval, OrderBook,
     OrderBookLevel, Side, Signal, SignalKind, Symbol, Tick,
 };
 use tesser_data::recorder::{ParquetRecorder, RecorderConfig};
-use tesser_execution::PanicCloseConfig;
+use tesser_execution::{MinNotionalPolicy, OrderMappingPolicy, PanicCloseConfig};
 use tesser_rpc::proto::control_service_client::ControlServiceClient;
 use tesser_rpc::proto::{event::Payload, Event, MonitorRequest};
 use tesser_strategy::{Strategy, StrategyContext, StrategyResult};
@@ -219,7 +219,10 @@ async fn monitor_streams_signal_events_and_records() -> Result<()> {
     let harness = LiveTestHarness::start(true, true).await?;
 
     let mut client = connect_control_client(harness.control_addr).await?;
-    let mut stream = client.monitor(MonitorRequest {}).await?.into_inner();
+    let mut stream = client
+        .monitor(MonitorRequest::default())
+        .await?
+        .into_inner();
     harness.ready.notify_waiters();
 
     let (signal_tx, signal_rx) = oneshot::channel();
@@ -376,7 +379,10 @@ async fn flight_recorder_writes_order_books() -> Result<()> {
 async fn monitor_streams_tick_events() -> Result<()> {
     let harness = LiveTestHarness::start(false, false).await?;
     let mut client = connect_control_client(harness.control_addr).await?;
-    let mut stream = client.monitor(MonitorRequest {}).await?.into_inner();
+    let mut stream = client
+        .monitor(MonitorRequest::default())
+        .await?
+        .into_inner();
     harness.ready.notify_waiters();
 
     let tick = timeout(Duration::from_secs(10), async {
@@ -408,8 +414,14 @@ async fn monitor_supports_multiple_subscribers() -> Result<()> {
     let harness = LiveTestHarness::start(false, false).await?;
     let mut client_a = connect_control_client(harness.control_addr).await?;
     let mut client_b = connect_control_client(harness.control_addr).await?;
-    let stream_a = client_a.monitor(MonitorRequest {}).await?.into_inner();
-    let stream_b = client_b.monitor(MonitorRequest {}).await?.into_inner();
+    let stream_a = client_a
+        .monitor(MonitorRequest::default())
+        .await?
+        .into_inner();
+    let stream_b = client_b
+        .monitor(MonitorRequest::default())
+        .await?
+        .into_inner();
     harness.ready.notify_waiters();
 
     async fn first_tick(mut stream: tonic::Streaming<Event>) -> Result<(), anyhow::Error> {
@@ -441,6 +453,114 @@ async fn monitor_supports_multiple_subscribers() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn monitor_symbol_filter_only_forwards_matching_events() -> Result<()> {
+    let harness = LiveTestHarness::start(false, false).await?;
+    let mut client = connect_control_client(harness.control_addr).await?;
+    let filtered = |symbol: &str| MonitorRequest {
+        symbols: vec![symbol.to_string()],
+        ..MonitorRequest::default()
+    };
+    let mut matching = client.monitor(filtered(SYMBOL)).await?.into_inner();
+    let mut other = client.monitor(filtered("ETHUSDT")).await?.into_inner();
+    let invalid = client
+        .monitor(MonitorRequest {
+            algo_id: "not-a-uuid".into(),
+            ..MonitorRequest::default()
+        })
+        .await
+        .expect_err("invalid algo id rejected");
+    assert_eq!(invalid.code(), tonic::Code::InvalidArgument);
+    harness.ready.notify_waiters();
+
+    let tick = timeout(Duration::from_secs(10), async {
+        loop {
+            match stream_symbol(matching.message().await?) {
+                Some((symbol, Some(Payload::Tick(tick)))) => {
+                    assert_eq!(symbol, SYMBOL);
+                    return Ok::<_, tonic::Status>(tick);
+                }
+                Some((symbol, _)) => assert_eq!(symbol, SYMBOL),
+                None => return Err(tonic::Status::unknown("monitor stream closed")),
+            }
+        }
+    })
+    .await
+    .map_err(|_| anyhow!("timed out waiting for filtered monitor tick"))??;
+    assert_eq!(tick.symbol, SYMBOL);
+
+    let leaked = timeout(Duration::from_millis(500), other.message()).await;
+    assert!(
+        leaked.is_err(),
+        "ETHUSDT monitor received an event: {leaked:?}"
+    );
+
+    drop(matching);
+    drop(other);
+    drop(client);
+    let _ = harness.stop().await?;
+    Ok(())
+}
+
+/// Symbol and payload of a monitor event, or `None` once the stream closed.
+#[tokio::test(flavor = "multi_thread")]
+async fn monitor_replays_logged_events_before_live_ones() -> Result<()> {
+    let harness = LiveTestHarness::start(false, false).await?;
+    let mut client = connect_control_client(harness.control_addr).await?;
+    let mut live = client
+        .monitor(MonitorRequest::default())
+        .await?
+        .into_inner();
+    harness.ready.notify_waiters();
+
+    async fn next_tick(stream: &mut tonic::Streaming<Event>) -> Result<tesser_rpc::proto::Tick> {
+        loop {
+            match stream.message().await? {
+                Some(Event {
+                    payload: Some(Payload::Tick(tick)),
+                }) => return Ok(tick),
+                Some(_) => continue,
+                None => return Err(anyhow!("monitor stream closed")),
+            }
+        }
+    }
+
+    let first = timeout(Duration::from_secs(10), next_tick(&mut live))
+        .await
+        .map_err(|_| anyhow!("timed out waiting for live tick"))??;
+    let mut replayed = client
+        .monitor(MonitorRequest {
+            replay_from_seq: Some(0),
+            ..MonitorRequest::default()
+        })
+        .await?
+        .into_inner();
+    let replayed_first = timeout(Duration::from_secs(10), next_tick(&mut replayed))
+        .await
+        .map_err(|_| anyhow!("timed out waiting for replayed tick"))??;
+    // Replay starts at the first logged event, so it leads with the tick seen live first.
+    assert_eq!(replayed_first, first);
+
+    drop(live);
+    drop(replayed);
+    drop(client);
+    let _ = harness.stop().await?;
+    Ok(())
+}
+
+fn stream_symbol(event: Option<Event>) -> Option<(String, Option<Payload>)> {
+    let payload = event?.payload;
+    let symbol = match &payload {
+        Some(Payload::Tick(tick)) => tick.symbol.clone(),
+        Some(Payload::Candle(candle)) => candle.symbol.clone(),
+        Some(Payload::Signal(signal)) => signal.symbol.clone(),
+        Some(Payload::Fill(fill)) => fill.symbol.clone(),
+        Some(Payload::Order(order)) => order.symbol.clone(),
+        None => String::new(),
+    };
+    Some((symbol, payload))
+}
+
 struct LiveTestHarness {
     shutdown: ShutdownSignal,
     run_handle: tokio::task::JoinHandle<Result<()>>,
@@ -464,6 +584,7 @@ impl LiveTestHarness {
             close: Decimal::new(1_005, 0),
             volume: Decimal::ONE,
             timestamp: Utc::now(),
+            status: CandleStatus::Closed,
         }];
         let ticks = (0..5)
             .map(|i| Tick {
@@ -522,11 +643,22 @@ impl LiveTestHarness {
             risk: RiskManagementConfig::default(),
             reconciliation_interval: Duration::from_secs(5),
             reconciliation_threshold: Decimal::new(1, 3),
+            reconciliation_asset_thresholds: HashMap::new(),
             orderbook_depth: 50,
             record_path: record_data.then(|| record_root.clone()),
             control_addr,
+            control_token: None,
             panic_close: PanicCloseConfig::default(),
             plugins_dir: None,
+            candles_from_ticks: false,
+            tick_filter: None,
+            auto_correct_positions: false,
+            max_corrective_quantity: Decimal::ONE,
+            warmup_paths: Vec::new(),
+            order_mapping: OrderMappingPolicy::default(),
+            cooldown_after_stop: None,
+            min_notional_policy: MinNotionalPolicy::Drop,
+            event_log_dir: Some(temp.path().join("events")),
         };
 
         let shutdown = ShutdownSignal::new();
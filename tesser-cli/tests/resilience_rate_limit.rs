@@ -9,7 +9,8 @@ use rust_decimal::Decimal;
 use tesser_broker::{ExecutionClient, Quota};
 use tesser_bybit::{BybitClient, BybitConfig, BybitCredentials};
 use tesser_core::{
-    AccountBalance, AssetId, Candle, ExchangeId, Interval, OrderRequest, Side, Symbol, Tick,
+    AccountBalance, AssetId, Candle, CandleStatus, ExchangeId, Interval, OrderRequest, Side,
+    Symbol, Tick,
 };
 use tesser_test_utils::{AccountConfig, MockExchange, MockExchangeConfig};
 
@@ -46,6 +47,7 @@ async fn bybit_client_honors_private_rate_limit() -> Result<()> {
         close: Decimal::new(20_005, 0),
         volume: Decimal::ONE,
         timestamp: Utc::now(),
+        status: CandleStatus::Closed,
     }];
     let ticks = vec![Tick {
         symbol: test_symbol(),
@@ -12,8 +12,8 @@ use tokio::time::sleep;
 use tesser_broker::ExecutionClient;
 use tesser_bybit::{BybitClient, BybitConfig, BybitCredentials};
 use tesser_core::{
-    AccountBalance, AssetId, Candle, ExchangeId, ExecutionHint, Interval, Order, OrderStatus, Side,
-    Signal, SignalKind, Symbol, Tick,
+    AccountBalance, AssetId, Candle, CandleStatus, ExchangeId, ExecutionHint, Interval, Order,
+    OrderStatus, OrderType, Side, Signal, SignalKind, Symbol, Tick,
 };
 use tesser_execution::{
     ExecutionEngine, FixedOrderSizer, NoopRiskChecker, OrderOrchestrator, PanicCloseConfig,
@@ -76,6 +76,7 @@ async fn twap_orders_adopt_after_restart() -> Result<()> {
         close: Decimal::new(20_000, 0),
         volume: Decimal::ONE,
         timestamp: Utc::now(),
+        status: CandleStatus::Closed,
     }];
     let config = MockExchangeConfig::new()
         .with_exchange(bybit_exchange())
@@ -214,3 +215,138 @@ async fn twap_orders_adopt_after_restart() -> Result<()> {
     exchange.shutdown().await;
     Ok(())
 }
+
+fn tick_at(price: Decimal) -> Tick {
+    Tick {
+        symbol: test_symbol(),
+        price,
+        size: Decimal::ONE,
+        side: Side::Buy,
+        exchange_timestamp: Utc::now(),
+        received_at: Utc::now(),
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn trailing_stop_tightens_adopted_stop_after_restart() -> Result<()> {
+    let _ = tracing_subscriber::fmt::try_init();
+    let usdt = usdt_asset();
+    let account = AccountConfig::new("test-key", "test-secret").with_balance(AccountBalance {
+        exchange: usdt.exchange,
+        asset: usdt,
+        total: Decimal::new(10_000, 0),
+        available: Decimal::new(10_000, 0),
+        updated_at: Utc::now(),
+    });
+    let config = MockExchangeConfig::new()
+        .with_exchange(bybit_exchange())
+        .with_account(account);
+    let mut exchange = MockExchange::start(config).await?;
+
+    let bybit_cfg = BybitConfig {
+        base_url: exchange.rest_url(),
+        ws_url: Some(exchange.ws_url()),
+        ..BybitConfig::default()
+    };
+    let raw_client = Arc::new(BybitClient::new(
+        bybit_cfg,
+        Some(BybitCredentials {
+            api_key: "test-key".into(),
+            api_secret: "test-secret".into(),
+        }),
+        tesser_core::ExchangeId::from("bybit_linear"),
+    ));
+    let client: Arc<dyn ExecutionClient> = raw_client.clone();
+    let engine = Arc::new(ExecutionEngine::new(
+        client.clone(),
+        Box::new(FixedOrderSizer {
+            quantity: Decimal::ONE,
+        }),
+        Arc::new(NoopRiskChecker),
+    ));
+
+    let temp = tempdir()?;
+    let algo_path = temp.path().join("algos.db");
+    let repo = Arc::new(SqliteAlgoStateRepository::new(&algo_path)?);
+    let orchestrator = OrderOrchestrator::new(
+        engine,
+        repo.clone(),
+        Vec::new(),
+        PanicCloseConfig::default(),
+        None,
+        None,
+    )
+    .await?;
+
+    let symbol = test_symbol();
+    let signal =
+        Signal::new(symbol, SignalKind::ExitLong, 0.8).with_hint(ExecutionHint::TrailingStop {
+            activation_price: Decimal::new(20_000, 0),
+            callback_rate: Decimal::new(5, 2),
+        });
+    let ctx = RiskContext {
+        symbol,
+        exchange: symbol.exchange,
+        signed_position_qty: Decimal::ONE,
+        portfolio_equity: Decimal::from(10_000),
+        exchange_equity: Decimal::from(10_000),
+        last_price: Decimal::new(20_000, 0),
+        liquidate_only: false,
+        ..RiskContext::default()
+    };
+    orchestrator.on_signal(&signal, &ctx).await?;
+    orchestrator
+        .on_tick(&tick_at(Decimal::new(20_000, 0)))
+        .await?;
+
+    let first_stop = assert_single_open_order(raw_client.as_ref()).await?;
+    assert_eq!(first_stop.request.order_type, OrderType::StopMarket);
+    assert_eq!(
+        first_stop.request.trigger_price,
+        Some(Decimal::new(19_000, 0))
+    );
+
+    drop(orchestrator);
+    let mut open_orders = raw_client.list_open_orders(test_symbol()).await?;
+    for order in &mut open_orders {
+        order.request.symbol = test_symbol();
+    }
+    let restarted_engine = Arc::new(ExecutionEngine::new(
+        client.clone(),
+        Box::new(FixedOrderSizer {
+            quantity: Decimal::ONE,
+        }),
+        Arc::new(NoopRiskChecker),
+    ));
+    let restored = OrderOrchestrator::new(
+        restarted_engine,
+        repo.clone(),
+        open_orders,
+        PanicCloseConfig::default(),
+        None,
+        None,
+    )
+    .await?;
+    restored.update_risk_context(symbol, ctx);
+    assert_eq!(restored.active_algorithms_count(), 1);
+
+    // The mock venue does not support amends, so tightening the adopted stop falls back to
+    // canceling it; the next tick re-places it at the new threshold under a fresh client id.
+    restored.on_tick(&tick_at(Decimal::new(21_000, 0))).await?;
+    restored.on_tick(&tick_at(Decimal::new(21_000, 0))).await?;
+
+    let tightened = assert_single_open_order(raw_client.as_ref()).await?;
+    assert_ne!(tightened.id, first_stop.id);
+    assert_eq!(tightened.request.order_type, OrderType::StopMarket);
+    assert_eq!(
+        tightened.request.trigger_price,
+        Some(Decimal::new(19_950, 0))
+    );
+    assert_ne!(
+        tightened.request.client_order_id,
+        first_stop.request.client_order_id
+    );
+
+    exchange.shutdown().await;
+    Ok(())
+}
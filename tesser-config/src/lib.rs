@@ -74,16 +74,68 @@ pub struct LiveRuntimeConfig {
     pub metrics_addr: String,
     #[serde(default = "default_control_addr")]
     pub control_addr: String,
+    /// Bearer token required on every control-plane call; the plane is open when unset.
+    /// Prefer `control_token_file` or the `TESSER_LIVE__CONTROL_TOKEN` environment variable
+    /// over committing the secret to a config file.
+    #[serde(default)]
+    pub control_token: Option<String>,
+    /// File holding the control-plane token; takes precedence over `control_token`.
+    #[serde(default)]
+    pub control_token_file: Option<PathBuf>,
     #[serde(default = "default_live_log_path")]
     pub log_path: PathBuf,
     #[serde(default = "default_reconciliation_interval_secs")]
     pub reconciliation_interval_secs: u64,
     #[serde(default = "default_reconciliation_threshold")]
     pub reconciliation_threshold: Decimal,
+    /// Per-asset overrides of `reconciliation_threshold`, keyed by asset code (e.g. "BTC").
+    #[serde(default)]
+    pub reconciliation_asset_thresholds: HashMap<String, Decimal>,
+    /// Trade away position divergence above `reconciliation_threshold`; trading still halts
+    /// until an operator lifts liquidate-only.
+    #[serde(default)]
+    pub auto_correct_positions: bool,
+    /// Largest corrective order placed per symbol and cycle; defaults to the risk order cap.
+    #[serde(default)]
+    pub max_corrective_quantity: Option<Decimal>,
+    /// What to do with entry orders below the instrument's minimum notional.
+    #[serde(default)]
+    pub min_notional_policy: MinNotionalPolicy,
     #[serde(default)]
     pub alerting: AlertingConfig,
     #[serde(default)]
     pub plugins_dir: Option<PathBuf>,
+    /// Route live market data into simulated (paper) execution instead of the exchange.
+    #[serde(default)]
+    pub dry_run: bool,
+    /// Drop outlier ticks before they reach strategies; disabled when omitted.
+    #[serde(default)]
+    pub tick_filter: Option<TickFilterConfig>,
+    /// Directory of the write-ahead log of published runtime events, replayable by `monitor`
+    /// subscribers after a restart; events are not persisted when omitted.
+    #[serde(default)]
+    pub event_log_dir: Option<PathBuf>,
+}
+
+/// Handling of entry orders whose notional is below the instrument minimum.
+#[derive(Debug, Default, Deserialize, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MinNotionalPolicy {
+    /// Skip the order.
+    #[default]
+    Drop,
+    /// Raise the quantity to the smallest lot multiple meeting the minimum.
+    ScaleUp,
+}
+
+/// Rejects ticks with non-positive sizes or prices far from the recent rolling median.
+#[derive(Debug, Deserialize, Clone)]
+pub struct TickFilterConfig {
+    /// Maximum distance from the rolling median, in percent, before a tick is rejected.
+    pub max_deviation_pct: Decimal,
+    /// Number of recent prices per symbol used to compute the median.
+    #[serde(default = "default_tick_filter_window")]
+    pub window: usize,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -96,6 +148,9 @@ pub struct AlertingConfig {
     pub max_order_failures: u32,
     #[serde(default = "default_drawdown_limit")]
     pub max_drawdown: Decimal,
+    /// Enter liquidate-only mode when no market data arrives for `max_data_gap_secs`.
+    #[serde(default)]
+    pub data_gap_liquidate_only: bool,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -108,6 +163,16 @@ pub struct RiskManagementConfig {
     pub max_order_notional: Option<Decimal>,
     #[serde(default = "default_risk_drawdown_limit")]
     pub max_drawdown: Decimal,
+    /// Drawdown tiers that scale open positions down instead of halting trading.
+    #[serde(default)]
+    pub deleverage_tiers: Vec<DeleverageTierConfig>,
+}
+
+/// Drawdown threshold paired with the fraction of each position to cut when crossed.
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq)]
+pub struct DeleverageTierConfig {
+    pub drawdown: Decimal,
+    pub reduce_fraction: Decimal,
 }
 
 impl Default for BacktestConfig {
@@ -127,11 +192,20 @@ impl Default for LiveRuntimeConfig {
             persistence: PersistenceConfig::default(),
             metrics_addr: default_metrics_addr(),
             control_addr: default_control_addr(),
+            control_token: None,
+            control_token_file: None,
             log_path: default_live_log_path(),
             reconciliation_interval_secs: default_reconciliation_interval_secs(),
             reconciliation_threshold: default_reconciliation_threshold(),
+            reconciliation_asset_thresholds: HashMap::new(),
+            auto_correct_positions: false,
+            max_corrective_quantity: None,
+            min_notional_policy: MinNotionalPolicy::default(),
             alerting: AlertingConfig::default(),
             plugins_dir: None,
+            dry_run: false,
+            tick_filter: None,
+            event_log_dir: None,
         }
     }
 }
@@ -150,6 +224,7 @@ impl Default for AlertingConfig {
             max_data_gap_secs: default_data_gap_secs(),
             max_order_failures: default_order_failure_limit(),
             max_drawdown: default_drawdown_limit(),
+            data_gap_liquidate_only: false,
         }
     }
 }
@@ -161,6 +236,7 @@ impl Default for RiskManagementConfig {
             max_position_quantity: default_max_position_quantity(),
             max_order_notional: None,
             max_drawdown: default_risk_drawdown_limit(),
+            deleverage_tiers: Vec::new(),
         }
     }
 }
@@ -264,6 +340,10 @@ fn default_reconciliation_threshold() -> Decimal {
     Decimal::new(1, 3) // 0.001 == 0.1%
 }
 
+fn default_tick_filter_window() -> usize {
+    50
+}
+
 fn default_data_gap_secs() -> u64 {
     300
 }
@@ -0,0 +1,87 @@
+//! Serde helpers encoding [`chrono::Duration`] as a number of seconds.
+//!
+//! Whole durations serialize as integers and sub-second durations as floats. Deserialization
+//! accepts either form, so hand-written JSON such as `{"duration": 90}` works as expected.
+
+use std::fmt;
+
+use chrono::Duration;
+use serde::de::{self, Visitor};
+use serde::{Deserializer, Serializer};
+
+const NANOS_PER_SEC: f64 = 1_000_000_000.0;
+
+pub fn serialize<S>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    if duration.subsec_nanos() == 0 {
+        serializer.serialize_i64(duration.num_seconds())
+    } else {
+        let nanos = duration
+            .num_nanoseconds()
+            .map(|nanos| nanos as f64)
+            .unwrap_or_else(|| duration.num_milliseconds() as f64 * 1_000_000.0);
+        serializer.serialize_f64(nanos / NANOS_PER_SEC)
+    }
+}
+
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_any(SecondsVisitor)
+}
+
+struct SecondsVisitor;
+
+impl Visitor<'_> for SecondsVisitor {
+    type Value = Duration;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a duration in seconds")
+    }
+
+    fn visit_i64<E: de::Error>(self, secs: i64) -> Result<Duration, E> {
+        Duration::try_seconds(secs)
+            .ok_or_else(|| E::custom(format!("duration of {secs}s is out of range")))
+    }
+
+    fn visit_u64<E: de::Error>(self, secs: u64) -> Result<Duration, E> {
+        let secs = i64::try_from(secs)
+            .map_err(|_| E::custom(format!("duration of {secs}s is out of range")))?;
+        self.visit_i64(secs)
+    }
+
+    fn visit_f64<E: de::Error>(self, secs: f64) -> Result<Duration, E> {
+        let nanos = (secs * NANOS_PER_SEC).round();
+        if !nanos.is_finite() || nanos.abs() >= i64::MAX as f64 {
+            return Err(E::custom(format!("duration of {secs}s is out of range")));
+        }
+        Ok(Duration::nanoseconds(nanos as i64))
+    }
+}
+
+/// Variant of the parent module for `Option<Duration>` fields.
+pub mod option {
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(transparent)]
+    struct Seconds(#[serde(with = "super")] Duration);
+
+    pub fn serialize<S>(duration: &Option<Duration>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        duration.map(Seconds).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Duration>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(Option::<Seconds>::deserialize(deserializer)?.map(|Seconds(duration)| duration))
+    }
+}
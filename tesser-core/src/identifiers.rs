@@ -263,6 +263,9 @@ pub struct Symbol {
 }
 
 impl Symbol {
+    /// Separator between the exchange and the venue code in qualified symbols.
+    pub const DELIMITER: char = ':';
+
     pub const fn new(exchange: ExchangeId, market_id: u32) -> Self {
         Self {
             exchange,
@@ -294,11 +297,33 @@ impl Symbol {
         Self::new(exchange, id)
     }
 
+    /// Parse `exchange:CODE` (or a bare `CODE`) into a symbol.
+    pub fn parse(value: &str) -> Result<Self, IdentifierParseError> {
+        value.parse()
+    }
+
     #[must_use]
     pub fn code(&self) -> &'static str {
         symbol_code_lookup(self.exchange, self.market_id)
     }
 
+    #[must_use]
+    pub const fn exchange(&self) -> ExchangeId {
+        self.exchange
+    }
+
+    /// Returns `true` when the symbol carries an exchange qualifier.
+    #[must_use]
+    pub fn is_qualified(&self) -> bool {
+        self.exchange.is_specified()
+    }
+
+    /// Filesystem-safe rendering of a raw symbol string, e.g. for partition directories.
+    #[must_use]
+    pub fn sanitize(raw: &str) -> String {
+        raw.replace(Self::DELIMITER, "_")
+    }
+
     pub const fn unspecified() -> Self {
         Self::new(ExchangeId::UNSPECIFIED, 0)
     }
@@ -312,7 +337,7 @@ impl Default for Symbol {
 
 impl fmt::Display for Symbol {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}:{}", self.exchange, self.code())
+        write!(f, "{}{}{}", self.exchange, Self::DELIMITER, self.code())
     }
 }
 
@@ -428,7 +453,7 @@ fn split_identifier<'a>(
     if value.is_empty() {
         return Err(IdentifierParseError::new(kind, value));
     }
-    if let Some((exchange, rest)) = value.split_once(':') {
+    if let Some((exchange, rest)) = value.split_once(Symbol::DELIMITER) {
         let exchange = exchange.parse()?;
         let rest = rest.trim();
         if rest.is_empty() {
@@ -467,3 +492,41 @@ fn symbol_code_lookup(exchange: ExchangeId, id: u32) -> &'static str {
 fn leak_string(value: String) -> &'static str {
     Box::leak(value.into_boxed_str())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_qualified_symbols() {
+        let symbol = Symbol::parse("binance:btcusdt").unwrap();
+        assert!(symbol.is_qualified());
+        assert_eq!(symbol.exchange().name(), "binance");
+        assert_eq!(symbol.code(), "BTCUSDT");
+        let rendered = symbol.to_string();
+        assert_eq!(rendered, "binance:BTCUSDT");
+        assert_eq!(Symbol::parse(&rendered).unwrap(), symbol);
+    }
+
+    #[test]
+    fn parses_unqualified_symbols() {
+        let symbol = Symbol::parse("ETHUSDT").unwrap();
+        assert!(!symbol.is_qualified());
+        assert_eq!(symbol.exchange(), ExchangeId::UNSPECIFIED);
+        assert_eq!(symbol.code(), "ETHUSDT");
+        assert_eq!(Symbol::from("ETHUSDT"), symbol);
+    }
+
+    #[test]
+    fn rejects_malformed_symbols() {
+        assert!(Symbol::parse("").is_err());
+        assert!(Symbol::parse("binance:").is_err());
+        assert!(Symbol::parse(":BTCUSDT").is_err());
+    }
+
+    #[test]
+    fn sanitize_replaces_delimiter() {
+        assert_eq!(Symbol::sanitize("binance:BTCUSDT"), "binance_BTCUSDT");
+        assert_eq!(Symbol::sanitize("BTCUSDT"), "BTCUSDT");
+    }
+}
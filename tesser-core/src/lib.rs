@@ -12,7 +12,9 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use uuid::Uuid;
 
+mod duration_secs;
 mod identifiers;
+pub mod validated;
 
 pub use identifiers::{AssetId, ExchangeId, IdentifierParseError, Symbol};
 
@@ -43,6 +45,9 @@ pub struct Instrument {
     pub settlement_currency: AssetId,
     pub tick_size: Price,
     pub lot_size: Quantity,
+    /// Smallest order notional (price x quantity) accepted by the venue, if it enforces one.
+    #[serde(default)]
+    pub min_notional: Option<Price>,
 }
 
 /// Represents a currency balance and its current conversion rate to the reporting currency.
@@ -119,12 +124,21 @@ impl CashBook {
 }
 
 /// Execution hints for algorithmic order placement.
+///
+/// Hints serialize with a snake_case `type` tag and durations encoded as seconds. Tags this
+/// build does not recognise deserialize to [`ExecutionHint::Unknown`] instead of failing, so
+/// older binaries can still load signals produced by newer ones.
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
 pub enum ExecutionHint {
     /// Time-Weighted Average Price execution over specified duration.
-    Twap { duration: Duration },
+    Twap {
+        #[serde(with = "duration_secs")]
+        duration: Duration,
+    },
     /// Volume-Weighted Average Price execution.
     Vwap {
+        #[serde(with = "duration_secs")]
         duration: Duration,
         #[serde(default)]
         participation_rate: Option<Decimal>,
@@ -148,7 +162,7 @@ pub enum ExecutionHint {
     /// Sits on the sidelines until a target price is reached, then fires aggressively.
     Sniper {
         trigger_price: Price,
-        #[serde(default)]
+        #[serde(default, with = "duration_secs::option")]
         timeout: Option<Duration>,
     },
     /// Trailing stop that arms above an activation price and trails by a callback rate.
@@ -162,6 +176,9 @@ pub enum ExecutionHint {
         #[serde(default)]
         params: Value,
     },
+    /// A hint type introduced by a newer release; executors should refuse to act on it.
+    #[serde(other)]
+    Unknown,
 }
 
 /// Configurable exit management policies shared by strategies and control surfaces.
@@ -259,6 +276,30 @@ impl Interval {
         }
     }
 
+    /// Round `timestamp` down to the start of the interval bucket containing it.
+    #[must_use]
+    pub fn align_down(self, timestamp: DateTime<Utc>) -> DateTime<Utc> {
+        let step = self
+            .as_duration()
+            .num_nanoseconds()
+            .expect("interval nanoseconds fit into i64");
+        let nanos = timestamp
+            .timestamp_nanos_opt()
+            .expect("timestamp fits into i64 nanoseconds");
+        let aligned = nanos - nanos.rem_euclid(step);
+        DateTime::<Utc>::from_timestamp(
+            aligned.div_euclid(1_000_000_000),
+            aligned.rem_euclid(1_000_000_000) as u32,
+        )
+        .expect("aligned timestamp within chrono supported range")
+    }
+
+    /// Start of the interval bucket following the one containing `timestamp`.
+    #[must_use]
+    pub fn next_boundary(self, timestamp: DateTime<Utc>) -> DateTime<Utc> {
+        self.align_down(timestamp) + self.as_duration()
+    }
+
     /// Convert to Bybit interval identifiers.
     #[must_use]
     pub fn to_bybit(self) -> &'static str {
@@ -315,6 +356,17 @@ pub struct Tick {
     pub received_at: DateTime<Utc>,
 }
 
+/// Whether a candle's interval has ended.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CandleStatus {
+    /// The interval is still open; later updates may revise the bar.
+    Forming,
+    /// The interval has ended and the bar is final.
+    #[default]
+    Closed,
+}
+
 /// Aggregated OHLCV bar data.
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Candle {
@@ -326,8 +378,111 @@ pub struct Candle {
     pub close: Price,
     pub volume: Quantity,
     pub timestamp: DateTime<Utc>,
+    /// Historical and stored candles are always closed, so the field defaults accordingly.
+    #[serde(default)]
+    pub status: CandleStatus,
 }
 
+impl Candle {
+    /// Build a candle after checking that its OHLCV fields are self-consistent.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_validated(
+        symbol: Symbol,
+        interval: Interval,
+        open: Price,
+        high: Price,
+        low: Price,
+        close: Price,
+        volume: Quantity,
+        timestamp: DateTime<Utc>,
+    ) -> Result<Self, CandleError> {
+        let candle = Self {
+            symbol,
+            interval,
+            open,
+            high,
+            low,
+            close,
+            volume,
+            timestamp,
+            status: CandleStatus::Closed,
+        };
+        candle.validate()?;
+        Ok(candle)
+    }
+
+    /// True once the candle's interval has ended; forming candles may still repaint.
+    pub fn is_closed(&self) -> bool {
+        self.status == CandleStatus::Closed
+    }
+
+    /// Check the OHLCV invariants without constructing a new candle.
+    ///
+    /// Prices are decimals and therefore always finite; negative prices are rejected instead.
+    pub fn validate(&self) -> Result<(), CandleError> {
+        for (field, value) in [
+            ("open", self.open),
+            ("high", self.high),
+            ("low", self.low),
+            ("close", self.close),
+        ] {
+            if value.is_sign_negative() && !value.is_zero() {
+                return Err(CandleError::NegativePrice { field, value });
+            }
+        }
+        let body_high = self.open.max(self.close);
+        if self.high < body_high {
+            return Err(CandleError::HighBelowBody {
+                high: self.high,
+                body_high,
+            });
+        }
+        let body_low = self.open.min(self.close);
+        if self.low > body_low {
+            return Err(CandleError::LowAboveBody {
+                low: self.low,
+                body_low,
+            });
+        }
+        if self.volume.is_sign_negative() && !self.volume.is_zero() {
+            return Err(CandleError::NegativeVolume(self.volume));
+        }
+        Ok(())
+    }
+}
+
+/// Reasons a candle can fail [`Candle::validate`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CandleError {
+    /// The high is below `max(open, close)`.
+    HighBelowBody { high: Price, body_high: Price },
+    /// The low is above `min(open, close)`.
+    LowAboveBody { low: Price, body_low: Price },
+    /// The traded volume is negative.
+    NegativeVolume(Quantity),
+    /// One of the OHLC prices is negative.
+    NegativePrice { field: &'static str, value: Price },
+}
+
+impl std::fmt::Display for CandleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::HighBelowBody { high, body_high } => {
+                write!(f, "candle high {high} is below open/close {body_high}")
+            }
+            Self::LowAboveBody { low, body_low } => {
+                write!(f, "candle low {low} is above open/close {body_low}")
+            }
+            Self::NegativeVolume(volume) => write!(f, "candle volume {volume} is negative"),
+            Self::NegativePrice { field, value } => {
+                write!(f, "candle {field} price {value} is negative")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CandleError {}
+
 /// Represents a single level in the order book.
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct OrderBookLevel {
@@ -634,6 +789,38 @@ pub struct OrderRequest {
     pub display_quantity: Option<Quantity>,
 }
 
+impl OrderRequest {
+    /// Check that the quantity and every supplied price are strictly positive.
+    pub fn validate(&self) -> Result<(), validated::ValueError> {
+        validated::Quantity::try_new(self.quantity)?;
+        if let Some(display) = self.display_quantity {
+            validated::Quantity::try_new(display)?;
+        }
+        for price in [
+            self.price,
+            self.trigger_price,
+            self.take_profit,
+            self.stop_loss,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            validated::Price::try_new(price)?;
+        }
+        Ok(())
+    }
+
+    /// The order quantity as a checked [`validated::Quantity`].
+    pub fn checked_quantity(&self) -> Result<validated::Quantity, validated::ValueError> {
+        validated::Quantity::try_new(self.quantity)
+    }
+
+    /// The limit price as a checked [`validated::Price`], if one is set.
+    pub fn checked_price(&self) -> Result<Option<validated::Price>, validated::ValueError> {
+        self.price.map(validated::Price::try_new).transpose()
+    }
+}
+
 /// Order amendment intent allowing connectors to update existing orders in-place.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct OrderUpdateRequest {
@@ -642,6 +829,9 @@ pub struct OrderUpdateRequest {
     pub side: Side,
     pub new_price: Option<Price>,
     pub new_quantity: Option<Quantity>,
+    /// Moves the trigger of a conditional (stop) order.
+    #[serde(default)]
+    pub new_trigger_price: Option<Price>,
 }
 
 /// High-level order status maintained inside the framework.
@@ -655,6 +845,41 @@ pub enum OrderStatus {
     Rejected,
 }
 
+impl OrderStatus {
+    /// Every status, in lifecycle order.
+    pub const ALL: [OrderStatus; 6] = [
+        Self::PendingNew,
+        Self::Accepted,
+        Self::PartiallyFilled,
+        Self::Filled,
+        Self::Canceled,
+        Self::Rejected,
+    ];
+
+    /// Whether the order can no longer change.
+    #[must_use]
+    pub fn is_terminal(self) -> bool {
+        matches!(self, Self::Filled | Self::Canceled | Self::Rejected)
+    }
+
+    /// Whether an order in this status may legally move to `next`.
+    ///
+    /// Repeating the current status is always allowed so duplicate updates are harmless.
+    /// Only a pending order can be rejected, and nothing leaves a terminal status.
+    #[must_use]
+    pub fn can_transition_to(self, next: OrderStatus) -> bool {
+        if self == next {
+            return true;
+        }
+        match self {
+            Self::PendingNew => true,
+            Self::Accepted => matches!(next, Self::PartiallyFilled | Self::Filled | Self::Canceled),
+            Self::PartiallyFilled => matches!(next, Self::Filled | Self::Canceled),
+            Self::Filled | Self::Canceled | Self::Rejected => false,
+        }
+    }
+}
+
 /// Order representation that aggregates exchange state.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Order {
@@ -744,6 +969,9 @@ pub struct Signal {
     pub stop_loss: Option<Price>,
     pub take_profit: Option<Price>,
     pub execution_hint: Option<ExecutionHint>,
+    /// Latest time at which the signal may still be acted on.
+    #[serde(default)]
+    pub valid_until: Option<DateTime<Utc>>,
 }
 
 /// The type of action a signal instructs the execution layer to take.
@@ -754,6 +982,10 @@ pub enum SignalKind {
     EnterShort,
     ExitShort,
     Flatten,
+    /// Trim part of a long position; the amount comes from [`Signal::quantity`].
+    ReduceLong,
+    /// Trim part of a short position; the amount comes from [`Signal::quantity`].
+    ReduceShort,
 }
 
 impl SignalKind {
@@ -761,8 +993,8 @@ impl SignalKind {
     #[must_use]
     pub fn side(self) -> Side {
         match self {
-            Self::EnterLong | Self::ExitShort => Side::Buy,
-            Self::EnterShort | Self::ExitLong => Side::Sell,
+            Self::EnterLong | Self::ExitShort | Self::ReduceShort => Side::Buy,
+            Self::EnterShort | Self::ExitLong | Self::ReduceLong => Side::Sell,
             Self::Flatten => {
                 // For flatten, we need position context to determine side
                 // This is a simplification - in practice, the execution engine
@@ -790,6 +1022,7 @@ impl Signal {
             stop_loss: None,
             take_profit: None,
             execution_hint: None,
+            valid_until: None,
         }
     }
 
@@ -820,6 +1053,19 @@ impl Signal {
         self.panic_behavior = Some(behavior);
         self
     }
+
+    /// Discard the signal if it has not been acted on by `valid_until`.
+    #[must_use]
+    pub fn with_valid_until(mut self, valid_until: DateTime<Utc>) -> Self {
+        self.valid_until = Some(valid_until);
+        self
+    }
+
+    /// Returns true when the validity window has elapsed at `now`.
+    #[must_use]
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.valid_until.is_some_and(|deadline| now > deadline)
+    }
 }
 
 /// Overrides applied to the orchestrator's panic close logic for a specific signal/group.
@@ -836,12 +1082,136 @@ mod tests {
     use super::*;
     use rust_decimal::prelude::FromPrimitive;
 
+    fn limit_request(quantity: Decimal, price: Decimal) -> OrderRequest {
+        OrderRequest {
+            symbol: Symbol::from("BTCUSDT"),
+            side: Side::Buy,
+            order_type: OrderType::Limit,
+            quantity,
+            price: Some(price),
+            trigger_price: None,
+            time_in_force: None,
+            client_order_id: None,
+            take_profit: None,
+            stop_loss: None,
+            display_quantity: None,
+        }
+    }
+
+    #[test]
+    fn order_request_validation_rejects_non_positive_values() {
+        let valid = limit_request(Decimal::ONE, Decimal::from(100));
+        assert!(valid.validate().is_ok());
+        assert_eq!(*valid.checked_quantity().unwrap(), Decimal::ONE);
+        assert_eq!(*valid.checked_price().unwrap().unwrap(), Decimal::from(100));
+
+        for quantity in [Decimal::ZERO, Decimal::NEGATIVE_ONE] {
+            let request = limit_request(quantity, Decimal::from(100));
+            assert_eq!(
+                request.validate(),
+                Err(validated::ValueError::NonPositive {
+                    kind: "quantity",
+                    value: quantity,
+                })
+            );
+            assert!(request.checked_quantity().is_err());
+        }
+        assert!(limit_request(Decimal::ONE, Decimal::from(-100))
+            .validate()
+            .is_err());
+        let mut stop = valid.clone();
+        stop.stop_loss = Some(Decimal::ZERO);
+        assert!(stop.validate().is_err());
+    }
+
     #[test]
     fn interval_duration_matches_definition() {
         assert_eq!(Interval::OneMinute.as_duration(), Duration::minutes(1));
         assert_eq!(Interval::FourHours.as_duration(), Duration::hours(4));
     }
 
+    #[test]
+    fn interval_aligns_timestamps_to_bucket_boundaries() {
+        let ts = DateTime::parse_from_rfc3339("2024-03-01T10:07:42.5Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let start = DateTime::parse_from_rfc3339("2024-03-01T10:05:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        assert_eq!(Interval::FiveMinutes.align_down(ts), start);
+        assert_eq!(
+            Interval::FiveMinutes.next_boundary(ts),
+            start + Duration::minutes(5)
+        );
+        assert_eq!(Interval::FiveMinutes.align_down(start), start);
+    }
+
+    fn validated_candle(
+        open: i64,
+        high: i64,
+        low: i64,
+        close: i64,
+        volume: i64,
+    ) -> Result<Candle, CandleError> {
+        Candle::new_validated(
+            Symbol::from("BTCUSDT"),
+            Interval::OneMinute,
+            Decimal::from(open),
+            Decimal::from(high),
+            Decimal::from(low),
+            Decimal::from(close),
+            Decimal::from(volume),
+            DateTime::<Utc>::UNIX_EPOCH,
+        )
+    }
+
+    #[test]
+    fn validated_candle_accepts_consistent_bar() {
+        assert!(validated_candle(100, 105, 95, 102, 10).is_ok());
+        assert!(validated_candle(100, 100, 100, 100, 0).is_ok());
+    }
+
+    #[test]
+    fn validated_candle_rejects_high_below_body() {
+        assert_eq!(
+            validated_candle(100, 101, 95, 102, 10),
+            Err(CandleError::HighBelowBody {
+                high: Decimal::from(101),
+                body_high: Decimal::from(102),
+            })
+        );
+    }
+
+    #[test]
+    fn validated_candle_rejects_low_above_body() {
+        assert_eq!(
+            validated_candle(100, 105, 99, 98, 10),
+            Err(CandleError::LowAboveBody {
+                low: Decimal::from(99),
+                body_low: Decimal::from(98),
+            })
+        );
+    }
+
+    #[test]
+    fn validated_candle_rejects_negative_volume() {
+        assert_eq!(
+            validated_candle(100, 105, 95, 102, -1),
+            Err(CandleError::NegativeVolume(Decimal::from(-1)))
+        );
+    }
+
+    #[test]
+    fn validated_candle_rejects_negative_price() {
+        assert_eq!(
+            validated_candle(-1, 105, -2, 102, 10),
+            Err(CandleError::NegativePrice {
+                field: "open",
+                value: Decimal::from(-1),
+            })
+        );
+    }
+
     #[test]
     fn position_mark_price_updates_unrealized_pnl() {
         let mut position = Position {
@@ -931,4 +1301,97 @@ mod tests {
             Decimal::ZERO
         );
     }
+
+    #[test]
+    fn vwap_hint_round_trips_with_duration_in_seconds() {
+        let hint = ExecutionHint::Vwap {
+            duration: Duration::minutes(15),
+            participation_rate: Some(Decimal::new(25, 2)),
+        };
+        let encoded = serde_json::to_value(&hint).unwrap();
+        assert_eq!(encoded["type"], "vwap");
+        assert_eq!(encoded["duration"], 900);
+
+        let decoded: ExecutionHint = serde_json::from_value(encoded).unwrap();
+        match decoded {
+            ExecutionHint::Vwap {
+                duration,
+                participation_rate,
+            } => {
+                assert_eq!(duration, Duration::minutes(15));
+                assert_eq!(participation_rate, Some(Decimal::new(25, 2)));
+            }
+            other => panic!("unexpected hint {other:?}"),
+        }
+    }
+
+    #[test]
+    fn execution_hint_durations_accept_fractional_and_missing_seconds() {
+        let twap: ExecutionHint =
+            serde_json::from_str(r#"{"type":"twap","duration":1.5}"#).unwrap();
+        assert!(matches!(
+            twap,
+            ExecutionHint::Twap { duration } if duration == Duration::milliseconds(1_500)
+        ));
+        let encoded = serde_json::to_string(&twap).unwrap();
+        assert_eq!(encoded, r#"{"type":"twap","duration":1.5}"#);
+
+        let sniper: ExecutionHint =
+            serde_json::from_str(r#"{"type":"sniper","trigger_price":"100"}"#).unwrap();
+        assert!(matches!(
+            sniper,
+            ExecutionHint::Sniper { timeout: None, .. }
+        ));
+    }
+
+    #[test]
+    fn unknown_execution_hint_types_deserialize_to_unknown() {
+        let hint: ExecutionHint =
+            serde_json::from_str(r#"{"type":"bracket","take_profit":"110"}"#).unwrap();
+        assert!(matches!(hint, ExecutionHint::Unknown));
+    }
+
+    #[test]
+    fn order_status_transitions_follow_the_lifecycle() {
+        use OrderStatus::*;
+        let legal = [
+            (PendingNew, Accepted),
+            (PendingNew, PartiallyFilled),
+            (PendingNew, Filled),
+            (PendingNew, Canceled),
+            (PendingNew, Rejected),
+            (Accepted, PartiallyFilled),
+            (Accepted, Filled),
+            (Accepted, Canceled),
+            (PartiallyFilled, Filled),
+            (PartiallyFilled, Canceled),
+        ];
+        for from in OrderStatus::ALL {
+            for to in OrderStatus::ALL {
+                let expected = from == to || legal.contains(&(from, to));
+                assert_eq!(from.can_transition_to(to), expected, "{from:?} -> {to:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn terminal_statuses_cannot_be_reopened() {
+        use OrderStatus::*;
+        for terminal in [Filled, Canceled, Rejected] {
+            assert!(terminal.is_terminal());
+            for next in OrderStatus::ALL
+                .into_iter()
+                .filter(|next| *next != terminal)
+            {
+                assert!(
+                    !terminal.can_transition_to(next),
+                    "{terminal:?} -> {next:?}"
+                );
+            }
+        }
+        assert!(!Filled.can_transition_to(Accepted));
+        assert!(!PartiallyFilled.can_transition_to(Accepted));
+        assert!(!Accepted.can_transition_to(PendingNew));
+        assert!(!Accepted.is_terminal());
+    }
 }
@@ -0,0 +1,133 @@
+//! Checked price and quantity wrappers.
+//!
+//! The crate-level [`crate::Price`] and [`crate::Quantity`] aliases are bare decimals. The
+//! newtypes here can only hold strictly positive values and deref to [`Decimal`], so code that
+//! has already validated an input can keep using ordinary decimal methods.
+//!
+//! [`crate::OrderRequest::validate`] checks every quantity and price of a request against them.
+
+use std::fmt;
+use std::ops::Deref;
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Reasons a decimal is rejected by [`Price::try_new`] or [`Quantity::try_new`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ValueError {
+    /// The value is zero or negative.
+    NonPositive { kind: &'static str, value: Decimal },
+}
+
+impl fmt::Display for ValueError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NonPositive { kind, value } => write!(f, "{kind} {value} must be positive"),
+        }
+    }
+}
+
+impl std::error::Error for ValueError {}
+
+macro_rules! positive_decimal {
+    ($(#[$meta:meta])* $name:ident, $kind:literal) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+        #[serde(try_from = "Decimal", into = "Decimal")]
+        pub struct $name(Decimal);
+
+        impl $name {
+            /// Wrap `value`, rejecting zero and negative inputs.
+            ///
+            /// Decimals are always finite, so no separate NaN/infinity check is needed.
+            pub fn try_new(value: Decimal) -> Result<Self, ValueError> {
+                if value <= Decimal::ZERO {
+                    return Err(ValueError::NonPositive { kind: $kind, value });
+                }
+                Ok(Self(value))
+            }
+
+            #[must_use]
+            pub fn into_inner(self) -> Decimal {
+                self.0
+            }
+        }
+
+        impl Deref for $name {
+            type Target = Decimal;
+
+            fn deref(&self) -> &Decimal {
+                &self.0
+            }
+        }
+
+        impl TryFrom<Decimal> for $name {
+            type Error = ValueError;
+
+            fn try_from(value: Decimal) -> Result<Self, Self::Error> {
+                Self::try_new(value)
+            }
+        }
+
+        impl From<$name> for Decimal {
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+    };
+}
+
+positive_decimal!(
+    /// Strictly positive price.
+    Price,
+    "price"
+);
+
+positive_decimal!(
+    /// Strictly positive quantity.
+    Quantity,
+    "quantity"
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn negative_and_zero_values_are_rejected() {
+        assert_eq!(
+            Price::try_new(Decimal::NEGATIVE_ONE),
+            Err(ValueError::NonPositive {
+                kind: "price",
+                value: Decimal::NEGATIVE_ONE,
+            })
+        );
+        assert!(Price::try_new(Decimal::ZERO).is_err());
+        assert!(Quantity::try_new(Decimal::new(-5, 1)).is_err());
+        assert!(Quantity::try_new(Decimal::ZERO).is_err());
+    }
+
+    #[test]
+    fn valid_values_deref_transparently() {
+        let price = Price::try_new(Decimal::new(10150, 2)).unwrap();
+        let qty = Quantity::try_new(Decimal::new(25, 1)).unwrap();
+        assert_eq!(*price * *qty, Decimal::new(253750, 3));
+        assert_eq!(price.round_dp(0), Decimal::from(102));
+        assert!(qty.is_sign_positive());
+        assert_eq!(Decimal::from(qty), Decimal::new(25, 1));
+    }
+
+    #[test]
+    fn deserialization_enforces_positivity() {
+        let qty: Quantity = serde_json::from_str("\"1.5\"").unwrap();
+        assert_eq!(*qty, Decimal::new(15, 1));
+        assert!(serde_json::from_str::<Quantity>("\"-1\"").is_err());
+        assert_eq!(serde_json::to_string(&qty).unwrap(), "\"1.5\"");
+    }
+}
@@ -8,8 +8,9 @@ use arrow::datatypes::SchemaRef;
 use arrow::record_batch::RecordBatch;
 use chrono::{DateTime, Utc};
 use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use rust_decimal::prelude::ToPrimitive;
 use rust_decimal::Decimal;
-use tesser_core::{Side, Symbol};
+use tesser_core::{Candle, Side, Symbol};
 
 /// Request payload for execution analytics.
 #[derive(Debug, Clone)]
@@ -644,6 +645,147 @@ fn side_value(batch: &RecordBatch, column: usize, row: usize) -> Result<Side> {
     })
 }
 
+/// Share of total volume enclosed by the value area around the point of control.
+pub fn value_area_fraction() -> Decimal {
+    Decimal::new(70, 2)
+}
+
+/// Traded volume attributed to one price bucket.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VolumeBin {
+    pub low: Decimal,
+    pub high: Decimal,
+    pub volume: Decimal,
+}
+
+impl VolumeBin {
+    pub fn mid(&self) -> Decimal {
+        (self.low + self.high) / Decimal::TWO
+    }
+}
+
+/// Volume-by-price histogram together with its point of control and value area.
+#[derive(Debug, Clone)]
+pub struct VolumeProfile {
+    pub bins: Vec<VolumeBin>,
+    pub total_volume: Decimal,
+    /// Midpoint of the bucket holding the most volume.
+    pub point_of_control: Decimal,
+    /// Upper bound of the buckets holding [`value_area_fraction`] of the volume.
+    pub value_area_high: Decimal,
+    /// Lower bound of the buckets holding [`value_area_fraction`] of the volume.
+    pub value_area_low: Decimal,
+}
+
+/// Bucket candle volume into `bins` equal-width price levels.
+pub fn volume_profile(candles: &[Candle], bins: usize) -> Result<VolumeProfile> {
+    volume_profile_with_decay(candles, bins, None)
+}
+
+/// Same as [`volume_profile`], but weights each candle by `decay^age` where the newest
+/// candle has age zero, so recent activity dominates the profile. `decay` must lie in `(0, 1]`.
+pub fn volume_profile_with_decay(
+    candles: &[Candle],
+    bins: usize,
+    decay: Option<Decimal>,
+) -> Result<VolumeProfile> {
+    if bins == 0 {
+        bail!("volume profile requires at least one bin");
+    }
+    if candles.is_empty() {
+        bail!("volume profile requires at least one candle");
+    }
+    if let Some(decay) = decay {
+        if decay <= Decimal::ZERO || decay > Decimal::ONE {
+            bail!("decay factor must be within (0, 1], got {decay}");
+        }
+    }
+    let min = candles.iter().map(|c| c.low.min(c.high)).min().unwrap();
+    let max = candles.iter().map(|c| c.high.max(c.low)).max().unwrap();
+    let width = (max - min) / Decimal::from(bins);
+    let mut profile: Vec<VolumeBin> = (0..bins)
+        .map(|idx| VolumeBin {
+            low: min + width * Decimal::from(idx),
+            high: min + width * Decimal::from(idx + 1),
+            volume: Decimal::ZERO,
+        })
+        .collect();
+    let bin_index = |price: Decimal| -> usize {
+        if width.is_zero() {
+            return 0;
+        }
+        ((price - min) / width)
+            .floor()
+            .to_usize()
+            .unwrap_or(0)
+            .min(bins - 1)
+    };
+
+    let mut weight = Decimal::ONE;
+    for candle in candles.iter().rev() {
+        let volume = candle.volume * weight;
+        if let Some(decay) = decay {
+            weight *= decay;
+        }
+        if volume.is_zero() {
+            continue;
+        }
+        let low = candle.low.min(candle.high);
+        let high = candle.high.max(candle.low);
+        let range = high - low;
+        if range.is_zero() {
+            profile[bin_index(low)].volume += volume;
+            continue;
+        }
+        // Spread the candle's volume uniformly across the price range it covered.
+        for bin in &mut profile[bin_index(low)..=bin_index(high)] {
+            let overlap = high.min(bin.high) - low.max(bin.low);
+            if overlap > Decimal::ZERO {
+                bin.volume += volume * overlap / range;
+            }
+        }
+    }
+
+    let total_volume: Decimal = profile.iter().map(|bin| bin.volume).sum();
+    let poc = profile.iter().enumerate().fold(0, |best, (idx, bin)| {
+        if bin.volume > profile[best].volume {
+            idx
+        } else {
+            best
+        }
+    });
+    let target = total_volume * value_area_fraction();
+    let (mut lower, mut upper) = (poc, poc);
+    let mut covered = profile[poc].volume;
+    while covered < target && (lower > 0 || upper + 1 < bins) {
+        let below = lower.checked_sub(1).map(|idx| profile[idx].volume);
+        let above = (upper + 1 < bins).then(|| profile[upper + 1].volume);
+        match (below, above) {
+            (Some(down), Some(up)) if down > up => {
+                lower -= 1;
+                covered += down;
+            }
+            (_, Some(up)) => {
+                upper += 1;
+                covered += up;
+            }
+            (Some(down), None) => {
+                lower -= 1;
+                covered += down;
+            }
+            (None, None) => break,
+        }
+    }
+
+    Ok(VolumeProfile {
+        point_of_control: profile[poc].mid(),
+        value_area_high: profile[upper].high,
+        value_area_low: profile[lower].low,
+        total_volume,
+        bins: profile,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -654,11 +796,77 @@ mod tests {
     use rust_decimal::prelude::FromPrimitive;
     use tempfile::tempdir;
     use tesser_core::{
-        Fill, Order, OrderRequest, OrderStatus, OrderType, Symbol, Tick, TimeInForce,
+        CandleStatus, Fill, Order, OrderRequest, OrderStatus, OrderType, Symbol, Tick, TimeInForce,
     };
 
     use crate::encoding::{fills_to_batch, orders_to_batch, ticks_to_batch};
 
+    fn flat_bucket_candle(low: i64, volume: i64, minute: u32) -> Candle {
+        Candle {
+            symbol: Symbol::from("BTCUSDT"),
+            interval: tesser_core::Interval::OneMinute,
+            open: Decimal::from(low),
+            high: Decimal::from(low + 1),
+            low: Decimal::from(low),
+            close: Decimal::from(low + 1),
+            volume: Decimal::from(volume),
+            timestamp: Utc.with_ymd_and_hms(2024, 1, 1, 0, minute, 0).unwrap(),
+            status: CandleStatus::Closed,
+        }
+    }
+
+    #[test]
+    fn volume_profile_finds_poc_and_value_area() -> Result<()> {
+        let volumes = [1, 2, 3, 5, 20, 8, 4, 2, 1, 1];
+        let candles: Vec<_> = volumes
+            .iter()
+            .enumerate()
+            .map(|(idx, volume)| flat_bucket_candle(100 + idx as i64, *volume, idx as u32))
+            .collect();
+        let profile = volume_profile(&candles, 10)?;
+
+        assert_eq!(profile.total_volume, Decimal::from(47));
+        let bucket_volumes: Vec<_> = profile.bins.iter().map(|bin| bin.volume).collect();
+        assert_eq!(bucket_volumes, volumes.map(Decimal::from));
+        assert_eq!(profile.point_of_control, Decimal::new(1045, 1));
+        // 20 (POC) + 8 above + 5 below = 33 of 47 volume, the first expansion above 70%.
+        assert_eq!(profile.value_area_low, Decimal::from(103));
+        assert_eq!(profile.value_area_high, Decimal::from(106));
+        let covered: Decimal = profile
+            .bins
+            .iter()
+            .filter(|bin| bin.low >= profile.value_area_low && bin.high <= profile.value_area_high)
+            .map(|bin| bin.volume)
+            .sum();
+        assert!(covered >= profile.total_volume * value_area_fraction());
+        assert!(covered - Decimal::from(5) < profile.total_volume * value_area_fraction());
+        Ok(())
+    }
+
+    #[test]
+    fn volume_profile_decay_favours_recent_candles() -> Result<()> {
+        let candles = vec![
+            flat_bucket_candle(100, 10, 0),
+            flat_bucket_candle(109, 6, 1),
+        ];
+        let plain = volume_profile(&candles, 10)?;
+        assert_eq!(plain.point_of_control, Decimal::new(1005, 1));
+
+        let decayed = volume_profile_with_decay(&candles, 10, Some(Decimal::new(5, 1)))?;
+        assert_eq!(decayed.total_volume, Decimal::from(11));
+        assert_eq!(decayed.point_of_control, Decimal::new(1095, 1));
+        Ok(())
+    }
+
+    #[test]
+    fn volume_profile_rejects_invalid_inputs() {
+        let candles = vec![flat_bucket_candle(100, 1, 0)];
+        assert!(volume_profile(&candles, 0).is_err());
+        assert!(volume_profile(&[], 4).is_err());
+        assert!(volume_profile_with_decay(&candles, 4, Some(Decimal::ZERO)).is_err());
+        assert!(volume_profile_with_decay(&candles, 4, Some(Decimal::TWO)).is_err());
+    }
+
     #[test]
     fn computes_slippage_from_mock_data() -> Result<()> {
         let dir = tempdir()?;
@@ -2,32 +2,230 @@ use std::collections::HashSet;
 use std::fs::File as StdFile;
 use std::io::{BufRead as StdBufRead, BufReader as StdBufReader, IsTerminal, Write};
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 use anyhow::{anyhow, Context, Result};
 use async_trait::async_trait;
 use chrono::{DateTime, Days, Duration as ChronoDuration, NaiveTime, Utc};
 use futures::StreamExt;
-use reqwest::{Client, StatusCode};
+use reqwest::{Client, ClientBuilder, Proxy, RequestBuilder, Response, StatusCode};
 use rust_decimal::Decimal;
 use serde::Deserialize;
 use serde_json::Value as JsonValue;
-use tesser_core::{Candle, Interval, Side, Symbol, Tick};
+use tesser_core::{Candle, CandleStatus, Interval, Side, Symbol, Tick};
 use tokio::fs::{self, OpenOptions};
 use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
 use tokio::task;
+pub use tokio_util::sync::CancellationToken;
 use tracing::{debug, info, warn};
 use zip::ZipArchive;
 
+use crate::transform::{missing_candle_ranges, CandleGap};
+
 const MAX_LIMIT: usize = 1000;
 const BYBIT_PUBLIC_BASE_URL: &str = "https://public.bybit.com/trading";
 const BINANCE_PUBLIC_BASE_URL: &str = "https://data.binance.vision/data/futures/um/daily/aggTrades";
 const NANOS_PER_SECOND: i64 = 1_000_000_000;
+/// Default upper bound for a single REST request issued by the downloaders.
+pub const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// HTTP settings shared by the REST and archive clients of a downloader.
+#[derive(Clone, Debug)]
+pub struct DownloaderConfig {
+    timeout: Duration,
+    proxy: Option<String>,
+    proxy_credentials: Option<(String, String)>,
+}
+
+impl Default for DownloaderConfig {
+    fn default() -> Self {
+        Self {
+            timeout: DEFAULT_REQUEST_TIMEOUT,
+            proxy: None,
+            proxy_credentials: None,
+        }
+    }
+}
+
+impl DownloaderConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Override the per-request timeout applied to REST calls.
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Route all downloader traffic through an HTTP(S) proxy (e.g. `http://proxy:3128`).
+    #[must_use]
+    pub fn with_proxy(mut self, url: impl Into<String>) -> Self {
+        self.proxy = Some(url.into());
+        self
+    }
+
+    /// Basic-auth credentials sent to the proxy configured via [`Self::with_proxy`].
+    #[must_use]
+    pub fn with_proxy_credentials(
+        mut self,
+        username: impl Into<String>,
+        password: impl Into<String>,
+    ) -> Self {
+        self.proxy_credentials = Some((username.into(), password.into()));
+        self
+    }
+
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    pub fn proxy_url(&self) -> Option<&str> {
+        self.proxy.as_deref()
+    }
+
+    fn build_clients(&self) -> Result<(Client, Client)> {
+        let client = self
+            .apply_proxy(Client::builder().timeout(self.timeout))?
+            .build()
+            .context("failed to build HTTP client")?;
+        // Archive files can take minutes to stream, so only bound connect and idle reads.
+        let archive_client = self
+            .apply_proxy(
+                Client::builder()
+                    .connect_timeout(self.timeout)
+                    .read_timeout(self.timeout),
+            )?
+            .build()
+            .context("failed to build archive HTTP client")?;
+        Ok((client, archive_client))
+    }
+
+    fn apply_proxy(&self, builder: ClientBuilder) -> Result<ClientBuilder> {
+        let Some(url) = &self.proxy else {
+            return Ok(builder);
+        };
+        let mut proxy =
+            Proxy::all(url.as_str()).with_context(|| format!("invalid proxy url '{url}'"))?;
+        if let Some((username, password)) = &self.proxy_credentials {
+            proxy = proxy.basic_auth(username, password);
+        }
+        Ok(builder.proxy(proxy))
+    }
+}
+
+/// Receives kline pages as they arrive so long ranges can be persisted incrementally.
+#[async_trait]
+pub trait KlineSink: Send {
+    async fn on_page(&mut self, candles: Vec<Candle>) -> Result<()>;
+
+    /// Open times of the candles in the page just delivered that validation repaired.
+    async fn on_repaired(&mut self, _timestamps: Vec<DateTime<Utc>>) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl KlineSink for Vec<Candle> {
+    async fn on_page(&mut self, candles: Vec<Candle>) -> Result<()> {
+        self.extend(candles);
+        Ok(())
+    }
+}
+
+/// Buffers a streamed range along with the candles repaired on the way.
+#[derive(Default)]
+struct CheckedKlines {
+    candles: Vec<Candle>,
+    repaired: Vec<DateTime<Utc>>,
+}
+
+#[async_trait]
+impl KlineSink for CheckedKlines {
+    async fn on_page(&mut self, candles: Vec<Candle>) -> Result<()> {
+        self.candles.extend(candles);
+        Ok(())
+    }
+
+    async fn on_repaired(&mut self, timestamps: Vec<DateTime<Utc>>) -> Result<()> {
+        self.repaired.extend(timestamps);
+        Ok(())
+    }
+}
+
+/// Receives trades as they are downloaded so long ranges can be persisted incrementally.
+#[async_trait]
+pub trait TradeSink: Send {
+    async fn on_trades(&mut self, trades: Vec<NormalizedTrade>) -> Result<()>;
+}
+
+#[async_trait]
+impl TradeSink for Vec<NormalizedTrade> {
+    async fn on_trades(&mut self, trades: Vec<NormalizedTrade>) -> Result<()> {
+        self.extend(trades);
+        Ok(())
+    }
+}
 
 #[async_trait]
 pub trait MarketDataDownloader {
-    async fn download_klines(&self, req: &KlineRequest<'_>) -> Result<Vec<Candle>>;
+    async fn download_klines(&self, req: &KlineRequest<'_>) -> Result<Vec<Candle>> {
+        Ok(self.download_klines_checked(req).await?.0)
+    }
+    /// Like [`Self::download_klines`], also returning the [`KlineReport`] produced when the
+    /// request has validation enabled (the report is empty otherwise).
+    async fn download_klines_checked(
+        &self,
+        req: &KlineRequest<'_>,
+    ) -> Result<(Vec<Candle>, KlineReport)> {
+        let mut sink = CheckedKlines::default();
+        self.stream_klines(req, &mut sink).await?;
+        let CheckedKlines {
+            mut candles,
+            repaired,
+        } = sink;
+        candles.sort_by_key(|c| c.timestamp);
+        candles.dedup_by_key(|c| c.timestamp);
+        let report = if req.validate {
+            let mut report = validate_klines(&mut candles, req.interval, req.start, req.end);
+            // Streaming already repaired these, so the validation pass no longer sees them.
+            report.repaired.extend(repaired);
+            report.repaired.sort();
+            report.repaired.dedup();
+            report.log(req.symbol);
+            report
+        } else {
+            KlineReport::default()
+        };
+        Ok((candles, report))
+    }
+    /// Fetch klines page by page, handing each page to `sink` before requesting the next.
+    async fn stream_klines(&self, req: &KlineRequest<'_>, sink: &mut dyn KlineSink) -> Result<()>;
     async fn download_trades(&self, req: &TradeRequest<'_>) -> Result<Vec<NormalizedTrade>>;
+    /// Fetch trades one UTC day at a time, handing each day to `sink` before requesting the
+    /// next, so memory stays bounded by a single day however long the range is.
+    async fn stream_trades(&self, req: &TradeRequest<'_>, sink: &mut dyn TradeSink) -> Result<()> {
+        if req.start >= req.end {
+            return Err(anyhow!("start must be earlier than end"));
+        }
+        let mut day_start = req.start;
+        while day_start < req.end {
+            let next_day = day_start
+                .date_naive()
+                .checked_add_days(Days::new(1))
+                .and_then(|date| date.and_hms_opt(0, 0, 0))
+                .ok_or_else(|| anyhow!("invalid day after {day_start}"))?;
+            let day_end = DateTime::<Utc>::from_naive_utc_and_offset(next_day, Utc).min(req.end);
+            let mut day = req.clone();
+            day.start = day_start;
+            day.end = day_end;
+            sink.on_trades(self.download_trades(&day).await?).await?;
+            day_start = day_end;
+        }
+        Ok(())
+    }
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -37,6 +235,20 @@ pub enum TradeSource {
     BinancePublicArchive,
 }
 
+/// Snapshot of an archive download in flight.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct DownloadProgress {
+    /// Bytes present in the cache file, including any resumed prefix.
+    pub downloaded: u64,
+    /// Final archive size when the server reported one.
+    pub total: Option<u64>,
+    /// Average transfer rate since the request started.
+    pub bytes_per_sec: u64,
+}
+
+/// Receives periodic [`DownloadProgress`] updates in place of the terminal progress bar.
+pub type DownloadProgressCallback = Arc<dyn Fn(DownloadProgress) + Send + Sync>;
+
 /// Parameters for a trade download request.
 #[derive(Clone)]
 pub struct TradeRequest<'a> {
@@ -49,6 +261,8 @@ pub struct TradeRequest<'a> {
     pub public_data_url: Option<&'a str>,
     pub archive_cache_dir: Option<PathBuf>,
     pub resume_archives: bool,
+    /// Progress sink for archive downloads; the TTY bar is drawn when unset.
+    pub progress: Option<DownloadProgressCallback>,
 }
 
 impl<'a> TradeRequest<'a> {
@@ -63,6 +277,7 @@ impl<'a> TradeRequest<'a> {
             public_data_url: None,
             archive_cache_dir: None,
             resume_archives: false,
+            progress: None,
         }
     }
 
@@ -101,6 +316,12 @@ impl<'a> TradeRequest<'a> {
         self.resume_archives = resume;
         self
     }
+
+    #[must_use]
+    pub fn with_progress(mut self, progress: DownloadProgressCallback) -> Self {
+        self.progress = Some(progress);
+        self
+    }
 }
 
 /// Normalized trade enriched with the exchange-provided identifier.
@@ -116,6 +337,95 @@ impl NormalizedTrade {
     }
 }
 
+/// Sorts `trades` chronologically and drops duplicates from overlapping pages.
+///
+/// Trades carrying an exchange id are deduplicated strictly by that id, so distinct trades
+/// sharing a timestamp, price, size and side are kept. Trades without an id fall back to
+/// comparing those fields against other id-less trades at the same instant.
+fn dedup_trades(trades: &mut Vec<NormalizedTrade>) {
+    trades.sort_by_key(|trade| trade.tick.exchange_timestamp);
+    let mut seen_ids = HashSet::new();
+    let mut kept: Vec<NormalizedTrade> = Vec::with_capacity(trades.len());
+    for trade in trades.drain(..) {
+        let duplicate = match trade.trade_id.as_deref() {
+            Some(id) => !seen_ids.insert(id.to_string()),
+            None => kept
+                .iter()
+                .rev()
+                .take_while(|other| other.tick.exchange_timestamp == trade.tick.exchange_timestamp)
+                .any(|other| {
+                    other.trade_id.is_none()
+                        && other.tick.price == trade.tick.price
+                        && other.tick.size == trade.tick.size
+                        && other.tick.side == trade.tick.side
+                }),
+        };
+        if !duplicate {
+            kept.push(trade);
+        }
+    }
+    *trades = kept;
+}
+
+/// Outcome of the validation pass run by [`validate_klines`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct KlineReport {
+    /// Open times of candles whose high/low were widened to cover the open and close.
+    pub repaired: Vec<DateTime<Utc>>,
+    /// Runs of bars missing from the expected interval grid.
+    pub gaps: Vec<CandleGap>,
+}
+
+impl KlineReport {
+    pub fn is_clean(&self) -> bool {
+        self.repaired.is_empty() && self.gaps.is_empty()
+    }
+
+    fn log(&self, symbol: &str) {
+        if !self.repaired.is_empty() {
+            warn!(
+                symbol,
+                count = self.repaired.len(),
+                "repaired klines with high/low outside the open/close range"
+            );
+        }
+        for gap in &self.gaps {
+            warn!(symbol, first = %gap.first, last = %gap.last, "kline gap in downloaded range");
+        }
+    }
+}
+
+/// Clamp `high`/`low` so they include the open and close, returning whether anything changed.
+pub fn repair_candle(candle: &mut Candle) -> bool {
+    let body_high = candle.open.max(candle.close);
+    let body_low = candle.open.min(candle.close);
+    let repaired = candle.high < body_high || candle.low > body_low;
+    candle.high = candle.high.max(body_high);
+    candle.low = candle.low.min(body_low);
+    repaired
+}
+
+/// Repair inconsistent candles in place and report gaps against the `interval` grid over
+/// `[start, end)`.
+///
+/// `candles` are expected to be sorted and deduplicated by timestamp.
+pub fn validate_klines(
+    candles: &mut [Candle],
+    interval: Interval,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> KlineReport {
+    let repaired = candles
+        .iter_mut()
+        .filter_map(|candle| repair_candle(candle).then_some(candle.timestamp))
+        .collect();
+    let present: Vec<_> = candles.iter().map(|candle| candle.timestamp).collect();
+    KlineReport {
+        repaired,
+        gaps: missing_candle_ranges(&present, interval, start, end),
+    }
+}
+
 /// Parameters for a kline download request.
 pub struct KlineRequest<'a> {
     pub category: &'a str,
@@ -124,6 +434,8 @@ pub struct KlineRequest<'a> {
     pub start: DateTime<Utc>,
     pub end: DateTime<Utc>,
     pub limit: usize,
+    /// Keep candles with a high/low outside the body, repair them and report gaps.
+    pub validate: bool,
 }
 
 impl<'a> KlineRequest<'a> {
@@ -141,22 +453,63 @@ impl<'a> KlineRequest<'a> {
             start,
             end,
             limit: MAX_LIMIT,
+            validate: false,
         }
     }
+
+    /// Repair inconsistent candles instead of dropping them, and report interval gaps.
+    pub fn with_validation(mut self, validate: bool) -> Self {
+        self.validate = validate;
+        self
+    }
 }
 
 /// Simple Bybit REST downloader for kline data.
 pub struct BybitDownloader {
     client: Client,
+    archive_client: Client,
     base_url: String,
+    cancel: CancellationToken,
+    config: DownloaderConfig,
 }
 
 impl BybitDownloader {
-    pub fn new(base_url: impl Into<String>) -> Self {
-        Self {
-            client: Client::new(),
+    /// Build a downloader with the default [`DownloaderConfig`].
+    ///
+    /// Fails when the HTTP clients cannot be constructed, rather than falling back to
+    /// clients without the configured timeout.
+    pub fn new(base_url: impl Into<String>) -> Result<Self> {
+        let config = DownloaderConfig::default();
+        let (client, archive_client) = config.build_clients()?;
+        Ok(Self {
+            client,
+            archive_client,
             base_url: base_url.into(),
-        }
+            cancel: CancellationToken::new(),
+            config,
+        })
+    }
+
+    /// Rebuild the HTTP clients using the supplied timeout and proxy settings.
+    pub fn with_config(mut self, config: DownloaderConfig) -> Result<Self> {
+        let (client, archive_client) = config.build_clients()?;
+        self.client = client;
+        self.archive_client = archive_client;
+        self.config = config;
+        Ok(self)
+    }
+
+    /// Override the per-request timeout applied to REST calls.
+    pub fn with_timeout(self, timeout: Duration) -> Result<Self> {
+        let config = self.config.clone().with_timeout(timeout);
+        self.with_config(config)
+    }
+
+    /// Attach a token that aborts pagination once cancelled.
+    #[must_use]
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancel = token;
+        self
     }
 
     fn endpoint(&self, path: &str) -> String {
@@ -169,38 +522,53 @@ impl BybitDownloader {
         <Self as MarketDataDownloader>::download_klines(self, req).await
     }
 
+    /// Stream klines into `sink` one page at a time instead of buffering the range.
+    pub async fn stream_klines(
+        &self,
+        req: &KlineRequest<'_>,
+        sink: &mut dyn KlineSink,
+    ) -> Result<()> {
+        <Self as MarketDataDownloader>::stream_klines(self, req, sink).await
+    }
+
     /// Download historical trades from Bybit within the requested range.
     pub async fn download_trades(&self, req: &TradeRequest<'_>) -> Result<Vec<NormalizedTrade>> {
         <Self as MarketDataDownloader>::download_trades(self, req).await
     }
+
+    /// Stream trades into `sink` one UTC day at a time instead of buffering the range.
+    pub async fn stream_trades(
+        &self,
+        req: &TradeRequest<'_>,
+        sink: &mut dyn TradeSink,
+    ) -> Result<()> {
+        <Self as MarketDataDownloader>::stream_trades(self, req, sink).await
+    }
 }
 
 #[async_trait]
 impl MarketDataDownloader for BybitDownloader {
-    async fn download_klines(&self, req: &KlineRequest<'_>) -> Result<Vec<Candle>> {
+    async fn stream_klines(&self, req: &KlineRequest<'_>, sink: &mut dyn KlineSink) -> Result<()> {
         let mut cursor = req.start.timestamp_millis();
         let end_ms = req.end.timestamp_millis();
         if cursor >= end_ms {
             return Err(anyhow!("start must be earlier than end"));
         }
 
-        let mut candles = Vec::new();
         let interval_ms = req.interval.as_duration().num_milliseconds();
 
         while cursor < end_ms {
+            ensure_not_cancelled(&self.cancel)?;
             let limit = req.limit.min(MAX_LIMIT).to_string();
-            let response = self
-                .client
-                .get(self.endpoint("v5/market/kline"))
-                .query(&[
-                    ("category", req.category),
-                    ("symbol", req.symbol),
-                    ("interval", req.interval.to_bybit()),
-                    ("start", &cursor.to_string()),
-                    ("end", &end_ms.to_string()),
-                    ("limit", &limit),
-                ])
-                .send()
+            let request = self.client.get(self.endpoint("v5/market/kline")).query(&[
+                ("category", req.category),
+                ("symbol", req.symbol),
+                ("interval", req.interval.to_bybit()),
+                ("start", &cursor.to_string()),
+                ("end", &end_ms.to_string()),
+                ("limit", &limit),
+            ]);
+            let response = send_request(&self.cancel, request)
                 .await
                 .context("request to Bybit failed")?;
 
@@ -247,11 +615,17 @@ impl MarketDataDownloader for BybitDownloader {
             }
 
             let mut batch = Vec::new();
+            let mut repaired = Vec::new();
             for entry in result.list {
-                if let Some(candle) = parse_entry(&entry, req.symbol, req.interval) {
+                if let Some(candle) = parse_entry(&entry, req.symbol, req.interval, req.validate) {
                     if candle.timestamp.timestamp_millis() >= cursor
                         && candle.timestamp.timestamp_millis() <= end_ms
                     {
+                        if req.validate
+                            && parse_entry(&entry, req.symbol, req.interval, false).is_none()
+                        {
+                            repaired.push(candle.timestamp);
+                        }
                         batch.push(candle);
                     }
                 }
@@ -276,12 +650,12 @@ impl MarketDataDownloader for BybitDownloader {
                 .last()
                 .map(|c| c.timestamp.timestamp_millis() + interval_ms)
                 .unwrap_or(end_ms);
-            candles.extend(batch);
+            sink.on_page(batch).await?;
+            if !repaired.is_empty() {
+                sink.on_repaired(repaired).await?;
+            }
         }
-
-        candles.sort_by_key(|c| c.timestamp);
-        candles.dedup_by_key(|c| c.timestamp);
-        Ok(candles)
+        Ok(())
     }
 
     async fn download_trades(&self, req: &TradeRequest<'_>) -> Result<Vec<NormalizedTrade>> {
@@ -309,6 +683,7 @@ impl BybitDownloader {
         let limit = req.limit.min(MAX_LIMIT);
 
         loop {
+            ensure_not_cancelled(&self.cancel)?;
             let mut params = Vec::with_capacity(6);
             if let Some(category) = req.category {
                 params.push(("category", category.to_string()));
@@ -323,11 +698,11 @@ impl BybitDownloader {
             let params_ref: Vec<(&str, &str)> =
                 params.iter().map(|(k, v)| (*k, v.as_str())).collect();
 
-            let response = self
+            let request = self
                 .client
                 .get(self.endpoint("v5/market/history-trade"))
-                .query(&params_ref)
-                .send()
+                .query(&params_ref);
+            let response = send_request(&self.cancel, request)
                 .await
                 .context("request to Bybit failed")?;
 
@@ -392,13 +767,7 @@ impl BybitDownloader {
             }
         }
 
-        trades.sort_by_key(|trade| trade.tick.exchange_timestamp);
-        trades.dedup_by(|a, b| {
-            a.tick.exchange_timestamp == b.tick.exchange_timestamp
-                && a.tick.price == b.tick.price
-                && a.tick.size == b.tick.size
-                && a.tick.side == b.tick.side
-        });
+        dedup_trades(&mut trades);
         Ok(trades)
     }
 
@@ -428,6 +797,7 @@ impl BybitDownloader {
         );
 
         while cursor_date <= end_date {
+            ensure_not_cancelled(&self.cancel)?;
             let next_date = cursor_date
                 .checked_add_days(Days::new(1))
                 .unwrap_or(cursor_date);
@@ -461,9 +831,15 @@ impl BybitDownloader {
                 cursor_date.format("%Y-%m-%d"),
                 symbol = req.symbol
             );
-            if download_archive_file(&self.client, &url, &cache_path, req.resume_archives)
-                .await?
-                .is_none()
+            if download_archive_file(
+                &self.archive_client,
+                &url,
+                &cache_path,
+                req.resume_archives,
+                req.progress.as_ref(),
+            )
+            .await?
+            .is_none()
             {
                 if next_date == cursor_date {
                     break;
@@ -487,18 +863,17 @@ impl BybitDownloader {
             cursor_date = next_date;
         }
 
-        trades.sort_by_key(|trade| trade.tick.exchange_timestamp);
-        trades.dedup_by(|a, b| {
-            a.tick.exchange_timestamp == b.tick.exchange_timestamp
-                && a.tick.price == b.tick.price
-                && a.tick.size == b.tick.size
-                && a.tick.side == b.tick.side
-        });
+        dedup_trades(&mut trades);
         Ok(trades)
     }
 }
 
-fn parse_entry(entry: &[String], symbol: &str, interval: Interval) -> Option<Candle> {
+fn parse_entry(
+    entry: &[String],
+    symbol: &str,
+    interval: Interval,
+    repairable: bool,
+) -> Option<Candle> {
     if entry.len() < 6 {
         return None;
     }
@@ -509,16 +884,35 @@ fn parse_entry(entry: &[String], symbol: &str, interval: Interval) -> Option<Can
     let low = entry.get(3)?.parse::<Decimal>().ok()?;
     let close = entry.get(4)?.parse::<Decimal>().ok()?;
     let volume = entry.get(5)?.parse::<Decimal>().ok()?;
-    Some(Candle {
-        symbol: Symbol::from(symbol),
-        interval,
-        open,
-        high,
-        low,
-        close,
-        volume,
-        timestamp,
-    })
+    checked_kline(
+        Candle {
+            symbol: Symbol::from(symbol),
+            interval,
+            open,
+            high,
+            low,
+            close,
+            volume,
+            timestamp,
+            status: CandleStatus::Closed,
+        },
+        repairable,
+    )
+}
+
+/// Drop invalid klines, repairing the ones [`repair_candle`] can fix when `repairable` is set.
+fn checked_kline(candle: Candle, repairable: bool) -> Option<Candle> {
+    let mut probe = candle.clone();
+    if repairable {
+        repair_candle(&mut probe);
+    }
+    match probe.validate() {
+        Ok(()) => Some(probe),
+        Err(err) => {
+            warn!(symbol = %candle.symbol, timestamp = %candle.timestamp, error = %err, "skipping invalid kline");
+            None
+        }
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -565,15 +959,49 @@ struct BybitTradeEntry {
 /// Simple Binance REST downloader for kline data.
 pub struct BinanceDownloader {
     client: Client,
+    archive_client: Client,
     base_url: String,
+    cancel: CancellationToken,
+    config: DownloaderConfig,
 }
 
 impl BinanceDownloader {
-    pub fn new(base_url: impl Into<String>) -> Self {
-        Self {
-            client: Client::new(),
+    /// Build a downloader with the default [`DownloaderConfig`].
+    ///
+    /// Fails when the HTTP clients cannot be constructed, rather than falling back to
+    /// clients without the configured timeout.
+    pub fn new(base_url: impl Into<String>) -> Result<Self> {
+        let config = DownloaderConfig::default();
+        let (client, archive_client) = config.build_clients()?;
+        Ok(Self {
+            client,
+            archive_client,
             base_url: base_url.into(),
-        }
+            cancel: CancellationToken::new(),
+            config,
+        })
+    }
+
+    /// Rebuild the HTTP clients using the supplied timeout and proxy settings.
+    pub fn with_config(mut self, config: DownloaderConfig) -> Result<Self> {
+        let (client, archive_client) = config.build_clients()?;
+        self.client = client;
+        self.archive_client = archive_client;
+        self.config = config;
+        Ok(self)
+    }
+
+    /// Override the per-request timeout applied to REST calls.
+    pub fn with_timeout(self, timeout: Duration) -> Result<Self> {
+        let config = self.config.clone().with_timeout(timeout);
+        self.with_config(config)
+    }
+
+    /// Attach a token that aborts pagination once cancelled.
+    #[must_use]
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancel = token;
+        self
     }
 
     fn endpoint(&self, path: &str) -> String {
@@ -585,6 +1013,15 @@ impl BinanceDownloader {
         <Self as MarketDataDownloader>::download_klines(self, req).await
     }
 
+    /// Stream klines into `sink` one page at a time instead of buffering the range.
+    pub async fn stream_klines(
+        &self,
+        req: &KlineRequest<'_>,
+        sink: &mut dyn KlineSink,
+    ) -> Result<()> {
+        <Self as MarketDataDownloader>::stream_klines(self, req, sink).await
+    }
+
     /// Download aggregated trades via Binance's `aggTrades` endpoint.
     pub async fn download_agg_trades(
         &self,
@@ -598,6 +1035,15 @@ impl BinanceDownloader {
         <Self as MarketDataDownloader>::download_trades(self, req).await
     }
 
+    /// Stream trades into `sink` one UTC day at a time instead of buffering the range.
+    pub async fn stream_trades(
+        &self,
+        req: &TradeRequest<'_>,
+        sink: &mut dyn TradeSink,
+    ) -> Result<()> {
+        <Self as MarketDataDownloader>::stream_trades(self, req, sink).await
+    }
+
     async fn fetch_agg_trades(&self, req: &TradeRequest<'_>) -> Result<Vec<NormalizedTrade>> {
         let mut cursor = req.start.timestamp_millis();
         let end_ms = req.end.timestamp_millis();
@@ -608,16 +1054,14 @@ impl BinanceDownloader {
         let mut trades = Vec::new();
         let mut seen_ids = HashSet::new();
         while cursor < end_ms {
-            let response = self
-                .client
-                .get(self.endpoint("fapi/v1/aggTrades"))
-                .query(&[
-                    ("symbol", req.symbol),
-                    ("startTime", &cursor.to_string()),
-                    ("endTime", &end_ms.to_string()),
-                    ("limit", &limit.to_string()),
-                ])
-                .send()
+            ensure_not_cancelled(&self.cancel)?;
+            let request = self.client.get(self.endpoint("fapi/v1/aggTrades")).query(&[
+                ("symbol", req.symbol),
+                ("startTime", &cursor.to_string()),
+                ("endTime", &end_ms.to_string()),
+                ("limit", &limit.to_string()),
+            ]);
+            let response = send_request(&self.cancel, request)
                 .await
                 .context("request to Binance failed")?;
             let status = response.status();
@@ -664,13 +1108,7 @@ impl BinanceDownloader {
                 break;
             }
         }
-        trades.sort_by_key(|trade| trade.tick.exchange_timestamp);
-        trades.dedup_by(|a, b| {
-            a.tick.exchange_timestamp == b.tick.exchange_timestamp
-                && a.tick.price == b.tick.price
-                && a.tick.size == b.tick.size
-                && a.tick.side == b.tick.side
-        });
+        dedup_trades(&mut trades);
         Ok(trades)
     }
 
@@ -689,6 +1127,7 @@ impl BinanceDownloader {
         let cache_root = resolve_archive_cache_dir(req, "binance", req.symbol);
 
         while cursor_date <= end_date {
+            ensure_not_cancelled(&self.cancel)?;
             let next_date = cursor_date
                 .checked_add_days(Days::new(1))
                 .unwrap_or(cursor_date);
@@ -721,9 +1160,15 @@ impl BinanceDownloader {
             );
             let cache_path = cache_root.join(&filename);
             let url = format!("{}/{symbol}/{filename}", base_url, symbol = req.symbol);
-            if download_archive_file(&self.client, &url, &cache_path, req.resume_archives)
-                .await?
-                .is_none()
+            if download_archive_file(
+                &self.archive_client,
+                &url,
+                &cache_path,
+                req.resume_archives,
+                req.progress.as_ref(),
+            )
+            .await?
+            .is_none()
             {
                 if next_date == cursor_date {
                     break;
@@ -753,39 +1198,30 @@ impl BinanceDownloader {
             cursor_date = next_date;
         }
 
-        trades.sort_by_key(|trade| trade.tick.exchange_timestamp);
-        trades.dedup_by(|a, b| {
-            a.tick.exchange_timestamp == b.tick.exchange_timestamp
-                && a.tick.price == b.tick.price
-                && a.tick.size == b.tick.size
-                && a.tick.side == b.tick.side
-        });
+        dedup_trades(&mut trades);
         Ok(trades)
     }
 }
 
 #[async_trait]
 impl MarketDataDownloader for BinanceDownloader {
-    async fn download_klines(&self, req: &KlineRequest<'_>) -> Result<Vec<Candle>> {
+    async fn stream_klines(&self, req: &KlineRequest<'_>, sink: &mut dyn KlineSink) -> Result<()> {
         let mut cursor = req.start.timestamp_millis();
         let end_ms = req.end.timestamp_millis();
         if cursor >= end_ms {
             return Err(anyhow!("start must be earlier than end"));
         }
-        let mut candles = Vec::new();
         let interval_ms = req.interval.as_duration().num_milliseconds();
         while cursor < end_ms {
-            let response = self
-                .client
-                .get(self.endpoint("fapi/v1/klines"))
-                .query(&[
-                    ("symbol", req.symbol),
-                    ("interval", req.interval.to_binance()),
-                    ("startTime", &cursor.to_string()),
-                    ("endTime", &end_ms.to_string()),
-                    ("limit", &req.limit.min(MAX_LIMIT).to_string()),
-                ])
-                .send()
+            ensure_not_cancelled(&self.cancel)?;
+            let request = self.client.get(self.endpoint("fapi/v1/klines")).query(&[
+                ("symbol", req.symbol),
+                ("interval", req.interval.to_binance()),
+                ("startTime", &cursor.to_string()),
+                ("endTime", &end_ms.to_string()),
+                ("limit", &req.limit.min(MAX_LIMIT).to_string()),
+            ]);
+            let response = send_request(&self.cancel, request)
                 .await
                 .context("request to Binance failed")?;
             let status = response.status();
@@ -816,11 +1252,20 @@ impl MarketDataDownloader for BinanceDownloader {
                 break;
             }
             let mut batch = Vec::new();
+            let mut repaired = Vec::new();
             for entry in entries {
-                if let Some(candle) = parse_binance_entry(&entry, req.symbol, req.interval) {
+                if let Some(candle) =
+                    parse_binance_entry(&entry, req.symbol, req.interval, req.validate)
+                {
                     if candle.timestamp.timestamp_millis() >= cursor
                         && candle.timestamp.timestamp_millis() <= end_ms
                     {
+                        if req.validate
+                            && parse_binance_entry(&entry, req.symbol, req.interval, false)
+                                .is_none()
+                        {
+                            repaired.push(candle.timestamp);
+                        }
                         batch.push(candle);
                     }
                 }
@@ -833,11 +1278,12 @@ impl MarketDataDownloader for BinanceDownloader {
                 .last()
                 .map(|c| c.timestamp.timestamp_millis() + interval_ms)
                 .unwrap_or(end_ms);
-            candles.extend(batch);
+            sink.on_page(batch).await?;
+            if !repaired.is_empty() {
+                sink.on_repaired(repaired).await?;
+            }
         }
-        candles.sort_by_key(|c| c.timestamp);
-        candles.dedup_by_key(|c| c.timestamp);
-        Ok(candles)
+        Ok(())
     }
 
     async fn download_trades(&self, req: &TradeRequest<'_>) -> Result<Vec<NormalizedTrade>> {
@@ -851,7 +1297,12 @@ impl MarketDataDownloader for BinanceDownloader {
     }
 }
 
-fn parse_binance_entry(entry: &[JsonValue], symbol: &str, interval: Interval) -> Option<Candle> {
+fn parse_binance_entry(
+    entry: &[JsonValue],
+    symbol: &str,
+    interval: Interval,
+    repairable: bool,
+) -> Option<Candle> {
     if entry.len() < 6 {
         return None;
     }
@@ -862,16 +1313,20 @@ fn parse_binance_entry(entry: &[JsonValue], symbol: &str, interval: Interval) ->
     let low = entry.get(3)?.as_str()?.parse::<Decimal>().ok()?;
     let close = entry.get(4)?.as_str()?.parse::<Decimal>().ok()?;
     let volume = entry.get(5)?.as_str()?.parse::<Decimal>().ok()?;
-    Some(Candle {
-        symbol: Symbol::from(symbol),
-        interval,
-        open,
-        high,
-        low,
-        close,
-        volume,
-        timestamp,
-    })
+    checked_kline(
+        Candle {
+            symbol: Symbol::from(symbol),
+            interval,
+            open,
+            high,
+            low,
+            close,
+            volume,
+            timestamp,
+            status: CandleStatus::Closed,
+        },
+        repairable,
+    )
 }
 
 #[derive(Debug, Deserialize)]
@@ -896,7 +1351,11 @@ fn parse_bybit_public_line(symbol: &str, line: &str) -> Option<NormalizedTrade>
     let size = columns.next()?.trim().parse::<Decimal>().ok()?;
     let price = columns.next()?.trim().parse::<Decimal>().ok()?;
     columns.next()?; // tickDirection
-    let trade_id = columns.next().map(|value| value.trim().to_string());
+    let trade_id = columns
+        .next()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string);
 
     let tick = Tick {
         symbol: Symbol::from(symbol),
@@ -1002,6 +1461,20 @@ fn parse_side(value: &str) -> Option<Side> {
     }
 }
 
+fn ensure_not_cancelled(cancel: &CancellationToken) -> Result<()> {
+    if cancel.is_cancelled() {
+        return Err(anyhow!("download cancelled"));
+    }
+    Ok(())
+}
+
+async fn send_request(cancel: &CancellationToken, request: RequestBuilder) -> Result<Response> {
+    tokio::select! {
+        _ = cancel.cancelled() => Err(anyhow!("download cancelled")),
+        response = request.send() => response.map_err(Into::into),
+    }
+}
+
 fn truncate(body: &str, max: usize) -> String {
     if body.len() <= max {
         body.to_string()
@@ -1069,6 +1542,7 @@ async fn download_archive_file(
     url: &str,
     cache_path: &Path,
     resume: bool,
+    progress: Option<&DownloadProgressCallback>,
 ) -> Result<Option<()>> {
     if let Some(parent) = cache_path.parent() {
         fs::create_dir_all(parent)
@@ -1166,7 +1640,8 @@ async fn download_archive_file(
         response.content_length()
     };
 
-    let show_progress = std::io::stderr().is_terminal();
+    let show_progress = progress.is_none() && std::io::stderr().is_terminal();
+    let reporting = show_progress || progress.is_some();
     let label = cache_path
         .file_name()
         .and_then(|name| name.to_str())
@@ -1192,7 +1667,7 @@ async fn download_archive_file(
     };
 
     let mut render_progress = |downloaded: u64, done: bool| {
-        if !show_progress {
+        if !reporting {
             return;
         }
         let elapsed = started_at.elapsed();
@@ -1202,6 +1677,14 @@ async fn download_archive_file(
         } else {
             0
         };
+        if let Some(callback) = progress {
+            callback(DownloadProgress {
+                downloaded,
+                total: total_bytes,
+                bytes_per_sec,
+            });
+            return;
+        }
         let speed = format!("{}/s", format_bytes(bytes_per_sec));
         let line = if let Some(total) = total_bytes {
             let pct = if total > 0 {
@@ -1258,7 +1741,7 @@ async fn download_archive_file(
         let bytes = chunk.context("failed to read archive chunk")?;
         file.write_all(&bytes).await?;
         downloaded = downloaded.saturating_add(bytes.len() as u64);
-        if show_progress && last_render.elapsed() >= Duration::from_millis(250) {
+        if reporting && last_render.elapsed() >= Duration::from_millis(250) {
             render_progress(downloaded, false);
             last_render = Instant::now();
         }
@@ -1338,9 +1821,32 @@ mod tests {
     use std::sync::Arc;
 
     use super::*;
+    use crate::etl::{
+        download_universe, fill_lake_gaps, lake_candle_timestamps, CandleLakeWriter, DownloadJob,
+        Partitioning, UniverseProgress,
+    };
+    use crate::transform::missing_candle_ranges;
     use tokio::io::{AsyncReadExt, AsyncWriteExt};
     use tokio::net::TcpListener;
 
+    #[test]
+    fn kline_parsers_skip_inconsistent_rows() {
+        let row = |high: &str| -> Vec<String> {
+            ["1700000000000", "100", high, "95", "102", "10"]
+                .iter()
+                .map(|value| value.to_string())
+                .collect()
+        };
+        assert!(parse_entry(&row("105"), "BTCUSDT", Interval::OneMinute, false).is_some());
+        assert!(parse_entry(&row("101"), "BTCUSDT", Interval::OneMinute, false).is_none());
+        let kept = parse_entry(&row("101"), "BTCUSDT", Interval::OneMinute, true).unwrap();
+        assert_eq!(kept.high, Decimal::from(101));
+
+        let binance: Vec<JsonValue> =
+            serde_json::from_str(r#"[1700000000000, "100", "105", "103", "102", "10"]"#).unwrap();
+        assert!(parse_binance_entry(&binance, "BTCUSDT", Interval::OneMinute, false).is_none());
+    }
+
     #[test]
     fn parses_public_trade_line() {
         let line = "1585180700.0647,BTCUSDT,Buy,0.042,6698.5,PlusTick,08ff9568-cb50-55d6-b497-13727eec09dc,28133700000.0,0.042,281.337";
@@ -1353,6 +1859,10 @@ mod tests {
         assert_eq!(trade.tick.side, Side::Buy);
         assert_eq!(trade.tick.price, Decimal::from_str("6698.5").unwrap());
         assert_eq!(trade.tick.size, Decimal::from_str("0.042").unwrap());
+
+        let blank_id = "1585180700.0647,BTCUSDT,Buy,0.042,6698.5,PlusTick, ,28133700000.0";
+        let trade = parse_bybit_public_line("BTCUSDT", blank_id).expect("trade");
+        assert_eq!(trade.trade_id, None);
     }
 
     #[test]
@@ -1371,6 +1881,54 @@ mod tests {
         assert_eq!(trade.tick.side, Side::Sell);
     }
 
+    fn trade(id: Option<&str>, millis: i64, price: i64) -> NormalizedTrade {
+        let timestamp = DateTime::<Utc>::from_timestamp_millis(millis).unwrap();
+        NormalizedTrade::new(
+            Tick {
+                symbol: Symbol::from("BTCUSDT"),
+                price: Decimal::from(price),
+                size: Decimal::ONE,
+                side: Side::Buy,
+                exchange_timestamp: timestamp,
+                received_at: timestamp,
+            },
+            id.map(str::to_string),
+        )
+    }
+
+    #[test]
+    fn dedup_keeps_distinct_ids_at_the_same_instant_and_price() {
+        let mut trades = vec![
+            trade(Some("b"), 1_000, 100),
+            trade(Some("a"), 1_000, 100),
+            // Overlapping page repeats trade "a".
+            trade(Some("a"), 1_000, 100),
+        ];
+        dedup_trades(&mut trades);
+        let mut ids: Vec<_> = trades
+            .iter()
+            .filter_map(|trade| trade.trade_id.clone())
+            .collect();
+        ids.sort();
+        assert_eq!(ids, vec!["a".to_string(), "b".to_string()]);
+    }
+
+    #[test]
+    fn dedup_falls_back_to_payload_without_ids() {
+        let mut trades = vec![
+            trade(None, 2_000, 100),
+            trade(None, 1_000, 100),
+            trade(None, 1_000, 100),
+            trade(None, 1_000, 101),
+            trade(Some("x"), 1_000, 100),
+        ];
+        dedup_trades(&mut trades);
+        assert_eq!(trades.len(), 4);
+        assert!(trades
+            .windows(2)
+            .all(|pair| pair[0].tick.exchange_timestamp <= pair[1].tick.exchange_timestamp));
+    }
+
     async fn serve_body(listener: TcpListener, body: Arc<Vec<u8>>, honor_range: bool, max: usize) {
         for _ in 0..max {
             let (mut socket, _) = listener.accept().await.expect("accept");
@@ -1455,7 +2013,7 @@ mod tests {
 
         let client = Client::new();
         let url = format!("http://{}/archive.bin", addr);
-        download_archive_file(&client, &url, &cache_path, true)
+        download_archive_file(&client, &url, &cache_path, true, None)
             .await
             .unwrap()
             .expect("downloaded");
@@ -1480,7 +2038,7 @@ mod tests {
 
         let client = Client::new();
         let url = format!("http://{}/archive.bin", addr);
-        download_archive_file(&client, &url, &cache_path, true)
+        download_archive_file(&client, &url, &cache_path, true, None)
             .await
             .unwrap()
             .expect("downloaded");
@@ -1492,6 +2050,290 @@ mod tests {
         handle.await.unwrap();
     }
 
+    #[tokio::test]
+    async fn archive_download_reports_progress_to_callback() {
+        let body: Vec<u8> = (0..3072).map(|i| (i % 251) as u8).collect();
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let served = body.clone();
+        let handle = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.expect("accept");
+            read_request_head(&mut socket).await;
+            let head = format!(
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                served.len()
+            );
+            socket.write_all(head.as_bytes()).await.expect("write");
+            for chunk in served.chunks(1024) {
+                socket.write_all(chunk).await.expect("write chunk");
+                socket.flush().await.expect("flush");
+                tokio::time::sleep(Duration::from_millis(300)).await;
+            }
+        });
+
+        let updates = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = updates.clone();
+        let callback: DownloadProgressCallback = Arc::new(move |progress: DownloadProgress| {
+            sink.lock().unwrap().push(progress);
+        });
+
+        let dir = tempfile::tempdir().unwrap();
+        let cache_path = dir.path().join("archive.bin");
+        let client = Client::new();
+        let url = format!("http://{}/archive.bin", addr);
+        download_archive_file(&client, &url, &cache_path, false, Some(&callback))
+            .await
+            .unwrap()
+            .expect("downloaded");
+        handle.await.unwrap();
+
+        let updates = updates.lock().unwrap();
+        assert!(updates.len() >= 3, "expected periodic updates: {updates:?}");
+        assert!(updates
+            .windows(2)
+            .all(|pair| pair[0].downloaded <= pair[1].downloaded));
+        assert!(updates
+            .iter()
+            .any(|update| update.downloaded > 0 && update.downloaded < body.len() as u64));
+        let last = updates.last().unwrap();
+        assert_eq!(last.downloaded, body.len() as u64);
+        assert_eq!(last.total, Some(body.len() as u64));
+    }
+
+    async fn read_request_head(socket: &mut tokio::net::TcpStream) -> String {
+        let mut buf = Vec::new();
+        let mut tmp = [0u8; 1024];
+        loop {
+            let n = socket.read(&mut tmp).await.expect("read");
+            if n == 0 {
+                break;
+            }
+            buf.extend_from_slice(&tmp[..n]);
+            if buf.windows(4).any(|w| w == b"\r\n\r\n") {
+                break;
+            }
+        }
+        String::from_utf8_lossy(&buf).into_owned()
+    }
+
+    fn kline_window() -> (DateTime<Utc>, DateTime<Utc>) {
+        let start = DateTime::<Utc>::from_timestamp_millis(1_700_000_000_000).unwrap();
+        (start, start + ChronoDuration::hours(1))
+    }
+
+    #[tokio::test]
+    async fn kline_request_times_out_against_slow_server() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.expect("accept");
+            read_request_head(&mut socket).await;
+            tokio::time::sleep(Duration::from_secs(10)).await;
+        });
+
+        let downloader = BinanceDownloader::new(format!("http://{addr}"))
+            .unwrap()
+            .with_timeout(Duration::from_millis(200))
+            .unwrap();
+        let (start, end) = kline_window();
+        let request = KlineRequest::new("", "BTCUSDT", Interval::OneMinute, start, end);
+        let started = Instant::now();
+        let result = downloader.download_klines(&request).await;
+        assert!(result.is_err());
+        assert!(started.elapsed() < Duration::from_secs(5));
+        server.abort();
+    }
+
+    #[tokio::test]
+    async fn kline_requests_route_through_configured_proxy() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let proxy = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.expect("accept");
+            let head = read_request_head(&mut socket).await;
+            let response =
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: 2\r\nConnection: close\r\n\r\n[]";
+            socket.write_all(response.as_bytes()).await.expect("write");
+            head
+        });
+
+        let config = DownloaderConfig::new()
+            .with_proxy(format!("http://{addr}"))
+            .with_proxy_credentials("user", "pass");
+        assert_eq!(config.proxy_url(), Some(format!("http://{addr}").as_str()));
+        let downloader = BinanceDownloader::new("http://exchange.invalid")
+            .unwrap()
+            .with_config(config)
+            .unwrap();
+        let (start, end) = kline_window();
+        let request = KlineRequest::new("", "BTCUSDT", Interval::OneMinute, start, end);
+        let candles = downloader.download_klines(&request).await.unwrap();
+        assert!(candles.is_empty());
+
+        let head = proxy.await.unwrap().to_ascii_lowercase();
+        assert!(head.starts_with("get http://exchange.invalid/"));
+        // base64("user:pass")
+        assert!(head.contains("proxy-authorization: basic dxnlcjpwyxnz"));
+    }
+
+    #[test]
+    fn invalid_proxy_url_is_rejected() {
+        let config = DownloaderConfig::new().with_proxy("not a url");
+        assert!(BybitDownloader::new("http://localhost")
+            .unwrap()
+            .with_config(config)
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn cancellation_stops_pagination_between_pages() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let token = CancellationToken::new();
+        let server_token = token.clone();
+        let served = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let served_counter = served.clone();
+        let server = tokio::spawn(async move {
+            loop {
+                let (mut socket, _) = listener.accept().await.expect("accept");
+                let head = read_request_head(&mut socket).await;
+                let start_ms: i64 = head
+                    .split(['?', '&', ' '])
+                    .find_map(|part| part.strip_prefix("startTime="))
+                    .and_then(|value| value.parse().ok())
+                    .expect("startTime");
+                let body = format!(r#"[[{start_ms},"1","1","1","1","1"]]"#);
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                socket.write_all(response.as_bytes()).await.expect("write");
+                served_counter.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                server_token.cancel();
+            }
+        });
+
+        let downloader = BinanceDownloader::new(format!("http://{addr}"))
+            .unwrap()
+            .with_cancellation(token);
+        let (start, end) = kline_window();
+        let request = KlineRequest::new("", "BTCUSDT", Interval::OneMinute, start, end);
+        let err = downloader
+            .download_klines(&request)
+            .await
+            .expect_err("cancelled download");
+        assert!(format!("{err:#}").contains("cancelled"));
+        assert_eq!(served.load(std::sync::atomic::Ordering::SeqCst), 1);
+        server.abort();
+    }
+
+    #[tokio::test]
+    async fn validation_repairs_inconsistent_klines_and_reports_gaps() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let start = DateTime::<Utc>::from_timestamp_millis(1_700_000_040_000).unwrap();
+        let minute = |n: i64| start + ChronoDuration::minutes(n);
+        let rows = [
+            (minute(0), "100", "101"),
+            // High below the close.
+            (minute(1), "101", "102"),
+            (minute(3), "103", "104"),
+            (minute(4), "104", "105"),
+        ];
+        let server = tokio::spawn(async move {
+            loop {
+                let (mut socket, _) = listener.accept().await.expect("accept");
+                let head = read_request_head(&mut socket).await;
+                let start_ms: i64 = head
+                    .split(['?', '&', ' '])
+                    .find_map(|part| part.strip_prefix("startTime="))
+                    .and_then(|value| value.parse().ok())
+                    .expect("startTime");
+                let entries: Vec<String> = rows
+                    .iter()
+                    .filter(|(ts, _, _)| ts.timestamp_millis() >= start_ms)
+                    .map(|(ts, high, close)| {
+                        format!(
+                            r#"[{},"100","{high}","99","{close}","1"]"#,
+                            ts.timestamp_millis()
+                        )
+                    })
+                    .collect();
+                let body = format!("[{}]", entries.join(","));
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                socket.write_all(response.as_bytes()).await.expect("write");
+            }
+        });
+
+        let downloader = BinanceDownloader::new(format!("http://{addr}")).unwrap();
+        let request = KlineRequest::new("", "BTCUSDT", Interval::OneMinute, start, minute(5))
+            .with_validation(true);
+        let (candles, report) = downloader.download_klines_checked(&request).await.unwrap();
+        assert_eq!(candles.len(), 4);
+        assert_eq!(candles[1].high, Decimal::from(102));
+        assert!(candles.iter().all(|candle| candle.validate().is_ok()));
+        assert_eq!(report.repaired, vec![minute(1)]);
+        assert_eq!(
+            report.gaps,
+            vec![CandleGap {
+                first: minute(2),
+                last: minute(2),
+            }]
+        );
+
+        // Without validation the inconsistent candle is dropped as before.
+        let request = KlineRequest::new("", "BTCUSDT", Interval::OneMinute, start, minute(5));
+        let (candles, report) = downloader.download_klines_checked(&request).await.unwrap();
+        assert_eq!(candles.len(), 3);
+        assert!(report.is_clean());
+
+        // Streaming with validation hands the sink the repaired candle, not the raw one.
+        let request = request.with_validation(true);
+        let mut streamed = Vec::new();
+        downloader
+            .stream_klines(&request, &mut streamed)
+            .await
+            .unwrap();
+        assert_eq!(streamed.len(), 4);
+        assert_eq!(streamed[1].high, Decimal::from(102));
+        assert!(streamed.iter().all(|candle| candle.validate().is_ok()));
+        server.abort();
+    }
+
+    #[tokio::test]
+    async fn cancellation_interrupts_in_flight_request() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.expect("accept");
+            read_request_head(&mut socket).await;
+            tokio::time::sleep(Duration::from_secs(10)).await;
+        });
+
+        let token = CancellationToken::new();
+        let downloader = BybitDownloader::new(format!("http://{addr}"))
+            .unwrap()
+            .with_cancellation(token.clone());
+        let (start, end) = kline_window();
+        let request = KlineRequest::new("linear", "BTCUSDT", Interval::OneMinute, start, end);
+        let canceller = tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            token.cancel();
+        });
+        let started = Instant::now();
+        let err = downloader
+            .download_klines(&request)
+            .await
+            .expect_err("cancelled download");
+        assert!(format!("{err:#}").contains("cancelled"));
+        assert!(started.elapsed() < Duration::from_secs(5));
+        canceller.await.unwrap();
+        server.abort();
+    }
+
     #[test]
     fn archive_day_span_treats_midnight_end_as_exclusive() {
         let start = DateTime::<Utc>::from_naive_utc_and_offset(
@@ -1520,4 +2362,313 @@ mod tests {
             chrono::NaiveDate::from_ymd_opt(2021, 8, 27).unwrap()
         );
     }
+
+    /// Serves one trade at the start of every requested range and records the ranges.
+    struct DailyTrades {
+        requests: std::sync::Mutex<Vec<(DateTime<Utc>, DateTime<Utc>)>>,
+    }
+
+    #[async_trait]
+    impl MarketDataDownloader for DailyTrades {
+        async fn stream_klines(
+            &self,
+            _req: &KlineRequest<'_>,
+            _sink: &mut dyn KlineSink,
+        ) -> Result<()> {
+            unreachable!("klines are not requested")
+        }
+
+        async fn download_trades(&self, req: &TradeRequest<'_>) -> Result<Vec<NormalizedTrade>> {
+            self.requests.lock().unwrap().push((req.start, req.end));
+            let tick = Tick {
+                symbol: Symbol::from(req.symbol),
+                price: Decimal::ONE,
+                size: Decimal::ONE,
+                side: Side::Buy,
+                exchange_timestamp: req.start,
+                received_at: req.start,
+            };
+            Ok(vec![NormalizedTrade::new(tick, None)])
+        }
+    }
+
+    /// Counts the trades delivered per call alongside the requests issued so far.
+    struct RecordingTradeSink<'a> {
+        source: &'a DailyTrades,
+        deliveries: Vec<(usize, usize)>,
+    }
+
+    #[async_trait]
+    impl TradeSink for RecordingTradeSink<'_> {
+        async fn on_trades(&mut self, trades: Vec<NormalizedTrade>) -> Result<()> {
+            let requested = self.source.requests.lock().unwrap().len();
+            self.deliveries.push((trades.len(), requested));
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn stream_trades_hands_over_one_day_at_a_time() {
+        let source = DailyTrades {
+            requests: std::sync::Mutex::new(Vec::new()),
+        };
+        let start = DateTime::<Utc>::from_timestamp(1_704_110_400, 0).unwrap();
+        let end = start + ChronoDuration::days(2);
+        let request = TradeRequest::new("BTCUSDT", start, end);
+        let mut sink = RecordingTradeSink {
+            source: &source,
+            deliveries: Vec::new(),
+        };
+        source.stream_trades(&request, &mut sink).await.unwrap();
+
+        // Each day is handed over before the next one is requested.
+        assert_eq!(sink.deliveries, vec![(1, 1), (1, 2), (1, 3)]);
+        let midnight = |days| {
+            DateTime::<Utc>::from_timestamp(1_704_067_200, 0).unwrap() + ChronoDuration::days(days)
+        };
+        assert_eq!(
+            *source.requests.lock().unwrap(),
+            vec![
+                (start, midnight(1)),
+                (midnight(1), midnight(2)),
+                (midnight(2), end),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn stream_klines_writes_partitions_page_by_page() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let lake = tempfile::tempdir().unwrap();
+        let day_one = lake
+            .path()
+            .join("symbol=BTCUSDT/interval=1h/year=2024/month=01/day=01/part-00000.parquet");
+        let probe = day_one.clone();
+        let server = tokio::spawn(async move {
+            let mut day_one_seen = Vec::new();
+            for _ in 0..5 {
+                let (mut socket, _) = listener.accept().await.expect("accept");
+                day_one_seen.push(probe.exists());
+                let head = read_request_head(&mut socket).await;
+                let param = |name: &str| -> i64 {
+                    head.split(['?', '&', ' '])
+                        .find_map(|part| part.strip_prefix(name))
+                        .and_then(|value| value.parse().ok())
+                        .expect("query parameter")
+                };
+                let (start_ms, end_ms) = (param("startTime="), param("endTime="));
+                // Serve at most two hourly candles per page to force pagination.
+                let rows: Vec<String> = (0..2)
+                    .map(|idx| start_ms + idx * 3_600_000)
+                    .filter(|ts| *ts <= end_ms)
+                    .map(|ts| format!(r#"[{ts},"1","1","1","1","1"]"#))
+                    .collect();
+                let body = format!("[{}]", rows.join(","));
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                socket.write_all(response.as_bytes()).await.expect("write");
+            }
+            day_one_seen
+        });
+
+        let downloader = BinanceDownloader::new(format!("http://{addr}")).unwrap();
+        let start = DateTime::<Utc>::from_timestamp_millis(1_704_139_200_000).unwrap();
+        // The last page stops half an hour past 04:00, so it carries only that candle.
+        let end = start + ChronoDuration::hours(8) + ChronoDuration::minutes(30);
+        let request = KlineRequest::new("", "BTCUSDT", Interval::OneHour, start, end);
+        let mut writer = CandleLakeWriter::new(lake.path(), Partitioning::Daily);
+        downloader
+            .stream_klines(&request, &mut writer)
+            .await
+            .unwrap();
+        assert!(writer.buffered_rows() < 9);
+        assert_eq!(writer.finish().unwrap(), 9);
+
+        // The first day is flushed as soon as a page crosses midnight, before later pages arrive.
+        let day_one_seen = server.await.unwrap();
+        assert_eq!(day_one_seen, vec![false, false, false, true, true]);
+        let day_two = lake
+            .path()
+            .join("symbol=BTCUSDT/interval=1h/year=2024/month=01/day=02/part-00000.parquet");
+        assert!(day_one.exists());
+        assert!(day_two.exists());
+    }
+
+    #[tokio::test]
+    async fn fill_lake_gaps_fetches_only_the_missing_day() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let lake = tempfile::tempdir().unwrap();
+        let start = DateTime::<Utc>::from_timestamp_millis(1_704_067_200_000).unwrap();
+        let end = start + ChronoDuration::days(3);
+        let hourly = |day: i64| {
+            (0..24).map(move |hour| Candle {
+                symbol: Symbol::from("BTCUSDT"),
+                interval: Interval::OneHour,
+                open: Decimal::ONE,
+                high: Decimal::ONE,
+                low: Decimal::ONE,
+                close: Decimal::ONE,
+                volume: Decimal::ONE,
+                timestamp: start + ChronoDuration::days(day) + ChronoDuration::hours(hour),
+                status: CandleStatus::Closed,
+            })
+        };
+        let mut writer = CandleLakeWriter::new(lake.path(), Partitioning::Daily);
+        for candle in hourly(0).chain(hourly(2)) {
+            writer.push(&candle).unwrap();
+        }
+        assert_eq!(writer.finish().unwrap(), 48);
+
+        let requests = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let seen = requests.clone();
+        let server = tokio::spawn(async move {
+            loop {
+                let (mut socket, _) = listener.accept().await.expect("accept");
+                let head = read_request_head(&mut socket).await;
+                let param = |name: &str| -> i64 {
+                    head.split(['?', '&', ' '])
+                        .find_map(|part| part.strip_prefix(name))
+                        .and_then(|value| value.parse().ok())
+                        .expect("query parameter")
+                };
+                let (start_ms, end_ms) = (param("startTime="), param("endTime="));
+                seen.lock().unwrap().push((start_ms, end_ms));
+                let rows: Vec<String> = (0..)
+                    .map(|idx| start_ms + idx * 3_600_000)
+                    .take_while(|ts| *ts <= end_ms)
+                    .map(|ts| format!(r#"[{ts},"1","1","1","1","1"]"#))
+                    .collect();
+                let body = format!("[{}]", rows.join(","));
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                socket.write_all(response.as_bytes()).await.expect("write");
+            }
+        });
+
+        let downloader = BinanceDownloader::new(format!("http://{addr}")).unwrap();
+        let request = KlineRequest::new("", "BTCUSDT", Interval::OneHour, start, end);
+        let outcome = fill_lake_gaps(&downloader, lake.path(), &request, Partitioning::Daily)
+            .await
+            .unwrap();
+        server.abort();
+
+        let day_two = start + ChronoDuration::days(1);
+        assert_eq!(outcome.gaps.len(), 1);
+        assert_eq!(outcome.gaps[0].first, day_two);
+        assert_eq!(outcome.gaps[0].last, day_two + ChronoDuration::hours(23));
+        assert_eq!(outcome.rows_written, 24);
+        let requests = requests.lock().unwrap().clone();
+        assert_eq!(requests.len(), 1);
+        let (requested_start, requested_end) = requests[0];
+        assert_eq!(requested_start, day_two.timestamp_millis());
+        assert!(requested_end < (day_two + ChronoDuration::days(1)).timestamp_millis());
+
+        let present = lake_candle_timestamps(lake.path(), "BTCUSDT", Interval::OneHour).unwrap();
+        assert_eq!(present.len(), 72);
+        assert!(missing_candle_ranges(&present, Interval::OneHour, start, end).is_empty());
+    }
+
+    #[tokio::test]
+    async fn download_universe_isolates_a_failing_symbol() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let lake = tempfile::tempdir().unwrap();
+        let start = DateTime::<Utc>::from_timestamp_millis(1_704_067_200_000).unwrap();
+        let end = start + ChronoDuration::hours(6);
+        let server = tokio::spawn(async move {
+            loop {
+                let (mut socket, _) = listener.accept().await.expect("accept");
+                tokio::spawn(async move {
+                    let head = read_request_head(&mut socket).await;
+                    let param = |name: &str| -> String {
+                        head.split(['?', '&', ' '])
+                            .find_map(|part| part.strip_prefix(name))
+                            .expect("query parameter")
+                            .to_string()
+                    };
+                    let response = if param("symbol=") == "DELISTED" {
+                        let body = r#"{"code":-1121,"msg":"Invalid symbol."}"#;
+                        format!(
+                            "HTTP/1.1 400 Bad Request\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                            body.len()
+                        )
+                    } else {
+                        let start_ms: i64 = param("startTime=").parse().unwrap();
+                        let end_ms: i64 = param("endTime=").parse().unwrap();
+                        let rows: Vec<String> = (0..)
+                            .map(|idx| start_ms + idx * 3_600_000)
+                            .take_while(|ts| *ts < end_ms)
+                            .map(|ts| format!(r#"[{ts},"1","1","1","1","1"]"#))
+                            .collect();
+                        let body = format!("[{}]", rows.join(","));
+                        format!(
+                            "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                            body.len()
+                        )
+                    };
+                    socket.write_all(response.as_bytes()).await.expect("write");
+                });
+            }
+        });
+
+        let snapshots = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let seen = snapshots.clone();
+        let limiter = tesser_broker::RateLimiter::direct(tesser_broker::Quota::per_second(
+            std::num::NonZeroU32::new(100).unwrap(),
+        ));
+        let job = DownloadJob::new(
+            "",
+            ["BTCUSDT", "DELISTED", "ETHUSDT", "SOLUSDT"],
+            Interval::OneHour,
+            start,
+            end,
+        )
+        .with_concurrency(3)
+        .with_rate_limiter(limiter)
+        .with_progress(Arc::new(move |progress| {
+            seen.lock().unwrap().push(progress)
+        }));
+        let downloader = BinanceDownloader::new(format!("http://{addr}")).unwrap();
+        let summary = download_universe(&downloader, lake.path(), &job).await;
+        server.abort();
+
+        let symbols: Vec<&str> = summary
+            .symbols
+            .iter()
+            .map(|entry| entry.symbol.as_str())
+            .collect();
+        assert_eq!(symbols, vec!["BTCUSDT", "DELISTED", "ETHUSDT", "SOLUSDT"]);
+        let failed: Vec<&str> = summary
+            .failed()
+            .map(|entry| entry.symbol.as_str())
+            .collect();
+        assert_eq!(failed, vec!["DELISTED"]);
+        assert_eq!(summary.succeeded().count(), 3);
+        assert_eq!(summary.rows_written(), 18);
+        for symbol in ["BTCUSDT", "ETHUSDT", "SOLUSDT"] {
+            let present = lake_candle_timestamps(lake.path(), symbol, Interval::OneHour).unwrap();
+            assert_eq!(present.len(), 6, "{symbol}");
+        }
+        assert!(
+            lake_candle_timestamps(lake.path(), "DELISTED", Interval::OneHour)
+                .unwrap()
+                .is_empty()
+        );
+        let last = *snapshots.lock().unwrap().last().unwrap();
+        assert_eq!(
+            last,
+            UniverseProgress {
+                total: 4,
+                completed: 3,
+                failed: 1,
+                candles: 18,
+            }
+        );
+    }
 }
@@ -530,7 +530,7 @@ fn timestamp_to_nanos(ts: &DateTime<Utc>) -> i64 {
         .unwrap_or_else(|| ts.timestamp_micros() * 1_000)
 }
 
-fn interval_label(interval: Interval) -> &'static str {
+pub(crate) fn interval_label(interval: Interval) -> &'static str {
     match interval {
         Interval::OneSecond => "1s",
         Interval::OneMinute => "1m",
@@ -640,6 +640,7 @@ fn execution_hint_metadata(hint: &ExecutionHint) -> serde_json::Value {
             "name": name,
             "params": params,
         }),
+        ExecutionHint::Unknown => json!({ "type": "unknown" }),
     }
 }
 
@@ -650,5 +651,7 @@ fn signal_kind_label(kind: SignalKind) -> &'static str {
         SignalKind::EnterShort => "enter_short",
         SignalKind::ExitShort => "exit_short",
         SignalKind::Flatten => "flatten",
+        SignalKind::ReduceLong => "reduce_long",
+        SignalKind::ReduceShort => "reduce_short",
     }
 }
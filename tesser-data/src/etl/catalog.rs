@@ -0,0 +1,187 @@
+//! JSON index of the parquet parts stored in a canonical candle lake.
+//!
+//! Every part written through [`super::CandleLakeWriter`] or [`super::Pipeline`] is recorded
+//! in `_catalog.json` at the lake root, so readers can find the files covering a symbol,
+//! interval and time range without walking the partition tree.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use anyhow::{anyhow, Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tesser_core::Interval;
+
+use crate::encoding::interval_label;
+
+use super::PartitionStats;
+
+/// File name of the catalog, relative to the lake root.
+pub const CATALOG_FILE: &str = "_catalog.json";
+
+/// Serializes catalog read-modify-write cycles between writers in this process.
+static CATALOG_LOCK: Mutex<()> = Mutex::new(());
+
+/// One parquet part recorded in the catalog.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct CatalogEntry {
+    /// Path of the part relative to the lake root, with `/` separators.
+    pub path: String,
+    pub symbol: String,
+    pub interval: String,
+    /// Open time of the first candle in the part.
+    pub first: DateTime<Utc>,
+    /// Open time of the last candle in the part.
+    pub last: DateTime<Utc>,
+    pub rows: u64,
+}
+
+impl CatalogEntry {
+    fn overlaps(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> bool {
+        self.first <= end && self.last >= start
+    }
+}
+
+/// Index of the parts stored under a lake root, keyed by relative path.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct LakeCatalog {
+    entries: BTreeMap<String, CatalogEntry>,
+}
+
+impl LakeCatalog {
+    /// Read the catalog of the lake under `root`, or an empty one when none was written yet.
+    pub fn load(root: &Path) -> Result<Self> {
+        let path = root.join(CATALOG_FILE);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let raw = fs::read_to_string(&path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        serde_json::from_str(&raw).with_context(|| format!("failed to parse {}", path.display()))
+    }
+
+    /// Index every parquet part under `root` from its footer statistics and save the result.
+    ///
+    /// Use this for lakes written before the catalog existed or modified by other tools.
+    pub fn rebuild(root: &Path) -> Result<Self> {
+        let _guard = CATALOG_LOCK
+            .lock()
+            .map_err(|_| anyhow!("catalog lock poisoned"))?;
+        let mut catalog = Self::default();
+        let mut pending = vec![root.to_path_buf()];
+        while let Some(dir) = pending.pop() {
+            let entries =
+                fs::read_dir(&dir).with_context(|| format!("failed to read {}", dir.display()))?;
+            for entry in entries {
+                let path = entry?.path();
+                if path.is_dir() {
+                    pending.push(path);
+                } else if path.extension().and_then(|ext| ext.to_str()) == Some("parquet") {
+                    catalog.record(entry_from_stats(root, &path)?);
+                }
+            }
+        }
+        catalog.save(root)?;
+        Ok(catalog)
+    }
+
+    /// Merge `entries` into the catalog stored under `root`, replacing parts that were rewritten.
+    pub fn update(root: &Path, entries: Vec<CatalogEntry>) -> Result<()> {
+        if entries.is_empty() {
+            return Ok(());
+        }
+        let _guard = CATALOG_LOCK
+            .lock()
+            .map_err(|_| anyhow!("catalog lock poisoned"))?;
+        let mut catalog = Self::load(root)?;
+        for entry in entries {
+            catalog.record(entry);
+        }
+        catalog.save(root)
+    }
+
+    /// Add or replace the entry for a part.
+    pub fn record(&mut self, entry: CatalogEntry) {
+        self.entries.insert(entry.path.clone(), entry);
+    }
+
+    /// Recorded parts, ordered by path.
+    pub fn entries(&self) -> impl Iterator<Item = &CatalogEntry> {
+        self.entries.values()
+    }
+
+    /// Parts under `root` holding `symbol`/`interval` candles that open between `start` and
+    /// `end` (inclusive), ordered by path. `symbol` is matched against the stored symbol column.
+    pub fn files_covering(
+        &self,
+        root: &Path,
+        symbol: &str,
+        interval: Interval,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Vec<PathBuf> {
+        let label = interval_label(interval);
+        self.entries
+            .values()
+            .filter(|entry| {
+                entry.symbol == symbol && entry.interval == label && entry.overlaps(start, end)
+            })
+            .map(|entry| root.join(&entry.path))
+            .collect()
+    }
+
+    fn save(&self, root: &Path) -> Result<()> {
+        fs::create_dir_all(root).with_context(|| format!("failed to create {}", root.display()))?;
+        let path = root.join(CATALOG_FILE);
+        // Write to a sibling first so readers never observe a truncated catalog.
+        let staging = root.join(format!("{CATALOG_FILE}.tmp"));
+        fs::write(&staging, serde_json::to_vec_pretty(self)?)
+            .with_context(|| format!("failed to write {}", staging.display()))?;
+        fs::rename(&staging, &path).with_context(|| format!("failed to replace {}", path.display()))
+    }
+}
+
+/// Catalog path of `part` relative to `root`.
+fn relative_path(root: &Path, part: &Path) -> Result<String> {
+    let relative = part.strip_prefix(root).with_context(|| {
+        format!(
+            "{} is not inside the lake {}",
+            part.display(),
+            root.display()
+        )
+    })?;
+    let segments: Vec<_> = relative
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy().into_owned())
+        .collect();
+    Ok(segments.join("/"))
+}
+
+fn entry_from_stats(root: &Path, part: &Path) -> Result<CatalogEntry> {
+    let stats = super::partition_stats(&[part.to_path_buf()])?;
+    let PartitionStats {
+        rows,
+        first_timestamp,
+        last_timestamp,
+        symbols,
+        intervals,
+        ..
+    } = stats;
+    let single = |values: BTreeSet<String>, column: &str| {
+        let mut values = values.into_iter();
+        match (values.next(), values.next()) {
+            (Some(value), None) => Ok(value),
+            _ => Err(anyhow!("{} must hold exactly one {column}", part.display())),
+        }
+    };
+    Ok(CatalogEntry {
+        path: relative_path(root, part)?,
+        symbol: single(symbols, "symbol")?,
+        interval: single(intervals, "interval")?,
+        first: first_timestamp.ok_or_else(|| anyhow!("{} holds no rows", part.display()))?,
+        last: last_timestamp.ok_or_else(|| anyhow!("{} holds no rows", part.display()))?,
+        rows,
+    })
+}
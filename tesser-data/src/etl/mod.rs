@@ -1,29 +1,47 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::fs::{self, File};
-use std::io::{BufReader, Read};
-use std::path::Path;
+use std::io::{self, BufReader, Read};
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
 use anyhow::{anyhow, bail, Context, Result};
-use arrow::array::{ArrayRef, Decimal128Builder, Int64Builder, StringBuilder};
+use arrow::array::{
+    Array, ArrayRef, Decimal128Builder, Int64Array, Int64Builder, StringArray, StringBuilder,
+    TimestampNanosecondArray,
+};
 use arrow::datatypes::{DataType, SchemaRef};
 use arrow::record_batch::RecordBatch;
-use chrono::{DateTime, Datelike, Utc};
+use async_trait::async_trait;
+use chrono::{DateTime, Datelike, Duration as ChronoDuration, Utc};
 use csv::StringRecord;
 use flate2::read::GzDecoder;
+use futures::StreamExt;
 use glob::glob;
-use parquet::arrow::ArrowWriter;
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use parquet::arrow::{ArrowWriter, ProjectionMask};
+use parquet::file::statistics::Statistics;
 use rayon::prelude::*;
 use rust_decimal::prelude::RoundingStrategy;
 use rust_decimal::Decimal;
 use serde::Deserialize;
-use tracing::{debug, info};
+use sha2::{Digest, Sha256};
+use tesser_broker::RateLimiter;
+use tesser_core::{Candle, Interval, Symbol, Tick};
+use tracing::{debug, info, warn};
 
+use crate::download::{KlineRequest, KlineSink, MarketDataDownloader, NormalizedTrade, TradeSink};
+use crate::encoding::{interval_label, tick_schema, ticks_to_batch};
+use crate::parquet::{MarketEvent, ParquetMarketStream};
 use crate::schema::{
     canonical_candle_schema, CANONICAL_DECIMAL_PRECISION, CANONICAL_DECIMAL_SCALE,
     CANONICAL_DECIMAL_SCALE_U32,
 };
+use crate::transform::{missing_candle_ranges, CandleBuilder, CandleGap};
+
+mod catalog;
+
+pub use catalog::{CatalogEntry, LakeCatalog, CATALOG_FILE};
 
 /// Strategy that controls how normalized candles are partitioned.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -234,13 +252,21 @@ impl Pipeline {
             bail!("no files matched pattern {pattern}");
         }
         sources.sort();
-        sources
+        let normalized = sources
             .par_iter()
             .enumerate()
             .map(|(source_seq, path)| {
                 self.normalize_file(path, output, symbol, partitioning, source_seq)
             })
-            .try_reduce(|| 0usize, |left, right| Ok(left + right))
+            .collect::<Result<Vec<_>>>()?;
+        let mut rows = 0usize;
+        let mut written = Vec::new();
+        for (file_rows, parts) in normalized {
+            rows += file_rows;
+            written.extend(parts);
+        }
+        LakeCatalog::update(output, written)?;
+        Ok(rows)
     }
 
     fn normalize_file(
@@ -250,7 +276,7 @@ impl Pipeline {
         symbol: &str,
         partitioning: Partitioning,
         source_seq: usize,
-    ) -> Result<usize> {
+    ) -> Result<(usize, Vec<CatalogEntry>)> {
         let interval_label = self.mapping.interval.clone();
         let source = self.create_reader(path)?;
         let mut reader = csv::ReaderBuilder::new()
@@ -261,6 +287,7 @@ impl Pipeline {
         let mut partitions: BTreeMap<String, PartitionBuffer> = BTreeMap::new();
         let mut total_buffered = 0usize;
         let mut rows_seen = 0usize;
+        let mut written = Vec::new();
 
         for (idx, record) in reader.records().enumerate() {
             let record = record.with_context(|| format!("failed to read record {}", idx + 1))?;
@@ -346,20 +373,32 @@ impl Pipeline {
             rows_seen += 1;
 
             if let Some(flush_key) = flush_key {
-                let flushed =
-                    self.flush_partition(&schema, output, &mut partitions, source_seq, &flush_key)?;
+                let flushed = self.flush_partition(
+                    &schema,
+                    output,
+                    &mut partitions,
+                    source_seq,
+                    &flush_key,
+                    &mut written,
+                )?;
                 total_buffered = total_buffered.saturating_sub(flushed);
             }
 
             if total_buffered >= MAX_TOTAL_BUFFERED_ROWS {
-                self.flush_all_partitions(&schema, output, &mut partitions, source_seq)?;
+                self.flush_all_partitions(
+                    &schema,
+                    output,
+                    &mut partitions,
+                    source_seq,
+                    &mut written,
+                )?;
                 total_buffered = partitions.values().map(|buf| buf.rows.len()).sum();
             }
         }
 
-        self.flush_all_partitions(&schema, output, &mut partitions, source_seq)?;
+        self.flush_all_partitions(&schema, output, &mut partitions, source_seq, &mut written)?;
         info!(path = %path.display(), rows = rows_seen, "normalized source file");
-        Ok(rows_seen)
+        Ok((rows_seen, written))
     }
 
     fn flush_partition(
@@ -369,6 +408,7 @@ impl Pipeline {
         partitions: &mut BTreeMap<String, PartitionBuffer>,
         source_seq: usize,
         key: &str,
+        written: &mut Vec<CatalogEntry>,
     ) -> Result<usize> {
         let Some(buffer) = partitions.get_mut(key) else {
             return Ok(0);
@@ -378,7 +418,14 @@ impl Pipeline {
         }
         let rows = std::mem::take(&mut buffer.rows);
         let flushed = rows.len();
-        self.write_partition_rows(schema, output, key, &rows, source_seq, buffer.chunk)?;
+        written.push(write_partition_rows(
+            schema,
+            output,
+            key,
+            &rows,
+            source_seq,
+            buffer.chunk,
+        )?);
         buffer.chunk = buffer.chunk.saturating_add(1);
         Ok(flushed)
     }
@@ -389,45 +436,999 @@ impl Pipeline {
         output: &Path,
         partitions: &mut BTreeMap<String, PartitionBuffer>,
         source_seq: usize,
+        written: &mut Vec<CatalogEntry>,
     ) -> Result<()> {
         for (relative, buffer) in partitions.iter_mut() {
             if buffer.rows.is_empty() {
                 continue;
             }
             let rows = std::mem::take(&mut buffer.rows);
-            self.write_partition_rows(schema, output, relative, &rows, source_seq, buffer.chunk)?;
+            written.push(write_partition_rows(
+                schema,
+                output,
+                relative,
+                &rows,
+                source_seq,
+                buffer.chunk,
+            )?);
             buffer.chunk = buffer.chunk.saturating_add(1);
         }
         Ok(())
     }
+}
 
-    fn write_partition_rows(
-        &self,
-        schema: &SchemaRef,
-        output: &Path,
-        relative: &str,
-        records: &[CanonicalCandle],
-        source_seq: usize,
-        chunk: usize,
-    ) -> Result<()> {
-        let dir = output.join(relative);
-        fs::create_dir_all(&dir).with_context(|| format!("failed to create {}", dir.display()))?;
-        let file_name = if chunk == 0 {
-            format!("part-{source_seq:05}.parquet")
-        } else {
-            format!("part-{source_seq:05}-{chunk:05}.parquet")
+/// Incrementally writes downloaded candles into the partitioned canonical lake.
+///
+/// Only rows belonging to the partition currently being filled are buffered, so memory
+/// stays bounded by one partition no matter how long the requested range is. New parts are
+/// added after those already present, and candles whose open time is already stored in the
+/// partition are skipped, so re-running a download neither overwrites nor duplicates rows.
+pub struct CandleLakeWriter {
+    output: PathBuf,
+    partitioning: Partitioning,
+    schema: SchemaRef,
+    current: Option<String>,
+    rows: Vec<CanonicalCandle>,
+    /// Open times stored in, or buffered for, the current partition.
+    stored: HashSet<i64>,
+    chunks: BTreeMap<String, usize>,
+    rows_written: usize,
+    written: Vec<CatalogEntry>,
+}
+
+impl CandleLakeWriter {
+    pub fn new(output: impl Into<PathBuf>, partitioning: Partitioning) -> Self {
+        Self {
+            output: output.into(),
+            partitioning,
+            schema: canonical_candle_schema(),
+            current: None,
+            rows: Vec::new(),
+            stored: HashSet::new(),
+            chunks: BTreeMap::new(),
+            rows_written: 0,
+            written: Vec::new(),
+        }
+    }
+
+    /// Append a candle, flushing the previous partition once the key changes.
+    ///
+    /// Candles already stored in the lake, or pushed earlier, are dropped.
+    pub fn push(&mut self, candle: &Candle) -> Result<()> {
+        let timestamp = candle
+            .timestamp
+            .timestamp_nanos_opt()
+            .ok_or_else(|| anyhow!("timestamp {} out of range", candle.timestamp))?;
+        let row = CanonicalCandle {
+            timestamp,
+            symbol: candle.symbol.code().to_string(),
+            interval: interval_label(candle.interval).to_string(),
+            open: candle.open,
+            high: candle.high,
+            low: candle.low,
+            close: candle.close,
+            volume: Some(candle.volume),
+        };
+        let key = partition_path(&row.symbol, &row.interval, timestamp, self.partitioning)?;
+        if self.current.as_deref() != Some(key.as_str()) {
+            self.flush()?;
+            self.stored = stored_part_timestamps(&self.output.join(&key))?;
+            self.current = Some(key);
+        }
+        if !self.stored.insert(timestamp) {
+            return Ok(());
+        }
+        self.rows.push(row);
+        if self.rows.len() >= MAX_ROWS_PER_PART {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    /// Number of rows held in memory and not yet written.
+    #[must_use]
+    pub fn buffered_rows(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Flush the remaining rows, record the written parts in the lake catalog and return the
+    /// total number of rows written.
+    pub fn finish(mut self) -> Result<usize> {
+        self.flush()?;
+        LakeCatalog::update(&self.output, std::mem::take(&mut self.written))?;
+        Ok(self.rows_written)
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        let Some(key) = self.current.as_deref() else {
+            return Ok(());
+        };
+        if self.rows.is_empty() {
+            return Ok(());
+        }
+        let dir = self.output.join(key);
+        let chunk = self
+            .chunks
+            .entry(key.to_string())
+            .or_insert_with(|| next_free_chunk(&dir, 0));
+        let entry = write_partition_rows(&self.schema, &self.output, key, &self.rows, 0, *chunk)?;
+        self.written.push(entry);
+        *chunk = chunk.saturating_add(1);
+        self.rows_written += self.rows.len();
+        self.rows.clear();
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl KlineSink for CandleLakeWriter {
+    async fn on_page(&mut self, candles: Vec<Candle>) -> Result<()> {
+        for candle in &candles {
+            self.push(candle)?;
+        }
+        Ok(())
+    }
+}
+
+/// Incrementally writes downloaded trades into daily tick partitions
+/// (`symbol=<code>/year=YYYY/month=MM/day=DD/part-NNNNN.parquet`) using the tick schema.
+///
+/// Like [`CandleLakeWriter`], only the partition being filled is buffered and new parts are
+/// added after existing ones. Trades repeated within a run are dropped by exchange id, or by
+/// timestamp, price, size and side when the venue reports none. Trades falling inside the time
+/// span of a part written by an earlier run are skipped, so re-running a download resumes
+/// instead of duplicating the stored range.
+pub struct TickLakeWriter {
+    output: PathBuf,
+    current: Option<String>,
+    rows: Vec<Tick>,
+    /// `(first, last)` exchange timestamps of the parts already stored in the current partition.
+    stored: Vec<(i64, i64)>,
+    seen_ids: HashSet<String>,
+    seen_keys: HashSet<(i64, Decimal, Decimal, i8)>,
+    chunks: BTreeMap<String, usize>,
+    rows_written: usize,
+}
+
+impl TickLakeWriter {
+    pub fn new(output: impl Into<PathBuf>) -> Self {
+        Self {
+            output: output.into(),
+            current: None,
+            rows: Vec::new(),
+            stored: Vec::new(),
+            seen_ids: HashSet::new(),
+            seen_keys: HashSet::new(),
+            chunks: BTreeMap::new(),
+            rows_written: 0,
+        }
+    }
+
+    /// Append a trade, flushing the previous partition once the day changes.
+    pub fn push(&mut self, trade: &NormalizedTrade) -> Result<()> {
+        let tick = &trade.tick;
+        let timestamp = tick
+            .exchange_timestamp
+            .timestamp_nanos_opt()
+            .ok_or_else(|| anyhow!("timestamp {} out of range", tick.exchange_timestamp))?;
+        let key = tick_partition_path(tick.symbol.code(), timestamp)?;
+        if self.current.as_deref() != Some(key.as_str()) {
+            self.flush()?;
+            self.stored = stored_tick_spans(&self.output.join(&key))?;
+            self.seen_ids.clear();
+            self.seen_keys.clear();
+            self.current = Some(key);
+        }
+        if self
+            .stored
+            .iter()
+            .any(|(first, last)| (*first..=*last).contains(&timestamp))
+        {
+            return Ok(());
+        }
+        let fresh = match trade.trade_id.as_deref().filter(|id| !id.is_empty()) {
+            Some(id) => self.seen_ids.insert(id.to_string()),
+            None => self
+                .seen_keys
+                .insert((timestamp, tick.price, tick.size, tick.side.as_i8())),
+        };
+        if !fresh {
+            return Ok(());
+        }
+        self.rows.push(tick.clone());
+        if self.rows.len() >= MAX_ROWS_PER_PART {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    /// Number of rows held in memory and not yet written.
+    #[must_use]
+    pub fn buffered_rows(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Flush the remaining rows and return the total number of trades written.
+    pub fn finish(mut self) -> Result<usize> {
+        self.flush()?;
+        Ok(self.rows_written)
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        let Some(key) = self.current.as_deref() else {
+            return Ok(());
         };
-        let file_path = dir.join(file_name);
-        let batch = rows_to_batch(records, schema)?;
+        if self.rows.is_empty() {
+            return Ok(());
+        }
+        let dir = self.output.join(key);
+        fs::create_dir_all(&dir).with_context(|| format!("failed to create {}", dir.display()))?;
+        let chunk = self
+            .chunks
+            .entry(key.to_string())
+            .or_insert_with(|| next_free_chunk(&dir, 0));
+        let file_path = dir.join(part_file_name(0, *chunk));
+        let batch = ticks_to_batch(&self.rows)?;
         let file = File::create(&file_path)
             .with_context(|| format!("failed to create {}", file_path.display()))?;
-        let mut writer = ArrowWriter::try_new(file, schema.clone(), None)?;
+        let mut writer = ArrowWriter::try_new(file, tick_schema(), None)?;
         writer.write(&batch)?;
         writer.close()?;
+        write_checksum_sidecar(&file_path)?;
+        *chunk = chunk.saturating_add(1);
+        self.rows_written += self.rows.len();
+        self.rows.clear();
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl TradeSink for TickLakeWriter {
+    async fn on_trades(&mut self, trades: Vec<NormalizedTrade>) -> Result<()> {
+        for trade in &trades {
+            self.push(trade)?;
+        }
+        Ok(())
+    }
+}
+
+fn tick_partition_path(symbol: &str, timestamp: i64) -> Result<String> {
+    let dt = datetime_from_ns(timestamp)?;
+    Ok(format!(
+        "symbol={}/year={:04}/month={:02}/day={:02}",
+        Symbol::sanitize(symbol),
+        dt.year(),
+        dt.month(),
+        dt.day()
+    ))
+}
+
+/// `(first, last)` exchange timestamps of every tick part directly inside `dir`.
+fn stored_tick_spans(dir: &Path) -> Result<Vec<(i64, i64)>> {
+    let mut spans = Vec::new();
+    if !dir.exists() {
+        return Ok(spans);
+    }
+    let entries = fs::read_dir(dir).with_context(|| format!("failed to read {}", dir.display()))?;
+    for entry in entries {
+        let path = entry?.path();
+        if path.extension().and_then(|ext| ext.to_str()) != Some("parquet") {
+            continue;
+        }
+        let file =
+            File::open(&path).with_context(|| format!("failed to open {}", path.display()))?;
+        let reader = ParquetRecordBatchReaderBuilder::try_new(file)?.build()?;
+        let mut span: Option<(i64, i64)> = None;
+        for batch in reader {
+            let batch = batch?;
+            let (idx, _) = batch
+                .schema()
+                .column_with_name("exchange_timestamp")
+                .ok_or_else(|| anyhow!("{} has no exchange_timestamp column", path.display()))?;
+            let column = batch
+                .column(idx)
+                .as_any()
+                .downcast_ref::<TimestampNanosecondArray>()
+                .ok_or_else(|| {
+                    anyhow!(
+                        "{} exchange_timestamp column is not a timestamp",
+                        path.display()
+                    )
+                })?;
+            for value in column.iter().flatten() {
+                span = Some(match span {
+                    Some((first, last)) => (first.min(value), last.max(value)),
+                    None => (value, value),
+                });
+            }
+        }
+        spans.extend(span);
+    }
+    Ok(spans)
+}
+
+fn write_partition_rows(
+    schema: &SchemaRef,
+    output: &Path,
+    relative: &str,
+    records: &[CanonicalCandle],
+    source_seq: usize,
+    chunk: usize,
+) -> Result<CatalogEntry> {
+    let (Some(head), Some(first), Some(last)) = (
+        records.first(),
+        records.iter().map(|row| row.timestamp).min(),
+        records.iter().map(|row| row.timestamp).max(),
+    ) else {
+        bail!("refusing to write an empty part into {relative}");
+    };
+    let dir = output.join(relative);
+    fs::create_dir_all(&dir).with_context(|| format!("failed to create {}", dir.display()))?;
+    let file_name = part_file_name(source_seq, chunk);
+    let file_path = dir.join(&file_name);
+    let batch = rows_to_batch(records, schema)?;
+    let file = File::create(&file_path)
+        .with_context(|| format!("failed to create {}", file_path.display()))?;
+    let mut writer = ArrowWriter::try_new(file, schema.clone(), None)?;
+    writer.write(&batch)?;
+    writer.close()?;
+    write_checksum_sidecar(&file_path)?;
+    Ok(CatalogEntry {
+        path: format!("{relative}/{file_name}"),
+        symbol: head.symbol.clone(),
+        interval: head.interval.clone(),
+        first: datetime_from_ns(first)?,
+        last: datetime_from_ns(last)?,
+        rows: records.len() as u64,
+    })
+}
+
+fn part_file_name(source_seq: usize, chunk: usize) -> String {
+    if chunk == 0 {
+        format!("part-{source_seq:05}.parquet")
+    } else {
+        format!("part-{source_seq:05}-{chunk:05}.parquet")
+    }
+}
+
+/// First chunk number whose part file does not exist yet in `dir`.
+fn next_free_chunk(dir: &Path, source_seq: usize) -> usize {
+    let mut chunk = 0;
+    while dir.join(part_file_name(source_seq, chunk)).exists() {
+        chunk += 1;
+    }
+    chunk
+}
+
+/// Result of [`ticks_to_candle_lake`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TickConversion {
+    /// Ticks read from the input partitions.
+    pub ticks: usize,
+    /// Candle rows written across all requested intervals.
+    pub candles: usize,
+}
+
+/// Aggregate tick partitions into candle partitions for every interval in one pass.
+///
+/// Ticks are streamed from `tick_paths` in order, so memory stays bounded by one open candle
+/// and one buffered partition per symbol and interval. Ticks older than the candle currently
+/// being built for their symbol are dropped, matching [`CandleBuilder`].
+pub async fn ticks_to_candle_lake(
+    tick_paths: Vec<PathBuf>,
+    output: &Path,
+    intervals: &[Interval],
+    partitioning: Partitioning,
+) -> Result<TickConversion> {
+    if intervals.is_empty() {
+        bail!("at least one candle interval is required");
+    }
+    let mut stream =
+        ParquetMarketStream::new(Vec::new(), tick_paths, Vec::new(), Vec::new(), Vec::new());
+    let mut outputs: Vec<(CandleBuilder, HashMap<Symbol, CandleLakeWriter>)> = intervals
+        .iter()
+        .map(|interval| (CandleBuilder::new(*interval), HashMap::new()))
+        .collect();
+    let mut summary = TickConversion::default();
+    while let Some(event) = stream.next_event().await? {
+        let MarketEvent::Tick(tick) = event else {
+            continue;
+        };
+        summary.ticks += 1;
+        for (builder, writers) in &mut outputs {
+            if let Some(candle) = builder.push_tick(&tick) {
+                writers
+                    .entry(candle.symbol)
+                    .or_insert_with(|| CandleLakeWriter::new(output, partitioning))
+                    .push(&candle)?;
+            }
+        }
+    }
+    for (builder, mut writers) in outputs {
+        for candle in builder.finish() {
+            writers
+                .entry(candle.symbol)
+                .or_insert_with(|| CandleLakeWriter::new(output, partitioning))
+                .push(&candle)?;
+        }
+        for writer in writers.into_values() {
+            summary.candles += writer.finish()?;
+        }
+    }
+    Ok(summary)
+}
+
+/// Open times of every `symbol`/`interval` candle already stored in the lake, sorted and deduplicated.
+pub fn lake_candle_timestamps(
+    root: &Path,
+    symbol: &str,
+    interval: Interval,
+) -> Result<Vec<DateTime<Utc>>> {
+    let base = root
+        .join(format!(
+            "symbol={}",
+            Symbol::sanitize(Symbol::from(symbol).code())
+        ))
+        .join(format!(
+            "interval={}",
+            sanitize_interval(interval_label(interval))
+        ));
+    let mut timestamps = Vec::new();
+    if !base.exists() {
+        return Ok(timestamps);
+    }
+    let mut pending = vec![base];
+    while let Some(dir) = pending.pop() {
+        let entries =
+            fs::read_dir(&dir).with_context(|| format!("failed to read {}", dir.display()))?;
+        for entry in entries {
+            let path = entry?.path();
+            if path.is_dir() {
+                pending.push(path);
+                continue;
+            }
+            if path.extension().and_then(|ext| ext.to_str()) != Some("parquet") {
+                continue;
+            }
+            for value in part_timestamps(&path)? {
+                timestamps.push(datetime_from_ns(value)?);
+            }
+        }
+    }
+    timestamps.sort();
+    timestamps.dedup();
+    Ok(timestamps)
+}
+
+/// Open times stored in the parquet parts directly inside one partition directory.
+fn stored_part_timestamps(dir: &Path) -> Result<HashSet<i64>> {
+    let mut stored = HashSet::new();
+    if !dir.exists() {
+        return Ok(stored);
+    }
+    let entries = fs::read_dir(dir).with_context(|| format!("failed to read {}", dir.display()))?;
+    for entry in entries {
+        let path = entry?.path();
+        if path.extension().and_then(|ext| ext.to_str()) == Some("parquet") {
+            stored.extend(part_timestamps(&path)?);
+        }
+    }
+    Ok(stored)
+}
+
+/// Raw nanosecond open times held by a canonical candle part.
+fn part_timestamps(path: &Path) -> Result<Vec<i64>> {
+    let file = File::open(path).with_context(|| format!("failed to open {}", path.display()))?;
+    let reader = ParquetRecordBatchReaderBuilder::try_new(file)?.build()?;
+    let mut timestamps = Vec::new();
+    for batch in reader {
+        let batch = batch?;
+        let (idx, _) = batch
+            .schema()
+            .column_with_name("timestamp")
+            .ok_or_else(|| anyhow!("{} has no timestamp column", path.display()))?;
+        let column = batch
+            .column(idx)
+            .as_any()
+            .downcast_ref::<Int64Array>()
+            .ok_or_else(|| anyhow!("{} timestamp column is not int64", path.display()))?;
+        timestamps.extend(column.iter().flatten());
+    }
+    Ok(timestamps)
+}
+
+/// Result of [`fill_lake_gaps`].
+#[derive(Clone, Debug, Default)]
+pub struct LakeGapFill {
+    /// Ranges that were missing before the fill and were requested from the venue.
+    pub gaps: Vec<CandleGap>,
+    pub rows_written: usize,
+}
+
+/// Download only the bars of `request` that are missing from the lake under `root`.
+///
+/// Each gap is fetched separately and merged into its partition as an additional part, so
+/// existing files are never rewritten.
+pub async fn fill_lake_gaps<D>(
+    downloader: &D,
+    root: &Path,
+    request: &KlineRequest<'_>,
+    partitioning: Partitioning,
+) -> Result<LakeGapFill>
+where
+    D: MarketDataDownloader + Sync,
+{
+    let present = lake_candle_timestamps(root, request.symbol, request.interval)?;
+    let gaps = missing_candle_ranges(&present, request.interval, request.start, request.end);
+    let mut writer = CandleLakeWriter::new(root, partitioning);
+    // Stop just before the next bar so the candle bounding the gap is not fetched again.
+    let tail = request.interval.as_duration() - ChronoDuration::milliseconds(1);
+    for gap in &gaps {
+        info!(
+            symbol = request.symbol,
+            first = %gap.first,
+            last = %gap.last,
+            "fetching missing candles"
+        );
+        let mut gap_request = KlineRequest::new(
+            request.category,
+            request.symbol,
+            request.interval,
+            gap.first,
+            gap.last + tail,
+        );
+        gap_request.limit = request.limit;
+        downloader.stream_klines(&gap_request, &mut writer).await?;
+    }
+    let rows_written = writer.finish()?;
+    Ok(LakeGapFill { gaps, rows_written })
+}
+
+/// Aggregated progress of a [`download_universe`] run.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct UniverseProgress {
+    /// Symbols in the job.
+    pub total: usize,
+    /// Symbols whose download finished successfully.
+    pub completed: usize,
+    /// Symbols whose download failed.
+    pub failed: usize,
+    /// Candles received so far across all symbols.
+    pub candles: usize,
+}
+
+/// Receives a [`UniverseProgress`] snapshot after every page and every finished symbol.
+pub type UniverseProgressCallback = Arc<dyn Fn(UniverseProgress) + Send + Sync>;
+
+/// Kline backfill of many symbols into the lake, run by [`download_universe`].
+#[derive(Clone)]
+pub struct DownloadJob<'a> {
+    pub category: &'a str,
+    pub symbols: Vec<String>,
+    pub interval: Interval,
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    /// Symbols downloaded at the same time.
+    pub concurrency: usize,
+    pub partitioning: Partitioning,
+    /// Limiter awaited before every request, shared by all symbols of the job.
+    pub limiter: Option<RateLimiter>,
+    pub progress: Option<UniverseProgressCallback>,
+}
+
+impl<'a> DownloadJob<'a> {
+    pub fn new(
+        category: &'a str,
+        symbols: impl IntoIterator<Item = impl Into<String>>,
+        interval: Interval,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            category,
+            symbols: symbols.into_iter().map(Into::into).collect(),
+            interval,
+            start,
+            end,
+            concurrency: 4,
+            partitioning: Partitioning::Daily,
+            limiter: None,
+            progress: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency;
+        self
+    }
+
+    #[must_use]
+    pub fn with_partitioning(mut self, partitioning: Partitioning) -> Self {
+        self.partitioning = partitioning;
+        self
+    }
+
+    /// Throttle the requests of every symbol through one shared limiter.
+    #[must_use]
+    pub fn with_rate_limiter(mut self, limiter: RateLimiter) -> Self {
+        self.limiter = Some(limiter);
+        self
+    }
+
+    #[must_use]
+    pub fn with_progress(mut self, progress: UniverseProgressCallback) -> Self {
+        self.progress = Some(progress);
+        self
+    }
+}
+
+/// Outcome of one symbol of a [`DownloadJob`].
+#[derive(Debug)]
+pub struct SymbolDownload {
+    pub symbol: String,
+    /// Rows written to the lake, or the error that stopped the symbol.
+    pub result: Result<usize>,
+}
+
+/// Per-symbol results of [`download_universe`], in the order the symbols were requested.
+#[derive(Debug, Default)]
+pub struct UniverseDownload {
+    pub symbols: Vec<SymbolDownload>,
+}
+
+impl UniverseDownload {
+    /// Symbols that were downloaded completely.
+    pub fn succeeded(&self) -> impl Iterator<Item = &SymbolDownload> {
+        self.symbols.iter().filter(|entry| entry.result.is_ok())
+    }
+
+    /// Symbols whose download failed.
+    pub fn failed(&self) -> impl Iterator<Item = &SymbolDownload> {
+        self.symbols.iter().filter(|entry| entry.result.is_err())
+    }
+
+    /// Rows written to the lake across all symbols.
+    pub fn rows_written(&self) -> usize {
+        self.symbols
+            .iter()
+            .filter_map(|entry| entry.result.as_ref().ok())
+            .sum()
+    }
+}
+
+/// Download the klines of every symbol in `job` into the lake under `root`.
+///
+/// Up to [`DownloadJob::concurrency`] symbols are fetched at once. A failing symbol is
+/// reported in the returned summary without aborting the others; rows it wrote before
+/// failing stay in the lake and can be completed later with [`fill_lake_gaps`].
+pub async fn download_universe<D>(
+    downloader: &D,
+    root: &Path,
+    job: &DownloadJob<'_>,
+) -> UniverseDownload
+where
+    D: MarketDataDownloader + Sync,
+{
+    let progress = Mutex::new(UniverseProgress {
+        total: job.symbols.len(),
+        ..UniverseProgress::default()
+    });
+    let progress = &progress;
+    let symbols: Vec<SymbolDownload> =
+        futures::stream::iter(job.symbols.iter().map(|symbol| async move {
+            let result = download_symbol(downloader, root, job, symbol, progress).await;
+            match &result {
+                Ok(rows) => info!(symbol = %symbol, rows, "symbol download complete"),
+                Err(err) => warn!(symbol = %symbol, error = %err, "symbol download failed"),
+            }
+            let snapshot = {
+                let mut progress = progress.lock().unwrap();
+                if result.is_ok() {
+                    progress.completed += 1;
+                } else {
+                    progress.failed += 1;
+                }
+                *progress
+            };
+            if let Some(callback) = &job.progress {
+                callback(snapshot);
+            }
+            SymbolDownload {
+                symbol: symbol.clone(),
+                result,
+            }
+        }))
+        .buffered(job.concurrency.max(1))
+        .collect()
+        .await;
+    UniverseDownload { symbols }
+}
+
+async fn download_symbol<D>(
+    downloader: &D,
+    root: &Path,
+    job: &DownloadJob<'_>,
+    symbol: &str,
+    progress: &Mutex<UniverseProgress>,
+) -> Result<usize>
+where
+    D: MarketDataDownloader + Sync,
+{
+    let request = KlineRequest::new(job.category, symbol, job.interval, job.start, job.end);
+    let mut sink = UniverseSink {
+        writer: CandleLakeWriter::new(root, job.partitioning),
+        job,
+        progress,
+    };
+    if let Some(limiter) = &job.limiter {
+        limiter.until_ready().await?;
+    }
+    downloader.stream_klines(&request, &mut sink).await?;
+    sink.writer.finish()
+}
+
+/// Lake writer that reports progress and waits for the shared limiter between pages.
+struct UniverseSink<'a, 'j> {
+    writer: CandleLakeWriter,
+    job: &'a DownloadJob<'j>,
+    progress: &'a Mutex<UniverseProgress>,
+}
+
+#[async_trait]
+impl KlineSink for UniverseSink<'_, '_> {
+    async fn on_page(&mut self, candles: Vec<Candle>) -> Result<()> {
+        let received = candles.len();
+        self.writer.on_page(candles).await?;
+        let snapshot = {
+            let mut progress = self.progress.lock().unwrap();
+            progress.candles += received;
+            *progress
+        };
+        if let Some(callback) = &self.job.progress {
+            callback(snapshot);
+        }
+        // The downloader issues its next request as soon as this page is accepted.
+        if let Some(limiter) = &self.job.limiter {
+            limiter.until_ready().await?;
+        }
         Ok(())
     }
 }
 
+/// Extension appended to a parquet part's file name for its checksum sidecar.
+const CHECKSUM_EXTENSION: &str = "sha256";
+
+fn sidecar_path(part: &Path) -> PathBuf {
+    let mut name = part.as_os_str().to_os_string();
+    name.push(".");
+    name.push(CHECKSUM_EXTENSION);
+    PathBuf::from(name)
+}
+
+fn file_sha256(path: &Path) -> Result<String> {
+    let mut file =
+        File::open(path).with_context(|| format!("failed to open {}", path.display()))?;
+    let mut hasher = Sha256::new();
+    io::copy(&mut file, &mut hasher)
+        .with_context(|| format!("failed to hash {}", path.display()))?;
+    Ok(hex::encode(hasher.finalize()))
+}
+
+/// Write a `sha256sum`-compatible sidecar next to `part`.
+fn write_checksum_sidecar(part: &Path) -> Result<()> {
+    let digest = file_sha256(part)?;
+    let name = part
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let sidecar = sidecar_path(part);
+    fs::write(&sidecar, format!("{digest}  {name}\n"))
+        .with_context(|| format!("failed to write {}", sidecar.display()))
+}
+
+/// Check every parquet part under `root` against its `.sha256` sidecar.
+///
+/// Returns the parts whose content no longer matches the recorded hash, including parts that
+/// vanished while their sidecar remained. Parts without a sidecar are skipped.
+pub fn verify_lake(root: &Path) -> Result<Vec<PathBuf>> {
+    let mut mismatched = Vec::new();
+    let mut pending = vec![root.to_path_buf()];
+    while let Some(dir) = pending.pop() {
+        let entries =
+            fs::read_dir(&dir).with_context(|| format!("failed to read {}", dir.display()))?;
+        for entry in entries {
+            let path = entry?.path();
+            if path.is_dir() {
+                pending.push(path);
+                continue;
+            }
+            if path.extension().and_then(|ext| ext.to_str()) != Some(CHECKSUM_EXTENSION) {
+                continue;
+            }
+            let part = path.with_extension("");
+            let recorded = fs::read_to_string(&path)
+                .with_context(|| format!("failed to read {}", path.display()))?;
+            let expected = recorded.split_whitespace().next().unwrap_or_default();
+            let intact = part.exists() && file_sha256(&part)?.eq_ignore_ascii_case(expected);
+            if !intact {
+                warn!(path = %part.display(), "lake file failed checksum verification");
+                mismatched.push(part);
+            }
+        }
+    }
+    mismatched.sort();
+    Ok(mismatched)
+}
+
+/// Summary of one or more parquet files, as reported by [`partition_stats`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PartitionStats {
+    pub files: usize,
+    pub rows: u64,
+    pub first_timestamp: Option<DateTime<Utc>>,
+    pub last_timestamp: Option<DateTime<Utc>>,
+    pub symbols: BTreeSet<String>,
+    pub intervals: BTreeSet<String>,
+    /// Null count per column name, summed across files.
+    pub null_counts: BTreeMap<String, u64>,
+}
+
+impl PartitionStats {
+    fn observe_timestamp(&mut self, timestamp: i64) -> Result<()> {
+        let ts = datetime_from_ns(timestamp)?;
+        if self.first_timestamp.is_none_or(|first| ts < first) {
+            self.first_timestamp = Some(ts);
+        }
+        if self.last_timestamp.is_none_or(|last| ts > last) {
+            self.last_timestamp = Some(ts);
+        }
+        Ok(())
+    }
+
+    fn values_mut(&mut self, column: &str) -> Option<&mut BTreeSet<String>> {
+        match column {
+            "symbol" => Some(&mut self.symbols),
+            "interval" => Some(&mut self.intervals),
+            _ => None,
+        }
+    }
+}
+
+/// Summarize parquet files using row-group statistics from the footer.
+///
+/// Only columns whose statistics are missing (or, for `symbol`/`interval`, hold more than one
+/// value in a row group) are read back from the data pages.
+pub fn partition_stats(paths: &[PathBuf]) -> Result<PartitionStats> {
+    let mut stats = PartitionStats::default();
+    for path in paths {
+        file_stats(path, &mut stats)
+            .with_context(|| format!("failed to summarize {}", path.display()))?;
+    }
+    Ok(stats)
+}
+
+fn file_stats(path: &Path, stats: &mut PartitionStats) -> Result<()> {
+    let file = File::open(path)?;
+    let builder = ParquetRecordBatchReaderBuilder::try_new(file)?;
+    let metadata = builder.metadata().clone();
+    stats.files += 1;
+
+    let mut scan = BTreeSet::new();
+    let mut scan_nulls = BTreeSet::new();
+    for (idx, column) in metadata
+        .file_metadata()
+        .schema_descr()
+        .columns()
+        .iter()
+        .enumerate()
+    {
+        let name = column.path().string();
+        let chunks = metadata
+            .row_groups()
+            .iter()
+            .map(|group| group.column(idx).statistics());
+        let mut nulls = Some(0u64);
+        for chunk in chunks.clone() {
+            nulls = nulls
+                .zip(chunk.and_then(Statistics::null_count_opt))
+                .map(|(total, count)| total + count);
+        }
+        let total = stats.null_counts.entry(name.clone()).or_default();
+        match nulls {
+            Some(count) => *total += count,
+            None => {
+                scan.insert(idx);
+                scan_nulls.insert(name.clone());
+            }
+        }
+
+        if name == "timestamp" {
+            for chunk in chunks {
+                match chunk {
+                    Some(Statistics::Int64(values)) => match (values.min_opt(), values.max_opt()) {
+                        (Some(min), Some(max)) => {
+                            stats.observe_timestamp(*min)?;
+                            stats.observe_timestamp(*max)?;
+                        }
+                        _ => {
+                            scan.insert(idx);
+                        }
+                    },
+                    _ => {
+                        scan.insert(idx);
+                    }
+                }
+            }
+        } else if let Some(values) = stats.values_mut(&name) {
+            for chunk in chunks {
+                let single = match chunk {
+                    Some(Statistics::ByteArray(range)) => range
+                        .min_opt()
+                        .zip(range.max_opt())
+                        .filter(|(min, max)| min == max)
+                        .and_then(|(min, _)| min.as_utf8().ok()),
+                    _ => None,
+                };
+                match single {
+                    Some(value) => {
+                        values.insert(value.to_string());
+                    }
+                    None => {
+                        scan.insert(idx);
+                    }
+                }
+            }
+        }
+    }
+    stats.rows += metadata
+        .row_groups()
+        .iter()
+        .map(|group| group.num_rows().max(0) as u64)
+        .sum::<u64>();
+
+    if scan.is_empty() {
+        return Ok(());
+    }
+    let mask = ProjectionMask::leaves(builder.parquet_schema(), scan);
+    let reader = builder.with_projection(mask).build()?;
+    for batch in reader {
+        let batch = batch?;
+        for (field, column) in batch.schema().fields().iter().zip(batch.columns()) {
+            let name = field.name().as_str();
+            if scan_nulls.contains(name) {
+                *stats.null_counts.entry(name.to_string()).or_default() +=
+                    column.null_count() as u64;
+            }
+            if name == "timestamp" {
+                for value in timestamp_values(column)? {
+                    stats.observe_timestamp(value)?;
+                }
+            } else if let Some(values) = stats.values_mut(name) {
+                let strings = column
+                    .as_any()
+                    .downcast_ref::<StringArray>()
+                    .ok_or_else(|| anyhow!("{name} column is not utf8"))?;
+                values.extend(strings.iter().flatten().map(str::to_string));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn timestamp_values(column: &ArrayRef) -> Result<Vec<i64>> {
+    if let Some(values) = column.as_any().downcast_ref::<Int64Array>() {
+        return Ok(values.iter().flatten().collect());
+    }
+    if let Some(values) = column.as_any().downcast_ref::<TimestampNanosecondArray>() {
+        return Ok(values.iter().flatten().collect());
+    }
+    bail!(
+        "timestamp column has unsupported type {}",
+        column.data_type()
+    )
+}
+
 #[derive(Clone)]
 struct CanonicalCandle {
     timestamp: i64,
@@ -513,7 +1514,7 @@ fn partition_path(
 ) -> Result<String> {
     let dt = datetime_from_ns(timestamp)?;
     let mut segments = vec![
-        format!("symbol={}", sanitize_symbol(symbol)),
+        format!("symbol={}", Symbol::sanitize(symbol)),
         format!("interval={}", sanitize_interval(interval)),
     ];
     segments.push(format!("year={:04}", dt.year()));
@@ -524,10 +1525,6 @@ fn partition_path(
     Ok(segments.join("/"))
 }
 
-fn sanitize_symbol(symbol: &str) -> String {
-    symbol.replace(':', "_")
-}
-
 fn sanitize_interval(interval: &str) -> String {
     interval
         .chars()
@@ -548,6 +1545,7 @@ mod tests {
     use std::fs;
     use std::io::Write;
     use tempfile::tempdir;
+    use tesser_core::{CandleStatus, Side, Tick};
 
     #[test]
     fn pipeline_normalizes_csv() {
@@ -712,6 +1710,472 @@ mod tests {
         assert!(count_files(&output) > 0);
     }
 
+    #[test]
+    fn partition_path_sanitizes_qualified_symbols() {
+        let path = partition_path(
+            "binance:BTCUSDT",
+            "1m",
+            1_704_067_200_000_000_000,
+            Partitioning::Daily,
+        )
+        .unwrap();
+        assert_eq!(
+            path,
+            "symbol=binance_BTCUSDT/interval=1m/year=2024/month=01/day=01"
+        );
+    }
+
+    fn write_sample_lake(root: &Path) {
+        let mut writer = CandleLakeWriter::new(root, Partitioning::Daily);
+        let start = DateTime::<Utc>::from_timestamp(1_704_067_200, 0).unwrap();
+        for day in 0..2 {
+            writer
+                .push(&Candle {
+                    symbol: Symbol::from("binance:BTCUSDT"),
+                    interval: tesser_core::Interval::OneDay,
+                    open: Decimal::from(100 + day),
+                    high: Decimal::from(110 + day),
+                    low: Decimal::from(90 + day),
+                    close: Decimal::from(105 + day),
+                    volume: Decimal::ONE,
+                    timestamp: start + chrono::Duration::days(day),
+                    status: CandleStatus::Closed,
+                })
+                .unwrap();
+        }
+        assert_eq!(writer.finish().unwrap(), 2);
+    }
+
+    #[test]
+    fn partition_stats_reads_footer_statistics() {
+        let dir = tempdir().unwrap();
+        write_sample_lake(dir.path());
+        let parts = parquet_parts(dir.path());
+
+        let stats = partition_stats(&parts).unwrap();
+        let start = DateTime::<Utc>::from_timestamp(1_704_067_200, 0).unwrap();
+        assert_eq!(stats.files, 2);
+        assert_eq!(stats.rows, 2);
+        assert_eq!(stats.first_timestamp, Some(start));
+        assert_eq!(
+            stats.last_timestamp,
+            Some(start + chrono::Duration::days(1))
+        );
+        assert_eq!(stats.symbols.iter().collect::<Vec<_>>(), vec!["BTCUSDT"]);
+        assert_eq!(stats.intervals.iter().collect::<Vec<_>>(), vec!["1d"]);
+        assert_eq!(stats.null_counts.len(), 8);
+        assert!(stats.null_counts.values().all(|count| *count == 0));
+    }
+
+    #[test]
+    fn partition_stats_scans_columns_without_statistics() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("mixed.parquet");
+        let row = |timestamp: i64, symbol: &str, volume: Option<Decimal>| CanonicalCandle {
+            timestamp,
+            symbol: symbol.to_string(),
+            interval: "1m".to_string(),
+            open: Decimal::ONE,
+            high: Decimal::ONE,
+            low: Decimal::ONE,
+            close: Decimal::ONE,
+            volume,
+        };
+        let schema = canonical_candle_schema();
+        let batch = rows_to_batch(
+            &[
+                row(180_000_000_000, "ETHUSDT", None),
+                row(60_000_000_000, "BTCUSDT", Some(Decimal::ONE)),
+                row(120_000_000_000, "BTCUSDT", None),
+            ],
+            &schema,
+        )
+        .unwrap();
+        let props = parquet::file::properties::WriterProperties::builder()
+            .set_statistics_enabled(parquet::file::properties::EnabledStatistics::None)
+            .build();
+        let mut writer =
+            ArrowWriter::try_new(File::create(&path).unwrap(), schema, Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        let stats = partition_stats(std::slice::from_ref(&path)).unwrap();
+        assert_eq!(stats.rows, 3);
+        assert_eq!(
+            stats.first_timestamp,
+            DateTime::<Utc>::from_timestamp(60, 0)
+        );
+        assert_eq!(
+            stats.last_timestamp,
+            DateTime::<Utc>::from_timestamp(180, 0)
+        );
+        assert_eq!(
+            stats.symbols.iter().collect::<Vec<_>>(),
+            vec!["BTCUSDT", "ETHUSDT"]
+        );
+        assert_eq!(stats.null_counts["volume"], 2);
+        assert_eq!(stats.null_counts["open"], 0);
+    }
+
+    fn parquet_parts(root: &Path) -> Vec<PathBuf> {
+        let pattern = format!("{}/**/*.parquet", root.display());
+        let mut parts: Vec<PathBuf> = glob(&pattern).unwrap().flatten().collect();
+        parts.sort();
+        parts
+    }
+
+    fn write_tick_partition(path: &Path, ticks: &[Tick]) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        let batch = ticks_to_batch(ticks).unwrap();
+        let mut writer =
+            ArrowWriter::try_new(File::create(path).unwrap(), batch.schema(), None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+    }
+
+    async fn read_lake_candles(root: &Path, interval: &str) -> Vec<Candle> {
+        let parts = parquet_parts(
+            &root
+                .join("symbol=BTCUSDT")
+                .join(format!("interval={interval}")),
+        );
+        let mut stream = ParquetMarketStream::with_candles(Vec::new(), parts);
+        let mut candles = Vec::new();
+        while let Some(event) = stream.next_event().await.unwrap() {
+            if let MarketEvent::Candle(candle) = event {
+                candles.push(candle);
+            }
+        }
+        candles
+    }
+
+    #[tokio::test]
+    async fn ticks_convert_to_multiple_candle_intervals() {
+        let dir = tempdir().unwrap();
+        let start = DateTime::<Utc>::from_timestamp(1_704_067_200, 0).unwrap();
+        let ticks: Vec<Tick> = [
+            (0, 100, 1),
+            (30, 105, 2),
+            (59, 95, 1),
+            (60, 101, 3),
+            (200, 110, 1),
+            (299, 90, 2),
+            (300, 102, 1),
+        ]
+        .into_iter()
+        .map(|(offset, price, size)| {
+            let ts = start + ChronoDuration::seconds(offset);
+            Tick {
+                symbol: Symbol::from("BTCUSDT"),
+                price: Decimal::from(price),
+                size: Decimal::from(size),
+                side: Side::Buy,
+                exchange_timestamp: ts,
+                received_at: ts,
+            }
+        })
+        .collect();
+        let input = dir
+            .path()
+            .join("ticks/BTCUSDT/2024-01-01/part-00000.parquet");
+        write_tick_partition(&input, &ticks);
+        let output = dir.path().join("candles");
+
+        let summary = ticks_to_candle_lake(
+            vec![input],
+            &output,
+            &[Interval::OneMinute, Interval::FiveMinutes],
+            Partitioning::Daily,
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            summary,
+            TickConversion {
+                ticks: 7,
+                candles: 7
+            }
+        );
+
+        let ohlcv = |candle: &Candle| {
+            (
+                (candle.timestamp - start).num_seconds(),
+                candle.open,
+                candle.high,
+                candle.low,
+                candle.close,
+                candle.volume,
+            )
+        };
+        let bar = |offset: i64, open: i64, high: i64, low: i64, close: i64, volume: i64| {
+            (
+                offset,
+                Decimal::from(open),
+                Decimal::from(high),
+                Decimal::from(low),
+                Decimal::from(close),
+                Decimal::from(volume),
+            )
+        };
+        let minute = read_lake_candles(&output, "1m").await;
+        assert!(minute.iter().all(|c| c.interval == Interval::OneMinute));
+        assert_eq!(
+            minute.iter().map(ohlcv).collect::<Vec<_>>(),
+            vec![
+                bar(0, 100, 105, 95, 95, 4),
+                bar(60, 101, 101, 101, 101, 3),
+                bar(180, 110, 110, 110, 110, 1),
+                bar(240, 90, 90, 90, 90, 2),
+                bar(300, 102, 102, 102, 102, 1),
+            ]
+        );
+        let five = read_lake_candles(&output, "5m").await;
+        assert!(five.iter().all(|c| c.interval == Interval::FiveMinutes));
+        assert_eq!(
+            five.iter().map(ohlcv).collect::<Vec<_>>(),
+            vec![
+                bar(0, 100, 110, 90, 90, 10),
+                bar(300, 102, 102, 102, 102, 1)
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn tick_conversion_requires_an_interval() {
+        let dir = tempdir().unwrap();
+        let err = ticks_to_candle_lake(Vec::new(), dir.path(), &[], Partitioning::Daily)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("interval"));
+    }
+
+    #[test]
+    fn untouched_lake_verifies_clean() {
+        let dir = tempdir().unwrap();
+        write_sample_lake(dir.path());
+        let parts = parquet_parts(dir.path());
+        assert_eq!(parts.len(), 2);
+        assert!(parts.iter().all(|part| sidecar_path(part).exists()));
+        assert!(verify_lake(dir.path()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn tampered_parquet_part_is_reported() {
+        let dir = tempdir().unwrap();
+        write_sample_lake(dir.path());
+        let parts = parquet_parts(dir.path());
+        let target = &parts[1];
+        let mut bytes = fs::read(target).unwrap();
+        let middle = bytes.len() / 2;
+        bytes[middle] ^= 0xFF;
+        fs::write(target, bytes).unwrap();
+
+        assert_eq!(verify_lake(dir.path()).unwrap(), vec![target.clone()]);
+
+        fs::remove_file(&parts[0]).unwrap();
+        assert_eq!(verify_lake(dir.path()).unwrap(), parts);
+    }
+
+    #[test]
+    fn catalog_lists_parts_covering_range() {
+        let dir = tempdir().unwrap();
+        write_sample_lake(dir.path());
+        let parts = parquet_parts(dir.path());
+        let catalog = LakeCatalog::load(dir.path()).unwrap();
+        let start = DateTime::<Utc>::from_timestamp(1_704_067_200, 0).unwrap();
+        let day = chrono::Duration::days(1);
+        let covering = |symbol: &str, interval, from, to| {
+            catalog.files_covering(dir.path(), symbol, interval, from, to)
+        };
+
+        assert_eq!(catalog.entries().count(), 2);
+        assert!(catalog.entries().all(|entry| entry.rows == 1));
+        assert_eq!(
+            covering("BTCUSDT", Interval::OneDay, start, start + day),
+            parts
+        );
+        assert_eq!(
+            covering("BTCUSDT", Interval::OneDay, start + day, start + day * 5),
+            vec![parts[1].clone()]
+        );
+        assert!(covering("BTCUSDT", Interval::OneDay, start - day * 3, start - day).is_empty());
+        assert!(covering("BTCUSDT", Interval::OneMinute, start, start + day).is_empty());
+        assert!(covering("ETHUSDT", Interval::OneDay, start, start + day).is_empty());
+
+        let rebuilt = LakeCatalog::rebuild(dir.path()).unwrap();
+        assert!(rebuilt.entries().eq(catalog.entries()));
+    }
+
+    #[test]
+    fn catalog_tracks_appended_parts() {
+        let dir = tempdir().unwrap();
+        write_sample_lake(dir.path());
+        let start = DateTime::<Utc>::from_timestamp(1_704_067_200, 0).unwrap();
+        let day = chrono::Duration::days(1);
+        let hour = chrono::Duration::hours(1);
+        let candle = |timestamp| Candle {
+            symbol: Symbol::from("binance:BTCUSDT"),
+            interval: Interval::OneDay,
+            open: Decimal::ONE,
+            high: Decimal::ONE,
+            low: Decimal::ONE,
+            close: Decimal::ONE,
+            volume: Decimal::ONE,
+            timestamp,
+            status: CandleStatus::Closed,
+        };
+        let mut writer = CandleLakeWriter::new(dir.path(), Partitioning::Daily);
+        writer.push(&candle(start + day)).unwrap();
+        writer.push(&candle(start + day + hour)).unwrap();
+        writer.push(&candle(start + day * 2)).unwrap();
+        assert_eq!(writer.finish().unwrap(), 2);
+
+        let parts = parquet_parts(dir.path());
+        let catalog = LakeCatalog::load(dir.path()).unwrap();
+        assert_eq!(parts.len(), 4);
+        assert_eq!(
+            catalog
+                .files_covering(
+                    dir.path(),
+                    "BTCUSDT",
+                    Interval::OneDay,
+                    start,
+                    start + day * 2
+                )
+                .len(),
+            4
+        );
+        assert_eq!(
+            catalog.files_covering(
+                dir.path(),
+                "BTCUSDT",
+                Interval::OneDay,
+                start + day,
+                start + day + hour
+            ),
+            parts[1..3].to_vec()
+        );
+        assert!(LakeCatalog::rebuild(dir.path())
+            .unwrap()
+            .entries()
+            .eq(catalog.entries()));
+    }
+
+    #[test]
+    fn rerun_adds_parts_and_skips_stored_candles() {
+        let dir = tempdir().unwrap();
+        write_sample_lake(dir.path());
+        let first_part = dir
+            .path()
+            .join("symbol=BTCUSDT/interval=1d/year=2024/month=01/day=01/part-00000.parquet");
+        let original = fs::read(&first_part).unwrap();
+        let start = DateTime::<Utc>::from_timestamp(1_704_067_200, 0).unwrap();
+        let candle = |days: i64, close: i64| Candle {
+            symbol: Symbol::from("binance:BTCUSDT"),
+            interval: Interval::OneDay,
+            open: Decimal::ONE,
+            high: Decimal::ONE,
+            low: Decimal::ONE,
+            close: Decimal::from(close),
+            volume: Decimal::ONE,
+            timestamp: start + chrono::Duration::days(days),
+            status: CandleStatus::Closed,
+        };
+
+        let mut writer = CandleLakeWriter::new(dir.path(), Partitioning::Daily);
+        for days in 0..3 {
+            writer.push(&candle(days, 7)).unwrap();
+        }
+        assert_eq!(writer.finish().unwrap(), 1);
+
+        assert_eq!(fs::read(&first_part).unwrap(), original);
+        assert_eq!(parquet_parts(dir.path()).len(), 3);
+        assert_eq!(
+            lake_candle_timestamps(dir.path(), "BTCUSDT", Interval::OneDay)
+                .unwrap()
+                .len(),
+            3
+        );
+    }
+
+    #[tokio::test]
+    async fn tick_lake_writer_dedups_and_resumes() {
+        let dir = tempdir().unwrap();
+        let start = DateTime::<Utc>::from_timestamp(1_704_153_540, 0).unwrap();
+        let trade = |offset: i64, id: Option<&str>| {
+            let ts = start + ChronoDuration::seconds(offset);
+            NormalizedTrade::new(
+                Tick {
+                    symbol: Symbol::from("BTCUSDT"),
+                    price: Decimal::from(100 + offset),
+                    size: Decimal::ONE,
+                    side: Side::Buy,
+                    exchange_timestamp: ts,
+                    received_at: ts,
+                },
+                id.map(str::to_string),
+            )
+        };
+
+        // 23:59:00 .. 00:00:30 across two days, with a repeated id and a repeated id-less trade.
+        let mut writer = TickLakeWriter::new(dir.path());
+        writer
+            .on_trades(vec![
+                trade(0, Some("1")),
+                trade(30, Some("2")),
+                trade(30, Some("2")),
+                trade(59, None),
+                trade(59, None),
+            ])
+            .await
+            .unwrap();
+        writer.on_trades(vec![trade(60, Some("3"))]).await.unwrap();
+        assert_eq!(writer.finish().unwrap(), 4);
+
+        let day_one = dir
+            .path()
+            .join("symbol=BTCUSDT/year=2024/month=01/day=01/part-00000.parquet");
+        let day_two = dir
+            .path()
+            .join("symbol=BTCUSDT/year=2024/month=01/day=02/part-00000.parquet");
+        assert!(day_one.exists());
+        assert!(day_two.exists());
+
+        // A re-run over an overlapping range only adds the trades after the stored span.
+        let mut writer = TickLakeWriter::new(dir.path());
+        writer
+            .on_trades(vec![
+                trade(30, Some("2")),
+                trade(60, Some("3")),
+                trade(90, Some("4")),
+            ])
+            .await
+            .unwrap();
+        assert_eq!(writer.finish().unwrap(), 1);
+        let appended = dir
+            .path()
+            .join("symbol=BTCUSDT/year=2024/month=01/day=02/part-00001.parquet");
+        assert!(appended.exists());
+
+        let mut stream = ParquetMarketStream::new(
+            Vec::new(),
+            parquet_parts(dir.path()),
+            Vec::new(),
+            Vec::new(),
+            Vec::new(),
+        );
+        let mut prices = Vec::new();
+        while let Some(event) = stream.next_event().await.unwrap() {
+            if let MarketEvent::Tick(tick) = event {
+                prices.push(tick.price);
+            }
+        }
+        assert_eq!(
+            prices,
+            [100, 130, 159, 160, 190].map(Decimal::from).to_vec()
+        );
+    }
+
     fn count_files(root: &Path) -> usize {
         fn visit(dir: &Path, total: &mut usize) {
             if let Ok(entries) = fs::read_dir(dir) {
@@ -719,7 +2183,7 @@ mod tests {
                     let path = entry.path();
                     if path.is_dir() {
                         visit(&path, total);
-                    } else {
+                    } else if path.extension().is_some_and(|ext| ext == "parquet") {
                         *total += 1;
                     }
                 }
@@ -17,7 +17,7 @@ use rust_decimal::Decimal;
 use tokio::fs;
 use tokio::fs::File as TokioFile;
 
-use tesser_core::{Candle, Interval, Symbol, Tick};
+use tesser_core::{Candle, CandleStatus, Interval, Symbol, Tick};
 
 use crate::encoding::{candles_to_batch, tick_schema, ticks_to_batch};
 
@@ -268,6 +268,7 @@ fn read_csv(path: &Path) -> Result<Vec<Candle>> {
             close: parse_decimal(record.get(5), "close", path)?,
             volume: parse_decimal(record.get(6), "volume", path)?,
             timestamp,
+            status: CandleStatus::Closed,
         };
         candles.push(candle);
     }
@@ -406,7 +407,7 @@ struct CandleColumns {
     high: usize,
     low: usize,
     close: usize,
-    volume: usize,
+    volume: Option<usize>,
     timestamp: usize,
 }
 
@@ -419,7 +420,7 @@ impl CandleColumns {
             high: column_index(batch, "high")?,
             low: column_index(batch, "low")?,
             close: column_index(batch, "close")?,
-            volume: column_index(batch, "volume")?,
+            volume: optional_column_index(batch, "volume"),
             timestamp: column_index(batch, "timestamp")?,
         })
     }
@@ -436,8 +437,12 @@ impl CandleColumns {
             high: decimal_value(batch, self.high, row)?,
             low: decimal_value(batch, self.low, row)?,
             close: decimal_value(batch, self.close, row)?,
-            volume: decimal_value(batch, self.volume, row)?,
+            volume: match self.volume {
+                Some(column) => decimal_value(batch, column, row)?,
+                None => Decimal::ZERO,
+            },
             timestamp: timestamp_value(batch, self.timestamp, row)?,
+            status: CandleStatus::Closed,
         })
     }
 }
@@ -450,6 +455,10 @@ fn column_index(batch: &RecordBatch, name: &str) -> Result<usize> {
         .ok_or_else(|| anyhow!("column '{name}' missing from schema"))
 }
 
+fn optional_column_index(batch: &RecordBatch, name: &str) -> Option<usize> {
+    batch.schema().column_with_name(name).map(|(idx, _)| idx)
+}
+
 fn string_value(batch: &RecordBatch, column: usize, row: usize) -> Result<String> {
     let array = batch
         .column(column)
@@ -516,6 +525,7 @@ mod tests {
                 close: Decimal::new(10 + idx as i64, 0),
                 volume: Decimal::new(1, 0),
                 timestamp: base + Duration::minutes(idx as i64),
+                status: CandleStatus::Closed,
             })
             .collect()
     }
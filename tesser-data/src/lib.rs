@@ -116,7 +116,7 @@ mod tests {
         Arc,
     };
     use tesser_broker::BrokerInfo;
-    use tesser_core::{Interval, Side, Symbol};
+    use tesser_core::{CandleStatus, Interval, Side, Symbol};
 
     struct TestStream {
         ticks: Vec<Tick>,
@@ -199,6 +199,7 @@ mod tests {
             close: Decimal::ONE,
             volume: Decimal::ONE,
             timestamp: chrono::Utc::now(),
+            status: CandleStatus::Closed,
         }];
         let mut distributor = DataDistributor::new(TestStream { ticks, candles });
         let ticks_counter = Arc::new(AtomicUsize::new(0));
@@ -1,7 +1,8 @@
 use std::collections::{HashMap, VecDeque};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::pin::Pin;
 use std::str::FromStr;
+use std::time::Duration as StdDuration;
 
 use anyhow::{anyhow, Context, Result};
 use arrow::array::{
@@ -15,28 +16,92 @@ use chrono::{DateTime, Utc};
 use futures::StreamExt;
 use parquet::arrow::async_reader::ParquetRecordBatchStream;
 use parquet::arrow::ParquetRecordBatchStreamBuilder;
+use parquet::file::metadata::ParquetMetaData;
+use parquet::file::statistics::Statistics;
 use rust_decimal::Decimal;
 use tokio::fs::File;
+use tokio::time::{sleep_until, Instant};
 
 use tesser_broker::{BrokerError, BrokerInfo, BrokerResult, MarketStream};
 use tesser_core::{
-    Candle, DepthUpdate, Interval, LocalOrderBook, OrderBook, OrderBookLevel, Side, Symbol, Tick,
+    Candle, CandleStatus, DepthUpdate, Interval, LocalOrderBook, OrderBook, OrderBookLevel, Side,
+    Symbol, Tick,
 };
 
 const DEFAULT_BATCH_SIZE: usize = 4_096;
 
+/// Tick or candle emitted by [`ParquetMarketStream::next_event`].
+#[derive(Clone, Debug)]
+pub enum MarketEvent {
+    Tick(Tick),
+    Candle(Candle),
+}
+
+impl MarketEvent {
+    /// Timestamp used to order the event within the merged feed.
+    ///
+    /// Candles are keyed by their close time (`timestamp + interval`), since a bar is only
+    /// known once its interval has elapsed.
+    pub fn timestamp(&self) -> DateTime<Utc> {
+        match self {
+            Self::Tick(tick) => tick.exchange_timestamp,
+            Self::Candle(candle) => candle_close_time(candle),
+        }
+    }
+}
+
+fn candle_close_time(candle: &Candle) -> DateTime<Utc> {
+    candle.timestamp + candle.interval.as_duration()
+}
+
+/// What a stream does with a candle whose interval differs from the expected one.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum IntervalMismatch {
+    /// Drop the candle and continue with the next record.
+    Skip,
+    /// Fail the read so mixed-interval inputs are caught early.
+    Error,
+}
+
 /// Market stream backed by on-disk parquet files (flight recorder output).
 pub struct ParquetMarketStream {
     info: BrokerInfo,
     ticks: Option<TickCursor>,
+    tick_peek: Option<Tick>,
     candles: Option<CandleCursor>,
+    candle_peek: Option<Candle>,
     order_books: Option<OrderBookCursor>,
     depth_updates: Option<DepthCursor>,
     book_state: HashMap<Symbol, LocalOrderBook>,
+    pacer: Option<ReplayPacer>,
 }
 
 unsafe impl Sync for ParquetMarketStream {}
 
+/// Holds events back so replay follows the recorded timestamps at a fixed speed.
+///
+/// Delays are measured from the first event rather than between consecutive ones, so slow
+/// consumers catch up instead of accumulating drift.
+struct ReplayPacer {
+    speed: f64,
+    anchor: Option<(DateTime<Utc>, Instant)>,
+}
+
+impl ReplayPacer {
+    async fn wait(&mut self, ts: DateTime<Utc>) {
+        let (start_ts, start) = *self.anchor.get_or_insert_with(|| (ts, Instant::now()));
+        let Ok(offset) = (ts - start_ts).to_std() else {
+            return;
+        };
+        let Ok(delay) = StdDuration::try_from_secs_f64(offset.as_secs_f64() / self.speed) else {
+            return;
+        };
+        if let Some(deadline) = start.checked_add(delay) {
+            sleep_until(deadline).await;
+        }
+    }
+}
+
 impl ParquetMarketStream {
     /// Build a stream configured with the provided parquet partitions.
     pub fn new(
@@ -58,11 +123,13 @@ impl ParquetMarketStream {
             } else {
                 Some(TickCursor::new(tick_paths))
             },
+            tick_peek: None,
             candles: if candle_paths.is_empty() {
                 None
             } else {
                 Some(CandleCursor::new(candle_paths))
             },
+            candle_peek: None,
             order_books: if order_book_paths.is_empty() {
                 None
             } else {
@@ -74,6 +141,42 @@ impl ParquetMarketStream {
                 Some(DepthCursor::new(depth_paths))
             },
             book_state: HashMap::new(),
+            pacer: None,
+        }
+    }
+
+    /// Delay events so their spacing matches the recorded timestamps divided by `factor`.
+    ///
+    /// `1.0` replays in real time and `10.0` ten times faster; `0` (or any non-positive
+    /// factor) keeps the default as-fast-as-possible replay.
+    #[must_use]
+    pub fn with_replay_speed(mut self, factor: f64) -> Self {
+        self.pacer = (factor.is_finite() && factor > 0.0).then_some(ReplayPacer {
+            speed: factor,
+            anchor: None,
+        });
+        self
+    }
+
+    /// Only deliver candles recorded at `interval`, handling others according to `mismatch`.
+    ///
+    /// Without this filter the `interval` column is trusted as-is, so files mixing several
+    /// timeframes feed every candle through.
+    #[must_use]
+    pub fn with_expected_interval(
+        mut self,
+        interval: Interval,
+        mismatch: IntervalMismatch,
+    ) -> Self {
+        if let Some(cursor) = self.candles.as_mut() {
+            cursor.expected_interval = Some((interval, mismatch));
+        }
+        self
+    }
+
+    async fn pace(&mut self, ts: DateTime<Utc>) {
+        if let Some(pacer) = self.pacer.as_mut() {
+            pacer.wait(ts).await;
         }
     }
 
@@ -97,6 +200,62 @@ impl ParquetMarketStream {
     pub fn with_depth_updates(symbols: Vec<Symbol>, depth_paths: Vec<PathBuf>) -> Self {
         Self::new(symbols, Vec::new(), Vec::new(), Vec::new(), depth_paths)
     }
+
+    /// Restart replay so only records stamped at or after `ts` are delivered.
+    ///
+    /// Files and row groups whose timestamp statistics end before `ts` are skipped
+    /// without being decoded.
+    pub fn seek_to(&mut self, ts: DateTime<Utc>) {
+        if let Some(cursor) = self.ticks.as_mut() {
+            cursor.loader.seek_to(ts);
+        }
+        if let Some(cursor) = self.candles.as_mut() {
+            cursor.loader.seek_to(ts);
+        }
+        if let Some(cursor) = self.order_books.as_mut() {
+            cursor.loader.seek_to(ts);
+        }
+        if let Some(cursor) = self.depth_updates.as_mut() {
+            cursor.loader.seek_to(ts);
+        }
+        self.tick_peek = None;
+        self.candle_peek = None;
+        self.book_state.clear();
+        if let Some(pacer) = self.pacer.as_mut() {
+            pacer.anchor = None;
+        }
+    }
+
+    /// Next tick or candle in chronological order across both sources.
+    ///
+    /// Candles are ordered by close time, so every tick inside a bar is delivered before the
+    /// bar itself; a tick stamped exactly at the close belongs to the next bar and follows it.
+    pub async fn next_event(&mut self) -> BrokerResult<Option<MarketEvent>> {
+        if self.tick_peek.is_none() {
+            if let Some(cursor) = self.ticks.as_mut() {
+                self.tick_peek = cursor.next().await.map_err(map_err)?;
+            }
+        }
+        if self.candle_peek.is_none() {
+            if let Some(cursor) = self.candles.as_mut() {
+                self.candle_peek = cursor.next().await.map_err(map_err)?;
+            }
+        }
+        let take_tick = match (&self.tick_peek, &self.candle_peek) {
+            (Some(tick), Some(candle)) => tick.exchange_timestamp < candle_close_time(candle),
+            (Some(_), None) => true,
+            (None, _) => false,
+        };
+        let event = if take_tick {
+            self.tick_peek.take().map(MarketEvent::Tick)
+        } else {
+            self.candle_peek.take().map(MarketEvent::Candle)
+        };
+        if let Some(event) = &event {
+            self.pace(event.timestamp()).await;
+        }
+        Ok(event)
+    }
 }
 
 #[async_trait]
@@ -116,20 +275,40 @@ impl MarketStream for ParquetMarketStream {
     }
 
     async fn next_tick(&mut self) -> BrokerResult<Option<Tick>> {
-        match &mut self.ticks {
-            Some(cursor) => cursor.next().await.map_err(map_err),
-            None => Ok(None),
+        let tick = match (self.tick_peek.take(), &mut self.ticks) {
+            (Some(tick), _) => Some(tick),
+            (None, Some(cursor)) => cursor.next().await.map_err(map_err)?,
+            (None, None) => None,
+        };
+        if let Some(tick) = &tick {
+            self.pace(tick.exchange_timestamp).await;
         }
+        Ok(tick)
     }
 
     async fn next_candle(&mut self) -> BrokerResult<Option<Candle>> {
-        match &mut self.candles {
-            Some(cursor) => cursor.next().await.map_err(map_err),
-            None => Ok(None),
+        let candle = match (self.candle_peek.take(), &mut self.candles) {
+            (Some(candle), _) => Some(candle),
+            (None, Some(cursor)) => cursor.next().await.map_err(map_err)?,
+            (None, None) => None,
+        };
+        if let Some(candle) = &candle {
+            self.pace(candle.timestamp).await;
         }
+        Ok(candle)
     }
 
     async fn next_order_book(&mut self) -> BrokerResult<Option<OrderBook>> {
+        let book = self.read_order_book().await?;
+        if let Some(book) = &book {
+            self.pace(book.timestamp).await;
+        }
+        Ok(book)
+    }
+}
+
+impl ParquetMarketStream {
+    async fn read_order_book(&mut self) -> BrokerResult<Option<OrderBook>> {
         if let Some(cursor) = self.order_books.as_mut() {
             if let Some(book) = cursor.next().await.map_err(map_err)? {
                 return Ok(Some(book));
@@ -164,7 +343,7 @@ unsafe impl Sync for TickCursor {}
 impl TickCursor {
     pub(crate) fn new(paths: Vec<PathBuf>) -> Self {
         Self {
-            loader: BatchLoader::new(paths),
+            loader: BatchLoader::new(paths, "exchange_timestamp"),
             columns: None,
         }
     }
@@ -182,7 +361,11 @@ impl TickCursor {
                     .columns
                     .as_ref()
                     .ok_or_else(|| anyhow!("tick schema not initialized"))?;
-                return decode_tick(&batch, row, columns).map(Some);
+                let record = decode_tick(&batch, row, columns)?;
+                if self.loader.is_before_start(record.exchange_timestamp) {
+                    continue;
+                }
+                return Ok(Some(record));
             }
         }
     }
@@ -191,6 +374,7 @@ impl TickCursor {
 struct CandleCursor {
     loader: BatchLoader,
     columns: Option<CandleColumns>,
+    expected_interval: Option<(Interval, IntervalMismatch)>,
 }
 
 unsafe impl Sync for CandleCursor {}
@@ -198,8 +382,9 @@ unsafe impl Sync for CandleCursor {}
 impl CandleCursor {
     fn new(paths: Vec<PathBuf>) -> Self {
         Self {
-            loader: BatchLoader::new(paths),
+            loader: BatchLoader::new(paths, "timestamp"),
             columns: None,
+            expected_interval: None,
         }
     }
 
@@ -216,7 +401,27 @@ impl CandleCursor {
                     .columns
                     .as_ref()
                     .ok_or_else(|| anyhow!("candle schema not initialized"))?;
-                return decode_candle(&batch, row, columns).map(Some);
+                let record = decode_candle(&batch, row, columns)?;
+                if self.loader.is_before_start(record.timestamp) {
+                    continue;
+                }
+                if let Some((expected, mismatch)) = self.expected_interval {
+                    if record.interval != expected {
+                        match mismatch {
+                            IntervalMismatch::Skip => continue,
+                            IntervalMismatch::Error => {
+                                return Err(anyhow!(
+                                    "candle for {} at {} has interval {:?}, expected {:?}",
+                                    record.symbol,
+                                    record.timestamp,
+                                    record.interval,
+                                    expected
+                                ))
+                            }
+                        }
+                    }
+                }
+                return Ok(Some(record));
             }
         }
     }
@@ -232,7 +437,7 @@ unsafe impl Sync for OrderBookCursor {}
 impl OrderBookCursor {
     pub(crate) fn new(paths: Vec<PathBuf>) -> Self {
         Self {
-            loader: BatchLoader::new(paths),
+            loader: BatchLoader::new(paths, "timestamp"),
             columns: None,
         }
     }
@@ -250,7 +455,11 @@ impl OrderBookCursor {
                     .columns
                     .as_ref()
                     .ok_or_else(|| anyhow!("order book schema not initialized"))?;
-                return decode_order_book(&batch, row, columns).map(Some);
+                let record = decode_order_book(&batch, row, columns)?;
+                if self.loader.is_before_start(record.timestamp) {
+                    continue;
+                }
+                return Ok(Some(record));
             }
         }
     }
@@ -266,7 +475,7 @@ unsafe impl Sync for DepthCursor {}
 impl DepthCursor {
     pub(crate) fn new(paths: Vec<PathBuf>) -> Self {
         Self {
-            loader: BatchLoader::new(paths),
+            loader: BatchLoader::new(paths, "timestamp"),
             columns: None,
         }
     }
@@ -284,14 +493,21 @@ impl DepthCursor {
                     .columns
                     .as_ref()
                     .ok_or_else(|| anyhow!("depth schema not initialized"))?;
-                return decode_depth_update(&batch, row, columns).map(Some);
+                let record = decode_depth_update(&batch, row, columns)?;
+                if self.loader.is_before_start(record.timestamp) {
+                    continue;
+                }
+                return Ok(Some(record));
             }
         }
     }
 }
 
 struct BatchLoader {
+    paths: Vec<PathBuf>,
     files: VecDeque<PathBuf>,
+    timestamp_column: &'static str,
+    start: Option<DateTime<Utc>>,
     stream: Option<Pin<Box<ParquetRecordBatchStream<File>>>>,
     batch: Option<RecordBatch>,
     row_index: usize,
@@ -302,10 +518,13 @@ struct BatchLoader {
 unsafe impl Sync for BatchLoader {}
 
 impl BatchLoader {
-    fn new(mut paths: Vec<PathBuf>) -> Self {
+    fn new(mut paths: Vec<PathBuf>, timestamp_column: &'static str) -> Self {
         paths.sort();
         Self {
-            files: paths.into(),
+            files: paths.clone().into(),
+            paths,
+            timestamp_column,
+            start: None,
             stream: None,
             batch: None,
             row_index: 0,
@@ -354,16 +573,66 @@ impl BatchLoader {
         self.schema_update.take()
     }
 
+    /// Rewind to the first file and drop records stamped before `ts`.
+    fn seek_to(&mut self, ts: DateTime<Utc>) {
+        self.files = self.paths.clone().into();
+        self.stream = None;
+        self.batch = None;
+        self.row_index = 0;
+        self.start = Some(ts);
+    }
+
+    fn is_before_start(&self, timestamp: DateTime<Utc>) -> bool {
+        self.start.is_some_and(|start| timestamp < start)
+    }
+
+    /// Row groups that may contain records at or after the seek point.
+    fn row_groups_from_start(&self, metadata: &ParquetMetaData) -> Option<Vec<usize>> {
+        let start = self.start?.timestamp_nanos_opt()?;
+        let column = metadata
+            .file_metadata()
+            .schema_descr()
+            .columns()
+            .iter()
+            .position(|column| column.path().string() == self.timestamp_column)?;
+        let groups = metadata
+            .row_groups()
+            .iter()
+            .enumerate()
+            .filter(|(_, group)| match group.column(column).statistics() {
+                Some(Statistics::Int64(stats)) => stats.max_opt().is_none_or(|max| *max >= start),
+                _ => true,
+            })
+            .map(|(idx, _)| idx)
+            .collect();
+        Some(groups)
+    }
+
     async fn open_next_stream(&mut self) -> Result<bool> {
-        let Some(path) = self.files.pop_front() else {
-            return Ok(false);
-        };
-        let file = File::open(&path)
+        loop {
+            let Some(path) = self.files.pop_front() else {
+                return Ok(false);
+            };
+            if self.open_stream(&path).await? {
+                return Ok(true);
+            }
+        }
+    }
+
+    /// Open `path`, returning false when every row group ends before the seek point.
+    async fn open_stream(&mut self, path: &Path) -> Result<bool> {
+        let file = File::open(path)
             .await
             .with_context(|| format!("failed to open {}", path.display()))?;
         let mut builder = ParquetRecordBatchStreamBuilder::new(file)
             .await
             .with_context(|| format!("failed to read parquet metadata from {}", path.display()))?;
+        if let Some(groups) = self.row_groups_from_start(builder.metadata()) {
+            if groups.is_empty() {
+                return Ok(false);
+            }
+            builder = builder.with_row_groups(groups);
+        }
         builder = builder.with_batch_size(self.batch_size);
         let schema = builder.schema().clone();
         let stream = builder
@@ -406,7 +675,8 @@ struct CandleColumns {
     high: usize,
     low: usize,
     close: usize,
-    volume: usize,
+    /// Absent in partitions written before volume was recorded.
+    volume: Option<usize>,
     timestamp: usize,
 }
 
@@ -419,7 +689,7 @@ impl CandleColumns {
             high: column_index(schema, "high")?,
             low: column_index(schema, "low")?,
             close: column_index(schema, "close")?,
-            volume: column_index(schema, "volume")?,
+            volume: optional_column_index(schema, "volume"),
             timestamp: column_index(schema, "timestamp")?,
         })
     }
@@ -528,7 +798,10 @@ fn decode_candle(batch: &RecordBatch, row: usize, columns: &CandleColumns) -> Re
     let high = decimal_value(batch, columns.high, row)?;
     let low = decimal_value(batch, columns.low, row)?;
     let close = decimal_value(batch, columns.close, row)?;
-    let volume = decimal_value_or_default(batch, columns.volume, row, Decimal::ZERO)?;
+    let volume = match columns.volume {
+        Some(column) => decimal_value_or_default(batch, column, row, Decimal::ZERO)?,
+        None => Decimal::ZERO,
+    };
     let timestamp = timestamp_value(batch, columns.timestamp, row)?;
     Ok(Candle {
         symbol,
@@ -539,6 +812,7 @@ fn decode_candle(batch: &RecordBatch, row: usize, columns: &CandleColumns) -> Re
         close,
         volume,
         timestamp,
+        status: CandleStatus::Closed,
     })
 }
 
@@ -939,6 +1213,7 @@ mod tests {
             close: Decimal::new(15, 1),
             volume: Decimal::new(5, 0),
             timestamp: Utc::now(),
+            status: CandleStatus::Closed,
         }]
     }
 
@@ -953,6 +1228,193 @@ mod tests {
         }]
     }
 
+    fn candles_at(start: DateTime<Utc>, minutes: std::ops::Range<i64>) -> Vec<Candle> {
+        minutes
+            .map(|minute| Candle {
+                symbol: Symbol::from("BTCUSDT"),
+                interval: Interval::OneMinute,
+                open: Decimal::from(minute),
+                high: Decimal::from(minute),
+                low: Decimal::from(minute),
+                close: Decimal::from(minute),
+                volume: Decimal::ONE,
+                timestamp: start + chrono::Duration::minutes(minute),
+                status: CandleStatus::Closed,
+            })
+            .collect()
+    }
+
+    fn split_candle_files(dir: &std::path::Path, start: DateTime<Utc>) -> Result<Vec<PathBuf>> {
+        let first = dir.join("candles-0.parquet");
+        let second = dir.join("candles-1.parquet");
+        write_parquet_file(&first, &candles_to_batch(&candles_at(start, 0..3))?)?;
+        write_parquet_file(&second, &candles_to_batch(&candles_at(start, 3..6))?)?;
+        Ok(vec![first, second])
+    }
+
+    #[tokio::test]
+    async fn seek_skips_files_that_end_before_the_target() -> Result<()> {
+        let tmp = tempdir()?;
+        let start = DateTime::<Utc>::from_timestamp(1_700_000_000, 0).unwrap();
+        let paths = split_candle_files(tmp.path(), start)?;
+
+        let mut stream = ParquetMarketStream::with_candles(vec![Symbol::from("BTCUSDT")], paths);
+        stream.seek_to(start + chrono::Duration::minutes(4));
+        let mut closes = Vec::new();
+        while let Some(candle) = stream.next_candle().await? {
+            closes.push(candle.close);
+        }
+        assert_eq!(closes, vec![Decimal::from(4), Decimal::from(5)]);
+
+        // Seeking again rewinds to the earlier file.
+        stream.seek_to(start + chrono::Duration::minutes(2));
+        let candle = stream.next_candle().await?.expect("candle after rewind");
+        assert_eq!(candle.timestamp, start + chrono::Duration::minutes(2));
+        Ok(())
+    }
+
+    fn mixed_interval_file(dir: &std::path::Path, start: DateTime<Utc>) -> Result<PathBuf> {
+        let path = dir.join("mixed.parquet");
+        let mut candles = candles_at(start, 0..4);
+        for candle in candles.iter_mut().skip(1).step_by(2) {
+            candle.interval = Interval::FiveMinutes;
+        }
+        write_parquet_file(&path, &candles_to_batch(&candles)?)?;
+        Ok(path)
+    }
+
+    #[tokio::test]
+    async fn expected_interval_skips_other_timeframes() -> Result<()> {
+        let tmp = tempdir()?;
+        let start = DateTime::<Utc>::from_timestamp(1_700_000_000, 0).unwrap();
+        let path = mixed_interval_file(tmp.path(), start)?;
+
+        let mut stream =
+            ParquetMarketStream::with_candles(vec![Symbol::from("BTCUSDT")], vec![path.clone()])
+                .with_expected_interval(Interval::OneMinute, IntervalMismatch::Skip);
+        let mut closes = Vec::new();
+        while let Some(candle) = stream.next_candle().await? {
+            assert_eq!(candle.interval, Interval::OneMinute);
+            closes.push(candle.close);
+        }
+        assert_eq!(closes, vec![Decimal::from(0), Decimal::from(2)]);
+
+        let mut stream =
+            ParquetMarketStream::with_candles(vec![Symbol::from("BTCUSDT")], vec![path])
+                .with_expected_interval(Interval::FiveMinutes, IntervalMismatch::Skip);
+        let mut count = 0;
+        while let Some(event) = stream.next_event().await? {
+            let MarketEvent::Candle(candle) = event else {
+                panic!("unexpected tick");
+            };
+            assert_eq!(candle.interval, Interval::FiveMinutes);
+            count += 1;
+        }
+        assert_eq!(count, 2);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn expected_interval_can_reject_mismatches() -> Result<()> {
+        let tmp = tempdir()?;
+        let start = DateTime::<Utc>::from_timestamp(1_700_000_000, 0).unwrap();
+        let path = mixed_interval_file(tmp.path(), start)?;
+
+        let mut stream =
+            ParquetMarketStream::with_candles(vec![Symbol::from("BTCUSDT")], vec![path])
+                .with_expected_interval(Interval::OneMinute, IntervalMismatch::Error);
+        assert!(stream.next_candle().await?.is_some());
+        let err = stream.next_candle().await.unwrap_err();
+        assert!(err.to_string().contains("FiveMinutes"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn seek_past_the_last_record_yields_nothing() -> Result<()> {
+        let tmp = tempdir()?;
+        let start = DateTime::<Utc>::from_timestamp(1_700_000_000, 0).unwrap();
+        let paths = split_candle_files(tmp.path(), start)?;
+
+        let mut stream = ParquetMarketStream::with_candles(vec![Symbol::from("BTCUSDT")], paths);
+        stream.seek_to(start + chrono::Duration::hours(1));
+        assert!(stream.next_candle().await?.is_none());
+        Ok(())
+    }
+
+    fn ticks_at(start: DateTime<Utc>, seconds: &[i64]) -> Vec<Tick> {
+        seconds
+            .iter()
+            .map(|second| {
+                let ts = start + chrono::Duration::seconds(*second);
+                Tick {
+                    symbol: Symbol::from("BTCUSDT"),
+                    price: Decimal::from(*second),
+                    size: Decimal::ONE,
+                    side: Side::Buy,
+                    exchange_timestamp: ts,
+                    received_at: ts,
+                }
+            })
+            .collect()
+    }
+
+    fn event_label(event: &MarketEvent) -> String {
+        match event {
+            MarketEvent::Tick(tick) => format!("t{}", tick.price.normalize()),
+            MarketEvent::Candle(candle) => format!("c{}", candle.close.normalize()),
+        }
+    }
+
+    #[tokio::test]
+    async fn next_event_merges_ticks_and_candles_chronologically() -> Result<()> {
+        let tmp = tempdir()?;
+        let start = DateTime::<Utc>::from_timestamp(1_700_000_000, 0).unwrap();
+        let tick_path = tmp.path().join("ticks.parquet");
+        let candle_path = tmp.path().join("candles.parquet");
+        // Candles close at 60, 120 and 180 seconds; ticks fall inside the bars, one exactly on a close.
+        write_parquet_file(
+            &tick_path,
+            &ticks_to_batch(&ticks_at(start, &[30, 60, 90, 150, 200]))?,
+        )?;
+        write_parquet_file(&candle_path, &candles_to_batch(&candles_at(start, 0..3))?)?;
+
+        let mut stream = ParquetMarketStream::new(
+            vec![Symbol::from("BTCUSDT")],
+            vec![tick_path],
+            vec![candle_path],
+            Vec::new(),
+            Vec::new(),
+        );
+        let mut labels = Vec::new();
+        let mut last = None;
+        while let Some(event) = stream.next_event().await? {
+            assert!(last.is_none_or(|ts| ts <= event.timestamp()));
+            last = Some(event.timestamp());
+            labels.push(event_label(&event));
+        }
+        assert_eq!(
+            labels,
+            vec!["t30", "c0", "t60", "t90", "c1", "t150", "c2", "t200"]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn next_event_drains_a_single_source() -> Result<()> {
+        let tmp = tempdir()?;
+        let start = DateTime::<Utc>::from_timestamp(1_700_000_000, 0).unwrap();
+        let paths = split_candle_files(tmp.path(), start)?;
+
+        let mut stream = ParquetMarketStream::with_candles(vec![Symbol::from("BTCUSDT")], paths);
+        let mut count = 0;
+        while let Some(event) = stream.next_event().await? {
+            assert!(matches!(event, MarketEvent::Candle(_)));
+            count += 1;
+        }
+        assert_eq!(count, 6);
+        Ok(())
+    }
+
     #[tokio::test]
     async fn replays_candles_from_parquet() -> Result<()> {
         let tmp = tempdir()?;
@@ -972,6 +1434,60 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn reads_legacy_candles_without_volume_column() -> Result<()> {
+        let tmp = tempdir()?;
+        let path = tmp.path().join("legacy.parquet");
+        let candles = sample_candles();
+        let mut batch = candles_to_batch(&candles)?;
+        let (volume_idx, _) = batch
+            .schema()
+            .column_with_name("volume")
+            .expect("volume column");
+        batch.remove_column(volume_idx);
+        write_parquet_file(&path, &batch)?;
+
+        let mut stream =
+            ParquetMarketStream::with_candles(vec![Symbol::from("BTCUSDT")], vec![path]);
+        let candle = stream
+            .next_candle()
+            .await
+            .context("expected candle")?
+            .expect("candle available");
+        assert_eq!(candle.close, candles[0].close);
+        assert_eq!(candle.volume, Decimal::ZERO);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn ignores_unknown_candle_columns() -> Result<()> {
+        let tmp = tempdir()?;
+        let path = tmp.path().join("extended.parquet");
+        let candles = sample_candles();
+        let batch = candles_to_batch(&candles)?;
+        let mut fields = batch.schema().fields().to_vec();
+        fields.push(Arc::new(Field::new("venue", DataType::Utf8, true)));
+        let mut venues = StringBuilder::new();
+        for _ in 0..batch.num_rows() {
+            venues.append_value("bybit");
+        }
+        let mut columns = batch.columns().to_vec();
+        columns.push(Arc::new(venues.finish()));
+        let extended = RecordBatch::try_new(Arc::new(Schema::new(fields)), columns)?;
+        write_parquet_file(&path, &extended)?;
+
+        let mut stream =
+            ParquetMarketStream::with_candles(vec![Symbol::from("BTCUSDT")], vec![path]);
+        let candle = stream
+            .next_candle()
+            .await
+            .context("expected candle")?
+            .expect("candle available");
+        assert_eq!(candle.volume, candles[0].volume);
+        assert_eq!(candle.close, candles[0].close);
+        Ok(())
+    }
+
     #[tokio::test]
     async fn replays_ticks_from_parquet() -> Result<()> {
         let tmp = tempdir()?;
@@ -1041,4 +1557,85 @@ mod tests {
         assert_eq!(book.asks[0].price, Decimal::new(19_910, 0));
         Ok(())
     }
+
+    /// Offsets on the paused tokio clock at which each tick is delivered by a stream
+    /// replaying at `speed`.
+    ///
+    /// Sleeping tasks only wake when the test runtime advances the clock to their deadline,
+    /// so the offsets are exact rather than subject to scheduler jitter.
+    async fn replay_offsets(speed: f64) -> Result<Vec<std::time::Duration>> {
+        let tmp = tempdir()?;
+        let path = tmp.path().join("ticks.parquet");
+        let start = DateTime::<Utc>::from_timestamp(1_700_000_000, 0).unwrap();
+        let mut ticks = ticks_at(start, &[0, 0, 0]);
+        for (tick, millis) in ticks.iter_mut().zip([0, 150, 300]) {
+            tick.exchange_timestamp = start + chrono::Duration::milliseconds(millis);
+        }
+        write_parquet_file(&path, &ticks_to_batch(&ticks)?)?;
+
+        let mut stream = ParquetMarketStream::new(
+            vec![Symbol::from("BTCUSDT")],
+            vec![path],
+            Vec::new(),
+            Vec::new(),
+            Vec::new(),
+        )
+        .with_replay_speed(speed);
+        let mut offsets = Vec::new();
+        let mut first = None;
+        while stream.next_tick().await?.is_some() {
+            let now = Instant::now();
+            offsets.push(now - *first.get_or_insert(now));
+        }
+        Ok(offsets)
+    }
+
+    /// Tokio timers fire on millisecond boundaries, so a delivery may trail its scaled
+    /// offset by up to one millisecond but never precede it.
+    fn assert_offsets(offsets: &[std::time::Duration], expected_micros: &[u64]) {
+        assert_eq!(offsets.len(), expected_micros.len(), "{offsets:?}");
+        for (offset, micros) in offsets.iter().zip(expected_micros) {
+            let expected = std::time::Duration::from_micros(*micros);
+            assert!(*offset >= expected, "{offsets:?}");
+            assert!(
+                *offset <= expected + std::time::Duration::from_millis(1),
+                "{offsets:?}"
+            );
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn real_time_replay_follows_source_spacing() -> Result<()> {
+        let offsets = replay_offsets(1.0).await?;
+        assert_offsets(&offsets, &[0, 150_000, 300_000]);
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn faster_replay_compresses_gaps() -> Result<()> {
+        let offsets = replay_offsets(100.0).await?;
+        assert_offsets(&offsets, &[0, 1_500, 3_000]);
+
+        let unpaced = replay_offsets(0.0).await?;
+        assert_eq!(unpaced, vec![std::time::Duration::ZERO; 3]);
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn pacer_holds_events_until_their_scaled_offset() {
+        let start = DateTime::<Utc>::from_timestamp(1_700_000_000, 0).unwrap();
+        let mut pacer = ReplayPacer {
+            speed: 2.0,
+            anchor: None,
+        };
+        pacer.wait(start).await;
+
+        let wait = pacer.wait(start + chrono::Duration::milliseconds(300));
+        tokio::pin!(wait);
+        assert!(futures::poll!(&mut wait).is_pending());
+        tokio::time::advance(std::time::Duration::from_millis(149)).await;
+        assert!(futures::poll!(&mut wait).is_pending());
+        tokio::time::advance(std::time::Duration::from_millis(1)).await;
+        assert!(futures::poll!(&mut wait).is_ready());
+    }
 }
@@ -1,13 +1,13 @@
 use std::cmp::Ordering;
 use std::collections::hash_map::Entry;
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::mem;
 
 use chrono::{DateTime, Utc};
 use futures::StreamExt;
 use rust_decimal::Decimal;
 
-use tesser_core::{Candle, Interval, Symbol};
+use tesser_core::{Candle, CandleStatus, Interval, Symbol, Tick};
 
 /// Resamples a chronologically ordered candle stream into coarser intervals.
 ///
@@ -123,6 +123,179 @@ impl Resampler {
     }
 }
 
+/// Aggregates live ticks into per-symbol candles, closing a bar at each interval boundary.
+///
+/// Useful for venues that only stream trades: feed every tick through [`CandleBuilder::push_tick`]
+/// and forward the completed candles it returns.
+pub struct CandleBuilder {
+    interval: Interval,
+    active: HashMap<Symbol, Bucket>,
+}
+
+impl CandleBuilder {
+    /// Create a builder emitting candles of the provided [`Interval`].
+    pub fn new(interval: Interval) -> Self {
+        Self {
+            interval,
+            active: HashMap::new(),
+        }
+    }
+
+    /// Interval of the candles produced by this builder.
+    pub fn interval(&self) -> Interval {
+        self.interval
+    }
+
+    /// Ingest one tick, returning the previous candle for its symbol once the tick crosses into
+    /// a new interval. Ticks older than the active bucket are ignored.
+    pub fn push_tick(&mut self, tick: &Tick) -> Option<Candle> {
+        let start = self.interval.align_down(tick.exchange_timestamp);
+        match self.active.entry(tick.symbol) {
+            Entry::Vacant(slot) => {
+                slot.insert(Bucket::from_tick(start, self.interval, tick));
+                None
+            }
+            Entry::Occupied(mut slot) => {
+                let entry = slot.get_mut();
+                match start.cmp(&entry.start) {
+                    Ordering::Less => None,
+                    Ordering::Equal => {
+                        entry.update_tick(tick);
+                        None
+                    }
+                    Ordering::Greater => {
+                        let finished =
+                            mem::replace(entry, Bucket::from_tick(start, self.interval, tick));
+                        Some(finished.into_candle())
+                    }
+                }
+            }
+        }
+    }
+
+    /// Snapshot of the in-progress candle for `symbol`, if any tick has been seen.
+    ///
+    /// The snapshot is marked [`CandleStatus::Forming`]; candles returned by
+    /// [`CandleBuilder::push_tick`] are closed.
+    pub fn partial(&self, symbol: Symbol) -> Option<Candle> {
+        self.active.get(&symbol).cloned().map(|bucket| Candle {
+            status: CandleStatus::Forming,
+            ..bucket.into_candle()
+        })
+    }
+
+    /// Consume the builder, returning every in-progress candle as closed since no further
+    /// ticks will arrive.
+    pub fn finish(self) -> Vec<Candle> {
+        self.active.into_values().map(Bucket::into_candle).collect()
+    }
+}
+
+/// Reason a tick was dropped by [`TickSanitizer`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TickRejection {
+    /// The tick reported a zero or negative trade size.
+    NonPositiveSize,
+    /// The tick price strayed beyond the allowed distance from the rolling median.
+    PriceDeviation,
+}
+
+impl TickRejection {
+    /// Stable label used for metrics and logs.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::NonPositiveSize => "non_positive_size",
+            Self::PriceDeviation => "price_deviation",
+        }
+    }
+}
+
+/// Screens live ticks for fat-finger prices and empty trades before they reach strategies.
+///
+/// Each symbol keeps a window of its most recent prices. A tick is rejected when its size is not
+/// positive or its price deviates from the window median by more than `max_deviation_pct`
+/// percent. Rejected prices still enter the window, so a single spike barely moves the median
+/// while a genuine level shift is adopted once it fills half of the window.
+pub struct TickSanitizer {
+    max_deviation_pct: Decimal,
+    window: usize,
+    prices: HashMap<Symbol, PriceWindow>,
+}
+
+impl TickSanitizer {
+    /// Create a sanitizer tracking `window` prices per symbol (at least one).
+    pub fn new(max_deviation_pct: Decimal, window: usize) -> Self {
+        Self {
+            max_deviation_pct: max_deviation_pct.abs(),
+            window: window.max(1),
+            prices: HashMap::new(),
+        }
+    }
+
+    /// Check one tick, returning the rejection reason when it should be dropped.
+    pub fn check(&mut self, tick: &Tick) -> Result<(), TickRejection> {
+        if tick.size <= Decimal::ZERO {
+            return Err(TickRejection::NonPositiveSize);
+        }
+        let window = self.window;
+        let prices = self.prices.entry(tick.symbol).or_default();
+        let verdict = match prices.median() {
+            Some(median) if median > Decimal::ZERO => {
+                let deviation = (tick.price - median).abs() / median * Decimal::ONE_HUNDRED;
+                if deviation > self.max_deviation_pct {
+                    Err(TickRejection::PriceDeviation)
+                } else {
+                    Ok(())
+                }
+            }
+            _ => Ok(()),
+        };
+        prices.push(tick.price, window);
+        verdict
+    }
+
+    /// Median of the prices currently tracked for `symbol`.
+    pub fn median(&self, symbol: Symbol) -> Option<Decimal> {
+        self.prices.get(&symbol).and_then(PriceWindow::median)
+    }
+}
+
+/// Most recent prices for one symbol, kept both in arrival order and sorted.
+#[derive(Default)]
+struct PriceWindow {
+    arrivals: VecDeque<Decimal>,
+    sorted: Vec<Decimal>,
+}
+
+impl PriceWindow {
+    fn push(&mut self, price: Decimal, window: usize) {
+        if self.arrivals.len() == window {
+            if let Some(oldest) = self.arrivals.pop_front() {
+                if let Ok(index) = self.sorted.binary_search(&oldest) {
+                    self.sorted.remove(index);
+                }
+            }
+        }
+        self.arrivals.push_back(price);
+        let index = self.sorted.partition_point(|existing| *existing < price);
+        self.sorted.insert(index, price);
+    }
+
+    fn median(&self) -> Option<Decimal> {
+        let sorted = &self.sorted;
+        if sorted.is_empty() {
+            return None;
+        }
+        let mid = sorted.len() / 2;
+        if sorted.len() % 2 == 0 {
+            Some((sorted[mid - 1] + sorted[mid]) / Decimal::TWO)
+        } else {
+            Some(sorted[mid])
+        }
+    }
+}
+
+#[derive(Clone)]
 struct Bucket {
     symbol: Symbol,
     interval: Interval,
@@ -153,6 +326,26 @@ impl Bucket {
         }
     }
 
+    fn from_tick(start: DateTime<Utc>, interval: Interval, tick: &Tick) -> Self {
+        Self {
+            symbol: tick.symbol,
+            interval,
+            start,
+            open: tick.price,
+            high: tick.price,
+            low: tick.price,
+            close: tick.price,
+            volume: tick.size,
+        }
+    }
+
+    fn update_tick(&mut self, tick: &Tick) {
+        self.high = self.high.max(tick.price);
+        self.low = self.low.min(tick.price);
+        self.close = tick.price;
+        self.volume += tick.size;
+    }
+
     fn update(&mut self, candle: &Candle) {
         if candle.high > self.high {
             self.high = candle.high;
@@ -174,6 +367,7 @@ impl Bucket {
             close: self.close,
             volume: self.volume,
             timestamp: self.start,
+            status: CandleStatus::Closed,
         }
     }
 }
@@ -189,11 +383,119 @@ fn align_timestamp(ts: DateTime<Utc>, step_nanos: i64) -> DateTime<Utc> {
         .expect("aligned timestamp within chrono supported range")
 }
 
+/// A candle returned by [`fill_candle_gaps`], tagged with whether it was synthesized.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FilledCandle {
+    pub candle: Candle,
+    /// `true` for flat placeholder bars inserted into a gap.
+    pub synthetic: bool,
+}
+
+/// Insert flat candles for every missing `interval` boundary between consecutive bars of
+/// the same symbol.
+///
+/// Each placeholder repeats the prior close as OHLC with zero volume. Input must be sorted
+/// by timestamp; real candles are passed through unchanged.
+pub fn fill_candle_gaps(candles: &[Candle], interval: Interval) -> Vec<FilledCandle> {
+    let step = interval.as_duration();
+    let mut last_seen: HashMap<Symbol, &Candle> = HashMap::new();
+    let mut output = Vec::with_capacity(candles.len());
+    for candle in candles {
+        if let Some(prev) = last_seen.get(&candle.symbol) {
+            if let Some(gap) = gap_between(prev.timestamp, candle.timestamp, step) {
+                let mut next = gap.first;
+                while next <= gap.last {
+                    output.push(FilledCandle {
+                        candle: Candle {
+                            symbol: candle.symbol,
+                            interval,
+                            open: prev.close,
+                            high: prev.close,
+                            low: prev.close,
+                            close: prev.close,
+                            volume: Decimal::ZERO,
+                            timestamp: next,
+                            status: CandleStatus::Closed,
+                        },
+                        synthetic: true,
+                    });
+                    next += step;
+                }
+            }
+        }
+        last_seen.insert(candle.symbol, candle);
+        output.push(FilledCandle {
+            candle: candle.clone(),
+            synthetic: false,
+        });
+    }
+    output
+}
+
+/// A run of consecutive missing bars, identified by the first and last absent bar open times.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CandleGap {
+    pub first: DateTime<Utc>,
+    pub last: DateTime<Utc>,
+}
+
+/// The bars expected strictly between a bar opening at `prev` and the instant `next`.
+fn gap_between(
+    prev: DateTime<Utc>,
+    next: DateTime<Utc>,
+    step: chrono::Duration,
+) -> Option<CandleGap> {
+    let first = prev + step;
+    if first >= next {
+        return None;
+    }
+    let step_nanos = step.num_nanoseconds().unwrap_or(i64::MAX).max(1);
+    let span = (next - first).num_nanoseconds().unwrap_or(i64::MAX);
+    let bars = (span - 1) / step_nanos;
+    Some(CandleGap {
+        first,
+        last: first + chrono::Duration::nanoseconds(bars * step_nanos),
+    })
+}
+
+/// Find runs of `interval` bars in `[start, end)` whose open time is absent from `present`.
+///
+/// Expected bars sit on interval boundaries, so `start` is rounded up to the next boundary and
+/// off-grid entries in `present` are ignored. Uses the same detection as [`fill_candle_gaps`],
+/// with the bar before `start` and the instant `end` standing in for the outer neighbours.
+pub fn missing_candle_ranges(
+    present: &[DateTime<Utc>],
+    interval: Interval,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> Vec<CandleGap> {
+    let step = interval.as_duration();
+    let step_nanos = step.num_nanoseconds().unwrap_or(i64::MAX).max(1);
+    let mut first = align_timestamp(start, step_nanos);
+    if first < start {
+        first += step;
+    }
+    let mut bars: Vec<DateTime<Utc>> = present
+        .iter()
+        .copied()
+        .filter(|ts| (first..end).contains(ts) && align_timestamp(*ts, step_nanos) == *ts)
+        .collect();
+    bars.sort();
+    bars.dedup();
+    let mut gaps = Vec::new();
+    let mut prev = first - step;
+    for bar in bars.into_iter().chain(std::iter::once(end)) {
+        gaps.extend(gap_between(prev, bar, step));
+        prev = bar;
+    }
+    gaps
+}
+
 #[cfg(test)]
 mod tests {
     use chrono::{Duration, TimeZone, Timelike, Utc};
     use rust_decimal::Decimal;
-    use tesser_core::Interval;
+    use tesser_core::{Interval, Side};
 
     use super::*;
 
@@ -208,6 +510,7 @@ mod tests {
             volume: Decimal::new(10, 0),
             timestamp: Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap()
                 + Duration::minutes(minute),
+            status: CandleStatus::Closed,
         }
     }
 
@@ -221,6 +524,84 @@ mod tests {
         assert_eq!(resampled[1].close, candles[9].close);
     }
 
+    #[test]
+    fn fills_three_bar_gap_with_flat_candles() {
+        let candles = vec![candle_at(0, 100), candle_at(1, 101), candle_at(5, 105)];
+        let filled = fill_candle_gaps(&candles, Interval::OneMinute);
+        assert_eq!(filled.len(), 6);
+
+        let synthetic: Vec<_> = filled.iter().map(|entry| entry.synthetic).collect();
+        assert_eq!(synthetic, [false, false, true, true, true, false]);
+        for (offset, entry) in filled[2..5].iter().enumerate() {
+            assert_eq!(
+                entry.candle.timestamp,
+                candles[1].timestamp + Duration::minutes(offset as i64 + 1)
+            );
+            assert_eq!(entry.candle.open, Decimal::from(101));
+            assert_eq!(entry.candle.high, Decimal::from(101));
+            assert_eq!(entry.candle.low, Decimal::from(101));
+            assert_eq!(entry.candle.close, Decimal::from(101));
+            assert!(entry.candle.volume.is_zero());
+        }
+        assert_eq!(filled[0].candle, candles[0]);
+        assert_eq!(filled[1].candle, candles[1]);
+        assert_eq!(filled[5].candle, candles[2]);
+    }
+
+    #[test]
+    fn missing_candle_ranges_groups_consecutive_bars() {
+        let base = Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap();
+        let present: Vec<_> = [0, 1, 4, 5, 7]
+            .iter()
+            .map(|minute| base + Duration::minutes(*minute))
+            .collect();
+        let gaps = missing_candle_ranges(
+            &present,
+            Interval::OneMinute,
+            base,
+            base + Duration::minutes(10),
+        );
+        assert_eq!(
+            gaps,
+            vec![
+                CandleGap {
+                    first: base + Duration::minutes(2),
+                    last: base + Duration::minutes(3),
+                },
+                CandleGap {
+                    first: base + Duration::minutes(6),
+                    last: base + Duration::minutes(6),
+                },
+                CandleGap {
+                    first: base + Duration::minutes(8),
+                    last: base + Duration::minutes(9),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn missing_candle_ranges_rounds_start_to_the_next_boundary() {
+        let base = Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap();
+        let present = vec![base + Duration::minutes(1)];
+        let gaps = missing_candle_ranges(
+            &present,
+            Interval::OneMinute,
+            base + Duration::seconds(30),
+            base + Duration::minutes(2),
+        );
+        assert!(gaps.is_empty());
+    }
+
+    #[test]
+    fn fill_candle_gaps_tracks_symbols_independently() {
+        let mut eth = candle_at(1, 50);
+        eth.symbol = "ETHUSDT".into();
+        let candles = vec![candle_at(0, 100), eth, candle_at(1, 101)];
+        let filled = fill_candle_gaps(&candles, Interval::OneMinute);
+        assert!(filled.iter().all(|entry| !entry.synthetic));
+    }
+
     #[test]
     fn resamples_vec() {
         let candles: Vec<_> = (0..10).map(|idx| candle_at(idx, idx)).collect();
@@ -229,4 +610,92 @@ mod tests {
         assert_eq!(resampled[0].timestamp.minute(), 0);
         assert_eq!(resampled[1].timestamp.minute(), 5);
     }
+
+    fn tick_at(seconds: i64, price: i64, size: i64) -> Tick {
+        let timestamp =
+            Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap() + Duration::seconds(seconds);
+        Tick {
+            symbol: "BTCUSDT".into(),
+            price: Decimal::new(price, 0),
+            size: Decimal::new(size, 0),
+            side: Side::Buy,
+            exchange_timestamp: timestamp,
+            received_at: timestamp,
+        }
+    }
+
+    #[test]
+    fn candle_builder_emits_ohlcv_at_interval_boundary() {
+        let mut builder = CandleBuilder::new(Interval::OneMinute);
+        assert!(builder.push_tick(&tick_at(5, 100, 1)).is_none());
+        assert!(builder.push_tick(&tick_at(20, 104, 2)).is_none());
+        assert!(builder.push_tick(&tick_at(40, 98, 1)).is_none());
+        assert!(builder.push_tick(&tick_at(59, 101, 3)).is_none());
+
+        let candle = builder
+            .push_tick(&tick_at(61, 102, 1))
+            .expect("boundary crossing closes the first minute");
+        assert_eq!(candle.interval, Interval::OneMinute);
+        assert_eq!(candle.timestamp.minute(), 0);
+        assert_eq!(candle.open, Decimal::new(100, 0));
+        assert_eq!(candle.high, Decimal::new(104, 0));
+        assert_eq!(candle.low, Decimal::new(98, 0));
+        assert_eq!(candle.close, Decimal::new(101, 0));
+        assert_eq!(candle.volume, Decimal::new(7, 0));
+        assert!(candle.is_closed());
+
+        let partial = builder.partial("BTCUSDT".into()).expect("partial bar");
+        assert_eq!(partial.status, CandleStatus::Forming);
+        assert_eq!(partial.timestamp.minute(), 1);
+        assert_eq!(partial.open, Decimal::new(102, 0));
+        assert_eq!(partial.volume, Decimal::ONE);
+    }
+
+    #[test]
+    fn candle_builder_ignores_stale_ticks() {
+        let mut builder = CandleBuilder::new(Interval::OneMinute);
+        builder.push_tick(&tick_at(65, 100, 1));
+        assert!(builder.push_tick(&tick_at(30, 50, 1)).is_none());
+        let partial = builder.partial("BTCUSDT".into()).unwrap();
+        assert_eq!(partial.low, Decimal::new(100, 0));
+        assert!(builder.partial("ETHUSDT".into()).is_none());
+    }
+
+    #[test]
+    fn tick_sanitizer_rejects_outliers_and_empty_trades() {
+        let mut sanitizer = TickSanitizer::new(Decimal::new(5, 0), 10);
+        for (second, price) in [(0, 100), (1, 101), (2, 99), (3, 100)] {
+            assert_eq!(sanitizer.check(&tick_at(second, price, 1)), Ok(()));
+        }
+        assert_eq!(
+            sanitizer.check(&tick_at(4, 1_000, 1)),
+            Err(TickRejection::PriceDeviation)
+        );
+        assert_eq!(
+            sanitizer.check(&tick_at(5, 100, 0)),
+            Err(TickRejection::NonPositiveSize)
+        );
+        assert_eq!(sanitizer.check(&tick_at(6, 102, 1)), Ok(()));
+        assert_eq!(
+            sanitizer.median("BTCUSDT".into()),
+            Some(Decimal::new(1005, 1))
+        );
+    }
+
+    #[test]
+    fn tick_sanitizer_median_follows_a_level_shift() {
+        let mut sanitizer = TickSanitizer::new(Decimal::new(5, 0), 5);
+        for second in 0..5 {
+            assert_eq!(sanitizer.check(&tick_at(second, 100, 1)), Ok(()));
+        }
+        let shifted: Vec<_> = (5..10)
+            .map(|second| sanitizer.check(&tick_at(second, 120, 1)))
+            .collect();
+        assert_eq!(shifted[0], Err(TickRejection::PriceDeviation));
+        assert_eq!(shifted[4], Ok(()));
+        assert_eq!(
+            sanitizer.median("BTCUSDT".into()),
+            Some(Decimal::new(120, 0))
+        );
+    }
 }
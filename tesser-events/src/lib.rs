@@ -1,6 +1,21 @@
+mod wal;
+
+use std::collections::HashSet;
+use std::io;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{mpsc, Arc, Mutex};
+use std::task::{ready, Context, Poll};
+use std::thread::JoinHandle;
+
+use futures::{Stream, StreamExt};
 use serde::{Deserialize, Serialize};
-use tesser_core::{Candle, Fill, Order, OrderBook, Signal, Tick};
+use tesser_core::{Candle, Fill, Order, OrderBook, OrderId, Signal, Symbol, Tick};
 use tokio::sync::broadcast;
+use tokio::sync::broadcast::error::RecvError;
+use tokio_stream::wrappers::{errors::BroadcastStreamRecvError, BroadcastStream};
+
+pub use wal::{EventLog, EventLogConfig, LoggedEvent};
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct TickEvent {
@@ -42,33 +57,602 @@ pub enum Event {
     OrderUpdate(OrderUpdateEvent),
 }
 
+impl Event {
+    /// Instrument the event refers to.
+    pub fn symbol(&self) -> Symbol {
+        match self {
+            Self::Tick(evt) => evt.tick.symbol,
+            Self::Candle(evt) => evt.candle.symbol,
+            Self::OrderBook(evt) => evt.order_book.symbol,
+            Self::Signal(evt) => evt.signal.symbol,
+            Self::Fill(evt) => evt.fill.symbol,
+            Self::OrderUpdate(evt) => evt.order.request.symbol,
+        }
+    }
+}
+
+/// Criteria selecting the events yielded by [`EventBus::subscribe_filtered`].
+///
+/// Criteria are combined with AND; a default filter lets every event through.
+#[derive(Clone, Debug, Default)]
+pub struct EventFilter {
+    symbols: HashSet<Symbol>,
+    algo_id: Option<String>,
+    algo_orders: HashSet<OrderId>,
+}
+
+impl EventFilter {
+    /// Only pass events for one of `symbols`. An empty set leaves symbols unrestricted.
+    ///
+    /// Symbols without an exchange match that market code on every exchange.
+    #[must_use]
+    pub fn with_symbols(mut self, symbols: impl IntoIterator<Item = Symbol>) -> Self {
+        self.symbols = symbols.into_iter().collect();
+        self
+    }
+
+    /// Only pass order updates and fills of the execution algorithm `algo_id`.
+    ///
+    /// Child orders are recognised by the algorithm id embedded in their client order id;
+    /// fills match once an update for their order has been seen.
+    #[must_use]
+    pub fn with_algo(mut self, algo_id: impl Into<String>) -> Self {
+        self.algo_id = Some(algo_id.into());
+        self
+    }
+
+    /// Whether `event` passes the filter, remembering algorithm orders for later fills.
+    pub fn matches(&mut self, event: &Event) -> bool {
+        if !self.symbols.is_empty() && !self.matches_symbol(event.symbol()) {
+            return false;
+        }
+        let Some(algo_id) = &self.algo_id else {
+            return true;
+        };
+        match event {
+            Event::OrderUpdate(evt) => {
+                let owned = evt
+                    .order
+                    .request
+                    .client_order_id
+                    .as_deref()
+                    .is_some_and(|client_id| client_id.contains(algo_id.as_str()));
+                if owned {
+                    self.algo_orders.insert(evt.order.id.clone());
+                }
+                owned
+            }
+            Event::Fill(evt) => self.algo_orders.contains(&evt.fill.order_id),
+            _ => false,
+        }
+    }
+
+    fn matches_symbol(&self, symbol: Symbol) -> bool {
+        self.symbols.iter().any(|wanted| {
+            *wanted == symbol
+                || (!wanted.exchange().is_specified() && wanted.code() == symbol.code())
+        })
+    }
+}
+
 pub struct EventBus {
     sender: broadcast::Sender<Event>,
+    log: Option<LogWriter>,
 }
 
 impl EventBus {
     pub fn new(capacity: usize) -> Self {
         let (sender, _) = broadcast::channel(capacity);
-        Self { sender }
+        Self { sender, log: None }
+    }
+
+    /// Build a bus that also persists every published event to `log`.
+    ///
+    /// Appends run on a dedicated writer thread, so publishing never waits on disk.
+    pub fn with_log(capacity: usize, log: EventLog) -> io::Result<Self> {
+        let (sender, _) = broadcast::channel(capacity);
+        Ok(Self {
+            sender,
+            log: Some(LogWriter::spawn(log)?),
+        })
     }
 
     pub fn subscribe(&self) -> EventStream {
-        EventStream {
-            receiver: self.sender.subscribe(),
+        EventStream::new(self.sender.subscribe())
+    }
+
+    /// Subscribe to the events matching `filter`; everything else is skipped.
+    pub fn subscribe_filtered(&self, filter: EventFilter) -> FilteredEventStream {
+        FilteredEventStream {
+            inner: self.subscribe(),
+            filter,
         }
     }
 
     pub fn publish(&self, event: Event) {
+        if let Some(log) = &self.log {
+            log.append(&event);
+        }
         let _ = self.sender.send(event);
     }
+
+    /// Sequence number the next published event will be logged under, if a log is attached.
+    pub fn next_seq(&self) -> Option<u64> {
+        self.log
+            .as_ref()
+            .map(|log| log.next_seq.load(Ordering::SeqCst))
+    }
+
+    /// Read back persisted events numbered `from_seq` onwards, oldest first.
+    ///
+    /// Replayed events are delivered on their own stream rather than re-broadcast, so live
+    /// subscribers never see an event twice. Waits for queued appends to reach the log first;
+    /// a bus without a log replays nothing.
+    pub fn replay(&self, from_seq: u64) -> io::Result<EventReplay> {
+        let records = match &self.log {
+            Some(log) => log.read_from(from_seq)?,
+            None => Vec::new(),
+        };
+        Ok(futures::stream::iter(records))
+    }
 }
 
+/// Persisted events returned by [`EventBus::replay`].
+pub type EventReplay = futures::stream::Iter<std::vec::IntoIter<LoggedEvent>>;
+
+enum LogCommand {
+    Append(Box<Event>),
+    Flush(mpsc::SyncSender<()>),
+}
+
+/// Queue feeding the thread that owns appends to an [`EventLog`].
+struct LogWriter {
+    queue: Option<mpsc::Sender<LogCommand>>,
+    log: Arc<Mutex<EventLog>>,
+    next_seq: AtomicU64,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl LogWriter {
+    fn spawn(log: EventLog) -> io::Result<Self> {
+        let next_seq = AtomicU64::new(log.next_seq());
+        let log = Arc::new(Mutex::new(log));
+        let (queue, commands) = mpsc::channel();
+        let writer_log = log.clone();
+        let thread = std::thread::Builder::new()
+            .name("event-log-writer".into())
+            .spawn(move || {
+                for command in commands {
+                    match command {
+                        LogCommand::Append(event) => {
+                            let mut log = writer_log
+                                .lock()
+                                .unwrap_or_else(|poisoned| poisoned.into_inner());
+                            if let Err(err) = log.append(&event) {
+                                tracing::warn!(error = %err, "failed to persist event to the event log");
+                            }
+                        }
+                        LogCommand::Flush(done) => {
+                            let _ = done.send(());
+                        }
+                    }
+                }
+            })?;
+        Ok(Self {
+            queue: Some(queue),
+            log,
+            next_seq,
+            thread: Some(thread),
+        })
+    }
+
+    fn append(&self, event: &Event) {
+        if let Some(queue) = &self.queue {
+            self.next_seq.fetch_add(1, Ordering::SeqCst);
+            let _ = queue.send(LogCommand::Append(Box::new(event.clone())));
+        }
+    }
+
+    fn read_from(&self, from_seq: u64) -> io::Result<Vec<LoggedEvent>> {
+        if let Some(queue) = &self.queue {
+            let (done, flushed) = mpsc::sync_channel(1);
+            if queue.send(LogCommand::Flush(done)).is_ok() {
+                let _ = flushed.recv();
+            }
+        }
+        self.log
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .read_from(from_seq)
+    }
+}
+
+impl Drop for LogWriter {
+    /// Drain queued appends so a clean shutdown leaves every published event on disk.
+    fn drop(&mut self) {
+        self.queue.take();
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// Subscription to an [`EventBus`], usable directly or as a [`Stream`].
+///
+/// As a stream it yields `Err(RecvError::Lagged)` when events were dropped and ends once
+/// the bus is closed.
 pub struct EventStream {
-    receiver: broadcast::Receiver<Event>,
+    inner: BroadcastStream<Event>,
 }
 
 impl EventStream {
-    pub async fn recv(&mut self) -> Result<Event, broadcast::error::RecvError> {
-        self.receiver.recv().await
+    fn new(receiver: broadcast::Receiver<Event>) -> Self {
+        Self {
+            inner: BroadcastStream::new(receiver),
+        }
+    }
+
+    pub async fn recv(&mut self) -> Result<Event, RecvError> {
+        self.next().await.unwrap_or(Err(RecvError::Closed))
+    }
+}
+
+impl Stream for EventStream {
+    type Item = Result<Event, RecvError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Pin::new(&mut self.inner).poll_next(cx).map(|item| {
+            item.map(|result| {
+                result
+                    .map_err(|BroadcastStreamRecvError::Lagged(skipped)| RecvError::Lagged(skipped))
+            })
+        })
+    }
+}
+
+/// [`EventStream`] restricted to the events accepted by an [`EventFilter`].
+pub struct FilteredEventStream {
+    inner: EventStream,
+    filter: EventFilter,
+}
+
+impl FilteredEventStream {
+    pub async fn recv(&mut self) -> Result<Event, RecvError> {
+        self.next().await.unwrap_or(Err(RecvError::Closed))
+    }
+}
+
+impl Stream for FilteredEventStream {
+    type Item = Result<Event, RecvError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            match ready!(Pin::new(&mut self.inner).poll_next(cx)) {
+                Some(Ok(event)) if !self.filter.matches(&event) => continue,
+                item => return Poll::Ready(item),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use futures::{FutureExt, StreamExt};
+    use rust_decimal::Decimal;
+    use tesser_core::{OrderRequest, OrderStatus, OrderType, Side, SignalKind, Symbol};
+
+    use super::*;
+
+    fn tick_event(price: i64) -> Event {
+        let now = chrono::Utc::now();
+        Event::Tick(TickEvent {
+            tick: Tick {
+                symbol: Symbol::from("BTCUSDT"),
+                price: Decimal::from(price),
+                size: Decimal::ONE,
+                side: Side::Buy,
+                exchange_timestamp: now,
+                received_at: now,
+            },
+        })
+    }
+
+    fn signal_event() -> Event {
+        Event::Signal(SignalEvent {
+            signal: Signal::new("BTCUSDT", SignalKind::EnterLong, 1.0),
+        })
+    }
+
+    fn tick_price(event: &Event) -> Option<Decimal> {
+        match event {
+            Event::Tick(tick) => Some(tick.tick.price),
+            _ => None,
+        }
+    }
+
+    #[tokio::test]
+    async fn stream_filters_events_with_combinators() {
+        let bus = EventBus::new(16);
+        let stream = bus.subscribe();
+        bus.publish(tick_event(1));
+        bus.publish(signal_event());
+        bus.publish(tick_event(2));
+        bus.publish(tick_event(3));
+
+        let prices: Vec<_> = stream
+            .filter_map(|event| async move { event.ok().as_ref().and_then(tick_price) })
+            .filter(|price| futures::future::ready(*price > Decimal::ONE))
+            .take(2)
+            .collect()
+            .await;
+        assert_eq!(prices, vec![Decimal::from(2), Decimal::from(3)]);
+    }
+
+    #[tokio::test]
+    async fn take_until_stops_at_the_shutdown_signal() {
+        let bus = EventBus::new(16);
+        let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel::<()>();
+        let stream = bus.subscribe().take_until(shutdown_rx);
+        bus.publish(tick_event(1));
+        bus.publish(tick_event(2));
+
+        let collector = tokio::spawn(stream.collect::<Vec<_>>());
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        shutdown_tx.send(()).unwrap();
+        bus.publish(tick_event(3));
+
+        let events = collector.await.unwrap();
+        assert_eq!(events.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn stream_ends_when_the_bus_is_dropped() {
+        let bus = EventBus::new(4);
+        let stream = bus.subscribe();
+        bus.publish(tick_event(1));
+        drop(bus);
+        let events: Vec<_> = stream.collect().await;
+        assert_eq!(events.len(), 1);
+        assert!(events[0].is_ok());
+    }
+
+    #[tokio::test]
+    async fn lagged_subscribers_surface_the_error_and_resume() {
+        let bus = EventBus::new(2);
+        let mut stream = bus.subscribe();
+        for price in 1..=4 {
+            bus.publish(tick_event(price));
+        }
+        assert!(matches!(
+            stream.next().await,
+            Some(Err(RecvError::Lagged(2)))
+        ));
+        let next = stream.next().await.unwrap().unwrap();
+        assert_eq!(tick_price(&next), Some(Decimal::from(3)));
+    }
+
+    fn order_event(id: &str, symbol: &str, client_order_id: &str) -> Event {
+        let now = chrono::Utc::now();
+        Event::OrderUpdate(OrderUpdateEvent {
+            order: Order {
+                id: id.to_string(),
+                request: OrderRequest {
+                    symbol: Symbol::from(symbol),
+                    side: Side::Buy,
+                    order_type: OrderType::Market,
+                    quantity: Decimal::ONE,
+                    price: None,
+                    trigger_price: None,
+                    time_in_force: None,
+                    client_order_id: Some(client_order_id.to_string()),
+                    take_profit: None,
+                    stop_loss: None,
+                    display_quantity: None,
+                },
+                status: OrderStatus::Accepted,
+                filled_quantity: Decimal::ZERO,
+                avg_fill_price: None,
+                created_at: now,
+                updated_at: now,
+            },
+        })
+    }
+
+    fn fill_event(order_id: &str, symbol: &str) -> Event {
+        Event::Fill(FillEvent {
+            fill: Fill {
+                order_id: order_id.to_string(),
+                symbol: Symbol::from(symbol),
+                side: Side::Buy,
+                fill_price: Decimal::ONE,
+                fill_quantity: Decimal::ONE,
+                fee: None,
+                fee_asset: None,
+                timestamp: chrono::Utc::now(),
+            },
+        })
+    }
+
+    #[tokio::test]
+    async fn symbol_filter_skips_other_instruments() {
+        let bus = EventBus::new(16);
+        let mut stream =
+            bus.subscribe_filtered(EventFilter::default().with_symbols([Symbol::from("ETHUSDT")]));
+        bus.publish(tick_event(1));
+        bus.publish(fill_event("a", "ETHUSDT"));
+        bus.publish(signal_event());
+        bus.publish(order_event("b", "ETHUSDT", "manual"));
+
+        let first = stream.recv().await.unwrap();
+        assert!(matches!(first, Event::Fill(_)));
+        let second = stream.recv().await.unwrap();
+        assert!(matches!(second, Event::OrderUpdate(_)));
+        assert!(stream.next().now_or_never().is_none());
+    }
+
+    #[tokio::test]
+    async fn algo_filter_follows_child_orders_and_their_fills() {
+        let bus = EventBus::new(16);
+        let algo = "5f0c6a2e-8a57-4c1e-9d0e-3b7c2f1a9e44";
+        let mut stream = bus.subscribe_filtered(EventFilter::default().with_algo(algo));
+        bus.publish(tick_event(1));
+        bus.publish(order_event("other", "BTCUSDT", "manual"));
+        bus.publish(fill_event("other", "BTCUSDT"));
+        bus.publish(order_event(
+            "child",
+            "BTCUSDT",
+            &format!("twap-{algo}-slice-0"),
+        ));
+        bus.publish(fill_event("child", "BTCUSDT"));
+
+        let events: Vec<_> = std::iter::from_fn(|| stream.next().now_or_never().flatten())
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(events.len(), 2);
+        assert!(matches!(&events[0], Event::OrderUpdate(evt) if evt.order.id == "child"));
+        assert!(matches!(&events[1], Event::Fill(evt) if evt.fill.order_id == "child"));
+    }
+
+    #[tokio::test]
+    async fn idle_stream_times_out() {
+        let bus = EventBus::new(4);
+        let stream = tokio_stream::StreamExt::timeout(bus.subscribe(), Duration::from_millis(20));
+        tokio::pin!(stream);
+        assert!(stream.next().await.unwrap().is_err());
+
+        bus.publish(tick_event(7));
+        let event = stream.next().await.unwrap().expect("event before timeout");
+        assert_eq!(tick_price(&event.unwrap()), Some(Decimal::from(7)));
+    }
+
+    fn log_config(dir: &std::path::Path) -> EventLogConfig {
+        EventLogConfig {
+            dir: dir.to_path_buf(),
+            max_segment_events: 3,
+            max_segments: 3,
+        }
+    }
+
+    fn drain_prices(stream: &mut EventStream) -> Vec<Decimal> {
+        let mut prices = Vec::new();
+        while let Some(Some(Ok(event))) = stream.next().now_or_never() {
+            prices.extend(tick_price(&event));
+        }
+        prices
+    }
+
+    fn replayed_prices(bus: &EventBus, from_seq: u64) -> Vec<(u64, Decimal)> {
+        let replay = bus.replay(from_seq).unwrap();
+        futures::executor::block_on(replay.collect::<Vec<_>>())
+            .into_iter()
+            .filter_map(|record| Some((record.seq, tick_price(&record.event)?)))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn replay_after_restart_delivers_logged_events_in_order() {
+        let dir = tempfile::tempdir().unwrap();
+        {
+            let bus =
+                EventBus::with_log(16, EventLog::open(log_config(dir.path())).unwrap()).unwrap();
+            for price in 0..5 {
+                bus.publish(tick_event(price));
+            }
+            assert_eq!(bus.next_seq(), Some(5));
+        }
+
+        let bus = EventBus::with_log(16, EventLog::open(log_config(dir.path())).unwrap()).unwrap();
+        assert_eq!(bus.next_seq(), Some(5));
+        let mut stream = bus.subscribe();
+        assert_eq!(
+            replayed_prices(&bus, 2),
+            vec![
+                (2, Decimal::from(2)),
+                (3, Decimal::from(3)),
+                (4, Decimal::from(4))
+            ]
+        );
+        // Replays stay off the live stream, so subscribers never apply an event twice.
+        assert!(drain_prices(&mut stream).is_empty());
+
+        // New events continue the sequence and are readable as soon as they are published.
+        bus.publish(tick_event(5));
+        assert_eq!(bus.next_seq(), Some(6));
+        assert_eq!(drain_prices(&mut stream), vec![Decimal::from(5)]);
+        let replayed = replayed_prices(&bus, 0);
+        assert_eq!(
+            replayed,
+            (0..6)
+                .map(|price| (price, Decimal::from(price)))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn bus_without_a_log_replays_nothing() {
+        let bus = EventBus::new(4);
+        bus.publish(tick_event(1));
+        assert_eq!(bus.next_seq(), None);
+        assert!(replayed_prices(&bus, 0).is_empty());
+    }
+
+    #[tokio::test]
+    async fn event_log_rotation_drops_the_oldest_segments() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut log = EventLog::open(log_config(dir.path())).unwrap();
+        for price in 0..11 {
+            log.append(&tick_event(price)).unwrap();
+        }
+        let segments = std::fs::read_dir(dir.path()).unwrap().count();
+        assert_eq!(segments, 3);
+
+        let seqs: Vec<_> = log.read_from(0).unwrap().iter().map(|r| r.seq).collect();
+        assert_eq!(seqs, (3..11).collect::<Vec<_>>());
+        let seqs: Vec<_> = log.read_from(7).unwrap().iter().map(|r| r.seq).collect();
+        assert_eq!(seqs, vec![7, 8, 9, 10]);
+    }
+
+    #[test]
+    fn event_log_ignores_a_torn_trailing_record() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut log = EventLog::open(log_config(dir.path())).unwrap();
+        log.append(&tick_event(1)).unwrap();
+        log.append(&tick_event(2)).unwrap();
+        drop(log);
+        let segment = std::fs::read_dir(dir.path())
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap()
+            .path();
+        let mut file = std::fs::OpenOptions::new()
+            .append(true)
+            .open(segment)
+            .unwrap();
+        std::io::Write::write_all(&mut file, b"{\"seq\":2,\"ev").unwrap();
+
+        let mut log = EventLog::open(log_config(dir.path())).unwrap();
+        assert_eq!(log.next_seq(), 2);
+        assert_eq!(log.append(&tick_event(3)).unwrap(), 2);
+        let seqs: Vec<_> = log.read_from(0).unwrap().iter().map(|r| r.seq).collect();
+        assert_eq!(seqs, vec![0, 1, 2]);
+    }
+
+    #[tokio::test]
+    async fn recv_still_delivers_events_in_order() {
+        let bus = EventBus::new(4);
+        let mut stream = bus.subscribe();
+        bus.publish(tick_event(1));
+        bus.publish(tick_event(2));
+        assert_eq!(
+            tick_price(&stream.recv().await.unwrap()),
+            Some(Decimal::from(1))
+        );
+        assert_eq!(
+            tick_price(&stream.recv().await.unwrap()),
+            Some(Decimal::from(2))
+        );
     }
 }
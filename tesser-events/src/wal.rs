@@ -0,0 +1,204 @@
+//! Append-only event log used to recover in-flight events after a crash.
+//!
+//! Events are written as JSON lines into numbered segment files. Each segment holds at most
+//! [`EventLogConfig::max_segment_events`] records and only the newest
+//! [`EventLogConfig::max_segments`] segments are retained, so the log stays bounded.
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::Event;
+
+const SEGMENT_PREFIX: &str = "events-";
+const SEGMENT_SUFFIX: &str = ".jsonl";
+
+/// Location and retention limits of an [`EventLog`].
+#[derive(Clone, Debug)]
+pub struct EventLogConfig {
+    /// Directory holding the segment files; created on open.
+    pub dir: PathBuf,
+    /// Records written to a segment before rolling over to a new one.
+    pub max_segment_events: usize,
+    /// Segments kept on disk; the oldest are deleted once the limit is exceeded.
+    pub max_segments: usize,
+}
+
+impl EventLogConfig {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            max_segment_events: 10_000,
+            max_segments: 8,
+        }
+    }
+}
+
+/// Event persisted with the sequence number assigned at append time.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct LoggedEvent {
+    pub seq: u64,
+    pub event: Event,
+}
+
+struct Segment {
+    first_seq: u64,
+    path: PathBuf,
+}
+
+/// Write-ahead log of published [`Event`]s with segment rotation.
+pub struct EventLog {
+    config: EventLogConfig,
+    segments: Vec<Segment>,
+    writer: Option<BufWriter<File>>,
+    segment_events: usize,
+    next_seq: u64,
+}
+
+impl EventLog {
+    /// Open (or create) the log in `config.dir`, resuming sequence numbers after the last
+    /// persisted record.
+    pub fn open(config: EventLogConfig) -> io::Result<Self> {
+        fs::create_dir_all(&config.dir)?;
+        let mut segments = Vec::new();
+        for entry in fs::read_dir(&config.dir)? {
+            let path = entry?.path();
+            if let Some(first_seq) = segment_seq(&path) {
+                segments.push(Segment { first_seq, path });
+            }
+        }
+        segments.sort_by_key(|segment| segment.first_seq);
+
+        // Appends always go to a fresh segment: a crash may have torn the last line of the
+        // previous one.
+        let next_seq = match segments.last() {
+            Some(segment) => read_segment(&segment.path)?
+                .last()
+                .map(|record| record.seq + 1)
+                .unwrap_or(segment.first_seq),
+            None => 0,
+        };
+        Ok(Self {
+            config,
+            segments,
+            writer: None,
+            segment_events: 0,
+            next_seq,
+        })
+    }
+
+    /// Sequence number the next appended event will receive.
+    pub fn next_seq(&self) -> u64 {
+        self.next_seq
+    }
+
+    /// Persist `event`, returning its sequence number.
+    pub fn append(&mut self, event: &Event) -> io::Result<u64> {
+        let max_events = self.config.max_segment_events.max(1);
+        if self.writer.is_none() || self.segment_events >= max_events {
+            self.roll_segment()?;
+        }
+        let writer = self.writer.as_mut().expect("segment opened");
+        let seq = self.next_seq;
+        let record = LoggedEvent {
+            seq,
+            event: event.clone(),
+        };
+        serde_json::to_writer(&mut *writer, &record)?;
+        writer.write_all(b"\n")?;
+        writer.flush()?;
+        self.segment_events += 1;
+        self.next_seq += 1;
+        Ok(seq)
+    }
+
+    /// Retained events with a sequence number of at least `from_seq`, oldest first.
+    pub fn read_from(&self, from_seq: u64) -> io::Result<Vec<LoggedEvent>> {
+        let start = self
+            .segments
+            .iter()
+            .rposition(|segment| segment.first_seq <= from_seq)
+            .unwrap_or(0);
+        let mut events = Vec::new();
+        for segment in &self.segments[start..] {
+            events.extend(
+                read_segment(&segment.path)?
+                    .into_iter()
+                    .filter(|record| record.seq >= from_seq),
+            );
+        }
+        Ok(events)
+    }
+
+    fn roll_segment(&mut self) -> io::Result<()> {
+        if let Some(mut writer) = self.writer.take() {
+            writer.flush()?;
+        }
+        let path = self.config.dir.join(format!(
+            "{SEGMENT_PREFIX}{:020}{SEGMENT_SUFFIX}",
+            self.next_seq
+        ));
+        // A segment left behind by an earlier run can only share this name if it holds no
+        // complete record, so it is safe to truncate.
+        let file = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .truncate(true)
+            .open(&path)?;
+        self.writer = Some(BufWriter::new(file));
+        if self
+            .segments
+            .last()
+            .is_none_or(|segment| segment.first_seq != self.next_seq)
+        {
+            self.segments.push(Segment {
+                first_seq: self.next_seq,
+                path,
+            });
+        }
+        self.segment_events = 0;
+
+        let keep = self.config.max_segments.max(1);
+        while self.segments.len() > keep {
+            let expired = self.segments.remove(0);
+            if let Err(err) = fs::remove_file(&expired.path) {
+                if err.kind() != io::ErrorKind::NotFound {
+                    return Err(err);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn segment_seq(path: &Path) -> Option<u64> {
+    path.file_name()?
+        .to_str()?
+        .strip_prefix(SEGMENT_PREFIX)?
+        .strip_suffix(SEGMENT_SUFFIX)?
+        .parse()
+        .ok()
+}
+
+/// Decode a segment, stopping at a torn trailing line left by a crash mid-write.
+fn read_segment(path: &Path) -> io::Result<Vec<LoggedEvent>> {
+    let file = match File::open(path) {
+        Ok(file) => file,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err),
+    };
+    let mut records = Vec::new();
+    for line in BufReader::new(file).lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        match serde_json::from_str(&line) {
+            Ok(record) => records.push(record),
+            Err(_) => break,
+        }
+    }
+    Ok(records)
+}
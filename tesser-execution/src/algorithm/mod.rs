@@ -2,7 +2,7 @@
 
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
-use tesser_core::{Fill, Order, OrderRequest, OrderUpdateRequest, Tick};
+use tesser_core::{Fill, Order, OrderBook, OrderRequest, OrderStatus, OrderUpdateRequest, Tick};
 use uuid::Uuid;
 
 /// Actions generated by algorithms for managing their child orders.
@@ -12,6 +12,33 @@ pub enum ChildOrderAction {
     Place(OrderRequest),
     /// Amend an existing child order in-place.
     Amend(OrderUpdateRequest),
+    /// Amend the child order if its current status satisfies `condition`, otherwise cancel it.
+    ///
+    /// The orchestrator evaluates the condition against the last status it observed for the
+    /// order, so algorithms do not have to guess whether the order is still safe to modify.
+    ReplaceOrCancel {
+        update: OrderUpdateRequest,
+        condition: AmendCondition,
+    },
+}
+
+/// Order states under which a [`ChildOrderAction::ReplaceOrCancel`] amends instead of cancels.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum AmendCondition {
+    /// Amend only while nothing has been filled yet.
+    Unfilled,
+    /// Amend while the order is open, including after partial fills.
+    Working,
+}
+
+impl AmendCondition {
+    /// Whether an order in `status` may be amended under this condition.
+    pub fn permits(self, status: OrderStatus) -> bool {
+        match self {
+            Self::Unfilled => matches!(status, OrderStatus::PendingNew | OrderStatus::Accepted),
+            Self::Working => !status.is_terminal(),
+        }
+    }
 }
 
 /// Represents a child order request from an execution algorithm.
@@ -36,6 +63,16 @@ pub enum AlgoStatus {
     Failed(String),
 }
 
+/// Point-in-time view of a running algorithm used for monitoring.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct AlgorithmSnapshot {
+    pub id: Uuid,
+    pub kind: String,
+    pub status: AlgoStatus,
+    /// Serialized output of [`ExecutionAlgorithm::state`].
+    pub state: serde_json::Value,
+}
+
 /// Trait defining the behavior of an execution algorithm.
 ///
 /// Each execution algorithm is a stateful entity that responds to various events
@@ -60,6 +97,11 @@ pub trait ExecutionAlgorithm: Send + Sync {
     /// Called when a fill is received for one of this algorithm's child orders.
     fn on_fill(&mut self, fill: &Fill) -> Result<Vec<ChildOrderRequest>>;
 
+    /// Called when a child order is rejected, keyed by its client order id when one was set.
+    fn on_child_order_rejected(&mut self, _order_id: &str, _reason: &str) -> Result<()> {
+        Ok(())
+    }
+
     /// Bind a previously placed child order to the algorithm (used during recovery).
     fn bind_child_order(&mut self, _order: Order) -> Result<()> {
         Ok(())
@@ -68,6 +110,9 @@ pub trait ExecutionAlgorithm: Send + Sync {
     /// Called when market tick data is received (mainly for VWAP algorithms).
     fn on_tick(&mut self, tick: &Tick) -> Result<Vec<ChildOrderRequest>>;
 
+    /// Called with the latest order book snapshot so depth-aware algorithms can cache it.
+    fn on_order_book(&mut self, _book: &OrderBook) {}
+
     /// Called when a timer event occurs (mainly for TWAP algorithms).
     fn on_timer(&mut self) -> Result<Vec<ChildOrderRequest>>;
 
@@ -95,3 +140,61 @@ pub mod sniper;
 pub use sniper::SniperAlgorithm;
 pub mod trailing_stop;
 pub use trailing_stop::TrailingStopAlgorithm;
+
+/// Rebuilds a boxed algorithm from its persisted state.
+type AlgorithmRestorer = fn(serde_json::Value) -> Result<Box<dyn ExecutionAlgorithm>>;
+
+/// Built-in algorithms keyed by their [`ExecutionAlgorithm::kind`], sorted by kind.
+const BUILTIN_ALGORITHMS: &[(&str, AlgorithmRestorer)] = &[
+    ("ICEBERG", restore::<IcebergAlgorithm>),
+    ("PEGGED_BEST", restore::<PeggedBestAlgorithm>),
+    ("SNIPER", restore::<SniperAlgorithm>),
+    ("TRAILING_STOP", restore::<TrailingStopAlgorithm>),
+    ("TWAP", restore::<TwapAlgorithm>),
+    ("VWAP", restore::<VwapAlgorithm>),
+];
+
+fn restore<A: ExecutionAlgorithm + 'static>(
+    state: serde_json::Value,
+) -> Result<Box<dyn ExecutionAlgorithm>> {
+    Ok(Box::new(A::from_state(state)?))
+}
+
+/// Identifiers reported by [`ExecutionAlgorithm::kind`] for every built-in algorithm.
+///
+/// WASM plugins are listed separately since they are discovered at runtime.
+pub fn builtin_algorithm_names() -> Vec<&'static str> {
+    BUILTIN_ALGORITHMS.iter().map(|(kind, _)| *kind).collect()
+}
+
+/// Restore a built-in algorithm of `kind` from its persisted state.
+///
+/// Returns `None` when `kind` is not one of [`builtin_algorithm_names`].
+pub(crate) fn restore_builtin_algorithm(
+    kind: &str,
+    state: serde_json::Value,
+) -> Option<Result<Box<dyn ExecutionAlgorithm>>> {
+    BUILTIN_ALGORITHMS
+        .iter()
+        .find(|(name, _)| *name == kind)
+        .map(|(_, restore)| restore(state))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builtin_algorithm_names_include_trailing_stop() {
+        let names = builtin_algorithm_names();
+        assert!(names.contains(&"TRAILING_STOP"));
+        assert!(names.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn restore_dispatches_only_builtin_kinds() {
+        let invalid = serde_json::Value::Null;
+        assert!(restore_builtin_algorithm("TWAP", invalid.clone()).is_some_and(|res| res.is_err()));
+        assert!(restore_builtin_algorithm("WASM_PLUGIN", invalid).is_none());
+    }
+}
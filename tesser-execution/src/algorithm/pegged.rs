@@ -259,6 +259,7 @@ impl ExecutionAlgorithm for PeggedBestAlgorithm {
                 side: self.state.parent_signal.kind.side(),
                 new_price: Some(price),
                 new_quantity: Some(active.total_quantity),
+                new_trigger_price: None,
             };
             return Ok(vec![ChildOrderRequest {
                 parent_algo_id: self.state.id,
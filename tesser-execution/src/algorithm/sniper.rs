@@ -59,8 +59,12 @@ impl SniperAlgorithm {
 
     fn ready_to_fire(&self, price: Price) -> bool {
         match self.state.parent_signal.kind {
-            SignalKind::EnterLong | SignalKind::ExitShort => price <= self.state.trigger_price,
-            SignalKind::EnterShort | SignalKind::ExitLong => price >= self.state.trigger_price,
+            SignalKind::EnterLong | SignalKind::ExitShort | SignalKind::ReduceShort => {
+                price <= self.state.trigger_price
+            }
+            SignalKind::EnterShort | SignalKind::ExitLong | SignalKind::ReduceLong => {
+                price >= self.state.trigger_price
+            }
             SignalKind::Flatten => false,
         }
     }
@@ -3,8 +3,11 @@ use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use super::{AlgoStatus, ChildOrderAction, ChildOrderRequest, ExecutionAlgorithm};
-use tesser_core::{Fill, Order, OrderRequest, OrderType, Price, Quantity, Side, Signal, Tick};
+use super::{AlgoStatus, AmendCondition, ChildOrderAction, ChildOrderRequest, ExecutionAlgorithm};
+use tesser_core::{
+    Fill, Order, OrderRequest, OrderStatus, OrderType, OrderUpdateRequest, Price, Quantity, Side,
+    Signal, Tick,
+};
 
 #[derive(Debug, Deserialize, Serialize)]
 struct TrailingStopState {
@@ -18,10 +21,31 @@ struct TrailingStopState {
     highest_market_price: Price,
     activated: bool,
     triggered: bool,
+    /// Venue id of the resting stop order, once acknowledged.
+    #[serde(default)]
+    stop_order_id: Option<String>,
+    /// Trigger price of the requested or resting stop order.
+    #[serde(default)]
+    stop_price: Option<Price>,
+    /// Tightened trigger price awaiting the venue's amend acknowledgement.
+    #[serde(default)]
+    pending_trigger: Option<Price>,
+    /// Number of orders placed so far; keeps each placement's client id unique.
+    #[serde(default)]
+    placements: u32,
+    /// Set when the venue refused the stop order; the algorithm then fires a market order itself.
+    #[serde(default)]
+    stop_rejected: bool,
 }
 
 /// Simple trailing stop that arms once price trades through an activation level and
-/// fires a market sell when price retraces by the configured callback percentage.
+/// sells when price retraces by the configured callback percentage.
+///
+/// Once armed, the exit rests on the venue as a stop-market order that is tightened as the
+/// high-water mark rises. Tightening uses [`ChildOrderAction::ReplaceOrCancel`]: a stop that has
+/// already partially filled is canceled and re-placed for the remaining quantity instead.
+/// The tightened trigger only takes effect once the venue acknowledges the amend; a refused
+/// amend leaves the previous trigger in place until the stop is re-placed.
 pub struct TrailingStopAlgorithm {
     state: TrailingStopState,
 }
@@ -60,6 +84,11 @@ impl TrailingStopAlgorithm {
                 highest_market_price: activation_price,
                 activated: false,
                 triggered: false,
+                stop_order_id: None,
+                stop_price: None,
+                pending_trigger: None,
+                placements: 0,
+                stop_rejected: false,
             },
         })
     }
@@ -81,18 +110,56 @@ impl TrailingStopAlgorithm {
         }
     }
 
-    fn build_child(&self, qty: Quantity) -> ChildOrderRequest {
+    fn threshold(&self) -> Price {
+        self.state.highest_market_price * (Decimal::ONE - self.state.callback_rate)
+    }
+
+    fn build_child(&mut self, qty: Quantity) -> ChildOrderRequest {
+        self.build_order(OrderType::Market, qty, None)
+    }
+
+    fn build_stop(&mut self, qty: Quantity, trigger_price: Price) -> ChildOrderRequest {
+        self.build_order(OrderType::StopMarket, qty, Some(trigger_price))
+    }
+
+    fn tighten_stop(&self, order_id: String, trigger_price: Price) -> ChildOrderRequest {
+        ChildOrderRequest {
+            parent_algo_id: self.state.id,
+            action: ChildOrderAction::ReplaceOrCancel {
+                update: OrderUpdateRequest {
+                    order_id,
+                    symbol: self.state.parent_signal.symbol,
+                    side: self.state.parent_signal.kind.side(),
+                    new_price: None,
+                    new_quantity: None,
+                    new_trigger_price: Some(trigger_price),
+                },
+                condition: AmendCondition::Unfilled,
+            },
+        }
+    }
+
+    fn build_order(
+        &mut self,
+        order_type: OrderType,
+        qty: Quantity,
+        trigger_price: Option<Price>,
+    ) -> ChildOrderRequest {
+        self.state.placements += 1;
         ChildOrderRequest {
             parent_algo_id: self.state.id,
             action: ChildOrderAction::Place(OrderRequest {
                 symbol: self.state.parent_signal.symbol,
                 side: self.state.parent_signal.kind.side(),
-                order_type: OrderType::Market,
+                order_type,
                 quantity: qty,
                 price: None,
-                trigger_price: None,
+                trigger_price,
                 time_in_force: None,
-                client_order_id: Some(format!("trailing-{}", self.state.id)),
+                client_order_id: Some(format!(
+                    "trailing-{}-{}",
+                    self.state.id, self.state.placements
+                )),
                 take_profit: None,
                 stop_loss: None,
                 display_quantity: None,
@@ -123,7 +190,52 @@ impl ExecutionAlgorithm for TrailingStopAlgorithm {
         Ok(Vec::new())
     }
 
-    fn on_child_order_placed(&mut self, _order: &Order) {}
+    fn on_child_order_placed(&mut self, order: &Order) {
+        if order.request.order_type != OrderType::StopMarket {
+            return;
+        }
+        if order.status.is_terminal() {
+            if self.state.stop_order_id.as_deref() == Some(order.id.as_str()) {
+                self.state.stop_order_id = None;
+            }
+            self.state.pending_trigger = None;
+            if order.status != OrderStatus::Filled {
+                // Re-placed for the remaining quantity on the next tick.
+                self.state.stop_price = None;
+            }
+            return;
+        }
+        self.state.stop_order_id = Some(order.id.clone());
+        if let Some(trigger) = self
+            .state
+            .pending_trigger
+            .take()
+            .or(order.request.trigger_price)
+        {
+            self.state.stop_price = Some(trigger);
+        }
+    }
+
+    fn on_child_order_rejected(&mut self, order_id: &str, _reason: &str) -> Result<()> {
+        if self.state.pending_trigger.is_some()
+            && self.state.stop_order_id.as_deref() == Some(order_id)
+        {
+            // Only the amend was refused; the stop still rests at its previous trigger.
+            self.state.pending_trigger = None;
+            return Ok(());
+        }
+        if self.state.stop_price.is_some() {
+            self.state.stop_order_id = None;
+            self.state.stop_price = None;
+            self.state.stop_rejected = true;
+        }
+        Ok(())
+    }
+
+    fn bind_child_order(&mut self, order: Order) -> Result<()> {
+        self.on_child_order_placed(&order);
+        Ok(())
+    }
 
     fn on_fill(&mut self, fill: &Fill) -> Result<Vec<ChildOrderRequest>> {
         self.state.filled_quantity += fill.fill_quantity;
@@ -140,7 +252,9 @@ impl ExecutionAlgorithm for TrailingStopAlgorithm {
 
         if !self.state.activated {
             self.try_activate(tick.price);
-            return Ok(Vec::new());
+            if !self.state.activated {
+                return Ok(Vec::new());
+            }
         }
 
         if self.state.triggered {
@@ -148,15 +262,31 @@ impl ExecutionAlgorithm for TrailingStopAlgorithm {
         }
 
         self.update_trail(tick.price);
-        let threshold = self.state.highest_market_price * (Decimal::ONE - self.state.callback_rate);
-        if tick.price <= threshold {
-            self.state.triggered = true;
-            let qty = self.remaining();
-            if qty > Decimal::ZERO {
-                return Ok(vec![self.build_child(qty)]);
+        let threshold = self.threshold();
+        let qty = self.remaining();
+        if qty <= Decimal::ZERO {
+            return Ok(Vec::new());
+        }
+        if self.state.pending_trigger.is_some() {
+            return Ok(Vec::new());
+        }
+        match (self.state.stop_price, self.state.stop_order_id.clone()) {
+            (Some(stop), Some(order_id)) if threshold > stop => {
+                self.state.pending_trigger = Some(threshold);
+                Ok(vec![self.tighten_stop(order_id, threshold)])
+            }
+            // Waiting for the stop to be acknowledged, or it is already at the threshold.
+            (Some(_), _) => Ok(Vec::new()),
+            (None, _) if tick.price <= threshold => {
+                self.state.triggered = true;
+                Ok(vec![self.build_child(qty)])
+            }
+            (None, _) if self.state.stop_rejected => Ok(Vec::new()),
+            (None, _) => {
+                self.state.stop_price = Some(threshold);
+                Ok(vec![self.build_stop(qty, threshold)])
             }
         }
-        Ok(Vec::new())
     }
 
     fn on_timer(&mut self) -> Result<Vec<ChildOrderRequest>> {
@@ -215,8 +345,28 @@ mod tests {
         assert!(algo.state.activated);
     }
 
+    fn acknowledge(request: &OrderRequest, status: OrderStatus) -> Order {
+        Order {
+            id: "stop-1".into(),
+            request: request.clone(),
+            status,
+            filled_quantity: Decimal::ZERO,
+            avg_fill_price: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn placed(orders: &[ChildOrderRequest]) -> &OrderRequest {
+        assert_eq!(orders.len(), 1);
+        match &orders[0].action {
+            ChildOrderAction::Place(request) => request,
+            other => panic!("unexpected action: {other:?}"),
+        }
+    }
+
     #[test]
-    fn trailing_stop_triggers_after_callback() {
+    fn trailing_stop_rests_and_tightens_a_stop_order() {
         let signal = Signal::new("BTCUSDT", SignalKind::ExitLong, 1.0);
         let mut algo = TrailingStopAlgorithm::new(
             signal,
@@ -225,23 +375,124 @@ mod tests {
             Decimal::new(5, 2),
         )
         .unwrap();
-        // Activate and push to a new high
-        algo.on_tick(&tick(Decimal::from(105))).unwrap();
-        algo.on_tick(&tick(Decimal::from(112))).unwrap();
-        // Drop below the trailing threshold (112 * (1 - 0.05) = 106.4)
+        // Activation places the stop at 105 * (1 - 0.05) = 99.75
         let orders = algo.on_tick(&tick(Decimal::from(105))).unwrap();
+        let request = placed(&orders).clone();
+        assert_eq!(request.order_type, OrderType::StopMarket);
+        assert_eq!(request.trigger_price, Some(Decimal::new(9975, 2)));
+        assert_eq!(request.quantity, Decimal::from(3));
+        assert!(request
+            .client_order_id
+            .as_ref()
+            .expect("client id missing")
+            .starts_with("trailing-"));
+        algo.on_child_order_placed(&acknowledge(&request, OrderStatus::Accepted));
+
+        // A new high tightens the stop to 112 * (1 - 0.05) = 106.4
+        let orders = algo.on_tick(&tick(Decimal::from(112))).unwrap();
         assert_eq!(orders.len(), 1);
         match &orders[0].action {
-            ChildOrderAction::Place(request) => {
-                assert_eq!(request.order_type, OrderType::Market);
-                assert_eq!(request.quantity, Decimal::from(3));
-                assert!(request
-                    .client_order_id
-                    .as_ref()
-                    .expect("client id missing")
-                    .starts_with("trailing-"));
+            ChildOrderAction::ReplaceOrCancel { update, condition } => {
+                assert_eq!(update.order_id, "stop-1");
+                assert_eq!(update.new_trigger_price, Some(Decimal::new(1064, 1)));
+                assert_eq!(*condition, AmendCondition::Unfilled);
             }
             other => panic!("unexpected action: {other:?}"),
         }
+        // Retracing neither moves the stop nor fires a market order
+        assert!(algo.on_tick(&tick(Decimal::from(105))).unwrap().is_empty());
+    }
+
+    #[test]
+    fn canceled_stop_is_replaced_for_the_remainder() {
+        let signal = Signal::new("BTCUSDT", SignalKind::ExitLong, 1.0);
+        let mut algo = TrailingStopAlgorithm::new(
+            signal,
+            Decimal::from(3),
+            Decimal::from(100),
+            Decimal::new(5, 2),
+        )
+        .unwrap();
+        let orders = algo.on_tick(&tick(Decimal::from(105))).unwrap();
+        let request = placed(&orders).clone();
+        algo.on_child_order_placed(&acknowledge(&request, OrderStatus::Accepted));
+        algo.on_fill(&Fill {
+            order_id: "stop-1".into(),
+            symbol: request.symbol,
+            side: Side::Sell,
+            fill_price: Decimal::from(99),
+            fill_quantity: Decimal::ONE,
+            fee: None,
+            fee_asset: None,
+            timestamp: Utc::now(),
+        })
+        .unwrap();
+        algo.on_child_order_placed(&acknowledge(&request, OrderStatus::Canceled));
+
+        let orders = algo.on_tick(&tick(Decimal::from(110))).unwrap();
+        let replacement = placed(&orders);
+        assert_eq!(replacement.order_type, OrderType::StopMarket);
+        assert_eq!(replacement.quantity, Decimal::from(2));
+        assert_eq!(replacement.trigger_price, Some(Decimal::new(1045, 1)));
+    }
+
+    #[test]
+    fn refused_amend_keeps_the_old_trigger_and_replaces_with_a_fresh_id() {
+        let signal = Signal::new("BTCUSDT", SignalKind::ExitLong, 1.0);
+        let mut algo = TrailingStopAlgorithm::new(
+            signal,
+            Decimal::from(3),
+            Decimal::from(100),
+            Decimal::new(5, 2),
+        )
+        .unwrap();
+        let orders = algo.on_tick(&tick(Decimal::from(105))).unwrap();
+        let request = placed(&orders).clone();
+        algo.on_child_order_placed(&acknowledge(&request, OrderStatus::Accepted));
+
+        let orders = algo.on_tick(&tick(Decimal::from(112))).unwrap();
+        assert!(matches!(
+            orders[0].action,
+            ChildOrderAction::ReplaceOrCancel { .. }
+        ));
+        // Unconfirmed tightening does not move the recorded stop
+        assert_eq!(algo.state.stop_price, Some(Decimal::new(9975, 2)));
+        algo.on_child_order_rejected("stop-1", "amend refused")
+            .unwrap();
+        assert_eq!(algo.state.stop_price, Some(Decimal::new(9975, 2)));
+        assert!(algo.state.pending_trigger.is_none());
+        assert!(!algo.state.stop_rejected);
+
+        // The orchestrator cancels the stop instead, and it is re-placed at the new threshold
+        algo.on_child_order_placed(&acknowledge(&request, OrderStatus::Canceled));
+        let orders = algo.on_tick(&tick(Decimal::from(112))).unwrap();
+        let replacement = placed(&orders);
+        assert_eq!(replacement.order_type, OrderType::StopMarket);
+        assert_eq!(replacement.trigger_price, Some(Decimal::new(1064, 1)));
+        assert_ne!(replacement.client_order_id, request.client_order_id);
+    }
+
+    #[test]
+    fn trailing_stop_triggers_after_callback_when_stop_is_rejected() {
+        let signal = Signal::new("BTCUSDT", SignalKind::ExitLong, 1.0);
+        let mut algo = TrailingStopAlgorithm::new(
+            signal,
+            Decimal::from(3),
+            Decimal::from(100),
+            Decimal::new(5, 2),
+        )
+        .unwrap();
+        // Activate and push to a new high
+        let orders = algo.on_tick(&tick(Decimal::from(105))).unwrap();
+        let id = placed(&orders).client_order_id.clone().unwrap();
+        algo.on_child_order_rejected(&id, "stop orders unsupported")
+            .unwrap();
+        assert!(algo.on_tick(&tick(Decimal::from(112))).unwrap().is_empty());
+        // Drop below the trailing threshold (112 * (1 - 0.05) = 106.4)
+        let orders = algo.on_tick(&tick(Decimal::from(105))).unwrap();
+        let request = placed(&orders);
+        assert_eq!(request.order_type, OrderType::Market);
+        assert_eq!(request.quantity, Decimal::from(3));
+        assert!(algo.on_tick(&tick(Decimal::from(100))).unwrap().is_empty());
     }
 }
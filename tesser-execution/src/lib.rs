@@ -1,23 +1,32 @@
 //! Order management and signal execution helpers.
 
 pub mod algorithm;
+pub mod mapping;
 pub mod orchestrator;
 pub mod repository;
 pub mod wasm;
 
 // Re-export key types for convenience
-pub use algorithm::{AlgoStatus, ChildOrderRequest, ExecutionAlgorithm};
-pub use orchestrator::OrderOrchestrator;
+pub use algorithm::{
+    builtin_algorithm_names, AlgoStatus, AlgorithmSnapshot, AmendCondition, ChildOrderRequest,
+    ExecutionAlgorithm,
+};
+pub use mapping::{
+    protective_orders, resolve_flatten, signal_to_order, OrderMapping, OrderMappingPolicy,
+    OrderPlacement, PriceReference, TopOfBook, TranslationContext,
+};
+pub use orchestrator::{MinNotionalPolicy, OrderOrchestrator};
 pub use repository::{AlgoStateRepository, SqliteAlgoStateRepository, StoredAlgoState};
 pub use wasm::{WasmAlgorithm, WasmAlgorithmState, WasmPluginEngine};
 
 use anyhow::{bail, Context};
 use rust_decimal::Decimal;
-use std::sync::Arc;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
 use tesser_broker::{BrokerError, BrokerResult, ExecutionClient};
 use tesser_core::{
-    AssetId, ExchangeId, InstrumentKind, Order, OrderRequest, OrderType, OrderUpdateRequest, Price,
-    Quantity, Side, Signal, SignalKind, Symbol,
+    AssetId, ExchangeId, Fill, InstrumentKind, Order, OrderId, OrderRequest, OrderType,
+    OrderUpdateRequest, Price, Quantity, Side, Signal, Symbol,
 };
 use thiserror::Error;
 use tracing::{info, warn};
@@ -549,6 +558,37 @@ mod tests {
             Err(RiskError::MaxOrderNotional { .. })
         ));
     }
+
+    #[tokio::test]
+    async fn engine_rejects_non_positive_quantities_before_the_broker() {
+        let engine = ExecutionEngine::new(
+            Arc::new(tesser_paper::PaperExecutionClient::default()),
+            Box::new(FixedOrderSizer {
+                quantity: Decimal::ONE,
+            }),
+            Arc::new(NoopRiskChecker),
+        );
+        for quantity in [Decimal::ZERO, Decimal::NEGATIVE_ONE] {
+            let order = OrderRequest {
+                symbol: "BTCUSDT".into(),
+                side: Side::Buy,
+                order_type: OrderType::Market,
+                quantity,
+                price: None,
+                trigger_price: None,
+                time_in_force: None,
+                client_order_id: None,
+                take_profit: None,
+                stop_loss: None,
+                display_quantity: None,
+            };
+            let err = engine
+                .send_order(order, &RiskContext::default())
+                .await
+                .unwrap_err();
+            assert!(matches!(err, BrokerError::InvalidRequest(_)), "{err}");
+        }
+    }
 }
 
 /// Errors surfaced by pre-trade risk checks.
@@ -573,11 +613,20 @@ pub enum RiskError {
     },
 }
 
+/// Protective orders held back until their resting entry order fills.
+struct PendingProtection {
+    orders: Vec<OrderRequest>,
+    ctx: RiskContext,
+    unprotected: Quantity,
+    fills: u32,
+}
+
 /// Translates signals into orders using a provided [`ExecutionClient`].
 pub struct ExecutionEngine {
     client: Arc<dyn ExecutionClient>,
     sizer: Box<dyn OrderSizer>,
     risk: Arc<dyn PreTradeRiskChecker>,
+    pending_protection: Mutex<HashMap<OrderId, PendingProtection>>,
 }
 
 impl ExecutionEngine {
@@ -591,6 +640,7 @@ impl ExecutionEngine {
             client,
             sizer,
             risk,
+            pending_protection: Mutex::new(HashMap::new()),
         }
     }
 
@@ -606,120 +656,122 @@ impl ExecutionEngine {
         self.sizer.size(signal, ctx.exchange_equity, ctx.last_price)
     }
 
-    /// Consume a signal and forward it to the broker.
+    /// Consume a signal and forward it to the broker as a market order.
     pub async fn handle_signal(
         &self,
         signal: Signal,
         ctx: RiskContext,
+    ) -> BrokerResult<Option<Order>> {
+        self.handle_signal_with(signal, ctx, OrderPlacement::Market)
+            .await
+    }
+
+    /// Consume a signal and forward it to the broker using `placement` for the entry order.
+    ///
+    /// Stop-loss and take-profit orders go out with a market entry. A limit entry may rest
+    /// unfilled, so its protective orders wait for [`Self::on_fill`] and cover only what filled.
+    pub async fn handle_signal_with(
+        &self,
+        signal: Signal,
+        ctx: RiskContext,
+        placement: OrderPlacement,
     ) -> BrokerResult<Option<Order>> {
         let qty = self
             .determine_quantity(&signal, &ctx)
             .context("failed to determine order size")
             .map_err(|err| BrokerError::Other(err.to_string()))?;
 
-        if qty <= Decimal::ZERO {
-            warn!(signal = ?signal.id, "order size is zero, skipping");
-            return Ok(None);
-        }
-
-        let client_order_id = if let Some(group) = signal.group_id {
-            format!("{}|grp:{}", signal.id, group)
-        } else {
-            signal.id.to_string()
+        let translation = TranslationContext {
+            sized_quantity: qty,
+            placement,
+            signed_position_qty: ctx.signed_position_qty,
         };
-        let request = match signal.kind {
-            SignalKind::EnterLong => {
-                self.build_request(signal.symbol, Side::Buy, qty, Some(client_order_id.clone()))
-            }
-            SignalKind::ExitLong | SignalKind::Flatten => self.build_request(
-                signal.symbol,
-                Side::Sell,
-                qty,
-                Some(client_order_id.clone()),
-            ),
-            SignalKind::EnterShort => self.build_request(
-                signal.symbol,
-                Side::Sell,
-                qty,
-                Some(client_order_id.clone()),
-            ),
-            SignalKind::ExitShort => {
-                self.build_request(signal.symbol, Side::Buy, qty, Some(client_order_id.clone()))
-            }
+        let Some(request) = signal_to_order(&signal, &translation) else {
+            warn!(signal = ?signal.id, "signal does not translate to a valid order, skipping");
+            return Ok(None);
         };
+        let protective = protective_orders(&signal, &request);
+        let protect_now = request.order_type == OrderType::Market;
 
         let order = self.send_order(request, &ctx).await?;
 
-        let stop_side = match signal.kind {
-            SignalKind::EnterLong | SignalKind::ExitShort => Side::Sell,
-            SignalKind::EnterShort | SignalKind::ExitLong => Side::Buy,
-            SignalKind::Flatten => return Ok(Some(order)),
-        };
-
-        if let Some(sl_price) = signal.stop_loss {
-            let sl_request = OrderRequest {
-                symbol: signal.symbol,
-                side: stop_side,
-                order_type: OrderType::StopMarket,
-                quantity: qty,
-                price: None,
-                trigger_price: Some(sl_price),
-                time_in_force: None,
-                client_order_id: Some(format!("{}-sl", signal.id)),
-                take_profit: None,
-                stop_loss: None,
-                display_quantity: None,
-            };
-            if let Err(e) = self.send_order(sl_request, &ctx).await {
-                warn!(error = %e, "failed to place stop-loss order");
-            }
+        if protect_now {
+            self.send_protective(protective, &ctx).await;
+        } else if !protective.is_empty() {
+            self.pending_protection.lock().unwrap().insert(
+                order.id.clone(),
+                PendingProtection {
+                    orders: protective,
+                    ctx,
+                    unprotected: order.request.quantity,
+                    fills: 0,
+                },
+            );
         }
 
-        if let Some(tp_price) = signal.take_profit {
-            let tp_request = OrderRequest {
-                symbol: signal.symbol,
-                side: stop_side,
-                order_type: OrderType::StopMarket,
-                quantity: qty,
-                price: None,
-                trigger_price: Some(tp_price),
-                time_in_force: None,
-                client_order_id: Some(format!("{}-tp", signal.id)),
-                take_profit: None,
-                stop_loss: None,
-                display_quantity: None,
+        Ok(Some(order))
+    }
+
+    /// Place the protective orders held for a resting entry, sized to this fill.
+    ///
+    /// Each partial fill gets its own stop-loss/take-profit pair; later pairs carry the fill
+    /// number in their client ids (`<signal>-2-sl`).
+    pub async fn on_fill(&self, fill: &Fill) {
+        let (requests, ctx) = {
+            let mut pending = self.pending_protection.lock().unwrap();
+            let Some(entry) = pending.get_mut(&fill.order_id) else {
+                return;
             };
-            if let Err(e) = self.send_order(tp_request, &ctx).await {
-                warn!(error = %e, "failed to place take-profit order");
+            let quantity = fill.fill_quantity.min(entry.unprotected);
+            if quantity <= Decimal::ZERO {
+                return;
             }
-        }
+            entry.unprotected -= quantity;
+            entry.fills += 1;
+            let fills = entry.fills;
+            let requests: Vec<OrderRequest> = entry
+                .orders
+                .iter()
+                .cloned()
+                .map(|mut request| {
+                    request.quantity = quantity;
+                    if fills > 1 {
+                        request.client_order_id =
+                            request.client_order_id.map(|id| match id.rsplit_once('-') {
+                                Some((base, suffix)) => format!("{base}-{fills}-{suffix}"),
+                                None => format!("{id}-{fills}"),
+                            });
+                    }
+                    request
+                })
+                .collect();
+            let ctx = entry.ctx;
+            if entry.unprotected <= Decimal::ZERO {
+                pending.remove(&fill.order_id);
+            }
+            (requests, ctx)
+        };
+        self.send_protective(requests, &ctx).await;
+    }
 
-        Ok(Some(order))
+    /// Drop protective orders still waiting on an entry that was canceled or rejected.
+    pub fn forget_protection(&self, order_id: &str) {
+        self.pending_protection.lock().unwrap().remove(order_id);
     }
 
-    fn build_request(
-        &self,
-        symbol: Symbol,
-        side: Side,
-        qty: Quantity,
-        client_order_id: Option<String>,
-    ) -> OrderRequest {
-        OrderRequest {
-            symbol,
-            side,
-            order_type: OrderType::Market,
-            quantity: qty,
-            price: None,
-            trigger_price: None,
-            time_in_force: None,
-            client_order_id,
-            take_profit: None,
-            stop_loss: None,
-            display_quantity: None,
+    async fn send_protective(&self, requests: Vec<OrderRequest>, ctx: &RiskContext) {
+        for request in requests {
+            let client_order_id = request.client_order_id.clone();
+            if let Err(e) = self.send_order(request, ctx).await {
+                warn!(error = %e, ?client_order_id, "failed to place protective order");
+            }
         }
     }
 
     async fn send_order(&self, request: OrderRequest, ctx: &RiskContext) -> BrokerResult<Order> {
+        request
+            .validate()
+            .map_err(|err| BrokerError::InvalidRequest(err.to_string()))?;
         self.risk
             .check(&request, ctx)
             .map_err(|err| BrokerError::InvalidRequest(err.to_string()))?;
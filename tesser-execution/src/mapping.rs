@@ -0,0 +1,495 @@
+//! Translation of plain signals into concrete order types.
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use tesser_core::{OrderRequest, OrderType, Price, Quantity, Side, Signal, SignalKind};
+
+/// Price a limit order is anchored to before the tick offset is applied.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PriceReference {
+    /// Best bid, regardless of side.
+    Bid,
+    /// Best ask, regardless of side.
+    Ask,
+    /// Same-side touch: the bid for buys and the ask for sells.
+    Passive,
+    /// Opposite touch: the ask for buys and the bid for sells.
+    Aggressive,
+    /// Last traded price.
+    Last,
+}
+
+/// How one signal kind is turned into an order.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum OrderMapping {
+    #[default]
+    Market,
+    /// Limit order at `reference`, shifted by `offset_ticks` instrument ticks.
+    ///
+    /// Positive offsets make the price more aggressive (higher for buys, lower for sells).
+    Limit {
+        reference: PriceReference,
+        #[serde(default)]
+        offset_ticks: i32,
+    },
+}
+
+/// Per-[`SignalKind`] order mapping, configured per strategy under `[order_mapping]`.
+///
+/// Kinds without an explicit entry use `default`, which is a market order unless overridden.
+/// `Flatten` signals are resolved into exits before mapping, so they follow `exit_long` /
+/// `exit_short`.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(default)]
+pub struct OrderMappingPolicy {
+    pub default: OrderMapping,
+    pub enter_long: Option<OrderMapping>,
+    pub exit_long: Option<OrderMapping>,
+    pub enter_short: Option<OrderMapping>,
+    pub exit_short: Option<OrderMapping>,
+    pub reduce_long: Option<OrderMapping>,
+    pub reduce_short: Option<OrderMapping>,
+}
+
+/// Best bid/ask and last trade used to resolve [`PriceReference`]s.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TopOfBook {
+    pub bid: Option<Price>,
+    pub ask: Option<Price>,
+    pub last: Option<Price>,
+}
+
+/// Concrete order type and price selected for a signal.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OrderPlacement {
+    Market,
+    Limit(Price),
+}
+
+impl OrderMappingPolicy {
+    /// Every signal kind becomes a limit order at `reference`.
+    #[must_use]
+    pub fn limit(reference: PriceReference, offset_ticks: i32) -> Self {
+        Self {
+            default: OrderMapping::Limit {
+                reference,
+                offset_ticks,
+            },
+            ..Self::default()
+        }
+    }
+
+    /// Override the mapping used for `kind`.
+    #[must_use]
+    pub fn with_kind(mut self, kind: SignalKind, mapping: OrderMapping) -> Self {
+        match kind {
+            SignalKind::EnterLong => self.enter_long = Some(mapping),
+            SignalKind::ExitLong | SignalKind::Flatten => self.exit_long = Some(mapping),
+            SignalKind::EnterShort => self.enter_short = Some(mapping),
+            SignalKind::ExitShort => self.exit_short = Some(mapping),
+            SignalKind::ReduceLong => self.reduce_long = Some(mapping),
+            SignalKind::ReduceShort => self.reduce_short = Some(mapping),
+        }
+        self
+    }
+
+    /// Mapping configured for `kind`, falling back to the default.
+    pub fn mapping_for(&self, kind: SignalKind) -> OrderMapping {
+        let specific = match kind {
+            SignalKind::EnterLong => self.enter_long,
+            SignalKind::ExitLong | SignalKind::Flatten => self.exit_long,
+            SignalKind::EnterShort => self.enter_short,
+            SignalKind::ExitShort => self.exit_short,
+            SignalKind::ReduceLong => self.reduce_long,
+            SignalKind::ReduceShort => self.reduce_short,
+        };
+        specific.unwrap_or(self.default)
+    }
+
+    /// Resolve the order placement for a signal of `kind` trading on `side`.
+    ///
+    /// Missing bid/ask quotes fall back to the last trade. When no usable reference price is
+    /// known the order is sent at market; offsets are ignored when `tick_size` is unknown.
+    pub fn resolve(
+        &self,
+        kind: SignalKind,
+        side: Side,
+        book: &TopOfBook,
+        tick_size: Option<Price>,
+    ) -> OrderPlacement {
+        let OrderMapping::Limit {
+            reference,
+            offset_ticks,
+        } = self.mapping_for(kind)
+        else {
+            return OrderPlacement::Market;
+        };
+        let quote = match (reference, side) {
+            (PriceReference::Bid, _)
+            | (PriceReference::Passive, Side::Buy)
+            | (PriceReference::Aggressive, Side::Sell) => book.bid,
+            (PriceReference::Ask, _)
+            | (PriceReference::Passive, Side::Sell)
+            | (PriceReference::Aggressive, Side::Buy) => book.ask,
+            (PriceReference::Last, _) => None,
+        };
+        let Some(base) = quote.or(book.last).filter(|price| *price > Decimal::ZERO) else {
+            return OrderPlacement::Market;
+        };
+        let offset = match tick_size {
+            Some(tick) if tick > Decimal::ZERO => tick * Decimal::from(offset_ticks),
+            _ => Decimal::ZERO,
+        };
+        let price = match side {
+            Side::Buy => base + offset,
+            Side::Sell => base - offset,
+        };
+        if price <= Decimal::ZERO {
+            return OrderPlacement::Market;
+        }
+        OrderPlacement::Limit(price)
+    }
+}
+
+/// Inputs needed by [`signal_to_order`] besides the signal itself.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TranslationContext {
+    /// Quantity chosen by the order sizer, used when the signal carries no explicit quantity.
+    pub sized_quantity: Quantity,
+    /// Order type and price resolved for the entry order.
+    pub placement: OrderPlacement,
+    /// Signed open position (long positive, short negative), used to resolve `Flatten`.
+    pub signed_position_qty: Quantity,
+}
+
+/// Rewrite a `Flatten` signal into an exit of the open position, or `None` when flat.
+///
+/// The exit closes the full `signed_qty` (long positive, short negative); protective
+/// stops are dropped since nothing remains to protect. Callers resolving an
+/// [`OrderPlacement`] should do this first so the exit's side picks the price reference.
+pub fn resolve_flatten(signal: &Signal, signed_qty: Quantity) -> Option<Signal> {
+    if signed_qty.is_zero() {
+        return None;
+    }
+    let mut exit = signal.clone();
+    exit.kind = if signed_qty > Decimal::ZERO {
+        SignalKind::ExitLong
+    } else {
+        SignalKind::ExitShort
+    };
+    exit.quantity = Some(signed_qty.abs());
+    exit.stop_loss = None;
+    exit.take_profit = None;
+    Some(exit)
+}
+
+/// Build the primary order for `signal`, or `None` when the size resolves to zero or the
+/// request fails [`OrderRequest::validate`].
+///
+/// Execution hints are not interpreted here: the orchestrator routes hinted signals to their
+/// execution algorithm before translation, and callers translating directly send the plain
+/// order. `Flatten` signals close `ctx.signed_position_qty` via [`resolve_flatten`] and
+/// translate to nothing when flat.
+pub fn signal_to_order(signal: &Signal, ctx: &TranslationContext) -> Option<OrderRequest> {
+    if signal.kind == SignalKind::Flatten {
+        let exit = resolve_flatten(signal, ctx.signed_position_qty)?;
+        return signal_to_order(&exit, ctx);
+    }
+    let quantity = signal
+        .quantity
+        .map(|qty| qty.max(Decimal::ZERO))
+        .unwrap_or(ctx.sized_quantity);
+    if quantity <= Decimal::ZERO {
+        return None;
+    }
+    let side = signal.kind.side();
+    let (order_type, price) = match ctx.placement {
+        OrderPlacement::Market => (OrderType::Market, None),
+        OrderPlacement::Limit(price) => (OrderType::Limit, Some(price)),
+    };
+    let client_order_id = match signal.group_id {
+        Some(group) => format!("{}|grp:{}", signal.id, group),
+        None => signal.id.to_string(),
+    };
+    let request = OrderRequest {
+        symbol: signal.symbol,
+        side,
+        order_type,
+        quantity,
+        price,
+        trigger_price: None,
+        time_in_force: None,
+        client_order_id: Some(client_order_id),
+        take_profit: None,
+        stop_loss: None,
+        display_quantity: None,
+    };
+    request.validate().is_ok().then_some(request)
+}
+
+/// Stop-loss and take-profit orders protecting the position opened by `entry`.
+///
+/// Only entries and full exits carry protective orders; `Flatten` and reduce signals never do.
+pub fn protective_orders(signal: &Signal, entry: &OrderRequest) -> Vec<OrderRequest> {
+    let side = match signal.kind {
+        SignalKind::EnterLong | SignalKind::ExitShort => Side::Sell,
+        SignalKind::EnterShort | SignalKind::ExitLong => Side::Buy,
+        SignalKind::Flatten | SignalKind::ReduceLong | SignalKind::ReduceShort => {
+            return Vec::new()
+        }
+    };
+    [(signal.stop_loss, "sl"), (signal.take_profit, "tp")]
+        .into_iter()
+        .filter_map(|(trigger, suffix)| {
+            Some(OrderRequest {
+                symbol: signal.symbol,
+                side,
+                order_type: OrderType::StopMarket,
+                quantity: entry.quantity,
+                price: None,
+                trigger_price: Some(trigger?),
+                time_in_force: None,
+                client_order_id: Some(format!("{}-{suffix}", signal.id)),
+                take_profit: None,
+                stop_loss: None,
+                display_quantity: None,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+    use tesser_core::ExecutionHint;
+    use uuid::Uuid;
+
+    fn book() -> TopOfBook {
+        TopOfBook {
+            bid: Some(Decimal::from(100)),
+            ask: Some(Decimal::from(101)),
+            last: Some(Decimal::new(1005, 1)),
+        }
+    }
+
+    #[test]
+    fn default_policy_maps_everything_to_market() {
+        let policy = OrderMappingPolicy::default();
+        assert_eq!(
+            policy.resolve(SignalKind::EnterLong, Side::Buy, &book(), None),
+            OrderPlacement::Market
+        );
+    }
+
+    #[test]
+    fn passive_and_aggressive_references_follow_the_side() {
+        let policy = OrderMappingPolicy::limit(PriceReference::Passive, 0);
+        assert_eq!(
+            policy.resolve(SignalKind::EnterLong, Side::Buy, &book(), None),
+            OrderPlacement::Limit(Decimal::from(100))
+        );
+        assert_eq!(
+            policy.resolve(SignalKind::EnterShort, Side::Sell, &book(), None),
+            OrderPlacement::Limit(Decimal::from(101))
+        );
+        let policy = OrderMappingPolicy::limit(PriceReference::Aggressive, 0);
+        assert_eq!(
+            policy.resolve(SignalKind::ExitLong, Side::Sell, &book(), None),
+            OrderPlacement::Limit(Decimal::from(100))
+        );
+    }
+
+    #[test]
+    fn tick_offsets_make_prices_more_aggressive() {
+        let tick = Some(Decimal::new(5, 1));
+        let policy = OrderMappingPolicy::limit(PriceReference::Passive, 2);
+        assert_eq!(
+            policy.resolve(SignalKind::EnterLong, Side::Buy, &book(), tick),
+            OrderPlacement::Limit(Decimal::from(101))
+        );
+        assert_eq!(
+            policy.resolve(SignalKind::EnterShort, Side::Sell, &book(), tick),
+            OrderPlacement::Limit(Decimal::from(100))
+        );
+    }
+
+    #[test]
+    fn kind_overrides_take_precedence_and_missing_quotes_fall_back() {
+        let policy = OrderMappingPolicy::default().with_kind(
+            SignalKind::EnterLong,
+            OrderMapping::Limit {
+                reference: PriceReference::Bid,
+                offset_ticks: 0,
+            },
+        );
+        assert_eq!(
+            policy.resolve(SignalKind::ExitLong, Side::Sell, &book(), None),
+            OrderPlacement::Market
+        );
+        let no_bid = TopOfBook {
+            bid: None,
+            ..book()
+        };
+        assert_eq!(
+            policy.resolve(SignalKind::EnterLong, Side::Buy, &no_bid, None),
+            OrderPlacement::Limit(Decimal::new(1005, 1))
+        );
+        assert_eq!(
+            policy.resolve(
+                SignalKind::EnterLong,
+                Side::Buy,
+                &TopOfBook::default(),
+                None
+            ),
+            OrderPlacement::Market
+        );
+    }
+
+    fn market(quantity: i64) -> TranslationContext {
+        TranslationContext {
+            sized_quantity: Decimal::from(quantity),
+            placement: OrderPlacement::Market,
+            signed_position_qty: Decimal::ZERO,
+        }
+    }
+
+    #[test]
+    fn signal_kinds_translate_to_sides() {
+        let cases = [
+            (SignalKind::EnterLong, Side::Buy),
+            (SignalKind::ExitLong, Side::Sell),
+            (SignalKind::EnterShort, Side::Sell),
+            (SignalKind::ExitShort, Side::Buy),
+            (SignalKind::ReduceLong, Side::Sell),
+            (SignalKind::ReduceShort, Side::Buy),
+        ];
+        for (kind, side) in cases {
+            let signal = Signal::new("BTCUSDT", kind, 1.0);
+            let order = signal_to_order(&signal, &market(2)).unwrap();
+            assert_eq!(order.side, side, "{kind:?}");
+            assert_eq!(order.order_type, OrderType::Market);
+            assert_eq!(order.quantity, Decimal::from(2));
+            assert_eq!(order.client_order_id, Some(signal.id.to_string()));
+        }
+    }
+
+    #[test]
+    fn flatten_closes_the_signed_position() {
+        let signal = Signal::new("BTCUSDT", SignalKind::Flatten, 1.0);
+        for (position, side) in [(3, Side::Sell), (-2, Side::Buy)] {
+            let ctx = TranslationContext {
+                signed_position_qty: Decimal::from(position),
+                ..market(5)
+            };
+            let order = signal_to_order(&signal, &ctx).unwrap();
+            assert_eq!(order.side, side, "position {position}");
+            assert_eq!(order.quantity, Decimal::from(position).abs());
+        }
+        assert!(signal_to_order(&signal, &market(5)).is_none());
+    }
+
+    #[test]
+    fn translation_honors_quantity_override_placement_and_group() {
+        let group = Uuid::new_v4();
+        let mut signal = Signal::new("BTCUSDT", SignalKind::EnterLong, 1.0)
+            .with_quantity(Decimal::new(5, 1))
+            .with_group(group);
+        let ctx = TranslationContext {
+            sized_quantity: Decimal::from(3),
+            placement: OrderPlacement::Limit(Decimal::from(100)),
+            signed_position_qty: Decimal::ZERO,
+        };
+        let order = signal_to_order(&signal, &ctx).unwrap();
+        assert_eq!(order.quantity, Decimal::new(5, 1));
+        assert_eq!(order.order_type, OrderType::Limit);
+        assert_eq!(order.price, Some(Decimal::from(100)));
+        assert_eq!(
+            order.client_order_id,
+            Some(format!("{}|grp:{group}", signal.id))
+        );
+
+        signal.quantity = Some(Decimal::from(-1));
+        assert!(signal_to_order(&signal, &ctx).is_none());
+        signal.quantity = None;
+        assert!(signal_to_order(&signal, &market(0)).is_none());
+        let negative_limit = TranslationContext {
+            sized_quantity: Decimal::ONE,
+            placement: OrderPlacement::Limit(Decimal::from(-100)),
+            signed_position_qty: Decimal::ZERO,
+        };
+        assert!(signal_to_order(&signal, &negative_limit).is_none());
+    }
+
+    #[test]
+    fn stop_loss_and_take_profit_become_protective_orders() {
+        let mut signal = Signal::new("BTCUSDT", SignalKind::EnterLong, 1.0);
+        signal.stop_loss = Some(Decimal::from(90));
+        signal.take_profit = Some(Decimal::from(120));
+        let entry = signal_to_order(&signal, &market(2)).unwrap();
+        assert!(entry.stop_loss.is_none() && entry.take_profit.is_none());
+
+        let protective = protective_orders(&signal, &entry);
+        assert_eq!(protective.len(), 2);
+        assert!(protective.iter().all(|order| order.side == Side::Sell
+            && order.order_type == OrderType::StopMarket
+            && order.quantity == entry.quantity));
+        assert_eq!(protective[0].trigger_price, Some(Decimal::from(90)));
+        assert_eq!(
+            protective[0].client_order_id,
+            Some(format!("{}-sl", signal.id))
+        );
+        assert_eq!(protective[1].trigger_price, Some(Decimal::from(120)));
+
+        signal.kind = SignalKind::EnterShort;
+        signal.take_profit = None;
+        let protective = protective_orders(&signal, &entry);
+        assert_eq!(protective.len(), 1);
+        assert_eq!(protective[0].side, Side::Buy);
+
+        signal.kind = SignalKind::ReduceShort;
+        assert!(protective_orders(&signal, &entry).is_empty());
+    }
+
+    #[test]
+    fn execution_hints_pass_through_untouched() {
+        let plain = Signal::new("BTCUSDT", SignalKind::EnterLong, 1.0);
+        let mut hinted = plain.clone();
+        hinted.execution_hint = Some(ExecutionHint::Vwap {
+            duration: Duration::from_secs(600),
+            participation_rate: Some(Decimal::new(1, 1)),
+        });
+        let order = |signal: &Signal| {
+            serde_json::to_value(signal_to_order(signal, &market(1)).unwrap()).unwrap()
+        };
+        assert_eq!(order(&hinted), order(&plain));
+    }
+
+    #[test]
+    fn policy_deserializes_from_toml_tables() {
+        let policy: OrderMappingPolicy = toml::from_str(
+            r#"
+            enter_long = { type = "limit", reference = "bid" }
+            exit_short = { type = "limit", reference = "aggressive", offset_ticks = 1 }
+            "#,
+        )
+        .unwrap();
+        assert_eq!(policy.default, OrderMapping::Market);
+        assert_eq!(
+            policy.mapping_for(SignalKind::EnterLong),
+            OrderMapping::Limit {
+                reference: PriceReference::Bid,
+                offset_ticks: 0,
+            }
+        );
+        assert_eq!(
+            policy.mapping_for(SignalKind::ExitShort),
+            OrderMapping::Limit {
+                reference: PriceReference::Aggressive,
+                offset_ticks: 1,
+            }
+        );
+    }
+}
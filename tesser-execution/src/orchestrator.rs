@@ -1,29 +1,89 @@
 //! Order orchestrator for managing algorithmic execution.
 
 use anyhow::{anyhow, bail, Result};
-use chrono::Duration;
+use chrono::{DateTime, Duration, Utc};
 use rust_decimal::Decimal;
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
 use std::time::{Duration as StdDuration, Instant};
 use uuid::Uuid;
 
 use crate::algorithm::{
-    AlgoStatus, ChildOrderAction, ChildOrderRequest, ExecutionAlgorithm, IcebergAlgorithm,
-    PeggedBestAlgorithm, SniperAlgorithm, TrailingStopAlgorithm, TwapAlgorithm, VwapAlgorithm,
+    restore_builtin_algorithm, AlgoStatus, AlgorithmSnapshot, ChildOrderAction, ChildOrderRequest,
+    ExecutionAlgorithm, IcebergAlgorithm, PeggedBestAlgorithm, SniperAlgorithm,
+    TrailingStopAlgorithm, TwapAlgorithm, VwapAlgorithm,
 };
+use crate::mapping::{resolve_flatten, OrderMappingPolicy, OrderPlacement, TopOfBook};
 use crate::repository::{AlgoStateRepository, StoredAlgoState};
 use crate::wasm::{WasmAlgorithm, WasmAlgorithmState, WasmPluginEngine};
 use crate::{ExecutionEngine, PanicCloseConfig, PanicCloseMode, PanicObserver, RiskContext};
 use serde_json::Value;
 use tesser_core::{
-    ExecutionHint, Fill, Order, OrderRequest, OrderStatus, OrderType, Price, Quantity, Side,
-    Signal, SignalPanicBehavior, Symbol, Tick, TimeInForce,
+    ExecutionHint, Fill, Order, OrderBook, OrderRequest, OrderStatus, OrderType,
+    OrderUpdateRequest, Price, Quantity, Side, Signal, SignalKind, SignalPanicBehavior, Symbol,
+    Tick, TimeInForce,
 };
+use tesser_markets::MarketRegistry;
 
 /// Maps order IDs to their parent algorithm IDs for routing fills.
 type OrderToAlgoMap = HashMap<String, Uuid>;
 
+/// Action taken when an order's notional falls below the instrument's `min_notional`.
+///
+/// Only orders that add exposure are checked; exits and other position-reducing orders always
+/// pass so positions can be closed even when their remaining notional is small.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum MinNotionalPolicy {
+    /// Skip the order instead of sending it to the venue.
+    #[default]
+    Drop,
+    /// Raise the quantity to the smallest lot multiple that meets the minimum.
+    ScaleUp,
+}
+
+#[derive(Clone)]
+struct MinNotionalGuard {
+    registry: Arc<MarketRegistry>,
+    policy: MinNotionalPolicy,
+}
+
+/// Whether `request` only trades down the open position described by `ctx`.
+fn reduces_position(request: &OrderRequest, ctx: &RiskContext) -> bool {
+    let position = ctx.signed_position_qty;
+    match request.side {
+        Side::Sell => position > Decimal::ZERO && request.quantity <= position,
+        Side::Buy => position < Decimal::ZERO && request.quantity <= -position,
+    }
+}
+
+impl MinNotionalGuard {
+    /// Returns the quantity to submit, or `None` when the order must be dropped.
+    fn resolve(&self, symbol: Symbol, quantity: Quantity, price: Price) -> Option<Quantity> {
+        let Some(instrument) = self.registry.get(symbol) else {
+            return Some(quantity);
+        };
+        let Some(minimum) = instrument.min_notional else {
+            return Some(quantity);
+        };
+        let lot_size = instrument.lot_size;
+        if price <= Decimal::ZERO || quantity <= Decimal::ZERO || quantity * price >= minimum {
+            return Some(quantity);
+        }
+        match self.policy {
+            MinNotionalPolicy::Drop => None,
+            MinNotionalPolicy::ScaleUp => {
+                let required = minimum / price;
+                if lot_size > Decimal::ZERO {
+                    Some((required / lot_size).ceil() * lot_size)
+                } else {
+                    Some(required)
+                }
+            }
+        }
+    }
+}
+
 #[derive(Clone)]
 struct PendingOrder {
     request: OrderRequest,
@@ -124,6 +184,9 @@ impl ExecutionGroupState {
     }
 }
 
+/// Number of recently failed group identifiers remembered to reject late legs.
+const MAX_ABORTED_GROUPS: usize = 1024;
+
 pub const ORDER_TIMEOUT: StdDuration = StdDuration::from_secs(60);
 pub const ORDER_POLL_INTERVAL: StdDuration = StdDuration::from_secs(15);
 
@@ -160,7 +223,31 @@ pub struct OrderOrchestrator {
     execution_groups: Arc<Mutex<HashMap<Uuid, ExecutionGroupState>>>,
     /// Maps order IDs to their execution group identifiers.
     group_order_mapping: Arc<Mutex<HashMap<String, (Uuid, Symbol)>>>,
+    /// Recently failed groups whose remaining legs must not be submitted.
+    aborted_groups: Arc<Mutex<VecDeque<Uuid>>>,
     wasm_plugins: Option<Arc<WasmPluginEngine>>,
+    /// Optional instrument minimum-notional enforcement.
+    min_notional: Option<MinNotionalGuard>,
+    /// Instrument metadata used to round prices and quantities to the venue's increments.
+    markets: Option<Arc<MarketRegistry>>,
+    /// Runtime clock used to expire signals past their validity window.
+    clock: Arc<dyn Fn() -> DateTime<Utc> + Send + Sync>,
+    /// Signals discarded because their validity window had elapsed.
+    stale_signals: Arc<AtomicU64>,
+    /// Order type and price chosen for plain (non-algorithmic) signals.
+    order_mapping_policy: OrderMappingPolicy,
+    /// Latest best bid/ask and trade per symbol, used to price mapped limit orders.
+    top_of_book: Arc<Mutex<HashMap<Symbol, TopOfBook>>>,
+    /// How long entry signals stay suppressed after a stop-loss fill on their symbol.
+    cooldown_after_stop: Option<Duration>,
+    /// Working stop-loss orders, used to recognise stop fills.
+    stop_orders: Arc<Mutex<HashSet<String>>>,
+    /// Time of the latest stop-loss fill per symbol.
+    last_stop: Arc<Mutex<HashMap<Symbol, DateTime<Utc>>>>,
+    /// Entry signals discarded while their symbol was cooling down.
+    cooldown_signals: Arc<AtomicU64>,
+    /// Last observed state of open algorithm child orders, used to evaluate conditional amends.
+    child_orders: Arc<Mutex<HashMap<String, Order>>>,
 }
 
 impl OrderOrchestrator {
@@ -189,7 +276,19 @@ impl OrderOrchestrator {
             panic_observer,
             execution_groups: Arc::new(Mutex::new(HashMap::new())),
             group_order_mapping: Arc::new(Mutex::new(HashMap::new())),
+            aborted_groups: Arc::new(Mutex::new(VecDeque::new())),
             wasm_plugins,
+            min_notional: None,
+            markets: None,
+            clock: Arc::new(Utc::now),
+            stale_signals: Arc::new(AtomicU64::new(0)),
+            order_mapping_policy: OrderMappingPolicy::default(),
+            top_of_book: Arc::new(Mutex::new(HashMap::new())),
+            cooldown_after_stop: None,
+            stop_orders: Arc::new(Mutex::new(HashSet::new())),
+            last_stop: Arc::new(Mutex::new(HashMap::new())),
+            cooldown_signals: Arc::new(AtomicU64::new(0)),
+            child_orders: Arc::new(Mutex::new(HashMap::new())),
         };
 
         // Restore algorithms from persistent state
@@ -199,6 +298,136 @@ impl OrderOrchestrator {
         Ok(orchestrator)
     }
 
+    /// Check every order against the instrument `min_notional` published in `registry`.
+    ///
+    /// Also uses `registry` for instrument lookups unless [`Self::with_market_registry`] set one.
+    #[must_use]
+    pub fn with_min_notional(
+        mut self,
+        registry: Arc<MarketRegistry>,
+        policy: MinNotionalPolicy,
+    ) -> Self {
+        self.markets.get_or_insert_with(|| registry.clone());
+        self.min_notional = Some(MinNotionalGuard { registry, policy });
+        self
+    }
+
+    /// Look up tick and lot sizes in `registry` when pricing and sizing orders.
+    #[must_use]
+    pub fn with_market_registry(mut self, registry: Arc<MarketRegistry>) -> Self {
+        self.markets = Some(registry);
+        self
+    }
+
+    /// Replace the wall clock used to check signal validity windows.
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Fn() -> DateTime<Utc> + Send + Sync>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Choose order types for plain signals per [`SignalKind`] instead of always sending market orders.
+    #[must_use]
+    pub fn with_order_mapping(mut self, policy: OrderMappingPolicy) -> Self {
+        self.order_mapping_policy = policy;
+        self
+    }
+
+    /// Suppress entry signals for a symbol during `cooldown` after a stop-loss fill on it.
+    ///
+    /// Stop-loss orders are `StopMarket` orders other than attached take-profits (client ids
+    /// ending in `-tp`). Exits and flattens are never suppressed.
+    #[must_use]
+    pub fn with_cooldown_after_stop(mut self, cooldown: Duration) -> Self {
+        self.cooldown_after_stop = Some(cooldown);
+        self
+    }
+
+    /// Resolve the entry order placement for a plain signal from the cached top of book.
+    ///
+    /// Tick offsets use the instrument tick size from the market registry, when one is configured.
+    fn order_placement(&self, signal: &Signal, ctx: &RiskContext) -> OrderPlacement {
+        let mut book = self
+            .top_of_book
+            .lock()
+            .unwrap()
+            .get(&signal.symbol)
+            .copied()
+            .unwrap_or_default();
+        if book.last.is_none() && ctx.last_price > Decimal::ZERO {
+            book.last = Some(ctx.last_price);
+        }
+        let tick_size = self
+            .markets
+            .as_ref()
+            .and_then(|markets| markets.get(signal.symbol))
+            .map(|instrument| instrument.tick_size);
+        self.order_mapping_policy
+            .resolve(signal.kind, signal.kind.side(), &book, tick_size)
+    }
+
+    /// Number of signals dropped because they expired before reaching execution.
+    pub fn stale_signals_dropped(&self) -> u64 {
+        self.stale_signals.load(Ordering::Relaxed)
+    }
+
+    /// Number of entry signals dropped because their symbol was cooling down after a stop.
+    pub fn cooldown_signals_dropped(&self) -> u64 {
+        self.cooldown_signals.load(Ordering::Relaxed)
+    }
+
+    /// End of the post-stop cooldown for `symbol`, if one is still running at `now`.
+    fn cooldown_until(&self, symbol: Symbol, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let cooldown = self.cooldown_after_stop?;
+        let stopped_at = *self.last_stop.lock().unwrap().get(&symbol)?;
+        let until = stopped_at + cooldown;
+        (now < until).then_some(until)
+    }
+
+    fn record_stop(&self, symbol: Symbol) {
+        if self.cooldown_after_stop.is_none() {
+            return;
+        }
+        let now = (self.clock)();
+        tracing::info!(%symbol, %now, "stop-loss filled; starting entry cooldown");
+        self.last_stop.lock().unwrap().insert(symbol, now);
+    }
+
+    /// Apply the min-notional policy to a plain entry signal, returning `None` when it must be
+    /// dropped. Exits, reductions and flattens pass through unchanged.
+    fn size_for_min_notional(&self, signal: &Signal, ctx: &RiskContext) -> Result<Option<Signal>> {
+        let Some(guard) = &self.min_notional else {
+            return Ok(Some(signal.clone()));
+        };
+        if !matches!(signal.kind, SignalKind::EnterLong | SignalKind::EnterShort) {
+            return Ok(Some(signal.clone()));
+        }
+        let quantity = self.execution_engine.determine_quantity(signal, ctx)?;
+        match guard.resolve(signal.symbol, quantity, ctx.last_price) {
+            Some(resolved) if resolved != quantity => {
+                tracing::info!(
+                    symbol = %signal.symbol,
+                    from = %quantity,
+                    to = %resolved,
+                    "scaled order up to instrument min notional"
+                );
+                let mut sized = signal.clone();
+                sized.quantity = Some(resolved);
+                Ok(Some(sized))
+            }
+            Some(_) => Ok(Some(signal.clone())),
+            None => {
+                tracing::warn!(
+                    symbol = %signal.symbol,
+                    quantity = %quantity,
+                    price = %ctx.last_price,
+                    "dropping order below instrument min notional"
+                );
+                Ok(None)
+            }
+        }
+    }
+
     /// Restore algorithms from persistent state.
     async fn restore_algorithms(&self) -> Result<()> {
         let states = self.state_repo.load_all()?;
@@ -295,6 +524,7 @@ impl OrderOrchestrator {
                 mapping.insert(order.id.clone(), algo_id);
             }
             self.register_pending(&order);
+            self.track_child_order(&order);
             tracing::info!(
                 algo_id = %algo_id,
                 order_id = %order.id,
@@ -323,26 +553,19 @@ impl OrderOrchestrator {
         algo_type: &str,
         state: Value,
     ) -> Result<Box<dyn ExecutionAlgorithm>> {
-        match algo_type {
-            "TWAP" => Ok(Box::new(TwapAlgorithm::from_state(state)?)),
-            "VWAP" => Ok(Box::new(VwapAlgorithm::from_state(state)?)),
-            "ICEBERG" => Ok(Box::new(IcebergAlgorithm::from_state(state)?)),
-            "PEGGED_BEST" => Ok(Box::new(PeggedBestAlgorithm::from_state(state)?)),
-            "SNIPER" => Ok(Box::new(SniperAlgorithm::from_state(state)?)),
-            "TRAILING_STOP" => Ok(Box::new(TrailingStopAlgorithm::from_state(state)?)),
-            "WASM_PLUGIN" => {
-                let engine = self
-                    .wasm_plugins
-                    .as_ref()
-                    .ok_or_else(|| anyhow!("plugin runtime not configured"))?
-                    .clone();
-                let snapshot: WasmAlgorithmState = serde_json::from_value(state)?;
-                Ok(Box::new(WasmAlgorithm::from_snapshot(
-                    engine, algo_id, snapshot,
-                )?))
-            }
-            other => bail!("unsupported algorithm type '{other}'"),
-        }
+        if algo_type == "WASM_PLUGIN" {
+            let engine = self
+                .wasm_plugins
+                .as_ref()
+                .ok_or_else(|| anyhow!("plugin runtime not configured"))?
+                .clone();
+            let snapshot: WasmAlgorithmState = serde_json::from_value(state)?;
+            return Ok(Box::new(WasmAlgorithm::from_snapshot(
+                engine, algo_id, snapshot,
+            )?));
+        }
+        restore_builtin_algorithm(algo_type, state)
+            .unwrap_or_else(|| Err(anyhow!("unsupported algorithm type '{algo_type}'")))
     }
 
     /// Update the latest risk context for a symbol.
@@ -397,6 +620,21 @@ impl OrderOrchestrator {
         mapping.insert(order.id.clone(), (group_id, order.request.symbol));
     }
 
+    fn mark_group_aborted(&self, group_id: Uuid) {
+        let mut aborted = self.aborted_groups.lock().unwrap();
+        if aborted.contains(&group_id) {
+            return;
+        }
+        if aborted.len() >= MAX_ABORTED_GROUPS {
+            aborted.pop_front();
+        }
+        aborted.push_back(group_id);
+    }
+
+    fn is_group_aborted(&self, group_id: Uuid) -> bool {
+        self.aborted_groups.lock().unwrap().contains(&group_id)
+    }
+
     fn purge_group_orders(&self, group_id: Uuid) {
         let mut mapping = self.group_order_mapping.lock().unwrap();
         mapping.retain(|_, (gid, _)| *gid != group_id);
@@ -451,12 +689,32 @@ impl OrderOrchestrator {
     async fn fail_group_leg(&self, group_id: Uuid, symbol: Symbol, reason: &str) -> Result<()> {
         let mut maybe_actions = None;
         let mut group_config = None;
+        let mut sibling_orders = Vec::new();
+        self.mark_group_aborted(group_id);
         {
             let mut groups = self.execution_groups.lock().unwrap();
             if let Some(state) = groups.get_mut(&group_id) {
                 if let Some(leg) = state.legs.get_mut(&symbol) {
                     leg.status = ExecutionLegStatus::Failed;
                 }
+                // Abort the remaining working legs so the group never stays half-hedged.
+                let mut aborted = HashSet::new();
+                for leg in state.legs.values_mut() {
+                    if leg.symbol != symbol && leg.status == ExecutionLegStatus::Pending {
+                        leg.status = ExecutionLegStatus::Failed;
+                        aborted.insert(leg.symbol);
+                    }
+                }
+                if !aborted.is_empty() {
+                    let mut mapping = self.group_order_mapping.lock().unwrap();
+                    mapping.retain(|order_id, (gid, leg_symbol)| {
+                        let cancel = *gid == group_id && aborted.contains(leg_symbol);
+                        if cancel {
+                            sibling_orders.push((order_id.clone(), *leg_symbol));
+                        }
+                        !cancel
+                    });
+                }
                 group_config = Some(state.panic_config);
                 if let Some(actions) = state.panic_actions() {
                     maybe_actions = Some(actions);
@@ -465,6 +723,7 @@ impl OrderOrchestrator {
                 }
             }
         }
+        self.cancel_group_siblings(group_id, sibling_orders).await;
         if let Some(actions) = maybe_actions {
             let config = group_config.unwrap_or(self.panic_config);
             self.execute_panic_actions(group_id, reason, actions, config)
@@ -476,6 +735,32 @@ impl OrderOrchestrator {
         Ok(())
     }
 
+    async fn cancel_group_siblings(&self, group_id: Uuid, orders: Vec<(String, Symbol)>) {
+        if orders.is_empty() {
+            return;
+        }
+        let client = self.execution_engine.client();
+        for (order_id, symbol) in orders {
+            self.clear_pending(&order_id);
+            if let Err(err) = client.cancel_order(order_id.clone(), symbol).await {
+                tracing::error!(
+                    %symbol,
+                    order_id = %order_id,
+                    group = %group_id,
+                    error = %err,
+                    "failed to cancel sibling leg of failed group"
+                );
+            } else {
+                tracing::warn!(
+                    %symbol,
+                    order_id = %order_id,
+                    group = %group_id,
+                    "canceled sibling leg after group failure"
+                );
+            }
+        }
+    }
+
     async fn execute_panic_actions(
         &self,
         group_id: Uuid,
@@ -625,7 +910,11 @@ impl OrderOrchestrator {
             return Uuid::parse_str(rest).ok();
         }
         if let Some(rest) = client_id.strip_prefix("trailing-") {
-            return Uuid::parse_str(rest).ok();
+            if let Ok(id) = Uuid::parse_str(rest) {
+                return Some(id);
+            }
+            let (id_part, _) = rest.rsplit_once('-')?;
+            return Uuid::parse_str(id_part).ok();
         }
         if let Some(rest) = client_id.strip_prefix("plugin-") {
             let (id_part, _) = rest.split_once('-')?;
@@ -645,6 +934,45 @@ impl OrderOrchestrator {
 
     /// Handle a signal from a strategy.
     pub async fn on_signal(&self, signal: &Signal, ctx: &RiskContext) -> Result<()> {
+        let now = (self.clock)();
+        if signal.is_expired(now) {
+            self.stale_signals.fetch_add(1, Ordering::Relaxed);
+            tracing::warn!(
+                signal_id = %signal.id,
+                symbol = %signal.symbol,
+                valid_until = ?signal.valid_until,
+                %now,
+                "dropping stale signal past its validity window"
+            );
+            return Ok(());
+        }
+        if matches!(signal.kind, SignalKind::EnterLong | SignalKind::EnterShort) {
+            if let Some(until) = self.cooldown_until(signal.symbol, now) {
+                self.cooldown_signals.fetch_add(1, Ordering::Relaxed);
+                tracing::warn!(
+                    signal_id = %signal.id,
+                    symbol = %signal.symbol,
+                    %until,
+                    "dropping entry signal during post-stop cooldown"
+                );
+                return Ok(());
+            }
+        }
+        let resolved;
+        let signal = if signal.kind == SignalKind::Flatten {
+            let Some(exit) = resolve_flatten(signal, ctx.signed_position_qty) else {
+                tracing::debug!(
+                    signal_id = %signal.id,
+                    symbol = %signal.symbol,
+                    "flatten signal ignored; no open position"
+                );
+                return Ok(());
+            };
+            resolved = exit;
+            &resolved
+        } else {
+            signal
+        };
         match &signal.execution_hint {
             Some(ExecutionHint::Twap { duration }) => {
                 self.handle_twap_signal(signal.clone(), *duration, ctx)
@@ -703,12 +1031,42 @@ impl OrderOrchestrator {
                 self.handle_plugin_signal(signal.clone(), name.clone(), params.clone(), ctx)
                     .await
             }
+            Some(ExecutionHint::Unknown) => {
+                tracing::warn!(
+                    signal_id = %signal.id,
+                    symbol = %signal.symbol,
+                    "signal ignored; execution hint is not supported by this build"
+                );
+                Ok(())
+            }
             None => {
                 // Handle normal, non-algorithmic orders
+                if let Some(group_id) = signal.group_id {
+                    if self.is_group_aborted(group_id) {
+                        tracing::warn!(
+                            %group_id,
+                            symbol = %signal.symbol,
+                            "dropping signal for execution group that already failed"
+                        );
+                        return Ok(());
+                    }
+                }
                 self.register_group_signal(signal);
+                let Some(sized) = self.size_for_min_notional(signal, ctx)? else {
+                    if let Some(group_id) = signal.group_id {
+                        self.fail_group_leg(
+                            group_id,
+                            signal.symbol,
+                            "order notional below instrument minimum",
+                        )
+                        .await?;
+                    }
+                    return Ok(());
+                };
+                let placement = self.order_placement(&sized, ctx);
                 match self
                     .execution_engine
-                    .handle_signal(signal.clone(), *ctx)
+                    .handle_signal_with(sized, *ctx, placement)
                     .await
                 {
                     Ok(Some(order)) => {
@@ -1020,8 +1378,13 @@ impl OrderOrchestrator {
             );
             return Ok(());
         }
-        let context =
+        let mut context =
             WasmAlgorithm::context_from_signal(&plugin_name, params, &signal, total_quantity, ctx);
+        context.risk.lot_size = self
+            .markets
+            .as_ref()
+            .and_then(|markets| markets.get(signal.symbol))
+            .map(|instrument| instrument.lot_size);
         let mut algo = WasmAlgorithm::new(engine.clone(), context)?;
         let algo_id = *algo.id();
         tracing::info!(
@@ -1059,6 +1422,28 @@ impl OrderOrchestrator {
                     .ok_or_else(|| anyhow!("missing risk context for symbol {}", symbol))?;
                 // Keep cache warm with the latest context.
                 self.update_risk_context(symbol, resolved_ctx);
+                let mut order_request = order_request;
+                let rejection_id = order_request.client_order_id.clone();
+                let guard = self
+                    .min_notional
+                    .as_ref()
+                    .filter(|_| !reduces_position(&order_request, &resolved_ctx));
+                if let Some(guard) = guard {
+                    let price = order_request.price.unwrap_or(resolved_ctx.last_price);
+                    match guard.resolve(symbol, order_request.quantity, price) {
+                        Some(quantity) => order_request.quantity = quantity,
+                        None => {
+                            let reason = format!(
+                                "child order for {symbol} is below the instrument min notional"
+                            );
+                            if let Some(id) = rejection_id.as_deref() {
+                                self.notify_algo_rejection(parent_algo_id, id, &reason)
+                                    .await;
+                            }
+                            bail!(reason)
+                        }
+                    }
+                }
                 let group_hint = Self::group_from_request(&order_request);
                 let order = match self
                     .execution_engine
@@ -1067,6 +1452,10 @@ impl OrderOrchestrator {
                 {
                     Ok(order) => order,
                     Err(err) => {
+                        if let Some(id) = rejection_id.as_deref() {
+                            self.notify_algo_rejection(parent_algo_id, id, &err.to_string())
+                                .await;
+                        }
                         if let Some(group_id) = group_hint {
                             let message = format!("order placement failed: {err}");
                             self.fail_group_leg(group_id, symbol, &message).await?;
@@ -1080,6 +1469,7 @@ impl OrderOrchestrator {
                     mapping.insert(order.id.clone(), parent_algo_id);
                 }
                 self.register_pending(&order);
+                self.track_child_order(&order);
                 if let Some(group_id) = group_hint {
                     self.track_group_order(group_id, &order);
                 }
@@ -1087,15 +1477,70 @@ impl OrderOrchestrator {
                 Ok(order)
             }
             ChildOrderAction::Amend(update_request) => {
-                let order = self.execution_engine.amend_order(update_request).await?;
-                self.refresh_pending(&order);
-                self.ensure_order_mapping(&order.id, parent_algo_id);
-                self.notify_algo_child(parent_algo_id, &order);
-                Ok(order)
+                self.amend_child_order(parent_algo_id, update_request).await
+            }
+            ChildOrderAction::ReplaceOrCancel { update, condition } => {
+                let tracked = self
+                    .child_orders
+                    .lock()
+                    .unwrap()
+                    .get(&update.order_id)
+                    .cloned();
+                let Some(tracked) = tracked else {
+                    bail!("child order {} is not open", update.order_id);
+                };
+                if condition.permits(tracked.status) {
+                    match self.amend_child_order(parent_algo_id, update).await {
+                        Ok(order) => return Ok(order),
+                        Err(err) => tracing::warn!(
+                            order_id = %tracked.id,
+                            error = %err,
+                            "Amend failed; canceling child order so it can be re-placed"
+                        ),
+                    }
+                } else {
+                    tracing::debug!(
+                        order_id = %tracked.id,
+                        status = ?tracked.status,
+                        ?condition,
+                        "Canceling child order instead of amending"
+                    );
+                }
+                self.execution_engine
+                    .client()
+                    .cancel_order(tracked.id.clone(), tracked.request.symbol)
+                    .await?;
+                let mut canceled = tracked;
+                canceled.status = OrderStatus::Canceled;
+                canceled.updated_at = Utc::now();
+                self.on_order_update(&canceled).await;
+                self.notify_algo_child(parent_algo_id, &canceled);
+                Ok(canceled)
             }
         }
     }
 
+    async fn amend_child_order(
+        &self,
+        parent_algo_id: Uuid,
+        update_request: OrderUpdateRequest,
+    ) -> Result<Order> {
+        let order_id = update_request.order_id.clone();
+        let order = match self.execution_engine.amend_order(update_request).await {
+            Ok(order) => order,
+            Err(err) => {
+                self.notify_algo_rejection(parent_algo_id, &order_id, &err.to_string())
+                    .await;
+                return Err(anyhow!(err.to_string()));
+            }
+        };
+        self.refresh_pending(&order);
+        self.track_child_order(&order);
+        self.ensure_order_mapping(&order.id, parent_algo_id);
+        self.notify_algo_child(parent_algo_id, &order);
+        Ok(order)
+    }
+
     /// Handle a fill from the execution engine.
     pub async fn on_fill(&self, fill: &Fill) -> Result<()> {
         // Find the parent algorithm for this fill
@@ -1104,7 +1549,12 @@ impl OrderOrchestrator {
             mapping.get(&fill.order_id).copied()
         };
         self.clear_pending(&fill.order_id);
+        self.execution_engine.on_fill(fill).await;
+        self.apply_child_fill(fill);
         self.handle_group_fill(fill);
+        if self.stop_orders.lock().unwrap().contains(&fill.order_id) {
+            self.record_stop(fill.symbol);
+        }
 
         let Some(algo_id) = parent_algo_id else {
             // Non-algorithmic fill handled via group bookkeeping
@@ -1168,8 +1618,30 @@ impl OrderOrchestrator {
         Ok(())
     }
 
+    /// Forward an order book snapshot to every working algorithm.
+    pub fn on_order_book(&self, book: &OrderBook) {
+        {
+            let mut quotes = self.top_of_book.lock().unwrap();
+            let entry = quotes.entry(book.symbol).or_default();
+            entry.bid = book.best_bid().map(|level| level.price);
+            entry.ask = book.best_ask().map(|level| level.price);
+        }
+        let mut algorithms = self.algorithms.lock().unwrap();
+        for algo in algorithms.values_mut() {
+            if matches!(algo.status(), AlgoStatus::Working) {
+                algo.on_order_book(book);
+            }
+        }
+    }
+
     /// Handle market tick data.
     pub async fn on_tick(&self, tick: &Tick) -> Result<()> {
+        self.top_of_book
+            .lock()
+            .unwrap()
+            .entry(tick.symbol)
+            .or_default()
+            .last = Some(tick.price);
         let mut algorithms_to_process = Vec::new();
 
         // Collect algorithms that might be interested in this tick
@@ -1375,19 +1847,79 @@ impl OrderOrchestrator {
             .collect()
     }
 
+    /// Kind, status, and serialized state of every active algorithm, ordered by kind and id.
+    pub fn algorithm_snapshots(&self) -> Vec<AlgorithmSnapshot> {
+        let algorithms = self.algorithms.lock().unwrap();
+        let mut snapshots: Vec<AlgorithmSnapshot> = algorithms
+            .values()
+            .map(|algo| AlgorithmSnapshot {
+                id: *algo.id(),
+                kind: algo.kind().to_string(),
+                status: algo.status(),
+                state: algo.state(),
+            })
+            .collect();
+        snapshots.sort_by(|a, b| a.kind.cmp(&b.kind).then(a.id.cmp(&b.id)));
+        snapshots
+    }
+
     /// Access to the underlying execution engine.
     pub fn execution_engine(&self) -> Arc<ExecutionEngine> {
         Arc::clone(&self.execution_engine)
     }
 
+    /// Names of the WASM plugins available to `WASM_PLUGIN` execution, if plugins are enabled.
+    pub fn plugin_names(&self) -> Vec<String> {
+        let Some(engine) = &self.wasm_plugins else {
+            return Vec::new();
+        };
+        engine.plugin_names().unwrap_or_else(|err| {
+            tracing::warn!(error = %err, "failed to list wasm plugins");
+            Vec::new()
+        })
+    }
+
     /// Remove a pending order when an update arrives.
     pub async fn on_order_update(&self, order: &Order) {
+        if self.child_orders.lock().unwrap().contains_key(&order.id) {
+            self.track_child_order(order);
+        }
+        if self.cooldown_after_stop.is_some() && is_stop_loss_order(order) {
+            match order.status {
+                OrderStatus::Filled => {
+                    self.stop_orders.lock().unwrap().remove(&order.id);
+                    self.record_stop(order.request.symbol);
+                }
+                OrderStatus::Canceled | OrderStatus::Rejected => {
+                    self.stop_orders.lock().unwrap().remove(&order.id);
+                }
+                _ => {
+                    self.stop_orders.lock().unwrap().insert(order.id.clone());
+                }
+            }
+        }
         if matches!(
             order.status,
             OrderStatus::Filled | OrderStatus::Canceled | OrderStatus::Rejected
         ) {
             self.clear_pending(&order.id);
+            if order.status == OrderStatus::Rejected {
+                let parent_algo_id = {
+                    let mapping = self.order_mapping.lock().unwrap();
+                    mapping.get(&order.id).copied()
+                };
+                if let Some(algo_id) = parent_algo_id {
+                    let id = order
+                        .request
+                        .client_order_id
+                        .as_deref()
+                        .unwrap_or(&order.id);
+                    self.notify_algo_rejection(algo_id, id, "order rejected by exchange")
+                        .await;
+                }
+            }
             if matches!(order.status, OrderStatus::Canceled | OrderStatus::Rejected) {
+                self.execution_engine.forget_protection(&order.id);
                 let reason = format!("order marked {:?}", order.status);
                 let _ = self.fail_group_leg_by_order(&order.id, &reason).await;
             }
@@ -1507,6 +2039,29 @@ impl OrderOrchestrator {
             });
     }
 
+    fn track_child_order(&self, order: &Order) {
+        let mut children = self.child_orders.lock().unwrap();
+        if order.status.is_terminal() {
+            children.remove(&order.id);
+        } else {
+            children.insert(order.id.clone(), order.clone());
+        }
+    }
+
+    fn apply_child_fill(&self, fill: &Fill) {
+        let mut children = self.child_orders.lock().unwrap();
+        let Some(order) = children.get_mut(&fill.order_id) else {
+            return;
+        };
+        order.filled_quantity += fill.fill_quantity;
+        order.updated_at = fill.timestamp;
+        if order.filled_quantity >= order.request.quantity {
+            children.remove(&fill.order_id);
+        } else {
+            order.status = OrderStatus::PartiallyFilled;
+        }
+    }
+
     fn clear_order_mapping(&self, order_id: &str) {
         let mut mapping = self.order_mapping.lock().unwrap();
         mapping.remove(order_id);
@@ -1523,6 +2078,33 @@ impl OrderOrchestrator {
             algo.on_child_order_placed(order);
         }
     }
+
+    async fn notify_algo_rejection(&self, algo_id: Uuid, order_id: &str, reason: &str) {
+        let delivered = {
+            let mut algorithms = self.algorithms.lock().unwrap();
+            let Some(algo) = algorithms.get_mut(&algo_id) else {
+                return;
+            };
+            algo.on_child_order_rejected(order_id, reason)
+        };
+        if let Err(err) = delivered {
+            tracing::warn!(algo_id = %algo_id, order_id, error = %err, "algorithm failed to handle rejection");
+            return;
+        }
+        if let Err(err) = self.persist_algo_state(&algo_id).await {
+            tracing::warn!(algo_id = %algo_id, error = %err, "failed to persist algorithm after rejection");
+        }
+    }
+}
+
+/// Whether `order` protects a position as a stop-loss rather than taking profit.
+fn is_stop_loss_order(order: &Order) -> bool {
+    order.request.order_type == OrderType::StopMarket
+        && !order
+            .request
+            .client_order_id
+            .as_deref()
+            .is_some_and(|cid| cid.ends_with("-tp"))
 }
 
 fn build_timeout_order(id: String, request: OrderRequest, status: OrderStatus) -> Order {
@@ -1,24 +1,25 @@
 use std::sync::{Arc, Mutex};
 
-use anyhow::{anyhow, Context, Result};
+use anyhow::{anyhow, bail, Context, Result};
+use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
 use tesser_core::{
-    Fill, OrderRequest, OrderType, OrderUpdateRequest, Quantity, Side, Signal, SignalKind, Symbol,
-    Tick, TimeInForce,
+    Fill, OrderBook, OrderBookLevel, OrderRequest, OrderType, OrderUpdateRequest, Price, Quantity,
+    Side, Signal, SignalKind, Symbol, Tick, TimeInForce,
 };
 use tesser_wasm::{
     PluginChildOrderAction, PluginChildOrderRequest, PluginFill, PluginInitContext,
     PluginOrderRequest, PluginOrderType, PluginOrderUpdateRequest, PluginResult, PluginRiskContext,
     PluginSide, PluginSignal, PluginTick, PluginTimeInForce,
 };
-use tracing::debug;
+use tracing::{debug, warn};
 use uuid::Uuid;
 
 use crate::algorithm::{AlgoStatus, ChildOrderAction, ChildOrderRequest, ExecutionAlgorithm};
 use crate::RiskContext;
 
-use super::engine::{WasmInstance, WasmPluginEngine};
+use super::engine::{PluginPanic, WasmInstance, WasmPluginEngine};
 
 const KIND: &str = "WASM_PLUGIN";
 
@@ -36,10 +37,17 @@ pub struct WasmAlgorithm {
     id: Uuid,
     status: AlgoStatus,
     started: bool,
+    engine: Arc<WasmPluginEngine>,
     instance: Mutex<WasmInstance>,
     context: PluginInitContext,
     plugin_state: Value,
     next_client_seq: u64,
+    /// Latest top-of-book for the plugin's symbol as `(price, size)` pairs.
+    top_of_book: (Option<(Price, Quantity)>, Option<(Price, Quantity)>),
+    /// Latest trade price for the plugin's symbol, used to size notional orders.
+    last_trade: Option<Price>,
+    /// Earliest time the plugin wants its next `on_timer` callback.
+    timer_due: Option<DateTime<Utc>>,
 }
 
 impl WasmAlgorithm {
@@ -70,6 +78,7 @@ impl WasmAlgorithm {
             quote_available: ctx.quote_available,
             settlement_available: ctx.settlement_available,
             instrument_kind: ctx.instrument_kind.map(|kind| format!("{:?}", kind)),
+            lot_size: None,
         };
         let metadata = json!({
             "panic_behavior": signal.panic_behavior,
@@ -93,10 +102,14 @@ impl WasmAlgorithm {
             id: Uuid::new_v4(),
             status: AlgoStatus::Working,
             started: false,
+            engine,
             instance: Mutex::new(instance),
             context,
             plugin_state: Value::Null,
             next_client_seq: 0,
+            top_of_book: (None, None),
+            last_trade: None,
+            timer_due: None,
         })
     }
 
@@ -105,33 +118,77 @@ impl WasmAlgorithm {
         algo_id: Uuid,
         snapshot: WasmAlgorithmState,
     ) -> Result<Self> {
-        let mut instance = engine.instantiate(&snapshot.plugin.plugin)?;
-        let context_json = serde_json::to_string(&snapshot.plugin)?;
-        let state_json = serde_json::to_string(&snapshot.plugin_state)?;
-        // Initialize the plugin to rebuild any static state, then restore.
-        let _ = instance.call_init(&context_json);
-        instance.call_restore(&state_json)?;
+        let instance = Self::restore_instance(&engine, &snapshot.plugin, &snapshot.plugin_state)?;
         Ok(Self {
             id: algo_id,
             status: snapshot.status.clone(),
             started: true,
+            engine,
             instance: Mutex::new(instance),
             context: snapshot.plugin,
             plugin_state: snapshot.plugin_state,
             next_client_seq: snapshot.next_client_seq,
+            top_of_book: (None, None),
+            last_trade: None,
+            timer_due: None,
         })
     }
 
+    /// Instantiate the plugin and bring it back to a previously captured `plugin_state`.
+    fn restore_instance(
+        engine: &WasmPluginEngine,
+        context: &PluginInitContext,
+        plugin_state: &Value,
+    ) -> Result<WasmInstance> {
+        let mut instance = engine.instantiate(&context.plugin)?;
+        let context_json = serde_json::to_string(context)?;
+        let state_json = serde_json::to_string(plugin_state)?;
+        // Initialize the plugin to rebuild any static state, then restore.
+        let _ = instance.call_init(&context_json);
+        instance.call_restore(&state_json)?;
+        Ok(instance)
+    }
+
+    /// Run `call` against the plugin instance.
+    ///
+    /// A panic may have trapped the instance, so on [`PluginPanic`] it is replaced by a fresh
+    /// instance restored from the last snapshot before the error is returned.
+    fn call_plugin<T>(&mut self, call: impl FnOnce(&mut WasmInstance) -> Result<T>) -> Result<T> {
+        let instance = self
+            .instance
+            .get_mut()
+            .map_err(|_| anyhow!("plugin instance poisoned"))?;
+        let result = call(instance);
+        if let Err(err) = &result {
+            if err.downcast_ref::<PluginPanic>().is_some() {
+                warn!(
+                    plugin = %self.context.plugin,
+                    algo = %self.id,
+                    error = %err,
+                    "plugin panicked; re-instantiating it from its last snapshot"
+                );
+                match Self::restore_instance(&self.engine, &self.context, &self.plugin_state) {
+                    Ok(fresh) => *instance = fresh,
+                    Err(restore_err) => {
+                        warn!(
+                            plugin = %self.context.plugin,
+                            algo = %self.id,
+                            error = %restore_err,
+                            "failed to re-instantiate plugin"
+                        );
+                    }
+                }
+            }
+        }
+        result
+    }
+
     fn context_json(&self) -> Result<String> {
         serde_json::to_string(&self.context).map_err(|err| anyhow!(err))
     }
 
     fn refresh_snapshot(&mut self) -> Result<()> {
-        let mut instance = self
-            .instance
-            .lock()
-            .map_err(|_| anyhow!("plugin instance poisoned"))?;
-        let raw = instance.call_snapshot()?;
+        let raw = self.call_plugin(|instance| instance.call_snapshot())?;
         self.plugin_state = serde_json::from_str(&raw)?;
         Ok(())
     }
@@ -148,6 +205,10 @@ impl WasmAlgorithm {
         } else {
             self.status = AlgoStatus::Working;
         }
+        if let Some(delay_ms) = result.next_timer_ms {
+            let delay = Duration::milliseconds(i64::try_from(delay_ms).unwrap_or(i64::MAX));
+            self.timer_due = Utc::now().checked_add_signed(delay);
+        }
         let mut mapped = Vec::with_capacity(result.orders.len());
         for req in result.orders {
             mapped.push(self.build_child_request(req)?);
@@ -157,13 +218,7 @@ impl WasmAlgorithm {
 
     fn call_init(&mut self) -> Result<Vec<ChildOrderRequest>> {
         let payload = self.context_json()?;
-        let raw = {
-            let mut instance = self
-                .instance
-                .lock()
-                .map_err(|_| anyhow!("plugin instance poisoned"))?;
-            instance.call_init(&payload)?
-        };
+        let raw = self.call_plugin(|instance| instance.call_init(&payload))?;
         let orders = self.decode_result(raw)?;
         self.refresh_snapshot()?;
         self.started = true;
@@ -171,14 +226,12 @@ impl WasmAlgorithm {
     }
 
     fn call_tick(&mut self, tick: &Tick) -> Result<Vec<ChildOrderRequest>> {
-        let plugin_tick = to_plugin_tick(tick);
-        let raw = {
-            let mut instance = self
-                .instance
-                .lock()
-                .map_err(|_| anyhow!("plugin instance poisoned"))?;
-            instance.call_on_tick(&plugin_tick)?
-        };
+        if tick.symbol.code() == self.context.signal.symbol {
+            self.last_trade = Some(tick.price);
+        }
+        let (bid, ask) = self.top_of_book;
+        let plugin_tick = to_plugin_tick(tick).with_depth(bid, ask);
+        let raw = self.call_plugin(|instance| instance.call_on_tick(&plugin_tick))?;
         let orders = self.decode_result(raw)?;
         self.refresh_snapshot()?;
         Ok(orders)
@@ -187,49 +240,44 @@ impl WasmAlgorithm {
     fn call_fill(&mut self, fill: &Fill) -> Result<Vec<ChildOrderRequest>> {
         let plugin_fill = to_plugin_fill(fill);
         let payload = serde_json::to_string(&plugin_fill)?;
-        let raw = {
-            let mut instance = self
-                .instance
-                .lock()
-                .map_err(|_| anyhow!("plugin instance poisoned"))?;
-            instance.call_on_fill(&payload)?
-        };
+        let raw = self.call_plugin(|instance| instance.call_on_fill(&payload))?;
         let orders = self.decode_result(raw)?;
         self.refresh_snapshot()?;
         Ok(orders)
     }
 
     fn call_timer(&mut self) -> Result<Vec<ChildOrderRequest>> {
-        let raw = {
-            let mut instance = self
-                .instance
-                .lock()
-                .map_err(|_| anyhow!("plugin instance poisoned"))?;
-            instance.call_on_timer()?
-        };
+        if self.timer_due.is_some_and(|due| Utc::now() < due) {
+            return Ok(Vec::new());
+        }
+        self.timer_due = None;
+        let raw = self.call_plugin(|instance| instance.call_on_timer())?;
         let orders = self.decode_result(raw)?;
         self.refresh_snapshot()?;
         Ok(orders)
     }
 
+    fn call_order_rejected(&mut self, order_id: &str, reason: &str) -> Result<()> {
+        self.call_plugin(|instance| instance.call_on_order_rejected(order_id, reason))?;
+        self.refresh_snapshot()
+    }
+
     fn build_child_request(&mut self, req: PluginChildOrderRequest) -> Result<ChildOrderRequest> {
-        match req.action {
-            PluginChildOrderAction::Place(order) => {
-                let mut request = convert_order_request(order)?;
-                self.ensure_client_id(&mut request);
-                Ok(ChildOrderRequest {
-                    parent_algo_id: self.id,
-                    action: ChildOrderAction::Place(request),
-                })
-            }
-            PluginChildOrderAction::Amend(update) => {
-                let request = convert_order_update(update)?;
-                Ok(ChildOrderRequest {
-                    parent_algo_id: self.id,
-                    action: ChildOrderAction::Amend(request),
-                })
-            }
+        let sizing = NotionalSizing {
+            last_price: self
+                .last_trade
+                .or((self.context.risk.last_price > Decimal::ZERO)
+                    .then_some(self.context.risk.last_price)),
+            lot_size: self.context.risk.lot_size,
+        };
+        let mut action = convert_child_action(req.action, &sizing)?;
+        if let ChildOrderAction::Place(request) = &mut action {
+            self.ensure_client_id(request);
         }
+        Ok(ChildOrderRequest {
+            parent_algo_id: self.id,
+            action,
+        })
     }
 
     fn ensure_client_id(&mut self, order: &mut OrderRequest) {
@@ -267,6 +315,10 @@ impl ExecutionAlgorithm for WasmAlgorithm {
         self.call_fill(fill)
     }
 
+    fn on_child_order_rejected(&mut self, order_id: &str, reason: &str) -> Result<()> {
+        self.call_order_rejected(order_id, reason)
+    }
+
     fn bind_child_order(&mut self, _order: tesser_core::Order) -> Result<()> {
         Ok(())
     }
@@ -275,6 +327,14 @@ impl ExecutionAlgorithm for WasmAlgorithm {
         self.call_tick(tick)
     }
 
+    fn on_order_book(&mut self, book: &OrderBook) {
+        if book.symbol.code() != self.context.signal.symbol {
+            return;
+        }
+        let level = |level: &OrderBookLevel| (level.price, level.size);
+        self.top_of_book = (book.best_bid().map(level), book.best_ask().map(level));
+    }
+
     fn on_timer(&mut self) -> Result<Vec<ChildOrderRequest>> {
         self.call_timer()
     }
@@ -310,13 +370,13 @@ fn to_plugin_side(side: Side) -> PluginSide {
 }
 
 fn to_plugin_tick(tick: &Tick) -> PluginTick {
-    PluginTick {
-        symbol: tick.symbol.code().to_string(),
-        price: tick.price,
-        size: tick.size,
-        side: to_plugin_side(tick.side),
-        timestamp_ms: tick.exchange_timestamp.timestamp_millis(),
-    }
+    PluginTick::new(
+        tick.symbol.code(),
+        tick.price,
+        tick.size,
+        to_plugin_side(tick.side),
+        tick.exchange_timestamp.timestamp_millis(),
+    )
 }
 
 fn to_plugin_fill(fill: &Fill) -> PluginFill {
@@ -332,7 +392,62 @@ fn to_plugin_fill(fill: &Fill) -> PluginFill {
     }
 }
 
-fn convert_order_request(req: PluginOrderRequest) -> Result<OrderRequest> {
+/// Market data used to turn a plugin's notional order size into a quantity.
+#[derive(Clone, Copy, Debug, Default)]
+struct NotionalSizing {
+    last_price: Option<Price>,
+    lot_size: Option<Quantity>,
+}
+
+impl NotionalSizing {
+    /// Quantity for `notional`, priced at the limit price when set and the last trade otherwise.
+    fn quantity(&self, req: &PluginOrderRequest, notional: Decimal) -> Result<Quantity> {
+        let price = match req.order_type {
+            PluginOrderType::Limit => req.price.or(self.last_price),
+            PluginOrderType::Market => self.last_price,
+        }
+        .filter(|price| *price > Decimal::ZERO)
+        .ok_or_else(|| {
+            anyhow!(
+                "no price available to size notional order for {}",
+                req.symbol
+            )
+        })?;
+        let mut quantity = notional / price;
+        if let Some(lot) = self.lot_size.filter(|lot| *lot > Decimal::ZERO) {
+            quantity = (quantity / lot).floor() * lot;
+        }
+        if quantity <= Decimal::ZERO {
+            bail!(
+                "notional {notional} for {} is below one lot at price {price}",
+                req.symbol
+            );
+        }
+        Ok(quantity)
+    }
+}
+
+fn convert_child_action(
+    action: PluginChildOrderAction,
+    sizing: &NotionalSizing,
+) -> Result<ChildOrderAction> {
+    match action {
+        PluginChildOrderAction::Place(order) => {
+            convert_order_request(order, sizing).map(ChildOrderAction::Place)
+        }
+        PluginChildOrderAction::Amend(update) => {
+            convert_order_update(update).map(ChildOrderAction::Amend)
+        }
+    }
+}
+
+fn convert_order_request(req: PluginOrderRequest, sizing: &NotionalSizing) -> Result<OrderRequest> {
+    req.validate_size()
+        .map_err(|err| anyhow!("invalid plugin order: {}", err.message()))?;
+    let quantity = match req.notional {
+        Some(notional) => sizing.quantity(&req, notional)?,
+        None => req.quantity.unwrap_or_default(),
+    };
     let symbol = Symbol::from(req.symbol.as_str());
     let side = match req.side {
         PluginSide::Buy => Side::Buy,
@@ -353,7 +468,7 @@ fn convert_order_request(req: PluginOrderRequest) -> Result<OrderRequest> {
         symbol,
         side,
         order_type,
-        quantity: req.quantity,
+        quantity,
         price: req.price,
         trigger_price: req.trigger_price,
         time_in_force,
@@ -376,6 +491,7 @@ fn convert_order_update(req: PluginOrderUpdateRequest) -> Result<OrderUpdateRequ
         side,
         new_price: req.new_price,
         new_quantity: req.new_quantity,
+        new_trigger_price: None,
     })
 }
 
@@ -386,5 +502,136 @@ fn signal_kind_label(kind: SignalKind) -> &'static str {
         SignalKind::EnterShort => "enter_short",
         SignalKind::ExitShort => "exit_short",
         SignalKind::Flatten => "flatten",
+        SignalKind::ReduceLong => "reduce_long",
+        SignalKind::ReduceShort => "reduce_short",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal::Decimal;
+
+    fn grid_level(price: i64, side: PluginSide) -> PluginChildOrderAction {
+        PluginChildOrderAction::Place(PluginOrderRequest {
+            symbol: "BTCUSDT".into(),
+            side,
+            order_type: PluginOrderType::Limit,
+            quantity: Some(Decimal::ONE),
+            notional: None,
+            price: Some(Decimal::from(price)),
+            trigger_price: None,
+            time_in_force: Some(PluginTimeInForce::Gtc),
+            client_order_id: None,
+            take_profit: None,
+            stop_loss: None,
+            display_quantity: None,
+        })
+    }
+
+    #[test]
+    fn multi_order_plugin_result_decodes_into_child_actions() {
+        let raw = serde_json::to_string(
+            &PluginResult::builder()
+                .orders(vec![
+                    grid_level(98, PluginSide::Buy),
+                    grid_level(99, PluginSide::Buy),
+                    grid_level(101, PluginSide::Sell),
+                ])
+                .log("grid armed")
+                .next_timer_ms(250)
+                .build(),
+        )
+        .unwrap();
+        let result: PluginResult = serde_json::from_str(&raw).unwrap();
+        assert_eq!(result.next_timer_ms, Some(250));
+        let actions = result
+            .orders
+            .into_iter()
+            .map(|req| convert_child_action(req.action, &NotionalSizing::default()))
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+        let levels: Vec<_> = actions
+            .iter()
+            .map(|action| match action {
+                ChildOrderAction::Place(order) => (order.side, order.price),
+                other => panic!("unexpected action: {other:?}"),
+            })
+            .collect();
+        assert_eq!(
+            levels,
+            vec![
+                (Side::Buy, Some(Decimal::from(98))),
+                (Side::Buy, Some(Decimal::from(99))),
+                (Side::Sell, Some(Decimal::from(101))),
+            ]
+        );
+        assert!(actions.iter().all(|action| matches!(
+            action,
+            ChildOrderAction::Place(order)
+                if order.time_in_force == Some(TimeInForce::GoodTilCanceled)
+        )));
+    }
+
+    fn notional_order(order_type: PluginOrderType, price: Option<Decimal>) -> PluginOrderRequest {
+        PluginOrderRequest {
+            symbol: "BTCUSDT".into(),
+            side: PluginSide::Buy,
+            order_type,
+            quantity: None,
+            notional: Some(Decimal::from(1_000)),
+            price,
+            trigger_price: None,
+            time_in_force: None,
+            client_order_id: None,
+            take_profit: None,
+            stop_loss: None,
+            display_quantity: None,
+        }
+    }
+
+    #[test]
+    fn notional_order_is_sized_to_whole_lots() {
+        let sizing = NotionalSizing {
+            last_price: Some(Decimal::from(30_000)),
+            lot_size: Some(Decimal::new(1, 3)),
+        };
+        let market =
+            convert_order_request(notional_order(PluginOrderType::Market, None), &sizing).unwrap();
+        // 1000 / 30000 = 0.0333.. rounds down to 0.033.
+        assert_eq!(market.quantity, Decimal::new(33, 3));
+        assert_eq!(market.order_type, OrderType::Market);
+
+        let limit = convert_order_request(
+            notional_order(PluginOrderType::Limit, Some(Decimal::from(25_000))),
+            &sizing,
+        )
+        .unwrap();
+        assert_eq!(limit.quantity, Decimal::new(40, 3));
+        assert_eq!(limit.price, Some(Decimal::from(25_000)));
+    }
+
+    #[test]
+    fn notional_order_without_price_or_below_one_lot_is_rejected() {
+        let unpriced = NotionalSizing {
+            last_price: None,
+            lot_size: None,
+        };
+        assert!(
+            convert_order_request(notional_order(PluginOrderType::Market, None), &unpriced)
+                .is_err()
+        );
+
+        let coarse = NotionalSizing {
+            last_price: Some(Decimal::from(30_000)),
+            lot_size: Some(Decimal::ONE),
+        };
+        assert!(
+            convert_order_request(notional_order(PluginOrderType::Market, None), &coarse).is_err()
+        );
+
+        let mut both = notional_order(PluginOrderType::Market, None);
+        both.quantity = Some(Decimal::ONE);
+        assert!(convert_order_request(both, &coarse).is_err());
     }
 }
@@ -4,13 +4,15 @@ use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
 use std::time::{SystemTime, UNIX_EPOCH};
 
-use anyhow::{anyhow, Context, Result};
+use anyhow::{anyhow, bail, Context, Result};
+use rust_decimal::Decimal;
 use tesser_wasm::{
     host::{ComponentBindings, DecimalValue, WasiSide, WasiTick},
-    PluginSide, PluginTick,
+    PluginError, PluginSide, PluginTick, PLUGIN_ABI_VERSION,
 };
+use thiserror::Error;
 use wasmtime::component::{Component, Linker, ResourceTable};
-use wasmtime::{Config, Engine, Store};
+use wasmtime::{Config, Engine, Store, Trap};
 use wasmtime_wasi::preview2::{command::sync::add_to_linker, WasiCtx, WasiCtxBuilder, WasiView};
 
 /// Runtime responsible for loading, caching, and instantiating WASM plugins.
@@ -94,6 +96,28 @@ impl WasmPluginEngine {
         let component = self.load_component(name)?;
         WasmInstance::new(self.engine.clone(), component)
     }
+
+    /// List the plugin names (file stems of `.wasm` files) available in the plugin directory.
+    pub fn plugin_names(&self) -> Result<Vec<String>> {
+        let entries = fs::read_dir(&self.plugins_dir).with_context(|| {
+            format!(
+                "failed to read plugin directory {}",
+                self.plugins_dir.display()
+            )
+        })?;
+        let mut names = Vec::new();
+        for entry in entries {
+            let path = entry?.path();
+            if path.extension().and_then(|ext| ext.to_str()) != Some("wasm") {
+                continue;
+            }
+            if let Some(stem) = path.file_stem().and_then(|stem| stem.to_str()) {
+                names.push(stem.to_string());
+            }
+        }
+        names.sort();
+        Ok(names)
+    }
 }
 
 struct PluginStore {
@@ -127,6 +151,33 @@ impl WasiView for PluginStore {
     }
 }
 
+/// Raised when a plugin panics inside a callback.
+///
+/// Plugins built with `panic = "unwind"` report the panic through [`PluginError::Panic`] and stay
+/// usable. Under the default `abort` strategy for wasm targets the panic traps instead, which
+/// leaves the instance unusable; the caller must instantiate the plugin again.
+#[derive(Debug, Error)]
+#[error("plugin panicked: {0}")]
+pub struct PluginPanic(pub String);
+
+/// Convert an error string returned across the ABI into a host error.
+///
+/// Panics become a [`PluginPanic`], which callers can recover with `downcast_ref`.
+pub fn plugin_failure(raw: String) -> anyhow::Error {
+    match PluginError::decode(&raw) {
+        PluginError::Panic(message) => PluginPanic(message).into(),
+        PluginError::Message(message) => anyhow!(message),
+    }
+}
+
+/// Wrap an error raised by wasmtime while running `callback`, turning traps into [`PluginPanic`].
+fn call_failure(err: anyhow::Error, callback: &str) -> anyhow::Error {
+    match err.downcast_ref::<Trap>() {
+        Some(trap) => PluginPanic(format!("{callback} trapped: {trap}")).into(),
+        None => err.context(format!("plugin {callback} failed")),
+    }
+}
+
 /// Active plugin instance backed by a wasmtime store.
 pub struct WasmInstance {
     store: Store<PluginStore>,
@@ -138,50 +189,71 @@ impl WasmInstance {
         let mut linker: Linker<PluginStore> = Linker::new(&engine);
         add_to_linker(&mut linker)?;
         let mut store = Store::new(&engine, PluginStore::new());
-        let (plugin, _) = ComponentBindings::instantiate(&mut store, component.as_ref(), &linker)?;
+        let (plugin, _) = ComponentBindings::instantiate(&mut store, component.as_ref(), &linker)
+            .with_context(|| {
+            format!(
+                "failed to instantiate plugin; rebuild it against plugin ABI v{PLUGIN_ABI_VERSION}"
+            )
+        })?;
+        let version = plugin
+            .call_abi_version(&mut store)
+            .context("plugin abi_version failed")?;
+        if version != PLUGIN_ABI_VERSION {
+            bail!(
+                "plugin was built for ABI v{version} but the host requires v{PLUGIN_ABI_VERSION}"
+            );
+        }
         Ok(Self { store, plugin })
     }
 
     pub fn call_init(&mut self, payload: &str) -> Result<String> {
-        match self
-            .plugin
+        self.plugin
             .call_init(&mut self.store, payload)
-            .context("plugin init failed")?
-        {
-            Ok(value) => Ok(value),
-            Err(err) => Err(anyhow!(err)),
-        }
+            .map_err(|err| call_failure(err, "init"))?
+            .map_err(plugin_failure)
     }
 
     pub fn call_on_tick(&mut self, tick: &PluginTick) -> Result<String> {
         let wasi_tick = Self::convert_tick(tick);
         self.plugin
             .call_on_tick(&mut self.store, &wasi_tick)
-            .context("plugin on_tick failed")
+            .map_err(|err| call_failure(err, "on_tick"))?
+            .map_err(plugin_failure)
     }
 
     pub fn call_on_fill(&mut self, payload: &str) -> Result<String> {
         self.plugin
             .call_on_fill(&mut self.store, payload)
-            .context("plugin on_fill failed")
+            .map_err(|err| call_failure(err, "on_fill"))?
+            .map_err(plugin_failure)
     }
 
     pub fn call_on_timer(&mut self) -> Result<String> {
         self.plugin
             .call_on_timer(&mut self.store)
-            .context("plugin on_timer failed")
+            .map_err(|err| call_failure(err, "on_timer"))?
+            .map_err(plugin_failure)
+    }
+
+    pub fn call_on_order_rejected(&mut self, order_id: &str, reason: &str) -> Result<()> {
+        self.plugin
+            .call_on_order_rejected(&mut self.store, order_id, reason)
+            .map_err(|err| call_failure(err, "on_order_rejected"))?
+            .map_err(plugin_failure)
     }
 
     pub fn call_snapshot(&mut self) -> Result<String> {
         self.plugin
             .call_snapshot(&mut self.store)
-            .context("plugin snapshot failed")
+            .map_err(|err| call_failure(err, "snapshot"))?
+            .map_err(plugin_failure)
     }
 
     pub fn call_restore(&mut self, payload: &str) -> Result<()> {
         self.plugin
             .call_restore(&mut self.store, payload)
-            .context("plugin restore failed")
+            .map_err(|err| call_failure(err, "restore"))?
+            .map_err(plugin_failure)
     }
 
     fn convert_tick(tick: &PluginTick) -> WasiTick {
@@ -198,6 +270,16 @@ impl WasmInstance {
                 PluginSide::Sell => WasiSide::Sell,
             },
             timestamp_ms: tick.timestamp_ms,
+            best_bid: tick.best_bid.map(decimal_value),
+            best_ask: tick.best_ask.map(decimal_value),
+            bid_size: tick.bid_size.map(decimal_value),
+            ask_size: tick.ask_size.map(decimal_value),
         }
     }
 }
+
+fn decimal_value(value: Decimal) -> DecimalValue {
+    DecimalValue {
+        value: value.to_string(),
+    }
+}
@@ -2,4 +2,4 @@ pub mod adapter;
 pub mod engine;
 
 pub use adapter::{WasmAlgorithm, WasmAlgorithmState};
-pub use engine::{WasmInstance, WasmPluginEngine};
+pub use engine::{plugin_failure, PluginPanic, WasmInstance, WasmPluginEngine};
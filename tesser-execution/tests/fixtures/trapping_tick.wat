@@ -0,0 +1,154 @@
+;; Hand-written execution plugin used by `tests/wasm_plugin_trap.rs`.
+;;
+;; It implements the `execution-plugin` world for plugin ABI v4 with fixed responses: every
+;; callback succeeds with `{}` (snapshot returns `null`) except `on-tick`, which executes
+;; `unreachable` the way a `panic = "abort"` guest does when it panics.
+(component
+  ;; Core module: bump allocator, fixed return area at offset 16, JSON literals at 32 and 40.
+  (core module (;0;)
+    (type (;0;) (func (param i32 i32 i32 i32) (result i32)))
+    (type (;1;) (func (result i32)))
+    (type (;2;) (func (param i32 i32) (result i32)))
+    (type (;3;) (func (param i32) (result i32)))
+    ;; cabi_realloc
+    (func (;0;) (type 0) (param i32 i32 i32 i32) (result i32)
+      (local i32)
+      global.get 0
+      i32.const 7
+      i32.add
+      i32.const -8
+      i32.and
+      local.tee 4
+      local.get 3
+      i32.add
+      global.set 0
+      local.get 4
+    )
+    (func (;1;) (type 1) (result i32)
+      i32.const 4
+    )
+    (func (;2;) (type 2) (param i32 i32) (result i32)
+      i32.const 16
+      i32.const 0
+      i32.store8
+      i32.const 16
+      i32.const 32
+      i32.store offset=4
+      i32.const 16
+      i32.const 2
+      i32.store offset=8
+      i32.const 16
+    )
+    ;; on-tick: trap
+    (func (;3;) (type 3) (param i32) (result i32)
+      unreachable
+    )
+    (func (;4;) (type 2) (param i32 i32) (result i32)
+      i32.const 16
+      i32.const 0
+      i32.store8
+      i32.const 16
+      i32.const 32
+      i32.store offset=4
+      i32.const 16
+      i32.const 2
+      i32.store offset=8
+      i32.const 16
+    )
+    (func (;5;) (type 1) (result i32)
+      i32.const 16
+      i32.const 0
+      i32.store8
+      i32.const 16
+      i32.const 32
+      i32.store offset=4
+      i32.const 16
+      i32.const 2
+      i32.store offset=8
+      i32.const 16
+    )
+    (func (;6;) (type 0) (param i32 i32 i32 i32) (result i32)
+      i32.const 16
+      i32.const 0
+      i32.store8
+      i32.const 16
+    )
+    (func (;7;) (type 1) (result i32)
+      i32.const 16
+      i32.const 0
+      i32.store8
+      i32.const 16
+      i32.const 40
+      i32.store offset=4
+      i32.const 16
+      i32.const 4
+      i32.store offset=8
+      i32.const 16
+    )
+    (func (;8;) (type 2) (param i32 i32) (result i32)
+      i32.const 16
+      i32.const 0
+      i32.store8
+      i32.const 16
+    )
+    (memory (;0;) 1)
+    (global (;0;) (mut i32) i32.const 1024)
+    (export "memory" (memory 0))
+    (export "cabi_realloc" (func 0))
+    (export "abi-version" (func 1))
+    (export "init" (func 2))
+    (export "on-tick" (func 3))
+    (export "on-fill" (func 4))
+    (export "on-timer" (func 5))
+    (export "on-order-rejected" (func 6))
+    (export "snapshot" (func 7))
+    (export "restore" (func 8))
+    (data (;0;) (i32.const 32) "{}")
+    (data (;1;) (i32.const 40) "null")
+  )
+  (core instance (;0;) (instantiate 0))
+  (alias core export 0 "memory" (core memory (;0;)))
+  (alias core export 0 "cabi_realloc" (core func (;0;)))
+  ;; Component types mirroring `wit/execution.wit`.
+  (type (;0;) (record (field "value" string)))
+  (export (;1;) "decimal-value" (type 0))
+  (type (;2;) (enum "buy" "sell"))
+  (export (;3;) "side" (type 2))
+  (type (;4;) (option 1))
+  (type (;5;) (record (field "symbol" string) (field "price" 1) (field "size" 1) (field "side" 3) (field "timestamp-ms" s64) (field "best-bid" 4) (field "best-ask" 4) (field "bid-size" 4) (field "ask-size" 4)))
+  (export (;6;) "tick" (type 5))
+  (type (;7;) (result string (error string)))
+  (type (;8;) (result (error string)))
+  (type (;9;) (func (result u32)))
+  (alias core export 0 "abi-version" (core func (;1;)))
+  (func (;0;) (type 9) (canon lift (core func 1) string-encoding=utf8 (memory 0) (realloc 0)))
+  (export (;1;) "abi-version" (func 0))
+  (type (;10;) (func (param "config-json" string) (result 7)))
+  (alias core export 0 "init" (core func (;2;)))
+  (func (;2;) (type 10) (canon lift (core func 2) string-encoding=utf8 (memory 0) (realloc 0)))
+  (export (;3;) "init" (func 2))
+  (type (;11;) (func (param "tick" 6) (result 7)))
+  (alias core export 0 "on-tick" (core func (;3;)))
+  (func (;4;) (type 11) (canon lift (core func 3) string-encoding=utf8 (memory 0) (realloc 0)))
+  (export (;5;) "on-tick" (func 4))
+  (type (;12;) (func (param "fill-json" string) (result 7)))
+  (alias core export 0 "on-fill" (core func (;4;)))
+  (func (;6;) (type 12) (canon lift (core func 4) string-encoding=utf8 (memory 0) (realloc 0)))
+  (export (;7;) "on-fill" (func 6))
+  (type (;13;) (func (result 7)))
+  (alias core export 0 "on-timer" (core func (;5;)))
+  (func (;8;) (type 13) (canon lift (core func 5) string-encoding=utf8 (memory 0) (realloc 0)))
+  (export (;9;) "on-timer" (func 8))
+  (type (;14;) (func (param "order-id" string) (param "reason" string) (result 8)))
+  (alias core export 0 "on-order-rejected" (core func (;6;)))
+  (func (;10;) (type 14) (canon lift (core func 6) string-encoding=utf8 (memory 0) (realloc 0)))
+  (export (;11;) "on-order-rejected" (func 10))
+  (type (;15;) (func (result 7)))
+  (alias core export 0 "snapshot" (core func (;7;)))
+  (func (;12;) (type 15) (canon lift (core func 7) string-encoding=utf8 (memory 0) (realloc 0)))
+  (export (;13;) "snapshot" (func 12))
+  (type (;16;) (func (param "state-json" string) (result 8)))
+  (alias core export 0 "restore" (core func (;8;)))
+  (func (;14;) (type 16) (canon lift (core func 8) string-encoding=utf8 (memory 0) (realloc 0)))
+  (export (;15;) "restore" (func 14))
+)
@@ -3,14 +3,20 @@ use chrono::Duration;
 use rust_decimal::Decimal;
 use std::sync::{Arc, Mutex};
 use tempfile::NamedTempFile;
-use tesser_broker::{BrokerError, BrokerInfo, ExecutionClient};
-use tesser_core::{ExecutionHint, Signal, SignalKind, SignalPanicBehavior, Symbol};
+use tesser_broker::{BrokerError, BrokerInfo, ConnectorFactory, ExecutionClient};
+use tesser_core::{
+    AssetId, ExecutionHint, Fill, Instrument, InstrumentKind, Order, OrderBook, OrderBookLevel,
+    OrderRequest, OrderStatus, OrderType, Side, Signal, SignalKind, SignalPanicBehavior, Symbol,
+    Tick,
+};
 use tesser_execution::{
     algorithm::{ChildOrderAction, TwapAlgorithm},
-    AlgoStatus, ExecutionAlgorithm, ExecutionEngine, FixedOrderSizer, NoopRiskChecker,
-    OrderOrchestrator, PanicCloseConfig, PanicObserver, RiskContext, SqliteAlgoStateRepository,
+    AlgoStatus, ExecutionAlgorithm, ExecutionEngine, FixedOrderSizer, MinNotionalPolicy,
+    NoopRiskChecker, OrderMapping, OrderMappingPolicy, OrderOrchestrator, PanicCloseConfig,
+    PanicObserver, PortfolioPercentSizer, PriceReference, RiskContext, SqliteAlgoStateRepository,
 };
-use tesser_paper::PaperExecutionClient;
+use tesser_markets::MarketRegistry;
+use tesser_paper::{PaperExecutionClient, PaperFactory};
 use uuid::Uuid;
 
 #[tokio::test]
@@ -328,3 +334,801 @@ async fn router_failure_triggers_panic_observer() {
     assert_eq!(events[0].0, group);
     assert_eq!(events[0].1, signal.symbol);
 }
+
+type CancelLog = Arc<Mutex<Vec<(String, Symbol)>>>;
+
+/// Accepts every order except those targeting the configured symbol.
+struct SelectiveRejectClient {
+    reject: Symbol,
+    placed: Mutex<usize>,
+    requests: Mutex<Vec<tesser_core::OrderRequest>>,
+    amended: Mutex<Vec<tesser_core::OrderUpdateRequest>>,
+    canceled: CancelLog,
+}
+
+impl SelectiveRejectClient {
+    fn new(reject: Symbol) -> (Self, CancelLog) {
+        let canceled = Arc::new(Mutex::new(Vec::new()));
+        (
+            Self {
+                reject,
+                placed: Mutex::new(0),
+                requests: Mutex::new(Vec::new()),
+                amended: Mutex::new(Vec::new()),
+                canceled: canceled.clone(),
+            },
+            canceled,
+        )
+    }
+}
+
+#[async_trait]
+impl ExecutionClient for SelectiveRejectClient {
+    fn info(&self) -> BrokerInfo {
+        BrokerInfo {
+            name: "selective".into(),
+            markets: vec![],
+            supports_testnet: true,
+        }
+    }
+
+    async fn place_order(
+        &self,
+        request: tesser_core::OrderRequest,
+    ) -> Result<tesser_core::Order, BrokerError> {
+        if request.symbol == self.reject {
+            return Err(BrokerError::Exchange("synthetic rejection".into()));
+        }
+        self.requests.lock().unwrap().push(request.clone());
+        let mut placed = self.placed.lock().unwrap();
+        *placed += 1;
+        let now = chrono::Utc::now();
+        Ok(tesser_core::Order {
+            id: format!("order-{}", *placed),
+            request,
+            status: tesser_core::OrderStatus::Accepted,
+            filled_quantity: Decimal::ZERO,
+            avg_fill_price: None,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    async fn cancel_order(&self, order_id: String, symbol: Symbol) -> Result<(), BrokerError> {
+        self.canceled.lock().unwrap().push((order_id, symbol));
+        Ok(())
+    }
+
+    async fn amend_order(
+        &self,
+        request: tesser_core::OrderUpdateRequest,
+    ) -> Result<tesser_core::Order, BrokerError> {
+        let placed = request
+            .order_id
+            .strip_prefix("order-")
+            .and_then(|n| n.parse::<usize>().ok())
+            .and_then(|n| self.requests.lock().unwrap().get(n - 1).cloned())
+            .ok_or_else(|| BrokerError::InvalidRequest("unknown order".into()))?;
+        self.amended.lock().unwrap().push(request.clone());
+        let now = chrono::Utc::now();
+        Ok(tesser_core::Order {
+            id: request.order_id,
+            request: tesser_core::OrderRequest {
+                price: request.new_price.or(placed.price),
+                quantity: request.new_quantity.unwrap_or(placed.quantity),
+                trigger_price: request.new_trigger_price.or(placed.trigger_price),
+                ..placed
+            },
+            status: tesser_core::OrderStatus::Accepted,
+            filled_quantity: Decimal::ZERO,
+            avg_fill_price: None,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    async fn list_open_orders(
+        &self,
+        _symbol: Symbol,
+    ) -> Result<Vec<tesser_core::Order>, BrokerError> {
+        Ok(Vec::new())
+    }
+
+    async fn account_balances(&self) -> Result<Vec<tesser_core::AccountBalance>, BrokerError> {
+        Ok(Vec::new())
+    }
+
+    async fn positions(
+        &self,
+        _symbol: Option<&Vec<Symbol>>,
+    ) -> Result<Vec<tesser_core::Position>, BrokerError> {
+        Ok(Vec::new())
+    }
+
+    async fn list_instruments(
+        &self,
+        _category: &str,
+    ) -> Result<Vec<tesser_core::Instrument>, BrokerError> {
+        Ok(Vec::new())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+async fn group_orchestrator(client: Arc<dyn ExecutionClient>) -> OrderOrchestrator {
+    let engine = Arc::new(ExecutionEngine::new(
+        client,
+        Box::new(FixedOrderSizer {
+            quantity: Decimal::ONE,
+        }),
+        Arc::new(NoopRiskChecker),
+    ));
+    OrderOrchestrator::new(
+        engine,
+        Arc::new(new_sqlite_repo()),
+        Vec::new(),
+        PanicCloseConfig::default(),
+        None,
+        None,
+    )
+    .await
+    .unwrap()
+}
+
+fn group_ctx(symbol: Symbol) -> RiskContext {
+    RiskContext {
+        symbol,
+        exchange: symbol.exchange,
+        last_price: Decimal::from(100),
+        ..RiskContext::default()
+    }
+}
+
+#[tokio::test]
+async fn rejected_leg_cancels_working_sibling() {
+    let leg_a = Symbol::from("BINANCE:ETHUSDT");
+    let leg_b = Symbol::from("BINANCE:BTCUSDT");
+    let (client, canceled) = SelectiveRejectClient::new(leg_b);
+    let orchestrator = group_orchestrator(Arc::new(client)).await;
+    let group = Uuid::new_v4();
+
+    let first = Signal::new(leg_a, SignalKind::EnterLong, 0.8).with_group(group);
+    orchestrator
+        .on_signal(&first, &group_ctx(leg_a))
+        .await
+        .unwrap();
+    assert!(canceled.lock().unwrap().is_empty());
+
+    let second = Signal::new(leg_b, SignalKind::EnterShort, 0.8).with_group(group);
+    assert!(orchestrator
+        .on_signal(&second, &group_ctx(leg_b))
+        .await
+        .is_err());
+
+    let canceled = canceled.lock().unwrap();
+    assert_eq!(canceled.len(), 1);
+    assert_eq!(canceled[0], ("order-1".to_string(), leg_a));
+}
+
+#[tokio::test]
+async fn rejected_order_update_cancels_group_siblings() {
+    let leg_a = Symbol::from("BINANCE:ETHUSDT");
+    let leg_b = Symbol::from("BINANCE:SOLUSDT");
+    let unrelated = Symbol::from("BINANCE:XRPUSDT");
+    let (client, canceled) = SelectiveRejectClient::new(Symbol::from("BINANCE:DOGEUSDT"));
+    let client: Arc<dyn ExecutionClient> = Arc::new(client);
+    let orchestrator = group_orchestrator(client).await;
+    let group = Uuid::new_v4();
+
+    for (symbol, kind) in [
+        (leg_a, SignalKind::EnterLong),
+        (leg_b, SignalKind::EnterShort),
+    ] {
+        let signal = Signal::new(symbol, kind, 0.8).with_group(group);
+        orchestrator
+            .on_signal(&signal, &group_ctx(symbol))
+            .await
+            .unwrap();
+    }
+    let standalone = Signal::new(unrelated, SignalKind::EnterLong, 0.8);
+    orchestrator
+        .on_signal(&standalone, &group_ctx(unrelated))
+        .await
+        .unwrap();
+
+    let now = chrono::Utc::now();
+    let rejected = tesser_core::Order {
+        id: "order-2".into(),
+        request: tesser_core::OrderRequest {
+            symbol: leg_b,
+            side: tesser_core::Side::Sell,
+            order_type: tesser_core::OrderType::Market,
+            quantity: Decimal::ONE,
+            price: None,
+            trigger_price: None,
+            time_in_force: None,
+            client_order_id: None,
+            take_profit: None,
+            stop_loss: None,
+            display_quantity: None,
+        },
+        status: tesser_core::OrderStatus::Rejected,
+        filled_quantity: Decimal::ZERO,
+        avg_fill_price: None,
+        created_at: now,
+        updated_at: now,
+    };
+    orchestrator.on_order_update(&rejected).await;
+
+    let canceled = canceled.lock().unwrap();
+    assert_eq!(canceled.as_slice(), &[("order-1".to_string(), leg_a)]);
+}
+
+#[tokio::test]
+async fn legs_after_group_failure_are_not_submitted() {
+    let leg_a = Symbol::from("BINANCE:BTCUSDT");
+    let leg_b = Symbol::from("BINANCE:ETHUSDT");
+    let (client, canceled) = SelectiveRejectClient::new(leg_a);
+    let client = Arc::new(client);
+    let orchestrator = group_orchestrator(client.clone()).await;
+    let group = Uuid::new_v4();
+
+    let first = Signal::new(leg_a, SignalKind::EnterLong, 0.8).with_group(group);
+    assert!(orchestrator
+        .on_signal(&first, &group_ctx(leg_a))
+        .await
+        .is_err());
+    let second = Signal::new(leg_b, SignalKind::EnterShort, 0.8).with_group(group);
+    orchestrator
+        .on_signal(&second, &group_ctx(leg_b))
+        .await
+        .unwrap();
+
+    assert_eq!(*client.placed.lock().unwrap(), 0);
+    assert!(canceled.lock().unwrap().is_empty());
+}
+
+fn min_notional_registry(symbol: Symbol, min_notional: Decimal) -> Arc<MarketRegistry> {
+    let instrument = Instrument {
+        symbol,
+        base: AssetId::from_code(symbol.exchange, "BTC"),
+        quote: AssetId::from_code(symbol.exchange, "USDT"),
+        kind: InstrumentKind::LinearPerpetual,
+        settlement_currency: AssetId::from_code(symbol.exchange, "USDT"),
+        tick_size: Decimal::new(1, 1),
+        lot_size: Decimal::new(1, 3),
+        min_notional: Some(min_notional),
+    };
+    Arc::new(MarketRegistry::from_instruments(vec![instrument]).unwrap())
+}
+
+async fn submitted_quantities(min_notional: Decimal, policy: MinNotionalPolicy) -> Vec<Decimal> {
+    submitted_quantities_for(SignalKind::EnterLong, min_notional, policy).await
+}
+
+async fn submitted_quantities_for(
+    kind: SignalKind,
+    min_notional: Decimal,
+    policy: MinNotionalPolicy,
+) -> Vec<Decimal> {
+    let symbol = Symbol::from("BINANCE:BTCUSDT");
+    let (client, _) = SelectiveRejectClient::new(Symbol::from("BINANCE:ETHUSDT"));
+    let client = Arc::new(client);
+    let orchestrator = group_orchestrator(client.clone())
+        .await
+        .with_min_notional(min_notional_registry(symbol, min_notional), policy);
+
+    // Fixed size of 1 at a last price of 100 gives a notional of 100.
+    let signal = Signal::new(symbol, kind, 0.8);
+    let ctx = RiskContext {
+        signed_position_qty: Decimal::ONE,
+        ..group_ctx(symbol)
+    };
+    orchestrator.on_signal(&signal, &ctx).await.unwrap();
+    let requests = client.requests.lock().unwrap();
+    requests.iter().map(|request| request.quantity).collect()
+}
+
+#[tokio::test]
+async fn order_below_min_notional_is_dropped() {
+    let quantities = submitted_quantities(Decimal::from(101), MinNotionalPolicy::Drop).await;
+    assert!(quantities.is_empty());
+}
+
+#[tokio::test]
+async fn order_above_min_notional_passes_through() {
+    let quantities = submitted_quantities(Decimal::from(99), MinNotionalPolicy::Drop).await;
+    assert_eq!(quantities, vec![Decimal::ONE]);
+}
+
+#[tokio::test]
+async fn exits_below_min_notional_are_never_dropped_or_scaled() {
+    for policy in [MinNotionalPolicy::Drop, MinNotionalPolicy::ScaleUp] {
+        let quantities =
+            submitted_quantities_for(SignalKind::ExitLong, Decimal::from(500), policy).await;
+        assert_eq!(quantities, vec![Decimal::ONE], "{policy:?}");
+    }
+}
+
+#[tokio::test]
+async fn order_below_min_notional_scales_up_to_lot_multiple() {
+    let quantities =
+        submitted_quantities(Decimal::new(1_5005, 2), MinNotionalPolicy::ScaleUp).await;
+    // 150.05 / 100 = 1.5005, rounded up to the 0.001 lot size.
+    assert_eq!(quantities, vec![Decimal::new(1_501, 3)]);
+}
+
+#[tokio::test]
+async fn expired_signals_are_dropped_before_placing_orders() {
+    let symbol = Symbol::from("BINANCE:BTCUSDT");
+    let (client, _) = SelectiveRejectClient::new(Symbol::from("BINANCE:ETHUSDT"));
+    let client = Arc::new(client);
+    let now = chrono::Utc::now();
+    let orchestrator = group_orchestrator(client.clone())
+        .await
+        .with_clock(Arc::new(move || now));
+    let ctx = group_ctx(symbol);
+
+    let stale = Signal::new(symbol, SignalKind::EnterLong, 0.9)
+        .with_valid_until(now - Duration::seconds(5));
+    orchestrator.on_signal(&stale, &ctx).await.unwrap();
+    assert!(client.requests.lock().unwrap().is_empty());
+    assert_eq!(orchestrator.stale_signals_dropped(), 1);
+
+    let fresh = Signal::new(symbol, SignalKind::EnterLong, 0.9)
+        .with_valid_until(now + Duration::seconds(5));
+    orchestrator.on_signal(&fresh, &ctx).await.unwrap();
+    let requests = client.requests.lock().unwrap();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].symbol, symbol);
+    assert_eq!(orchestrator.stale_signals_dropped(), 1);
+}
+
+async fn flatten_requests(signed_position_qty: Decimal) -> Vec<tesser_core::OrderRequest> {
+    let symbol = Symbol::from("BINANCE:BTCUSDT");
+    let (client, _) = SelectiveRejectClient::new(Symbol::from("BINANCE:ETHUSDT"));
+    let client = Arc::new(client);
+    let orchestrator = group_orchestrator(client.clone()).await;
+    let ctx = RiskContext {
+        signed_position_qty,
+        ..group_ctx(symbol)
+    };
+    let signal = Signal::new(symbol, SignalKind::Flatten, 1.0);
+    orchestrator.on_signal(&signal, &ctx).await.unwrap();
+    let requests = client.requests.lock().unwrap().clone();
+    requests
+}
+
+#[tokio::test]
+async fn flatten_on_long_sells_the_full_position() {
+    let requests = flatten_requests(Decimal::new(25, 1)).await;
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].side, Side::Sell);
+    assert_eq!(requests[0].quantity, Decimal::new(25, 1));
+}
+
+#[tokio::test]
+async fn flatten_on_short_buys_back_the_full_position() {
+    let requests = flatten_requests(Decimal::new(-15, 1)).await;
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].side, Side::Buy);
+    assert_eq!(requests[0].quantity, Decimal::new(15, 1));
+}
+
+#[tokio::test]
+async fn flatten_when_flat_places_nothing() {
+    assert!(flatten_requests(Decimal::ZERO).await.is_empty());
+}
+
+fn tick(symbol: Symbol, price: i64) -> Tick {
+    let now = chrono::Utc::now();
+    Tick {
+        symbol,
+        price: Decimal::from(price),
+        size: Decimal::ONE,
+        side: Side::Buy,
+        exchange_timestamp: now,
+        received_at: now,
+    }
+}
+
+#[tokio::test]
+async fn trailing_stop_state_is_listed_while_running() {
+    let symbol = Symbol::from("BINANCE:BTCUSDT");
+    let (client, _) = SelectiveRejectClient::new(Symbol::from("BINANCE:ETHUSDT"));
+    let client = Arc::new(client);
+    let orchestrator = group_orchestrator(client.clone()).await;
+    let signal =
+        Signal::new(symbol, SignalKind::ExitLong, 0.9).with_hint(ExecutionHint::TrailingStop {
+            activation_price: Decimal::from(105),
+            callback_rate: Decimal::new(2, 2),
+        });
+    orchestrator
+        .on_signal(&signal, &group_ctx(symbol))
+        .await
+        .unwrap();
+
+    let listed = orchestrator.algorithm_snapshots();
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed[0].kind, "TRAILING_STOP");
+    assert_eq!(listed[0].status, AlgoStatus::Working);
+    assert_eq!(listed[0].state["activated"], false);
+
+    orchestrator.on_tick(&tick(symbol, 110)).await.unwrap();
+    orchestrator.on_tick(&tick(symbol, 112)).await.unwrap();
+    let listed = orchestrator.algorithm_snapshots();
+    let state = &listed[0].state;
+    assert_eq!(state["activated"], true);
+    assert_eq!(state["triggered"], false);
+    let highest: Decimal = serde_json::from_value(state["highest_market_price"].clone()).unwrap();
+    assert_eq!(highest, Decimal::from(112));
+    let requests = client.requests.lock().unwrap();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].order_type, OrderType::StopMarket);
+}
+
+/// Arms a trailing stop at 110 (2% callback) and returns the resting stop order id.
+async fn armed_trailing_stop(
+    client: Arc<SelectiveRejectClient>,
+    symbol: Symbol,
+) -> (OrderOrchestrator, String) {
+    let orchestrator = group_orchestrator(client.clone()).await;
+    let signal =
+        Signal::new(symbol, SignalKind::ExitLong, 0.9).with_hint(ExecutionHint::TrailingStop {
+            activation_price: Decimal::from(105),
+            callback_rate: Decimal::new(2, 2),
+        });
+    orchestrator
+        .on_signal(&signal, &group_ctx(symbol))
+        .await
+        .unwrap();
+    orchestrator.on_tick(&tick(symbol, 110)).await.unwrap();
+    let requests = client.requests.lock().unwrap().clone();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].trigger_price, Some(Decimal::new(1078, 1)));
+    (orchestrator, "order-1".to_string())
+}
+
+#[tokio::test]
+async fn replace_or_cancel_amends_a_working_stop() {
+    let symbol = Symbol::from("BINANCE:BTCUSDT");
+    let (client, canceled) = SelectiveRejectClient::new(Symbol::from("BINANCE:ETHUSDT"));
+    let client = Arc::new(client);
+    let (orchestrator, stop_id) = armed_trailing_stop(client.clone(), symbol).await;
+
+    orchestrator.on_tick(&tick(symbol, 120)).await.unwrap();
+
+    let amended = client.amended.lock().unwrap().clone();
+    assert_eq!(amended.len(), 1);
+    assert_eq!(amended[0].order_id, stop_id);
+    assert_eq!(amended[0].new_trigger_price, Some(Decimal::new(1176, 1)));
+    assert!(canceled.lock().unwrap().is_empty());
+    let state = &orchestrator.algorithm_snapshots()[0].state;
+    assert_eq!(state["stop_order_id"], stop_id);
+}
+
+#[tokio::test]
+async fn replace_or_cancel_cancels_a_partially_filled_stop() {
+    let symbol = Symbol::from("BINANCE:BTCUSDT");
+    let (client, canceled) = SelectiveRejectClient::new(Symbol::from("BINANCE:ETHUSDT"));
+    let client = Arc::new(client);
+    let (orchestrator, stop_id) = armed_trailing_stop(client.clone(), symbol).await;
+
+    orchestrator
+        .on_fill(&Fill {
+            order_id: stop_id.clone(),
+            symbol,
+            side: Side::Sell,
+            fill_price: Decimal::from(108),
+            fill_quantity: Decimal::new(5, 1),
+            fee: None,
+            fee_asset: None,
+            timestamp: chrono::Utc::now(),
+        })
+        .await
+        .unwrap();
+    orchestrator.on_tick(&tick(symbol, 120)).await.unwrap();
+
+    assert!(client.amended.lock().unwrap().is_empty());
+    assert_eq!(*canceled.lock().unwrap(), vec![(stop_id, symbol)]);
+    let state = &orchestrator.algorithm_snapshots()[0].state;
+    assert!(state["stop_order_id"].is_null());
+
+    // The remainder is protected again by a fresh stop at the tighter level.
+    orchestrator.on_tick(&tick(symbol, 120)).await.unwrap();
+    let requests = client.requests.lock().unwrap();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[1].order_type, OrderType::StopMarket);
+    assert_eq!(requests[1].quantity, Decimal::new(5, 1));
+    assert_eq!(requests[1].trigger_price, Some(Decimal::new(1176, 1)));
+}
+
+async fn mapped_orchestrator(
+    policy: OrderMappingPolicy,
+    symbol: Symbol,
+) -> (OrderOrchestrator, Arc<SelectiveRejectClient>) {
+    let (client, _) = SelectiveRejectClient::new(Symbol::from("BINANCE:ETHUSDT"));
+    let client = Arc::new(client);
+    let orchestrator = group_orchestrator(client.clone())
+        .await
+        .with_order_mapping(policy);
+    orchestrator.on_order_book(&OrderBook {
+        symbol,
+        bids: vec![OrderBookLevel {
+            price: Decimal::new(9990, 2),
+            size: Decimal::ONE,
+        }],
+        asks: vec![OrderBookLevel {
+            price: Decimal::new(10010, 2),
+            size: Decimal::ONE,
+        }],
+        timestamp: chrono::Utc::now(),
+        exchange_checksum: None,
+        local_checksum: None,
+    });
+    (orchestrator, client)
+}
+
+async fn mapped_entry_request(policy: OrderMappingPolicy) -> tesser_core::OrderRequest {
+    let symbol = Symbol::from("BINANCE:BTCUSDT");
+    let (orchestrator, client) = mapped_orchestrator(policy, symbol).await;
+    let signal = Signal::new(symbol, SignalKind::EnterLong, 1.0);
+    orchestrator
+        .on_signal(&signal, &group_ctx(symbol))
+        .await
+        .unwrap();
+    let mut requests = client.requests.lock().unwrap().clone();
+    assert_eq!(requests.len(), 1);
+    requests.remove(0)
+}
+
+#[tokio::test]
+async fn enter_long_under_limit_at_bid_policy_rests_at_the_bid() {
+    let policy = OrderMappingPolicy::default().with_kind(
+        SignalKind::EnterLong,
+        OrderMapping::Limit {
+            reference: PriceReference::Bid,
+            offset_ticks: 0,
+        },
+    );
+    let request = mapped_entry_request(policy).await;
+    assert_eq!(request.order_type, OrderType::Limit);
+    assert_eq!(request.price, Some(Decimal::new(9990, 2)));
+    assert_eq!(request.side, Side::Buy);
+}
+
+#[tokio::test]
+async fn enter_long_under_market_policy_sends_a_market_order() {
+    let request = mapped_entry_request(OrderMappingPolicy::default()).await;
+    assert_eq!(request.order_type, OrderType::Market);
+    assert_eq!(request.price, None);
+}
+
+fn entry_fill(symbol: Symbol, order_id: &str, quantity: Decimal) -> Fill {
+    Fill {
+        order_id: order_id.into(),
+        symbol,
+        side: Side::Buy,
+        fill_price: Decimal::new(9990, 2),
+        fill_quantity: quantity,
+        fee: None,
+        fee_asset: None,
+        timestamp: chrono::Utc::now(),
+    }
+}
+
+#[tokio::test]
+async fn resting_limit_entry_is_protected_only_as_it_fills() {
+    let symbol = Symbol::from("BINANCE:BTCUSDT");
+    let policy = OrderMappingPolicy::limit(PriceReference::Bid, 0);
+    let (orchestrator, client) = mapped_orchestrator(policy, symbol).await;
+    let mut signal = Signal::new(symbol, SignalKind::EnterLong, 1.0);
+    signal.stop_loss = Some(Decimal::from(95));
+    signal.take_profit = Some(Decimal::from(110));
+    orchestrator
+        .on_signal(&signal, &group_ctx(symbol))
+        .await
+        .unwrap();
+    {
+        let requests = client.requests.lock().unwrap();
+        assert_eq!(
+            requests.len(),
+            1,
+            "no protective orders before the entry fills"
+        );
+        assert_eq!(requests[0].order_type, OrderType::Limit);
+    }
+
+    orchestrator
+        .on_fill(&entry_fill(symbol, "order-1", Decimal::new(4, 1)))
+        .await
+        .unwrap();
+    orchestrator
+        .on_fill(&entry_fill(symbol, "order-1", Decimal::new(6, 1)))
+        .await
+        .unwrap();
+    let requests = client.requests.lock().unwrap().clone();
+    assert_eq!(requests.len(), 5);
+    for (pair, quantity) in [
+        (&requests[1..3], Decimal::new(4, 1)),
+        (&requests[3..5], Decimal::new(6, 1)),
+    ] {
+        assert!(pair
+            .iter()
+            .all(|request| request.order_type == OrderType::StopMarket
+                && request.side == Side::Sell
+                && request.quantity == quantity));
+        assert_eq!(pair[0].trigger_price, Some(Decimal::from(95)));
+        assert_eq!(pair[1].trigger_price, Some(Decimal::from(110)));
+    }
+    assert_eq!(
+        requests[3].client_order_id,
+        Some(format!("{}-2-sl", signal.id))
+    );
+
+    // The entry is fully protected; further fills for it place nothing.
+    orchestrator
+        .on_fill(&entry_fill(symbol, "order-1", Decimal::ONE))
+        .await
+        .unwrap();
+    assert_eq!(client.requests.lock().unwrap().len(), 5);
+}
+
+#[tokio::test]
+async fn market_entry_sends_protective_orders_immediately() {
+    let symbol = Symbol::from("BINANCE:BTCUSDT");
+    let (orchestrator, client) = mapped_orchestrator(OrderMappingPolicy::default(), symbol).await;
+    let mut signal = Signal::new(symbol, SignalKind::EnterLong, 1.0);
+    signal.stop_loss = Some(Decimal::from(95));
+    orchestrator
+        .on_signal(&signal, &group_ctx(symbol))
+        .await
+        .unwrap();
+    let requests = client.requests.lock().unwrap();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[1].order_type, OrderType::StopMarket);
+    assert_eq!(requests[1].quantity, Decimal::ONE);
+}
+
+#[tokio::test]
+async fn paper_starting_balance_bounds_percent_sizing() {
+    let symbol = Symbol::from("BTCUSDT");
+    let usdt = AssetId::from("USDT");
+    let client = PaperFactory::default()
+        .create_execution_client(&serde_json::json!({
+            "balance_currency": "USDT",
+            "balances": { "USDT": "2000" },
+        }))
+        .await
+        .unwrap();
+    let equity = client
+        .account_balances()
+        .await
+        .unwrap()
+        .into_iter()
+        .find(|balance| balance.asset == usdt)
+        .map(|balance| balance.available)
+        .unwrap();
+    assert_eq!(equity, Decimal::from(2_000));
+
+    let engine = ExecutionEngine::new(
+        client,
+        Box::new(PortfolioPercentSizer {
+            percent: Decimal::new(5, 1),
+        }),
+        Arc::new(NoopRiskChecker),
+    );
+    let ctx = RiskContext {
+        portfolio_equity: equity,
+        exchange_equity: equity,
+        ..group_ctx(symbol)
+    };
+    let order = engine
+        .handle_signal(Signal::new(symbol, SignalKind::EnterLong, 1.0), ctx)
+        .await
+        .unwrap()
+        .expect("order placed");
+    assert_eq!(order.request.quantity, Decimal::from(10));
+    assert!(order.request.quantity * Decimal::from(100) <= equity);
+}
+
+/// Orchestrator with a 60s post-stop cooldown whose stop-loss on `symbol` filled at `stopped_at`.
+async fn stopped_out_orchestrator(
+    client: Arc<SelectiveRejectClient>,
+    symbol: Symbol,
+    clock: Arc<Mutex<chrono::DateTime<chrono::Utc>>>,
+) -> OrderOrchestrator {
+    let now = clock.clone();
+    let orchestrator = group_orchestrator(client)
+        .await
+        .with_clock(Arc::new(move || *now.lock().unwrap()))
+        .with_cooldown_after_stop(Duration::seconds(60));
+    let stopped_at = *clock.lock().unwrap();
+    let stop = Order {
+        id: "stop-1".into(),
+        request: OrderRequest {
+            symbol,
+            side: Side::Sell,
+            order_type: OrderType::StopMarket,
+            quantity: Decimal::ONE,
+            price: None,
+            trigger_price: Some(Decimal::from(95)),
+            time_in_force: None,
+            client_order_id: Some("entry-1-sl".into()),
+            take_profit: None,
+            stop_loss: None,
+            display_quantity: None,
+        },
+        status: OrderStatus::Accepted,
+        filled_quantity: Decimal::ZERO,
+        avg_fill_price: None,
+        created_at: stopped_at,
+        updated_at: stopped_at,
+    };
+    orchestrator.on_order_update(&stop).await;
+    orchestrator
+        .on_fill(&Fill {
+            order_id: stop.id.clone(),
+            symbol,
+            side: Side::Sell,
+            fill_price: Decimal::from(95),
+            fill_quantity: Decimal::ONE,
+            fee: None,
+            fee_asset: None,
+            timestamp: stopped_at,
+        })
+        .await
+        .unwrap();
+    orchestrator
+}
+
+#[tokio::test]
+async fn entry_within_stop_cooldown_is_suppressed() {
+    let symbol = Symbol::from("BINANCE:BTCUSDT");
+    let (client, _) = SelectiveRejectClient::new(Symbol::from("BINANCE:ETHUSDT"));
+    let client = Arc::new(client);
+    let start = chrono::Utc::now();
+    let clock = Arc::new(Mutex::new(start));
+    let orchestrator = stopped_out_orchestrator(client.clone(), symbol, clock.clone()).await;
+
+    *clock.lock().unwrap() = start + Duration::seconds(30);
+    let entry = Signal::new(symbol, SignalKind::EnterLong, 0.9);
+    orchestrator
+        .on_signal(&entry, &group_ctx(symbol))
+        .await
+        .unwrap();
+    assert!(client.requests.lock().unwrap().is_empty());
+    assert_eq!(orchestrator.cooldown_signals_dropped(), 1);
+
+    // Exits are never held back by the cooldown.
+    let exit = Signal::new(symbol, SignalKind::ExitShort, 0.9);
+    orchestrator
+        .on_signal(&exit, &group_ctx(symbol))
+        .await
+        .unwrap();
+    assert_eq!(client.requests.lock().unwrap().len(), 1);
+    assert_eq!(orchestrator.cooldown_signals_dropped(), 1);
+}
+
+#[tokio::test]
+async fn entry_after_stop_cooldown_passes() {
+    let symbol = Symbol::from("BINANCE:BTCUSDT");
+    let (client, _) = SelectiveRejectClient::new(Symbol::from("BINANCE:ETHUSDT"));
+    let client = Arc::new(client);
+    let start = chrono::Utc::now();
+    let clock = Arc::new(Mutex::new(start));
+    let orchestrator = stopped_out_orchestrator(client.clone(), symbol, clock.clone()).await;
+
+    *clock.lock().unwrap() = start + Duration::seconds(61);
+    let entry = Signal::new(symbol, SignalKind::EnterShort, 0.9);
+    orchestrator
+        .on_signal(&entry, &group_ctx(symbol))
+        .await
+        .unwrap();
+    let requests = client.requests.lock().unwrap();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].side, Side::Sell);
+    assert_eq!(orchestrator.cooldown_signals_dropped(), 0);
+}
@@ -0,0 +1,64 @@
+use tesser_execution::wasm::{plugin_failure, PluginPanic};
+use tesser_wasm::guest::PluginRuntime;
+use tesser_wasm::{ExecutionPlugin, PluginError, PluginInitContext, PluginResult};
+
+/// Plugin whose first timer callback panics.
+#[derive(Default)]
+struct FlakyTimerPlugin {
+    timers: u32,
+}
+
+impl ExecutionPlugin for FlakyTimerPlugin {
+    fn init(&mut self, _ctx: PluginInitContext) -> Result<PluginResult, PluginError> {
+        Ok(PluginResult::new())
+    }
+
+    fn on_timer(&mut self) -> Result<PluginResult, PluginError> {
+        self.timers += 1;
+        if self.timers == 1 {
+            panic!("timer exploded");
+        }
+        Ok(PluginResult::builder()
+            .log(format!("timer {}", self.timers))
+            .build())
+    }
+
+    fn snapshot(&mut self) -> Result<serde_json::Value, PluginError> {
+        Err(PluginError::from("snapshot unsupported"))
+    }
+}
+
+#[test]
+fn panic_in_on_timer_reaches_host_as_panic_error() {
+    let runtime = PluginRuntime::<FlakyTimerPlugin>::default();
+
+    let raw = runtime.call_on_timer().unwrap_err();
+    let decoded = PluginError::decode(&raw);
+    assert!(decoded.is_panic(), "unexpected error: {decoded:?}");
+    assert!(decoded.message().contains("timer exploded"));
+
+    let err = plugin_failure(raw);
+    let panic = err.downcast_ref::<PluginPanic>().expect("panic error");
+    assert!(panic.0.contains("timer exploded"));
+
+    let response = runtime.call_on_timer().expect("plugin usable after panic");
+    let result: PluginResult = serde_json::from_str(&response).unwrap();
+    assert_eq!(result.logs, vec!["timer 2".to_string()]);
+}
+
+#[test]
+fn ordinary_errors_are_not_reported_as_panics() {
+    let runtime = PluginRuntime::<FlakyTimerPlugin>::default();
+    let raw = runtime.call_snapshot().unwrap_err();
+    assert_eq!(
+        PluginError::decode(&raw),
+        PluginError::Message("snapshot unsupported".into())
+    );
+    let err = plugin_failure(raw);
+    assert!(err.downcast_ref::<PluginPanic>().is_none());
+    assert_eq!(err.to_string(), "snapshot unsupported");
+    assert_eq!(
+        PluginError::decode("legacy plain error"),
+        PluginError::Message("legacy plain error".into())
+    );
+}
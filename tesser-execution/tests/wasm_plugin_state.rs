@@ -0,0 +1,139 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use rust_decimal::Decimal;
+use serde_json::{json, Value};
+use tesser_core::{Signal, SignalKind};
+use tesser_execution::{
+    ExecutionAlgorithm, RiskContext, WasmAlgorithm, WasmAlgorithmState, WasmPluginEngine,
+};
+use tesser_wasm::guest::PluginRuntime;
+use tesser_wasm::{
+    ExecutionPlugin, PluginChildOrderAction, PluginError, PluginInitContext, PluginOrderRequest,
+    PluginOrderType, PluginResult, PluginRiskContext, PluginSignal,
+};
+
+/// Plugin slicing its target into one-unit clips, one per timer, like the chase example.
+#[derive(Default)]
+struct ClipPlugin {
+    symbol: String,
+    remaining: Decimal,
+}
+
+impl ExecutionPlugin for ClipPlugin {
+    fn init(&mut self, ctx: PluginInitContext) -> Result<PluginResult, PluginError> {
+        self.symbol = ctx.signal.symbol;
+        self.remaining = ctx.signal.target_quantity;
+        Ok(PluginResult::new())
+    }
+
+    fn on_timer(&mut self) -> Result<PluginResult, PluginError> {
+        if self.remaining <= Decimal::ZERO {
+            return Ok(PluginResult::new());
+        }
+        let slice = Decimal::ONE.min(self.remaining);
+        self.remaining -= slice;
+        let order = PluginOrderRequest {
+            symbol: self.symbol.clone(),
+            side: tesser_wasm::PluginSide::Buy,
+            order_type: PluginOrderType::Market,
+            quantity: Some(slice),
+            notional: None,
+            price: None,
+            trigger_price: None,
+            time_in_force: None,
+            client_order_id: None,
+            take_profit: None,
+            stop_loss: None,
+            display_quantity: None,
+        };
+        Ok(PluginResult::default().with_order(PluginChildOrderAction::Place(order)))
+    }
+
+    fn snapshot(&mut self) -> Result<Value, PluginError> {
+        Ok(json!({ "remaining": self.remaining }))
+    }
+
+    fn restore(&mut self, state: Value) -> Result<(), PluginError> {
+        if let Some(remaining) = state.get("remaining") {
+            self.remaining =
+                serde_json::from_value(remaining.clone()).map_err(PluginError::from)?;
+        }
+        Ok(())
+    }
+}
+
+fn init_payload() -> String {
+    let context = PluginInitContext {
+        plugin: "clip".into(),
+        params: Value::Null,
+        signal: PluginSignal::test("BTCUSDT", Decimal::new(5, 0)),
+        risk: PluginRiskContext::default(),
+        metadata: Value::Null,
+    };
+    serde_json::to_string(&context).unwrap()
+}
+
+fn timer_quantity(runtime: &PluginRuntime<ClipPlugin>) -> Option<Decimal> {
+    let raw = runtime.call_on_timer().unwrap();
+    let result: PluginResult = serde_json::from_str(&raw).unwrap();
+    result.orders.first().map(|order| match &order.action {
+        PluginChildOrderAction::Place(order) => order.quantity,
+        other => panic!("unexpected action {other:?}"),
+    })
+}
+
+#[test]
+fn restored_plugin_resumes_with_remaining_quantity() {
+    let original = PluginRuntime::<ClipPlugin>::default();
+    original.call_init(init_payload()).unwrap();
+    assert_eq!(timer_quantity(&original), Some(Decimal::ONE));
+    assert_eq!(timer_quantity(&original), Some(Decimal::ONE));
+    let snapshot = original.call_snapshot().unwrap();
+
+    // Mirror the host restart path: init with the original context, then restore.
+    let restarted = PluginRuntime::<ClipPlugin>::default();
+    restarted.call_init(init_payload()).unwrap();
+    restarted.call_restore(snapshot.clone()).unwrap();
+    assert_eq!(restarted.call_snapshot().unwrap(), snapshot);
+
+    let resumed: Vec<_> = std::iter::from_fn(|| timer_quantity(&restarted)).collect();
+    assert_eq!(resumed, vec![Decimal::ONE; 3]);
+}
+
+/// Component counting down from 5 on each timer and snapshotting `{"remaining":N}`; see the
+/// fixture header.
+const COUNTING_PLUGIN: &str = "counting_timer.wat";
+
+fn remaining(algo: &WasmAlgorithm) -> Value {
+    algo.state()["plugin_state"]["remaining"].clone()
+}
+
+#[test]
+fn host_resumes_plugin_from_a_persisted_snapshot() {
+    let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+    let engine = Arc::new(WasmPluginEngine::new(dir).unwrap());
+    let signal = Signal::new("BTCUSDT", SignalKind::EnterLong, 1.0);
+    let context = WasmAlgorithm::context_from_signal(
+        COUNTING_PLUGIN,
+        Value::Null,
+        &signal,
+        Decimal::ONE,
+        &RiskContext::default(),
+    );
+    let mut original = WasmAlgorithm::new(engine.clone(), context).unwrap();
+    original.start().unwrap();
+    original.on_timer().unwrap();
+    original.on_timer().unwrap();
+    assert_eq!(remaining(&original), json!(3));
+
+    // Mirror the orchestrator restart path: persist `state()` and rebuild a fresh instance.
+    let state: WasmAlgorithmState = serde_json::from_value(original.state()).unwrap();
+    let mut restarted = WasmAlgorithm::from_snapshot(engine, *original.id(), state).unwrap();
+    assert_eq!(restarted.id(), original.id());
+    assert_eq!(remaining(&restarted), json!(3));
+
+    // `init` resets the counter to 5, so only a restored instance counts down from 3.
+    restarted.on_timer().unwrap();
+    assert_eq!(remaining(&restarted), json!(2));
+}
@@ -0,0 +1,55 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use chrono::Utc;
+use rust_decimal::Decimal;
+use serde_json::Value;
+use tesser_core::{Side, Signal, SignalKind, Tick};
+use tesser_execution::wasm::PluginPanic;
+use tesser_execution::{ExecutionAlgorithm, RiskContext, WasmAlgorithm, WasmPluginEngine};
+
+/// Component whose `on-tick` traps like a `panic = "abort"` plugin; see the fixture header.
+const TRAPPING_PLUGIN: &str = "trapping_tick.wat";
+
+fn trapping_plugin() -> WasmAlgorithm {
+    let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+    let engine = Arc::new(WasmPluginEngine::new(dir).unwrap());
+    let signal = Signal::new("BTCUSDT", SignalKind::EnterLong, 1.0);
+    let context = WasmAlgorithm::context_from_signal(
+        TRAPPING_PLUGIN,
+        Value::Null,
+        &signal,
+        Decimal::ONE,
+        &RiskContext::default(),
+    );
+    WasmAlgorithm::new(engine, context).unwrap()
+}
+
+fn tick() -> Tick {
+    Tick {
+        symbol: "BTCUSDT".into(),
+        price: Decimal::new(100, 0),
+        size: Decimal::ONE,
+        side: Side::Buy,
+        exchange_timestamp: Utc::now(),
+        received_at: Utc::now(),
+    }
+}
+
+#[test]
+fn trapping_plugin_reports_panic_and_is_reinstantiated() {
+    let mut algo = trapping_plugin();
+    assert!(algo.start().unwrap().is_empty());
+
+    let err = algo.on_tick(&tick()).unwrap_err();
+    let panic = err
+        .downcast_ref::<PluginPanic>()
+        .unwrap_or_else(|| panic!("trap not reported as a panic: {err:#}"));
+    assert!(panic.0.contains("on_tick trapped"), "{}", panic.0);
+
+    // wasmtime refuses to re-enter a trapped instance, so these only pass on a fresh one.
+    assert!(algo.on_timer().unwrap().is_empty());
+    let err = algo.on_tick(&tick()).unwrap_err();
+    assert!(err.downcast_ref::<PluginPanic>().is_some(), "{err:#}");
+    assert!(algo.on_timer().unwrap().is_empty());
+}
@@ -28,8 +28,146 @@ where
         self.second.next(intermediate)
     }
 
+    fn current(&self) -> Option<Self::Output> {
+        self.first.current()?;
+        self.second.current()
+    }
+
     fn reset(&mut self) {
         self.first.reset();
         self.second.reset();
     }
 }
+
+/// Builder for chains of any number of indicators, where each stage consumes the previous
+/// stage's output.
+///
+/// Stage compatibility is checked at compile time: [`IndicatorPipeline::then`] only accepts an
+/// indicator whose `Input` matches the current tail's `Output`. The pipeline emits a value once
+/// every stage has warmed up, so its warmup is the sum of the stages' warmups less one sample
+/// for each stage after the first.
+///
+/// ```
+/// use tesser_indicators::indicators::{Ema, Rsi, Sma};
+/// use tesser_indicators::{Indicator, IndicatorPipeline};
+///
+/// let mut pipeline = IndicatorPipeline::new(Ema::<f64>::new(3).unwrap())
+///     .then(Rsi::new(3).unwrap())
+///     .then(Sma::new(2).unwrap());
+/// assert_eq!(pipeline.stages(), 3);
+/// assert_eq!(pipeline.next(101.0), None);
+/// ```
+pub struct IndicatorPipeline<Chain> {
+    chain: Chain,
+    stages: usize,
+}
+
+impl<Chain> IndicatorPipeline<Chain>
+where
+    Chain: Indicator,
+{
+    /// Starts a pipeline with `first` as its only stage.
+    pub fn new(first: Chain) -> Self {
+        Self {
+            chain: first,
+            stages: 1,
+        }
+    }
+
+    /// Appends `next`, fed with the output of the current last stage.
+    #[must_use]
+    pub fn then<Next>(self, next: Next) -> IndicatorPipeline<PipedIndicator<Chain, Next>>
+    where
+        Next: Indicator<Input = Chain::Output>,
+    {
+        IndicatorPipeline {
+            chain: PipedIndicator::new(self.chain, next),
+            stages: self.stages + 1,
+        }
+    }
+
+    /// Number of indicators in the pipeline.
+    pub fn stages(&self) -> usize {
+        self.stages
+    }
+}
+
+impl<Chain> Indicator for IndicatorPipeline<Chain>
+where
+    Chain: Indicator,
+{
+    type Input = Chain::Input;
+    type Output = Chain::Output;
+
+    fn next(&mut self, input: Self::Input) -> Option<Self::Output> {
+        self.chain.next(input)
+    }
+
+    fn current(&self) -> Option<Self::Output> {
+        self.chain.current()
+    }
+
+    fn reset(&mut self) {
+        self.chain.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::Decimal;
+
+    use super::IndicatorPipeline;
+    use crate::indicators::{Ema, Rsi, Sma};
+    use crate::Indicator;
+
+    fn prices() -> Vec<Decimal> {
+        [
+            100, 102, 101, 104, 103, 107, 106, 105, 109, 111, 108, 112, 115, 113,
+        ]
+        .into_iter()
+        .map(Decimal::from)
+        .collect()
+    }
+
+    fn pipeline() -> IndicatorPipeline<impl Indicator<Input = Decimal, Output = Decimal>> {
+        IndicatorPipeline::new(Ema::new(3).unwrap())
+            .then(Rsi::new(3).unwrap())
+            .then(Sma::new(2).unwrap())
+    }
+
+    #[test]
+    fn three_stage_pipeline_matches_manual_chaining() {
+        let mut pipeline = pipeline();
+        assert_eq!(pipeline.stages(), 3);
+        let mut ema = Ema::new(3).unwrap();
+        let mut rsi = Rsi::new(3).unwrap();
+        let mut sma = Sma::new(2).unwrap();
+        for price in prices() {
+            let manual = ema
+                .next(price)
+                .and_then(|value| rsi.next(value))
+                .and_then(|value| sma.next(value));
+            assert_eq!(pipeline.next(price), manual);
+            if manual.is_some() {
+                assert_eq!(pipeline.current(), manual);
+            }
+        }
+    }
+
+    #[test]
+    fn pipeline_warmup_accumulates_across_stages() {
+        let mut pipeline = pipeline();
+        let outputs: Vec<_> = prices()
+            .into_iter()
+            .map(|price| pipeline.next(price))
+            .collect();
+        // EMA(3) emits on sample 3, RSI(3) needs 3 more EMA values and SMA(2) one more RSI value.
+        let first = outputs.iter().position(Option::is_some).unwrap();
+        assert_eq!(first + 1, 3 + 3 + 1);
+        assert!(outputs[first..].iter().all(Option::is_some));
+
+        pipeline.reset();
+        assert_eq!(pipeline.current(), None);
+        assert_eq!(pipeline.next(Decimal::from(100)), None);
+    }
+}
@@ -60,6 +60,20 @@ pub enum IndicatorError {
         /// Provided parameter value.
         value: Decimal,
     },
+    /// Returned when a parameter must fall inside a closed range.
+    #[error("{name} parameter '{parameter}' must be within [{min}, {max}] (got {value})")]
+    OutOfRange {
+        /// Human-readable indicator name.
+        name: &'static str,
+        /// Name of the invalid parameter (e.g., `quantile`).
+        parameter: &'static str,
+        /// Provided parameter value.
+        value: Decimal,
+        /// Inclusive lower bound.
+        min: Decimal,
+        /// Inclusive upper bound.
+        max: Decimal,
+    },
 }
 
 impl IndicatorError {
@@ -76,6 +90,23 @@ impl IndicatorError {
             value,
         }
     }
+
+    /// Helper constructor for out-of-range parameter errors.
+    pub fn out_of_range(
+        name: &'static str,
+        parameter: &'static str,
+        value: Decimal,
+        min: Decimal,
+        max: Decimal,
+    ) -> Self {
+        Self::OutOfRange {
+            name,
+            parameter,
+            value,
+            min,
+            max,
+        }
+    }
 }
 
 /// Core abstraction implemented by every indicator in the library.
@@ -88,6 +119,10 @@ pub trait Indicator {
     /// Consumes a new data point and returns the most recent value, if available.
     fn next(&mut self, input: Self::Input) -> Option<Self::Output>;
 
+    /// Returns the value produced by the most recent [`Indicator::next`] call without
+    /// feeding a new sample, or `None` before warmup.
+    fn current(&self) -> Option<Self::Output>;
+
     /// Resets the indicator to its initial state.
     fn reset(&mut self);
 
@@ -117,7 +152,7 @@ mod tests {
 
     use chrono::Utc;
     use rust_decimal::Decimal;
-    use tesser_core::{Candle, Interval, Symbol};
+    use tesser_core::{Candle, CandleStatus, Interval, Symbol};
 
     use super::{decimal_from_f64, Input};
 
@@ -139,6 +174,7 @@ mod tests {
             close: decimal_from_f64(12.5),
             volume: Decimal::from(100),
             timestamp: Utc::now(),
+            status: CandleStatus::Closed,
         };
 
         let decimal = candle.value();
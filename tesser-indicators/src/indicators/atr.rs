@@ -6,8 +6,13 @@ use tesser_core::Candle;
 use crate::core::{Indicator, IndicatorError};
 
 /// Average True Range indicator.
+///
+/// Emits the absolute ATR by default. [`Atr::as_percent`] switches the output to the normalized
+/// ATR (NATR), `ATR / close * 100`, which is comparable across symbols trading at very
+/// different price levels.
 pub struct Atr {
     period: usize,
+    percent: bool,
     prev_close: Option<Decimal>,
     atr: Option<Decimal>,
     warmup_sum: Decimal,
@@ -22,6 +27,7 @@ impl Atr {
         }
         Ok(Self {
             period,
+            percent: false,
             prev_close: None,
             atr: None,
             warmup_sum: Decimal::ZERO,
@@ -29,6 +35,31 @@ impl Atr {
         })
     }
 
+    /// Report ATR as a percentage of the latest close (NATR).
+    ///
+    /// Bars closing at zero yield `None` rather than dividing by zero; the underlying average
+    /// still advances.
+    #[must_use]
+    pub fn as_percent(mut self) -> Self {
+        self.percent = true;
+        self
+    }
+
+    /// Whether the output is normalized by the close price.
+    pub fn is_percent(&self) -> bool {
+        self.percent
+    }
+
+    fn normalize(&self, atr: Decimal, close: Decimal) -> Option<Decimal> {
+        if !self.percent {
+            Some(atr)
+        } else if close.is_zero() {
+            None
+        } else {
+            Some(atr / close * Decimal::ONE_HUNDRED)
+        }
+    }
+
     fn true_range(&self, candle: &Candle, prev_close: Decimal) -> Decimal {
         let high_low = candle.high - candle.low;
         let high_close = (candle.high - prev_close).abs();
@@ -50,7 +81,7 @@ impl Indicator for Atr {
             let factor = Decimal::from(self.period as i64 - 1);
             let next = (current * factor + tr) / Decimal::from(self.period as i64);
             self.atr = Some(next);
-            Some(next)
+            self.normalize(next, input.close)
         } else {
             self.warmup_sum += tr;
             self.warmup_count += 1;
@@ -58,13 +89,17 @@ impl Indicator for Atr {
                 let init = self.warmup_sum / Decimal::from(self.period as i64);
                 self.atr = Some(init);
                 self.warmup_sum = Decimal::ZERO;
-                Some(init)
+                self.normalize(init, input.close)
             } else {
                 None
             }
         }
     }
 
+    fn current(&self) -> Option<Self::Output> {
+        self.normalize(self.atr?, self.prev_close?)
+    }
+
     fn reset(&mut self) {
         self.prev_close = None;
         self.atr = None;
@@ -77,21 +112,37 @@ impl Indicator for Atr {
 mod tests {
     use super::*;
     use chrono::Utc;
-    use tesser_core::{Interval, Symbol};
+    use tesser_core::{CandleStatus, Interval, Symbol};
 
     fn candle(close: f64) -> Candle {
+        ranged_candle(close, 5.0)
+    }
+
+    fn ranged_candle(close: f64, half_range: f64) -> Candle {
         Candle {
             symbol: Symbol::from("BTCUSDT"),
             interval: Interval::OneMinute,
             open: Decimal::from_f64_retain(close).unwrap(),
-            high: Decimal::from_f64_retain(close + 5.0).unwrap(),
-            low: Decimal::from_f64_retain(close - 5.0).unwrap(),
+            high: Decimal::from_f64_retain(close + half_range).unwrap(),
+            low: Decimal::from_f64_retain(close - half_range).unwrap(),
             close: Decimal::from_f64_retain(close).unwrap(),
             volume: Decimal::ONE,
             timestamp: Utc::now(),
+            status: CandleStatus::Closed,
         }
     }
 
+    /// Feed a symbol whose bars span 2% of price around a slowly rising close.
+    fn last_output(mut atr: Atr, base: f64) -> Decimal {
+        (0..20)
+            .filter_map(|step| {
+                let close = base * (1.0 + step as f64 * 0.001);
+                atr.next(ranged_candle(close, close * 0.01))
+            })
+            .last()
+            .unwrap()
+    }
+
     #[test]
     fn atr_warms_up() {
         let mut atr = Atr::new(3).unwrap();
@@ -99,4 +150,38 @@ mod tests {
         assert!(atr.next(candle(101.0)).is_none());
         assert!(atr.next(candle(102.0)).is_some());
     }
+
+    #[test]
+    fn natr_is_comparable_across_price_levels() {
+        let high_atr = last_output(Atr::new(5).unwrap(), 50_000.0);
+        let low_atr = last_output(Atr::new(5).unwrap(), 0.5);
+        assert!(high_atr > low_atr * Decimal::from(10_000));
+
+        let high_natr = last_output(Atr::new(5).unwrap().as_percent(), 50_000.0);
+        let low_natr = last_output(Atr::new(5).unwrap().as_percent(), 0.5);
+        let tolerance = Decimal::new(1, 2);
+        assert!((high_natr - low_natr).abs() < tolerance);
+        assert!((high_natr - Decimal::TWO).abs() < Decimal::new(2, 1));
+    }
+
+    #[test]
+    fn natr_skips_zero_close() {
+        let mut atr = Atr::new(1).unwrap().as_percent();
+        assert!(atr.is_percent());
+        assert!(atr.next(ranged_candle(0.0, 0.0)).is_none());
+        let value = atr.next(ranged_candle(100.0, 1.0)).unwrap();
+        assert!(value > Decimal::ZERO);
+    }
+
+    #[test]
+    fn current_matches_latest_output() {
+        let mut atr = Atr::new(2).unwrap().as_percent();
+        assert!(atr.next(candle(100.0)).is_none());
+        assert_eq!(atr.current(), None);
+        for close in [101.0, 103.0, 99.0] {
+            let emitted = atr.next(candle(close));
+            assert!(emitted.is_some());
+            assert_eq!(atr.current(), emitted);
+        }
+    }
 }
@@ -18,6 +18,29 @@ pub struct BollingerBandsOutput {
     pub lower: Decimal,
 }
 
+impl BollingerBandsOutput {
+    /// Position of `price` relative to the bands (%B): `0` at the lower band, `1` at the upper.
+    ///
+    /// Returns `None` when the bands have collapsed to zero width.
+    pub fn percent_b(&self, price: Decimal) -> Option<Decimal> {
+        let width = self.upper - self.lower;
+        if width.is_zero() {
+            return None;
+        }
+        Some((price - self.lower) / width)
+    }
+
+    /// Band width normalized by the middle band.
+    ///
+    /// Returns `None` when the middle band is zero.
+    pub fn bandwidth(&self) -> Option<Decimal> {
+        if self.middle.is_zero() {
+            return None;
+        }
+        Some((self.upper - self.lower) / self.middle)
+    }
+}
+
 /// Produces Bollinger Bands from a rolling window.
 #[derive(Debug, Clone)]
 pub struct BollingerBands<I = Decimal> {
@@ -103,6 +126,10 @@ where
         }
     }
 
+    fn current(&self) -> Option<Self::Output> {
+        (self.window.len() == self.period).then(|| self.compute_bands())
+    }
+
     fn reset(&mut self) {
         self.sum = Decimal::ZERO;
         self.sum_of_squares = Decimal::ZERO;
@@ -116,7 +143,7 @@ mod tests {
 
     use rust_decimal::Decimal;
 
-    use super::BollingerBands;
+    use super::{BollingerBands, BollingerBandsOutput};
     use crate::Indicator;
 
     fn dec(value: &str) -> Decimal {
@@ -153,6 +180,26 @@ mod tests {
         assert_eq!(bb.next(dec("3")), None);
     }
 
+    #[test]
+    fn decimal_input_keeps_precision_lost_through_f64() {
+        let series = [
+            "65432.123456789012",
+            "65432.123456789013",
+            "65432.123456789014",
+        ];
+        let mut exact = BollingerBands::<Decimal>::new(3, dec("2")).unwrap();
+        let mut lossy = BollingerBands::<f64>::new(3, dec("2")).unwrap();
+        let (mut exact_bands, mut lossy_bands) = (None, None);
+        for value in series {
+            exact_bands = exact.next(dec(value));
+            lossy_bands = lossy.next(value.parse::<f64>().unwrap());
+        }
+
+        let expected = dec("65432.123456789013");
+        assert_eq!(exact_bands.unwrap().middle, expected);
+        assert_ne!(lossy_bands.unwrap().middle, expected);
+    }
+
     #[test]
     fn rejects_negative_multiplier() {
         let err = BollingerBands::<Decimal>::new(5, dec("-1")).unwrap_err();
@@ -161,4 +208,44 @@ mod tests {
             crate::IndicatorError::InvalidParameter { .. }
         ));
     }
+
+    #[test]
+    fn percent_b_maps_bands_to_unit_interval() {
+        let bands = BollingerBandsOutput {
+            upper: dec("12"),
+            middle: dec("10"),
+            lower: dec("8"),
+        };
+        assert_eq!(bands.percent_b(dec("8")), Some(dec("0")));
+        assert_eq!(bands.percent_b(dec("12")), Some(dec("1")));
+        assert_eq!(bands.percent_b(dec("10")), Some(dec("0.5")));
+    }
+
+    #[test]
+    fn percent_b_is_none_for_zero_width_bands() {
+        let bands = BollingerBandsOutput {
+            upper: dec("10"),
+            middle: dec("10"),
+            lower: dec("10"),
+        };
+        assert_eq!(bands.percent_b(dec("10")), None);
+        assert_eq!(bands.bandwidth(), Some(Decimal::ZERO));
+    }
+
+    #[test]
+    fn bandwidth_grows_with_band_width() {
+        let narrow = BollingerBandsOutput {
+            upper: dec("101"),
+            middle: dec("100"),
+            lower: dec("99"),
+        };
+        let wide = BollingerBandsOutput {
+            upper: dec("110"),
+            middle: dec("100"),
+            lower: dec("90"),
+        };
+        assert_eq!(narrow.bandwidth(), Some(dec("0.02")));
+        assert_eq!(wide.bandwidth(), Some(dec("0.2")));
+        assert!(wide.bandwidth() > narrow.bandwidth());
+    }
 }
@@ -0,0 +1,206 @@
+//! Two-sided CUSUM change-point detector.
+
+use std::marker::PhantomData;
+
+use rust_decimal::Decimal;
+use tesser_core::Side;
+
+use crate::core::{Indicator, IndicatorError, Input};
+
+/// Cumulative-sum detector over simple returns that flags shifts in their mean.
+///
+/// Each return, less `drift`, is accumulated into an upper and a lower sum. Once either
+/// sum exceeds `threshold` the detector emits [`Side::Buy`] (upward shift) or
+/// [`Side::Sell`] (downward shift) and both accumulators restart from zero.
+#[derive(Debug, Clone)]
+pub struct Cusum<I = Decimal> {
+    drift: Decimal,
+    threshold: Decimal,
+    prev_value: Option<Decimal>,
+    upper: Decimal,
+    lower: Decimal,
+    last_shift: Option<Side>,
+    marker: PhantomData<I>,
+}
+
+impl<I> Cusum<I>
+where
+    I: Input,
+{
+    /// Creates a new detector; both `drift` and `threshold` are expressed in return units.
+    pub fn new(drift: Decimal, threshold: Decimal) -> Result<Self, IndicatorError> {
+        if drift.is_sign_negative() {
+            return Err(IndicatorError::invalid_parameter("CUSUM", "drift", drift));
+        }
+        if threshold.is_sign_negative() {
+            return Err(IndicatorError::invalid_parameter(
+                "CUSUM",
+                "threshold",
+                threshold,
+            ));
+        }
+        Ok(Self {
+            drift,
+            threshold,
+            prev_value: None,
+            upper: Decimal::ZERO,
+            lower: Decimal::ZERO,
+            last_shift: None,
+            marker: PhantomData,
+        })
+    }
+
+    /// Current upper and lower accumulator values.
+    pub fn accumulators(&self) -> (Decimal, Decimal) {
+        (self.upper, self.lower)
+    }
+
+    fn detect(&mut self, value: Decimal) -> Option<Side> {
+        let prev = self.prev_value.replace(value)?;
+        if prev.is_zero() {
+            return None;
+        }
+        let ret = (value - prev) / prev;
+        self.upper = (self.upper + ret - self.drift).max(Decimal::ZERO);
+        self.lower = (self.lower - ret - self.drift).max(Decimal::ZERO);
+
+        let detected = if self.upper > self.threshold {
+            Some(Side::Buy)
+        } else if self.lower > self.threshold {
+            Some(Side::Sell)
+        } else {
+            None
+        };
+        if detected.is_some() {
+            self.upper = Decimal::ZERO;
+            self.lower = Decimal::ZERO;
+        }
+        detected
+    }
+}
+
+impl<I> Indicator for Cusum<I>
+where
+    I: Input,
+{
+    type Input = I;
+    type Output = Side;
+
+    fn next(&mut self, input: Self::Input) -> Option<Self::Output> {
+        self.last_shift = self.detect(input.value());
+        self.last_shift
+    }
+
+    /// The shift reported by the latest update, if that update detected one.
+    fn current(&self) -> Option<Self::Output> {
+        self.last_shift
+    }
+
+    fn reset(&mut self) {
+        self.last_shift = None;
+        self.prev_value = None;
+        self.upper = Decimal::ZERO;
+        self.lower = Decimal::ZERO;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use rust_decimal::Decimal;
+    use tesser_core::Side;
+
+    use super::Cusum;
+    use crate::Indicator;
+
+    fn dec(value: &str) -> Decimal {
+        Decimal::from_str(value).unwrap()
+    }
+
+    fn detector() -> Cusum {
+        Cusum::new(dec("0.001"), dec("0.02")).unwrap()
+    }
+
+    /// Deterministic zero-mean noise alternating around a base price.
+    fn noisy_prices(base: Decimal, len: usize) -> Vec<Decimal> {
+        let pattern = ["0.004", "-0.003", "0.002", "-0.005", "0.003", "-0.001"];
+        (0..len)
+            .map(|idx| base * (Decimal::ONE + dec(pattern[idx % pattern.len()])))
+            .collect()
+    }
+
+    #[test]
+    fn rejects_negative_parameters() {
+        assert!(Cusum::<Decimal>::new(dec("-0.1"), dec("1")).is_err());
+        assert!(Cusum::<Decimal>::new(dec("0"), dec("-1")).is_err());
+    }
+
+    #[test]
+    fn stationary_noise_is_not_flagged() {
+        let mut cusum = detector();
+        let detections: Vec<_> = noisy_prices(dec("100"), 300)
+            .into_iter()
+            .filter_map(|price| cusum.next(price))
+            .collect();
+        assert!(detections.is_empty(), "unexpected {detections:?}");
+    }
+
+    #[test]
+    fn detects_injected_mean_shift_near_change_point() {
+        let change_point = 100;
+        let mut prices = noisy_prices(dec("100"), change_point);
+        let mut price = *prices.last().unwrap();
+        for _ in 0..50 {
+            price *= dec("1.01");
+            prices.push(price);
+        }
+
+        let mut cusum = detector();
+        let first = prices
+            .into_iter()
+            .enumerate()
+            .find_map(|(idx, price)| cusum.next(price).map(|side| (idx, side)))
+            .expect("shift detected");
+        assert_eq!(first.1, Side::Buy);
+        assert!(first.0 >= change_point && first.0 <= change_point + 5);
+    }
+
+    #[test]
+    fn downward_shift_emits_sell_and_resets() {
+        let mut cusum = detector();
+        let mut price = dec("100");
+        cusum.next(price);
+        let mut detection = None;
+        for _ in 0..10 {
+            price *= dec("0.99");
+            detection = cusum.next(price);
+            if detection.is_some() {
+                break;
+            }
+        }
+        assert_eq!(detection, Some(Side::Sell));
+        assert_eq!(cusum.accumulators(), (Decimal::ZERO, Decimal::ZERO));
+    }
+
+    #[test]
+    fn reset_clears_previous_price() {
+        let mut cusum = detector();
+        cusum.next(dec("100"));
+        cusum.next(dec("95"));
+        cusum.reset();
+        assert_eq!(cusum.next(dec("50")), None);
+        assert_eq!(cusum.accumulators(), (Decimal::ZERO, Decimal::ZERO));
+    }
+
+    #[test]
+    fn current_reports_the_latest_detection_only() {
+        let mut cusum = detector();
+        assert_eq!(cusum.current(), None);
+        cusum.next(dec("100"));
+        assert_eq!(cusum.next(dec("105")), Some(Side::Buy));
+        assert_eq!(cusum.current(), Some(Side::Buy));
+        assert_eq!(cusum.next(dec("105")), None);
+        assert_eq!(cusum.current(), None);
+    }
+}
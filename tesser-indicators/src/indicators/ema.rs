@@ -78,6 +78,10 @@ where
         }
     }
 
+    fn current(&self) -> Option<Self::Output> {
+        self.state
+    }
+
     fn reset(&mut self) {
         self.state = None;
         self.warmup_sum = Decimal::ZERO;
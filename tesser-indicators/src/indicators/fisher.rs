@@ -0,0 +1,194 @@
+//! Ehlers Fisher Transform indicator.
+
+use std::collections::VecDeque;
+use std::marker::PhantomData;
+
+use rust_decimal::{Decimal, MathematicalOps};
+
+use crate::core::{Indicator, IndicatorError, Input};
+
+/// Largest magnitude the normalized price may reach before the log transform.
+const CLAMP: Decimal = Decimal::from_parts(999, 0, 0, false, 3);
+const INPUT_WEIGHT: Decimal = Decimal::from_parts(66, 0, 0, false, 2);
+const SMOOTHING: Decimal = Decimal::from_parts(67, 0, 0, false, 2);
+const HALF: Decimal = Decimal::from_parts(5, 0, 0, false, 1);
+
+/// Output value of the Fisher Transform.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FisherOutput {
+    /// Current Fisher Transform value.
+    pub fisher: Decimal,
+    /// Fisher value from the previous bar, used as the signal line.
+    pub trigger: Decimal,
+}
+
+/// Fisher Transform of the price normalized against its rolling high/low range.
+#[derive(Debug, Clone)]
+pub struct Fisher<I = Decimal> {
+    period: usize,
+    window: VecDeque<Decimal>,
+    value: Decimal,
+    fisher: Decimal,
+    last: Option<FisherOutput>,
+    marker: PhantomData<I>,
+}
+
+impl<I> Fisher<I>
+where
+    I: Input,
+{
+    /// Creates a new Fisher Transform over `period` observations.
+    pub fn new(period: usize) -> Result<Self, IndicatorError> {
+        if period == 0 {
+            return Err(IndicatorError::invalid_period("Fisher", period));
+        }
+        Ok(Self {
+            period,
+            window: VecDeque::with_capacity(period),
+            value: Decimal::ZERO,
+            fisher: Decimal::ZERO,
+            last: None,
+            marker: PhantomData,
+        })
+    }
+
+    /// Position of `price` within the window range mapped to `[-0.5, 0.5]`.
+    fn normalized(&self, price: Decimal) -> Decimal {
+        let high = self.window.iter().copied().fold(price, Decimal::max);
+        let low = self.window.iter().copied().fold(price, Decimal::min);
+        let range = high - low;
+        if range.is_zero() {
+            return Decimal::ZERO;
+        }
+        (price - low) / range - HALF
+    }
+}
+
+impl<I> Indicator for Fisher<I>
+where
+    I: Input,
+{
+    type Input = I;
+    type Output = FisherOutput;
+
+    fn next(&mut self, input: Self::Input) -> Option<Self::Output> {
+        let price = input.value();
+        self.window.push_back(price);
+        if self.window.len() > self.period {
+            self.window.pop_front();
+        }
+        if self.window.len() < self.period {
+            return None;
+        }
+
+        let value = INPUT_WEIGHT * self.normalized(price) + SMOOTHING * self.value;
+        self.value = value.clamp(-CLAMP, CLAMP);
+        let ratio = (Decimal::ONE + self.value) / (Decimal::ONE - self.value);
+        let trigger = self.fisher;
+        self.fisher = HALF * ratio.ln() + HALF * trigger;
+        self.last = Some(FisherOutput {
+            fisher: self.fisher,
+            trigger,
+        });
+        self.last
+    }
+
+    fn current(&self) -> Option<Self::Output> {
+        self.last
+    }
+
+    fn reset(&mut self) {
+        self.last = None;
+        self.window.clear();
+        self.value = Decimal::ZERO;
+        self.fisher = Decimal::ZERO;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::Decimal;
+
+    use super::{Fisher, FisherOutput};
+    use crate::Indicator;
+
+    /// Rises to 114, falls to 100, then rises again.
+    fn swing_prices() -> Vec<Decimal> {
+        (0..15)
+            .chain((1..15).map(|step| 14 - step))
+            .chain(1..10)
+            .map(|offset| Decimal::from(100 + offset))
+            .collect()
+    }
+
+    fn run(prices: &[Decimal]) -> Vec<Option<FisherOutput>> {
+        let mut fisher = Fisher::new(5).unwrap();
+        prices.iter().map(|price| fisher.next(*price)).collect()
+    }
+
+    #[test]
+    fn rejects_zero_period() {
+        assert!(Fisher::<Decimal>::new(0).is_err());
+    }
+
+    #[test]
+    fn warms_up_over_the_period() {
+        let outputs = run(&swing_prices());
+        assert!(outputs[..4].iter().all(Option::is_none));
+        assert!(outputs[4].is_some());
+    }
+
+    #[test]
+    fn turning_points_line_up_with_crossovers() {
+        let prices = swing_prices();
+        let outputs: Vec<_> = run(&prices).into_iter().flatten().collect();
+        let offset = prices.len() - outputs.len();
+        let crossings: Vec<(usize, bool)> = outputs
+            .windows(2)
+            .enumerate()
+            .filter_map(|(idx, pair)| {
+                let was_above = pair[0].fisher > pair[0].trigger;
+                let is_above = pair[1].fisher > pair[1].trigger;
+                (was_above != is_above).then_some((idx + 1 + offset, is_above))
+            })
+            .collect();
+        // Peak at index 14 and trough at index 28 flip the lines on the very next bar.
+        assert_eq!(crossings, vec![(15, false), (29, true)]);
+    }
+
+    #[test]
+    fn clamping_keeps_output_bounded_on_one_way_moves() {
+        let mut fisher = Fisher::new(3).unwrap();
+        let mut last = None;
+        for step in 0..500 {
+            last = fisher.next(Decimal::from(1_000 + step));
+        }
+        let output = last.unwrap();
+        // With |value| <= 0.999 the recursion converges to ln(1999) ~= 7.6.
+        assert!(output.fisher > Decimal::from(7));
+        assert!(output.fisher < Decimal::from(8));
+        assert!(output.trigger <= output.fisher);
+    }
+
+    #[test]
+    fn flat_prices_stay_at_zero() {
+        let mut fisher = Fisher::new(4).unwrap();
+        let outputs: Vec<_> = (0..10)
+            .filter_map(|_| fisher.next(Decimal::from(50)))
+            .collect();
+        assert!(outputs
+            .iter()
+            .all(|out| out.fisher.abs() < Decimal::new(1, 9)));
+    }
+
+    #[test]
+    fn reset_clears_window_and_state() {
+        let mut fisher = Fisher::new(2).unwrap();
+        fisher.next(Decimal::from(1));
+        fisher.next(Decimal::from(2));
+        fisher.reset();
+        assert_eq!(fisher.next(Decimal::from(3)), None);
+        let output = fisher.next(Decimal::from(3)).unwrap();
+        assert_eq!(output.trigger, Decimal::ZERO);
+    }
+}
@@ -18,6 +18,42 @@ pub struct IchimokuOutput {
     pub span_a: Decimal,
     /// Senkou Span B projection.
     pub span_b: Decimal,
+    /// Span A computed `displacement` bars ago, i.e. the cloud plotted under the current bar.
+    pub projected_span_a: Option<Decimal>,
+    /// Span B computed `displacement` bars ago, i.e. the cloud plotted under the current bar.
+    pub projected_span_b: Option<Decimal>,
+}
+
+/// Where a price sits relative to the projected cloud.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CloudPosition {
+    /// Price is above both projected spans.
+    Above,
+    /// Price is below both projected spans.
+    Below,
+    /// Price is between the projected spans, or touching one of them.
+    Inside,
+}
+
+impl IchimokuOutput {
+    /// Top and bottom of the projected cloud, once enough history has accumulated.
+    pub fn projected_cloud(&self) -> Option<(Decimal, Decimal)> {
+        let a = self.projected_span_a?;
+        let b = self.projected_span_b?;
+        Some((a.max(b), a.min(b)))
+    }
+
+    /// Classify `price` against the projected cloud; prices on an edge count as inside.
+    pub fn cloud_position(&self, price: Decimal) -> Option<CloudPosition> {
+        let (top, bottom) = self.projected_cloud()?;
+        Some(if price > top {
+            CloudPosition::Above
+        } else if price < bottom {
+            CloudPosition::Below
+        } else {
+            CloudPosition::Inside
+        })
+    }
 }
 
 /// Ichimoku Cloud indicator implementation.
@@ -25,16 +61,21 @@ pub struct Ichimoku {
     conversion_period: usize,
     base_period: usize,
     span_b_period: usize,
+    displacement: usize,
     highs_conv: VecDeque<Decimal>,
     lows_conv: VecDeque<Decimal>,
     highs_base: VecDeque<Decimal>,
     lows_base: VecDeque<Decimal>,
     highs_span_b: VecDeque<Decimal>,
     lows_span_b: VecDeque<Decimal>,
+    /// Recent (span A, span B) pairs, long enough to look back `displacement` bars.
+    spans: VecDeque<(Decimal, Decimal)>,
 }
 
 impl Ichimoku {
     /// Build a new Ichimoku indicator with custom periods.
+    ///
+    /// The cloud displacement defaults to `base_period`, as in the classic 9/26/52 setup.
     pub fn new(
         conversion_period: usize,
         base_period: usize,
@@ -56,15 +97,27 @@ impl Ichimoku {
             conversion_period,
             base_period,
             span_b_period,
+            displacement: base_period,
             highs_conv: VecDeque::with_capacity(conversion_period),
             lows_conv: VecDeque::with_capacity(conversion_period),
             highs_base: VecDeque::with_capacity(base_period),
             lows_base: VecDeque::with_capacity(base_period),
             highs_span_b: VecDeque::with_capacity(span_b_period),
             lows_span_b: VecDeque::with_capacity(span_b_period),
+            spans: VecDeque::with_capacity(base_period + 1),
         })
     }
 
+    /// Override how many bars the Senkou spans are shifted forward.
+    pub fn with_displacement(mut self, displacement: usize) -> Result<Self, IndicatorError> {
+        if displacement == 0 {
+            return Err(IndicatorError::invalid_period("Ichimoku", displacement));
+        }
+        self.displacement = displacement;
+        self.spans = VecDeque::with_capacity(displacement + 1);
+        Ok(self)
+    }
+
     fn midpoint(highs: &VecDeque<Decimal>, lows: &VecDeque<Decimal>) -> Option<Decimal> {
         let max_high = highs.iter().copied().reduce(Decimal::max)?;
         let min_low = lows.iter().copied().reduce(Decimal::min)?;
@@ -92,12 +145,31 @@ impl Indicator for Ichimoku {
         let base = Self::midpoint(&self.highs_base, &self.lows_base)?;
         let span_b = Self::midpoint(&self.highs_span_b, &self.lows_span_b)?;
         let span_a = (conversion + base) / Decimal::from(2);
+        self.spans.push_back((span_a, span_b));
+        if self.spans.len() > self.displacement + 1 {
+            self.spans.pop_front();
+        }
+        self.current()
+    }
+
+    fn current(&self) -> Option<Self::Output> {
+        if self.highs_span_b.len() < self.span_b_period {
+            return None;
+        }
+        let conversion = Self::midpoint(&self.highs_conv, &self.lows_conv)?;
+        let base = Self::midpoint(&self.highs_base, &self.lows_base)?;
+        let &(span_a, span_b) = self.spans.back()?;
+        let projected = (self.spans.len() > self.displacement)
+            .then(|| self.spans.front().copied())
+            .flatten();
 
         Some(IchimokuOutput {
             conversion_line: conversion,
             base_line: base,
             span_a,
             span_b,
+            projected_span_a: projected.map(|(a, _)| a),
+            projected_span_b: projected.map(|(_, b)| b),
         })
     }
 
@@ -108,6 +180,7 @@ impl Indicator for Ichimoku {
         self.lows_base.clear();
         self.highs_span_b.clear();
         self.lows_span_b.clear();
+        self.spans.clear();
     }
 }
 
@@ -122,7 +195,7 @@ fn push_queue(queue: &mut VecDeque<Decimal>, period: usize, value: Decimal) {
 mod tests {
     use super::*;
     use chrono::Utc;
-    use tesser_core::{Interval, Symbol};
+    use tesser_core::{CandleStatus, Interval, Symbol};
 
     fn candle(value: f64) -> Candle {
         Candle {
@@ -134,6 +207,7 @@ mod tests {
             close: Decimal::from_f64_retain(value).unwrap(),
             volume: Decimal::ONE,
             timestamp: Utc::now(),
+            status: CandleStatus::Closed,
         }
     }
 
@@ -145,4 +219,66 @@ mod tests {
         }
         assert!(ichi.next(candle(4.0)).is_some());
     }
+
+    #[test]
+    fn projected_spans_lag_by_the_displacement() {
+        let mut ichi = Ichimoku::new(2, 4, 4)
+            .unwrap()
+            .with_displacement(3)
+            .unwrap();
+        let outputs: Vec<_> = (1..=10)
+            .filter_map(|val| ichi.next(candle(val as f64)))
+            .collect();
+        for (idx, output) in outputs.iter().enumerate() {
+            if idx < 3 {
+                assert_eq!(output.projected_span_a, None);
+                assert_eq!(output.projected_cloud(), None);
+                continue;
+            }
+            assert_eq!(output.projected_span_a, Some(outputs[idx - 3].span_a));
+            assert_eq!(output.projected_span_b, Some(outputs[idx - 3].span_b));
+        }
+    }
+
+    #[test]
+    fn cloud_position_classifies_against_projected_cloud() {
+        let mut ichi = Ichimoku::new(2, 4, 4)
+            .unwrap()
+            .with_displacement(2)
+            .unwrap();
+        let last = (1..=8)
+            .filter_map(|val| ichi.next(candle(val as f64)))
+            .last()
+            .unwrap();
+        let (top, bottom) = last.projected_cloud().unwrap();
+        assert!(top >= bottom);
+        assert_eq!(
+            last.cloud_position(top + Decimal::ONE),
+            Some(CloudPosition::Above)
+        );
+        assert_eq!(
+            last.cloud_position(bottom - Decimal::ONE),
+            Some(CloudPosition::Below)
+        );
+        assert_eq!(last.cloud_position(bottom), Some(CloudPosition::Inside));
+        // A rising market trades above the cloud projected from older, lower bars.
+        assert_eq!(
+            last.cloud_position(Decimal::from(8)),
+            Some(CloudPosition::Above)
+        );
+    }
+
+    #[test]
+    fn default_displacement_matches_base_period() {
+        let mut ichi = Ichimoku::new(2, 4, 4).unwrap();
+        let outputs: Vec<_> = (1..=12)
+            .filter_map(|val| ichi.next(candle(val as f64)))
+            .collect();
+        assert_eq!(outputs[3].projected_span_a, None);
+        assert_eq!(outputs[4].projected_span_a, Some(outputs[0].span_a));
+        assert!(Ichimoku::new(2, 4, 4)
+            .unwrap()
+            .with_displacement(0)
+            .is_err());
+    }
 }
@@ -1,6 +1,7 @@
 //! Moving Average Convergence Divergence indicator implementation.
 
 use rust_decimal::Decimal;
+use tesser_core::Side;
 
 use crate::core::{Indicator, IndicatorError};
 use crate::indicators::ema::Ema;
@@ -75,6 +76,16 @@ impl Indicator for Macd {
         }
     }
 
+    fn current(&self) -> Option<Self::Output> {
+        let macd = self.fast.value()? - self.slow.value()?;
+        let signal = self.signal.value()?;
+        Some(MacdOutput {
+            macd,
+            signal,
+            histogram: macd - signal,
+        })
+    }
+
     fn reset(&mut self) {
         self.fast.reset();
         self.slow.reset();
@@ -83,10 +94,94 @@ impl Indicator for Macd {
     }
 }
 
+/// Stateful wrapper around [`Macd`] that reports histogram zero-crossings.
+///
+/// Emits [`Side::Buy`] when the histogram turns positive and [`Side::Sell`] when it turns
+/// negative. A histogram of exactly zero keeps the previous sign.
+pub struct MacdSignal {
+    macd: Macd,
+    last_output: Option<MacdOutput>,
+    positive: Option<bool>,
+    last_cross: Option<Side>,
+}
+
+impl MacdSignal {
+    /// Create a crossing detector with custom fast/slow/signal periods.
+    pub fn new(
+        fast_period: usize,
+        slow_period: usize,
+        signal_period: usize,
+    ) -> Result<Self, IndicatorError> {
+        Ok(Self::from_macd(Macd::new(
+            fast_period,
+            slow_period,
+            signal_period,
+        )?))
+    }
+
+    /// Wrap an existing MACD indicator.
+    pub fn from_macd(macd: Macd) -> Self {
+        Self {
+            macd,
+            last_output: None,
+            positive: None,
+            last_cross: None,
+        }
+    }
+
+    /// Most recent MACD output observed by the wrapper.
+    pub fn last_output(&self) -> Option<MacdOutput> {
+        self.last_output
+    }
+
+    fn cross(&mut self, input: Decimal) -> Option<Side> {
+        let output = self.macd.next(input)?;
+        self.last_output = Some(output);
+        if output.histogram.is_zero() {
+            return None;
+        }
+        let positive = output.histogram > Decimal::ZERO;
+        let previous = self.positive.replace(positive)?;
+        match (previous, positive) {
+            (false, true) => Some(Side::Buy),
+            (true, false) => Some(Side::Sell),
+            _ => None,
+        }
+    }
+}
+
+impl Indicator for MacdSignal {
+    type Input = Decimal;
+    type Output = Side;
+
+    fn next(&mut self, input: Self::Input) -> Option<Self::Output> {
+        self.last_cross = self.cross(input);
+        self.last_cross
+    }
+
+    /// The crossing reported by the latest update, if that update produced one.
+    fn current(&self) -> Option<Self::Output> {
+        self.last_cross
+    }
+
+    fn reset(&mut self) {
+        self.macd.reset();
+        self.last_output = None;
+        self.positive = None;
+        self.last_cross = None;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    const SERIES: [i64; 6] = [10, 12, 14, 7, 15, 6];
+
+    fn rounded(value: Decimal) -> Decimal {
+        value.round_dp(10)
+    }
+
     #[test]
     fn macd_emits_after_warmup() {
         let mut macd = Macd::new(3, 6, 3).unwrap();
@@ -95,4 +190,61 @@ mod tests {
         }
         assert!(macd.next(Decimal::from(16)).is_some());
     }
+
+    #[test]
+    fn histogram_is_macd_minus_signal() {
+        let mut macd = Macd::new(1, 2, 2).unwrap();
+        let outputs: Vec<MacdOutput> = SERIES
+            .iter()
+            .filter_map(|price| macd.next(Decimal::from(*price)))
+            .collect();
+        let histograms: Vec<Decimal> = outputs[..3]
+            .iter()
+            .map(|output| rounded(output.histogram))
+            .collect();
+        assert_eq!(
+            histograms,
+            vec![Decimal::ZERO, Decimal::from(-1), Decimal::ONE]
+        );
+        for output in outputs {
+            assert_eq!(output.histogram, output.macd - output.signal);
+        }
+    }
+
+    #[test]
+    fn signal_fires_on_histogram_zero_crossings() {
+        let mut detector = MacdSignal::new(1, 2, 2).unwrap();
+        let sides: Vec<Option<Side>> = SERIES
+            .iter()
+            .map(|price| detector.next(Decimal::from(*price)))
+            .collect();
+        // Warm-up, flat histogram, first negative reading, then up and down crosses.
+        assert_eq!(
+            sides,
+            vec![None, None, None, None, Some(Side::Buy), Some(Side::Sell)]
+        );
+        assert!(detector.last_output().unwrap().histogram < Decimal::ZERO);
+
+        detector.reset();
+        assert!(detector.last_output().is_none());
+        assert_eq!(detector.next(Decimal::from(10)), None);
+    }
+
+    #[test]
+    fn current_matches_latest_output() {
+        let mut macd = Macd::new(1, 2, 2).unwrap();
+        assert_eq!(macd.current(), None);
+        for price in SERIES {
+            let emitted = macd.next(Decimal::from(price));
+            assert_eq!(macd.current(), emitted);
+        }
+
+        let mut detector = MacdSignal::new(1, 2, 2).unwrap();
+        for price in SERIES {
+            let emitted = detector.next(Decimal::from(price));
+            assert_eq!(detector.current(), emitted);
+        }
+        detector.reset();
+        assert_eq!(detector.current(), None);
+    }
 }
@@ -3,18 +3,30 @@
 /// Average True Range indicator module.
 pub mod atr;
 pub mod bollinger;
+/// CUSUM change-point detector module.
+pub mod cusum;
 pub mod ema;
+/// Fisher Transform indicator module.
+pub mod fisher;
 /// Ichimoku Cloud indicator module.
 pub mod ichimoku;
 /// Moving Average Convergence Divergence module.
 pub mod macd;
+/// Rolling quantile module.
+pub mod quantile;
 pub mod rsi;
 pub mod sma;
+/// Vortex Indicator module.
+pub mod vortex;
 
 pub use atr::Atr;
 pub use bollinger::{BollingerBands, BollingerBandsOutput};
+pub use cusum::Cusum;
 pub use ema::Ema;
-pub use ichimoku::{Ichimoku, IchimokuOutput};
-pub use macd::{Macd, MacdOutput};
+pub use fisher::{Fisher, FisherOutput};
+pub use ichimoku::{CloudPosition, Ichimoku, IchimokuOutput};
+pub use macd::{Macd, MacdOutput, MacdSignal};
+pub use quantile::{QuantileMethod, RollingQuantile};
 pub use rsi::Rsi;
 pub use sma::Sma;
+pub use vortex::{Vortex, VortexOutput};
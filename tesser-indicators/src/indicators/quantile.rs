@@ -0,0 +1,514 @@
+//! Rolling quantile over a trailing window.
+
+use std::collections::VecDeque;
+use std::marker::PhantomData;
+
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+
+use crate::core::{decimal_from_usize, Indicator, IndicatorError, Input};
+
+/// Largest window [`RollingQuantile::new`] keeps sorted exactly; longer windows use P².
+pub const EXACT_WINDOW_LIMIT: usize = 1024;
+
+/// Strategy used by [`RollingQuantile`] to track the window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuantileMethod {
+    /// Keep the window sorted and interpolate between neighbouring ranks. `O(period)` per update.
+    Exact,
+    /// Jain & Chlamtac P² estimator with five markers. `O(1)` per update and memory, approximate.
+    P2,
+}
+
+/// Emits the `quantile`-th quantile of the last `period` inputs.
+///
+/// Useful for adaptive thresholds, e.g. treating the rolling 10th/90th percentile of RSI as
+/// oversold/overbought instead of fixed 30/70 levels. The exact method interpolates linearly
+/// between ranks, so the median of an even window is the mean of the two middle values.
+///
+/// P² cannot forget individual samples, so the approximate method runs two estimators that
+/// restart every `period` samples, half a window apart, and reports the one with more history.
+/// Its output therefore reflects between the last `period / 2` and `period` samples, and no
+/// sample older than `period` ever contributes.
+#[derive(Debug, Clone)]
+pub struct RollingQuantile<I = Decimal> {
+    period: usize,
+    quantile: Decimal,
+    method: QuantileMethod,
+    window: VecDeque<Decimal>,
+    sorted: Vec<Decimal>,
+    estimators: [P2Estimator; 2],
+    seen: usize,
+    marker: PhantomData<I>,
+}
+
+impl<I> RollingQuantile<I>
+where
+    I: Input,
+{
+    /// Creates a rolling quantile; `quantile` must be within `[0, 1]`.
+    ///
+    /// Windows up to [`EXACT_WINDOW_LIMIT`] are tracked exactly, longer ones with P².
+    pub fn new(period: usize, quantile: Decimal) -> Result<Self, IndicatorError> {
+        if period == 0 {
+            return Err(IndicatorError::invalid_period("RollingQuantile", period));
+        }
+        if quantile < Decimal::ZERO || quantile > Decimal::ONE {
+            return Err(IndicatorError::out_of_range(
+                "RollingQuantile",
+                "quantile",
+                quantile,
+                Decimal::ZERO,
+                Decimal::ONE,
+            ));
+        }
+        let method = if period > EXACT_WINDOW_LIMIT {
+            QuantileMethod::P2
+        } else {
+            QuantileMethod::Exact
+        };
+        Ok(Self {
+            period,
+            quantile,
+            method,
+            window: VecDeque::new(),
+            sorted: Vec::new(),
+            estimators: [P2Estimator::new(quantile), P2Estimator::new(quantile)],
+            seen: 0,
+            marker: PhantomData,
+        })
+    }
+
+    /// Overrides the automatically selected tracking method.
+    #[must_use]
+    pub fn with_method(mut self, method: QuantileMethod) -> Self {
+        self.method = method;
+        self.reset();
+        self
+    }
+
+    /// Returns the configured lookback period.
+    pub fn period(&self) -> usize {
+        self.period
+    }
+
+    /// Returns the quantile being tracked.
+    pub fn quantile(&self) -> Decimal {
+        self.quantile
+    }
+
+    /// Returns the method used to track the window.
+    pub fn method(&self) -> QuantileMethod {
+        self.method
+    }
+
+    fn next_exact(&mut self, value: Decimal) -> Option<Decimal> {
+        self.window.push_back(value);
+        let idx = self.sorted.partition_point(|existing| *existing < value);
+        self.sorted.insert(idx, value);
+
+        if self.window.len() > self.period {
+            if let Some(oldest) = self.window.pop_front() {
+                if let Ok(idx) = self.sorted.binary_search(&oldest) {
+                    self.sorted.remove(idx);
+                }
+            }
+        }
+
+        self.current_exact()
+    }
+
+    fn current_exact(&self) -> Option<Decimal> {
+        (self.window.len() == self.period).then(|| interpolate(&self.sorted, self.quantile))
+    }
+
+    fn next_approximate(&mut self, value: Decimal) -> Option<Decimal> {
+        self.seen += 1;
+        let stagger = self.period / 2;
+        for (idx, estimator) in self.estimators.iter_mut().enumerate() {
+            if idx == 1 && self.seen <= stagger {
+                continue;
+            }
+            if estimator.count == self.period {
+                estimator.reset();
+            }
+            estimator.push(value);
+        }
+        self.current_approximate()
+    }
+
+    fn current_approximate(&self) -> Option<Decimal> {
+        if self.seen < self.period {
+            return None;
+        }
+        let [first, second] = &self.estimators;
+        let freshest = if first.count >= second.count {
+            first
+        } else {
+            second
+        };
+        freshest.estimate()
+    }
+}
+
+impl<I> Indicator for RollingQuantile<I>
+where
+    I: Input,
+{
+    type Input = I;
+    type Output = Decimal;
+
+    fn next(&mut self, input: Self::Input) -> Option<Self::Output> {
+        let value = input.value();
+        match self.method {
+            QuantileMethod::Exact => self.next_exact(value),
+            QuantileMethod::P2 => self.next_approximate(value),
+        }
+    }
+
+    fn current(&self) -> Option<Self::Output> {
+        match self.method {
+            QuantileMethod::Exact => self.current_exact(),
+            QuantileMethod::P2 => self.current_approximate(),
+        }
+    }
+
+    fn reset(&mut self) {
+        self.window.clear();
+        self.sorted.clear();
+        for estimator in &mut self.estimators {
+            estimator.reset();
+        }
+        self.seen = 0;
+    }
+}
+
+/// Linearly interpolated quantile of an ascending, non-empty slice.
+fn interpolate(sorted: &[Decimal], quantile: Decimal) -> Decimal {
+    let last = sorted.len() - 1;
+    let rank = quantile * decimal_from_usize(last);
+    let lower = rank.floor();
+    let idx = lower.to_usize().unwrap_or(0).min(last);
+    if idx == last {
+        return sorted[last];
+    }
+    sorted[idx] + (sorted[idx + 1] - sorted[idx]) * (rank - lower)
+}
+
+/// P² marker state for a single quantile.
+#[derive(Debug, Clone)]
+struct P2Estimator {
+    count: usize,
+    heights: [Decimal; 5],
+    positions: [Decimal; 5],
+    desired: [Decimal; 5],
+    increments: [Decimal; 5],
+}
+
+impl P2Estimator {
+    fn new(quantile: Decimal) -> Self {
+        let two = Decimal::TWO;
+        Self {
+            count: 0,
+            heights: [Decimal::ZERO; 5],
+            positions: [Decimal::ZERO; 5],
+            desired: [Decimal::ZERO; 5],
+            increments: [
+                Decimal::ZERO,
+                quantile / two,
+                quantile,
+                (Decimal::ONE + quantile) / two,
+                Decimal::ONE,
+            ],
+        }
+    }
+
+    fn reset(&mut self) {
+        self.count = 0;
+    }
+
+    fn push(&mut self, value: Decimal) {
+        if self.count < 5 {
+            self.heights[self.count] = value;
+            self.count += 1;
+            if self.count == 5 {
+                self.heights.sort();
+                let quantile = self.increments[2];
+                let two = Decimal::TWO;
+                for (idx, position) in self.positions.iter_mut().enumerate() {
+                    *position = decimal_from_usize(idx + 1);
+                }
+                self.desired = [
+                    Decimal::ONE,
+                    Decimal::ONE + two * quantile,
+                    Decimal::ONE + Decimal::from(4) * quantile,
+                    Decimal::from(3) + two * quantile,
+                    Decimal::from(5),
+                ];
+            }
+            return;
+        }
+        self.count += 1;
+
+        let cell = if value < self.heights[0] {
+            self.heights[0] = value;
+            0
+        } else if value >= self.heights[4] {
+            self.heights[4] = value;
+            3
+        } else {
+            (0..4)
+                .find(|&idx| value < self.heights[idx + 1])
+                .unwrap_or(3)
+        };
+        for position in &mut self.positions[cell + 1..] {
+            *position += Decimal::ONE;
+        }
+        for (desired, increment) in self.desired.iter_mut().zip(self.increments) {
+            *desired += increment;
+        }
+
+        for idx in 1..4 {
+            let drift = self.desired[idx] - self.positions[idx];
+            let room_above = self.positions[idx + 1] - self.positions[idx];
+            let room_below = self.positions[idx - 1] - self.positions[idx];
+            let step = if drift >= Decimal::ONE && room_above > Decimal::ONE {
+                Decimal::ONE
+            } else if drift <= Decimal::NEGATIVE_ONE && room_below < Decimal::NEGATIVE_ONE {
+                Decimal::NEGATIVE_ONE
+            } else {
+                continue;
+            };
+            let candidate = self.parabolic(idx, step);
+            self.heights[idx] =
+                if self.heights[idx - 1] < candidate && candidate < self.heights[idx + 1] {
+                    candidate
+                } else {
+                    self.linear(idx, step)
+                };
+            self.positions[idx] += step;
+        }
+    }
+
+    fn parabolic(&self, idx: usize, step: Decimal) -> Decimal {
+        let (q, n) = (&self.heights, &self.positions);
+        let upper = (n[idx] - n[idx - 1] + step) * (q[idx + 1] - q[idx]) / (n[idx + 1] - n[idx]);
+        let lower = (n[idx + 1] - n[idx] - step) * (q[idx] - q[idx - 1]) / (n[idx] - n[idx - 1]);
+        q[idx] + step / (n[idx + 1] - n[idx - 1]) * (upper + lower)
+    }
+
+    fn linear(&self, idx: usize, step: Decimal) -> Decimal {
+        let (q, n) = (&self.heights, &self.positions);
+        let neighbour = if step.is_sign_positive() {
+            idx + 1
+        } else {
+            idx - 1
+        };
+        q[idx] + step * (q[neighbour] - q[idx]) / (n[neighbour] - n[idx])
+    }
+
+    fn estimate(&self) -> Option<Decimal> {
+        match self.count {
+            0 => None,
+            count if count < 5 => {
+                let mut sample = self.heights[..count].to_vec();
+                sample.sort();
+                Some(interpolate(&sample, self.increments[2]))
+            }
+            _ => Some(self.heights[2]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use rust_decimal::Decimal;
+
+    use super::{QuantileMethod, RollingQuantile};
+    use crate::{Indicator, IndicatorError};
+
+    fn dec(value: &str) -> Decimal {
+        Decimal::from_str(value).unwrap()
+    }
+
+    /// Deterministic pseudo-random integers in `0..1000`.
+    fn noise(seed: u64, len: usize) -> Vec<Decimal> {
+        let mut state = seed;
+        (0..len)
+            .map(|_| {
+                state = (state * 1_103_515_245 + 12_345) % (1 << 31);
+                Decimal::from((state >> 8) % 1000)
+            })
+            .collect()
+    }
+
+    fn exact_median(values: &[Decimal]) -> Decimal {
+        let mut sorted = values.to_vec();
+        sorted.sort();
+        super::interpolate(&sorted, dec("0.5"))
+    }
+
+    #[test]
+    fn median_of_known_window() {
+        let mut odd = RollingQuantile::new(5, dec("0.5")).unwrap();
+        let outputs: Vec<_> = [5, 1, 4, 2, 3]
+            .into_iter()
+            .map(|value| odd.next(Decimal::from(value)))
+            .collect();
+        assert_eq!(outputs[..4], [None, None, None, None]);
+        assert_eq!(outputs[4], Some(dec("3")));
+
+        let mut even = RollingQuantile::new(4, dec("0.5")).unwrap();
+        for value in ["4", "1", "3"] {
+            assert_eq!(even.next(dec(value)), None);
+        }
+        assert_eq!(even.next(dec("2")), Some(dec("2.5")));
+    }
+
+    #[test]
+    fn interpolates_between_ranks() {
+        let mut q = RollingQuantile::new(5, dec("0.9")).unwrap();
+        let mut last = None;
+        for value in [10, 20, 30, 40, 50] {
+            last = q.next(Decimal::from(value));
+        }
+        assert_eq!(last, Some(dec("46")));
+    }
+
+    #[test]
+    fn streaming_updates_evict_old_values() {
+        let mut max = RollingQuantile::new(3, Decimal::ONE).unwrap();
+        let mut min = RollingQuantile::new(3, Decimal::ZERO).unwrap();
+        let values = [10, 1, 2, 3, 1, 7];
+        let maxes: Vec<_> = values
+            .iter()
+            .map(|value| max.next(Decimal::from(*value)))
+            .collect();
+        let mins: Vec<_> = values
+            .iter()
+            .map(|value| min.next(Decimal::from(*value)))
+            .collect();
+        assert_eq!(
+            maxes[2..],
+            [
+                Some(dec("10")),
+                Some(dec("3")),
+                Some(dec("3")),
+                Some(dec("7"))
+            ]
+        );
+        assert_eq!(
+            mins[2..],
+            [
+                Some(dec("1")),
+                Some(dec("1")),
+                Some(dec("1")),
+                Some(dec("1"))
+            ]
+        );
+        assert_eq!(min.next(dec("8")), Some(dec("1")));
+        assert_eq!(min.next(dec("9")), Some(dec("7")));
+    }
+
+    #[test]
+    fn duplicate_values_are_evicted_one_at_a_time() {
+        let mut median = RollingQuantile::new(3, dec("0.5")).unwrap();
+        for value in ["5", "5", "1"] {
+            median.next(dec(value));
+        }
+        assert_eq!(median.next(dec("1")), Some(dec("1")));
+        assert_eq!(median.next(dec("9")), Some(dec("1")));
+        assert_eq!(median.next(dec("9")), Some(dec("9")));
+    }
+
+    #[test]
+    fn reset_clears_internal_state() {
+        let mut median = RollingQuantile::new(2, dec("0.5")).unwrap();
+        median.next(dec("1"));
+        assert_eq!(median.next(dec("3")), Some(dec("2")));
+        median.reset();
+        assert_eq!(median.next(dec("5")), None);
+        assert_eq!(median.next(dec("7")), Some(dec("6")));
+    }
+
+    #[test]
+    fn rejects_invalid_configuration() {
+        assert!(matches!(
+            RollingQuantile::<Decimal>::new(0, dec("0.5")),
+            Err(IndicatorError::InvalidPeriod { .. })
+        ));
+        assert!(matches!(
+            RollingQuantile::<Decimal>::new(10, dec("1.5")),
+            Err(IndicatorError::OutOfRange { .. })
+        ));
+        assert!(RollingQuantile::<Decimal>::new(10, dec("-0.1")).is_err());
+    }
+
+    #[test]
+    fn large_windows_select_p2() {
+        let small = RollingQuantile::<Decimal>::new(100, dec("0.5")).unwrap();
+        let large = RollingQuantile::<Decimal>::new(5_000, dec("0.5")).unwrap();
+        assert_eq!(small.method(), QuantileMethod::Exact);
+        assert_eq!(large.method(), QuantileMethod::P2);
+    }
+
+    #[test]
+    fn p2_approximates_exact_median() {
+        let period = 2_000;
+        let values = noise(7, 3 * period);
+        let mut approx = RollingQuantile::new(period, dec("0.5"))
+            .unwrap()
+            .with_method(QuantileMethod::P2);
+        let mut last = None;
+        for (idx, value) in values.iter().enumerate() {
+            last = approx.next(*value);
+            if idx + 1 < period {
+                assert_eq!(last, None);
+            }
+        }
+        let exact = exact_median(&values[values.len() - period..]);
+        let error = (last.unwrap() - exact).abs();
+        assert!(error < dec("50"), "estimate {last:?} vs exact {exact}");
+    }
+
+    #[test]
+    fn p2_forgets_values_outside_the_window() {
+        let period = 2_000;
+        let mut approx = RollingQuantile::new(period, dec("0.5"))
+            .unwrap()
+            .with_method(QuantileMethod::P2);
+        for value in noise(11, 2 * period) {
+            approx.next(value);
+        }
+        let shifted: Vec<_> = noise(13, period)
+            .into_iter()
+            .map(|value| value + dec("10000"))
+            .collect();
+        let mut last = None;
+        for value in &shifted {
+            last = approx.next(*value);
+        }
+        let estimate = last.unwrap();
+        assert!(
+            estimate > dec("10000"),
+            "estimate {estimate} still sees old regime"
+        );
+        assert!((estimate - exact_median(&shifted)).abs() < dec("50"));
+    }
+
+    #[test]
+    fn current_matches_latest_output_for_both_methods() {
+        for method in [QuantileMethod::Exact, QuantileMethod::P2] {
+            let mut quantile = RollingQuantile::new(20, dec("0.9"))
+                .unwrap()
+                .with_method(method);
+            assert_eq!(quantile.current(), None);
+            for value in noise(11, 60) {
+                let emitted = quantile.next(value);
+                assert_eq!(quantile.current(), emitted, "{method:?}");
+            }
+            assert!(quantile.current().is_some());
+        }
+    }
+}
@@ -122,6 +122,10 @@ where
         Some(Self::compute_rsi(avg_gain, avg_loss))
     }
 
+    fn current(&self) -> Option<Self::Output> {
+        Some(Self::compute_rsi(self.avg_gain?, self.avg_loss?))
+    }
+
     fn reset(&mut self) {
         self.prev_value = None;
         self.avg_gain = None;
@@ -67,6 +67,10 @@ where
         }
     }
 
+    fn current(&self) -> Option<Self::Output> {
+        (self.window.len() == self.period).then(|| self.sum / self.divisor)
+    }
+
     fn reset(&mut self) {
         self.sum = Decimal::ZERO;
         self.window.clear();
@@ -113,4 +117,19 @@ mod tests {
         sma.reset();
         assert_eq!(sma.next(dec("9")), None);
     }
+
+    #[test]
+    fn current_peeks_without_advancing() {
+        let mut sma = Sma::new(2).unwrap();
+        assert_eq!(sma.current(), None);
+        assert_eq!(sma.next(dec("4")), None);
+        assert_eq!(sma.current(), None);
+        let emitted = sma.next(dec("6"));
+        assert_eq!(emitted, Some(dec("5")));
+        assert_eq!(sma.current(), emitted);
+        assert_eq!(sma.current(), emitted);
+        assert_eq!(sma.next(dec("8")), Some(dec("7")));
+        sma.reset();
+        assert_eq!(sma.current(), None);
+    }
 }
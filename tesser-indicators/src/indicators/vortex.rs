@@ -0,0 +1,192 @@
+//! Vortex Indicator implementation.
+
+use std::collections::VecDeque;
+
+use rust_decimal::Decimal;
+use tesser_core::Candle;
+
+use crate::core::{Indicator, IndicatorError};
+
+/// Output value of the Vortex Indicator.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VortexOutput {
+    /// Positive trend movement normalized by true range.
+    pub vi_plus: Decimal,
+    /// Negative trend movement normalized by true range.
+    pub vi_minus: Decimal,
+}
+
+/// Per-bar movement terms kept in the rolling window.
+#[derive(Debug, Clone, Copy)]
+struct Movement {
+    plus: Decimal,
+    minus: Decimal,
+    true_range: Decimal,
+}
+
+/// Vortex Indicator over high/low/close candles.
+#[derive(Debug, Clone)]
+pub struct Vortex {
+    period: usize,
+    prev: Option<Candle>,
+    window: VecDeque<Movement>,
+    plus_sum: Decimal,
+    minus_sum: Decimal,
+    range_sum: Decimal,
+}
+
+impl Vortex {
+    /// Creates a new Vortex Indicator summing movements over `period` bars.
+    pub fn new(period: usize) -> Result<Self, IndicatorError> {
+        if period == 0 {
+            return Err(IndicatorError::invalid_period("Vortex", period));
+        }
+        Ok(Self {
+            period,
+            prev: None,
+            window: VecDeque::with_capacity(period),
+            plus_sum: Decimal::ZERO,
+            minus_sum: Decimal::ZERO,
+            range_sum: Decimal::ZERO,
+        })
+    }
+
+    fn movement(candle: &Candle, prev: &Candle) -> Movement {
+        let high_low = candle.high - candle.low;
+        let high_close = (candle.high - prev.close).abs();
+        let low_close = (candle.low - prev.close).abs();
+        Movement {
+            plus: (candle.high - prev.low).abs(),
+            minus: (candle.low - prev.high).abs(),
+            true_range: high_low.max(high_close).max(low_close),
+        }
+    }
+}
+
+impl Indicator for Vortex {
+    type Input = Candle;
+    type Output = VortexOutput;
+
+    fn next(&mut self, input: Self::Input) -> Option<Self::Output> {
+        let prev = self.prev.replace(input.clone())?;
+        let movement = Self::movement(&input, &prev);
+        self.plus_sum += movement.plus;
+        self.minus_sum += movement.minus;
+        self.range_sum += movement.true_range;
+        self.window.push_back(movement);
+        if self.window.len() > self.period {
+            if let Some(expired) = self.window.pop_front() {
+                self.plus_sum -= expired.plus;
+                self.minus_sum -= expired.minus;
+                self.range_sum -= expired.true_range;
+            }
+        }
+        self.current()
+    }
+
+    fn current(&self) -> Option<Self::Output> {
+        if self.window.len() < self.period || self.range_sum.is_zero() {
+            return None;
+        }
+        Some(VortexOutput {
+            vi_plus: self.plus_sum / self.range_sum,
+            vi_minus: self.minus_sum / self.range_sum,
+        })
+    }
+
+    fn reset(&mut self) {
+        self.prev = None;
+        self.window.clear();
+        self.plus_sum = Decimal::ZERO;
+        self.minus_sum = Decimal::ZERO;
+        self.range_sum = Decimal::ZERO;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use tesser_core::{CandleStatus, Interval, Symbol};
+
+    fn candle(close: i64) -> Candle {
+        Candle {
+            symbol: Symbol::from("BTCUSDT"),
+            interval: Interval::OneMinute,
+            open: Decimal::from(close),
+            high: Decimal::from(close + 1),
+            low: Decimal::from(close - 1),
+            close: Decimal::from(close),
+            volume: Decimal::ONE,
+            timestamp: Utc::now(),
+            status: CandleStatus::Closed,
+        }
+    }
+
+    fn plus_leads(output: &VortexOutput) -> bool {
+        output.vi_plus > output.vi_minus
+    }
+
+    #[test]
+    fn rejects_zero_period() {
+        assert!(Vortex::new(0).is_err());
+    }
+
+    #[test]
+    fn warms_up_after_period_plus_one_bars() {
+        let mut vortex = Vortex::new(3).unwrap();
+        for close in 100..103 {
+            assert!(vortex.next(candle(close)).is_none());
+        }
+        assert!(vortex.next(candle(103)).is_some());
+    }
+
+    #[test]
+    fn uptrend_keeps_plus_above_minus() {
+        let mut vortex = Vortex::new(5).unwrap();
+        let output = (100..120)
+            .filter_map(|close| vortex.next(candle(close * 2)))
+            .last()
+            .unwrap();
+        assert!(output.vi_plus > output.vi_minus);
+        // Each bar rises by 2 with a 2-wide range: VM+ = 4, VM- = 0, TR = 3.
+        assert_eq!(output.vi_plus, Decimal::from(4) / Decimal::from(3));
+        assert_eq!(output.vi_minus, Decimal::ZERO);
+    }
+
+    #[test]
+    fn crossover_flips_on_reversal() {
+        let mut vortex = Vortex::new(4).unwrap();
+        let closes: Vec<i64> = (0..10)
+            .chain((0..10).rev())
+            .map(|step| 100 + step * 2)
+            .collect();
+        let outputs: Vec<(usize, VortexOutput)> = closes
+            .into_iter()
+            .enumerate()
+            .filter_map(|(idx, close)| vortex.next(candle(close)).map(|out| (idx, out)))
+            .collect();
+        let flips: Vec<usize> = outputs
+            .windows(2)
+            .filter(|pair| plus_leads(&pair[0].1) != plus_leads(&pair[1].1))
+            .map(|pair| pair[1].0)
+            .collect();
+        // Price tops out at indices 9-10; plus leads before it and minus takes over shortly after.
+        assert_eq!(flips.len(), 1);
+        assert!((11..=14).contains(&flips[0]));
+        let last = outputs.last().unwrap().1;
+        assert!(last.vi_minus > last.vi_plus);
+    }
+
+    #[test]
+    fn reset_restarts_warmup() {
+        let mut vortex = Vortex::new(2).unwrap();
+        for close in 100..104 {
+            vortex.next(candle(close));
+        }
+        vortex.reset();
+        assert!(vortex.next(candle(200)).is_none());
+        assert!(vortex.next(candle(201)).is_none());
+        assert!(vortex.next(candle(202)).is_some());
+    }
+}
@@ -227,6 +227,7 @@ mod tests {
             open_orders: Vec::new(),
             last_prices: HashMap::new(),
             last_candle_ts: None,
+            strategy_name: None,
             strategy_state: None,
             execution_checkpoint: Default::default(),
         }
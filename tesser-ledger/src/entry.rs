@@ -3,9 +3,28 @@ use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::str::FromStr;
-use tesser_core::{AssetId, ExchangeId};
+use tesser_core::{AssetId, ExchangeId, Symbol};
 use uuid::Uuid;
 
+/// Keys used in [`LedgerEntry::meta`].
+///
+/// `meta` is a JSON object; entries written by the runtime only use these keys, and string
+/// values are stored exactly as returned by the matching accessor on [`LedgerEntry`].
+pub mod meta_keys {
+    /// Venue order identifier the entry was derived from.
+    pub const ORDER_ID: &str = "order_id";
+    /// Venue execution identifier, when the fill reports one.
+    pub const FILL_ID: &str = "fill_id";
+    /// Name of the strategy that owned the order.
+    pub const STRATEGY: &str = "strategy";
+    /// Asset the fee was charged in (`exchange:asset`).
+    pub const FEE_ASSET: &str = "fee_asset";
+    /// Instrument the entry relates to.
+    pub const SYMBOL: &str = "symbol";
+    /// Which leg of the cash movement the entry books (e.g. `fee`, `quote`, `funding`).
+    pub const COMPONENT: &str = "component";
+}
+
 /// Canonical ledger record describing a single balance delta.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct LedgerEntry {
@@ -47,6 +66,78 @@ impl LedgerEntry {
         self.sequence = sequence;
         self
     }
+
+    /// String value stored under `key` in [`Self::meta`].
+    pub fn meta_str(&self, key: &str) -> Option<&str> {
+        self.meta.as_ref()?.get(key)?.as_str()
+    }
+
+    /// Store `value` under `key`, turning a missing or non-object `meta` into an object.
+    pub fn set_meta(&mut self, key: &str, value: impl Into<serde_json::Value>) {
+        let meta = self
+            .meta
+            .get_or_insert_with(|| serde_json::Value::Object(Default::default()));
+        if !meta.is_object() {
+            *meta = serde_json::Value::Object(Default::default());
+        }
+        if let Some(map) = meta.as_object_mut() {
+            map.insert(key.to_string(), value.into());
+        }
+    }
+
+    /// See [`meta_keys::ORDER_ID`].
+    pub fn order_id(&self) -> Option<&str> {
+        self.meta_str(meta_keys::ORDER_ID)
+    }
+
+    pub fn set_order_id(&mut self, order_id: impl Into<String>) {
+        self.set_meta(meta_keys::ORDER_ID, order_id.into());
+    }
+
+    /// See [`meta_keys::FILL_ID`].
+    pub fn fill_id(&self) -> Option<&str> {
+        self.meta_str(meta_keys::FILL_ID)
+    }
+
+    pub fn set_fill_id(&mut self, fill_id: impl Into<String>) {
+        self.set_meta(meta_keys::FILL_ID, fill_id.into());
+    }
+
+    /// See [`meta_keys::STRATEGY`].
+    pub fn strategy(&self) -> Option<&str> {
+        self.meta_str(meta_keys::STRATEGY)
+    }
+
+    pub fn set_strategy(&mut self, strategy: impl Into<String>) {
+        self.set_meta(meta_keys::STRATEGY, strategy.into());
+    }
+
+    /// See [`meta_keys::FEE_ASSET`]; `None` when absent or not a valid asset identifier.
+    pub fn fee_asset(&self) -> Option<AssetId> {
+        self.meta_str(meta_keys::FEE_ASSET)?.parse().ok()
+    }
+
+    pub fn set_fee_asset(&mut self, asset: AssetId) {
+        self.set_meta(meta_keys::FEE_ASSET, asset.to_string());
+    }
+
+    /// See [`meta_keys::SYMBOL`]; `None` when absent or not a valid symbol.
+    pub fn symbol(&self) -> Option<Symbol> {
+        self.meta_str(meta_keys::SYMBOL)?.parse().ok()
+    }
+
+    pub fn set_symbol(&mut self, symbol: Symbol) {
+        self.set_meta(meta_keys::SYMBOL, symbol.to_string());
+    }
+
+    /// See [`meta_keys::COMPONENT`].
+    pub fn component(&self) -> Option<&str> {
+        self.meta_str(meta_keys::COMPONENT)
+    }
+
+    pub fn set_component(&mut self, component: impl Into<String>) {
+        self.set_meta(meta_keys::COMPONENT, component.into());
+    }
 }
 
 /// Enumerates the supported ledger line item categories.
@@ -55,6 +146,8 @@ impl LedgerEntry {
 pub enum LedgerType {
     TradeRealizedPnl,
     Fee,
+    /// Maker rebate credited by the venue (a negative fee).
+    Rebate,
     Funding,
     TransferIn,
     TransferOut,
@@ -66,12 +159,52 @@ impl LedgerType {
         match self {
             LedgerType::TradeRealizedPnl => "trade_realized_pnl",
             LedgerType::Fee => "fee",
+            LedgerType::Rebate => "rebate",
             LedgerType::Funding => "funding",
             LedgerType::TransferIn => "transfer_in",
             LedgerType::TransferOut => "transfer_out",
             LedgerType::Adjustment => "adjustment",
         }
     }
+
+    /// Balance-sheet side this entry type is booked against.
+    pub fn classification(self) -> LedgerClass {
+        match self {
+            LedgerType::TransferIn | LedgerType::TransferOut => LedgerClass::Asset,
+            LedgerType::Fee => LedgerClass::Liability,
+            LedgerType::TradeRealizedPnl
+            | LedgerType::Rebate
+            | LedgerType::Funding
+            | LedgerType::Adjustment => LedgerClass::Equity,
+        }
+    }
+}
+
+/// Balance-sheet classes used to check the accounting identity.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LedgerClass {
+    Asset,
+    Liability,
+    Equity,
+}
+
+/// Total `(assets, liabilities, equity)` for `entries`.
+///
+/// Liabilities are booked with the opposite sign of the entry amount, so fees (negative
+/// amounts) grow them and a consistent journal satisfies `assets == liabilities + equity`.
+pub fn summarize(entries: &[LedgerEntry]) -> (Decimal, Decimal, Decimal) {
+    let mut assets = Decimal::ZERO;
+    let mut liabilities = Decimal::ZERO;
+    let mut equity = Decimal::ZERO;
+    for entry in entries {
+        match entry.entry_type.classification() {
+            LedgerClass::Asset => assets += entry.amount,
+            LedgerClass::Liability => liabilities -= entry.amount,
+            LedgerClass::Equity => equity += entry.amount,
+        }
+    }
+    (assets, liabilities, equity)
 }
 
 impl fmt::Display for LedgerType {
@@ -87,6 +220,7 @@ impl FromStr for LedgerType {
         match s {
             "trade_realized_pnl" => Ok(LedgerType::TradeRealizedPnl),
             "fee" => Ok(LedgerType::Fee),
+            "rebate" => Ok(LedgerType::Rebate),
             "funding" => Ok(LedgerType::Funding),
             "transfer_in" => Ok(LedgerType::TransferIn),
             "transfer_out" => Ok(LedgerType::TransferOut),
@@ -0,0 +1,104 @@
+use std::collections::{BTreeSet, HashSet};
+
+use chrono::{DateTime, Utc};
+
+/// Outcome of [`LedgerRepository::verify_integrity`](crate::LedgerRepository::verify_integrity).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IntegrityReport {
+    /// Number of persisted rows inspected.
+    pub entries_checked: usize,
+    /// Sequence numbers that appear on more than one row.
+    pub duplicate_sequences: Vec<u64>,
+    /// Entry ids that appear on more than one row.
+    pub duplicate_ids: Vec<String>,
+    /// Sequences whose timestamp precedes an entry with a lower sequence.
+    pub non_monotonic_timestamps: Vec<u64>,
+    /// Sequences whose amount cannot be decoded and re-encoded losslessly.
+    pub invalid_amounts: Vec<u64>,
+}
+
+impl IntegrityReport {
+    /// Returns `true` when no issue was detected.
+    #[must_use]
+    pub fn is_clean(&self) -> bool {
+        self.duplicate_sequences.is_empty()
+            && self.duplicate_ids.is_empty()
+            && self.non_monotonic_timestamps.is_empty()
+            && self.invalid_amounts.is_empty()
+    }
+
+    pub(crate) fn from_records(mut records: Vec<IntegrityRecord>) -> Self {
+        records.sort_by_key(|record| record.sequence);
+        let mut report = Self {
+            entries_checked: records.len(),
+            ..Self::default()
+        };
+        let mut sequences = HashSet::new();
+        let mut duplicate_sequences = BTreeSet::new();
+        let mut ids = HashSet::new();
+        let mut duplicate_ids = BTreeSet::new();
+        let mut latest_timestamp: Option<DateTime<Utc>> = None;
+        for record in records {
+            if !sequences.insert(record.sequence) {
+                duplicate_sequences.insert(record.sequence);
+            }
+            if !ids.insert(record.id.clone()) {
+                duplicate_ids.insert(record.id);
+            }
+            if let Some(timestamp) = record.timestamp {
+                match latest_timestamp {
+                    Some(latest) if timestamp < latest => {
+                        report.non_monotonic_timestamps.push(record.sequence);
+                    }
+                    _ => latest_timestamp = Some(timestamp),
+                }
+            }
+            if !record.amount_valid {
+                report.invalid_amounts.push(record.sequence);
+            }
+        }
+        report.duplicate_sequences = duplicate_sequences.into_iter().collect();
+        report.duplicate_ids = duplicate_ids.into_iter().collect();
+        report
+    }
+}
+
+/// Raw view of a persisted row used by the integrity check.
+pub(crate) struct IntegrityRecord {
+    pub sequence: u64,
+    pub id: String,
+    pub timestamp: Option<DateTime<Utc>>,
+    pub amount_valid: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn record(sequence: u64, id: &str, offset_secs: i64) -> IntegrityRecord {
+        IntegrityRecord {
+            sequence,
+            id: id.to_string(),
+            timestamp: Some(DateTime::<Utc>::UNIX_EPOCH + Duration::seconds(offset_secs)),
+            amount_valid: true,
+        }
+    }
+
+    #[test]
+    fn ordered_unique_records_are_clean() {
+        let report = IntegrityReport::from_records(vec![record(2, "b", 2), record(1, "a", 1)]);
+        assert!(report.is_clean());
+        assert_eq!(report.entries_checked, 2);
+    }
+
+    #[test]
+    fn out_of_order_timestamp_is_flagged_once() {
+        let report = IntegrityReport::from_records(vec![
+            record(1, "a", 10),
+            record(2, "b", 5),
+            record(3, "c", 11),
+        ]);
+        assert_eq!(report.non_monotonic_timestamps, vec![2]);
+    }
+}
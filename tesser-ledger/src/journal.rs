@@ -1,6 +1,6 @@
+use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
-use serde_json::json;
-use tesser_core::{AssetId, Fill, Instrument, InstrumentKind, Side};
+use tesser_core::{AssetId, Fill, Instrument, InstrumentKind, Side, Symbol};
 
 use crate::{LedgerEntry, LedgerType};
 
@@ -9,6 +9,13 @@ pub struct FillLedgerContext<'a> {
     pub fill: &'a Fill,
     pub instrument: &'a Instrument,
     pub realized_pnl: Decimal,
+    /// Asset charged when the fill does not report one; defaults to the quote (spot) or
+    /// settlement (derivatives) currency.
+    pub default_fee_asset: Option<AssetId>,
+    /// Venue execution identifier recorded as [`crate::meta_keys::FILL_ID`].
+    pub fill_id: Option<String>,
+    /// Strategy recorded as [`crate::meta_keys::STRATEGY`].
+    pub strategy: Option<String>,
 }
 
 impl<'a> FillLedgerContext<'a> {
@@ -17,46 +24,127 @@ impl<'a> FillLedgerContext<'a> {
             fill,
             instrument,
             realized_pnl,
+            default_fee_asset: None,
+            fill_id: None,
+            strategy: None,
         }
     }
+
+    /// Journal fees without an explicit asset in `asset` (e.g. a venue fee token).
+    #[must_use]
+    pub fn with_default_fee_asset(mut self, asset: AssetId) -> Self {
+        self.default_fee_asset = Some(asset);
+        self
+    }
+
+    /// Tag every entry with the venue execution identifier of the fill.
+    #[must_use]
+    pub fn with_fill_id(mut self, fill_id: impl Into<String>) -> Self {
+        self.fill_id = Some(fill_id.into());
+        self
+    }
+
+    /// Tag every entry with the strategy that owned the order.
+    #[must_use]
+    pub fn with_strategy(mut self, strategy: impl Into<String>) -> Self {
+        self.strategy = Some(strategy.into());
+        self
+    }
+
+    fn fee_asset(&self) -> AssetId {
+        self.fill
+            .fee_asset
+            .or(self.default_fee_asset)
+            .unwrap_or(match self.instrument.kind {
+                InstrumentKind::Spot => self.instrument.quote,
+                _ => self.instrument.settlement_currency,
+            })
+    }
 }
 
 /// Build the ledger entries representing cash movements for the provided fill.
+///
+/// Every entry carries the symbol, component and order id in its meta; fee and rebate entries
+/// also record the fee asset. See [`crate::meta_keys`].
 pub fn entries_from_fill(ctx: FillLedgerContext<'_>) -> Vec<LedgerEntry> {
     let mut entries = match ctx.instrument.kind {
-        InstrumentKind::Spot => spot_entries(ctx.fill, ctx.instrument),
+        InstrumentKind::Spot => spot_entries(&ctx),
         InstrumentKind::LinearPerpetual | InstrumentKind::InversePerpetual => {
-            derivative_entries(ctx.fill, ctx.instrument)
+            derivative_entries(&ctx)
         }
     };
     if !ctx.realized_pnl.is_zero() {
         entries.push(build_entry(
+            &ctx,
             ctx.instrument.settlement_currency,
             ctx.realized_pnl,
-            ctx.fill,
             LedgerType::TradeRealizedPnl,
-            Some("realized_pnl"),
+            "realized_pnl",
         ));
     }
-    if let Some(fee) = ctx.fill.fee {
-        let fee_asset = ctx.fill.fee_asset.unwrap_or(match ctx.instrument.kind {
-            InstrumentKind::Spot => ctx.instrument.quote,
-            _ => ctx.instrument.settlement_currency,
-        });
-        if !fee.is_zero() {
-            entries.push(build_entry(
-                fee_asset,
-                -fee,
-                ctx.fill,
-                LedgerType::Fee,
-                Some("fee"),
-            ));
-        }
+    if let Some(fee) = ctx.fill.fee.filter(|fee| !fee.is_zero()) {
+        // Negative fees are maker rebates and are credited as income.
+        let (entry_type, component) = if fee < Decimal::ZERO {
+            (LedgerType::Rebate, "rebate")
+        } else {
+            (LedgerType::Fee, "fee")
+        };
+        let fee_asset = ctx.fee_asset();
+        let mut entry = build_entry(&ctx, fee_asset, -fee, entry_type, component);
+        entry.set_fee_asset(fee_asset);
+        entries.push(entry);
     }
     entries
 }
 
-fn spot_entries(fill: &Fill, instrument: &Instrument) -> Vec<LedgerEntry> {
+/// Funding payment settled against a perpetual position.
+#[derive(Clone, Debug)]
+pub struct FundingEntry {
+    pub symbol: Symbol,
+    /// Cash exchanged; positive when funding was received, negative when it was paid.
+    pub amount: Decimal,
+    pub rate: Option<Decimal>,
+    pub timestamp: DateTime<Utc>,
+    /// Venue identifier of the settlement, stored as the ledger reference.
+    pub reference_id: String,
+}
+
+/// Context required to derive ledger entries from a funding payment.
+pub struct FundingLedgerContext<'a> {
+    pub instrument: &'a Instrument,
+}
+
+impl<'a> FundingLedgerContext<'a> {
+    pub fn new(instrument: &'a Instrument) -> Self {
+        Self { instrument }
+    }
+}
+
+/// Build the ledger entries representing the cash movement of a funding payment.
+pub fn entries_from_funding(
+    funding: &FundingEntry,
+    ctx: FundingLedgerContext<'_>,
+) -> Vec<LedgerEntry> {
+    if funding.amount.is_zero() {
+        return Vec::new();
+    }
+    let asset = ctx.instrument.settlement_currency;
+    let mut entry = LedgerEntry::new(
+        asset.exchange,
+        asset,
+        funding.amount,
+        LedgerType::Funding,
+        funding.reference_id.clone(),
+    );
+    entry.set_symbol(funding.symbol);
+    entry.set_component("funding");
+    entry.set_meta("rate", funding.rate.map(|rate| rate.to_string()));
+    entry.timestamp = funding.timestamp;
+    vec![entry]
+}
+
+fn spot_entries(ctx: &FillLedgerContext<'_>) -> Vec<LedgerEntry> {
+    let (fill, instrument) = (ctx.fill, ctx.instrument);
     let qty = fill.fill_quantity;
     let notional = fill.fill_price * qty;
     let mut entries = Vec::new();
@@ -66,11 +154,11 @@ fn spot_entries(fill: &Fill, instrument: &Instrument) -> Vec<LedgerEntry> {
     };
     if !base_delta.is_zero() {
         entries.push(build_entry(
+            ctx,
             instrument.base,
             base_delta,
-            fill,
             LedgerType::Adjustment,
-            Some("base"),
+            "base",
         ));
     }
     let quote_delta = match fill.side {
@@ -79,40 +167,42 @@ fn spot_entries(fill: &Fill, instrument: &Instrument) -> Vec<LedgerEntry> {
     };
     if !quote_delta.is_zero() {
         entries.push(build_entry(
+            ctx,
             instrument.quote,
             quote_delta,
-            fill,
             LedgerType::Adjustment,
-            Some("quote"),
+            "quote",
         ));
     }
     entries
 }
 
-fn derivative_entries(fill: &Fill, instrument: &Instrument) -> Vec<LedgerEntry> {
+fn derivative_entries(ctx: &FillLedgerContext<'_>) -> Vec<LedgerEntry> {
+    let (fill, instrument) = (ctx.fill, ctx.instrument);
     let notional = fill.fill_price * fill.fill_quantity;
     let direction = Decimal::from(fill.side.as_i8());
     let settlement_delta = -(notional * direction);
     let mut entries = Vec::new();
     if !settlement_delta.is_zero() {
         entries.push(build_entry(
+            ctx,
             instrument.settlement_currency,
             settlement_delta,
-            fill,
             LedgerType::Adjustment,
-            Some("settlement"),
+            "settlement",
         ));
     }
     entries
 }
 
 fn build_entry(
+    ctx: &FillLedgerContext<'_>,
     asset: AssetId,
     amount: Decimal,
-    fill: &Fill,
     entry_type: LedgerType,
-    component: Option<&str>,
+    component: &str,
 ) -> LedgerEntry {
+    let fill = ctx.fill;
     let mut entry = LedgerEntry::new(
         asset.exchange,
         asset,
@@ -120,11 +210,14 @@ fn build_entry(
         entry_type,
         fill.order_id.to_string(),
     );
-    if let Some(kind) = component {
-        entry.meta = Some(json!({
-            "symbol": fill.symbol.to_string(),
-            "component": kind,
-        }));
+    entry.set_symbol(fill.symbol);
+    entry.set_component(component);
+    entry.set_order_id(fill.order_id.to_string());
+    if let Some(fill_id) = &ctx.fill_id {
+        entry.set_fill_id(fill_id.clone());
+    }
+    if let Some(strategy) = &ctx.strategy {
+        entry.set_strategy(strategy.clone());
     }
     entry.timestamp = fill.timestamp;
     entry
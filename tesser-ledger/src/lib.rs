@@ -2,6 +2,7 @@
 
 mod entry;
 mod error;
+mod integrity;
 mod journal;
 mod parquet;
 mod query;
@@ -9,11 +10,14 @@ mod repository;
 mod sequencer;
 mod sqlite;
 
-pub use entry::{LedgerEntry, LedgerType};
+pub use entry::{meta_keys, summarize, LedgerClass, LedgerEntry, LedgerType};
 pub use error::{LedgerError, LedgerResult};
-pub use journal::{entries_from_fill, FillLedgerContext};
+pub use integrity::IntegrityReport;
+pub use journal::{
+    entries_from_fill, entries_from_funding, FillLedgerContext, FundingEntry, FundingLedgerContext,
+};
 pub use parquet::ParquetLedgerRepository;
-pub use query::LedgerQuery;
+pub use query::{AggregateRow, LedgerGroupKey, LedgerQuery};
 pub use repository::LedgerRepository;
 pub use sequencer::LedgerSequencer;
 pub use sqlite::SqliteLedgerRepository;
@@ -24,7 +28,7 @@ mod tests {
     use chrono::Utc;
     use rust_decimal::Decimal;
     use rust_decimal_macros::dec;
-    use tesser_core::{AssetId, ExchangeId};
+    use tesser_core::{AssetId, ExchangeId, Fill, Instrument, InstrumentKind, Side, Symbol};
 
     fn sample_entry(kind: LedgerType, amount: Decimal, seq: u64) -> LedgerEntry {
         LedgerEntry {
@@ -52,19 +56,221 @@ mod tests {
         assert_eq!(assets, liabilities + equity);
     }
 
-    fn summarize(entries: &[LedgerEntry]) -> (Decimal, Decimal, Decimal) {
-        let mut assets = Decimal::ZERO;
-        let mut liabilities = Decimal::ZERO;
-        let mut equity = Decimal::ZERO;
-        for entry in entries {
-            match entry.entry_type {
-                LedgerType::TransferIn | LedgerType::TransferOut => assets += entry.amount,
-                LedgerType::Fee => liabilities += -entry.amount,
-                LedgerType::Funding | LedgerType::TradeRealizedPnl | LedgerType::Adjustment => {
-                    equity += entry.amount
-                }
-            }
+    fn perp_instrument() -> Instrument {
+        Instrument {
+            symbol: Symbol::from("bybit:BTCUSDT"),
+            base: AssetId::from("bybit:BTC"),
+            quote: AssetId::from("bybit:USDT"),
+            kind: InstrumentKind::LinearPerpetual,
+            settlement_currency: AssetId::from("bybit:USDT"),
+            tick_size: dec!(0.1),
+            lot_size: dec!(0.001),
+            min_notional: None,
         }
-        (assets, liabilities, equity)
+    }
+
+    fn funding(amount: Decimal) -> FundingEntry {
+        FundingEntry {
+            symbol: Symbol::from("bybit:BTCUSDT"),
+            amount,
+            rate: Some(dec!(0.0001)),
+            timestamp: Utc::now(),
+            reference_id: "funding-1".to_string(),
+        }
+    }
+
+    #[test]
+    fn funding_payments_are_signed_funding_entries() {
+        let instrument = perp_instrument();
+        let received =
+            entries_from_funding(&funding(dec!(1.25)), FundingLedgerContext::new(&instrument));
+        let paid = entries_from_funding(
+            &funding(dec!(-0.75)),
+            FundingLedgerContext::new(&instrument),
+        );
+        assert_eq!(received.len(), 1);
+        assert_eq!(received[0].entry_type, LedgerType::Funding);
+        assert_eq!(received[0].amount, dec!(1.25));
+        assert_eq!(received[0].asset, AssetId::from("bybit:USDT"));
+        assert_eq!(received[0].reference_id, "funding-1");
+        assert_eq!(paid[0].entry_type, LedgerType::Funding);
+        assert_eq!(paid[0].amount, dec!(-0.75));
+        assert!(entries_from_funding(
+            &funding(Decimal::ZERO),
+            FundingLedgerContext::new(&instrument)
+        )
+        .is_empty());
+
+        let mut entries = vec![
+            sample_entry(LedgerType::TransferIn, dec!(100), 1),
+            sample_entry(LedgerType::TransferOut, dec!(-99.5), 2),
+        ];
+        entries.extend(received);
+        entries.extend(paid);
+        let (assets, liabilities, equity) = summarize(&entries);
+        assert_eq!(equity, dec!(0.5));
+        assert_eq!(assets, liabilities + equity);
+    }
+
+    fn spot_instrument() -> Instrument {
+        Instrument {
+            symbol: Symbol::from("binance:BTCUSDT"),
+            base: AssetId::from("binance:BTC"),
+            quote: AssetId::from("binance:USDT"),
+            kind: InstrumentKind::Spot,
+            settlement_currency: AssetId::from("binance:USDT"),
+            tick_size: dec!(0.01),
+            lot_size: dec!(0.0001),
+            min_notional: None,
+        }
+    }
+
+    fn spot_fill(fee: Decimal, fee_asset: Option<&str>) -> Fill {
+        Fill {
+            order_id: "order-1".to_string(),
+            symbol: Symbol::from("binance:BTCUSDT"),
+            side: Side::Buy,
+            fill_price: dec!(100),
+            fill_quantity: dec!(2),
+            fee: Some(fee),
+            fee_asset: fee_asset.map(AssetId::from),
+            timestamp: Utc::now(),
+        }
+    }
+
+    fn fee_entries(entries: &[LedgerEntry]) -> Vec<&LedgerEntry> {
+        entries
+            .iter()
+            .filter(|entry| matches!(entry.entry_type, LedgerType::Fee | LedgerType::Rebate))
+            .collect()
+    }
+
+    #[test]
+    fn maker_rebates_are_journaled_as_income() {
+        let instrument = spot_instrument();
+        let fill = spot_fill(dec!(-0.5), None);
+        let entries = entries_from_fill(FillLedgerContext::new(&fill, &instrument, Decimal::ZERO));
+        let fees = fee_entries(&entries);
+        assert_eq!(fees.len(), 1);
+        assert_eq!(fees[0].entry_type, LedgerType::Rebate);
+        assert_eq!(fees[0].amount, dec!(0.5));
+        assert_eq!(fees[0].asset, AssetId::from("binance:USDT"));
+        assert_eq!("rebate".parse::<LedgerType>(), Ok(LedgerType::Rebate));
+
+        let (_, liabilities, equity) = summarize(&fees.into_iter().cloned().collect::<Vec<_>>());
+        assert_eq!(liabilities, Decimal::ZERO);
+        assert_eq!(equity, dec!(0.5));
+    }
+
+    #[test]
+    fn cross_currency_fees_are_journaled_in_their_own_asset() {
+        let instrument = spot_instrument();
+        let fill = spot_fill(dec!(0.01), Some("binance:BNB"));
+        let entries = entries_from_fill(FillLedgerContext::new(&fill, &instrument, Decimal::ZERO));
+        let fees = fee_entries(&entries);
+        assert_eq!(fees.len(), 1);
+        assert_eq!(fees[0].entry_type, LedgerType::Fee);
+        assert_eq!(fees[0].asset, AssetId::from("binance:BNB"));
+        assert_eq!(fees[0].amount, dec!(-0.01));
+        // The quote leg only carries the notional; the fee does not leak into it.
+        let quote_total: Decimal = entries
+            .iter()
+            .filter(|entry| entry.asset == AssetId::from("binance:USDT"))
+            .map(|entry| entry.amount)
+            .sum();
+        assert_eq!(quote_total, dec!(-200));
+
+        let untagged = spot_fill(dec!(0.02), None);
+        let entries = entries_from_fill(
+            FillLedgerContext::new(&untagged, &instrument, Decimal::ZERO)
+                .with_default_fee_asset(AssetId::from("binance:BNB")),
+        );
+        assert_eq!(fee_entries(&entries)[0].asset, AssetId::from("binance:BNB"));
+    }
+
+    #[test]
+    fn fill_entries_carry_typed_meta() {
+        let instrument = spot_instrument();
+        let fill = spot_fill(dec!(0.01), Some("binance:BNB"));
+        let entries = entries_from_fill(
+            FillLedgerContext::new(&fill, &instrument, Decimal::ZERO)
+                .with_fill_id("exec-7")
+                .with_strategy("sma-cross"),
+        );
+        assert_eq!(entries.len(), 3);
+        for entry in &entries {
+            assert_eq!(entry.order_id(), Some("order-1"));
+            assert_eq!(entry.fill_id(), Some("exec-7"));
+            assert_eq!(entry.strategy(), Some("sma-cross"));
+            assert_eq!(entry.symbol(), Some(Symbol::from("binance:BTCUSDT")));
+        }
+        let components: Vec<_> = entries.iter().filter_map(LedgerEntry::component).collect();
+        assert_eq!(components, vec!["base", "quote", "fee"]);
+
+        let fees = fee_entries(&entries);
+        assert_eq!(fees[0].fee_asset(), Some(AssetId::from("binance:BNB")));
+        assert_eq!(
+            fees[0].meta.as_ref().unwrap()[meta_keys::FEE_ASSET],
+            "binance:BNB"
+        );
+        assert!(entries
+            .iter()
+            .filter(|entry| entry.entry_type == LedgerType::Adjustment)
+            .all(|entry| entry.fee_asset().is_none()));
+
+        let untagged = entries_from_fill(FillLedgerContext::new(&fill, &instrument, Decimal::ZERO));
+        assert!(untagged
+            .iter()
+            .all(|entry| entry.fill_id().is_none() && entry.strategy().is_none()));
+    }
+
+    #[test]
+    fn meta_setters_replace_values_and_recover_non_objects() {
+        let mut entry = sample_entry(LedgerType::Fee, dec!(-1), 1);
+        assert_eq!(entry.order_id(), None);
+        entry.meta = Some(serde_json::json!("legacy"));
+        entry.set_order_id("order-9");
+        entry.set_fee_asset(AssetId::from("paper:USDT"));
+        entry.set_order_id("order-10");
+        assert_eq!(entry.order_id(), Some("order-10"));
+        assert_eq!(entry.fee_asset(), Some(AssetId::from("paper:USDT")));
+        assert_eq!(entry.meta_str(meta_keys::ORDER_ID), Some("order-10"));
+
+        entry.set_meta(meta_keys::FEE_ASSET, 42);
+        assert_eq!(entry.fee_asset(), None);
+    }
+
+    #[test]
+    fn ledger_types_map_to_their_balance_class() {
+        let expected = [
+            (LedgerType::TransferIn, LedgerClass::Asset),
+            (LedgerType::TransferOut, LedgerClass::Asset),
+            (LedgerType::Fee, LedgerClass::Liability),
+            (LedgerType::TradeRealizedPnl, LedgerClass::Equity),
+            (LedgerType::Rebate, LedgerClass::Equity),
+            (LedgerType::Funding, LedgerClass::Equity),
+            (LedgerType::Adjustment, LedgerClass::Equity),
+        ];
+        for (kind, class) in expected {
+            assert_eq!(kind.classification(), class, "{kind}");
+        }
+    }
+
+    #[test]
+    fn mixed_entries_satisfy_accounting_identity() {
+        let entries = vec![
+            sample_entry(LedgerType::TransferIn, dec!(1000), 1),
+            sample_entry(LedgerType::TransferOut, dec!(-120), 2),
+            sample_entry(LedgerType::TradeRealizedPnl, dec!(-40), 3),
+            sample_entry(LedgerType::Fee, dec!(-3.5), 4),
+            sample_entry(LedgerType::Rebate, dec!(0.5), 5),
+            sample_entry(LedgerType::Funding, dec!(-1.25), 6),
+            sample_entry(LedgerType::Adjustment, dec!(917.25), 7),
+        ];
+        let (assets, liabilities, equity) = summarize(&entries);
+        assert_eq!(assets, dec!(880));
+        assert_eq!(liabilities, dec!(3.5));
+        assert_eq!(equity, dec!(876.5));
+        assert_eq!(assets, liabilities + equity);
     }
 }
@@ -17,7 +17,11 @@ use rust_decimal::Decimal;
 use tesser_core::{AssetId, ExchangeId};
 use uuid::Uuid;
 
-use crate::{LedgerEntry, LedgerError, LedgerQuery, LedgerRepository, LedgerResult, LedgerType};
+use crate::integrity::IntegrityRecord;
+use crate::{
+    IntegrityReport, LedgerEntry, LedgerError, LedgerQuery, LedgerRepository, LedgerResult,
+    LedgerType,
+};
 
 const LEDGER_DECIMAL_SCALE: u32 = 18;
 const LEDGER_DECIMAL_SCALE_I8: i8 = 18;
@@ -117,6 +121,16 @@ impl ParquetLedgerRepository {
         }
         Ok(entries)
     }
+
+    fn read_integrity_records(&self, path: &Path) -> LedgerResult<Vec<IntegrityRecord>> {
+        let file = File::open(path)?;
+        let reader = ParquetRecordBatchReaderBuilder::try_new(file)?.build()?;
+        let mut records = Vec::new();
+        for batch in reader {
+            records.extend(batch_to_integrity_records(&batch?)?);
+        }
+        Ok(records)
+    }
 }
 
 impl LedgerRepository for ParquetLedgerRepository {
@@ -166,6 +180,14 @@ impl LedgerRepository for ParquetLedgerRepository {
         }
         Ok(rows)
     }
+
+    fn verify_integrity(&self) -> LedgerResult<IntegrityReport> {
+        let mut records = Vec::new();
+        for path in self.list_parquet_files()? {
+            records.extend(self.read_integrity_records(&path)?);
+        }
+        Ok(IntegrityReport::from_records(records))
+    }
 }
 
 fn matches_query(entry: &LedgerEntry, query: &LedgerQuery) -> bool {
@@ -344,9 +366,13 @@ fn batch_to_entries(batch: &RecordBatch) -> LedgerResult<Vec<LedgerEntry>> {
         let exchange = ExchangeId::from_str(exchanges.value(idx)).map_err(|err| {
             LedgerError::Serialization(format!("invalid exchange {}: {err}", exchanges.value(idx)))
         })?;
-        let asset = AssetId::from_str(assets.value(idx)).map_err(|err| {
+        let mut asset = AssetId::from_str(assets.value(idx)).map_err(|err| {
             LedgerError::Serialization(format!("invalid asset {}: {err}", assets.value(idx)))
         })?;
+        // The asset column holds only the code; qualify it with the row's exchange.
+        if !asset.exchange.is_specified() {
+            asset = AssetId::from_code(exchange, asset.code());
+        }
         let amount = decimal_from_i128(amounts.value(idx))?;
         let entry_type =
             LedgerType::from_str(types.value(idx)).map_err(LedgerError::Serialization)?;
@@ -379,6 +405,50 @@ fn batch_to_entries(batch: &RecordBatch) -> LedgerResult<Vec<LedgerEntry>> {
     Ok(entries)
 }
 
+fn batch_to_integrity_records(batch: &RecordBatch) -> LedgerResult<Vec<IntegrityRecord>> {
+    let sequences = batch
+        .column_by_name("sequence")
+        .and_then(|array| array.as_any().downcast_ref::<UInt64Array>())
+        .ok_or_else(|| {
+            LedgerError::InvalidState("missing sequence column in ledger parquet".into())
+        })?;
+    let ids = batch
+        .column_by_name("id")
+        .and_then(|array| array.as_any().downcast_ref::<StringArray>())
+        .ok_or_else(|| LedgerError::InvalidState("missing id column in ledger parquet".into()))?;
+    let timestamps = batch
+        .column_by_name("timestamp")
+        .and_then(|array| array.as_any().downcast_ref::<TimestampNanosecondArray>())
+        .ok_or_else(|| {
+            LedgerError::InvalidState("missing timestamp column in ledger parquet".into())
+        })?;
+    let amounts = batch
+        .column_by_name("amount")
+        .and_then(|array| array.as_any().downcast_ref::<Decimal128Array>())
+        .ok_or_else(|| {
+            LedgerError::InvalidState("missing amount column in ledger parquet".into())
+        })?;
+
+    let mut records = Vec::with_capacity(batch.num_rows());
+    for idx in 0..batch.num_rows() {
+        let ts_value = timestamps.value(idx);
+        let raw_amount = amounts.value(idx);
+        let reencoded = Decimal::try_from_i128_with_scale(raw_amount, LEDGER_DECIMAL_SCALE)
+            .ok()
+            .and_then(|value| decimal_to_i128(value).ok());
+        records.push(IntegrityRecord {
+            sequence: sequences.value(idx),
+            id: ids.value(idx).to_string(),
+            timestamp: DateTime::<Utc>::from_timestamp(
+                ts_value.div_euclid(1_000_000_000),
+                ts_value.rem_euclid(1_000_000_000) as u32,
+            ),
+            amount_valid: reencoded == Some(raw_amount),
+        });
+    }
+    Ok(records)
+}
+
 fn decimal_to_i128(value: Decimal) -> LedgerResult<i128> {
     let mut normalized = value;
     let scale = normalized.scale();
@@ -402,6 +472,8 @@ fn decimal_from_i128(value: i128) -> LedgerResult<Decimal> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::query::fixtures::{assert_asset_type_groups, mixed_ledger};
+    use crate::LedgerGroupKey;
     use rust_decimal_macros::dec;
     use tempfile::tempdir;
 
@@ -427,4 +499,96 @@ mod tests {
         let loaded = repo.query(LedgerQuery::default()).unwrap();
         assert_eq!(loaded.len(), 5);
     }
+
+    fn sample_entries(count: u64) -> Vec<LedgerEntry> {
+        let start = Utc::now();
+        (1..=count)
+            .map(|seq| LedgerEntry {
+                id: Uuid::new_v4(),
+                sequence: seq,
+                timestamp: start + chrono::Duration::seconds(seq as i64),
+                exchange: ExchangeId::from("paper"),
+                asset: AssetId::from("paper:USDT"),
+                amount: dec!(0.5) * Decimal::from(seq as i64),
+                entry_type: LedgerType::Fee,
+                reference_id: format!("ref-{seq}"),
+                meta: None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn clean_parquet_ledger_passes_integrity_check() {
+        let dir = tempdir().unwrap();
+        let repo = ParquetLedgerRepository::new(dir.path()).unwrap();
+        repo.append_batch(&sample_entries(4)).unwrap();
+
+        let report = repo.verify_integrity().unwrap();
+        assert!(report.is_clean(), "{report:?}");
+        assert_eq!(report.entries_checked, 4);
+    }
+
+    #[test]
+    fn corrupted_parquet_ledger_reports_each_issue() {
+        let dir = tempdir().unwrap();
+        let repo = ParquetLedgerRepository::new(dir.path()).unwrap();
+        let mut entries = sample_entries(3);
+        // Sequence 2 is stamped before sequence 1.
+        entries[1].timestamp = entries[0].timestamp - chrono::Duration::seconds(10);
+        repo.append_batch(&entries).unwrap();
+        // Replaying the first entry duplicates both its sequence and id.
+        repo.append(&entries[0]).unwrap();
+
+        // Sequence 4 carries an amount outside the range a Decimal can represent.
+        let mut bad = sample_entries(4).pop().unwrap();
+        bad.timestamp = entries[2].timestamp + chrono::Duration::seconds(1);
+        let batch = entries_to_batch(&[bad], &repo.schema).unwrap();
+        let mut columns = batch.columns().to_vec();
+        let amounts = Decimal128Array::from(vec![10i128.pow(37)])
+            .with_precision_and_scale(LEDGER_DECIMAL_PRECISION, LEDGER_DECIMAL_SCALE_I8)
+            .unwrap();
+        columns[5] = Arc::new(amounts);
+        let corrupted = RecordBatch::try_new(repo.schema.clone(), columns).unwrap();
+        let path = repo
+            .partition_dir(entries[0].timestamp)
+            .join("corrupted.parquet");
+        let mut writer =
+            ArrowWriter::try_new(File::create(path).unwrap(), repo.schema.clone(), None).unwrap();
+        writer.write(&corrupted).unwrap();
+        writer.close().unwrap();
+
+        let report = repo.verify_integrity().unwrap();
+        assert!(!report.is_clean());
+        assert_eq!(report.entries_checked, 5);
+        assert_eq!(report.duplicate_sequences, vec![1]);
+        assert_eq!(report.duplicate_ids, vec![entries[0].id.to_string()]);
+        assert_eq!(report.non_monotonic_timestamps, vec![2]);
+        assert_eq!(report.invalid_amounts, vec![4]);
+    }
+
+    #[test]
+    fn parquet_aggregates_by_asset_and_type() {
+        let dir = tempdir().unwrap();
+        let repo = ParquetLedgerRepository::new(dir.path()).unwrap();
+        repo.append_batch(&mixed_ledger()).unwrap();
+
+        let rows = repo
+            .aggregate(
+                &[LedgerGroupKey::Asset, LedgerGroupKey::EntryType],
+                LedgerQuery::default(),
+            )
+            .unwrap();
+        assert_asset_type_groups(&rows);
+
+        let fees = repo
+            .aggregate(
+                &[LedgerGroupKey::Exchange],
+                LedgerQuery::default().with_type(LedgerType::Fee),
+            )
+            .unwrap();
+        assert_eq!(fees.len(), 1);
+        assert_eq!(fees[0].exchange, Some(ExchangeId::from("paper")));
+        assert_eq!(fees[0].total, dec!(-2.0));
+        assert_eq!(fees[0].count, 2);
+    }
 }
@@ -1,7 +1,10 @@
-use chrono::{DateTime, Utc};
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Duration, Utc};
+use rust_decimal::Decimal;
 use tesser_core::{AssetId, ExchangeId};
 
-use crate::LedgerType;
+use crate::{LedgerEntry, LedgerType};
 
 /// Filter describing which ledger entries to load from storage.
 #[derive(Clone, Debug, Default)]
@@ -59,3 +62,180 @@ impl LedgerQuery {
         self
     }
 }
+
+/// Dimension used to bucket entries in [`LedgerRepository::aggregate`](crate::LedgerRepository::aggregate).
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum LedgerGroupKey {
+    Exchange,
+    Asset,
+    EntryType,
+}
+
+impl LedgerGroupKey {
+    pub(crate) fn column(self) -> &'static str {
+        match self {
+            LedgerGroupKey::Exchange => "exchange",
+            LedgerGroupKey::Asset => "asset",
+            LedgerGroupKey::EntryType => "entry_type",
+        }
+    }
+}
+
+/// Summed amount and entry count for one group. Dimensions not grouped on are `None`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AggregateRow {
+    pub exchange: Option<ExchangeId>,
+    pub asset: Option<AssetId>,
+    pub entry_type: Option<LedgerType>,
+    pub total: Decimal,
+    pub count: usize,
+}
+
+/// Fold already-filtered entries into groups ordered by their textual keys.
+pub(crate) fn aggregate_entries(
+    entries: &[LedgerEntry],
+    group_by: &[LedgerGroupKey],
+) -> Vec<AggregateRow> {
+    let mut groups: BTreeMap<[Option<String>; 3], AggregateRow> = BTreeMap::new();
+    for entry in entries {
+        let mut row = AggregateRow {
+            exchange: None,
+            asset: None,
+            entry_type: None,
+            total: Decimal::ZERO,
+            count: 0,
+        };
+        let mut key: [Option<String>; 3] = Default::default();
+        for (slot, group) in group_by.iter().enumerate().take(3) {
+            key[slot] = Some(match group {
+                LedgerGroupKey::Exchange => {
+                    row.exchange = Some(entry.exchange);
+                    entry.exchange.to_string()
+                }
+                LedgerGroupKey::Asset => {
+                    row.asset = Some(entry.asset);
+                    entry.asset.to_string()
+                }
+                LedgerGroupKey::EntryType => {
+                    row.entry_type = Some(entry.entry_type);
+                    entry.entry_type.as_str().to_string()
+                }
+            });
+        }
+        let group = groups.entry(key).or_insert(row);
+        group.total += entry.amount;
+        group.count += 1;
+    }
+    groups.into_values().collect()
+}
+
+/// Cumulative balance after each entry, ordered by timestamp then sequence.
+pub(crate) fn running_balances(entries: &[LedgerEntry]) -> Vec<(DateTime<Utc>, Decimal)> {
+    let mut ordered: Vec<&LedgerEntry> = entries.iter().collect();
+    ordered.sort_by_key(|entry| (entry.timestamp, entry.sequence));
+    let mut balance = Decimal::ZERO;
+    ordered
+        .into_iter()
+        .map(|entry| {
+            balance += entry.amount;
+            (entry.timestamp, balance)
+        })
+        .collect()
+}
+
+/// Sample a running balance at every `step` from `start` through `end`, inclusive.
+///
+/// Each sample reflects all entries stamped at or before the boundary.
+pub(crate) fn sample_balances(
+    balances: &[(DateTime<Utc>, Decimal)],
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    step: Duration,
+) -> Vec<(DateTime<Utc>, Decimal)> {
+    let mut samples = Vec::new();
+    if step <= Duration::zero() {
+        return samples;
+    }
+    let mut applied = 0;
+    let mut balance = Decimal::ZERO;
+    let mut boundary = start;
+    while boundary <= end {
+        while let Some((timestamp, running)) = balances.get(applied) {
+            if *timestamp > boundary {
+                break;
+            }
+            balance = *running;
+            applied += 1;
+        }
+        samples.push((boundary, balance));
+        boundary += step;
+    }
+    samples
+}
+
+/// Ledger fixtures shared by the backend aggregation tests.
+#[cfg(test)]
+pub(crate) mod fixtures {
+    use rust_decimal_macros::dec;
+    use tesser_core::{AssetId, ExchangeId};
+
+    use crate::{AggregateRow, LedgerEntry, LedgerType};
+
+    /// Five entries across two assets and three entry types on the `paper` exchange.
+    pub(crate) fn mixed_ledger() -> Vec<LedgerEntry> {
+        let rows = [
+            ("paper:USDT", dec!(100), LedgerType::TransferIn),
+            ("paper:USDT", dec!(50), LedgerType::TransferIn),
+            ("paper:USDT", dec!(-1.5), LedgerType::Fee),
+            ("paper:BTC", dec!(0.25), LedgerType::TradeRealizedPnl),
+            ("paper:USDT", dec!(-0.5), LedgerType::Fee),
+        ];
+        rows.into_iter()
+            .enumerate()
+            .map(|(idx, (asset, amount, kind))| {
+                LedgerEntry::new(
+                    ExchangeId::from("paper"),
+                    AssetId::from(asset),
+                    amount,
+                    kind,
+                    format!("ref-{idx}"),
+                )
+                .with_sequence(idx as u64 + 1)
+            })
+            .collect()
+    }
+
+    /// Check rows grouped by asset and entry type against [`mixed_ledger`].
+    pub(crate) fn assert_asset_type_groups(rows: &[AggregateRow]) {
+        let summary: Vec<_> = rows
+            .iter()
+            .map(|row| {
+                (
+                    row.asset.unwrap().to_string(),
+                    row.entry_type.unwrap(),
+                    row.total,
+                    row.count,
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (
+                    "paper:BTC".to_string(),
+                    LedgerType::TradeRealizedPnl,
+                    dec!(0.25),
+                    1
+                ),
+                ("paper:USDT".to_string(), LedgerType::Fee, dec!(-2.0), 2),
+                (
+                    "paper:USDT".to_string(),
+                    LedgerType::TransferIn,
+                    dec!(150),
+                    2
+                ),
+            ]
+        );
+        assert!(rows.iter().all(|row| row.exchange.is_none()));
+    }
+}
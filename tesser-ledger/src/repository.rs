@@ -1,4 +1,11 @@
-use crate::{LedgerEntry, LedgerQuery, LedgerResult};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use tesser_core::{AssetId, Interval};
+
+use crate::query::{aggregate_entries, running_balances, sample_balances};
+use crate::{
+    AggregateRow, IntegrityReport, LedgerEntry, LedgerGroupKey, LedgerQuery, LedgerResult,
+};
 
 /// Abstraction over durable ledger storage engines.
 pub trait LedgerRepository: Send + Sync {
@@ -15,4 +22,46 @@ pub trait LedgerRepository: Send + Sync {
 
     /// Stream entries matching the supplied query.
     fn query(&self, query: LedgerQuery) -> LedgerResult<Vec<LedgerEntry>>;
+
+    /// Sum amounts and count entries matching `query`, bucketed by `group_by`.
+    ///
+    /// The query limit caps the number of returned groups. The default implementation folds
+    /// the query results in memory.
+    fn aggregate(
+        &self,
+        group_by: &[LedgerGroupKey],
+        query: LedgerQuery,
+    ) -> LedgerResult<Vec<AggregateRow>> {
+        let limit = query.limit;
+        let entries = self.query(LedgerQuery {
+            limit: None,
+            ..query
+        })?;
+        let mut rows = aggregate_entries(&entries, group_by);
+        if let Some(limit) = limit {
+            rows.truncate(limit);
+        }
+        Ok(rows)
+    }
+
+    /// Running balance of `asset` sampled at each `bucket` boundary from `start` through `end`.
+    ///
+    /// Entries before `start` count towards the opening balance.
+    fn equity_curve(
+        &self,
+        asset: AssetId,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        bucket: Interval,
+    ) -> LedgerResult<Vec<(DateTime<Utc>, Decimal)>> {
+        let entries = self.query(LedgerQuery {
+            ascending: true,
+            ..LedgerQuery::default().with_asset(asset)
+        })?;
+        let balances = running_balances(&entries);
+        Ok(sample_balances(&balances, start, end, bucket.as_duration()))
+    }
+
+    /// Scan every persisted row and report consistency problems without failing on them.
+    fn verify_integrity(&self) -> LedgerResult<IntegrityReport>;
 }
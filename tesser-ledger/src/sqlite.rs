@@ -1,15 +1,21 @@
 use std::fs;
 use std::path::PathBuf;
 use std::str::FromStr;
+use std::sync::{Arc, Mutex};
 
 use chrono::{DateTime, Utc};
+use rusqlite::functions::{Aggregate, Context, FunctionFlags};
 use rusqlite::types::Value;
 use rusqlite::{params, params_from_iter, Connection, OptionalExtension};
 use rust_decimal::Decimal;
 use tesser_core::{AssetId, ExchangeId};
 use uuid::Uuid;
 
-use crate::{LedgerEntry, LedgerError, LedgerQuery, LedgerRepository, LedgerResult, LedgerType};
+use crate::integrity::IntegrityRecord;
+use crate::{
+    AggregateRow, IntegrityReport, LedgerEntry, LedgerError, LedgerGroupKey, LedgerQuery,
+    LedgerRepository, LedgerResult, LedgerType,
+};
 
 const LEDGER_SCHEMA: &str = r#"
 CREATE TABLE IF NOT EXISTS ledger_entries (
@@ -29,23 +35,63 @@ CREATE INDEX IF NOT EXISTS ledger_idx_reference
     ON ledger_entries(reference_id);
 "#;
 
+/// Shared `WHERE` clause binding the [`LedgerQuery`] filters as parameters `?1`..`?7`.
+const QUERY_FILTER: &str = "WHERE (?1 IS NULL OR exchange = ?1)
+               AND (?2 IS NULL OR asset = ?2)
+               AND (?3 IS NULL OR entry_type = ?3)
+               AND (?4 IS NULL OR sequence >= ?4)
+               AND (?5 IS NULL OR sequence <= ?5)
+               AND (?6 IS NULL OR timestamp >= ?6)
+               AND (?7 IS NULL OR timestamp <= ?7)";
+
 /// SQLite-backed ledger repository used by the live runtime.
+///
+/// By default every call opens its own connection. Use
+/// [`with_persistent_connection`](Self::with_persistent_connection) to share a single
+/// connection between calls when append throughput matters more than read concurrency.
 #[derive(Clone, Debug)]
 pub struct SqliteLedgerRepository {
     path: PathBuf,
+    shared: Option<Arc<Mutex<Connection>>>,
 }
 
 impl SqliteLedgerRepository {
     pub fn new(path: impl Into<PathBuf>) -> LedgerResult<Self> {
-        let repo = Self { path: path.into() };
+        let repo = Self {
+            path: path.into(),
+            shared: None,
+        };
         repo.initialize_schema()?;
         Ok(repo)
     }
 
-    fn initialize_schema(&self) -> LedgerResult<()> {
+    /// Reuse one connection for every call instead of reopening the database each time.
+    pub fn with_persistent_connection(mut self) -> LedgerResult<Self> {
         let conn = self.connect()?;
-        conn.execute_batch(LEDGER_SCHEMA)?;
-        Ok(())
+        self.shared = Some(Arc::new(Mutex::new(conn)));
+        Ok(self)
+    }
+
+    fn initialize_schema(&self) -> LedgerResult<()> {
+        self.with_connection(|conn| {
+            conn.execute_batch(LEDGER_SCHEMA)?;
+            Ok(())
+        })
+    }
+
+    fn with_connection<R>(
+        &self,
+        f: impl FnOnce(&mut Connection) -> LedgerResult<R>,
+    ) -> LedgerResult<R> {
+        match &self.shared {
+            Some(shared) => {
+                let mut conn = shared.lock().map_err(|_| {
+                    LedgerError::Storage("ledger connection mutex poisoned".to_string())
+                })?;
+                f(&mut conn)
+            }
+            None => f(&mut self.connect()?),
+        }
     }
 
     fn connect(&self) -> LedgerResult<Connection> {
@@ -56,61 +102,82 @@ impl SqliteLedgerRepository {
         }
         let conn = Connection::open(&self.path)?;
         conn.execute_batch("PRAGMA journal_mode = WAL; PRAGMA synchronous = NORMAL;")?;
+        conn.create_aggregate_function(
+            "decimal_sum",
+            1,
+            FunctionFlags::SQLITE_UTF8 | FunctionFlags::SQLITE_DETERMINISTIC,
+            DecimalSum,
+        )?;
         Ok(conn)
     }
 }
 
+/// `SUM` over the text-encoded amounts, kept exact instead of rounding through `REAL`.
+struct DecimalSum;
+
+impl Aggregate<Decimal, String> for DecimalSum {
+    fn init(&self, _ctx: &mut Context<'_>) -> rusqlite::Result<Decimal> {
+        Ok(Decimal::ZERO)
+    }
+
+    fn step(&self, ctx: &mut Context<'_>, total: &mut Decimal) -> rusqlite::Result<()> {
+        let amount: String = ctx.get(0)?;
+        *total += Decimal::from_str(&amount)
+            .map_err(|err| rusqlite::Error::UserFunctionError(Box::new(err)))?;
+        Ok(())
+    }
+
+    fn finalize(&self, _ctx: &mut Context<'_>, total: Option<Decimal>) -> rusqlite::Result<String> {
+        Ok(total.unwrap_or_default().to_string())
+    }
+}
+
 impl LedgerRepository for SqliteLedgerRepository {
     fn append_batch(&self, entries: &[LedgerEntry]) -> LedgerResult<()> {
         if entries.is_empty() {
             return Ok(());
         }
-        let mut conn = self.connect()?;
-        let tx = conn.transaction()?;
-        for entry in entries {
-            tx.execute(
-                "INSERT INTO ledger_entries (
-                    sequence, entry_id, timestamp, exchange, asset, amount, entry_type, reference_id, meta
-                 ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
-                params![
-                    entry.sequence as i64,
-                    entry.id.to_string(),
-                    entry.timestamp.to_rfc3339(),
-                    entry.exchange.to_string(),
-                    entry.asset.to_string(),
-                    entry.amount.to_string(),
-                    entry.entry_type.as_str(),
-                    entry.reference_id,
-                    entry.meta.as_ref().map(|value| value.to_string())
-                ],
-            )?;
-        }
-        tx.commit()?;
-        Ok(())
+        self.with_connection(|conn| {
+            let tx = conn.transaction()?;
+            for entry in entries {
+                tx.execute(
+                    "INSERT INTO ledger_entries (
+                        sequence, entry_id, timestamp, exchange, asset, amount, entry_type, reference_id, meta
+                     ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+                    params![
+                        entry.sequence as i64,
+                        entry.id.to_string(),
+                        entry.timestamp.to_rfc3339(),
+                        entry.exchange.to_string(),
+                        entry.asset.to_string(),
+                        entry.amount.to_string(),
+                        entry.entry_type.as_str(),
+                        entry.reference_id,
+                        entry.meta.as_ref().map(|value| value.to_string())
+                    ],
+                )?;
+            }
+            tx.commit()?;
+            Ok(())
+        })
     }
 
     fn latest_sequence(&self) -> LedgerResult<Option<u64>> {
-        let conn = self.connect()?;
-        let seq: Option<Option<i64>> = conn
-            .query_row("SELECT MAX(sequence) FROM ledger_entries", [], |row| {
-                row.get::<_, Option<i64>>(0)
-            })
-            .optional()?;
+        let seq: Option<Option<i64>> = self.with_connection(|conn| {
+            Ok(conn
+                .query_row("SELECT MAX(sequence) FROM ledger_entries", [], |row| {
+                    row.get::<_, Option<i64>>(0)
+                })
+                .optional()?)
+        })?;
         Ok(seq.flatten().map(|value| value as u64))
     }
 
     fn query(&self, query: LedgerQuery) -> LedgerResult<Vec<LedgerEntry>> {
-        let conn = self.connect()?;
-        let mut sql = String::from(
+        let mut sql = format!(
             "SELECT sequence, entry_id, timestamp, exchange, asset, amount, entry_type, reference_id, meta
              FROM ledger_entries
-             WHERE (?1 IS NULL OR exchange = ?1)
-               AND (?2 IS NULL OR asset = ?2)
-               AND (?3 IS NULL OR entry_type = ?3)
-               AND (?4 IS NULL OR sequence >= ?4)
-               AND (?5 IS NULL OR sequence <= ?5)
-               AND (?6 IS NULL OR timestamp >= ?6)
-               AND (?7 IS NULL OR timestamp <= ?7)"
+             {QUERY_FILTER}"
         );
         sql.push_str(if query.ascending {
             " ORDER BY sequence ASC"
@@ -121,34 +188,138 @@ impl LedgerRepository for SqliteLedgerRepository {
             sql.push_str(" LIMIT ?8");
         }
 
-        let mut params: Vec<Value> = Vec::with_capacity(8);
-        params.push(optional_text(query.exchange.map(|id| id.to_string())));
-        params.push(optional_text(query.asset.map(|id| id.to_string())));
-        params.push(optional_text(
-            query.entry_type.map(|t| t.as_str().to_string()),
-        ));
-        params.push(optional_int(query.start_sequence));
-        params.push(optional_int(query.end_sequence));
-        params.push(optional_text(query.start_time.map(|ts| ts.to_rfc3339())));
-        params.push(optional_text(query.end_time.map(|ts| ts.to_rfc3339())));
+        let mut params = filter_params(&query);
         if let Some(limit) = query.limit {
             params.push(Value::Integer(limit as i64));
         }
 
-        let mut stmt = conn.prepare(&sql)?;
-        let mut rows = if params.is_empty() {
-            stmt.query([])?
-        } else {
-            stmt.query(params_from_iter(params.iter()))?
-        };
-        let mut entries = Vec::new();
-        while let Some(row) = rows.next()? {
-            entries.push(row_to_entry(row)?);
+        self.with_connection(|conn| {
+            let mut stmt = conn.prepare(&sql)?;
+            let mut rows = if params.is_empty() {
+                stmt.query([])?
+            } else {
+                stmt.query(params_from_iter(params.iter()))?
+            };
+            let mut entries = Vec::new();
+            while let Some(row) = rows.next()? {
+                entries.push(row_to_entry(row)?);
+            }
+            Ok(entries)
+        })
+    }
+
+    fn aggregate(
+        &self,
+        group_by: &[LedgerGroupKey],
+        query: LedgerQuery,
+    ) -> LedgerResult<Vec<AggregateRow>> {
+        let columns: Vec<&str> = group_by.iter().map(|key| key.column()).collect();
+        let selected = columns
+            .iter()
+            .map(|column| format!("{column}, "))
+            .collect::<String>();
+        let mut sql = format!(
+            "SELECT {selected}COUNT(*), decimal_sum(amount) FROM ledger_entries {QUERY_FILTER}"
+        );
+        if !columns.is_empty() {
+            let grouped = columns.join(", ");
+            sql.push_str(&format!(" GROUP BY {grouped} ORDER BY {grouped}"));
+        }
+        let mut params = filter_params(&query);
+        if let Some(limit) = query.limit {
+            sql.push_str(" LIMIT ?8");
+            params.push(Value::Integer(limit as i64));
         }
-        Ok(entries)
+
+        self.with_connection(|conn| {
+            let mut stmt = conn.prepare(&sql)?;
+            let mut rows = stmt.query(params_from_iter(params.iter()))?;
+            let mut aggregates = Vec::new();
+            while let Some(row) = rows.next()? {
+                let count: i64 = row.get(columns.len())?;
+                if count == 0 {
+                    continue;
+                }
+                let mut aggregate = AggregateRow {
+                    exchange: None,
+                    asset: None,
+                    entry_type: None,
+                    total: Decimal::ZERO,
+                    count: count as usize,
+                };
+                for (idx, key) in group_by.iter().enumerate() {
+                    let raw: String = row.get(idx)?;
+                    match key {
+                        LedgerGroupKey::Exchange => {
+                            aggregate.exchange =
+                                Some(ExchangeId::from_str(&raw).map_err(|err| {
+                                    LedgerError::Serialization(format!(
+                                        "invalid exchange {raw}: {err}"
+                                    ))
+                                })?);
+                        }
+                        LedgerGroupKey::Asset => {
+                            aggregate.asset = Some(AssetId::from_str(&raw).map_err(|err| {
+                                LedgerError::Serialization(format!("invalid asset {raw}: {err}"))
+                            })?);
+                        }
+                        LedgerGroupKey::EntryType => {
+                            aggregate.entry_type = Some(
+                                LedgerType::from_str(&raw).map_err(LedgerError::Serialization)?,
+                            );
+                        }
+                    }
+                }
+                let total: String = row.get(columns.len() + 1)?;
+                aggregate.total = Decimal::from_str(&total).map_err(|err| {
+                    LedgerError::Serialization(format!("invalid decimal {total}: {err}"))
+                })?;
+                aggregates.push(aggregate);
+            }
+            Ok(aggregates)
+        })
+    }
+
+    fn verify_integrity(&self) -> LedgerResult<IntegrityReport> {
+        self.with_connection(|conn| {
+            let mut stmt =
+                conn.prepare("SELECT sequence, entry_id, timestamp, amount FROM ledger_entries")?;
+            let mut rows = stmt.query([])?;
+            let mut records = Vec::new();
+            while let Some(row) = rows.next()? {
+                let sequence: i64 = row.get(0)?;
+                let entry_id: String = row.get(1)?;
+                let timestamp: String = row.get(2)?;
+                let amount: String = row.get(3)?;
+                records.push(IntegrityRecord {
+                    sequence: sequence as u64,
+                    id: entry_id,
+                    timestamp: DateTime::parse_from_rfc3339(&timestamp)
+                        .ok()
+                        .map(|ts| ts.with_timezone(&Utc)),
+                    amount_valid: Decimal::from_str(&amount)
+                        .is_ok_and(|value| value.to_string() == amount),
+                });
+            }
+            Ok(IntegrityReport::from_records(records))
+        })
     }
 }
 
+fn filter_params(query: &LedgerQuery) -> Vec<Value> {
+    let mut params: Vec<Value> = Vec::with_capacity(8);
+    params.push(optional_text(query.exchange.map(|id| id.to_string())));
+    params.push(optional_text(query.asset.map(|id| id.to_string())));
+    params.push(optional_text(
+        query.entry_type.map(|t| t.as_str().to_string()),
+    ));
+    params.push(optional_int(query.start_sequence));
+    params.push(optional_int(query.end_sequence));
+    params.push(optional_text(query.start_time.map(|ts| ts.to_rfc3339())));
+    params.push(optional_text(query.end_time.map(|ts| ts.to_rfc3339())));
+    params
+}
+
 fn optional_text(value: Option<String>) -> Value {
     value.map(Value::from).unwrap_or(Value::Null)
 }
@@ -210,6 +381,7 @@ fn row_to_entry(row: &rusqlite::Row<'_>) -> LedgerResult<LedgerEntry> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::query::fixtures::{assert_asset_type_groups, mixed_ledger};
     use rust_decimal_macros::dec;
     use tempfile::tempdir;
 
@@ -235,4 +407,228 @@ mod tests {
         assert_eq!(result[0].amount, dec!(12.5));
         assert_eq!(result[0].entry_type, LedgerType::TransferIn);
     }
+
+    #[test]
+    fn clean_sqlite_ledger_passes_integrity_check() {
+        let dir = tempdir().unwrap();
+        let repo = SqliteLedgerRepository::new(dir.path().join("ledger.db")).unwrap();
+        let entries: Vec<_> = (1..=3)
+            .map(|seq| {
+                LedgerEntry::new(
+                    ExchangeId::from("paper"),
+                    AssetId::from("paper:USDT"),
+                    dec!(1.5),
+                    LedgerType::Fee,
+                    format!("ref-{seq}"),
+                )
+                .with_sequence(seq)
+            })
+            .collect();
+        repo.append_batch(&entries).unwrap();
+
+        let report = repo.verify_integrity().unwrap();
+        assert!(report.is_clean(), "{report:?}");
+        assert_eq!(report.entries_checked, 3);
+    }
+
+    #[test]
+    fn corrupted_sqlite_ledger_reports_each_issue() {
+        let dir = tempdir().unwrap();
+        let db_path = dir.path().join("ledger.db");
+        // A table without the key constraints lets duplicate rows slip in.
+        let conn = Connection::open(&db_path).unwrap();
+        conn.execute_batch(
+            "CREATE TABLE ledger_entries (
+                sequence INTEGER, entry_id TEXT, timestamp TEXT, exchange TEXT, asset TEXT,
+                amount TEXT, entry_type TEXT, reference_id TEXT, meta TEXT
+            );",
+        )
+        .unwrap();
+        let rows = [
+            (1, "id-a", "2024-01-01T00:00:02+00:00", "1.5"),
+            (1, "id-b", "2024-01-01T00:00:03+00:00", "2"),
+            (2, "id-b", "2024-01-01T00:00:01+00:00", "3"),
+            (3, "id-c", "2024-01-01T00:00:04+00:00", "not-a-number"),
+        ];
+        for (sequence, id, timestamp, amount) in rows {
+            conn.execute(
+                "INSERT INTO ledger_entries VALUES (?1, ?2, ?3, 'paper', 'paper:USDT', ?4, 'fee', 'ref', NULL)",
+                params![sequence, id, timestamp, amount],
+            )
+            .unwrap();
+        }
+        drop(conn);
+
+        let repo = SqliteLedgerRepository::new(&db_path).unwrap();
+        let report = repo.verify_integrity().unwrap();
+        assert!(!report.is_clean());
+        assert_eq!(report.entries_checked, 4);
+        assert_eq!(report.duplicate_sequences, vec![1]);
+        assert_eq!(report.duplicate_ids, vec!["id-b".to_string()]);
+        assert_eq!(report.non_monotonic_timestamps, vec![2]);
+        assert_eq!(report.invalid_amounts, vec![3]);
+    }
+
+    #[test]
+    fn sqlite_aggregates_by_asset_and_type() {
+        let dir = tempdir().unwrap();
+        let repo = SqliteLedgerRepository::new(dir.path().join("ledger.db")).unwrap();
+        repo.append_batch(&mixed_ledger()).unwrap();
+
+        let rows = repo
+            .aggregate(
+                &[LedgerGroupKey::Asset, LedgerGroupKey::EntryType],
+                LedgerQuery::default(),
+            )
+            .unwrap();
+        assert_asset_type_groups(&rows);
+
+        let fees = repo
+            .aggregate(
+                &[LedgerGroupKey::Exchange],
+                LedgerQuery::default().with_type(LedgerType::Fee),
+            )
+            .unwrap();
+        assert_eq!(fees.len(), 1);
+        assert_eq!(fees[0].exchange, Some(ExchangeId::from("paper")));
+        assert_eq!(fees[0].total, dec!(-2.0));
+        assert_eq!(fees[0].count, 2);
+    }
+
+    #[test]
+    fn persistent_connection_matches_per_call_queries() {
+        let dir = tempdir().unwrap();
+        let per_call = SqliteLedgerRepository::new(dir.path().join("per_call.db")).unwrap();
+        let persistent = SqliteLedgerRepository::new(dir.path().join("persistent.db"))
+            .unwrap()
+            .with_persistent_connection()
+            .unwrap();
+        let entries = mixed_ledger();
+        per_call.append_batch(&entries).unwrap();
+        persistent.append_batch(&entries).unwrap();
+
+        let summarize = |repo: &SqliteLedgerRepository, query: LedgerQuery| {
+            repo.query(query)
+                .unwrap()
+                .into_iter()
+                .map(|entry| (entry.id, entry.sequence, entry.amount, entry.entry_type))
+                .collect::<Vec<_>>()
+        };
+        for query in [
+            LedgerQuery::default(),
+            LedgerQuery::default().with_type(LedgerType::Fee),
+            LedgerQuery::default().with_sequence_range(Some(2), Some(4)),
+        ] {
+            assert_eq!(
+                summarize(&per_call, query.clone()),
+                summarize(&persistent, query)
+            );
+        }
+        assert_eq!(
+            per_call.latest_sequence().unwrap(),
+            persistent.latest_sequence().unwrap()
+        );
+        assert_asset_type_groups(
+            &persistent
+                .aggregate(
+                    &[LedgerGroupKey::Asset, LedgerGroupKey::EntryType],
+                    LedgerQuery::default(),
+                )
+                .unwrap(),
+        );
+    }
+
+    #[test]
+    fn persistent_connection_sustains_many_appends() {
+        let dir = tempdir().unwrap();
+        let repo = SqliteLedgerRepository::new(dir.path().join("ledger.db"))
+            .unwrap()
+            .with_persistent_connection()
+            .unwrap();
+        let total = 2_000u64;
+        for seq in 1..=total {
+            let entry = LedgerEntry::new(
+                ExchangeId::from("paper"),
+                AssetId::from("paper:USDT"),
+                dec!(1),
+                LedgerType::Fee,
+                format!("fill-{seq}"),
+            )
+            .with_sequence(seq);
+            repo.append(&entry).unwrap();
+        }
+        assert_eq!(repo.latest_sequence().unwrap(), Some(total));
+        let rows = repo
+            .aggregate(&[LedgerGroupKey::Asset], LedgerQuery::default())
+            .unwrap();
+        assert_eq!(rows[0].count, total as usize);
+        assert_eq!(rows[0].total, Decimal::from(total));
+        assert!(repo.verify_integrity().unwrap().is_clean());
+    }
+
+    #[test]
+    fn equity_curve_samples_running_balance_per_bucket() {
+        let dir = tempdir().unwrap();
+        let repo = SqliteLedgerRepository::new(dir.path().join("ledger.db")).unwrap();
+        let start = DateTime::parse_from_rfc3339("2024-01-01T00:00:00+00:00")
+            .unwrap()
+            .with_timezone(&Utc);
+        let rows = [
+            ("paper:USDT", dec!(100), -30),
+            ("paper:BTC", dec!(1), 10),
+            ("paper:USDT", dec!(-20), 30),
+            ("paper:USDT", dec!(5), 60),
+            ("paper:USDT", dec!(50), 150),
+            ("paper:BTC", dec!(-0.5), 170),
+            ("paper:USDT", dec!(-1), 240),
+        ];
+        let entries: Vec<_> = rows
+            .into_iter()
+            .enumerate()
+            .map(|(idx, (asset, amount, offset_secs))| {
+                let mut entry = LedgerEntry::new(
+                    ExchangeId::from("paper"),
+                    AssetId::from(asset),
+                    amount,
+                    LedgerType::TransferIn,
+                    format!("ref-{idx}"),
+                )
+                .with_sequence(idx as u64 + 1);
+                entry.timestamp = start + chrono::Duration::seconds(offset_secs);
+                entry
+            })
+            .collect();
+        repo.append_batch(&entries).unwrap();
+
+        let end = start + chrono::Duration::minutes(3);
+        let usdt = repo
+            .equity_curve(
+                AssetId::from("paper:USDT"),
+                start,
+                end,
+                tesser_core::Interval::OneMinute,
+            )
+            .unwrap();
+        let minute = |n| start + chrono::Duration::minutes(n);
+        assert_eq!(
+            usdt,
+            vec![
+                (minute(0), dec!(100)),
+                (minute(1), dec!(85)),
+                (minute(2), dec!(85)),
+                (minute(3), dec!(135)),
+            ]
+        );
+
+        let btc = repo
+            .equity_curve(
+                AssetId::from("paper:BTC"),
+                start,
+                end,
+                tesser_core::Interval::OneMinute,
+            )
+            .unwrap();
+        let values: Vec<_> = btc.into_iter().map(|(_, value)| value).collect();
+        assert_eq!(values, vec![dec!(0), dec!(1), dec!(1), dec!(0.5)]);
+    }
 }
@@ -215,6 +215,8 @@ struct RawInstrumentInfo {
     tick_size: Option<Price>,
     #[serde(default)]
     lot_size: Option<Quantity>,
+    #[serde(default)]
+    min_notional: Option<Price>,
 }
 
 impl RawInstrumentInfo {
@@ -231,6 +233,7 @@ impl RawInstrumentInfo {
             kind: self.kind,
             tick_size: self.tick_size,
             lot_size: self.lot_size,
+            min_notional: self.min_notional,
         }
     }
 }
@@ -244,6 +247,7 @@ struct InstrumentInfo {
     kind: Option<InstrumentKind>,
     tick_size: Option<Price>,
     lot_size: Option<Quantity>,
+    min_notional: Option<Price>,
 }
 
 impl InstrumentInfo {
@@ -272,6 +276,12 @@ impl InstrumentInfo {
             kind: merge_field(symbol, "kind", self.kind, other.kind)?,
             tick_size: merge_field(symbol, "tick_size", self.tick_size, other.tick_size)?,
             lot_size: merge_field(symbol, "lot_size", self.lot_size, other.lot_size)?,
+            min_notional: merge_field(
+                symbol,
+                "min_notional",
+                self.min_notional,
+                other.min_notional,
+            )?,
         })
     }
 
@@ -292,6 +302,7 @@ impl InstrumentInfo {
             settlement_currency: settlement,
             tick_size,
             lot_size,
+            min_notional: self.min_notional,
         })
     }
 }
@@ -334,6 +345,7 @@ impl From<Instrument> for InstrumentInfo {
             kind: Some(value.kind),
             tick_size: Some(value.tick_size),
             lot_size: Some(value.lot_size),
+            min_notional: value.min_notional,
         }
     }
 }
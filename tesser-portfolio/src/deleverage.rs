@@ -0,0 +1,200 @@
+//! Tiered exposure reduction driven by portfolio drawdown.
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use tesser_core::{Position, Price, Side, Signal, SignalKind};
+
+/// Confidence attached to reduce signals emitted by the guard.
+const DELEVERAGE_CONFIDENCE: f64 = 1.0;
+
+/// Drawdown level at which a fraction of every open position is cut.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub struct DeleverageTier {
+    /// Fractional drawdown from peak equity (e.g. `0.05` for 5%).
+    pub drawdown: Decimal,
+    /// Fraction of the current position to close when the tier is crossed.
+    pub reduce_fraction: Decimal,
+}
+
+/// Emits `ReduceLong`/`ReduceShort` signals as drawdown crosses configured tiers.
+///
+/// Each tier fires once on the way down. A tier is re-armed only after drawdown recovers
+/// below its threshold, so improving equity never triggers further cuts.
+#[derive(Clone, Debug)]
+pub struct DeleverageGuard {
+    tiers: Vec<DeleverageTier>,
+    peak_equity: Price,
+    armed_from: usize,
+}
+
+impl DeleverageGuard {
+    /// Build a guard from tiers in any order; fractions are clamped to `[0, 1]`.
+    pub fn new(mut tiers: Vec<DeleverageTier>) -> Self {
+        tiers.retain(|tier| tier.drawdown > Decimal::ZERO);
+        for tier in &mut tiers {
+            tier.reduce_fraction = tier.reduce_fraction.clamp(Decimal::ZERO, Decimal::ONE);
+        }
+        tiers.sort_by(|a, b| a.drawdown.cmp(&b.drawdown));
+        Self {
+            tiers,
+            peak_equity: Decimal::ZERO,
+            armed_from: 0,
+        }
+    }
+
+    /// Returns true when no tiers are configured.
+    pub fn is_empty(&self) -> bool {
+        self.tiers.is_empty()
+    }
+
+    /// Number of tiers currently triggered.
+    pub fn active_tiers(&self) -> usize {
+        self.armed_from
+    }
+
+    /// Current drawdown from the highest equity observed so far.
+    pub fn drawdown(&self, equity: Price) -> Decimal {
+        if self.peak_equity <= Decimal::ZERO {
+            return Decimal::ZERO;
+        }
+        ((self.peak_equity - equity) / self.peak_equity).max(Decimal::ZERO)
+    }
+
+    /// Update with the latest equity and return reduce signals for any newly crossed tiers.
+    pub fn evaluate(&mut self, equity: Price, positions: &[Position]) -> Vec<Signal> {
+        if equity > self.peak_equity {
+            self.peak_equity = equity;
+        }
+        let drawdown = self.drawdown(equity);
+        let crossed = self
+            .tiers
+            .iter()
+            .take_while(|tier| drawdown >= tier.drawdown)
+            .count();
+        if crossed <= self.armed_from {
+            self.armed_from = crossed;
+            return Vec::new();
+        }
+
+        // Crossing several tiers at once compounds their fractions.
+        let keep = self.tiers[self.armed_from..crossed]
+            .iter()
+            .fold(Decimal::ONE, |keep, tier| {
+                keep * (Decimal::ONE - tier.reduce_fraction)
+            });
+        self.armed_from = crossed;
+        let fraction = Decimal::ONE - keep;
+        if fraction <= Decimal::ZERO {
+            return Vec::new();
+        }
+        let note = format!("drawdown {drawdown} crossed deleverage tier {crossed}");
+        positions
+            .iter()
+            .filter_map(|position| {
+                let quantity = (position.quantity.abs() * fraction).normalize();
+                let kind = match position.side? {
+                    Side::Buy => SignalKind::ReduceLong,
+                    Side::Sell => SignalKind::ReduceShort,
+                };
+                (quantity > Decimal::ZERO).then(|| {
+                    let mut signal = Signal::new(position.symbol, kind, DELEVERAGE_CONFIDENCE)
+                        .with_quantity(quantity);
+                    signal.note = Some(note.clone());
+                    signal
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn tiers() -> Vec<DeleverageTier> {
+        vec![
+            DeleverageTier {
+                drawdown: Decimal::new(10, 2),
+                reduce_fraction: Decimal::new(50, 2),
+            },
+            DeleverageTier {
+                drawdown: Decimal::new(5, 2),
+                reduce_fraction: Decimal::new(25, 2),
+            },
+        ]
+    }
+
+    fn position(symbol: &str, side: Side, quantity: i64) -> Position {
+        Position {
+            symbol: symbol.into(),
+            side: Some(side),
+            quantity: Decimal::from(quantity),
+            entry_price: Some(Decimal::from(100)),
+            unrealized_pnl: Decimal::ZERO,
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn crossing_tiers_emits_proportional_reduce_signals() {
+        let mut guard = DeleverageGuard::new(tiers());
+        let mut positions = vec![
+            position("BTCUSDT", Side::Buy, 8),
+            position("ETHUSDT", Side::Sell, 4),
+        ];
+        assert!(guard.evaluate(Decimal::from(1_000), &positions).is_empty());
+        assert!(guard.evaluate(Decimal::from(970), &positions).is_empty());
+
+        let first = guard.evaluate(Decimal::from(950), &positions);
+        assert_eq!(first.len(), 2);
+        assert_eq!(first[0].kind, SignalKind::ReduceLong);
+        assert_eq!(first[0].quantity, Some(Decimal::from(2)));
+        assert_eq!(first[1].kind, SignalKind::ReduceShort);
+        assert_eq!(first[1].quantity, Some(Decimal::ONE));
+        assert_eq!(guard.active_tiers(), 1);
+
+        // Same tier again: no repeated cut.
+        assert!(guard.evaluate(Decimal::from(940), &positions).is_empty());
+
+        positions[0].quantity = Decimal::from(6);
+        positions[1].quantity = Decimal::from(3);
+        let second = guard.evaluate(Decimal::from(890), &positions);
+        assert_eq!(second[0].quantity, Some(Decimal::from(3)));
+        assert_eq!(second[1].quantity, Some(Decimal::new(15, 1)));
+        assert_eq!(guard.active_tiers(), 2);
+    }
+
+    #[test]
+    fn recovery_halts_further_cuts() {
+        let mut guard = DeleverageGuard::new(tiers());
+        let positions = vec![position("BTCUSDT", Side::Buy, 8)];
+        guard.evaluate(Decimal::from(1_000), &positions);
+        assert_eq!(guard.evaluate(Decimal::from(890), &positions).len(), 1);
+        assert_eq!(guard.active_tiers(), 2);
+
+        for equity in [900, 930, 960, 990] {
+            assert!(guard.evaluate(Decimal::from(equity), &positions).is_empty());
+        }
+        assert_eq!(guard.active_tiers(), 0);
+    }
+
+    #[test]
+    fn skipping_tiers_compounds_fractions() {
+        let mut guard = DeleverageGuard::new(tiers());
+        let positions = vec![position("BTCUSDT", Side::Buy, 8)];
+        guard.evaluate(Decimal::from(1_000), &positions);
+        let signals = guard.evaluate(Decimal::from(800), &positions);
+        // Keep 0.75 * 0.5 of the position, so cut 5 of 8.
+        assert_eq!(signals[0].quantity, Some(Decimal::from(5)));
+    }
+
+    #[test]
+    fn flat_positions_are_ignored() {
+        let mut guard = DeleverageGuard::new(tiers());
+        let mut flat = position("BTCUSDT", Side::Buy, 0);
+        flat.side = None;
+        guard.evaluate(Decimal::from(1_000), &[]);
+        assert!(guard.evaluate(Decimal::from(900), &[flat]).is_empty());
+    }
+}
@@ -1,7 +1,9 @@
 //! Portfolio accounting primitives.
 
+pub mod deleverage;
+
 use std::cmp;
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::fs;
 use std::path::PathBuf;
 use std::sync::Arc;
@@ -18,6 +20,11 @@ use tesser_ledger::LedgerEntry;
 use tesser_markets::MarketRegistry;
 use thiserror::Error;
 
+pub use deleverage::{DeleverageGuard, DeleverageTier};
+
+/// Maximum number of (equity, price) samples retained per symbol for beta estimates.
+const PRICE_SAMPLE_CAPACITY: usize = 1_024;
+
 /// Result alias for portfolio operations.
 pub type PortfolioResult<T> = Result<T, PortfolioError>;
 
@@ -93,6 +100,8 @@ pub struct Portfolio {
     peak_equity: Price,
     liquidate_only: bool,
     market_registry: Arc<MarketRegistry>,
+    /// Equity observed alongside each market price update, keyed by the updated symbol.
+    price_samples: HashMap<Symbol, VecDeque<(Price, Price)>>,
 }
 
 impl Portfolio {
@@ -120,6 +129,7 @@ impl Portfolio {
             peak_equity: Decimal::ZERO,
             liquidate_only: false,
             market_registry: registry,
+            price_samples: HashMap::new(),
         };
         portfolio.ensure_reporting_currency_entries();
         portfolio.initial_equity = portfolio.cash_value();
@@ -170,6 +180,20 @@ impl Portfolio {
         portfolio
     }
 
+    /// Rebuild the cash book by replaying ledger `entries` on top of the configured opening
+    /// balances.
+    ///
+    /// The ledger only books cash movements, so the returned portfolio holds no positions.
+    pub fn from_ledger(
+        entries: &[LedgerEntry],
+        config: PortfolioConfig,
+        registry: Arc<MarketRegistry>,
+    ) -> PortfolioResult<Self> {
+        let mut portfolio = Self::new(config, registry);
+        portfolio.apply_ledger_entries(entries)?;
+        Ok(portfolio)
+    }
+
     /// Update positions in response to an execution fill and return realized PnL.
     pub fn apply_fill_positions(&mut self, fill: &Fill) -> PortfolioResult<FillLedgerImpact> {
         let instrument = self
@@ -263,6 +287,23 @@ impl Portfolio {
         Ok(())
     }
 
+    /// Replace the tracked position for `symbol` with the venue's view of it.
+    ///
+    /// `remote` of `None` (or a flat position) drops the local position. Cash is left untouched;
+    /// balance drift is reconciled separately.
+    pub fn resync_position(&mut self, symbol: Symbol, remote: Option<Position>) {
+        let account = self.account_mut(symbol.exchange);
+        match remote.filter(|position| !position.quantity.is_zero()) {
+            Some(position) => {
+                account.positions.insert(symbol, position);
+            }
+            None => {
+                account.positions.remove(&symbol);
+            }
+        }
+        self.update_drawdown_state();
+    }
+
     /// Retrieve a position snapshot for a symbol.
     #[must_use]
     pub fn position(&self, symbol: impl Into<Symbol>) -> Option<&Position> {
@@ -421,6 +462,7 @@ impl Portfolio {
             peak_equity: cmp::max(state.peak_equity, state.initial_equity),
             liquidate_only: state.liquidate_only,
             market_registry: registry,
+            price_samples: HashMap::new(),
         };
         if state.sub_accounts.is_empty() {
             for (symbol, position) in state.positions {
@@ -464,9 +506,49 @@ impl Portfolio {
         }
         Self::update_conversion_rates(account, reporting, &instrument, price);
         self.update_drawdown_state();
+        self.record_price_sample(symbol, price);
         Ok(updated)
     }
 
+    /// Beta of portfolio equity returns to `reference` over the last `returns_window` price updates.
+    ///
+    /// Returns `None` until `returns_window + 1` samples of the reference have been observed or
+    /// when the reference price did not move over the window.
+    #[must_use]
+    pub fn beta_to(&self, reference: Symbol, returns_window: usize) -> Option<Decimal> {
+        if returns_window < 2 {
+            return None;
+        }
+        let samples = self.price_samples.get(&reference)?;
+        if samples.len() <= returns_window {
+            return None;
+        }
+        let window: Vec<_> = samples
+            .iter()
+            .skip(samples.len() - returns_window - 1)
+            .collect();
+        let mut portfolio_returns = Vec::with_capacity(returns_window);
+        let mut reference_returns = Vec::with_capacity(returns_window);
+        for pair in window.windows(2) {
+            let ((prev_equity, prev_price), (equity, price)) = (pair[0], pair[1]);
+            if prev_equity.is_zero() || prev_price.is_zero() {
+                continue;
+            }
+            portfolio_returns.push(equity / prev_equity - Decimal::ONE);
+            reference_returns.push(price / prev_price - Decimal::ONE);
+        }
+        beta_from_returns(&portfolio_returns, &reference_returns)
+    }
+
+    fn record_price_sample(&mut self, symbol: Symbol, price: Price) {
+        let equity = self.equity();
+        let samples = self.price_samples.entry(symbol).or_default();
+        if samples.len() == PRICE_SAMPLE_CAPACITY {
+            samples.pop_front();
+        }
+        samples.push_back((equity, price));
+    }
+
     fn update_drawdown_state(&mut self) {
         let equity = self.equity();
         if equity > self.peak_equity {
@@ -657,6 +739,27 @@ fn matches_reporting_currency(currency: AssetId, reporting: AssetId) -> bool {
     reporting.exchange == ExchangeId::UNSPECIFIED && currency.code() == reporting.code()
 }
 
+/// Covariance of the two return series divided by the variance of the reference series.
+fn beta_from_returns(portfolio: &[Decimal], reference: &[Decimal]) -> Option<Decimal> {
+    if portfolio.len() != reference.len() || reference.len() < 2 {
+        return None;
+    }
+    let count = Decimal::from(reference.len());
+    let portfolio_mean = portfolio.iter().sum::<Decimal>() / count;
+    let reference_mean = reference.iter().sum::<Decimal>() / count;
+    let mut covariance = Decimal::ZERO;
+    let mut variance = Decimal::ZERO;
+    for (p, r) in portfolio.iter().zip(reference) {
+        let reference_dev = *r - reference_mean;
+        covariance += (*p - portfolio_mean) * reference_dev;
+        variance += reference_dev * reference_dev;
+    }
+    if variance.is_zero() {
+        return None;
+    }
+    Some(covariance / variance)
+}
+
 fn conversion_rate_for_currency(currency: AssetId, reporting: AssetId) -> Price {
     if matches_reporting_currency(currency, reporting) {
         Decimal::ONE
@@ -702,6 +805,9 @@ pub struct LiveState {
     pub open_orders: Vec<Order>,
     pub last_prices: HashMap<Symbol, Price>,
     pub last_candle_ts: Option<DateTime<Utc>>,
+    /// Name of the strategy that produced `strategy_state`.
+    #[serde(default)]
+    pub strategy_name: Option<String>,
     pub strategy_state: Option<serde_json::Value>,
     #[serde(default)]
     pub execution_checkpoint: ExecutionCheckpoint,
@@ -834,6 +940,7 @@ mod tests {
             settlement_currency: "USDT".into(),
             tick_size: Decimal::new(1, 0),
             lot_size: Decimal::new(1, 0),
+            min_notional: None,
         };
         Arc::new(MarketRegistry::from_instruments(vec![instrument]).unwrap())
     }
@@ -895,7 +1002,79 @@ mod tests {
         assert_eq!(snapshot.peak_equity, Decimal::from(25_000));
     }
 
-    fn apply_with_ledger(portfolio: &mut Portfolio, fill: &Fill) {
+    fn portfolio_with_samples(reference: Symbol, equity: &[&str], prices: &[&str]) -> Portfolio {
+        let mut portfolio = Portfolio::new(PortfolioConfig::default(), sample_registry());
+        let samples = equity
+            .iter()
+            .zip(prices)
+            .map(|(equity, price)| (equity.parse().unwrap(), price.parse().unwrap()))
+            .collect();
+        portfolio.price_samples.insert(reference, samples);
+        portfolio
+    }
+
+    #[test]
+    fn beta_of_perfectly_correlated_series() {
+        let reference = Symbol::from("BTCUSDT");
+        // Reference returns alternate +/-10%, equity returns +/-20%.
+        let portfolio = portfolio_with_samples(
+            reference,
+            &["1000", "1200", "960", "1152", "921.6"],
+            &["100", "110", "99", "108.9", "98.01"],
+        );
+        assert_eq!(portfolio.beta_to(reference, 4), Some(Decimal::from(2)));
+        assert_eq!(portfolio.beta_to(reference, 5), None);
+    }
+
+    #[test]
+    fn beta_of_uncorrelated_series_is_zero() {
+        let reference = Symbol::from("BTCUSDT");
+        let portfolio = portfolio_with_samples(
+            reference,
+            &["1000", "1100", "1210", "1089", "980.1"],
+            &["100", "110", "99", "108.9", "98.01"],
+        );
+        assert_eq!(portfolio.beta_to(reference, 4), Some(Decimal::ZERO));
+    }
+
+    #[test]
+    fn beta_uses_market_updates_and_needs_reference_moves() {
+        let symbol = Symbol::from("BTCUSDT");
+        let mut portfolio = Portfolio::new(PortfolioConfig::default(), sample_registry());
+        for price in [100, 100, 100] {
+            portfolio
+                .update_market_data(symbol, Decimal::from(price))
+                .unwrap();
+        }
+        assert_eq!(portfolio.beta_to(symbol, 2), None);
+        for price in [110, 99] {
+            portfolio
+                .update_market_data(symbol, Decimal::from(price))
+                .unwrap();
+        }
+        // A flat (all-cash) book carries no exposure to the reference.
+        assert_eq!(portfolio.beta_to(symbol, 2), Some(Decimal::ZERO));
+    }
+
+    #[test]
+    fn ledger_replay_rebuilds_cash_balances() {
+        let mut live = Portfolio::new(PortfolioConfig::default(), sample_registry());
+        let mut journal = Vec::new();
+        for fill in [
+            sample_fill(Side::Buy, Decimal::from(100), Decimal::from(2)),
+            sample_fill(Side::Sell, Decimal::from(110), Decimal::from(2)),
+        ] {
+            journal.extend(apply_with_ledger(&mut live, &fill));
+        }
+        let replayed =
+            Portfolio::from_ledger(&journal, PortfolioConfig::default(), sample_registry())
+                .unwrap();
+        assert_eq!(replayed.cash(), live.cash());
+        assert_ne!(replayed.cash(), Decimal::from(10_000));
+        assert!(replayed.positions().is_empty());
+    }
+
+    fn apply_with_ledger(portfolio: &mut Portfolio, fill: &Fill) -> Vec<LedgerEntry> {
         let impact = portfolio.apply_fill_positions(fill).unwrap();
         let registry = sample_registry();
         let instrument = registry.get(fill.symbol).unwrap();
@@ -905,5 +1084,6 @@ mod tests {
             impact.realized_pnl,
         ));
         portfolio.apply_ledger_entries(&entries).unwrap();
+        entries
     }
 }
@@ -0,0 +1,93 @@
+//! Bearer-token authentication for the control plane.
+//!
+//! [`BearerAuth`] guards a server: every request must carry `authorization: Bearer <token>`.
+//! [`BearerToken`] is the matching client interceptor. Both are no-ops without a token so
+//! unauthenticated deployments keep working unchanged, but an empty token is rejected rather
+//! than silently disabling the check.
+
+use tonic::metadata::{Ascii, MetadataValue};
+use tonic::service::Interceptor;
+use tonic::{Request, Status};
+
+/// Metadata key carrying the bearer token.
+pub const AUTHORIZATION_HEADER: &str = "authorization";
+
+fn bearer_value(token: &str) -> Result<MetadataValue<Ascii>, Status> {
+    if token.trim().is_empty() {
+        return Err(Status::invalid_argument("control token must not be empty"));
+    }
+    format!("Bearer {token}")
+        .parse()
+        .map_err(|_| Status::invalid_argument("control token must be printable ASCII"))
+}
+
+/// Server interceptor rejecting requests that lack the configured bearer token.
+#[derive(Clone, Default)]
+pub struct BearerAuth {
+    expected: Option<MetadataValue<Ascii>>,
+}
+
+impl BearerAuth {
+    /// Require `token` on every request; `None` disables the check.
+    pub fn new(token: Option<&str>) -> Result<Self, Status> {
+        let expected = token.map(bearer_value).transpose()?;
+        Ok(Self { expected })
+    }
+
+    /// Whether requests are being checked.
+    pub fn is_enabled(&self) -> bool {
+        self.expected.is_some()
+    }
+}
+
+impl Interceptor for BearerAuth {
+    fn call(&mut self, request: Request<()>) -> Result<Request<()>, Status> {
+        let Some(expected) = &self.expected else {
+            return Ok(request);
+        };
+        match request.metadata().get(AUTHORIZATION_HEADER) {
+            Some(provided) if constant_time_eq(provided.as_bytes(), expected.as_bytes()) => {
+                Ok(request)
+            }
+            Some(_) => Err(Status::unauthenticated("invalid control token")),
+            None => Err(Status::unauthenticated("missing control token")),
+        }
+    }
+}
+
+/// Compare without short-circuiting, so response timing does not reveal how much of the token
+/// matched.
+fn constant_time_eq(left: &[u8], right: &[u8]) -> bool {
+    if left.len() != right.len() {
+        return false;
+    }
+    left.iter()
+        .zip(right)
+        .fold(0u8, |diff, (a, b)| diff | (a ^ b))
+        == 0
+}
+
+/// Client interceptor attaching a bearer token to every outgoing request.
+#[derive(Clone, Default)]
+pub struct BearerToken {
+    header: Option<MetadataValue<Ascii>>,
+}
+
+impl BearerToken {
+    /// Attach `token` to each request; `None` sends requests untouched.
+    pub fn new(token: Option<&str>) -> Result<Self, Status> {
+        let header = token.map(bearer_value).transpose()?;
+        Ok(Self { header })
+    }
+}
+
+impl Interceptor for BearerToken {
+    fn call(&mut self, mut request: Request<()>) -> Result<Request<()>, Status> {
+        if let Some(header) = &self.header {
+            request
+                .metadata_mut()
+                .insert(AUTHORIZATION_HEADER, header.clone());
+        }
+        Ok(request)
+    }
+}
@@ -5,11 +5,12 @@ use serde_json::{json, Map};
 use std::collections::HashMap;
 use std::str::FromStr;
 use tesser_core::{
-    AssetId, Candle, Cash, CashBook, ExchangeId, ExecutionHint, Fill, Interval, Order, OrderBook,
-    OrderBookLevel, OrderStatus, OrderType, Position, Side, Signal, SignalKind, Symbol, Tick,
+    AssetId, Candle, CandleStatus, Cash, CashBook, ExchangeId, ExecutionHint, Fill, Interval,
+    Order, OrderBook, OrderBookLevel, OrderStatus, OrderType, Position, Side, Signal, SignalKind,
+    Symbol, Tick,
 };
 use tesser_portfolio::{Portfolio, PortfolioState, SubAccountState};
-use tesser_strategy::StrategyContext;
+use tesser_strategy::{ContextSnapshot, StrategyContext};
 use uuid::Uuid;
 
 // --- Helpers ---
@@ -59,6 +60,13 @@ fn interval_to_proto(i: Interval) -> proto::Interval {
     }
 }
 
+fn candle_status_to_proto(status: CandleStatus) -> proto::CandleStatus {
+    match status {
+        CandleStatus::Forming => proto::CandleStatus::Forming,
+        CandleStatus::Closed => proto::CandleStatus::Closed,
+    }
+}
+
 fn order_type_to_proto(order_type: OrderType) -> proto::OrderType {
     match order_type {
         OrderType::Market => proto::OrderType::Market,
@@ -104,6 +112,7 @@ impl From<Candle> for proto::Candle {
             close: Some(to_decimal_proto(c.close)),
             volume: Some(to_decimal_proto(c.volume)),
             timestamp: Some(to_timestamp_proto(c.timestamp)),
+            status: candle_status_to_proto(c.status) as i32,
         }
     }
 }
@@ -226,6 +235,8 @@ fn signal_kind_to_proto(kind: SignalKind) -> proto::signal::Kind {
         SignalKind::EnterShort => proto::signal::Kind::EnterShort,
         SignalKind::ExitShort => proto::signal::Kind::ExitShort,
         SignalKind::Flatten => proto::signal::Kind::Flatten,
+        SignalKind::ReduceLong => proto::signal::Kind::ReduceLong,
+        SignalKind::ReduceShort => proto::signal::Kind::ReduceShort,
     }
 }
 
@@ -340,14 +351,22 @@ impl From<Position> for proto::Position {
     }
 }
 
-impl<'a> From<&'a StrategyContext> for proto::StrategyContext {
-    fn from(ctx: &'a StrategyContext) -> Self {
+impl From<ContextSnapshot> for proto::StrategyContext {
+    fn from(snapshot: ContextSnapshot) -> Self {
         Self {
-            positions: ctx.positions().iter().cloned().map(Into::into).collect(),
+            positions: snapshot.positions.into_iter().map(Into::into).collect(),
+            recent_candles: snapshot.candles.into_iter().map(Into::into).collect(),
+            recent_ticks: snapshot.ticks.into_iter().map(Into::into).collect(),
         }
     }
 }
 
+impl<'a> From<&'a StrategyContext> for proto::StrategyContext {
+    fn from(ctx: &'a StrategyContext) -> Self {
+        ContextSnapshot::from(ctx).into()
+    }
+}
+
 // --- Proto to Structs ---
 
 impl From<proto::Signal> for Signal {
@@ -360,6 +379,8 @@ impl From<proto::Signal> for Signal {
             proto::signal::Kind::EnterShort => SignalKind::EnterShort,
             proto::signal::Kind::ExitShort => SignalKind::ExitShort,
             proto::signal::Kind::Flatten => SignalKind::Flatten,
+            proto::signal::Kind::ReduceLong => SignalKind::ReduceLong,
+            proto::signal::Kind::ReduceShort => SignalKind::ReduceShort,
             _ => SignalKind::EnterLong, // Default fallback
         };
 
@@ -471,5 +492,6 @@ fn execution_hint_metadata(hint: &ExecutionHint) -> serde_json::Value {
             "name": name,
             "params": params,
         }),
+        ExecutionHint::Unknown => json!({ "type": "unknown" }),
     }
 }
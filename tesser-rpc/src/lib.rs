@@ -1,5 +1,7 @@
+pub mod auth;
 pub mod client;
 pub mod conversions;
+pub mod metrics;
 pub mod strategy;
 pub mod transport;
 
@@ -8,4 +10,5 @@ pub mod proto {
     tonic::include_proto!("tesser.rpc.v1");
 }
 
-pub use strategy::RpcStrategy;
+pub use metrics::RpcMetrics;
+pub use strategy::{FallbackPolicy, RpcStrategy};
@@ -0,0 +1,120 @@
+//! Prometheus instrumentation for [`crate::RpcStrategy`].
+
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use prometheus::{HistogramOpts, HistogramVec, IntCounterVec, Opts, Registry};
+
+/// Latency, error, reconnect and signal counters for remote strategies, labelled by endpoint.
+///
+/// Strategies are built through the registry, so a single process-wide instance is shared via
+/// [`global`]; the live runtime registers it with its Prometheus registry.
+pub struct RpcMetrics {
+    latency: HistogramVec,
+    errors: IntCounterVec,
+    reconnects: IntCounterVec,
+    signals: IntCounterVec,
+}
+
+impl RpcMetrics {
+    fn new() -> Self {
+        let latency = HistogramVec::new(
+            HistogramOpts::new(
+                "tesser_rpc_call_latency_seconds",
+                "Round-trip latency of remote strategy calls",
+            )
+            .buckets(vec![
+                0.0005, 0.001, 0.0025, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0,
+            ]),
+            &["endpoint", "method"],
+        )
+        .unwrap();
+        let errors = IntCounterVec::new(
+            Opts::new(
+                "tesser_rpc_errors_total",
+                "Failed remote strategy calls grouped by method",
+            ),
+            &["endpoint", "method"],
+        )
+        .unwrap();
+        let reconnects = IntCounterVec::new(
+            Opts::new(
+                "tesser_rpc_reconnects_total",
+                "Times the remote strategy client was re-established after a failure",
+            ),
+            &["endpoint"],
+        )
+        .unwrap();
+        let signals = IntCounterVec::new(
+            Opts::new(
+                "tesser_rpc_signals_received_total",
+                "Signals returned by the remote strategy",
+            ),
+            &["endpoint"],
+        )
+        .unwrap();
+        Self {
+            latency,
+            errors,
+            reconnects,
+            signals,
+        }
+    }
+
+    /// Add every RPC collector to `registry`.
+    pub fn register(&self, registry: &Registry) -> prometheus::Result<()> {
+        registry.register(Box::new(self.latency.clone()))?;
+        registry.register(Box::new(self.errors.clone()))?;
+        registry.register(Box::new(self.reconnects.clone()))?;
+        registry.register(Box::new(self.signals.clone()))?;
+        Ok(())
+    }
+
+    pub(crate) fn observe_latency(&self, endpoint: &str, method: &str, elapsed: Duration) {
+        self.latency
+            .with_label_values(&[endpoint, method])
+            .observe(elapsed.as_secs_f64());
+    }
+
+    pub(crate) fn record_error(&self, endpoint: &str, method: &str) {
+        self.errors.with_label_values(&[endpoint, method]).inc();
+    }
+
+    pub(crate) fn record_reconnect(&self, endpoint: &str) {
+        self.reconnects.with_label_values(&[endpoint]).inc();
+    }
+
+    pub(crate) fn record_signals(&self, endpoint: &str, count: usize) {
+        self.signals
+            .with_label_values(&[endpoint])
+            .inc_by(count as u64);
+    }
+
+    /// Number of latency samples recorded for `method` on `endpoint`.
+    pub fn latency_samples(&self, endpoint: &str, method: &str) -> u64 {
+        self.latency
+            .with_label_values(&[endpoint, method])
+            .get_sample_count()
+    }
+
+    /// Failed calls of `method` on `endpoint`.
+    pub fn errors(&self, endpoint: &str, method: &str) -> u64 {
+        self.errors.with_label_values(&[endpoint, method]).get()
+    }
+
+    /// Reconnections performed against `endpoint`.
+    pub fn reconnects(&self, endpoint: &str) -> u64 {
+        self.reconnects.with_label_values(&[endpoint]).get()
+    }
+
+    /// Signals received from `endpoint`.
+    pub fn signals_received(&self, endpoint: &str) -> u64 {
+        self.signals.with_label_values(&[endpoint]).get()
+    }
+}
+
+/// Process-wide RPC strategy metrics.
+pub fn global() -> &'static RpcMetrics {
+    static METRICS: OnceLock<RpcMetrics> = OnceLock::new();
+    METRICS.get_or_init(RpcMetrics::new)
+}
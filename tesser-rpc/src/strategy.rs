@@ -3,8 +3,8 @@ use async_trait::async_trait;
 use serde::Deserialize;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
-use std::time::Duration;
-use tesser_core::{Candle, Fill, OrderBook, Signal, Symbol, Tick};
+use std::time::{Duration, Instant};
+use tesser_core::{Candle, Fill, OrderBook, Signal, SignalKind, Symbol, Tick};
 use tesser_strategy::{
     register_strategy, Strategy, StrategyContext, StrategyError, StrategyResult,
 };
@@ -15,6 +15,7 @@ use tracing::{error, info, warn};
 use url::Url;
 
 use crate::client::RemoteStrategyClient;
+use crate::metrics;
 use crate::proto::{CandleRequest, FillRequest, InitRequest, OrderBookRequest, TickRequest};
 use crate::transport::grpc::GrpcAdapter;
 
@@ -38,6 +39,21 @@ fn default_heartbeat_ms() -> u64 {
     5_000
 }
 
+fn default_max_transport_failures() -> u32 {
+    MAX_HEARTBEAT_FAILURES
+}
+
+/// Behaviour applied once the remote strategy has been unreachable for too long.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum FallbackPolicy {
+    /// Keep existing positions untouched and wait for the remote to recover.
+    #[default]
+    Hold,
+    /// Emit a `Flatten` signal for every subscribed symbol to de-risk.
+    Flatten,
+}
+
 #[derive(Clone, Deserialize, Debug)]
 struct RpcStrategyConfig {
     #[serde(flatten)]
@@ -46,9 +62,15 @@ struct RpcStrategyConfig {
     symbols: Vec<String>,
     #[serde(default = "default_heartbeat_ms")]
     heartbeat_interval_ms: u64,
+    #[serde(default)]
+    fallback: FallbackPolicy,
+    /// Consecutive failed calls after which the fallback policy is applied.
+    #[serde(default = "default_max_transport_failures")]
+    max_transport_failures: u32,
 }
 
 type SharedClient = Arc<AsyncMutex<Box<dyn RemoteStrategyClient>>>;
+type TransportFactory = Arc<dyn Fn() -> Box<dyn RemoteStrategyClient> + Send + Sync>;
 
 const DEFAULT_HEARTBEAT_INTERVAL: Duration = Duration::from_millis(5_000);
 const MAX_HEARTBEAT_FAILURES: u32 = 3;
@@ -65,6 +87,16 @@ pub struct RpcStrategy {
     heartbeat_handle: Option<JoinHandle<()>>,
     heartbeat_interval: Duration,
     max_heartbeat_failures: u32,
+    fallback: FallbackPolicy,
+    max_transport_failures: u32,
+    transport_failures: u32,
+    fallback_engaged: bool,
+    /// Overrides the configured transport when set (used to inject in-process clients).
+    transport_factory: Option<TransportFactory>,
+    /// Endpoint label attached to metrics.
+    endpoint: String,
+    /// Whether a client has been established before, so later connects count as reconnects.
+    connected_once: bool,
 }
 
 impl Default for RpcStrategy {
@@ -80,11 +112,28 @@ impl Default for RpcStrategy {
             heartbeat_handle: None,
             heartbeat_interval: DEFAULT_HEARTBEAT_INTERVAL,
             max_heartbeat_failures: MAX_HEARTBEAT_FAILURES,
+            fallback: FallbackPolicy::default(),
+            max_transport_failures: MAX_HEARTBEAT_FAILURES,
+            transport_failures: 0,
+            fallback_engaged: false,
+            transport_factory: None,
+            endpoint: String::new(),
+            connected_once: false,
         }
     }
 }
 
 impl RpcStrategy {
+    /// Build clients with `factory` instead of the transport named in the configuration.
+    #[must_use]
+    pub fn with_transport<F>(mut self, factory: F) -> Self
+    where
+        F: Fn() -> Box<dyn RemoteStrategyClient> + Send + Sync + 'static,
+    {
+        self.transport_factory = Some(Arc::new(factory));
+        self
+    }
+
     fn build_client(config: &TransportConfig) -> Box<dyn RemoteStrategyClient> {
         match config {
             TransportConfig::Grpc {
@@ -112,6 +161,7 @@ impl RpcStrategy {
         let interval_duration = self.heartbeat_interval;
         let max_failures = self.max_heartbeat_failures;
         let health = self.health.clone();
+        let endpoint = self.endpoint.clone();
         self.heartbeat_handle = Some(tokio::spawn(async move {
             let mut ticker = interval(interval_duration);
             ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
@@ -119,7 +169,11 @@ impl RpcStrategy {
             loop {
                 ticker.tick().await;
                 let mut guard = client.lock().await;
-                match guard.heartbeat().await {
+                let started = Instant::now();
+                let result = guard.heartbeat().await;
+                drop(guard);
+                metrics::global().observe_latency(&endpoint, "Heartbeat", started.elapsed());
+                match result {
                     Ok(resp) if resp.healthy => {
                         health.store(true, Ordering::Relaxed);
                         failures = 0;
@@ -135,6 +189,7 @@ impl RpcStrategy {
                     }
                     Err(err) => {
                         warn!(target: "rpc", %err, "heartbeat failure");
+                        metrics::global().record_error(&endpoint, "Heartbeat");
                         failures += 1;
                         health.store(false, Ordering::Relaxed);
                     }
@@ -166,22 +221,30 @@ impl RpcStrategy {
                 .clone()
                 .ok_or_else(|| StrategyError::InvalidConfig("rpc config missing".into()))?;
 
-            let mut client = Self::build_client(&config.transport);
+            let mut client = match &self.transport_factory {
+                Some(factory) => factory(),
+                None => Self::build_client(&config.transport),
+            };
 
-            client
-                .connect()
-                .await
-                .map_err(|e| StrategyError::Internal(format!("RPC connect failed: {e}")))?;
+            client.connect().await.map_err(|e| {
+                metrics::global().record_error(&self.endpoint, "Connect");
+                StrategyError::Internal(format!("RPC connect failed: {e}"))
+            })?;
 
             let init_request = InitRequest {
                 config_json: self.config_payload.clone(),
             };
 
-            let response = client.initialize(init_request).await.map_err(|e| {
+            let started = Instant::now();
+            let response = client.initialize(init_request).await;
+            metrics::global().observe_latency(&self.endpoint, "Initialize", started.elapsed());
+            let response = response.map_err(|e| {
+                metrics::global().record_error(&self.endpoint, "Initialize");
                 StrategyError::Internal(format!("remote strategy init failed: {e}"))
             })?;
 
             if !response.success {
+                metrics::global().record_error(&self.endpoint, "Initialize");
                 return Err(StrategyError::Internal(format!(
                     "remote strategy rejected init: {}",
                     response.error_message
@@ -196,6 +259,10 @@ impl RpcStrategy {
             self.apply_remote_metadata(symbols);
             info!(target: "rpc", symbols = ?self.subscriptions, "RPC strategy initialized");
             self.health.store(true, Ordering::Relaxed);
+            if self.connected_once {
+                metrics::global().record_reconnect(&self.endpoint);
+            }
+            self.connected_once = true;
             let shared = Arc::new(AsyncMutex::new(client));
             self.spawn_heartbeat(shared.clone());
             self.client = Some(shared.clone());
@@ -220,6 +287,9 @@ impl RpcStrategy {
     }
 
     fn handle_signals(&mut self, signals: Vec<crate::proto::Signal>) {
+        self.transport_failures = 0;
+        self.fallback_engaged = false;
+        metrics::global().record_signals(&self.endpoint, signals.len());
         for proto_sig in signals {
             self.pending_signals.push(proto_sig.into());
         }
@@ -227,7 +297,33 @@ impl RpcStrategy {
 
     fn handle_rpc_error(&mut self, err: Error, context: &str) {
         error!(target: "rpc", %context, error = %err, "RPC call failed; dropping client");
+        metrics::global().record_error(&self.endpoint, context);
         self.teardown_client();
+        self.record_transport_failure();
+    }
+
+    /// Count a failed call and apply the fallback policy once failures persist.
+    fn record_transport_failure(&mut self) {
+        self.transport_failures = self.transport_failures.saturating_add(1);
+        if self.fallback_engaged
+            || self.fallback != FallbackPolicy::Flatten
+            || self.transport_failures < self.max_transport_failures
+        {
+            return;
+        }
+        let symbols = self.subscriptions();
+        warn!(
+            target: "rpc",
+            failures = self.transport_failures,
+            symbols = ?symbols,
+            "remote strategy unreachable; flattening subscriptions"
+        );
+        for symbol in symbols {
+            let mut signal = Signal::new(symbol, SignalKind::Flatten, 1.0);
+            signal.note = Some("rpc fallback: remote strategy unreachable".to_string());
+            self.pending_signals.push(signal);
+        }
+        self.fallback_engaged = true;
     }
 
     fn is_symbol_allowed(&self, symbol: &Symbol) -> bool {
@@ -267,7 +363,15 @@ impl Strategy for RpcStrategy {
             })?,
         };
 
+        self.endpoint = match &config.transport {
+            TransportConfig::Grpc { endpoint, .. } => endpoint.clone(),
+        };
+        self.connected_once = false;
         self.heartbeat_interval = Duration::from_millis(config.heartbeat_interval_ms.max(1));
+        self.fallback = config.fallback;
+        self.max_transport_failures = config.max_transport_failures.max(1);
+        self.transport_failures = 0;
+        self.fallback_engaged = false;
         self.config = Some(config.clone());
         self.teardown_client();
         self.pending_signals.clear();
@@ -298,12 +402,15 @@ impl Strategy for RpcStrategy {
             Ok(client) => client,
             Err(e) => {
                 warn!(target: "rpc", "Skipping OnTick, client unavailable: {}", e);
+                self.record_transport_failure();
                 return Ok(());
             }
         };
         let mut transport = client.lock().await;
+        let started = Instant::now();
         let result = transport.on_tick(request).await;
         drop(transport);
+        metrics::global().observe_latency(&self.endpoint, "OnTick", started.elapsed());
         match result {
             Ok(response) => self.handle_signals(response.signals),
             Err(e) => self.handle_rpc_error(e, "OnTick"),
@@ -324,12 +431,15 @@ impl Strategy for RpcStrategy {
             Ok(client) => client,
             Err(e) => {
                 warn!(target: "rpc", "Skipping OnCandle, client unavailable: {}", e);
+                self.record_transport_failure();
                 return Ok(());
             }
         };
         let mut transport = client.lock().await;
+        let started = Instant::now();
         let result = transport.on_candle(request).await;
         drop(transport);
+        metrics::global().observe_latency(&self.endpoint, "OnCandle", started.elapsed());
         match result {
             Ok(response) => self.handle_signals(response.signals),
             Err(e) => self.handle_rpc_error(e, "OnCandle"),
@@ -347,12 +457,15 @@ impl Strategy for RpcStrategy {
             Ok(client) => client,
             Err(e) => {
                 warn!(target: "rpc", "Skipping OnFill, client unavailable: {}", e);
+                self.record_transport_failure();
                 return Ok(());
             }
         };
         let mut transport = client.lock().await;
+        let started = Instant::now();
         let result = transport.on_fill(request).await;
         drop(transport);
+        metrics::global().observe_latency(&self.endpoint, "OnFill", started.elapsed());
         match result {
             Ok(response) => self.handle_signals(response.signals),
             Err(e) => self.handle_rpc_error(e, "OnFill"),
@@ -377,12 +490,15 @@ impl Strategy for RpcStrategy {
             Ok(client) => client,
             Err(e) => {
                 warn!(target: "rpc", "Skipping OnOrderBook, client unavailable: {}", e);
+                self.record_transport_failure();
                 return Ok(());
             }
         };
         let mut transport = client.lock().await;
+        let started = Instant::now();
         let result = transport.on_order_book(request).await;
         drop(transport);
+        metrics::global().observe_latency(&self.endpoint, "OnOrderBook", started.elapsed());
         match result {
             Ok(response) => self.handle_signals(response.signals),
             Err(e) => self.handle_rpc_error(e, "OnOrderBook"),
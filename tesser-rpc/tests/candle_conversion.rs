@@ -0,0 +1,26 @@
+use chrono::Utc;
+use rust_decimal::Decimal;
+use tesser_core::{Candle, CandleStatus, Interval, Symbol};
+use tesser_rpc::proto;
+
+fn candle(status: CandleStatus) -> Candle {
+    Candle {
+        symbol: Symbol::from("BTCUSDT"),
+        interval: Interval::OneMinute,
+        open: Decimal::from(100),
+        high: Decimal::from(105),
+        low: Decimal::from(95),
+        close: Decimal::from(102),
+        volume: Decimal::ONE,
+        timestamp: Utc::now(),
+        status,
+    }
+}
+
+#[test]
+fn candle_conversion_carries_status() {
+    let forming: proto::Candle = candle(CandleStatus::Forming).into();
+    assert_eq!(forming.status(), proto::CandleStatus::Forming);
+    let closed: proto::Candle = candle(CandleStatus::Closed).into();
+    assert_eq!(closed.status(), proto::CandleStatus::Closed);
+}
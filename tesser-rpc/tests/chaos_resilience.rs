@@ -5,7 +5,7 @@ use std::time::Duration;
 
 use chrono::Utc;
 use rust_decimal::Decimal;
-use tesser_core::{Side, Symbol, Tick};
+use tesser_core::{Side, SignalKind, Symbol, Tick};
 use tesser_rpc::proto::strategy_service_server::{StrategyService, StrategyServiceServer};
 use tesser_rpc::proto::{
     self, CandleRequest, FillRequest, HeartbeatRequest, HeartbeatResponse, InitRequest,
@@ -257,3 +257,59 @@ async fn filters_symbols_locally() {
     assert_eq!(signals.len(), 1);
     assert_eq!(controller.init_counter.load(Ordering::SeqCst), 1);
 }
+
+async fn unreachable_endpoint() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    drop(listener);
+    format!("http://{addr}")
+}
+
+#[tokio::test]
+async fn flatten_fallback_emits_flatten_for_each_subscription() {
+    let mut strategy = RpcStrategy::default();
+    let config_toml = format!(
+        "transport = \"grpc\"\nendpoint = \"{}\"\ntimeout_ms = 200\nsymbols = [\"BTC-USD\", \"ETH-USD\"]\nfallback = \"flatten\"\nmax_transport_failures = 2\n",
+        unreachable_endpoint().await
+    );
+    let config: toml::Value = config_toml.parse().unwrap();
+    strategy.configure(config).unwrap();
+
+    let ctx = StrategyContext::default();
+
+    strategy.on_tick(&ctx, &build_tick()).await.unwrap();
+    assert!(strategy.drain_signals().is_empty());
+
+    strategy.on_tick(&ctx, &build_tick()).await.unwrap();
+    let signals = strategy.drain_signals();
+    let mut symbols: Vec<_> = signals
+        .iter()
+        .map(|signal| {
+            assert_eq!(signal.kind, SignalKind::Flatten);
+            signal.symbol.code().to_string()
+        })
+        .collect();
+    symbols.sort();
+    assert_eq!(symbols, vec!["BTC-USD", "ETH-USD"]);
+
+    // The fallback fires once per outage rather than on every failed call.
+    strategy.on_tick(&ctx, &build_tick()).await.unwrap();
+    assert!(strategy.drain_signals().is_empty());
+}
+
+#[tokio::test]
+async fn hold_fallback_emits_nothing_while_remote_is_down() {
+    let mut strategy = RpcStrategy::default();
+    let config_toml = format!(
+        "transport = \"grpc\"\nendpoint = \"{}\"\ntimeout_ms = 200\nmax_transport_failures = 1\n",
+        unreachable_endpoint().await
+    );
+    let config: toml::Value = config_toml.parse().unwrap();
+    strategy.configure(config).unwrap();
+
+    let ctx = StrategyContext::default();
+    for _ in 0..3 {
+        strategy.on_tick(&ctx, &build_tick()).await.unwrap();
+    }
+    assert!(strategy.drain_signals().is_empty());
+}
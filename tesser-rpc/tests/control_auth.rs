@@ -0,0 +1,52 @@
+use tesser_rpc::auth::{BearerAuth, BearerToken, AUTHORIZATION_HEADER};
+use tonic::service::Interceptor;
+use tonic::{Code, Request};
+
+fn check(auth: &mut BearerAuth, header: Option<&str>) -> Result<(), Code> {
+    let mut request = Request::new(());
+    if let Some(value) = header {
+        request
+            .metadata_mut()
+            .insert(AUTHORIZATION_HEADER, value.parse().unwrap());
+    }
+    auth.call(request)
+        .map(|_| ())
+        .map_err(|status| status.code())
+}
+
+#[test]
+fn rejects_missing_or_wrong_token() {
+    let mut auth = BearerAuth::new(Some("s3cret")).unwrap();
+    assert_eq!(check(&mut auth, None), Err(Code::Unauthenticated));
+    assert_eq!(
+        check(&mut auth, Some("Bearer wrong")),
+        Err(Code::Unauthenticated)
+    );
+    assert_eq!(check(&mut auth, Some("s3cret")), Err(Code::Unauthenticated));
+    assert_eq!(
+        check(&mut auth, Some("Bearer s3cret!")),
+        Err(Code::Unauthenticated)
+    );
+}
+
+#[test]
+fn accepts_token_attached_by_client() {
+    let mut auth = BearerAuth::new(Some("s3cret")).unwrap();
+    let mut client = BearerToken::new(Some("s3cret")).unwrap();
+    let request = client.call(Request::new(())).unwrap();
+    assert!(auth.call(request).is_ok());
+}
+
+#[test]
+fn disabled_auth_accepts_everything() {
+    let mut auth = BearerAuth::new(None).unwrap();
+    assert!(!auth.is_enabled());
+    assert_eq!(check(&mut auth, None), Ok(()));
+}
+
+#[test]
+fn empty_tokens_are_rejected() {
+    assert!(BearerAuth::new(Some("")).is_err());
+    assert!(BearerAuth::new(Some("  ")).is_err());
+    assert!(BearerToken::new(Some("")).is_err());
+}
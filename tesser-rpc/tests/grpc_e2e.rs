@@ -4,7 +4,7 @@ use chrono::Utc;
 use rust_decimal::prelude::FromPrimitive;
 use rust_decimal::Decimal;
 use tesser_core::{
-    Candle, Fill, Interval, OrderBook, OrderBookLevel, Side, SignalKind, Symbol, Tick,
+    Candle, CandleStatus, Fill, Interval, OrderBook, OrderBookLevel, Side, SignalKind, Symbol, Tick,
 };
 use tesser_rpc::proto::strategy_service_server::{StrategyService, StrategyServiceServer};
 use tesser_rpc::proto::{
@@ -142,6 +142,7 @@ fn build_candle() -> Candle {
         close: Decimal::from(50_050),
         volume: Decimal::from(1000),
         timestamp: Utc::now(),
+        status: CandleStatus::Closed,
     }
 }
 
@@ -0,0 +1,148 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use chrono::Utc;
+use rust_decimal::Decimal;
+use tesser_core::{Side, Symbol, Tick};
+use tesser_rpc::client::RemoteStrategyClient;
+use tesser_rpc::proto::{
+    self, CandleRequest, FillRequest, HeartbeatResponse, InitRequest, InitResponse,
+    OrderBookRequest, Signal, SignalList, TickRequest,
+};
+use tesser_rpc::{metrics, RpcStrategy};
+use tesser_strategy::{Strategy, StrategyContext};
+
+/// In-process client whose tick calls fail while `failing` is set.
+struct MockClient {
+    failing: Arc<AtomicBool>,
+}
+
+#[async_trait]
+impl RemoteStrategyClient for MockClient {
+    async fn connect(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    async fn initialize(&mut self, _req: InitRequest) -> Result<InitResponse> {
+        Ok(InitResponse {
+            symbols: vec!["BTC-USD".to_string()],
+            success: true,
+            error_message: String::new(),
+        })
+    }
+
+    async fn on_tick(&mut self, _req: TickRequest) -> Result<SignalList> {
+        if self.failing.load(Ordering::SeqCst) {
+            return Err(anyhow!("mock transport failure"));
+        }
+        Ok(SignalList {
+            signals: vec![enter_long()],
+        })
+    }
+
+    async fn on_candle(&mut self, _req: CandleRequest) -> Result<SignalList> {
+        Ok(SignalList::default())
+    }
+
+    async fn on_order_book(&mut self, _req: OrderBookRequest) -> Result<SignalList> {
+        Ok(SignalList::default())
+    }
+
+    async fn on_fill(&mut self, _req: FillRequest) -> Result<SignalList> {
+        Ok(SignalList::default())
+    }
+
+    async fn heartbeat(&mut self) -> Result<HeartbeatResponse> {
+        Ok(HeartbeatResponse {
+            healthy: true,
+            status_msg: "ok".to_string(),
+        })
+    }
+}
+
+fn enter_long() -> Signal {
+    Signal {
+        symbol: "BTC-USD".to_string(),
+        kind: proto::signal::Kind::EnterLong as i32,
+        confidence: 1.0,
+        stop_loss: None,
+        take_profit: None,
+        execution_hint: None,
+        note: String::new(),
+        id: "mock-signal".to_string(),
+        generated_at: Some(prost_types::Timestamp {
+            seconds: 0,
+            nanos: 0,
+        }),
+        metadata: String::new(),
+        quantity: None,
+        group_id: String::new(),
+    }
+}
+
+fn build_tick() -> Tick {
+    Tick {
+        symbol: Symbol::from("BTC-USD"),
+        price: Decimal::from(100),
+        size: Decimal::ONE,
+        side: Side::Buy,
+        exchange_timestamp: Utc::now(),
+        received_at: Utc::now(),
+    }
+}
+
+fn mock_strategy(endpoint: &str, failing: Arc<AtomicBool>) -> RpcStrategy {
+    let mut strategy = RpcStrategy::default().with_transport(move || {
+        Box::new(MockClient {
+            failing: failing.clone(),
+        }) as Box<dyn RemoteStrategyClient>
+    });
+    let config: toml::Value = format!("transport = \"grpc\"\nendpoint = \"{endpoint}\"\n")
+        .parse()
+        .unwrap();
+    strategy.configure(config).unwrap();
+    strategy
+}
+
+#[tokio::test]
+async fn tick_calls_record_latency_and_signals() {
+    let endpoint = "http://metrics-latency.test:50051";
+    let mut strategy = mock_strategy(endpoint, Arc::new(AtomicBool::new(false)));
+    let ctx = StrategyContext::default();
+
+    for _ in 0..3 {
+        strategy.on_tick(&ctx, &build_tick()).await.unwrap();
+    }
+
+    let metrics = metrics::global();
+    assert_eq!(metrics.latency_samples(endpoint, "OnTick"), 3);
+    assert_eq!(metrics.latency_samples(endpoint, "Initialize"), 1);
+    assert_eq!(metrics.signals_received(endpoint), 3);
+    assert_eq!(metrics.errors(endpoint, "OnTick"), 0);
+    assert_eq!(metrics.reconnects(endpoint), 0);
+    assert_eq!(strategy.drain_signals().len(), 3);
+}
+
+#[tokio::test]
+async fn failed_calls_count_errors_and_reconnects() {
+    let endpoint = "http://metrics-errors.test:50051";
+    let failing = Arc::new(AtomicBool::new(false));
+    let mut strategy = mock_strategy(endpoint, failing.clone());
+    let ctx = StrategyContext::default();
+
+    strategy.on_tick(&ctx, &build_tick()).await.unwrap();
+    failing.store(true, Ordering::SeqCst);
+    strategy.on_tick(&ctx, &build_tick()).await.unwrap();
+    strategy.on_tick(&ctx, &build_tick()).await.unwrap();
+    failing.store(false, Ordering::SeqCst);
+    strategy.on_tick(&ctx, &build_tick()).await.unwrap();
+
+    let metrics = metrics::global();
+    assert_eq!(metrics.errors(endpoint, "OnTick"), 2);
+    assert_eq!(metrics.latency_samples(endpoint, "OnTick"), 4);
+    // Each failure drops the client, so the two calls that follow one reconnect.
+    assert_eq!(metrics.reconnects(endpoint), 2);
+    assert_eq!(metrics.signals_received(endpoint), 2);
+}
@@ -89,6 +89,24 @@ pub fn register_strategy(input: TokenStream) -> TokenStream {
                     &[ #( #alias_literals ),* ]
                 }
 
+                fn description(&self) -> &'static str {
+                    static DESCRIPTION: ::std::sync::OnceLock<::std::string::String> =
+                        ::std::sync::OnceLock::new();
+                    DESCRIPTION.get_or_init(|| {
+                        let strategy = <#ty as ::core::default::Default>::default();
+                        ::tesser_strategy::Strategy::description(&strategy).to_string()
+                    })
+                }
+
+                fn tags(&self) -> &'static [&'static str] {
+                    static TAGS: ::std::sync::OnceLock<&'static [&'static str]> =
+                        ::std::sync::OnceLock::new();
+                    TAGS.get_or_init(|| {
+                        let strategy = <#ty as ::core::default::Default>::default();
+                        ::tesser_strategy::Strategy::tags(&strategy)
+                    })
+                }
+
                 fn build(&self, params: ::toml::Value) -> ::tesser_strategy::StrategyResult<Box<dyn ::tesser_strategy::Strategy>> {
                     let mut strategy = Box::new(<#ty as ::core::default::Default>::default());
                     strategy.configure(params)?;
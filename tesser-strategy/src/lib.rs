@@ -20,12 +20,14 @@ use std::fs;
 use std::path::PathBuf;
 use std::sync::{Arc, RwLock};
 use tesser_core::{
-    Candle, ExecutionHint, ExitStrategy, Fill, OrderBook, Position, Quantity, Signal, SignalKind,
-    Symbol, Tick,
+    Candle, ExecutionHint, ExitStrategy, Fill, OrderBook, Position, Quantity, Side, Signal,
+    SignalKind, Symbol, Tick,
 };
 use tesser_cortex::{CortexConfig, CortexDevice, CortexEngine, FeatureBuffer};
 use tesser_indicators::{
-    indicators::{Atr, BollingerBands, Ichimoku, IchimokuOutput, Macd, Rsi, Sma},
+    indicators::{
+        Atr, BollingerBands, Ema, Ichimoku, IchimokuOutput, Macd, RollingQuantile, Rsi, Sma,
+    },
     Indicator,
 };
 use tesser_markets::MarketRegistry;
@@ -49,6 +51,29 @@ pub enum StrategyError {
     Internal(String),
 }
 
+impl StrategyError {
+    /// Whether the runtime should skip the event and keep feeding the strategy.
+    ///
+    /// Only [`StrategyError::NotEnoughData`] is transient; configuration and internal
+    /// errors are escalated to the caller.
+    pub fn is_transient(&self) -> bool {
+        matches!(self, Self::NotEnoughData)
+    }
+}
+
+/// Whether the runtime is replaying history or processing live market data.
+///
+/// During [`ContextMode::Replay`] strategies should keep updating indicators but must not
+/// emit signals; the runtime switches to [`ContextMode::Live`] once it has caught up.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum ContextMode {
+    /// Historical data is being fed to warm up the strategy.
+    Replay,
+    /// Events reflect the current market.
+    #[default]
+    Live,
+}
+
 /// Immutable view of recent market data and portfolio state shared with strategies.
 pub struct StrategyContext {
     recent_candles: VecDeque<Candle>,
@@ -61,6 +86,7 @@ pub struct StrategyContext {
     position_index: HashMap<Symbol, Position>,
     max_history: usize,
     market_registry: Option<Arc<MarketRegistry>>,
+    mode: ContextMode,
 }
 
 impl StrategyContext {
@@ -78,9 +104,30 @@ impl StrategyContext {
             position_index: HashMap::new(),
             max_history: capacity,
             market_registry: None,
+            mode: ContextMode::default(),
         }
     }
 
+    /// Maximum number of events retained per buffer.
+    pub fn max_history(&self) -> usize {
+        self.max_history
+    }
+
+    /// Grow the history limit to at least `required`; never shrinks retained data.
+    pub fn ensure_history(&mut self, required: usize) {
+        self.max_history = self.max_history.max(required);
+    }
+
+    /// Current replay/live mode; see [`ContextMode`] for the contract strategies follow.
+    pub fn mode(&self) -> ContextMode {
+        self.mode
+    }
+
+    /// Switch between history replay and live processing.
+    pub fn set_mode(&mut self, mode: ContextMode) {
+        self.mode = mode;
+    }
+
     fn push_with_capacity<T>(buffer: &mut VecDeque<T>, item: T, limit: usize) {
         if buffer.len() >= limit {
             buffer.pop_front();
@@ -89,7 +136,12 @@ impl StrategyContext {
     }
 
     /// Push a candle while respecting the configured history size.
+    ///
+    /// Only closed candles are recorded; forming updates of the current bar are ignored.
     pub fn push_candle(&mut self, candle: Candle) {
+        if !candle.is_closed() {
+            return;
+        }
         let symbol = candle.symbol;
         Self::push_with_capacity(&mut self.recent_candles, candle.clone(), self.max_history);
         let entry = self
@@ -183,6 +235,58 @@ impl StrategyContext {
         self.position_index.get(&symbol)
     }
 
+    /// Unrealized PnL of the symbol's position marked at the latest observed price.
+    ///
+    /// Returns `None` when the position is flat, has no entry price, or no tick or candle has
+    /// been seen for the symbol yet.
+    #[must_use]
+    pub fn unrealized_pnl(&self, symbol: impl Into<Symbol>) -> Option<Decimal> {
+        let symbol = symbol.into();
+        let (move_per_unit, position) = self.position_move(symbol)?;
+        Some(move_per_unit * position.quantity)
+    }
+
+    /// Fractional return of the symbol's position since entry, e.g. `0.05` for +5%.
+    ///
+    /// Shorts profit from falling prices, so their return is positive when the mark is below
+    /// the entry. Returns `None` under the same conditions as [`Self::unrealized_pnl`].
+    #[must_use]
+    pub fn position_return(&self, symbol: impl Into<Symbol>) -> Option<Decimal> {
+        let symbol = symbol.into();
+        let (move_per_unit, position) = self.position_move(symbol)?;
+        let entry = position.entry_price?;
+        (!entry.is_zero()).then(|| move_per_unit / entry)
+    }
+
+    /// Per-unit price move in the position's favour, paired with the position itself.
+    fn position_move(&self, symbol: Symbol) -> Option<(Decimal, &Position)> {
+        let position = self.position_index.get(&symbol)?;
+        if position.quantity.is_zero() {
+            return None;
+        }
+        let entry = position.entry_price?;
+        let mark = self.last_price(symbol)?;
+        let delta = match position.side? {
+            Side::Buy => mark - entry,
+            Side::Sell => entry - mark,
+        };
+        Some((delta, position))
+    }
+
+    /// Most recent tick price for the symbol, falling back to the latest candle close.
+    fn last_price(&self, symbol: Symbol) -> Option<Decimal> {
+        self.tick_index
+            .get(&symbol)
+            .and_then(|ticks| ticks.back())
+            .map(|tick| tick.price)
+            .or_else(|| {
+                self.candle_index
+                    .get(&symbol)
+                    .and_then(|candles| candles.back())
+                    .map(|candle| candle.close)
+            })
+    }
+
     /// Returns the latest order book snapshot for the specified symbol.
     #[must_use]
     pub fn order_book(&self, symbol: impl Into<Symbol>) -> Option<&OrderBook> {
@@ -192,6 +296,38 @@ impl StrategyContext {
             .and_then(|books| books.back())
     }
 
+    /// Price and size of the `rank`-th level (0 = best) on `side` across the last `lookback`
+    /// order book snapshots for the symbol, oldest first.
+    ///
+    /// Snapshots too shallow to contain the level are skipped, so the result may hold fewer
+    /// than `lookback` entries.
+    #[must_use]
+    pub fn level_history(
+        &self,
+        symbol: impl Into<Symbol>,
+        side: Side,
+        rank: usize,
+        lookback: usize,
+    ) -> Vec<(DateTime<Utc>, Decimal, Decimal)> {
+        let symbol = symbol.into();
+        let Some(books) = self.order_book_index.get(&symbol) else {
+            return Vec::new();
+        };
+        books
+            .iter()
+            .skip(books.len().saturating_sub(lookback))
+            .filter_map(|book| {
+                let levels = match side {
+                    Side::Buy => &book.bids,
+                    Side::Sell => &book.asks,
+                };
+                levels
+                    .get(rank)
+                    .map(|level| (book.timestamp, level.price, level.size))
+            })
+            .collect()
+    }
+
     /// Attach a market registry so strategies can query instrument metadata.
     pub fn attach_market_registry(&mut self, registry: Arc<MarketRegistry>) {
         self.market_registry = Some(registry);
@@ -209,6 +345,42 @@ impl StrategyContext {
             .as_ref()
             .and_then(|registry| registry.normalize_pair_quantity(first, second, quantity))
     }
+
+    /// Place a protective stop `atr_mult` ATRs away from `entry_price` in the adverse direction.
+    ///
+    /// The ATR uses [`DEFAULT_ATR_STOP_PERIOD`] over the symbol's candle history and returns
+    /// `None` until enough candles have been observed.
+    #[must_use]
+    pub fn atr_stop(
+        &self,
+        symbol: impl Into<Symbol>,
+        entry_price: Decimal,
+        side: Side,
+        atr_mult: Decimal,
+    ) -> Option<Decimal> {
+        let candles = self.candles_for(symbol)?;
+        let mut atr = Atr::new(DEFAULT_ATR_STOP_PERIOD).ok()?;
+        let value = candles
+            .iter()
+            .filter_map(|candle| atr.next(candle.clone()))
+            .last()?;
+        let distance = value * atr_mult;
+        Some(match side {
+            Side::Buy => entry_price - distance,
+            Side::Sell => entry_price + distance,
+        })
+    }
+}
+
+/// Lookback used by [`StrategyContext::atr_stop`].
+pub const DEFAULT_ATR_STOP_PERIOD: usize = 14;
+
+/// Multiple of the longest indicator period built-in strategies request as context history.
+pub const HISTORY_PERIOD_MULTIPLE: usize = 2;
+
+/// Context history needed to recompute indicators with the given periods.
+fn history_for_periods(periods: &[usize]) -> usize {
+    periods.iter().copied().max().unwrap_or(0) * HISTORY_PERIOD_MULTIPLE
 }
 
 impl Default for StrategyContext {
@@ -217,12 +389,77 @@ impl Default for StrategyContext {
     }
 }
 
+/// Default number of candles and ticks retained by [`ContextSnapshot::from`].
+pub const DEFAULT_CONTEXT_SNAPSHOT_CAP: usize = 64;
+
+/// Serializable view of a [`StrategyContext`] used for RPC payloads and debug dumps.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ContextSnapshot {
+    /// Most recent candles across all symbols, oldest first.
+    pub candles: Vec<Candle>,
+    /// Most recent ticks across all symbols, oldest first.
+    pub ticks: Vec<Tick>,
+    /// Positions tracked by the context at capture time.
+    pub positions: Vec<Position>,
+}
+
+impl ContextSnapshot {
+    /// Capture at most `cap` of the latest candles and ticks from `ctx`.
+    #[must_use]
+    pub fn capture(ctx: &StrategyContext, cap: usize) -> Self {
+        fn tail<T: Clone>(buffer: &VecDeque<T>, cap: usize) -> Vec<T> {
+            buffer
+                .iter()
+                .skip(buffer.len().saturating_sub(cap))
+                .cloned()
+                .collect()
+        }
+        Self {
+            candles: tail(ctx.candles(), cap),
+            ticks: tail(ctx.ticks(), cap),
+            positions: ctx.positions().clone(),
+        }
+    }
+
+    /// Render the snapshot as pretty-printed JSON.
+    pub fn to_json_pretty(&self) -> StrategyResult<String> {
+        serde_json::to_string_pretty(self).map_err(|err| StrategyError::Internal(err.to_string()))
+    }
+}
+
+impl From<&StrategyContext> for ContextSnapshot {
+    fn from(ctx: &StrategyContext) -> Self {
+        Self::capture(ctx, DEFAULT_CONTEXT_SNAPSHOT_CAP)
+    }
+}
+
+/// How much internal state [`Strategy::reconfigure`] kept when applying new parameters.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReconfigureOutcome {
+    /// The new parameters matched the active configuration.
+    Unchanged,
+    /// Only thresholds or other non-structural parameters changed; indicators kept their warmup.
+    Preserved,
+    /// A structural parameter changed, so indicators and counters were rebuilt from scratch.
+    Rebuilt,
+}
+
 /// Strategy lifecycle hooks used by engines that drive market data and fills.
 #[async_trait]
 pub trait Strategy: Send + Sync + Any {
     /// Human-friendly identifier used in logs and telemetry.
     fn name(&self) -> &str;
 
+    /// One-line summary of what the strategy trades on, shown by tooling (defaults to empty).
+    fn description(&self) -> &str {
+        ""
+    }
+
+    /// Free-form labels such as `"mean-reversion"` or `"order-book"` (defaults to none).
+    fn tags(&self) -> &'static [&'static str] {
+        &[]
+    }
+
     /// The primary symbol operated on by the strategy.
     fn symbol(&self) -> Symbol;
 
@@ -231,9 +468,38 @@ pub trait Strategy: Send + Sync + Any {
         vec![self.symbol()]
     }
 
+    /// Number of historical candles per subscription replayed before live trading (defaults to none).
+    fn warmup_candles(&self) -> usize {
+        0
+    }
+
+    /// Minimum events per symbol the [`StrategyContext`] must retain (defaults to the warmup).
+    ///
+    /// Runtimes grow the context to at least this size, so strategies reading history through
+    /// the context never see it truncated by a smaller configured `history`.
+    fn required_history(&self) -> usize {
+        self.warmup_candles()
+    }
+
     /// Called once before the strategy is registered, allowing it to parse parameters.
     fn configure(&mut self, params: toml::Value) -> StrategyResult<()>;
 
+    /// Configure from JSON parameters (RPC/plugin paths) by converting them to TOML first.
+    fn configure_json(&mut self, params: serde_json::Value) -> StrategyResult<()> {
+        self.configure(json_to_toml(params)?)
+    }
+
+    /// Apply new parameters to a running strategy, rebuilding only the state they affect.
+    ///
+    /// The default implementation calls [`Strategy::configure`] and reports a full rebuild.
+    /// Every indicator-driven built-in overrides it. `MlClassifier` (which reads its history from
+    /// the context) and `LstmCortex` (whose parameters all shape the model or its feature window)
+    /// keep the default, since a rebuild loses no warmup they could have kept.
+    fn reconfigure(&mut self, params: toml::Value) -> StrategyResult<ReconfigureOutcome> {
+        self.configure(params)?;
+        Ok(ReconfigureOutcome::Rebuilt)
+    }
+
     /// Called whenever the data pipeline emits a new tick.
     async fn on_tick(&mut self, ctx: &StrategyContext, tick: &Tick) -> StrategyResult<()>;
 
@@ -266,6 +532,50 @@ pub trait Strategy: Send + Sync + Any {
     }
 }
 
+/// Convert JSON parameters into the TOML representation strategies parse.
+///
+/// Integers stay integers and other numbers become floats. TOML has no null, so null
+/// table entries are dropped (leaving serde defaults in charge); nulls elsewhere are rejected.
+pub fn json_to_toml(value: serde_json::Value) -> StrategyResult<toml::Value> {
+    use serde_json::Value as Json;
+    Ok(match value {
+        Json::Null => {
+            return Err(StrategyError::InvalidConfig(
+                "null is not representable in strategy parameters".into(),
+            ))
+        }
+        Json::Bool(flag) => toml::Value::Boolean(flag),
+        Json::Number(number) => {
+            if let Some(int) = number.as_i64() {
+                toml::Value::Integer(int)
+            } else if number.is_u64() {
+                return Err(StrategyError::InvalidConfig(format!(
+                    "integer {number} does not fit in a signed 64-bit value"
+                )));
+            } else {
+                toml::Value::Float(number.as_f64().unwrap_or_default())
+            }
+        }
+        Json::String(text) => toml::Value::String(text),
+        Json::Array(items) => toml::Value::Array(
+            items
+                .into_iter()
+                .map(json_to_toml)
+                .collect::<StrategyResult<_>>()?,
+        ),
+        Json::Object(entries) => {
+            let mut table = toml::map::Map::new();
+            for (key, value) in entries {
+                if value.is_null() {
+                    continue;
+                }
+                table.insert(key, json_to_toml(value)?);
+            }
+            toml::Value::Table(table)
+        }
+    })
+}
+
 // -------------------------------------------------------------------------------------------------
 // Strategy registry
 // -------------------------------------------------------------------------------------------------
@@ -292,6 +602,72 @@ pub fn builtin_strategy_names() -> Vec<&'static str> {
     strategy_registry().names()
 }
 
+/// Returns name, description, aliases and tags for every registered strategy, sorted by name.
+pub fn builtin_strategy_descriptors() -> Vec<StrategyDescriptor> {
+    strategy_registry().descriptors()
+}
+
+/// Dry-runs every strategy declared in a config file without starting it.
+///
+/// The file may hold a single top-level `strategy_name` with its `[params]` table, or
+/// several `[[strategies]]` blocks of the same shape. Each block is built through the
+/// registry, which runs `configure`, and reported under its strategy name (or its block
+/// position when the name is missing). A file that is not valid TOML yields a single
+/// `"config"` entry.
+pub fn validate_config_file(toml: &str) -> Vec<(String, StrategyResult<()>)> {
+    let document: Value = match toml.parse() {
+        Ok(document) => document,
+        Err(err) => {
+            return vec![(
+                "config".to_string(),
+                Err(StrategyError::InvalidConfig(err.to_string())),
+            )]
+        }
+    };
+    let blocks = match document.get("strategies") {
+        Some(Value::Array(blocks)) => blocks.iter().collect(),
+        Some(_) => {
+            return vec![(
+                "strategies".to_string(),
+                Err(StrategyError::InvalidConfig(
+                    "`strategies` must be an array of tables".into(),
+                )),
+            )]
+        }
+        None => vec![&document],
+    };
+    blocks
+        .into_iter()
+        .enumerate()
+        .map(|(idx, block)| validate_strategy_block(idx, block))
+        .collect()
+}
+
+fn validate_strategy_block(idx: usize, block: &Value) -> (String, StrategyResult<()>) {
+    let Some(name) = block.get("strategy_name").and_then(Value::as_str) else {
+        return (
+            format!("strategies[{idx}]"),
+            Err(StrategyError::InvalidConfig(
+                "missing `strategy_name`".into(),
+            )),
+        );
+    };
+    let params = block
+        .get("params")
+        .cloned()
+        .unwrap_or_else(|| Value::Table(Default::default()));
+    (name.to_string(), load_strategy(name, params).map(|_| ()))
+}
+
+/// Introspection data for a registered strategy.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StrategyDescriptor {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub aliases: &'static [&'static str],
+    pub tags: &'static [&'static str],
+}
+
 /// Factory contract used to construct strategies from configuration.
 pub trait StrategyFactory: Send + Sync {
     /// Canonical, user-facing identifier for the strategy (e.g. "SmaCross").
@@ -302,6 +678,16 @@ pub trait StrategyFactory: Send + Sync {
         &[]
     }
 
+    /// Description of the strategies this factory builds (see [`Strategy::description`]).
+    fn description(&self) -> &'static str {
+        ""
+    }
+
+    /// Tags of the strategies this factory builds (see [`Strategy::tags`]).
+    fn tags(&self) -> &'static [&'static str] {
+        &[]
+    }
+
     /// Builds and configures a strategy instance with the provided parameters.
     fn build(&self, params: Value) -> StrategyResult<Box<dyn Strategy>>;
 }
@@ -327,23 +713,82 @@ impl StrategyRegistry {
 
     fn register(&self, factory: Arc<dyn StrategyFactory>) {
         let mut inner = self.inner.write().expect("registry poisoned");
-        let canonical = factory.canonical_name();
-        if inner
-            .by_canonical
-            .insert(canonical, factory.clone())
-            .is_some()
-        {
+        if self.insert_factory(&mut inner, factory.clone()).is_some() {
             tracing::warn!(
-                strategy = canonical,
+                strategy = factory.canonical_name(),
                 "duplicate strategy registration detected; overriding previous factory"
             );
         }
+    }
+
+    /// Removes the strategy resolved by `name` (canonical or alias) along with all of its aliases.
+    pub fn unregister(&self, name: &str) -> Option<Arc<dyn StrategyFactory>> {
+        let mut inner = self.inner.write().expect("registry poisoned");
+        let factory = inner.by_alias.get(&normalize_name(name)).cloned()?;
+        inner.by_canonical.remove(factory.canonical_name());
+        inner
+            .by_alias
+            .retain(|_, existing| !Arc::ptr_eq(existing, &factory));
+        Some(factory)
+    }
+
+    /// Registers `factory` until the returned guard is dropped, then restores the prior entries.
+    #[must_use = "the override is reverted as soon as the guard is dropped"]
+    pub fn with_override(&self, factory: Arc<dyn StrategyFactory>) -> StrategyOverrideGuard<'_> {
+        let mut inner = self.inner.write().expect("registry poisoned");
+        let canonical = factory.canonical_name();
+        let previous = inner.by_canonical.get(canonical).cloned();
+        let mut keys: Vec<String> = std::iter::once(canonical)
+            .chain(factory.aliases().iter().copied())
+            .map(normalize_name)
+            .collect();
+        if let Some(previous) = &previous {
+            keys.extend(
+                inner
+                    .by_alias
+                    .iter()
+                    .filter(|(_, existing)| Arc::ptr_eq(existing, previous))
+                    .map(|(key, _)| key.clone()),
+            );
+        }
+        keys.sort_unstable();
+        keys.dedup();
+        let aliases = keys
+            .into_iter()
+            .map(|key| {
+                let existing = inner.by_alias.get(&key).cloned();
+                (key, existing)
+            })
+            .collect();
+        self.insert_factory(&mut inner, factory);
+        StrategyOverrideGuard {
+            registry: self,
+            canonical,
+            previous,
+            aliases,
+        }
+    }
+
+    /// Inserts `factory`, dropping aliases left over from the factory it replaces.
+    fn insert_factory(
+        &self,
+        inner: &mut RegistryInner,
+        factory: Arc<dyn StrategyFactory>,
+    ) -> Option<Arc<dyn StrategyFactory>> {
+        let canonical = factory.canonical_name();
+        let previous = inner.by_canonical.insert(canonical, factory.clone());
+        if let Some(previous) = &previous {
+            inner
+                .by_alias
+                .retain(|_, existing| !Arc::ptr_eq(existing, previous));
+        }
         inner
             .by_alias
             .insert(normalize_name(canonical), factory.clone());
         for alias in factory.aliases() {
             self.insert_alias(&mut inner.by_alias, alias, factory.clone(), canonical);
         }
+        previous
     }
 
     fn insert_alias(
@@ -384,6 +829,22 @@ impl StrategyRegistry {
         names.sort_unstable();
         names
     }
+
+    fn descriptors(&self) -> Vec<StrategyDescriptor> {
+        let inner = self.inner.read().expect("registry poisoned");
+        let mut descriptors: Vec<StrategyDescriptor> = inner
+            .by_canonical
+            .values()
+            .map(|factory| StrategyDescriptor {
+                name: factory.canonical_name(),
+                description: factory.description(),
+                aliases: factory.aliases(),
+                tags: factory.tags(),
+            })
+            .collect();
+        descriptors.sort_unstable_by_key(|descriptor| descriptor.name);
+        descriptors
+    }
 }
 
 impl Default for StrategyRegistry {
@@ -392,6 +853,32 @@ impl Default for StrategyRegistry {
     }
 }
 
+/// Scoped override returned by [`StrategyRegistry::with_override`].
+pub struct StrategyOverrideGuard<'a> {
+    registry: &'a StrategyRegistry,
+    canonical: &'static str,
+    previous: Option<Arc<dyn StrategyFactory>>,
+    aliases: Vec<(String, Option<Arc<dyn StrategyFactory>>)>,
+}
+
+impl Drop for StrategyOverrideGuard<'_> {
+    fn drop(&mut self) {
+        let Ok(mut inner) = self.registry.inner.write() else {
+            return;
+        };
+        match self.previous.take() {
+            Some(previous) => inner.by_canonical.insert(self.canonical, previous),
+            None => inner.by_canonical.remove(self.canonical),
+        };
+        for (key, factory) in self.aliases.drain(..) {
+            match factory {
+                Some(factory) => inner.by_alias.insert(key, factory),
+                None => inner.by_alias.remove(&key),
+            };
+        }
+    }
+}
+
 fn normalize_name(name: &str) -> String {
     name.trim().to_ascii_lowercase()
 }
@@ -431,12 +918,35 @@ fn z_score(values: &[Decimal]) -> Option<Decimal> {
     Some((last - mean) / std)
 }
 
+/// OLS slope of `ys` regressed on `xs`; `None` when `xs` has no variance or the slope is not
+/// positive, since a negative hedge would flip the direction of the second leg.
+fn hedge_ratio(ys: &[Decimal], xs: &[Decimal]) -> Option<Decimal> {
+    if ys.len() != xs.len() || xs.len() < 2 {
+        return None;
+    }
+    let len = Decimal::from_usize(xs.len())?;
+    let mean_x = xs.iter().copied().sum::<Decimal>() / len;
+    let mean_y = ys.iter().copied().sum::<Decimal>() / len;
+    let (covariance, variance) =
+        xs.iter()
+            .zip(ys.iter())
+            .fold((Decimal::ZERO, Decimal::ZERO), |(cov, var), (x, y)| {
+                let dx = *x - mean_x;
+                (cov + dx * (*y - mean_y), var + dx * dx)
+            });
+    if variance.is_zero() {
+        return None;
+    }
+    let beta = covariance / variance;
+    (beta > Decimal::ZERO).then_some(beta)
+}
+
 // -------------------------------------------------------------------------------------------------
 // Baseline Strategies
 // -------------------------------------------------------------------------------------------------
 
 /// Double moving-average crossover strategy.
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(default)]
 pub struct SmaCrossConfig {
     pub symbol: Symbol,
@@ -445,6 +955,7 @@ pub struct SmaCrossConfig {
     pub min_samples: usize,
     pub vwap_duration_secs: Option<i64>,
     pub vwap_participation: Option<Decimal>,
+    pub stop_atr_multiplier: Decimal,
 }
 
 impl Default for SmaCrossConfig {
@@ -456,6 +967,7 @@ impl Default for SmaCrossConfig {
             min_samples: 25,
             vwap_duration_secs: None,
             vwap_participation: None,
+            stop_atr_multiplier: Decimal::from(2),
         }
     }
 }
@@ -507,6 +1019,16 @@ impl SmaCross {
         }
     }
 
+    fn parse_config(params: toml::Value) -> StrategyResult<SmaCrossConfig> {
+        let cfg = SmaCrossConfig::try_from(params)?;
+        if cfg.fast_period == 0 || cfg.slow_period == 0 {
+            return Err(StrategyError::InvalidConfig(
+                "period values must be greater than zero".into(),
+            ));
+        }
+        Ok(cfg)
+    }
+
     fn rebuild_indicators(&mut self) -> StrategyResult<()> {
         self.fast_ma = Sma::new(self.cfg.fast_period)
             .map_err(|err| StrategyError::InvalidConfig(err.to_string()))?;
@@ -520,7 +1042,11 @@ impl SmaCross {
         Ok(())
     }
 
-    fn maybe_emit_signal(&mut self, candle: &Candle) -> StrategyResult<()> {
+    fn maybe_emit_signal(&mut self, ctx: &StrategyContext, candle: &Candle) -> StrategyResult<()> {
+        // A forming bar would feed the averages a price that may still repaint.
+        if !candle.is_closed() {
+            return Ok(());
+        }
         if let Some(value) = self.fast_ma.next(candle.close) {
             self.fast_prev = self.fast_last.replace(value);
         }
@@ -528,7 +1054,7 @@ impl SmaCross {
             self.slow_prev = self.slow_last.replace(value);
         }
         self.samples += 1;
-        if self.samples < self.cfg.min_samples {
+        if self.samples < self.cfg.min_samples || ctx.mode() == ContextMode::Replay {
             return Ok(());
         }
         if let (Some(fast_prev), Some(fast_curr), Some(slow_prev), Some(slow_curr)) = (
@@ -539,8 +1065,16 @@ impl SmaCross {
         ) {
             if fast_prev <= slow_prev && fast_curr > slow_curr {
                 let mut signal = Signal::new(self.cfg.symbol, SignalKind::EnterLong, 0.75);
-                let stop_loss_factor = Decimal::new(98, 2); // 0.98
-                signal.stop_loss = Some(candle.low * stop_loss_factor);
+                // Fall back to a fixed 2% below the low until the ATR has warmed up.
+                let stop_loss = ctx
+                    .atr_stop(
+                        self.cfg.symbol,
+                        candle.close,
+                        Side::Buy,
+                        self.cfg.stop_atr_multiplier,
+                    )
+                    .unwrap_or_else(|| candle.low * Decimal::new(98, 2));
+                signal.stop_loss = Some(stop_loss);
                 if let Some(duration_secs) = self.cfg.vwap_duration_secs.filter(|v| *v > 0) {
                     let duration = Duration::seconds(duration_secs);
                     let participation = self
@@ -569,30 +1103,61 @@ impl Strategy for SmaCross {
         "sma-cross"
     }
 
+    fn description(&self) -> &str {
+        "Enters long when the fast SMA crosses above the slow SMA and exits on the reverse cross."
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["trend-following", "candles"]
+    }
+
     fn symbol(&self) -> Symbol {
         self.cfg.symbol
     }
 
+    fn warmup_candles(&self) -> usize {
+        self.cfg.slow_period.max(self.cfg.min_samples)
+    }
+
+    fn required_history(&self) -> usize {
+        history_for_periods(&[
+            self.cfg.fast_period,
+            self.cfg.slow_period,
+            DEFAULT_ATR_STOP_PERIOD + 1,
+        ])
+        .max(self.cfg.min_samples)
+    }
+
     fn configure(&mut self, params: toml::Value) -> StrategyResult<()> {
-        let cfg = SmaCrossConfig::try_from(params)?;
-        if cfg.fast_period == 0 || cfg.slow_period == 0 {
-            return Err(StrategyError::InvalidConfig(
-                "period values must be greater than zero".into(),
-            ));
+        self.cfg = Self::parse_config(params)?;
+        self.rebuild_indicators()
+    }
+
+    fn reconfigure(&mut self, params: toml::Value) -> StrategyResult<ReconfigureOutcome> {
+        let cfg = Self::parse_config(params)?;
+        if cfg == self.cfg {
+            return Ok(ReconfigureOutcome::Unchanged);
         }
+        let structural = cfg.symbol != self.cfg.symbol
+            || cfg.fast_period != self.cfg.fast_period
+            || cfg.slow_period != self.cfg.slow_period;
         self.cfg = cfg;
-        self.rebuild_indicators()
+        if structural {
+            self.rebuild_indicators()?;
+            return Ok(ReconfigureOutcome::Rebuilt);
+        }
+        Ok(ReconfigureOutcome::Preserved)
     }
 
     async fn on_tick(&mut self, _ctx: &StrategyContext, _tick: &Tick) -> StrategyResult<()> {
         Ok(())
     }
 
-    async fn on_candle(&mut self, _ctx: &StrategyContext, candle: &Candle) -> StrategyResult<()> {
+    async fn on_candle(&mut self, ctx: &StrategyContext, candle: &Candle) -> StrategyResult<()> {
         if candle.symbol != self.cfg.symbol {
             return Ok(());
         }
-        self.maybe_emit_signal(candle)
+        self.maybe_emit_signal(ctx, candle)
     }
 
     async fn on_fill(&mut self, _ctx: &StrategyContext, _fill: &Fill) -> StrategyResult<()> {
@@ -660,6 +1225,18 @@ impl RsiReversion {
         }
     }
 
+    fn parse_config(params: toml::Value) -> StrategyResult<RsiReversionConfig> {
+        let cfg: RsiReversionConfig = params.try_into().map_err(|err: toml::de::Error| {
+            StrategyError::InvalidConfig(format!("failed to parse RsiReversion config: {err}"))
+        })?;
+        if cfg.period == 0 {
+            return Err(StrategyError::InvalidConfig(
+                "period must be greater than zero".into(),
+            ));
+        }
+        Ok(cfg)
+    }
+
     fn rebuild_indicator(&mut self) -> StrategyResult<()> {
         self.rsi = Rsi::new(self.cfg.period)
             .map_err(|err| StrategyError::InvalidConfig(err.to_string()))?;
@@ -694,23 +1271,47 @@ impl Strategy for RsiReversion {
         "rsi-reversion"
     }
 
+    fn description(&self) -> &str {
+        "Buys when RSI falls to the oversold level and exits once it reaches overbought."
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["mean-reversion", "candles"]
+    }
+
     fn symbol(&self) -> Symbol {
         self.cfg.symbol
     }
 
+    fn required_history(&self) -> usize {
+        history_for_periods(&[self.cfg.period]).max(self.cfg.lookback)
+    }
+
     fn configure(&mut self, params: toml::Value) -> StrategyResult<()> {
-        let cfg: RsiReversionConfig = params.try_into().map_err(|err: toml::de::Error| {
-            StrategyError::InvalidConfig(format!("failed to parse RsiReversion config: {err}"))
-        })?;
-        if cfg.period == 0 {
-            return Err(StrategyError::InvalidConfig(
-                "period must be greater than zero".into(),
-            ));
-        }
-        self.cfg = cfg;
+        self.cfg = Self::parse_config(params)?;
         self.rebuild_indicator()
     }
 
+    fn reconfigure(&mut self, params: toml::Value) -> StrategyResult<ReconfigureOutcome> {
+        let cfg = Self::parse_config(params)?;
+        let structural = cfg.symbol != self.cfg.symbol || cfg.period != self.cfg.period;
+        let unchanged = !structural
+            && cfg.oversold == self.cfg.oversold
+            && cfg.overbought == self.cfg.overbought
+            && cfg.lookback == self.cfg.lookback;
+        self.cfg = cfg;
+        if structural {
+            self.rebuild_indicator()?;
+            return Ok(ReconfigureOutcome::Rebuilt);
+        }
+        if unchanged {
+            return Ok(ReconfigureOutcome::Unchanged);
+        }
+        self.oversold_level = self.cfg.oversold;
+        self.overbought_level = self.cfg.overbought;
+        Ok(ReconfigureOutcome::Preserved)
+    }
+
     async fn on_tick(&mut self, _ctx: &StrategyContext, _tick: &Tick) -> StrategyResult<()> {
         Ok(())
     }
@@ -733,62 +1334,245 @@ impl Strategy for RsiReversion {
 
 register_strategy!(RsiReversion, "RsiReversion");
 
-/// Bollinger band breakout strategy.
-#[derive(Debug, Clone, Deserialize, Serialize)]
+/// RSI mean reversion with thresholds taken from the recent RSI distribution.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(default)]
-pub struct BollingerBreakoutConfig {
+pub struct AdaptiveRsiConfig {
     pub symbol: Symbol,
     pub period: usize,
-    pub std_multiplier: Decimal,
+    /// Lower tail used as the oversold level; the overbought level is `1 - quantile`.
+    pub quantile: Decimal,
+    /// Number of recent RSI values the quantiles are computed over.
     pub lookback: usize,
 }
 
-impl Default for BollingerBreakoutConfig {
+impl Default for AdaptiveRsiConfig {
     fn default() -> Self {
         Self {
             symbol: "BTCUSDT".into(),
-            period: 20,
-            std_multiplier: Decimal::from(2),
-            lookback: 200,
+            period: 14,
+            quantile: Decimal::new(1, 1),
+            lookback: 100,
         }
     }
 }
 
-pub struct BollingerBreakout {
-    cfg: BollingerBreakoutConfig,
+/// Variant of [`RsiReversion`] whose oversold/overbought levels are the `q`/`1 - q` rolling
+/// quantiles of its own RSI, so it adapts to instruments that trend in a high or low RSI regime.
+///
+/// Each RSI value is compared against the quantiles of the values before it and must fall
+/// strictly outside them, so a flat RSI history never triggers.
+pub struct AdaptiveRsiReversion {
+    cfg: AdaptiveRsiConfig,
     signals: Vec<Signal>,
-    bands: BollingerBands,
-    std_multiplier: Decimal,
-    neutral_band: Decimal,
-    samples: usize,
+    rsi: Rsi,
+    oversold: RollingQuantile,
+    overbought: RollingQuantile,
 }
 
-impl Default for BollingerBreakout {
+impl Default for AdaptiveRsiReversion {
     fn default() -> Self {
-        Self::new(BollingerBreakoutConfig::default())
+        Self::from_config(AdaptiveRsiConfig::default()).expect("default config is valid")
     }
 }
 
-impl BollingerBreakout {
-    /// Instantiate the strategy with the provided configuration.
-    pub fn new(cfg: BollingerBreakoutConfig) -> Self {
-        let std_multiplier = cfg.std_multiplier;
-        let neutral_band = std_multiplier * Decimal::new(25, 2); // 0.25
-        let bands = BollingerBands::new(cfg.period, std_multiplier)
-            .expect("period and multiplier must be valid");
-        Self {
+impl AdaptiveRsiReversion {
+    /// Instantiate the strategy, validating the period, quantile and lookback.
+    pub fn from_config(cfg: AdaptiveRsiConfig) -> StrategyResult<Self> {
+        if cfg.quantile >= Decimal::new(5, 1) {
+            return Err(StrategyError::InvalidConfig(
+                "quantile must be below 0.5".into(),
+            ));
+        }
+        let invalid =
+            |err: tesser_indicators::IndicatorError| StrategyError::InvalidConfig(err.to_string());
+        let rsi = Rsi::new(cfg.period).map_err(invalid)?;
+        let oversold = RollingQuantile::new(cfg.lookback, cfg.quantile).map_err(invalid)?;
+        let overbought =
+            RollingQuantile::new(cfg.lookback, Decimal::ONE - cfg.quantile).map_err(invalid)?;
+        Ok(Self {
             cfg,
             signals: Vec::new(),
-            bands,
-            std_multiplier,
-            neutral_band,
-            samples: 0,
-        }
+            rsi,
+            oversold,
+            overbought,
+        })
+    }
+
+    fn parse_config(params: toml::Value) -> StrategyResult<AdaptiveRsiConfig> {
+        params.try_into().map_err(|err: toml::de::Error| {
+            StrategyError::InvalidConfig(format!(
+                "failed to parse AdaptiveRsiReversion config: {err}"
+            ))
+        })
+    }
+
+    fn maybe_emit_signal(&mut self, ctx: &StrategyContext, candle: &Candle) {
+        let Some(rsi_value) = self.rsi.next(candle.close) else {
+            return;
+        };
+        let levels = self.oversold.current().zip(self.overbought.current());
+        self.oversold.next(rsi_value);
+        self.overbought.next(rsi_value);
+        let Some((oversold, overbought)) = levels else {
+            return;
+        };
+        if ctx.mode() == ContextMode::Replay {
+            return;
+        }
+        if rsi_value < oversold {
+            self.signals
+                .push(Signal::new(self.cfg.symbol, SignalKind::EnterLong, 0.8));
+        } else if rsi_value > overbought {
+            self.signals
+                .push(Signal::new(self.cfg.symbol, SignalKind::ExitLong, 0.8));
+        }
+    }
+}
+
+#[async_trait]
+impl Strategy for AdaptiveRsiReversion {
+    fn name(&self) -> &str {
+        "adaptive-rsi-reversion"
+    }
+
+    fn description(&self) -> &str {
+        "Buys when RSI drops below its rolling lower quantile and exits above the upper one."
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["mean-reversion", "candles"]
+    }
+
+    fn symbol(&self) -> Symbol {
+        self.cfg.symbol
+    }
+
+    fn warmup_candles(&self) -> usize {
+        self.cfg.period + self.cfg.lookback
+    }
+
+    fn configure(&mut self, params: toml::Value) -> StrategyResult<()> {
+        *self = Self::from_config(Self::parse_config(params)?)?;
+        Ok(())
+    }
+
+    fn reconfigure(&mut self, params: toml::Value) -> StrategyResult<ReconfigureOutcome> {
+        let cfg = Self::parse_config(params)?;
+        if cfg == self.cfg {
+            return Ok(ReconfigureOutcome::Unchanged);
+        }
+        let rebuilt = Self::from_config(cfg)?;
+        if rebuilt.cfg.symbol != self.cfg.symbol || rebuilt.cfg.period != self.cfg.period {
+            *self = rebuilt;
+            return Ok(ReconfigureOutcome::Rebuilt);
+        }
+        // Every parameter shapes the quantile windows, but the RSI itself can stay warm.
+        self.cfg = rebuilt.cfg;
+        self.oversold = rebuilt.oversold;
+        self.overbought = rebuilt.overbought;
+        Ok(ReconfigureOutcome::Rebuilt)
+    }
+
+    async fn on_tick(&mut self, _ctx: &StrategyContext, _tick: &Tick) -> StrategyResult<()> {
+        Ok(())
+    }
+
+    async fn on_candle(&mut self, ctx: &StrategyContext, candle: &Candle) -> StrategyResult<()> {
+        if candle.symbol == self.cfg.symbol {
+            self.maybe_emit_signal(ctx, candle);
+        }
+        Ok(())
+    }
+
+    async fn on_fill(&mut self, _ctx: &StrategyContext, _fill: &Fill) -> StrategyResult<()> {
+        Ok(())
+    }
+
+    fn drain_signals(&mut self) -> Vec<Signal> {
+        std::mem::take(&mut self.signals)
+    }
+}
+
+register_strategy!(AdaptiveRsiReversion, "AdaptiveRsiReversion");
+
+/// Bollinger band breakout strategy.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct BollingerBreakoutConfig {
+    pub symbol: Symbol,
+    pub period: usize,
+    pub std_multiplier: Decimal,
+    pub lookback: usize,
+}
+
+impl Default for BollingerBreakoutConfig {
+    fn default() -> Self {
+        Self {
+            symbol: "BTCUSDT".into(),
+            period: 20,
+            std_multiplier: Decimal::from(2),
+            lookback: 200,
+        }
+    }
+}
+
+pub struct BollingerBreakout {
+    cfg: BollingerBreakoutConfig,
+    signals: Vec<Signal>,
+    bands: BollingerBands,
+    std_multiplier: Decimal,
+    neutral_band: Decimal,
+    samples: usize,
+}
+
+impl Default for BollingerBreakout {
+    fn default() -> Self {
+        Self::new(BollingerBreakoutConfig::default())
+    }
+}
+
+impl BollingerBreakout {
+    /// Instantiate the strategy with the provided configuration.
+    pub fn new(cfg: BollingerBreakoutConfig) -> Self {
+        let std_multiplier = cfg.std_multiplier;
+        let neutral_band = Self::neutral_percent_b_band(std_multiplier);
+        let bands = BollingerBands::new(cfg.period, std_multiplier)
+            .expect("period and multiplier must be valid");
+        Self {
+            cfg,
+            signals: Vec::new(),
+            bands,
+            std_multiplier,
+            neutral_band,
+            samples: 0,
+        }
+    }
+
+    /// Half-width of the %B neutral zone, equivalent to a quarter standard deviation
+    /// around the middle band.
+    fn neutral_percent_b_band(std_multiplier: Decimal) -> Decimal {
+        if std_multiplier.is_zero() {
+            return Decimal::ZERO;
+        }
+        Decimal::new(125, 3) / std_multiplier // 0.25 / (2 * k)
+    }
+
+    fn parse_config(params: toml::Value) -> StrategyResult<BollingerBreakoutConfig> {
+        let cfg: BollingerBreakoutConfig = params.try_into().map_err(|err: toml::de::Error| {
+            StrategyError::InvalidConfig(format!("failed to parse BollingerBreakout config: {err}"))
+        })?;
+        if cfg.period == 0 {
+            return Err(StrategyError::InvalidConfig(
+                "period must be greater than zero".into(),
+            ));
+        }
+        Ok(cfg)
     }
 
     fn rebuild_indicator(&mut self) -> StrategyResult<()> {
         self.std_multiplier = self.cfg.std_multiplier;
-        self.neutral_band = self.std_multiplier * Decimal::new(25, 2);
+        self.neutral_band = Self::neutral_percent_b_band(self.std_multiplier);
         self.bands = BollingerBands::new(self.cfg.period, self.std_multiplier)
             .map_err(|err| StrategyError::InvalidConfig(err.to_string()))?;
         self.samples = 0;
@@ -814,7 +1598,10 @@ impl BollingerBreakout {
         } else if price < bands.lower {
             self.signals
                 .push(Signal::new(self.cfg.symbol, SignalKind::EnterShort, 0.7));
-        } else if (price - bands.middle).abs() <= self.neutral_band {
+        } else if bands
+            .percent_b(price)
+            .is_some_and(|percent_b| (percent_b - Decimal::new(5, 1)).abs() <= self.neutral_band)
+        {
             self.signals
                 .push(Signal::new(self.cfg.symbol, SignalKind::Flatten, 0.6));
         }
@@ -828,21 +1615,41 @@ impl Strategy for BollingerBreakout {
         "bollinger-breakout"
     }
 
+    fn description(&self) -> &str {
+        "Trades closes outside the Bollinger Bands in the breakout direction and flattens near the middle band."
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["breakout", "volatility", "candles"]
+    }
+
     fn symbol(&self) -> Symbol {
         self.cfg.symbol
     }
 
+    fn required_history(&self) -> usize {
+        history_for_periods(&[self.cfg.period]).max(self.cfg.lookback)
+    }
+
     fn configure(&mut self, params: toml::Value) -> StrategyResult<()> {
-        let cfg: BollingerBreakoutConfig = params.try_into().map_err(|err: toml::de::Error| {
-            StrategyError::InvalidConfig(format!("failed to parse BollingerBreakout config: {err}"))
-        })?;
-        if cfg.period == 0 {
-            return Err(StrategyError::InvalidConfig(
-                "period must be greater than zero".into(),
-            ));
+        self.cfg = Self::parse_config(params)?;
+        self.rebuild_indicator()
+    }
+
+    fn reconfigure(&mut self, params: toml::Value) -> StrategyResult<ReconfigureOutcome> {
+        let cfg = Self::parse_config(params)?;
+        if cfg == self.cfg {
+            return Ok(ReconfigureOutcome::Unchanged);
         }
+        let structural = cfg.symbol != self.cfg.symbol
+            || cfg.period != self.cfg.period
+            || cfg.std_multiplier != self.cfg.std_multiplier;
         self.cfg = cfg;
-        self.rebuild_indicator()
+        if structural {
+            self.rebuild_indicator()?;
+            return Ok(ReconfigureOutcome::Rebuilt);
+        }
+        Ok(ReconfigureOutcome::Preserved)
     }
 
     async fn on_tick(&mut self, _ctx: &StrategyContext, _tick: &Tick) -> StrategyResult<()> {
@@ -945,10 +1752,22 @@ impl Strategy for MlClassifier {
         "ml-classifier"
     }
 
+    fn description(&self) -> &str {
+        "Scores each candle with a classifier model and trades its long/short predictions."
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["machine-learning", "candles"]
+    }
+
     fn symbol(&self) -> Symbol {
         self.cfg.symbol
     }
 
+    fn required_history(&self) -> usize {
+        self.cfg.lookback + 1
+    }
+
     fn configure(&mut self, params: toml::Value) -> StrategyResult<()> {
         let cfg: MlClassifierConfig = params.try_into().map_err(|err: toml::de::Error| {
             StrategyError::InvalidConfig(format!("failed to parse MlClassifier config: {err}"))
@@ -1104,10 +1923,22 @@ impl Strategy for LstmCortex {
         "lstm-cortex"
     }
 
+    fn description(&self) -> &str {
+        "Runs an ONNX LSTM over a rolling OHLCV window and trades its directional forecast."
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["machine-learning", "candles"]
+    }
+
     fn symbol(&self) -> Symbol {
         self.cfg.symbol
     }
 
+    fn required_history(&self) -> usize {
+        history_for_periods(&[self.cfg.window])
+    }
+
     fn configure(&mut self, params: toml::Value) -> StrategyResult<()> {
         let cfg: LstmCortexConfig = params.try_into().map_err(|err: toml::de::Error| {
             StrategyError::InvalidConfig(format!("failed to parse LstmCortex config: {err}"))
@@ -1180,7 +2011,7 @@ impl Strategy for LstmCortex {
 register_strategy!(LstmCortex, "LstmCortex");
 
 /// Statistical arbitrage pairs-trading strategy.
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(default)]
 pub struct PairsTradingConfig {
     pub symbols: [Symbol; 2],
@@ -1222,11 +2053,14 @@ struct ManagedPairTrade {
     entry_z_score: Decimal,
     exit_strategy: ExitStrategy,
     clip: Option<Decimal>,
+    /// Quantity of the second leg, scaled by the hedge ratio at entry.
+    #[serde(default)]
+    hedge_clip: Option<Decimal>,
     symbols: [Symbol; 2],
 }
 
 impl ManagedPairTrade {
-    fn snapshot(&self, candles_held: u64) -> PairTradeSnapshot {
+    fn snapshot(&self, candles_held: u64, beta: Decimal) -> PairTradeSnapshot {
         PairTradeSnapshot {
             trade_id: self.id,
             direction: self.direction,
@@ -1235,6 +2069,7 @@ impl ManagedPairTrade {
             exit_strategy: self.exit_strategy.clone(),
             candles_held,
             symbols: self.symbols,
+            beta,
         }
     }
 }
@@ -1243,6 +2078,12 @@ impl ManagedPairTrade {
 struct PairsTradingState {
     trades: Vec<ManagedPairTrade>,
     candle_counter: u64,
+    #[serde(default = "unit_hedge_ratio")]
+    beta: Decimal,
+}
+
+fn unit_hedge_ratio() -> Decimal {
+    Decimal::ONE
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -1254,6 +2095,8 @@ pub struct PairTradeSnapshot {
     pub exit_strategy: ExitStrategy,
     pub candles_held: u64,
     pub symbols: [Symbol; 2],
+    /// Current hedge ratio of the first leg against the second.
+    pub beta: Decimal,
 }
 
 impl Default for PairsTradingConfig {
@@ -1263,7 +2106,7 @@ impl Default for PairsTradingConfig {
             lookback: 200,
             entry_z: Decimal::from(2),
             exit_z: Decimal::new(5, 1),
-            clip_size: Decimal::ZERO,
+            clip_size: Decimal::ONE,
             default_exit_strategy: None,
         }
     }
@@ -1276,6 +2119,7 @@ pub struct PairsTradingArbitrage {
     default_exit_strategy: ExitStrategy,
     active_trades: HashMap<Uuid, ManagedPairTrade>,
     candle_counter: u64,
+    beta: Decimal,
 }
 
 impl Default for PairsTradingArbitrage {
@@ -1288,6 +2132,7 @@ impl Default for PairsTradingArbitrage {
 impl PairsTradingArbitrage {
     fn from_config(cfg: PairsTradingConfig) -> StrategyResult<Self> {
         Self::validate_symbols(&cfg)?;
+        Self::validate_clip(&cfg)?;
         let mut strategy = Self {
             cfg,
             signals: Vec::new(),
@@ -1297,11 +2142,38 @@ impl PairsTradingArbitrage {
             },
             active_trades: HashMap::new(),
             candle_counter: 0,
+            beta: Decimal::ONE,
         };
         strategy.rebuild_thresholds()?;
         Ok(strategy)
     }
 
+    fn parse_config(params: toml::Value) -> StrategyResult<PairsTradingConfig> {
+        let cfg: PairsTradingConfig = params.try_into().map_err(|err: toml::de::Error| {
+            StrategyError::InvalidConfig(format!(
+                "failed to parse PairsTradingArbitrage config: {err}"
+            ))
+        })?;
+        if cfg.lookback < 2 {
+            return Err(StrategyError::InvalidConfig(
+                "lookback must be at least 2".into(),
+            ));
+        }
+        Self::validate_symbols(&cfg)?;
+        Self::validate_clip(&cfg)?;
+        Ok(cfg)
+    }
+
+    fn validate_clip(cfg: &PairsTradingConfig) -> StrategyResult<()> {
+        if cfg.clip_size <= Decimal::ZERO {
+            return Err(StrategyError::InvalidConfig(
+                "`clip_size` must be positive so the second leg can be sized by the hedge ratio"
+                    .into(),
+            ));
+        }
+        Ok(())
+    }
+
     fn validate_symbols(cfg: &PairsTradingConfig) -> StrategyResult<()> {
         if cfg.symbols[0] == cfg.symbols[1] {
             return Err(StrategyError::InvalidConfig(
@@ -1374,28 +2246,40 @@ impl PairsTradingArbitrage {
         Ok(())
     }
 
-    fn spreads(&self, ctx: &StrategyContext) -> Option<Vec<Decimal>> {
+    /// Returns the `ln(a) - beta * ln(b)` spread series and the rolling OLS hedge ratio.
+    fn spreads(&self, ctx: &StrategyContext) -> Option<(Vec<Decimal>, Decimal)> {
         let closes_a = collect_symbol_closes(ctx, self.cfg.symbols[0], self.cfg.lookback);
         let closes_b = collect_symbol_closes(ctx, self.cfg.symbols[1], self.cfg.lookback);
         if closes_a.len() < self.cfg.lookback || closes_b.len() < self.cfg.lookback {
             return None;
         }
-        let mut spreads = Vec::with_capacity(self.cfg.lookback);
+        let mut log_a = Vec::with_capacity(self.cfg.lookback);
+        let mut log_b = Vec::with_capacity(self.cfg.lookback);
         for (a, b) in closes_a.iter().zip(closes_b.iter()) {
-            if b.is_zero() {
+            if *a <= Decimal::ZERO || *b <= Decimal::ZERO {
                 return None;
             }
-            spreads.push((*a / *b).ln());
+            log_a.push(a.ln());
+            log_b.push(b.ln());
         }
-        Some(spreads)
+        // Keep the previous estimate when the window cannot support a regression.
+        let beta = hedge_ratio(&log_a, &log_b).unwrap_or(self.beta);
+        let spreads = log_a
+            .iter()
+            .zip(log_b.iter())
+            .map(|(a, b)| *a - beta * *b)
+            .collect();
+        Some((spreads, beta))
     }
 
-    fn manual_clip(&self, ctx: &StrategyContext) -> Option<Decimal> {
-        if self.cfg.clip_size <= Decimal::ZERO {
-            return None;
-        }
+    /// Latest hedge ratio estimated over the lookback window.
+    pub fn hedge_ratio(&self) -> Decimal {
+        self.beta
+    }
+
+    fn manual_clip(&self, ctx: &StrategyContext) -> Decimal {
         ctx.normalize_pair_quantity(self.cfg.symbols[0], self.cfg.symbols[1], self.cfg.clip_size)
-            .or(Some(self.cfg.clip_size))
+            .unwrap_or(self.cfg.clip_size)
     }
 
     fn push_signal_with_clip(
@@ -1467,14 +2351,39 @@ impl PairsTradingArbitrage {
         for trade_id in completed {
             if let Some(trade) = self.active_trades.remove(&trade_id) {
                 let group_id = Some(trade.id);
-                let clip = trade.clip.or_else(|| self.manual_clip(ctx));
+                let clip = trade.clip.or_else(|| Some(self.manual_clip(ctx)));
+                let hedge_clip = trade.hedge_clip.or(clip);
                 let (first_kind, second_kind) = trade.direction.exit_kinds();
                 self.push_signal_with_clip(self.cfg.symbols[0], first_kind, 0.6, clip, group_id);
-                self.push_signal_with_clip(self.cfg.symbols[1], second_kind, 0.6, clip, group_id);
+                self.push_signal_with_clip(
+                    self.cfg.symbols[1],
+                    second_kind,
+                    0.6,
+                    hedge_clip,
+                    group_id,
+                );
             }
         }
     }
 
+    /// Size the second leg against `clip` units of the first.
+    ///
+    /// `beta` comes from a regression on log prices, so it relates returns rather than units:
+    /// the second leg carries `beta` times the first leg's notional, which is
+    /// `beta * clip * price_a / price_b` units.
+    fn hedge_quantity(&self, ctx: &StrategyContext, clip: Decimal) -> Option<Decimal> {
+        let [first, second] = self.cfg.symbols;
+        let price_a = ctx.last_price(first)?;
+        let price_b = ctx
+            .last_price(second)
+            .filter(|price| *price > Decimal::ZERO)?;
+        let scaled = self.beta * clip * price_a / price_b;
+        Some(
+            ctx.normalize_pair_quantity(first, second, scaled)
+                .unwrap_or(scaled),
+        )
+    }
+
     fn should_open_short(&self, z: Decimal) -> bool {
         self.active_trades.is_empty() && z >= self.entry_z_level
     }
@@ -1492,9 +2401,20 @@ impl PairsTradingArbitrage {
     ) {
         let group_id = Uuid::new_v4();
         let clip = self.manual_clip(ctx);
+        let Some(hedge) = self.hedge_quantity(ctx, clip) else {
+            tracing::warn!("no prices to size the hedge leg; skipping pair entry");
+            return;
+        };
+        let (clip, hedge_clip) = (Some(clip), Some(hedge));
         let (first_kind, second_kind) = direction.entry_kinds();
         self.push_signal_with_clip(self.cfg.symbols[0], first_kind, 0.8, clip, Some(group_id));
-        self.push_signal_with_clip(self.cfg.symbols[1], second_kind, 0.8, clip, Some(group_id));
+        self.push_signal_with_clip(
+            self.cfg.symbols[1],
+            second_kind,
+            0.8,
+            hedge_clip,
+            Some(group_id),
+        );
         let trade = ManagedPairTrade {
             id: group_id,
             direction,
@@ -1503,17 +2423,35 @@ impl PairsTradingArbitrage {
             entry_z_score: z,
             exit_strategy: self.default_exit_strategy.clone(),
             clip,
+            hedge_clip,
             symbols: self.cfg.symbols,
         };
         self.active_trades.insert(group_id, trade);
     }
 
+    fn process_candle(&mut self, ctx: &StrategyContext, now: DateTime<Utc>) {
+        self.candle_counter = self.candle_counter.saturating_add(1);
+        let Some((spreads, beta)) = self.spreads(ctx) else {
+            return;
+        };
+        self.beta = beta;
+        if let Some(z) = z_score(&spreads) {
+            tracing::info!(target: "strategy", %z, %beta, "pairs-trading z-score");
+            self.evaluate_trades(ctx, z, now);
+            if self.should_open_short(z) {
+                self.open_trade(ctx, z, now, PairTradeDirection::ShortFirst);
+            } else if self.should_open_long(z) {
+                self.open_trade(ctx, z, now, PairTradeDirection::LongFirst);
+            }
+        }
+    }
+
     pub fn managed_trades(&self) -> Vec<PairTradeSnapshot> {
         self.active_trades
             .values()
             .map(|trade| {
                 let candles_held = self.candle_counter.saturating_sub(trade.entry_candle_index);
-                trade.snapshot(candles_held)
+                trade.snapshot(candles_held, self.beta)
             })
             .collect()
     }
@@ -1537,6 +2475,14 @@ impl Strategy for PairsTradingArbitrage {
         "pairs-trading"
     }
 
+    fn description(&self) -> &str {
+        "Trades z-score deviations of a hedged two-symbol spread and exits as it reverts."
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["statistical-arbitrage", "mean-reversion", "multi-symbol"]
+    }
+
     fn symbol(&self) -> Symbol {
         self.cfg.symbols[0]
     }
@@ -1545,45 +2491,47 @@ impl Strategy for PairsTradingArbitrage {
         self.cfg.symbols.to_vec()
     }
 
+    fn required_history(&self) -> usize {
+        self.cfg.lookback
+    }
+
     fn configure(&mut self, params: toml::Value) -> StrategyResult<()> {
-        let cfg: PairsTradingConfig = params.try_into().map_err(|err: toml::de::Error| {
-            StrategyError::InvalidConfig(format!(
-                "failed to parse PairsTradingArbitrage config: {err}"
-            ))
-        })?;
-        if cfg.lookback < 2 {
-            return Err(StrategyError::InvalidConfig(
-                "lookback must be at least 2".into(),
-            ));
-        }
-        Self::validate_symbols(&cfg)?;
-        self.cfg = cfg;
+        self.cfg = Self::parse_config(params)?;
         self.rebuild_thresholds()?;
         self.active_trades.clear();
         self.candle_counter = 0;
+        self.beta = Decimal::ONE;
         Ok(())
     }
 
+    fn reconfigure(&mut self, params: toml::Value) -> StrategyResult<ReconfigureOutcome> {
+        let cfg = Self::parse_config(params)?;
+        if cfg == self.cfg {
+            return Ok(ReconfigureOutcome::Unchanged);
+        }
+        if cfg.symbols != self.cfg.symbols || cfg.lookback != self.cfg.lookback {
+            let mut rebuilt = Self::from_config(cfg)?;
+            rebuilt.signals = std::mem::take(&mut self.signals);
+            *self = rebuilt;
+            return Ok(ReconfigureOutcome::Rebuilt);
+        }
+        // Thresholds only: open trades and the hedge ratio carry over.
+        let previous = std::mem::replace(&mut self.cfg, cfg);
+        if let Err(err) = self.rebuild_thresholds() {
+            self.cfg = previous;
+            self.rebuild_thresholds()?;
+            return Err(err);
+        }
+        Ok(ReconfigureOutcome::Preserved)
+    }
+
     async fn on_tick(&mut self, _ctx: &StrategyContext, _tick: &Tick) -> StrategyResult<()> {
         Ok(())
     }
 
     async fn on_candle(&mut self, ctx: &StrategyContext, candle: &Candle) -> StrategyResult<()> {
-        if !self.cfg.symbols.contains(&candle.symbol) {
-            return Ok(());
-        }
-        self.candle_counter = self.candle_counter.saturating_add(1);
-        if let Some(spreads) = self.spreads(ctx) {
-            if let Some(z) = z_score(&spreads) {
-                tracing::info!(target: "strategy", %z, "pairs-trading z-score");
-                let now = candle.timestamp;
-                self.evaluate_trades(ctx, z, now);
-                if self.should_open_short(z) {
-                    self.open_trade(ctx, z, now, PairTradeDirection::ShortFirst);
-                } else if self.should_open_long(z) {
-                    self.open_trade(ctx, z, now, PairTradeDirection::LongFirst);
-                }
-            }
+        if self.cfg.symbols.contains(&candle.symbol) {
+            self.process_candle(ctx, candle.timestamp);
         }
         Ok(())
     }
@@ -1600,6 +2548,7 @@ impl Strategy for PairsTradingArbitrage {
         let state = PairsTradingState {
             trades: self.active_trades.values().cloned().collect(),
             candle_counter: self.candle_counter,
+            beta: self.beta,
         };
         serde_json::to_value(state).map_err(|err| {
             StrategyError::Internal(format!("failed to serialize pairs trading state: {err}"))
@@ -1619,6 +2568,7 @@ impl Strategy for PairsTradingArbitrage {
             .map(|trade| (trade.id, trade))
             .collect();
         self.candle_counter = restored.candle_counter;
+        self.beta = restored.beta;
         Ok(())
     }
 }
@@ -1630,7 +2580,7 @@ register_strategy!(
 );
 
 /// Order book imbalance strategy operating on depth snapshots.
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(default)]
 pub struct OrderBookImbalanceConfig {
     pub symbol: Symbol,
@@ -1638,6 +2588,8 @@ pub struct OrderBookImbalanceConfig {
     pub long_threshold: f64,
     pub short_threshold: f64,
     pub neutral_zone: f64,
+    /// EMA period applied to the imbalance series before thresholds are checked.
+    pub smoothing_period: Option<usize>,
 }
 
 impl Default for OrderBookImbalanceConfig {
@@ -1648,6 +2600,7 @@ impl Default for OrderBookImbalanceConfig {
             long_threshold: 0.2,
             short_threshold: -0.2,
             neutral_zone: 0.05,
+            smoothing_period: None,
         }
     }
 }
@@ -1656,19 +2609,11 @@ impl Default for OrderBookImbalanceConfig {
 pub struct OrderBookImbalance {
     cfg: OrderBookImbalanceConfig,
     signals: Vec<Signal>,
+    smoother: Option<Ema>,
 }
 
-#[async_trait]
-impl Strategy for OrderBookImbalance {
-    fn name(&self) -> &str {
-        "orderbook-imbalance"
-    }
-
-    fn symbol(&self) -> Symbol {
-        self.cfg.symbol
-    }
-
-    fn configure(&mut self, params: toml::Value) -> StrategyResult<()> {
+impl OrderBookImbalance {
+    fn parse_config(params: toml::Value) -> StrategyResult<OrderBookImbalanceConfig> {
         let cfg: OrderBookImbalanceConfig = params.try_into().map_err(|err: toml::de::Error| {
             StrategyError::InvalidConfig(format!(
                 "failed to parse OrderBookImbalance config: {err}"
@@ -1679,24 +2624,93 @@ impl Strategy for OrderBookImbalance {
                 "depth must be greater than zero".into(),
             ));
         }
-        self.cfg = cfg;
-        Ok(())
+        Ok(cfg)
     }
 
-    async fn on_tick(&mut self, _ctx: &StrategyContext, _tick: &Tick) -> StrategyResult<()> {
-        Ok(())
+    fn build_smoother(cfg: &OrderBookImbalanceConfig) -> StrategyResult<Option<Ema>> {
+        cfg.smoothing_period
+            .map(Ema::new)
+            .transpose()
+            .map_err(|err| StrategyError::InvalidConfig(err.to_string()))
     }
 
-    async fn on_candle(&mut self, _ctx: &StrategyContext, _candle: &Candle) -> StrategyResult<()> {
-        Ok(())
+    fn on_imbalance(&mut self, imbalance: Decimal) {
+        let imbalance = match self.smoother.as_mut() {
+            Some(ema) => match ema.next(imbalance) {
+                Some(smoothed) => smoothed,
+                None => return,
+            },
+            None => imbalance,
+        };
+        let Some(imbalance_f64) = imbalance.to_f64() else {
+            return;
+        };
+        if imbalance_f64 >= self.cfg.long_threshold {
+            self.signals
+                .push(Signal::new(self.cfg.symbol, SignalKind::EnterLong, 0.9));
+        } else if imbalance_f64 <= self.cfg.short_threshold {
+            self.signals
+                .push(Signal::new(self.cfg.symbol, SignalKind::EnterShort, 0.9));
+        } else if imbalance_f64.abs() <= self.cfg.neutral_zone {
+            self.signals
+                .push(Signal::new(self.cfg.symbol, SignalKind::Flatten, 0.6));
+        }
     }
+}
 
-    async fn on_fill(&mut self, _ctx: &StrategyContext, _fill: &Fill) -> StrategyResult<()> {
-        Ok(())
+#[async_trait]
+impl Strategy for OrderBookImbalance {
+    fn name(&self) -> &str {
+        "orderbook-imbalance"
     }
 
-    async fn on_order_book(
-        &mut self,
+    fn description(&self) -> &str {
+        "Trades bid/ask volume imbalance across the top levels of the order book."
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["order-book", "microstructure"]
+    }
+
+    fn symbol(&self) -> Symbol {
+        self.cfg.symbol
+    }
+
+    fn configure(&mut self, params: toml::Value) -> StrategyResult<()> {
+        let cfg = Self::parse_config(params)?;
+        self.smoother = Self::build_smoother(&cfg)?;
+        self.cfg = cfg;
+        Ok(())
+    }
+
+    fn reconfigure(&mut self, params: toml::Value) -> StrategyResult<ReconfigureOutcome> {
+        let cfg = Self::parse_config(params)?;
+        if cfg == self.cfg {
+            return Ok(ReconfigureOutcome::Unchanged);
+        }
+        if cfg.symbol != self.cfg.symbol || cfg.smoothing_period != self.cfg.smoothing_period {
+            self.smoother = Self::build_smoother(&cfg)?;
+            self.cfg = cfg;
+            return Ok(ReconfigureOutcome::Rebuilt);
+        }
+        self.cfg = cfg;
+        Ok(ReconfigureOutcome::Preserved)
+    }
+
+    async fn on_tick(&mut self, _ctx: &StrategyContext, _tick: &Tick) -> StrategyResult<()> {
+        Ok(())
+    }
+
+    async fn on_candle(&mut self, _ctx: &StrategyContext, _candle: &Candle) -> StrategyResult<()> {
+        Ok(())
+    }
+
+    async fn on_fill(&mut self, _ctx: &StrategyContext, _fill: &Fill) -> StrategyResult<()> {
+        Ok(())
+    }
+
+    async fn on_order_book(
+        &mut self,
         _ctx: &StrategyContext,
         book: &OrderBook,
     ) -> StrategyResult<()> {
@@ -1704,18 +2718,7 @@ impl Strategy for OrderBookImbalance {
             return Ok(());
         }
         if let Some(imbalance) = book.imbalance(self.cfg.depth) {
-            if let Some(imbalance_f64) = imbalance.to_f64() {
-                if imbalance_f64 >= self.cfg.long_threshold {
-                    self.signals
-                        .push(Signal::new(self.cfg.symbol, SignalKind::EnterLong, 0.9));
-                } else if imbalance_f64 <= self.cfg.short_threshold {
-                    self.signals
-                        .push(Signal::new(self.cfg.symbol, SignalKind::EnterShort, 0.9));
-                } else if imbalance_f64.abs() <= self.cfg.neutral_zone {
-                    self.signals
-                        .push(Signal::new(self.cfg.symbol, SignalKind::Flatten, 0.6));
-                }
-            }
+            self.on_imbalance(imbalance);
         }
         Ok(())
     }
@@ -1731,7 +2734,7 @@ register_strategy!(OrderBookImbalance, "OrderBookImbalance", aliases = ["OBI"]);
 // Advanced Strategies
 // -------------------------------------------------------------------------------------------------
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(default)]
 pub struct OrderBookScalperConfig {
     pub symbol: Symbol,
@@ -1792,6 +2795,26 @@ impl OrderBookScalper {
         }
     }
 
+    fn parse_config(params: toml::Value) -> StrategyResult<OrderBookScalperConfig> {
+        let cfg: OrderBookScalperConfig = params.try_into().map_err(|err: toml::de::Error| {
+            StrategyError::InvalidConfig(format!("failed to parse OrderBookScalper config: {err}"))
+        })?;
+        if cfg.depth == 0 {
+            return Err(StrategyError::InvalidConfig(
+                "depth must be greater than zero".into(),
+            ));
+        }
+        Ok(cfg)
+    }
+
+    fn rebuild_macd(&mut self, cfg: OrderBookScalperConfig) -> StrategyResult<()> {
+        self.macd = Macd::new(cfg.macd_fast, cfg.macd_slow, cfg.macd_signal)
+            .map_err(|err| StrategyError::InvalidConfig(err.to_string()))?;
+        self.cfg = cfg;
+        self.last_histogram = None;
+        Ok(())
+    }
+
     fn imbalance_supports_entry(&self, imbalance: f64, histogram: Decimal) -> SignalKind {
         if imbalance >= self.cfg.imbalance_threshold && histogram > Decimal::ZERO {
             SignalKind::EnterLong
@@ -1809,24 +2832,42 @@ impl Strategy for OrderBookScalper {
         "orderbook-scalper"
     }
 
+    fn description(&self) -> &str {
+        "Scalps order book imbalance confirmed by MACD momentum using pegged limit orders."
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["order-book", "microstructure", "scalping"]
+    }
+
     fn symbol(&self) -> Symbol {
         self.cfg.symbol
     }
 
+    fn required_history(&self) -> usize {
+        history_for_periods(&[self.cfg.macd_slow + self.cfg.macd_signal])
+    }
+
     fn configure(&mut self, params: toml::Value) -> StrategyResult<()> {
-        let cfg: OrderBookScalperConfig = params.try_into().map_err(|err: toml::de::Error| {
-            StrategyError::InvalidConfig(format!("failed to parse OrderBookScalper config: {err}"))
-        })?;
-        if cfg.depth == 0 {
-            return Err(StrategyError::InvalidConfig(
-                "depth must be greater than zero".into(),
-            ));
+        let cfg = Self::parse_config(params)?;
+        self.rebuild_macd(cfg)
+    }
+
+    fn reconfigure(&mut self, params: toml::Value) -> StrategyResult<ReconfigureOutcome> {
+        let cfg = Self::parse_config(params)?;
+        if cfg == self.cfg {
+            return Ok(ReconfigureOutcome::Unchanged);
+        }
+        let structural = cfg.symbol != self.cfg.symbol
+            || cfg.macd_fast != self.cfg.macd_fast
+            || cfg.macd_slow != self.cfg.macd_slow
+            || cfg.macd_signal != self.cfg.macd_signal;
+        if structural {
+            self.rebuild_macd(cfg)?;
+            return Ok(ReconfigureOutcome::Rebuilt);
         }
-        self.macd = Macd::new(cfg.macd_fast, cfg.macd_slow, cfg.macd_signal)
-            .map_err(|err| StrategyError::InvalidConfig(err.to_string()))?;
         self.cfg = cfg;
-        self.last_histogram = None;
-        Ok(())
+        Ok(ReconfigureOutcome::Preserved)
     }
 
     async fn on_tick(&mut self, _ctx: &StrategyContext, tick: &Tick) -> StrategyResult<()> {
@@ -1889,7 +2930,7 @@ impl Strategy for OrderBookScalper {
 
 register_strategy!(OrderBookScalper, "OrderBookScalper");
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(default)]
 pub struct CrossExchangeArbConfig {
     pub symbol_a: Symbol,
@@ -1948,6 +2989,23 @@ impl CrossExchangeArb {
         }
     }
 
+    fn parse_config(params: toml::Value) -> StrategyResult<CrossExchangeArbConfig> {
+        params.try_into().map_err(|err: toml::de::Error| {
+            StrategyError::InvalidConfig(format!("failed to parse CrossExchangeArb config: {err}"))
+        })
+    }
+
+    fn rebuild_ichimoku(&mut self, cfg: CrossExchangeArbConfig) -> StrategyResult<()> {
+        self.ichimoku = Ichimoku::new(
+            cfg.ichimoku_conversion,
+            cfg.ichimoku_base,
+            cfg.ichimoku_span_b,
+        )
+        .map_err(|err| StrategyError::InvalidConfig(err.to_string()))?;
+        self.cfg = cfg;
+        Ok(())
+    }
+
     fn emit_pair_trade(&mut self, long_a: bool) {
         let duration = Duration::seconds(30);
         let mut signal_a = Signal::new(
@@ -1981,6 +3039,14 @@ impl Strategy for CrossExchangeArb {
         "cross-exchange-arb"
     }
 
+    fn description(&self) -> &str {
+        "Trades the price spread between two venues for the same asset, gated by an Ichimoku cloud."
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["arbitrage", "multi-symbol", "order-book"]
+    }
+
     fn symbol(&self) -> Symbol {
         self.cfg.symbol_a
     }
@@ -1989,18 +3055,34 @@ impl Strategy for CrossExchangeArb {
         vec![self.cfg.symbol_a, self.cfg.symbol_b]
     }
 
+    fn required_history(&self) -> usize {
+        history_for_periods(&[self.cfg.ichimoku_span_b, self.cfg.ichimoku_base])
+    }
+
     fn configure(&mut self, params: toml::Value) -> StrategyResult<()> {
-        let cfg: CrossExchangeArbConfig = params.try_into().map_err(|err: toml::de::Error| {
-            StrategyError::InvalidConfig(format!("failed to parse CrossExchangeArb config: {err}"))
-        })?;
-        self.ichimoku = Ichimoku::new(
-            cfg.ichimoku_conversion,
-            cfg.ichimoku_base,
-            cfg.ichimoku_span_b,
-        )
-        .map_err(|err| StrategyError::InvalidConfig(err.to_string()))?;
+        let cfg = Self::parse_config(params)?;
+        self.rebuild_ichimoku(cfg)
+    }
+
+    fn reconfigure(&mut self, params: toml::Value) -> StrategyResult<ReconfigureOutcome> {
+        let cfg = Self::parse_config(params)?;
+        if cfg == self.cfg {
+            return Ok(ReconfigureOutcome::Unchanged);
+        }
+        let structural = cfg.symbol_a != self.cfg.symbol_a
+            || cfg.symbol_b != self.cfg.symbol_b
+            || cfg.ichimoku_conversion != self.cfg.ichimoku_conversion
+            || cfg.ichimoku_base != self.cfg.ichimoku_base
+            || cfg.ichimoku_span_b != self.cfg.ichimoku_span_b;
+        if structural {
+            self.rebuild_ichimoku(cfg)?;
+            self.last_price_a = None;
+            self.last_price_b = None;
+            self.last_cloud = None;
+            return Ok(ReconfigureOutcome::Rebuilt);
+        }
         self.cfg = cfg;
-        Ok(())
+        Ok(ReconfigureOutcome::Preserved)
     }
 
     async fn on_tick(&mut self, _ctx: &StrategyContext, _tick: &Tick) -> StrategyResult<()> {
@@ -2061,7 +3143,7 @@ impl Strategy for CrossExchangeArb {
 
 register_strategy!(CrossExchangeArb, "CrossExchangeArb");
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(default)]
 pub struct VolatilitySkewConfig {
     pub underlying: Symbol,
@@ -2100,7 +3182,7 @@ impl Default for VolatilitySkew {
 
 impl VolatilitySkew {
     pub fn new(cfg: VolatilitySkewConfig) -> Self {
-        let atr = Atr::new(cfg.atr_period).expect("valid ATR");
+        let atr = Atr::new(cfg.atr_period).expect("valid ATR").as_percent();
         Self {
             cfg,
             signals: Vec::new(),
@@ -2108,6 +3190,20 @@ impl VolatilitySkew {
             last_implied_vol: None,
         }
     }
+
+    fn parse_config(params: toml::Value) -> StrategyResult<VolatilitySkewConfig> {
+        params.try_into().map_err(|err: toml::de::Error| {
+            StrategyError::InvalidConfig(format!("failed to parse VolatilitySkew config: {err}"))
+        })
+    }
+
+    fn rebuild_atr(&mut self, cfg: VolatilitySkewConfig) -> StrategyResult<()> {
+        self.atr = Atr::new(cfg.atr_period)
+            .map_err(|err| StrategyError::InvalidConfig(err.to_string()))?
+            .as_percent();
+        self.cfg = cfg;
+        Ok(())
+    }
 }
 
 #[async_trait]
@@ -2116,6 +3212,14 @@ impl Strategy for VolatilitySkew {
         "volatility-skew"
     }
 
+    fn description(&self) -> &str {
+        "Trades the gap between implied volatility and ATR-based realized volatility."
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["volatility", "multi-symbol"]
+    }
+
     fn symbol(&self) -> Symbol {
         self.cfg.underlying
     }
@@ -2124,14 +3228,30 @@ impl Strategy for VolatilitySkew {
         vec![self.cfg.underlying, self.cfg.vol_symbol]
     }
 
+    fn required_history(&self) -> usize {
+        history_for_periods(&[self.cfg.atr_period])
+    }
+
     fn configure(&mut self, params: toml::Value) -> StrategyResult<()> {
-        let cfg: VolatilitySkewConfig = params.try_into().map_err(|err: toml::de::Error| {
-            StrategyError::InvalidConfig(format!("failed to parse VolatilitySkew config: {err}"))
-        })?;
-        self.atr = Atr::new(cfg.atr_period)
-            .map_err(|err| StrategyError::InvalidConfig(err.to_string()))?;
+        let cfg = Self::parse_config(params)?;
+        self.rebuild_atr(cfg)
+    }
+
+    fn reconfigure(&mut self, params: toml::Value) -> StrategyResult<ReconfigureOutcome> {
+        let cfg = Self::parse_config(params)?;
+        if cfg == self.cfg {
+            return Ok(ReconfigureOutcome::Unchanged);
+        }
+        let structural = cfg.underlying != self.cfg.underlying
+            || cfg.vol_symbol != self.cfg.vol_symbol
+            || cfg.atr_period != self.cfg.atr_period;
+        if structural {
+            self.rebuild_atr(cfg)?;
+            self.last_implied_vol = None;
+            return Ok(ReconfigureOutcome::Rebuilt);
+        }
         self.cfg = cfg;
-        Ok(())
+        Ok(ReconfigureOutcome::Preserved)
     }
 
     async fn on_tick(&mut self, _ctx: &StrategyContext, _tick: &Tick) -> StrategyResult<()> {
@@ -2146,12 +3266,9 @@ impl Strategy for VolatilitySkew {
         if candle.symbol != self.cfg.underlying {
             return Ok(());
         }
-        if let Some(realized) = self.atr.next(candle.clone()) {
+        if let Some(natr) = self.atr.next(candle.clone()) {
             if let Some(implied) = self.last_implied_vol {
-                if candle.close.is_zero() {
-                    return Ok(());
-                }
-                let realized_vol = realized / candle.close.max(Decimal::ONE);
+                let realized_vol = natr / Decimal::ONE_HUNDRED;
                 let threshold = realized_vol * self.cfg.realized_multiplier;
                 let premium =
                     threshold * (Decimal::ONE + self.cfg.implied_premium / Decimal::from(100));
@@ -2200,6 +3317,447 @@ register_strategy!(VolatilitySkew, "VolatilitySkew");
 #[cfg(test)]
 mod tests {
     use super::*;
+    use tesser_core::CandleStatus;
+
+    fn snapshot_context(events: usize) -> StrategyContext {
+        let symbol = Symbol::from("BTCUSDT");
+        let mut ctx = StrategyContext::new(512);
+        for idx in 0..events {
+            let price = Decimal::from(100 + idx as i64);
+            let timestamp = Utc::now() + Duration::seconds(idx as i64);
+            ctx.push_candle(Candle {
+                symbol,
+                interval: tesser_core::Interval::OneMinute,
+                open: price,
+                high: price,
+                low: price,
+                close: price,
+                volume: Decimal::ONE,
+                timestamp,
+                status: CandleStatus::Closed,
+            });
+            ctx.push_tick(Tick {
+                symbol,
+                price,
+                size: Decimal::ONE,
+                side: tesser_core::Side::Buy,
+                exchange_timestamp: timestamp,
+                received_at: timestamp,
+            });
+        }
+        ctx.update_positions(vec![Position {
+            symbol,
+            side: Some(tesser_core::Side::Buy),
+            quantity: Decimal::ONE,
+            entry_price: Some(Decimal::from(100)),
+            unrealized_pnl: Decimal::ZERO,
+            updated_at: Utc::now(),
+        }]);
+        ctx
+    }
+
+    #[test]
+    fn only_not_enough_data_is_transient() {
+        assert!(StrategyError::NotEnoughData.is_transient());
+        assert!(!StrategyError::InvalidConfig("bad".into()).is_transient());
+        assert!(!StrategyError::Internal("boom".into()).is_transient());
+    }
+
+    #[test]
+    fn context_snapshot_round_trips_through_json() {
+        let ctx = snapshot_context(5);
+        let snapshot = ContextSnapshot::from(&ctx);
+        let json = snapshot.to_json_pretty().unwrap();
+        let decoded: ContextSnapshot = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded.candles, snapshot.candles);
+        assert_eq!(decoded.ticks, snapshot.ticks);
+        assert_eq!(decoded.positions.len(), 1);
+        assert_eq!(decoded.positions[0].quantity, Decimal::ONE);
+    }
+
+    #[test]
+    fn context_snapshot_keeps_latest_events_within_cap() {
+        let ctx = snapshot_context(10);
+        let snapshot = ContextSnapshot::capture(&ctx, 3);
+        assert_eq!(snapshot.candles.len(), 3);
+        assert_eq!(snapshot.ticks.len(), 3);
+        let closes: Vec<_> = snapshot.candles.iter().map(|c| c.close).collect();
+        assert_eq!(
+            closes,
+            vec![Decimal::from(107), Decimal::from(108), Decimal::from(109)]
+        );
+
+        let default_cap =
+            ContextSnapshot::from(&snapshot_context(DEFAULT_CONTEXT_SNAPSHOT_CAP + 5));
+        assert_eq!(default_cap.candles.len(), DEFAULT_CONTEXT_SNAPSHOT_CAP);
+    }
+
+    fn ranged_candles(ctx: &mut StrategyContext, symbol: Symbol, count: usize, range: Decimal) {
+        let half = range / Decimal::from(2);
+        for idx in 0..count {
+            ctx.push_candle(Candle {
+                symbol,
+                interval: tesser_core::Interval::OneMinute,
+                open: Decimal::from(100),
+                high: Decimal::from(100) + half,
+                low: Decimal::from(100) - half,
+                close: Decimal::from(100),
+                volume: Decimal::ONE,
+                timestamp: Utc::now() + Duration::minutes(idx as i64),
+                status: CandleStatus::Closed,
+            });
+        }
+    }
+
+    #[test]
+    fn atr_stop_sits_expected_distance_below_long_entry() {
+        let symbol = Symbol::from("BTCUSDT");
+        let mut ctx = StrategyContext::new(64);
+        ranged_candles(
+            &mut ctx,
+            symbol,
+            DEFAULT_ATR_STOP_PERIOD + 5,
+            Decimal::from(2),
+        );
+
+        let entry = Decimal::from(100);
+        let stop = ctx
+            .atr_stop(symbol, entry, Side::Buy, Decimal::new(15, 1))
+            .expect("atr available");
+        assert_eq!(stop, Decimal::from(97));
+        assert_eq!(
+            ctx.atr_stop(symbol, entry, Side::Sell, Decimal::new(15, 1)),
+            Some(Decimal::from(103))
+        );
+    }
+
+    #[test]
+    fn atr_stop_requires_warmup_history() {
+        let symbol = Symbol::from("BTCUSDT");
+        let mut ctx = StrategyContext::new(64);
+        assert_eq!(
+            ctx.atr_stop(symbol, Decimal::from(100), Side::Buy, Decimal::ONE),
+            None
+        );
+        ranged_candles(
+            &mut ctx,
+            symbol,
+            DEFAULT_ATR_STOP_PERIOD - 1,
+            Decimal::from(2),
+        );
+        assert_eq!(
+            ctx.atr_stop(symbol, Decimal::from(100), Side::Buy, Decimal::ONE),
+            None
+        );
+    }
+
+    fn book(symbol: Symbol, minute: i64, bids: &[(i64, i64)], asks: &[(i64, i64)]) -> OrderBook {
+        let levels = |levels: &[(i64, i64)]| {
+            levels
+                .iter()
+                .map(|(price, size)| tesser_core::OrderBookLevel {
+                    price: Decimal::from(*price),
+                    size: Decimal::from(*size),
+                })
+                .collect()
+        };
+        OrderBook {
+            symbol,
+            bids: levels(bids),
+            asks: levels(asks),
+            timestamp: DateTime::<Utc>::from_timestamp(1_700_000_000, 0).unwrap()
+                + Duration::minutes(minute),
+            exchange_checksum: None,
+            local_checksum: None,
+        }
+    }
+
+    #[test]
+    fn level_history_tracks_a_ranked_level_over_recent_books() {
+        let symbol = Symbol::from("BTCUSDT");
+        let mut ctx = StrategyContext::new(8);
+        ctx.push_order_book(book(symbol, 0, &[(100, 5), (99, 7)], &[(101, 3)]));
+        ctx.push_order_book(book(symbol, 1, &[(100, 4), (99, 9)], &[(101, 2), (102, 6)]));
+        ctx.push_order_book(book(Symbol::from("ETHUSDT"), 2, &[(10, 1), (9, 1)], &[]));
+        ctx.push_order_book(book(symbol, 3, &[(101, 1)], &[(102, 8), (103, 4)]));
+        ctx.push_order_book(book(
+            symbol,
+            4,
+            &[(101, 2), (100, 12)],
+            &[(102, 5), (103, 1)],
+        ));
+
+        let at = |minute: i64| {
+            DateTime::<Utc>::from_timestamp(1_700_000_000, 0).unwrap() + Duration::minutes(minute)
+        };
+        let second_bid = ctx.level_history(symbol, Side::Buy, 1, 8);
+        assert_eq!(
+            second_bid,
+            vec![
+                (at(0), Decimal::from(99), Decimal::from(7)),
+                (at(1), Decimal::from(99), Decimal::from(9)),
+                (at(4), Decimal::from(100), Decimal::from(12)),
+            ]
+        );
+
+        let best_ask = ctx.level_history(symbol, Side::Sell, 0, 2);
+        assert_eq!(
+            best_ask,
+            vec![
+                (at(3), Decimal::from(102), Decimal::from(8)),
+                (at(4), Decimal::from(102), Decimal::from(5)),
+            ]
+        );
+        assert!(ctx.level_history(symbol, Side::Sell, 5, 8).is_empty());
+        assert!(ctx
+            .level_history(Symbol::from("SOLUSDT"), Side::Buy, 0, 8)
+            .is_empty());
+    }
+
+    #[test]
+    fn long_position_gains_as_price_rises() {
+        // Entry at 100, latest tick at 104.
+        let ctx = snapshot_context(5);
+        let symbol = Symbol::from("BTCUSDT");
+        assert_eq!(ctx.unrealized_pnl(symbol), Some(Decimal::from(4)));
+        assert_eq!(ctx.position_return(symbol), Some(Decimal::new(4, 2)));
+    }
+
+    #[test]
+    fn position_pnl_requires_a_position_and_a_price() {
+        let symbol = Symbol::from("BTCUSDT");
+        let mut ctx = StrategyContext::new(8);
+        ctx.update_positions(snapshot_context(1).positions().clone());
+        assert_eq!(ctx.unrealized_pnl(symbol), None);
+
+        push_close(&mut ctx, symbol, Decimal::from(90), 0);
+        assert_eq!(ctx.unrealized_pnl(symbol), Some(Decimal::from(-10)));
+        assert_eq!(ctx.position_return(Symbol::from("ETHUSDT")), None);
+    }
+
+    fn feed_sma_cross(mode: ContextMode, closes: &[i64]) -> Vec<Signal> {
+        let mut strategy = SmaCross::new(SmaCrossConfig {
+            fast_period: 2,
+            slow_period: 3,
+            min_samples: 3,
+            ..SmaCrossConfig::default()
+        });
+        let mut ctx = StrategyContext::new(16);
+        ctx.set_mode(mode);
+        for (idx, close) in closes.iter().enumerate() {
+            let price = Decimal::from(*close);
+            let candle = Candle {
+                symbol: strategy.cfg.symbol,
+                interval: tesser_core::Interval::OneMinute,
+                open: price,
+                high: price,
+                low: price,
+                close: price,
+                volume: Decimal::ONE,
+                timestamp: Utc::now() + Duration::minutes(idx as i64),
+                status: CandleStatus::Closed,
+            };
+            ctx.push_candle(candle.clone());
+            strategy.maybe_emit_signal(&ctx, &candle).unwrap();
+        }
+        strategy.drain_signals()
+    }
+
+    #[test]
+    fn sma_cross_ignores_forming_candles_until_close() {
+        let config = SmaCrossConfig {
+            fast_period: 2,
+            slow_period: 3,
+            min_samples: 3,
+            ..SmaCrossConfig::default()
+        };
+        let mut closed_only = SmaCross::new(config.clone());
+        let mut with_forming = SmaCross::new(config);
+        let mut closed_ctx = StrategyContext::new(16);
+        let mut forming_ctx = StrategyContext::new(16);
+        let symbol = closed_only.cfg.symbol;
+        let bar = |minute: i64, close: i64, status: CandleStatus| {
+            let price = Decimal::from(close);
+            Candle {
+                symbol,
+                interval: tesser_core::Interval::OneMinute,
+                open: price,
+                high: price,
+                low: price,
+                close: price,
+                volume: Decimal::ONE,
+                timestamp: Utc::now() + Duration::minutes(minute),
+                status,
+            }
+        };
+        for (minute, close) in [10, 9, 8, 7, 12, 15].into_iter().enumerate() {
+            let minute = minute as i64;
+            // Intra-bar updates swing far enough to cross the averages if they were consumed.
+            for spike in [100, 1] {
+                let forming = bar(minute, spike, CandleStatus::Forming);
+                forming_ctx.push_candle(forming.clone());
+                with_forming
+                    .maybe_emit_signal(&forming_ctx, &forming)
+                    .unwrap();
+                assert_eq!(with_forming.samples, closed_only.samples);
+                assert_eq!(with_forming.fast_last, closed_only.fast_last);
+            }
+            let closed = bar(minute, close, CandleStatus::Closed);
+            closed_ctx.push_candle(closed.clone());
+            closed_only.maybe_emit_signal(&closed_ctx, &closed).unwrap();
+            forming_ctx.push_candle(closed.clone());
+            with_forming
+                .maybe_emit_signal(&forming_ctx, &closed)
+                .unwrap();
+        }
+
+        assert_eq!(with_forming.samples, 6);
+        assert_eq!(with_forming.fast_last, closed_only.fast_last);
+        assert_eq!(with_forming.slow_last, closed_only.slow_last);
+        let kinds = |signals: Vec<Signal>| signals.into_iter().map(|s| s.kind).collect::<Vec<_>>();
+        assert_eq!(
+            kinds(with_forming.drain_signals()),
+            kinds(closed_only.drain_signals())
+        );
+        assert_eq!(forming_ctx.candles_for(symbol).map(VecDeque::len), Some(6));
+    }
+
+    #[test]
+    fn sma_cross_suppresses_signals_during_replay() {
+        // Fast SMA crosses above the slow SMA on the fifth close.
+        let closes = [10, 9, 8, 7, 12, 15];
+        assert!(feed_sma_cross(ContextMode::Replay, &closes).is_empty());
+
+        let live = feed_sma_cross(ContextMode::Live, &closes);
+        assert_eq!(live.len(), 1);
+        assert_eq!(live[0].kind, SignalKind::EnterLong);
+    }
+
+    #[test]
+    fn sma_cross_warmup_covers_slow_period() {
+        let strategy = SmaCross::new(SmaCrossConfig {
+            fast_period: 2,
+            slow_period: 30,
+            min_samples: 3,
+            ..SmaCrossConfig::default()
+        });
+        assert_eq!(strategy.warmup_candles(), 30);
+        assert_eq!(SmaCross::default().warmup_candles(), 25);
+    }
+
+    #[test]
+    fn sma_cross_json_and_toml_params_configure_identically() {
+        let toml_params: toml::Value = toml::from_str(
+            r#"
+            symbol = "ETHUSDT"
+            fast_period = 3
+            slow_period = 12
+            min_samples = 15
+            vwap_participation = 0.25
+            stop_atr_multiplier = 1.5
+            "#,
+        )
+        .unwrap();
+        let json_params = serde_json::json!({
+            "symbol": "ETHUSDT",
+            "fast_period": 3,
+            "slow_period": 12,
+            "min_samples": 15,
+            "vwap_duration_secs": null,
+            "vwap_participation": 0.25,
+            "stop_atr_multiplier": 1.5,
+        });
+
+        let mut from_toml = SmaCross::default();
+        from_toml.configure(toml_params).unwrap();
+        let mut from_json = SmaCross::default();
+        from_json.configure_json(json_params).unwrap();
+
+        assert_eq!(
+            serde_json::to_value(&from_toml.cfg).unwrap(),
+            serde_json::to_value(&from_json.cfg).unwrap()
+        );
+        assert_eq!(from_json.cfg.slow_period, 12);
+    }
+
+    #[test]
+    fn json_to_toml_converts_nested_values() {
+        let converted = json_to_toml(serde_json::json!({
+            "weights": [1, 2.5, "x"],
+            "nested": { "enabled": true, "skip": null },
+        }))
+        .unwrap();
+        let expected: toml::Value = toml::from_str(
+            r#"
+            weights = [1, 2.5, "x"]
+            nested = { enabled = true }
+            "#,
+        )
+        .unwrap();
+        assert_eq!(converted, expected);
+        assert!(json_to_toml(serde_json::Value::Null).is_err());
+        assert!(json_to_toml(serde_json::json!([null])).is_err());
+        assert!(json_to_toml(serde_json::json!(u64::MAX)).is_err());
+    }
+
+    #[test]
+    fn context_defaults_to_live_mode() {
+        assert_eq!(StrategyContext::new(4).mode(), ContextMode::Live);
+    }
+
+    fn imbalance_strategy(smoothing_period: Option<usize>) -> OrderBookImbalance {
+        let mut strategy = OrderBookImbalance::default();
+        let params = toml::Value::try_from(OrderBookImbalanceConfig {
+            smoothing_period,
+            ..OrderBookImbalanceConfig::default()
+        })
+        .unwrap();
+        strategy.configure(params).unwrap();
+        strategy
+    }
+
+    fn entry_signals(strategy: &mut OrderBookImbalance) -> usize {
+        strategy
+            .drain_signals()
+            .iter()
+            .filter(|signal| signal.kind == SignalKind::EnterLong)
+            .count()
+    }
+
+    #[test]
+    fn unsmoothed_imbalance_reacts_to_single_spike() {
+        let mut strategy = imbalance_strategy(None);
+        strategy.on_imbalance(Decimal::new(8, 1));
+        assert_eq!(entry_signals(&mut strategy), 1);
+    }
+
+    #[test]
+    fn smoothed_imbalance_suppresses_single_spike() {
+        let mut strategy = imbalance_strategy(Some(9));
+        for _ in 0..9 {
+            strategy.on_imbalance(Decimal::ZERO);
+        }
+        strategy.drain_signals();
+
+        strategy.on_imbalance(Decimal::new(8, 1));
+        assert_eq!(entry_signals(&mut strategy), 0);
+        strategy.on_imbalance(Decimal::ZERO);
+        assert_eq!(entry_signals(&mut strategy), 0);
+    }
+
+    #[test]
+    fn smoothed_imbalance_triggers_on_sustained_pressure() {
+        let mut strategy = imbalance_strategy(Some(9));
+        for _ in 0..9 {
+            strategy.on_imbalance(Decimal::ZERO);
+        }
+        strategy.drain_signals();
+
+        strategy.on_imbalance(Decimal::new(8, 1));
+        assert_eq!(entry_signals(&mut strategy), 0);
+        strategy.on_imbalance(Decimal::new(8, 1));
+        assert_eq!(entry_signals(&mut strategy), 1);
+    }
 
     #[test]
     fn rsi_handles_constant_input() {
@@ -2234,4 +3792,562 @@ mod tests {
 
         assert!((upper_low - lower_low) < (upper_high - lower_high));
     }
+
+    struct StubFactory {
+        name: &'static str,
+        aliases: &'static [&'static str],
+    }
+
+    impl StrategyFactory for StubFactory {
+        fn canonical_name(&self) -> &'static str {
+            self.name
+        }
+
+        fn aliases(&self) -> &'static [&'static str] {
+            self.aliases
+        }
+
+        fn build(&self, _params: Value) -> StrategyResult<Box<dyn Strategy>> {
+            Err(StrategyError::InvalidConfig(self.name.to_string()))
+        }
+    }
+
+    fn stub(name: &'static str, aliases: &'static [&'static str]) -> Arc<dyn StrategyFactory> {
+        Arc::new(StubFactory { name, aliases })
+    }
+
+    fn resolves_to(
+        registry: &StrategyRegistry,
+        name: &str,
+        factory: &Arc<dyn StrategyFactory>,
+    ) -> bool {
+        registry
+            .get(name)
+            .is_some_and(|existing| Arc::ptr_eq(&existing, factory))
+    }
+
+    #[test]
+    fn unregister_removes_canonical_and_alias_entries() {
+        let registry = StrategyRegistry::new();
+        let pairs = stub("Pairs", &["PairTrading", "Spread"]);
+        registry.register(pairs.clone());
+        registry.register(stub("Momentum", &[]));
+
+        let removed = registry.unregister("spread").expect("registered");
+        assert!(Arc::ptr_eq(&removed, &pairs));
+        assert!(registry.get("Pairs").is_none());
+        assert!(registry.get("PairTrading").is_none());
+        assert!(registry.get("Spread").is_none());
+        assert_eq!(registry.names(), vec!["Momentum"]);
+        assert!(registry.unregister("Pairs").is_none());
+    }
+
+    #[test]
+    fn re_registering_drops_aliases_of_the_replaced_factory() {
+        let registry = StrategyRegistry::new();
+        registry.register(stub("Pairs", &["Legacy"]));
+        let replacement = stub("Pairs", &["Spread"]);
+        registry.register(replacement.clone());
+        assert!(registry.get("Legacy").is_none());
+        assert!(resolves_to(&registry, "Spread", &replacement));
+    }
+
+    #[test]
+    fn override_guard_restores_previous_factories_on_drop() {
+        let registry = StrategyRegistry::new();
+        let original = stub("Pairs", &["Legacy"]);
+        let neighbour = stub("Momentum", &["Trend"]);
+        registry.register(original.clone());
+        registry.register(neighbour.clone());
+
+        {
+            let replacement = stub("Pairs", &["Trend"]);
+            let _guard = registry.with_override(replacement.clone());
+            assert!(resolves_to(&registry, "Pairs", &replacement));
+            assert!(resolves_to(&registry, "Trend", &replacement));
+            assert!(registry.get("Legacy").is_none());
+        }
+
+        assert!(resolves_to(&registry, "Pairs", &original));
+        assert!(resolves_to(&registry, "Legacy", &original));
+        assert!(resolves_to(&registry, "Trend", &neighbour));
+        assert_eq!(registry.names(), vec!["Momentum", "Pairs"]);
+
+        {
+            let _guard = registry.with_override(stub("Scratch", &["Tmp"]));
+            assert!(registry.get("tmp").is_some());
+        }
+        assert!(registry.get("Scratch").is_none());
+        assert!(registry.get("Tmp").is_none());
+    }
+
+    const PAIR_LOOKBACK: usize = 30;
+
+    fn pairs_strategy() -> PairsTradingArbitrage {
+        PairsTradingArbitrage::from_config(PairsTradingConfig {
+            lookback: PAIR_LOOKBACK,
+            clip_size: Decimal::ONE,
+            ..Default::default()
+        })
+        .unwrap()
+    }
+
+    fn push_close(ctx: &mut StrategyContext, symbol: Symbol, close: Decimal, idx: usize) {
+        ctx.push_candle(Candle {
+            symbol,
+            interval: tesser_core::Interval::OneMinute,
+            open: close,
+            high: close,
+            low: close,
+            close,
+            volume: Decimal::ONE,
+            timestamp: Utc::now() + Duration::minutes(idx as i64),
+            status: CandleStatus::Closed,
+        });
+    }
+
+    /// Feeds `a = b^2 / 50` (a log hedge ratio of 2), perturbed by `noise(idx)`.
+    fn squared_pair(
+        strategy: &PairsTradingArbitrage,
+        noise: impl Fn(usize) -> Decimal,
+    ) -> StrategyContext {
+        let [first, second] = strategy.cfg.symbols;
+        let mut ctx = StrategyContext::new(64);
+        for idx in 0..PAIR_LOOKBACK {
+            let b = Decimal::from(100 + idx as i64 + (idx as i64 % 7) * 3);
+            let a = b * b / Decimal::from(50) * (Decimal::ONE + noise(idx));
+            push_close(&mut ctx, first, a, idx);
+            push_close(&mut ctx, second, b, idx);
+        }
+        ctx
+    }
+
+    #[test]
+    fn pairs_hedge_ratio_tracks_log_price_regression() {
+        let mut strategy = pairs_strategy();
+        let ctx = squared_pair(&strategy, |_| Decimal::ZERO);
+        strategy.process_candle(&ctx, Utc::now());
+        let error = (strategy.hedge_ratio() - Decimal::from(2)).abs();
+        assert!(
+            error < Decimal::new(1, 6),
+            "beta {}",
+            strategy.hedge_ratio()
+        );
+    }
+
+    #[test]
+    fn pairs_entry_scales_second_leg_by_hedge_ratio() {
+        let mut strategy = pairs_strategy();
+        let ctx = squared_pair(&strategy, |idx| {
+            if idx == PAIR_LOOKBACK - 1 {
+                Decimal::new(5, 2)
+            } else if idx % 2 == 0 {
+                Decimal::new(2, 3)
+            } else {
+                Decimal::new(-2, 3)
+            }
+        });
+        strategy.process_candle(&ctx, Utc::now());
+
+        let beta = strategy.hedge_ratio();
+        assert!(
+            (beta - Decimal::from(2)).abs() < Decimal::new(5, 2),
+            "beta {beta}"
+        );
+        let signals = strategy.drain_signals();
+        assert_eq!(signals.len(), 2);
+        assert_eq!(signals[0].kind, SignalKind::EnterShort);
+        assert_eq!(signals[0].quantity, Some(Decimal::ONE));
+        assert_eq!(signals[1].kind, SignalKind::EnterLong);
+        let [first, second] = strategy.cfg.symbols;
+        let price_a = ctx.last_price(first).expect("first leg price");
+        let price_b = ctx.last_price(second).expect("second leg price");
+        assert_ne!(price_a, price_b);
+        assert_eq!(signals[1].quantity, Some(beta * price_a / price_b));
+
+        let trades = strategy.managed_trades();
+        assert_eq!(trades.len(), 1);
+        assert_eq!(trades[0].beta, beta);
+    }
+
+    #[test]
+    fn pairs_rejects_non_positive_clip() {
+        let cfg = PairsTradingConfig {
+            clip_size: Decimal::ZERO,
+            ..PairsTradingConfig::default()
+        };
+        assert!(matches!(
+            PairsTradingArbitrage::from_config(cfg),
+            Err(StrategyError::InvalidConfig(_))
+        ));
+    }
+
+    #[test]
+    fn builtin_strategies_advertise_tags() {
+        assert!(OrderBookImbalance::default().tags().contains(&"order-book"));
+        assert!(SmaCross::default().tags().contains(&"trend-following"));
+        assert!(!SmaCross::default().description().is_empty());
+
+        let descriptors = builtin_strategy_descriptors();
+        let imbalance = descriptors
+            .iter()
+            .find(|descriptor| descriptor.name == "OrderBookImbalance")
+            .expect("OrderBookImbalance registered");
+        assert!(imbalance.tags.contains(&"order-book"));
+        assert_eq!(imbalance.aliases, &["OBI"]);
+        assert_eq!(
+            imbalance.description,
+            OrderBookImbalance::default().description()
+        );
+        let sma = descriptors
+            .iter()
+            .find(|descriptor| descriptor.name == "SmaCross")
+            .expect("SmaCross registered");
+        assert!(sma.tags.contains(&"trend-following"));
+        assert!(!sma.tags.contains(&"order-book"));
+    }
+
+    /// Steady rise keeps RSI pinned at 100, then a single pullback at `dip_at`.
+    fn high_rsi_regime(dip_at: usize, len: usize) -> Vec<Candle> {
+        let mut close = Decimal::from(100);
+        (0..len)
+            .map(|idx| {
+                close += if idx == dip_at {
+                    Decimal::from(-3)
+                } else {
+                    Decimal::ONE
+                };
+                Candle {
+                    symbol: Symbol::from("BTCUSDT"),
+                    interval: tesser_core::Interval::OneMinute,
+                    open: close,
+                    high: close,
+                    low: close,
+                    close,
+                    volume: Decimal::ONE,
+                    timestamp: Utc::now() + Duration::minutes(idx as i64),
+                    status: CandleStatus::Closed,
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn adaptive_rsi_enters_on_relative_extremes_fixed_levels_miss() {
+        let candles = high_rsi_regime(40, 50);
+        let ctx = StrategyContext::new(64);
+
+        let mut adaptive = AdaptiveRsiReversion::from_config(AdaptiveRsiConfig {
+            period: 5,
+            lookback: 20,
+            ..AdaptiveRsiConfig::default()
+        })
+        .unwrap();
+        let mut fixed = RsiReversion::new(RsiReversionConfig {
+            period: 5,
+            lookback: 20,
+            ..RsiReversionConfig::default()
+        });
+        let mut entries = Vec::new();
+        for (idx, candle) in candles.iter().enumerate() {
+            adaptive.maybe_emit_signal(&ctx, candle);
+            fixed.maybe_emit_signal(candle).unwrap();
+            if adaptive
+                .drain_signals()
+                .iter()
+                .any(|signal| signal.kind == SignalKind::EnterLong)
+            {
+                entries.push(idx);
+            }
+        }
+        // RSI stays below its regime for a few bars while recovering from the pullback.
+        assert_eq!(entries.first(), Some(&40));
+        assert!(fixed
+            .drain_signals()
+            .iter()
+            .all(|signal| signal.kind != SignalKind::EnterLong));
+    }
+
+    #[test]
+    fn adaptive_rsi_stays_silent_during_replay() {
+        let mut ctx = StrategyContext::new(64);
+        ctx.set_mode(ContextMode::Replay);
+        let mut adaptive = AdaptiveRsiReversion::from_config(AdaptiveRsiConfig {
+            period: 5,
+            lookback: 20,
+            ..AdaptiveRsiConfig::default()
+        })
+        .unwrap();
+        for candle in high_rsi_regime(40, 50) {
+            adaptive.maybe_emit_signal(&ctx, &candle);
+        }
+        assert!(adaptive.drain_signals().is_empty());
+        assert!(AdaptiveRsiReversion::from_config(AdaptiveRsiConfig {
+            quantile: Decimal::new(6, 1),
+            ..AdaptiveRsiConfig::default()
+        })
+        .is_err());
+    }
+
+    fn rsi_params(period: i64, oversold: i64) -> toml::Value {
+        json_to_toml(serde_json::json!({
+            "period": period,
+            "oversold": oversold,
+            "lookback": 1,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn reconfiguring_thresholds_keeps_rsi_warmup() {
+        let mut rsi = RsiReversion::default();
+        rsi.configure(rsi_params(5, 30)).unwrap();
+        for candle in high_rsi_regime(usize::MAX, 6) {
+            rsi.maybe_emit_signal(&candle).unwrap();
+        }
+        let warm = rsi.rsi.current();
+        assert!(warm.is_some());
+
+        assert_eq!(
+            rsi.reconfigure(rsi_params(5, 30)).unwrap(),
+            ReconfigureOutcome::Unchanged
+        );
+        assert_eq!(
+            rsi.reconfigure(rsi_params(5, 25)).unwrap(),
+            ReconfigureOutcome::Preserved
+        );
+        assert_eq!(rsi.rsi.current(), warm);
+        assert_eq!(rsi.samples, 6);
+        assert_eq!(rsi.oversold_level, Decimal::from(25));
+    }
+
+    #[test]
+    fn reconfiguring_period_rebuilds_rsi() {
+        let mut rsi = RsiReversion::default();
+        rsi.configure(rsi_params(5, 30)).unwrap();
+        for candle in high_rsi_regime(usize::MAX, 6) {
+            rsi.maybe_emit_signal(&candle).unwrap();
+        }
+        assert!(rsi.rsi.current().is_some());
+
+        assert_eq!(
+            rsi.reconfigure(rsi_params(7, 30)).unwrap(),
+            ReconfigureOutcome::Rebuilt
+        );
+        assert_eq!(rsi.rsi.current(), None);
+        assert_eq!(rsi.samples, 0);
+        assert!(rsi.reconfigure(rsi_params(0, 30)).is_err());
+        assert_eq!(
+            rsi.cfg.period, 7,
+            "rejected parameters leave the config alone"
+        );
+    }
+
+    fn sma_params(slow_period: usize, min_samples: usize) -> toml::Value {
+        toml::Value::try_from(SmaCrossConfig {
+            fast_period: 2,
+            slow_period,
+            min_samples,
+            ..SmaCrossConfig::default()
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn sma_cross_reconfigure_rebuilds_only_on_period_changes() {
+        let mut sma = SmaCross::default();
+        sma.configure(sma_params(3, 3)).unwrap();
+        let ctx = StrategyContext::new(16);
+        for candle in high_rsi_regime(usize::MAX, 4) {
+            sma.maybe_emit_signal(&ctx, &candle).unwrap();
+        }
+        let warm = sma.slow_ma.current();
+        assert!(warm.is_some());
+
+        assert_eq!(
+            sma.reconfigure(sma_params(3, 10)).unwrap(),
+            ReconfigureOutcome::Preserved
+        );
+        assert_eq!(sma.slow_ma.current(), warm);
+        assert_eq!(sma.samples, 4);
+
+        assert_eq!(
+            sma.reconfigure(sma_params(4, 10)).unwrap(),
+            ReconfigureOutcome::Rebuilt
+        );
+        assert_eq!(sma.slow_ma.current(), None);
+        assert_eq!(sma.samples, 0);
+    }
+
+    #[test]
+    fn adaptive_rsi_quantile_change_keeps_rsi_warm() {
+        let mut adaptive = AdaptiveRsiReversion::from_config(AdaptiveRsiConfig {
+            period: 5,
+            lookback: 20,
+            ..AdaptiveRsiConfig::default()
+        })
+        .unwrap();
+        let ctx = StrategyContext::new(64);
+        for candle in high_rsi_regime(usize::MAX, 10) {
+            adaptive.maybe_emit_signal(&ctx, &candle);
+        }
+        let warm = adaptive.rsi.current();
+        assert!(warm.is_some());
+
+        let params = |quantile: Decimal| {
+            toml::Value::try_from(AdaptiveRsiConfig {
+                period: 5,
+                lookback: 20,
+                quantile,
+                ..AdaptiveRsiConfig::default()
+            })
+            .unwrap()
+        };
+        assert_eq!(
+            adaptive.reconfigure(params(Decimal::new(1, 1))).unwrap(),
+            ReconfigureOutcome::Unchanged
+        );
+        assert_eq!(
+            adaptive.reconfigure(params(Decimal::new(2, 1))).unwrap(),
+            ReconfigureOutcome::Rebuilt
+        );
+        assert_eq!(adaptive.rsi.current(), warm);
+        assert!(adaptive.oversold.current().is_none());
+    }
+
+    #[test]
+    fn pairs_threshold_reconfigure_keeps_open_trades() {
+        let mut strategy = pairs_strategy();
+        let ctx = squared_pair(&strategy, |idx| {
+            if idx == PAIR_LOOKBACK - 1 {
+                Decimal::new(5, 2)
+            } else if idx % 2 == 0 {
+                Decimal::new(2, 3)
+            } else {
+                Decimal::new(-2, 3)
+            }
+        });
+        strategy.process_candle(&ctx, Utc::now());
+        assert_eq!(strategy.managed_trades().len(), 1);
+        let beta = strategy.hedge_ratio();
+
+        // Symbols serialize without their exchange prefix, so spell the parameters out.
+        let params = |lookback: usize, entry_z: &str, exit_z: &str| {
+            json_to_toml(serde_json::json!({
+                "symbols": ["binance_perp:BTCUSDT", "binance_perp:ETHUSDT"],
+                "lookback": lookback,
+                "entry_z": entry_z,
+                "exit_z": exit_z,
+                "clip_size": "1",
+            }))
+            .unwrap()
+        };
+        assert_eq!(
+            strategy
+                .reconfigure(params(PAIR_LOOKBACK, "3", "0.5"))
+                .unwrap(),
+            ReconfigureOutcome::Preserved
+        );
+        assert_eq!(strategy.entry_z_level, Decimal::from(3));
+        assert_eq!(strategy.managed_trades().len(), 1);
+        assert_eq!(strategy.hedge_ratio(), beta);
+
+        assert!(strategy
+            .reconfigure(params(PAIR_LOOKBACK, "3", "5"))
+            .is_err());
+        assert_eq!(strategy.cfg.exit_z, Decimal::new(5, 1));
+
+        assert_eq!(
+            strategy
+                .reconfigure(params(PAIR_LOOKBACK + 1, "3", "0.5"))
+                .unwrap(),
+            ReconfigureOutcome::Rebuilt
+        );
+        assert!(strategy.managed_trades().is_empty());
+    }
+
+    #[test]
+    fn long_lookback_strategies_force_larger_context() {
+        let mut rsi = RsiReversion::default();
+        rsi.configure_json(serde_json::json!({ "lookback": 200 }))
+            .unwrap();
+        assert!(rsi.required_history() >= 200);
+
+        let mut ctx = StrategyContext::new(64);
+        ctx.ensure_history(rsi.required_history());
+        assert!(ctx.max_history() >= 200);
+        ctx.ensure_history(10);
+        assert!(ctx.max_history() >= 200, "ensure_history never shrinks");
+    }
+
+    #[test]
+    fn pairs_lookback_survives_small_configured_history() {
+        let strategy = PairsTradingArbitrage::from_config(PairsTradingConfig {
+            lookback: 200,
+            clip_size: Decimal::ONE,
+            ..Default::default()
+        })
+        .unwrap();
+        assert_eq!(strategy.required_history(), 200);
+
+        let [first, second] = strategy.cfg.symbols;
+        let mut ctx = StrategyContext::new(64);
+        ctx.ensure_history(strategy.required_history());
+        for idx in 0..250 {
+            push_close(&mut ctx, first, Decimal::from(100 + idx as i64), idx);
+            push_close(&mut ctx, second, Decimal::from(50 + idx as i64), idx);
+        }
+        assert_eq!(collect_symbol_closes(&ctx, first, 200).len(), 200);
+        assert_eq!(collect_symbol_closes(&ctx, second, 200).len(), 200);
+    }
+
+    #[test]
+    fn required_history_defaults_to_warmup() {
+        let sma = SmaCross::default();
+        assert!(sma.required_history() >= sma.warmup_candles());
+        assert_eq!(
+            OrderBookImbalance::default().required_history(),
+            OrderBookImbalance::default().warmup_candles()
+        );
+    }
+
+    #[test]
+    fn validate_config_file_reports_each_strategy_block() {
+        let config = r#"
+            [[strategies]]
+            strategy_name = "SmaCross"
+            [strategies.params]
+            symbol = "BTCUSDT"
+            fast_period = 5
+            slow_period = 20
+
+            [[strategies]]
+            strategy_name = "SmaCross"
+            [strategies.params]
+            symbol = "ETHUSDT"
+            fast_period = 0
+            slow_period = 20
+        "#;
+        let results = validate_config_file(config);
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].0, "SmaCross");
+        assert!(results[0].1.is_ok());
+        assert_eq!(results[1].0, "SmaCross");
+        assert!(matches!(results[1].1, Err(StrategyError::InvalidConfig(_))));
+    }
+
+    #[test]
+    fn validate_config_file_accepts_single_strategy_files() {
+        let config = "strategy_name = \"RsiReversion\"\n[params]\nsymbol = \"BTCUSDT\"\n";
+        let results = validate_config_file(config);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].0, "RsiReversion");
+        assert!(results[0].1.is_ok());
+
+        let unknown = validate_config_file("strategy_name = \"Nope\"");
+        assert!(matches!(unknown[0].1, Err(StrategyError::InvalidConfig(_))));
+        let missing = validate_config_file("[[strategies]]\n[strategies.params]\n");
+        assert_eq!(missing[0].0, "strategies[0]");
+        assert!(missing[0].1.is_err());
+    }
 }
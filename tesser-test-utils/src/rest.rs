@@ -111,6 +111,9 @@ async fn route(req: Request<Body>, state: MockExchangeState) -> Response<Body> {
     match (method, path.as_str()) {
         (Method::POST, "/v5/order/create") => handle_order_create(parts, body_bytes, state).await,
         (Method::POST, "/v5/order/cancel") => handle_order_cancel(parts, body_bytes, state).await,
+        (Method::POST, "/v5/order/cancel-batch") => {
+            handle_order_cancel_batch(parts, body_bytes, state).await
+        }
         (Method::GET, "/v5/position/list") => handle_positions(parts, state).await,
         (Method::GET, "/v5/account/wallet-balance") => handle_wallet_balance(parts, state).await,
         (Method::GET, "/v5/execution/list") => handle_execution_list(parts, state).await,
@@ -234,6 +237,56 @@ async fn handle_order_cancel(
     ok_response(json!({ "orderId": order_id }))
 }
 
+async fn handle_order_cancel_batch(
+    parts: http::request::Parts,
+    body: Bytes,
+    state: MockExchangeState,
+) -> Response<Body> {
+    let api_key = match authenticate(&parts, &body, Method::POST, &state).await {
+        Ok(api_key) => api_key,
+        Err(resp) => return resp,
+    };
+    let payload: CancelBatchPayload = match serde_json::from_slice(&body) {
+        Ok(payload) => payload,
+        Err(err) => return bad_request(format!("invalid JSON payload: {err}")),
+    };
+
+    let mut results = Vec::with_capacity(payload.request.len());
+    let mut statuses = Vec::with_capacity(payload.request.len());
+    for item in payload.request {
+        let cancelled = match state
+            .find_order_id(
+                &api_key,
+                item.order_id.as_deref(),
+                item.order_link_id.as_deref(),
+            )
+            .await
+        {
+            Ok(order_id) => state.cancel_order(&api_key, &order_id).await,
+            Err(err) => Err(err),
+        };
+        match cancelled {
+            Ok(order) => {
+                if let Err(err) = broadcast_order_update(&state, &order).await {
+                    warn!(error = %err, "failed to deliver order cancel event");
+                }
+                results.push(json!({ "orderId": order.id }));
+                statuses.push(json!({ "code": 0, "msg": "OK" }));
+            }
+            Err(err) => {
+                results.push(json!({
+                    "orderId": item.order_id.unwrap_or_default(),
+                }));
+                statuses.push(json!({ "code": 110001, "msg": err.to_string() }));
+            }
+        }
+    }
+
+    let mut envelope = create_envelope(json!({ "list": results }), 0, "OK");
+    envelope["retExtInfo"] = json!({ "list": statuses });
+    json_response(StatusCode::OK, envelope)
+}
+
 async fn handle_positions(parts: http::request::Parts, state: MockExchangeState) -> Response<Body> {
     let api_key = match authenticate(&parts, &Bytes::new(), Method::GET, &state).await {
         Ok(api_key) => api_key,
@@ -701,6 +754,13 @@ struct CreateOrderPayload {
     trigger_price: Option<String>,
 }
 
+#[derive(Deserialize)]
+struct CancelBatchPayload {
+    #[allow(dead_code)]
+    category: Option<String>,
+    request: Vec<CancelOrderPayload>,
+}
+
 #[derive(Deserialize)]
 struct CancelOrderPayload {
     #[allow(dead_code)]
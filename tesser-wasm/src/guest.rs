@@ -1,8 +1,13 @@
-use crate::types::{PluginFill, PluginInitContext, PluginResult, PluginSide, PluginTick};
+use crate::types::{
+    PluginError, PluginFill, PluginInitContext, PluginResult, PluginSide, PluginTick,
+};
 use once_cell::sync::OnceCell;
 use rust_decimal::Decimal;
 use serde_json::Value;
-use std::sync::Mutex;
+use std::any::Any;
+use std::cell::RefCell;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Mutex, Once, PoisonError};
 
 #[allow(clippy::too_many_arguments)]
 mod bindings {
@@ -11,6 +16,7 @@ mod bindings {
         path: "wit",
     });
 }
+use bindings::tesser::execution::primitives::DecimalValue as AbiDecimal;
 use bindings::tesser::execution::primitives::Side as AbiSide;
 pub use bindings::tesser::execution::primitives::Tick as AbiTick;
 
@@ -26,28 +32,52 @@ pub trait ExecutionPlugin: Default + 'static {
     fn on_timer(&mut self) -> Result<PluginResult, PluginError> {
         Ok(PluginResult::default())
     }
+    /// Called when the host rejects one of the plugin's orders (`order_id` is the client order id).
+    fn on_order_rejected(&mut self, _order_id: String, _reason: String) -> Result<(), PluginError> {
+        Ok(())
+    }
+    /// State the host persists after every callback so the plugin survives a restart.
     fn snapshot(&mut self) -> Result<Value, PluginError> {
         Ok(Value::Null)
     }
+    /// Reload a [`Self::snapshot`] after a restart; the host calls `init` with the original
+    /// context first, so only fields that change while working need to be restored.
     fn restore(&mut self, _state: Value) -> Result<(), PluginError> {
         Ok(())
     }
 }
 
-/// Simple error wrapper exposed to plugin authors.
-#[derive(Debug)]
-pub struct PluginError {
-    pub message: String,
+thread_local! {
+    static LAST_PANIC: RefCell<Option<String>> = const { RefCell::new(None) };
 }
 
-impl<T> From<T> for PluginError
-where
-    T: ToString,
-{
-    fn from(value: T) -> Self {
-        Self {
-            message: value.to_string(),
-        }
+/// Install a panic hook that records the panic message and location for [`PluginRuntime`].
+///
+/// The previous hook still runs afterwards. Called automatically by the runtime; calling it
+/// again is a no-op.
+pub fn install_panic_hook() {
+    static HOOK: Once = Once::new();
+    HOOK.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            let message = payload_message(info.payload());
+            let message = match info.location() {
+                Some(location) => format!("{message} at {location}"),
+                None => message,
+            };
+            LAST_PANIC.with(|slot| *slot.borrow_mut() = Some(message));
+            previous(info);
+        }));
+    });
+}
+
+fn payload_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        (*message).to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "plugin panicked".to_string()
     }
 }
 
@@ -75,15 +105,23 @@ impl<P: ExecutionPlugin> PluginRuntime<P> {
         self.inner.get_or_init(|| Mutex::new(P::default()))
     }
 
+    /// Run `f` against the plugin, converting a panic into [`PluginError::Panic`].
+    ///
+    /// A panic poisons the lock but leaves the plugin in place, so later calls keep working.
+    /// Panics only unwind when the plugin is built with `panic = "unwind"`; under the default
+    /// `abort` strategy for wasm targets the host still observes a trap.
     fn with_plugin<R>(
         &self,
         f: impl FnOnce(&mut P) -> Result<R, PluginError>,
     ) -> Result<R, PluginError> {
-        let mut guard = self
-            .plugin()
-            .lock()
-            .map_err(|_| PluginError::from("plugin lock poisoned"))?;
-        f(&mut *guard)
+        install_panic_hook();
+        let mut guard = self.plugin().lock().unwrap_or_else(PoisonError::into_inner);
+        panic::catch_unwind(AssertUnwindSafe(|| f(&mut *guard))).unwrap_or_else(|payload| {
+            let message = LAST_PANIC
+                .with(|slot| slot.borrow_mut().take())
+                .unwrap_or_else(|| payload_message(payload.as_ref()));
+            Err(PluginError::Panic(message))
+        })
     }
 
     pub fn call_init(&self, config_json: String) -> Result<String, String> {
@@ -91,15 +129,15 @@ impl<P: ExecutionPlugin> PluginRuntime<P> {
             serde_json::from_str(&config_json).map_err(|err| err.to_string())?;
         let response = self
             .with_plugin(|plugin| plugin.init(ctx))
-            .map_err(|err| err.message)?;
+            .map_err(|err| err.encode())?;
         serde_json::to_string(&response).map_err(|err| err.to_string())
     }
 
     pub fn call_on_tick(&self, tick: AbiTick) -> Result<String, String> {
-        let tick = convert_tick(tick).map_err(|err| err.message)?;
+        let tick = convert_tick(tick).map_err(|err| err.encode())?;
         let response = self
             .with_plugin(|plugin| plugin.on_tick(tick))
-            .map_err(|err| err.message)?;
+            .map_err(|err| err.encode())?;
         serde_json::to_string(&response).map_err(|err| err.to_string())
     }
 
@@ -107,28 +145,33 @@ impl<P: ExecutionPlugin> PluginRuntime<P> {
         let fill: PluginFill = serde_json::from_str(&fill_json).map_err(|err| err.to_string())?;
         let response = self
             .with_plugin(|plugin| plugin.on_fill(fill))
-            .map_err(|err| err.message)?;
+            .map_err(|err| err.encode())?;
         serde_json::to_string(&response).map_err(|err| err.to_string())
     }
 
     pub fn call_on_timer(&self) -> Result<String, String> {
         let response = self
             .with_plugin(|plugin| plugin.on_timer())
-            .map_err(|err| err.message)?;
+            .map_err(|err| err.encode())?;
         serde_json::to_string(&response).map_err(|err| err.to_string())
     }
 
+    pub fn call_on_order_rejected(&self, order_id: String, reason: String) -> Result<(), String> {
+        self.with_plugin(|plugin| plugin.on_order_rejected(order_id, reason))
+            .map_err(|err| err.encode())
+    }
+
     pub fn call_snapshot(&self) -> Result<String, String> {
         let snapshot = self
             .with_plugin(|plugin| plugin.snapshot())
-            .map_err(|err| err.message)?;
+            .map_err(|err| err.encode())?;
         serde_json::to_string(&snapshot).map_err(|err| err.to_string())
     }
 
     pub fn call_restore(&self, state_json: String) -> Result<(), String> {
         let state: Value = serde_json::from_str(&state_json).map_err(|err| err.to_string())?;
         self.with_plugin(|plugin| plugin.restore(state))
-            .map_err(|err| err.message)
+            .map_err(|err| err.encode())
     }
 }
 
@@ -146,9 +189,29 @@ fn convert_tick(source: AbiTick) -> Result<PluginTick, PluginError> {
             AbiSide::Sell => PluginSide::Sell,
         },
         timestamp_ms: source.timestamp_ms,
+        best_bid: convert_optional(source.best_bid, "best bid")?,
+        best_ask: convert_optional(source.best_ask, "best ask")?,
+        bid_size: convert_optional(source.bid_size, "bid size")?,
+        ask_size: convert_optional(source.ask_size, "ask size")?,
     })
 }
 
+fn convert_optional(
+    value: Option<AbiDecimal>,
+    label: &str,
+) -> Result<Option<Decimal>, PluginError> {
+    value
+        .map(|value| {
+            Decimal::from_str_exact(&value.value)
+                .map_err(|err| PluginError::from(format!("invalid {label}: {err}")))
+        })
+        .transpose()
+}
+
+/// Export `$ty` as the plugin implementation.
+///
+/// Every callback runs through [`PluginRuntime`], so a panic is returned to the host as an
+/// encoded [`PluginError::Panic`] instead of trapping.
 #[macro_export]
 macro_rules! export_plugin {
     ($ty:ty) => {
@@ -156,6 +219,10 @@ macro_rules! export_plugin {
         static RUNTIME: $crate::guest::PluginRuntime<$ty> = $crate::guest::PluginRuntime::new();
 
         impl $crate::guest::WasmGuest for __TesserWasmPlugin {
+            fn abi_version() -> u32 {
+                $crate::PLUGIN_ABI_VERSION
+            }
+
             fn init(config_json: String) -> Result<String, String> {
                 RUNTIME.call_init(config_json)
             }
@@ -172,6 +239,10 @@ macro_rules! export_plugin {
                 RUNTIME.call_on_timer()
             }
 
+            fn on_order_rejected(order_id: String, reason: String) -> Result<(), String> {
+                RUNTIME.call_on_order_rejected(order_id, reason)
+            }
+
             fn snapshot() -> Result<String, String> {
                 RUNTIME.call_snapshot()
             }
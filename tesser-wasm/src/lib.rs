@@ -7,7 +7,7 @@ pub use types::*;
 pub mod guest;
 
 #[cfg(feature = "guest")]
-pub use guest::{ExecutionPlugin, PluginError};
+pub use guest::ExecutionPlugin;
 
 #[cfg(feature = "host")]
 pub mod host;
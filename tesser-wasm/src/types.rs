@@ -4,6 +4,18 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use uuid::Uuid;
 
+/// Version of the plugin ABI: the WIT world plus the JSON payloads exchanged through it.
+///
+/// Plugins report the version they were built against through the `abi-version` export and the
+/// host refuses to load any other version. Bump it whenever either side of the contract changes.
+///
+/// - 1: `on-order-rejected` callback and `abi-version` export.
+/// - 2: top-of-book sizes (`bid_size` / `ask_size`) on ticks.
+/// - 3: callback errors carry an encoded `PluginError`, reporting panics as `PluginError::Panic`.
+/// - 4: orders may be sized by `notional` instead of `quantity`, which became optional; the risk
+///   context carries the instrument `lot_size`.
+pub const PLUGIN_ABI_VERSION: u32 = 4;
+
 /// Side of an order emitted by a plugin.
 #[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
 #[serde(rename_all = "snake_case")]
@@ -41,12 +53,20 @@ pub enum PluginTimeInForce {
 }
 
 /// Simplified order request structure returned by plugins.
+///
+/// Exactly one of `quantity` and `notional` must be set. Notional orders are sized by the
+/// host, which divides the notional by the limit price (or the latest trade price for market
+/// orders) and rounds down to the instrument lot size.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct PluginOrderRequest {
     pub symbol: String,
     pub side: PluginSide,
     pub order_type: PluginOrderType,
-    pub quantity: Decimal,
+    #[serde(default)]
+    pub quantity: Option<Decimal>,
+    /// Quote-denominated order size, resolved to a quantity by the host.
+    #[serde(default)]
+    pub notional: Option<Decimal>,
     #[serde(default)]
     pub price: Option<Decimal>,
     #[serde(default)]
@@ -63,6 +83,29 @@ pub struct PluginOrderRequest {
     pub display_quantity: Option<Decimal>,
 }
 
+impl PluginOrderRequest {
+    /// Check that the order carries exactly one positive size field.
+    pub fn validate_size(&self) -> Result<(), PluginError> {
+        match (self.quantity, self.notional) {
+            (Some(_), Some(_)) => Err(PluginError::Message(format!(
+                "order for {} sets both quantity and notional",
+                self.symbol
+            ))),
+            (None, None) => Err(PluginError::Message(format!(
+                "order for {} sets neither quantity nor notional",
+                self.symbol
+            ))),
+            (Some(size), None) | (None, Some(size)) if size <= Decimal::ZERO => {
+                Err(PluginError::Message(format!(
+                    "order for {} has non-positive size {size}",
+                    self.symbol
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
 /// Simplified amendment request emitted by plugins.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct PluginOrderUpdateRequest {
@@ -131,6 +174,9 @@ pub struct PluginRiskContext {
     pub settlement_available: Decimal,
     #[serde(default)]
     pub instrument_kind: Option<String>,
+    /// Quantity increment of the instrument, used to round notional-sized orders.
+    #[serde(default)]
+    pub lot_size: Option<Decimal>,
 }
 
 impl Default for PluginRiskContext {
@@ -144,6 +190,7 @@ impl Default for PluginRiskContext {
             quote_available: Decimal::ZERO,
             settlement_available: Decimal::ZERO,
             instrument_kind: None,
+            lot_size: None,
         }
     }
 }
@@ -160,6 +207,30 @@ pub struct PluginInitContext {
     pub metadata: Value,
 }
 
+impl PluginInitContext {
+    /// Read a decimal parameter given either as a string (`"0.25"`) or a JSON number.
+    pub fn param_decimal(&self, key: &str) -> Option<Decimal> {
+        match self.params.get(key)? {
+            Value::String(raw) => Decimal::from_str_exact(raw.trim()).ok(),
+            Value::Number(number) => Decimal::from_str_exact(&number.to_string()).ok(),
+            _ => None,
+        }
+    }
+
+    /// Read an unsigned integer parameter given either as a JSON number or a numeric string.
+    pub fn param_u64(&self, key: &str) -> Option<u64> {
+        match self.params.get(key)? {
+            Value::String(raw) => raw.trim().parse().ok(),
+            value => value.as_u64(),
+        }
+    }
+
+    /// Read a string parameter.
+    pub fn param_str(&self, key: &str) -> Option<&str> {
+        self.params.get(key)?.as_str()
+    }
+}
+
 /// Representation of a fill routed back into the plugin.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct PluginFill {
@@ -204,6 +275,18 @@ pub struct PluginTick {
     pub size: Decimal,
     pub side: PluginSide,
     pub timestamp_ms: i64,
+    /// Best bid from the host's current order book, when one is available.
+    #[serde(default)]
+    pub best_bid: Option<Decimal>,
+    /// Best ask from the host's current order book, when one is available.
+    #[serde(default)]
+    pub best_ask: Option<Decimal>,
+    /// Quantity resting at `best_bid`; present whenever `best_bid` is.
+    #[serde(default)]
+    pub bid_size: Option<Decimal>,
+    /// Quantity resting at `best_ask`; present whenever `best_ask` is.
+    #[serde(default)]
+    pub ask_size: Option<Decimal>,
 }
 
 impl PluginTick {
@@ -220,8 +303,75 @@ impl PluginTick {
             size,
             side,
             timestamp_ms,
+            best_bid: None,
+            best_ask: None,
+            bid_size: None,
+            ask_size: None,
+        }
+    }
+
+    /// Attach top-of-book levels as `(price, size)` pairs.
+    #[must_use]
+    pub fn with_depth(
+        mut self,
+        bid: Option<(Decimal, Decimal)>,
+        ask: Option<(Decimal, Decimal)>,
+    ) -> Self {
+        self.best_bid = bid.map(|(price, _)| price);
+        self.bid_size = bid.map(|(_, size)| size);
+        self.best_ask = ask.map(|(price, _)| price);
+        self.ask_size = ask.map(|(_, size)| size);
+        self
+    }
+
+    /// Returns true when the host supplied both sides of the book.
+    pub fn has_depth(&self) -> bool {
+        self.best_bid.is_some() && self.best_ask.is_some()
+    }
+}
+
+/// Error returned by plugin callbacks and carried back to the host.
+///
+/// Errors cross the ABI as JSON (see [`PluginError::encode`]); the host recovers them with
+/// [`PluginError::decode`], which treats any other string as a plain message.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(tag = "kind", content = "message", rename_all = "snake_case")]
+pub enum PluginError {
+    /// Failure reported by the plugin itself.
+    Message(String),
+    /// The plugin panicked inside a callback; holds the panic message.
+    Panic(String),
+}
+
+impl PluginError {
+    pub fn message(&self) -> &str {
+        match self {
+            Self::Message(message) | Self::Panic(message) => message,
         }
     }
+
+    pub fn is_panic(&self) -> bool {
+        matches!(self, Self::Panic(_))
+    }
+
+    /// Serialize the error into the string passed across the ABI.
+    pub fn encode(&self) -> String {
+        serde_json::to_string(self).unwrap_or_else(|_| self.message().to_string())
+    }
+
+    /// Parse an error string returned by a plugin.
+    pub fn decode(raw: &str) -> Self {
+        serde_json::from_str(raw).unwrap_or_else(|_| Self::Message(raw.to_string()))
+    }
+}
+
+impl<T> From<T> for PluginError
+where
+    T: ToString,
+{
+    fn from(value: T) -> Self {
+        Self::Message(value.to_string())
+    }
 }
 
 /// Canonical plugin callback result.
@@ -233,6 +383,9 @@ pub struct PluginResult {
     pub logs: Vec<String>,
     #[serde(default)]
     pub completed: bool,
+    /// Minimum delay before the host delivers the next `on_timer` callback.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_timer_ms: Option<u64>,
 }
 
 impl PluginResult {
@@ -240,13 +393,276 @@ impl PluginResult {
         Self::default()
     }
 
+    pub fn builder() -> PluginResultBuilder {
+        PluginResultBuilder::default()
+    }
+
+    #[must_use]
     pub fn with_order(mut self, action: PluginChildOrderAction) -> Self {
         self.orders.push(PluginChildOrderRequest { action });
         self
     }
 
+    /// Append several actions, preserving their order.
+    #[must_use]
+    pub fn with_orders(mut self, actions: Vec<PluginChildOrderAction>) -> Self {
+        self.orders.extend(
+            actions
+                .into_iter()
+                .map(|action| PluginChildOrderRequest { action }),
+        );
+        self
+    }
+
     pub fn completed(mut self) -> Self {
         self.completed = true;
         self
     }
+
+    /// Actions in the order the host will apply them.
+    pub fn actions(&self) -> impl Iterator<Item = &PluginChildOrderAction> {
+        self.orders.iter().map(|order| &order.action)
+    }
+}
+
+/// Incremental builder for [`PluginResult`].
+#[derive(Clone, Debug, Default)]
+pub struct PluginResultBuilder {
+    result: PluginResult,
+}
+
+impl PluginResultBuilder {
+    #[must_use]
+    pub fn place(mut self, order: PluginOrderRequest) -> Self {
+        self.result = self.result.with_order(PluginChildOrderAction::Place(order));
+        self
+    }
+
+    #[must_use]
+    pub fn amend(mut self, update: PluginOrderUpdateRequest) -> Self {
+        self.result = self
+            .result
+            .with_order(PluginChildOrderAction::Amend(update));
+        self
+    }
+
+    #[must_use]
+    pub fn orders(mut self, actions: Vec<PluginChildOrderAction>) -> Self {
+        self.result = self.result.with_orders(actions);
+        self
+    }
+
+    /// Attach a log line that the host emits under the `plugin` target.
+    #[must_use]
+    pub fn log(mut self, message: impl Into<String>) -> Self {
+        self.result.logs.push(message.into());
+        self
+    }
+
+    #[must_use]
+    pub fn next_timer_ms(mut self, delay_ms: u64) -> Self {
+        self.result.next_timer_ms = Some(delay_ms);
+        self
+    }
+
+    #[must_use]
+    pub fn completed(mut self) -> Self {
+        self.result.completed = true;
+        self
+    }
+
+    pub fn build(self) -> PluginResult {
+        self.result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn init_context(params: Value) -> PluginInitContext {
+        PluginInitContext {
+            plugin: "test".into(),
+            params,
+            signal: PluginSignal::test("BTCUSDT", Decimal::ONE),
+            risk: PluginRiskContext::default(),
+            metadata: Value::Null,
+        }
+    }
+
+    #[test]
+    fn param_decimal_parses_strings_and_numbers() {
+        let ctx = init_context(json!({ "clip": "0.25", "offset": 3, "bad": "abc", "flag": true }));
+        assert_eq!(ctx.param_decimal("clip"), Some(Decimal::new(25, 2)));
+        assert_eq!(ctx.param_decimal("offset"), Some(Decimal::from(3)));
+        assert_eq!(ctx.param_decimal("missing"), None);
+        assert_eq!(ctx.param_decimal("bad"), None);
+        assert_eq!(ctx.param_decimal("flag"), None);
+    }
+
+    #[test]
+    fn param_u64_parses_numbers_and_numeric_strings() {
+        let ctx = init_context(json!({ "slices": 4, "delay": "250", "neg": -1, "bad": "x" }));
+        assert_eq!(ctx.param_u64("slices"), Some(4));
+        assert_eq!(ctx.param_u64("delay"), Some(250));
+        assert_eq!(ctx.param_u64("missing"), None);
+        assert_eq!(ctx.param_u64("neg"), None);
+        assert_eq!(ctx.param_u64("bad"), None);
+    }
+
+    #[test]
+    fn param_str_requires_string_values() {
+        let ctx = init_context(json!({ "venue": "bybit", "count": 2 }));
+        assert_eq!(ctx.param_str("venue"), Some("bybit"));
+        assert_eq!(ctx.param_str("missing"), None);
+        assert_eq!(ctx.param_str("count"), None);
+    }
+
+    #[test]
+    fn params_helpers_handle_non_object_params() {
+        let ctx = init_context(Value::Null);
+        assert_eq!(ctx.param_decimal("clip"), None);
+        assert_eq!(ctx.param_u64("clip"), None);
+        assert_eq!(ctx.param_str("clip"), None);
+    }
+
+    #[test]
+    fn tick_depth_round_trips_through_json() {
+        let tick = PluginTick::new(
+            "BTCUSDT",
+            Decimal::from(100),
+            Decimal::ONE,
+            PluginSide::Buy,
+            1,
+        )
+        .with_depth(
+            Some((Decimal::from(99), Decimal::from(3))),
+            Some((Decimal::from(101), Decimal::from(4))),
+        );
+        let decoded: PluginTick =
+            serde_json::from_str(&serde_json::to_string(&tick).unwrap()).unwrap();
+        assert!(decoded.has_depth());
+        assert_eq!(decoded.best_bid, Some(Decimal::from(99)));
+        assert_eq!(decoded.bid_size, Some(Decimal::from(3)));
+        assert_eq!(decoded.best_ask, Some(Decimal::from(101)));
+        assert_eq!(decoded.ask_size, Some(Decimal::from(4)));
+    }
+
+    fn limit(symbol: &str, side: PluginSide, price: i64) -> PluginOrderRequest {
+        PluginOrderRequest {
+            symbol: symbol.into(),
+            side,
+            order_type: PluginOrderType::Limit,
+            quantity: Some(Decimal::ONE),
+            notional: None,
+            price: Some(Decimal::from(price)),
+            trigger_price: None,
+            time_in_force: None,
+            client_order_id: None,
+            take_profit: None,
+            stop_loss: None,
+            display_quantity: None,
+        }
+    }
+
+    #[test]
+    fn multi_order_result_round_trips_through_json() {
+        let result = PluginResult::builder()
+            .orders(vec![
+                PluginChildOrderAction::Place(limit("BTCUSDT", PluginSide::Buy, 99)),
+                PluginChildOrderAction::Place(limit("BTCUSDT", PluginSide::Sell, 101)),
+            ])
+            .amend(PluginOrderUpdateRequest {
+                order_id: "abc".into(),
+                symbol: "BTCUSDT".into(),
+                side: PluginSide::Buy,
+                new_price: Some(Decimal::from(98)),
+                new_quantity: None,
+            })
+            .log("grid placed")
+            .next_timer_ms(500)
+            .build();
+        let decoded: PluginResult =
+            serde_json::from_str(&serde_json::to_string(&result).unwrap()).unwrap();
+        let prices: Vec<_> = decoded
+            .actions()
+            .map(|action| match action {
+                PluginChildOrderAction::Place(order) => order.price,
+                PluginChildOrderAction::Amend(update) => update.new_price,
+            })
+            .collect();
+        assert_eq!(
+            prices,
+            vec![
+                Some(Decimal::from(99)),
+                Some(Decimal::from(101)),
+                Some(Decimal::from(98))
+            ]
+        );
+        assert_eq!(decoded.logs, vec!["grid placed".to_string()]);
+        assert_eq!(decoded.next_timer_ms, Some(500));
+        assert!(!decoded.completed);
+    }
+
+    #[test]
+    fn notional_order_decodes_without_quantity() {
+        let order: PluginOrderRequest = serde_json::from_value(json!({
+            "symbol": "BTCUSDT",
+            "side": "buy",
+            "order_type": "market",
+            "notional": "250",
+        }))
+        .unwrap();
+        assert_eq!(order.quantity, None);
+        assert_eq!(order.notional, Some(Decimal::from(250)));
+        assert!(order.validate_size().is_ok());
+
+        let legacy: PluginOrderRequest = serde_json::from_str(
+            &serde_json::to_string(&limit("BTCUSDT", PluginSide::Buy, 99)).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(legacy.quantity, Some(Decimal::ONE));
+        assert_eq!(legacy.notional, None);
+        assert!(legacy.validate_size().is_ok());
+    }
+
+    #[test]
+    fn order_size_requires_exactly_one_of_quantity_and_notional() {
+        let mut order = limit("BTCUSDT", PluginSide::Buy, 99);
+        order.notional = Some(Decimal::from(100));
+        assert!(order.validate_size().is_err());
+
+        order.quantity = None;
+        order.notional = None;
+        assert!(order.validate_size().is_err());
+
+        order.notional = Some(Decimal::ZERO);
+        assert!(order.validate_size().is_err());
+    }
+
+    #[test]
+    fn result_without_timer_omits_field() {
+        let encoded = serde_json::to_value(PluginResult::new()).unwrap();
+        assert!(encoded.get("next_timer_ms").is_none());
+        let decoded: PluginResult = serde_json::from_value(json!({ "completed": true })).unwrap();
+        assert!(decoded.completed);
+        assert_eq!(decoded.next_timer_ms, None);
+        assert_eq!(decoded.actions().count(), 0);
+    }
+
+    #[test]
+    fn tick_without_depth_fields_still_deserializes() {
+        let decoded: PluginTick = serde_json::from_value(json!({
+            "symbol": "BTCUSDT",
+            "price": "100",
+            "size": "1",
+            "side": "sell",
+            "timestamp_ms": 1
+        }))
+        .unwrap();
+        assert!(!decoded.has_depth());
+        assert_eq!(decoded.best_bid, None);
+        assert_eq!(decoded.ask_size, None);
+    }
 }